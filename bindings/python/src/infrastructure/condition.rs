@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 
 use super::{error::into_pyerr, status::StatusKind};
+use crate::subscription::sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind};
 
 #[pyclass]
 #[derive(Clone)]
@@ -42,3 +43,155 @@ impl StatusCondition {
         self.0.get_trigger_value().map_err(into_pyerr)
     }
 }
+
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct GuardCondition(dust_dds::condition::GuardCondition);
+
+impl From<dust_dds::condition::GuardCondition> for GuardCondition {
+    fn from(value: dust_dds::condition::GuardCondition) -> Self {
+        Self(value)
+    }
+}
+
+impl From<GuardCondition> for dust_dds::condition::GuardCondition {
+    fn from(value: GuardCondition) -> Self {
+        value.0
+    }
+}
+
+#[pymethods]
+impl GuardCondition {
+    #[new]
+    pub fn new() -> Self {
+        Self(dust_dds::condition::GuardCondition::new())
+    }
+
+    pub fn set_trigger_value(&self, value: bool) -> PyResult<()> {
+        self.0.set_trigger_value(value).map_err(into_pyerr)
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ReadCondition(dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime>);
+
+impl From<dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime>> for ReadCondition {
+    fn from(value: dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ReadCondition> for dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime> {
+    fn from(value: ReadCondition) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime>> for ReadCondition {
+    fn as_ref(&self) -> &dust_dds::condition::ReadCondition<dust_dds::std_runtime::StdRuntime> {
+        &self.0
+    }
+}
+
+#[pymethods]
+impl ReadCondition {
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.0
+            .get_sample_state_mask()
+            .into_iter()
+            .map(SampleStateKind::from)
+            .collect()
+    }
+
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.0
+            .get_view_state_mask()
+            .into_iter()
+            .map(ViewStateKind::from)
+            .collect()
+    }
+
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.0
+            .get_instance_state_mask()
+            .into_iter()
+            .map(InstanceStateKind::from)
+            .collect()
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct QueryCondition(dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime>);
+
+impl From<dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime>> for QueryCondition {
+    fn from(value: dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<QueryCondition> for dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime> {
+    fn from(value: QueryCondition) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime>> for QueryCondition {
+    fn as_ref(&self) -> &dust_dds::condition::QueryCondition<dust_dds::std_runtime::StdRuntime> {
+        &self.0
+    }
+}
+
+#[pymethods]
+impl QueryCondition {
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.0
+            .get_sample_state_mask()
+            .into_iter()
+            .map(SampleStateKind::from)
+            .collect()
+    }
+
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.0
+            .get_view_state_mask()
+            .into_iter()
+            .map(ViewStateKind::from)
+            .collect()
+    }
+
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.0
+            .get_instance_state_mask()
+            .into_iter()
+            .map(InstanceStateKind::from)
+            .collect()
+    }
+
+    pub fn get_query_expression(&self) -> String {
+        self.0.get_query_expression()
+    }
+
+    pub fn get_query_parameters(&self) -> PyResult<Vec<String>> {
+        self.0.get_query_parameters().map_err(into_pyerr)
+    }
+
+    pub fn set_query_parameters(&self, query_parameters: Vec<String>) -> PyResult<()> {
+        self.0
+            .set_query_parameters(&query_parameters)
+            .map_err(into_pyerr)
+    }
+
+    pub fn get_trigger_value(&self) -> PyResult<bool> {
+        self.0.get_trigger_value().map_err(into_pyerr)
+    }
+}
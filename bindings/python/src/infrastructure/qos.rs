@@ -70,6 +70,7 @@ impl DomainParticipantQos {
         Self(dust_dds::infrastructure::qos::DomainParticipantQos {
             user_data: user_data.clone().into(),
             entity_factory: entity_factory.into(),
+            ..Default::default()
         })
     }
 
@@ -380,6 +381,7 @@ impl DataWriterQos {
             ownership_strength: ownership_strength.into(),
             writer_data_lifecycle: writer_data_lifecycle.into(),
             representation: representation.into(),
+            ..Default::default()
         })
     }
 
@@ -500,6 +502,7 @@ impl DataReaderQos {
             time_based_filter: time_based_filter.into(),
             reader_data_lifecycle: reader_data_lifecycle.into(),
             representation: representation.into(),
+            ..Default::default()
         })
     }
 
@@ -18,6 +18,9 @@ pub enum StatusKind {
     LivelinessChanged,
     PublicationMatched,
     SubscriptionMatched,
+    WriterRestarted,
+    PublicationThrottled,
+    TaskStalled,
 }
 
 impl From<StatusKind> for dust_dds::infrastructure::status::StatusKind {
@@ -60,6 +63,13 @@ impl From<StatusKind> for dust_dds::infrastructure::status::StatusKind {
             StatusKind::SubscriptionMatched => {
                 dust_dds::infrastructure::status::StatusKind::SubscriptionMatched
             }
+            StatusKind::WriterRestarted => {
+                dust_dds::infrastructure::status::StatusKind::WriterRestarted
+            }
+            StatusKind::PublicationThrottled => {
+                dust_dds::infrastructure::status::StatusKind::PublicationThrottled
+            }
+            StatusKind::TaskStalled => dust_dds::infrastructure::status::StatusKind::TaskStalled,
         }
     }
 }
@@ -104,6 +114,13 @@ impl From<dust_dds::infrastructure::status::StatusKind> for StatusKind {
             dust_dds::infrastructure::status::StatusKind::SubscriptionMatched => {
                 StatusKind::SubscriptionMatched
             }
+            dust_dds::infrastructure::status::StatusKind::WriterRestarted => {
+                StatusKind::WriterRestarted
+            }
+            dust_dds::infrastructure::status::StatusKind::PublicationThrottled => {
+                StatusKind::PublicationThrottled
+            }
+            dust_dds::infrastructure::status::StatusKind::TaskStalled => StatusKind::TaskStalled,
         }
     }
 }
@@ -1,11 +1,19 @@
 use pyo3::prelude::*;
 
-use super::{condition::StatusCondition, error::into_pyerr, time::Duration};
+use super::{
+    condition::{GuardCondition, QueryCondition, ReadCondition, StatusCondition},
+    error::into_pyerr,
+    time::Duration,
+};
 
 #[pyclass]
 #[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum Condition {
     StatusCondition { condition: StatusCondition },
+    GuardCondition { condition: GuardCondition },
+    ReadCondition { condition: ReadCondition },
+    QueryCondition { condition: QueryCondition },
 }
 
 impl From<dust_dds::wait_set::Condition<dust_dds::std_runtime::StdRuntime>> for Condition {
@@ -14,6 +22,15 @@ impl From<dust_dds::wait_set::Condition<dust_dds::std_runtime::StdRuntime>> for
             dust_dds::wait_set::Condition::StatusCondition(c) => Condition::StatusCondition {
                 condition: c.into(),
             },
+            dust_dds::wait_set::Condition::GuardCondition(c) => Condition::GuardCondition {
+                condition: c.into(),
+            },
+            dust_dds::wait_set::Condition::ReadCondition(c) => Condition::ReadCondition {
+                condition: c.into(),
+            },
+            dust_dds::wait_set::Condition::QueryCondition(c) => Condition::QueryCondition {
+                condition: c.into(),
+            },
         }
     }
 }
@@ -24,6 +41,15 @@ impl From<Condition> for dust_dds::wait_set::Condition<dust_dds::std_runtime::St
             Condition::StatusCondition { condition } => {
                 dust_dds::wait_set::Condition::StatusCondition(condition.into())
             }
+            Condition::GuardCondition { condition } => {
+                dust_dds::wait_set::Condition::GuardCondition(condition.into())
+            }
+            Condition::ReadCondition { condition } => {
+                dust_dds::wait_set::Condition::ReadCondition(condition.into())
+            }
+            Condition::QueryCondition { condition } => {
+                dust_dds::wait_set::Condition::QueryCondition(condition.into())
+            }
         }
     }
 }
@@ -53,7 +79,7 @@ impl WaitSet {
         self.0.attach_condition(cond.into()).map_err(into_pyerr)
     }
 
-    pub fn detach_condition(&self, cond: Condition) -> PyResult<()> {
+    pub fn detach_condition(&mut self, cond: Condition) -> PyResult<()> {
         self.0.detach_condition(cond.into()).map_err(into_pyerr)
     }
 
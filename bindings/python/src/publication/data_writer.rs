@@ -129,8 +129,12 @@ impl DataWriter {
             .map_err(into_pyerr)
     }
 
-    pub fn get_key_value(&self, _key_holder: Py<PyAny>, _handle: InstanceHandle) -> PyResult<()> {
-        unimplemented!()
+    pub fn get_key_value(&self, key_holder: Py<PyAny>, handle: InstanceHandle) -> PyResult<()> {
+        let mut key_value = PythonDdsData::from_py_object(key_holder.clone())?;
+        self.0
+            .get_key_value(&mut key_value, handle.into())
+            .map_err(into_pyerr)?;
+        key_value.update_py_object(&key_holder)
     }
 
     pub fn lookup_instance(&self, instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
@@ -141,7 +145,7 @@ impl DataWriter {
             .map(InstanceHandle::from))
     }
 
-    pub fn write(&self, data: Py<PyAny>, handle: Option<InstanceHandle>) -> PyResult<()> {
+    pub fn write(&self, data: Py<PyAny>, handle: Option<InstanceHandle>) -> PyResult<Option<i64>> {
         self.0
             .write(
                 &PythonDdsData::from_py_object(data)?,
@@ -156,7 +160,7 @@ impl DataWriter {
         data: Py<PyAny>,
         handle: Option<InstanceHandle>,
         timestamp: Time,
-    ) -> PyResult<()> {
+    ) -> PyResult<Option<i64>> {
         self.0
             .write_w_timestamp(
                 &PythonDdsData::from_py_object(data)?,
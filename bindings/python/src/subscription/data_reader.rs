@@ -7,7 +7,7 @@ use crate::{
     builtin_topics::PublicationBuiltinTopicData,
     domain::domain_participant::DomainParticipant,
     infrastructure::{
-        condition::StatusCondition,
+        condition::{QueryCondition, ReadCondition, StatusCondition},
         error::into_pyerr,
         instance::InstanceHandle,
         qos::DataReaderQos,
@@ -385,12 +385,72 @@ impl DataReader {
         }
     }
 
-    pub fn get_key_value(&self, _key_holder: Py<PyAny>, _handle: InstanceHandle) -> PyResult<()> {
-        unimplemented!()
+    #[pyo3(signature = (max_samples, previous_handle, a_condition))]
+    pub fn read_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadCondition,
+    ) -> PyResult<Vec<Sample>> {
+        let type_ = DomainParticipant::get_type(&self.0.get_topicdescription().get_type_name())
+            .ok_or(PyTypeError::new_err("Type information not found"))?;
+        match self.0.read_next_instance_w_condition(
+            max_samples,
+            previous_handle.map(|x| x.into()),
+            a_condition.as_ref(),
+        ) {
+            Ok(s) => Ok(s
+                .into_iter()
+                .map(|s| Sample {
+                    sample: s,
+                    type_: type_.clone(),
+                })
+                .collect()),
+            Err(dust_dds::infrastructure::error::DdsError::NoData) => Ok(Vec::new()),
+            Err(e) => Err(PyTypeError::new_err(format!("{:?}", e))),
+        }
     }
 
-    pub fn lookup_instance(&self, _instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
-        unimplemented!()
+    #[pyo3(signature = (max_samples, previous_handle, a_condition))]
+    pub fn take_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadCondition,
+    ) -> PyResult<Vec<Sample>> {
+        let type_ = DomainParticipant::get_type(&self.0.get_topicdescription().get_type_name())
+            .ok_or(PyTypeError::new_err("Type information not found"))?;
+        match self.0.take_next_instance_w_condition(
+            max_samples,
+            previous_handle.map(|x| x.into()),
+            a_condition.as_ref(),
+        ) {
+            Ok(s) => Ok(s
+                .into_iter()
+                .map(|s| Sample {
+                    sample: s,
+                    type_: type_.clone(),
+                })
+                .collect()),
+            Err(dust_dds::infrastructure::error::DdsError::NoData) => Ok(Vec::new()),
+            Err(e) => Err(PyTypeError::new_err(format!("{:?}", e))),
+        }
+    }
+
+    pub fn get_key_value(&self, key_holder: Py<PyAny>, handle: InstanceHandle) -> PyResult<()> {
+        let mut key_value = PythonDdsData::from_py_object(key_holder.clone())?;
+        self.0
+            .get_key_value(&mut key_value, handle.into())
+            .map_err(into_pyerr)?;
+        key_value.update_py_object(&key_holder)
+    }
+
+    pub fn lookup_instance(&self, instance: Py<PyAny>) -> PyResult<Option<InstanceHandle>> {
+        Ok(self
+            .0
+            .lookup_instance(&PythonDdsData::from_py_object(instance)?)
+            .map_err(into_pyerr)?
+            .map(InstanceHandle::from))
     }
 
     pub fn get_liveliness_changed_status(&self) -> PyResult<LivelinessChangedStatus> {
@@ -464,6 +524,15 @@ impl DataReader {
             .into())
     }
 
+    pub fn get_matched_publication_high_watermark(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> PyResult<i64> {
+        self.0
+            .get_matched_publication_high_watermark(publication_handle.into())
+            .map_err(into_pyerr)
+    }
+
     pub fn get_matched_publications(&self) -> PyResult<Vec<InstanceHandle>> {
         Ok(self
             .0
@@ -503,6 +572,92 @@ impl DataReader {
         self.0.set_listener(listener, &mask).map_err(into_pyerr)
     }
 
+    #[pyo3(signature = (
+        sample_states=ANY_SAMPLE_STATE.to_vec(),
+        view_states=ANY_VIEW_STATE.to_vec(),
+        instance_states=ANY_INSTANCE_STATE.to_vec(),
+    ))]
+    pub fn create_readcondition(
+        &self,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> PyResult<ReadCondition> {
+        let sample_states: Vec<dust_dds::infrastructure::sample_info::SampleStateKind> =
+            sample_states
+                .into_iter()
+                .map(dust_dds::infrastructure::sample_info::SampleStateKind::from)
+                .collect();
+        let view_states: Vec<dust_dds::infrastructure::sample_info::ViewStateKind> = view_states
+            .into_iter()
+            .map(dust_dds::infrastructure::sample_info::ViewStateKind::from)
+            .collect();
+        let instance_states: Vec<dust_dds::infrastructure::sample_info::InstanceStateKind> =
+            instance_states
+                .into_iter()
+                .map(dust_dds::infrastructure::sample_info::InstanceStateKind::from)
+                .collect();
+        Ok(self
+            .0
+            .create_readcondition(&sample_states, &view_states, &instance_states)
+            .map_err(into_pyerr)?
+            .into())
+    }
+
+    #[pyo3(signature = (
+        sample_states,
+        view_states,
+        instance_states,
+        query_expression,
+        query_parameters=Vec::new(),
+    ))]
+    pub fn create_querycondition(
+        &self,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        query_expression: String,
+        query_parameters: Vec<String>,
+    ) -> PyResult<QueryCondition> {
+        let sample_states: Vec<dust_dds::infrastructure::sample_info::SampleStateKind> =
+            sample_states
+                .into_iter()
+                .map(dust_dds::infrastructure::sample_info::SampleStateKind::from)
+                .collect();
+        let view_states: Vec<dust_dds::infrastructure::sample_info::ViewStateKind> = view_states
+            .into_iter()
+            .map(dust_dds::infrastructure::sample_info::ViewStateKind::from)
+            .collect();
+        let instance_states: Vec<dust_dds::infrastructure::sample_info::InstanceStateKind> =
+            instance_states
+                .into_iter()
+                .map(dust_dds::infrastructure::sample_info::InstanceStateKind::from)
+                .collect();
+        Ok(self
+            .0
+            .create_querycondition(
+                &sample_states,
+                &view_states,
+                &instance_states,
+                &query_expression,
+                &query_parameters,
+            )
+            .map_err(into_pyerr)?
+            .into())
+    }
+
+    pub fn delete_readcondition(&self, a_condition: &ReadCondition) -> PyResult<()> {
+        self.0
+            .delete_readcondition(a_condition.as_ref())
+            .map_err(into_pyerr)
+    }
+
+    pub fn delete_querycondition(&self, a_condition: &QueryCondition) -> PyResult<()> {
+        self.0
+            .delete_querycondition(a_condition.as_ref())
+            .map_err(into_pyerr)
+    }
+
     pub fn get_statuscondition(&self) -> StatusCondition {
         self.0.get_statuscondition().into()
     }
@@ -448,6 +448,20 @@ impl PythonDdsData {
             _ => panic!("Unknown endianness"),
         }
     }
+
+    pub fn update_py_object(self, py_object: &Py<PyAny>) -> PyResult<()> {
+        let type_ = Python::with_gil(|py| py_object.bind(py).get_type().into_any().unbind());
+        let value = self.into_py_object(&type_)?;
+        Python::with_gil(|py| {
+            let annotations = type_.bind(py).getattr("__annotations__")?;
+            let annotation_dict = annotations.downcast::<PyDict>().map_err(PyErr::from)?;
+            for member_name in annotation_dict.keys() {
+                let member_name_str = member_name.downcast::<PyString>()?;
+                py_object.setattr(py, member_name_str, value.getattr(py, member_name_str)?)?;
+            }
+            Ok(())
+        })
+    }
 }
 
 impl DdsSerialize for PythonDdsData {
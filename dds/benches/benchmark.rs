@@ -300,11 +300,87 @@ fn best_effort_write_and_receive_frag(c: &mut Criterion) {
     });
 }
 
+fn reliable_write_with_many_writers(c: &mut Criterion) {
+    const WRITER_COUNT: usize = 8;
+
+    let domain_id = 204;
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let mut writer_list = Vec::new();
+    let mut reader_list = Vec::new();
+    for i in 0..WRITER_COUNT {
+        let topic = participant
+            .create_topic::<KeyedData>(
+                &format!("MyTopic{i}"),
+                "KeyedData",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let writer = publisher
+            .create_datawriter(
+                &topic,
+                QosKind::Specific(writer_qos.clone()),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let reader = subscriber
+            .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+
+        writer_list.push(writer);
+        reader_list.push(reader);
+    }
+
+    // Every writer publishes from its own thread so that the writes contend with each other and with the
+    // acknowledgments processed by the transport on the same participant
+    c.bench_function("reliable_write_with_many_writers", |b| {
+        b.iter(|| {
+            std::thread::scope(|s| {
+                for writer in &writer_list {
+                    s.spawn(|| {
+                        for value in 0..10 {
+                            writer.write(&KeyedData { id: 1, value }, None).unwrap();
+                        }
+                    });
+                }
+            });
+        })
+    });
+}
+
 criterion_group!(
     benches,
     best_effort_write_only,
     best_effort_read_only,
     best_effort_write_and_receive,
-    best_effort_write_and_receive_frag
+    best_effort_write_and_receive_frag,
+    reliable_write_with_many_writers
 );
 criterion_main!(benches);
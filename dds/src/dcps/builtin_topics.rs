@@ -17,7 +17,7 @@ use super::{
             DurabilityQosPolicy, GroupDataQosPolicy, HistoryQosPolicy, LatencyBudgetQosPolicy,
             LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy, OwnershipStrengthQosPolicy,
             PartitionQosPolicy, PresentationQosPolicy, ReliabilityQosPolicy,
-            ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
+            ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy, TopicMetadata,
             TransportPriorityQosPolicy, UserDataQosPolicy,
             DEFAULT_RELIABILITY_QOS_POLICY_DATA_READER_AND_TOPICS,
             DEFAULT_RELIABILITY_QOS_POLICY_DATA_WRITER,
@@ -40,6 +40,16 @@ pub const DCPS_TOPIC: &str = "DCPSTopic";
 /// Topic name of the built-in participant discovery topic
 pub const DCPS_PARTICIPANT: &str = "DCPSParticipant";
 
+/// Address at which a discovered endpoint receives the messages, as announced in its discovery information.
+/// The locators of the built-in UDP transport are of kind [`LOCATOR_KIND_UDP_V4`] or [`LOCATOR_KIND_UDP_V6`].
+pub use crate::transport::types::Locator;
+
+/// Kind of the [`Locator`] of an IPv4 UDP address.
+pub use crate::transport::types::LOCATOR_KIND_UDP_V4;
+
+/// Kind of the [`Locator`] of an IPv6 UDP address.
+pub use crate::transport::types::LOCATOR_KIND_UDP_V6;
+
 /// Structure representing the instance handle (or key) of an entity.
 #[derive(Debug, PartialEq, Eq, Clone, Default, XTypesSerialize, XTypesDeserialize)]
 pub struct BuiltInTopicKey {
@@ -216,6 +226,11 @@ impl TopicBuiltinTopicData {
         &self.topic_data
     }
 
+    /// Get the [`TopicMetadata`] encoded in the topic data QoS policy of the discovered topic.
+    pub fn topic_metadata(&self) -> DdsResult<TopicMetadata> {
+        self.topic_data.metadata()
+    }
+
     /// Get the data representation QoS policy of the discovered topic.
     pub fn representation(&self) -> &DataRepresentationQosPolicy {
         &self.representation
@@ -6,40 +6,59 @@ use super::{
         instance::InstanceHandle,
         qos::DataReaderQos,
         qos_policy::{
-            DestinationOrderQosPolicyKind, HistoryQosPolicyKind, OwnershipQosPolicyKind,
-            QosPolicyId,
+            DestinationOrderQosPolicyKind, DurabilityQosPolicyKind, HistoryQosPolicyKind, Length,
+            LivelinessQosPolicyKind, OwnershipQosPolicyKind, QosPolicyId, ReliabilityQosPolicyKind,
+        },
+        sample_info::{
+            InstanceStateKind, SampleInfo, SampleStateKind, SequenceNumber, ViewStateKind,
         },
-        sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
         status::{
-            QosPolicyCount, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-            SampleRejectedStatus, SampleRejectedStatusKind, StatusKind, SubscriptionMatchedStatus,
+            LivelinessChangedStatus, QosPolicyCount, RequestedDeadlineMissedStatus,
+            RequestedIncompatibleQosStatus, SampleLostStatus, SampleRejectedStatus,
+            SampleRejectedStatusKind, StatusKind, SubscriptionMatchedStatus, WriterRestartedStatus,
         },
-        time::{DurationKind, Time},
+        time::{Duration, DurationKind, Time},
     },
     listeners::domain_participant_listener::ListenerMail,
+    multitopic::QueryExpression,
     status_condition::StatusCondition,
     status_condition_actor::StatusConditionActor,
-    xtypes_glue::key_and_instance_handle::{
-        get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+    xtypes_glue::{
+        key_and_instance_handle::{
+            get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+        },
+        sample_members::{deserialize_sample_members, is_final_type, replace_key_members},
     },
 };
 use crate::{
-    runtime::DdsRuntime,
+    domain::memory_usage::DataReaderMemoryUsage,
+    runtime::{ChannelSend, DdsRuntime, OneshotSend},
+    subscription::data_reader::{
+        DeserializationErrorPolicy, HeadOfLineStatus, IncompleteCoherentSetPolicy,
+        InstanceActivity, InstanceFreshnessObserver, InstanceFreshnessStatus, InstanceStatistics,
+    },
     transport::{
         history_cache::CacheChange,
         reader::{TransportStatefulReader, TransportStatelessReader},
-        types::{ChangeKind, Guid},
+        types::{ChangeKind, Guid, Locator},
     },
     xtypes::dynamic_type::DynamicType,
 };
 use alloc::{
     boxed::Box,
+    collections::VecDeque,
     string::{String, ToString},
     sync::Arc,
+    vec,
     vec::Vec,
 };
+use core::{any::Any, fmt};
+
+type SampleList = Vec<(Option<SampleData>, SampleInfo)>;
+pub type LoanedSampleList = Vec<(Option<SampleData>, SampleInfo, Option<Arc<()>>)>;
 
-type SampleList = Vec<(Option<Arc<[u8]>>, SampleInfo)>;
+/// Checks whether the serialized data of a sample can be deserialized into the type of the reader.
+pub type DeserializationValidator = Box<dyn Fn(&[u8]) -> bool + Send>;
 
 pub enum AddChangeResult {
     Added(InstanceHandle),
@@ -47,12 +66,28 @@ pub enum AddChangeResult {
     Rejected(InstanceHandle, SampleRejectedStatusKind),
 }
 
+pub enum WriterLivelinessCheck {
+    /// The writer is alive and its lease expires after the given duration
+    Alive(Duration),
+    /// The writer lost its liveliness on this check
+    Lost,
+    /// The writer is not alive, not matched or has an infinite lease
+    Unchanged,
+}
+
 struct InstanceState {
     handle: InstanceHandle,
     view_state: ViewStateKind,
     instance_state: InstanceStateKind,
     most_recent_disposed_generation_count: i32,
     most_recent_no_writers_generation_count: i32,
+    most_recent_source_timestamp_per_writer: Vec<([u8; 16], Time)>,
+    received_sample_count: u64,
+    first_reception_timestamp: Option<Time>,
+    last_reception_timestamp: Option<Time>,
+    stale: bool,
+    // Serialized data of a received sample of the instance from which its key can be retrieved
+    key_holder: Option<Arc<[u8]>>,
 }
 
 impl InstanceState {
@@ -63,7 +98,27 @@ impl InstanceState {
             instance_state: InstanceStateKind::Alive,
             most_recent_disposed_generation_count: 0,
             most_recent_no_writers_generation_count: 0,
+            most_recent_source_timestamp_per_writer: Vec::new(),
+            received_sample_count: 0,
+            first_reception_timestamp: None,
+            last_reception_timestamp: None,
+            stale: false,
+            key_holder: None,
+        }
+    }
+
+    fn set_key_holder(&mut self, change_kind: ChangeKind, data_value: &Arc<[u8]>) {
+        if change_kind == ChangeKind::Alive && self.key_holder.is_none() {
+            self.key_holder = Some(data_value.clone());
+        }
+    }
+
+    fn record_reception(&mut self, reception_timestamp: Time) {
+        self.received_sample_count += 1;
+        if self.first_reception_timestamp.is_none() {
+            self.first_reception_timestamp = Some(reception_timestamp);
         }
+        self.last_reception_timestamp = Some(reception_timestamp);
     }
 
     fn update_state(&mut self, change_kind: ChangeKind) {
@@ -112,6 +167,19 @@ impl InstanceState {
     }
 }
 
+/// History buffer of a sample loaned to the application. The loan is outstanding while the application holds
+/// the other reference to it.
+struct SampleLoan {
+    data_value: Arc<[u8]>,
+    loan: Arc<()>,
+}
+
+impl SampleLoan {
+    fn is_outstanding(&self) -> bool {
+        Arc::strong_count(&self.loan) > 1
+    }
+}
+
 #[derive(Debug)]
 pub struct ReaderSample {
     pub kind: ChangeKind,
@@ -119,15 +187,43 @@ pub struct ReaderSample {
     pub instance_handle: InstanceHandle,
     pub source_timestamp: Option<Time>,
     pub data_value: Arc<[u8]>,
+    pub read_ahead_value: Option<ReadAheadValue>,
+    pub valid_data: bool,
     pub sample_state: SampleStateKind,
     pub disposed_generation_count: i32,
     pub no_writers_generation_count: i32,
     pub reception_timestamp: Time,
 }
 
+/// Serialized data of a received sample together with the value deserialized on arrival, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SampleData {
+    pub serialized_data: Arc<[u8]>,
+    pub read_ahead_value: Option<ReadAheadValue>,
+}
+
+/// Type-erased value of a sample deserialized on arrival by a reader with read-ahead deserialization enabled.
+#[derive(Clone)]
+pub struct ReadAheadValue(pub Arc<dyn Any + Send + Sync>);
+
+impl fmt::Debug for ReadAheadValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ReadAheadValue")
+    }
+}
+
+// The type-erased values can not be compared so two values are only equal if they are the same value
+impl PartialEq for ReadAheadValue {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ReadAheadValue {}
+
 pub struct IndexedSample {
     pub index: usize,
-    pub sample: (Option<Arc<[u8]>>, SampleInfo),
+    pub sample: (Option<SampleData>, SampleInfo),
 }
 
 pub enum TransportReaderKind {
@@ -147,9 +243,120 @@ impl TransportReaderKind {
 struct InstanceOwnership {
     instance_handle: InstanceHandle,
     owner_handle: [u8; 16],
+    // Publication which delivered the samples of the owner, which differs from the owner when they are forwarded
+    owner_publication: [u8; 16],
     last_received_time: Time,
 }
 
+struct PublicationHighWatermark {
+    publication_handle: InstanceHandle,
+    sequence_number: SequenceNumber,
+}
+
+struct MatchedPublicationLocators {
+    publication_handle: InstanceHandle,
+    locator_list: Vec<Locator>,
+}
+
+struct MatchedWriterLiveliness {
+    publication_handle: InstanceHandle,
+    last_assertion: Time,
+    alive: bool,
+}
+
+// The samples of a coherent set which the writer never completes, for example because it stopped in the
+// middle of the set, are not held indefinitely by default
+const DEFAULT_INCOMPLETE_COHERENT_SET_TIMEOUT: Duration = Duration::new(10, 0);
+
+struct PendingCoherentSet {
+    writer_guid: Guid,
+    coherent_set: i64,
+    changes: Vec<(CacheChange, Time)>,
+}
+
+struct HeadOfLineQueue {
+    writer_guid: Guid,
+    last_delivered_sequence_number: SequenceNumber,
+    blocked_sequence_number: Option<SequenceNumber>,
+    held_changes: VecDeque<(CacheChange, Time)>,
+}
+
+pub struct ContentFilter {
+    filter_expression: String,
+    filter_parameters: Vec<String>,
+    query_expression: QueryExpression,
+}
+
+impl ContentFilter {
+    pub fn filter_expression(&self) -> &str {
+        &self.filter_expression
+    }
+
+    pub fn filter_parameters(&self) -> &[String] {
+        &self.filter_parameters
+    }
+
+    /// Returns true if the serialized sample passes the filter.
+    pub fn evaluate(&self, data: &[u8], type_support: &dyn DynamicType) -> bool {
+        deserialize_sample_members(data, type_support).is_ok_and(|members| {
+            self.query_expression
+                .evaluate(&members, &self.filter_parameters)
+        })
+    }
+}
+
+struct ReadConditionState {
+    condition_id: u32,
+    sample_states: Vec<SampleStateKind>,
+    view_states: Vec<ViewStateKind>,
+    instance_states: Vec<InstanceStateKind>,
+    query: Option<(QueryExpression, Vec<String>)>,
+}
+
+/// Storage of a data reader allocated before any of its other state is created, so that a reader
+/// whose resources can not be allocated is rejected without leaving anything behind.
+pub struct DataReaderResources {
+    reserved_samples: usize,
+    sample_list: Vec<ReaderSample>,
+    instances: Vec<InstanceState>,
+    matched_publication_list: Vec<PublicationBuiltinTopicData>,
+    max_matched_writers: usize,
+}
+
+impl DataReaderResources {
+    pub fn allocate(
+        reserved_samples: Length,
+        max_instances: Length,
+        max_matched_writers: usize,
+    ) -> DdsResult<Self> {
+        let mut resources = Self {
+            reserved_samples: 0,
+            sample_list: Vec::new(),
+            instances: Vec::new(),
+            matched_publication_list: Vec::new(),
+            max_matched_writers,
+        };
+        if let Length::Limited(reserved_samples) = reserved_samples {
+            resources
+                .sample_list
+                .try_reserve_exact(reserved_samples as usize)
+                .map_err(|_| DdsError::OutOfResources)?;
+            resources.reserved_samples = reserved_samples as usize;
+        }
+        if let Length::Limited(max_instances) = max_instances {
+            resources
+                .instances
+                .try_reserve_exact(max_instances as usize)
+                .map_err(|_| DdsError::OutOfResources)?;
+        }
+        resources
+            .matched_publication_list
+            .try_reserve_exact(max_matched_writers)
+            .map_err(|_| DdsError::OutOfResources)?;
+        Ok(resources)
+    }
+}
+
 pub struct DataReaderEntity<R: DdsRuntime> {
     instance_handle: InstanceHandle,
     sample_list: Vec<ReaderSample>,
@@ -160,8 +367,13 @@ pub struct DataReaderEntity<R: DdsRuntime> {
     requested_deadline_missed_status: RequestedDeadlineMissedStatus,
     requested_incompatible_qos_status: RequestedIncompatibleQosStatus,
     sample_rejected_status: SampleRejectedStatus,
+    sample_lost_status: SampleLostStatus,
     subscription_matched_status: SubscriptionMatchedStatus,
     matched_publication_list: Vec<PublicationBuiltinTopicData>,
+    lost_durable_publication_list: Vec<PublicationBuiltinTopicData>,
+    writer_restarted_status: WriterRestartedStatus,
+    liveliness_changed_status: LivelinessChangedStatus,
+    writer_liveliness: Vec<MatchedWriterLiveliness>,
     enabled: bool,
     data_available_status_changed_flag: bool,
     incompatible_writer_list: Vec<InstanceHandle>,
@@ -170,7 +382,31 @@ pub struct DataReaderEntity<R: DdsRuntime> {
     listener_mask: Vec<StatusKind>,
     instances: Vec<InstanceState>,
     instance_ownership: Vec<InstanceOwnership>,
+    publication_high_watermarks: Vec<PublicationHighWatermark>,
+    matched_publication_locators: Vec<MatchedPublicationLocators>,
     transport_reader: TransportReaderKind,
+    reserved_samples: usize,
+    read_ahead_sender: Option<R::ChannelSender<Arc<[u8]>>>,
+    read_ahead_requests: Vec<Arc<[u8]>>,
+    deserialization_error_policy: DeserializationErrorPolicy,
+    deserialization_validator: Option<DeserializationValidator>,
+    pending_coherent_sets: Vec<PendingCoherentSet>,
+    incomplete_coherent_set_timeout: DurationKind,
+    incomplete_coherent_set_policy: IncompleteCoherentSetPolicy,
+    gap_free_delivery: bool,
+    head_of_line_queues: Vec<HeadOfLineQueue>,
+    multitopic_reader: Option<InstanceHandle>,
+    multitopic_constituent_list: Vec<InstanceHandle>,
+    multitopic_sequence_number: i64,
+    read_conditions: Vec<ReadConditionState>,
+    read_condition_counter: u32,
+    added_change_count: u64,
+    sample_waiters: Vec<R::OneshotSender<DdsResult<u64>>>,
+    content_filter: Option<ContentFilter>,
+    sample_loans: Vec<SampleLoan>,
+    instance_freshness_period: DurationKind,
+    instance_freshness_observer: Option<Arc<dyn InstanceFreshnessObserver>>,
+    instance_freshness_status: InstanceFreshnessStatus,
 }
 
 impl<R: DdsRuntime> DataReaderEntity<R> {
@@ -196,8 +432,13 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             requested_deadline_missed_status: RequestedDeadlineMissedStatus::const_default(),
             requested_incompatible_qos_status: RequestedIncompatibleQosStatus::const_default(),
             sample_rejected_status: SampleRejectedStatus::const_default(),
+            sample_lost_status: SampleLostStatus::const_default(),
             subscription_matched_status: SubscriptionMatchedStatus::const_default(),
             matched_publication_list: Vec::new(),
+            lost_durable_publication_list: Vec::new(),
+            writer_restarted_status: WriterRestartedStatus::const_default(),
+            liveliness_changed_status: LivelinessChangedStatus::const_default(),
+            writer_liveliness: Vec::new(),
             enabled: false,
             data_available_status_changed_flag: false,
             incompatible_writer_list: Vec::new(),
@@ -206,10 +447,134 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             listener_mask,
             instances: Vec::new(),
             instance_ownership: Vec::new(),
+            publication_high_watermarks: Vec::new(),
+            matched_publication_locators: Vec::new(),
             transport_reader,
+            reserved_samples: 0,
+            read_ahead_sender: None,
+            read_ahead_requests: Vec::new(),
+            deserialization_error_policy: DeserializationErrorPolicy::Deliver,
+            deserialization_validator: None,
+            pending_coherent_sets: Vec::new(),
+            incomplete_coherent_set_timeout: DurationKind::Finite(
+                DEFAULT_INCOMPLETE_COHERENT_SET_TIMEOUT,
+            ),
+            incomplete_coherent_set_policy: IncompleteCoherentSetPolicy::Release,
+            gap_free_delivery: false,
+            head_of_line_queues: Vec::new(),
+            multitopic_reader: None,
+            multitopic_constituent_list: Vec::new(),
+            multitopic_sequence_number: 0,
+            read_conditions: Vec::new(),
+            read_condition_counter: 0,
+            added_change_count: 0,
+            sample_waiters: Vec::new(),
+            content_filter: None,
+            sample_loans: Vec::new(),
+            instance_freshness_period: DurationKind::Infinite,
+            instance_freshness_observer: None,
+            instance_freshness_status: InstanceFreshnessStatus::const_default(),
+        }
+    }
+
+    pub fn has_outstanding_loans(&self) -> bool {
+        self.sample_loans.iter().any(SampleLoan::is_outstanding)
+    }
+
+    fn loan_samples(&mut self, samples: SampleList) -> LoanedSampleList {
+        self.sample_loans.retain(SampleLoan::is_outstanding);
+        samples
+            .into_iter()
+            .map(|(data, sample_info)| {
+                let loan = data.as_ref().map(|data| {
+                    let loan = Arc::new(());
+                    self.sample_loans.push(SampleLoan {
+                        data_value: data.serialized_data.clone(),
+                        loan: loan.clone(),
+                    });
+                    loan
+                });
+                (data, sample_info, loan)
+            })
+            .collect()
+    }
+
+    /// Data of the samples which are no longer in the history but are still loaned to the application. Their
+    /// storage is only released when the loans are returned so they keep counting against the resources of the reader.
+    fn loaned_data_outside_history(&self) -> Vec<&Arc<[u8]>> {
+        let mut loaned_data: Vec<&Arc<[u8]>> = Vec::new();
+        for sample_loan in self.sample_loans.iter().filter(|l| l.is_outstanding()) {
+            if !self
+                .sample_list
+                .iter()
+                .any(|s| Arc::ptr_eq(&s.data_value, &sample_loan.data_value))
+                && !loaned_data
+                    .iter()
+                    .any(|d| Arc::ptr_eq(d, &sample_loan.data_value))
+            {
+                loaned_data.push(&sample_loan.data_value);
+            }
+        }
+        loaned_data
+    }
+
+    pub fn reserved_samples(&self) -> usize {
+        self.reserved_samples
+    }
+
+    pub fn memory_usage(&self) -> DataReaderMemoryUsage {
+        let held_changes = self
+            .pending_coherent_sets
+            .iter()
+            .flat_map(|s| s.changes.iter())
+            .chain(
+                self.head_of_line_queues
+                    .iter()
+                    .flat_map(|q| q.held_changes.iter()),
+            );
+        let mut sample_count = self.sample_list.len();
+        let mut cache_bytes: usize = self
+            .sample_list
+            .iter()
+            .map(|s| core::mem::size_of::<ReaderSample>() + s.data_value.len())
+            .sum();
+        for (change, _) in held_changes {
+            sample_count += 1;
+            cache_bytes += core::mem::size_of::<CacheChange>() + change.data_value().len();
+        }
+        for loaned_data in self.loaned_data_outside_history() {
+            sample_count += 1;
+            cache_bytes += loaned_data.len();
+        }
+        let fragment_buffer_bytes = match &self.transport_reader {
+            TransportReaderKind::Stateful(r) => r.fragment_buffer_memory_usage(),
+            TransportReaderKind::Stateless(_) => 0,
+        };
+
+        DataReaderMemoryUsage {
+            reader_handle: self.instance_handle,
+            topic_name: self.topic_name.clone(),
+            sample_count: sample_count as u64,
+            instance_count: self.instances.len() as u64,
+            cache_bytes: cache_bytes as u64,
+            fragment_buffer_bytes: fragment_buffer_bytes as u64,
         }
     }
 
+    /// Takes the storage allocated for the reader before it was created and reserves the proxies of the
+    /// matched writers in the transport.
+    pub fn reserve_resources(&mut self, resources: DataReaderResources) -> DdsResult<()> {
+        self.sample_list = resources.sample_list;
+        self.instances = resources.instances;
+        self.matched_publication_list = resources.matched_publication_list;
+        self.reserved_samples = resources.reserved_samples;
+        if let TransportReaderKind::Stateful(r) = &mut self.transport_reader {
+            r.reserve_resources(resources.max_matched_writers)
+                .map_err(|_| DdsError::OutOfResources)?;
+        }
+        Ok(())
+    }
+
     fn create_indexed_sample_collection(
         &mut self,
         max_samples: i32,
@@ -226,7 +591,7 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
 
         let mut indexed_samples = Vec::new();
 
-        let mut instances_in_collection = Vec::<InstanceState>::new();
+        let mut instances_in_collection = Vec::<InstanceHandle>::new();
         for (index, cache_change) in self.sample_list.iter().enumerate() {
             if let Some(h) = specific_instance_handle {
                 if cache_change.instance_handle != h {
@@ -249,32 +614,32 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                 continue;
             }
 
-            if !instances_in_collection
-                .iter()
-                .any(|x| x.handle() == cache_change.instance_handle)
-            {
-                instances_in_collection.push(InstanceState::new(cache_change.instance_handle));
+            if !instances_in_collection.contains(&cache_change.instance_handle) {
+                instances_in_collection.push(cache_change.instance_handle);
             }
 
-            let instance_from_collection = instances_in_collection
-                .iter_mut()
-                .find(|x| x.handle() == cache_change.instance_handle)
-                .expect("Instance must exist");
-            instance_from_collection.update_state(cache_change.kind);
             let sample_state = cache_change.sample_state;
             let view_state = instance.view_state;
             let instance_state = instance.instance_state;
 
+            // The generation counts of the sample are those of its instance when it was received, so the
+            // difference with the current counts of the instance gives the generations which followed it
             let absolute_generation_rank = (instance.most_recent_disposed_generation_count
                 + instance.most_recent_no_writers_generation_count)
-                - (instance_from_collection.most_recent_disposed_generation_count
-                    + instance_from_collection.most_recent_no_writers_generation_count);
+                - (cache_change.disposed_generation_count
+                    + cache_change.no_writers_generation_count);
 
             let (data, valid_data) = match cache_change.kind {
-                ChangeKind::Alive | ChangeKind::AliveFiltered => {
-                    (Some(cache_change.data_value.clone()), true)
-                }
-                ChangeKind::NotAliveDisposed
+                ChangeKind::Alive | ChangeKind::AliveFiltered if cache_change.valid_data => (
+                    Some(SampleData {
+                        serialized_data: cache_change.data_value.clone(),
+                        read_ahead_value: cache_change.read_ahead_value.clone(),
+                    }),
+                    true,
+                ),
+                ChangeKind::Alive
+                | ChangeKind::AliveFiltered
+                | ChangeKind::NotAliveDisposed
                 | ChangeKind::NotAliveUnregistered
                 | ChangeKind::NotAliveDisposedUnregistered => (None, false),
             };
@@ -304,7 +669,7 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         }
 
         // After the collection is created, update the relative generation rank values and mark the read instances as viewed
-        for handle in instances_in_collection.iter().map(|x| x.handle()) {
+        for handle in instances_in_collection {
             let most_recent_sample_absolute_generation_rank = indexed_samples
                 .iter()
                 .filter(
@@ -374,30 +739,36 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         }
     }
 
+    fn get_cache_change_instance_handle(
+        &self,
+        cache_change: &CacheChange,
+    ) -> DdsResult<InstanceHandle> {
+        let instance_handle = match cache_change.kind {
+            ChangeKind::Alive | ChangeKind::AliveFiltered => {
+                get_instance_handle_from_serialized_foo(
+                    cache_change.data_value.as_ref(),
+                    self.type_support.as_ref(),
+                )?
+            }
+            ChangeKind::NotAliveDisposed
+            | ChangeKind::NotAliveUnregistered
+            | ChangeKind::NotAliveDisposedUnregistered => match cache_change.instance_handle {
+                Some(i) => InstanceHandle::new(i),
+                None => get_instance_handle_from_serialized_key(
+                    cache_change.data_value.as_ref(),
+                    self.type_support.as_ref(),
+                )?,
+            },
+        };
+        Ok(instance_handle)
+    }
+
     fn convert_cache_change_to_sample(
         &mut self,
         cache_change: CacheChange,
         reception_timestamp: Time,
     ) -> DdsResult<ReaderSample> {
-        let instance_handle = {
-            match cache_change.kind {
-                ChangeKind::Alive | ChangeKind::AliveFiltered => {
-                    get_instance_handle_from_serialized_foo(
-                        cache_change.data_value.as_ref(),
-                        self.type_support.as_ref(),
-                    )?
-                }
-                ChangeKind::NotAliveDisposed
-                | ChangeKind::NotAliveUnregistered
-                | ChangeKind::NotAliveDisposedUnregistered => match cache_change.instance_handle {
-                    Some(i) => InstanceHandle::new(i),
-                    None => get_instance_handle_from_serialized_key(
-                        cache_change.data_value.as_ref(),
-                        self.type_support.as_ref(),
-                    )?,
-                },
-            }
-        };
+        let instance_handle = self.get_cache_change_instance_handle(&cache_change)?;
 
         // Update the state of the instance before creating since this has direct impact on
         // the information that is store on the sample
@@ -408,10 +779,14 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                     .iter_mut()
                     .find(|x| x.handle() == instance_handle)
                 {
-                    Some(x) => x.update_state(cache_change.kind),
+                    Some(x) => {
+                        x.update_state(cache_change.kind);
+                        x.set_key_holder(cache_change.kind, &cache_change.data_value);
+                    }
                     None => {
                         let mut s = InstanceState::new(instance_handle);
                         s.update_state(cache_change.kind);
+                        s.set_key_holder(cache_change.kind, &cache_change.data_value);
                         self.instances.push(s);
                     }
                 }
@@ -442,10 +817,12 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             .expect("Sample with handle must exist");
         Ok(ReaderSample {
             kind: cache_change.kind,
-            writer_guid: cache_change.writer_guid.into(),
+            writer_guid: cache_change.original_writer_guid().into(),
             instance_handle,
             source_timestamp: cache_change.source_timestamp.map(Into::into),
             data_value: cache_change.data_value.clone(),
+            read_ahead_value: None,
+            valid_data: true,
             sample_state: SampleStateKind::NotRead,
             disposed_generation_count: instance.most_recent_disposed_generation_count,
             no_writers_generation_count: instance.most_recent_no_writers_generation_count,
@@ -458,7 +835,41 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         cache_change: CacheChange,
         reception_timestamp: Time,
     ) -> DdsResult<AddChangeResult> {
-        let sample = self.convert_cache_change_to_sample(cache_change, reception_timestamp)?;
+        let sequence_number = cache_change.sequence_number;
+        // The samples are identified by their original writer while the publication delivering them gives the
+        // ownership strength and the sequence numbers received
+        let sample_publication: [u8; 16] = cache_change.writer_guid.into();
+        // With BY_SOURCE_TIMESTAMP destination order the instance keeps the most recent value written
+        // by any of the writers so changes older than the last one accepted from another writer are
+        // dropped. This is checked before the change is converted since the conversion already updates
+        // the instance state.
+        if self.qos.destination_order.kind == DestinationOrderQosPolicyKind::BySourceTimestamp {
+            if let Some(source_timestamp) = cache_change.source_timestamp {
+                let instance_handle = self.get_cache_change_instance_handle(&cache_change)?;
+                let writer_guid: [u8; 16] = cache_change.original_writer_guid().into();
+                let most_recent_source_timestamp_other_writers = self
+                    .instances
+                    .iter()
+                    .find(|x| x.handle() == instance_handle)
+                    .and_then(|x| {
+                        x.most_recent_source_timestamp_per_writer
+                            .iter()
+                            .filter(|(w, _)| w != &writer_guid)
+                            .map(|(_, t)| *t)
+                            .max()
+                    });
+                if let Some(t) = most_recent_source_timestamp_other_writers {
+                    if Time::from(source_timestamp) < t {
+                        return Ok(AddChangeResult::NotAdded);
+                    }
+                }
+            }
+        }
+        let valid_data = self.deserialization_error_policy
+            != DeserializationErrorPolicy::DeliverAsInvalidData
+            || !self.is_deserialization_failure(&cache_change);
+        let mut sample = self.convert_cache_change_to_sample(cache_change, reception_timestamp)?;
+        sample.valid_data = valid_data;
         let change_instance_handle = sample.instance_handle;
         // data_reader exclusive access if the writer is not the allowed to write the sample do an early return
         if self.qos.ownership.kind == OwnershipQosPolicyKind::Exclusive {
@@ -468,18 +879,17 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                 .iter()
                 .find(|x| x.instance_handle == sample.instance_handle)
             {
-                let instance_writer = InstanceHandle::new(sample.writer_guid);
                 let Some(sample_owner) = self
                     .matched_publication_list
                     .iter()
-                    .find(|x| x.key().value == instance_owner.owner_handle.as_ref())
+                    .find(|x| x.key().value == instance_owner.owner_publication)
                 else {
                     return Ok(AddChangeResult::NotAdded);
                 };
                 let Some(sample_writer) = self
                     .matched_publication_list
                     .iter()
-                    .find(|x| &x.key().value == instance_writer.as_ref())
+                    .find(|x| x.key().value == sample_publication)
                 else {
                     return Ok(AddChangeResult::NotAdded);
                 };
@@ -498,10 +908,12 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             {
                 Some(x) => {
                     x.owner_handle = sample.writer_guid;
+                    x.owner_publication = sample_publication;
                 }
                 None => self.instance_ownership.push(InstanceOwnership {
                     instance_handle: sample.instance_handle,
                     owner_handle: sample.writer_guid,
+                    owner_publication: sample_publication,
                     last_received_time: reception_timestamp,
                 }),
             }
@@ -548,14 +960,26 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             return Ok(AddChangeResult::NotAdded);
         }
 
+        let num_alive_samples_of_instance = self
+            .sample_list
+            .iter()
+            .filter(|cc| {
+                cc.instance_handle == sample.instance_handle && cc.kind == ChangeKind::Alive
+            })
+            .count() as u32;
+        // A sample replacing the oldest one of its instance does not take up more resources
+        let replaces_oldest_sample = matches!(self.qos.history.kind,
+            HistoryQosPolicyKind::KeepLast(depth) if depth == num_alive_samples_of_instance);
+
         let is_max_samples_limit_reached = {
             let total_samples = self
                 .sample_list
                 .iter()
                 .filter(|cc| cc.kind == ChangeKind::Alive)
-                .count();
+                .count()
+                + self.loaned_data_outside_history().len();
 
-            total_samples == self.qos.resource_limits.max_samples
+            total_samples >= self.qos.resource_limits.max_samples
         };
         let is_max_instances_limit_reached = {
             let mut instance_handle_list = Vec::new();
@@ -580,7 +1004,16 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
 
             total_samples_of_instance == self.qos.resource_limits.max_samples_per_instance
         };
-        if is_max_samples_limit_reached {
+        if replaces_oldest_sample {
+            let index_sample_to_remove = self
+                .sample_list
+                .iter()
+                .position(|cc| {
+                    cc.instance_handle == sample.instance_handle && cc.kind == ChangeKind::Alive
+                })
+                .expect("Samples must exist");
+            self.sample_list.remove(index_sample_to_remove);
+        } else if is_max_samples_limit_reached {
             return Ok(AddChangeResult::Rejected(
                 sample.instance_handle,
                 SampleRejectedStatusKind::RejectedBySamplesLimit,
@@ -596,26 +1029,6 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                 SampleRejectedStatusKind::RejectedBySamplesPerInstanceLimit,
             ));
         }
-        let num_alive_samples_of_instance = self
-            .sample_list
-            .iter()
-            .filter(|cc| {
-                cc.instance_handle == sample.instance_handle && cc.kind == ChangeKind::Alive
-            })
-            .count() as u32;
-
-        if let HistoryQosPolicyKind::KeepLast(depth) = self.qos.history.kind {
-            if depth == num_alive_samples_of_instance {
-                let index_sample_to_remove = self
-                    .sample_list
-                    .iter()
-                    .position(|cc| {
-                        cc.instance_handle == sample.instance_handle && cc.kind == ChangeKind::Alive
-                    })
-                    .expect("Samples must exist");
-                self.sample_list.remove(index_sample_to_remove);
-            }
-        }
 
         match sample.kind {
             ChangeKind::Alive | ChangeKind::AliveFiltered => {
@@ -624,10 +1037,14 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                     .iter_mut()
                     .find(|x| x.handle() == sample.instance_handle)
                 {
-                    Some(x) => x.update_state(sample.kind),
+                    Some(x) => {
+                        x.update_state(sample.kind);
+                        x.set_key_holder(sample.kind, &sample.data_value);
+                    }
                     None => {
                         let mut s = InstanceState::new(sample.instance_handle);
                         s.update_state(sample.kind);
+                        s.set_key_holder(sample.kind, &sample.data_value);
                         self.instances.push(s);
                     }
                 }
@@ -652,6 +1069,48 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             }
         }?;
 
+        // Only the samples which are effectively stored are deserialized ahead of being read
+        if let (Some(_), ChangeKind::Alive | ChangeKind::AliveFiltered, true) =
+            (&self.read_ahead_sender, sample.kind, sample.valid_data)
+        {
+            self.read_ahead_requests.push(sample.data_value.clone());
+        }
+
+        if let (Some(instance), Some(source_timestamp)) = (
+            self.instances
+                .iter_mut()
+                .find(|x| x.handle() == sample.instance_handle),
+            sample.source_timestamp,
+        ) {
+            match instance
+                .most_recent_source_timestamp_per_writer
+                .iter_mut()
+                .find(|(w, _)| w == &sample.writer_guid)
+            {
+                Some((_, t)) => {
+                    if *t < source_timestamp {
+                        *t = source_timestamp;
+                    }
+                }
+                None => instance
+                    .most_recent_source_timestamp_per_writer
+                    .push((sample.writer_guid, source_timestamp)),
+            }
+        }
+
+        if let Some(instance) = self
+            .instances
+            .iter_mut()
+            .find(|x| x.handle() == sample.instance_handle)
+        {
+            instance.record_reception(sample.reception_timestamp);
+            if core::mem::take(&mut instance.stale) {
+                if let Some(observer) = &self.instance_freshness_observer {
+                    observer.on_instance_refreshed(instance.handle());
+                }
+            }
+        }
+
         let sample_writer_guid = sample.writer_guid;
         tracing::debug!(cache_change = ?sample, "Adding change to data reader history cache");
         self.sample_list.push(sample);
@@ -689,8 +1148,28 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                 instance_handle: change_instance_handle,
                 last_received_time: reception_timestamp,
                 owner_handle: sample_writer_guid,
+                owner_publication: sample_publication,
             }),
         }
+
+        let publication_handle = InstanceHandle::new(sample_publication);
+        match self
+            .publication_high_watermarks
+            .iter_mut()
+            .find(|x| x.publication_handle == publication_handle)
+        {
+            Some(x) => {
+                if x.sequence_number < sequence_number {
+                    x.sequence_number = sequence_number;
+                }
+            }
+            None => self
+                .publication_high_watermarks
+                .push(PublicationHighWatermark {
+                    publication_handle,
+                    sequence_number,
+                }),
+        }
         Ok(AddChangeResult::Added(change_instance_handle))
     }
 
@@ -700,6 +1179,13 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
 
     pub fn enable(&mut self) {
         self.enabled = true;
+        if let TransportReaderKind::Stateful(r) = &mut self.transport_reader {
+            let reader_protocol = &self.qos.reader_protocol;
+            r.set_heartbeat_response_timing(
+                reader_protocol.heartbeat_response_delay.into(),
+                reader_protocol.heartbeat_suppression_duration.into(),
+            );
+        }
     }
 
     pub fn enabled(&self) -> bool {
@@ -718,6 +1204,10 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         &self.topic_name
     }
 
+    pub fn type_support(&self) -> &Arc<dyn DynamicType + Send + Sync> {
+        &self.type_support
+    }
+
     pub fn set_qos(&mut self, qos: DataReaderQos) -> DdsResult<()> {
         qos.is_consistent()?;
         if self.enabled {
@@ -750,6 +1240,39 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         self.subscription_matched_status.total_count_change += 1;
     }
 
+    pub fn set_matched_publication_locators(
+        &mut self,
+        publication_handle: InstanceHandle,
+        locator_list: Vec<Locator>,
+    ) {
+        match self
+            .matched_publication_locators
+            .iter_mut()
+            .find(|x| x.publication_handle == publication_handle)
+        {
+            Some(x) => x.locator_list = locator_list,
+            None => self
+                .matched_publication_locators
+                .push(MatchedPublicationLocators {
+                    publication_handle,
+                    locator_list,
+                }),
+        }
+    }
+
+    pub fn update_matched_publication(
+        &mut self,
+        publication_builtin_topic_data: PublicationBuiltinTopicData,
+    ) {
+        if let Some(x) = self
+            .matched_publication_list
+            .iter_mut()
+            .find(|x| x.key() == publication_builtin_topic_data.key())
+        {
+            *x = publication_builtin_topic_data;
+        }
+    }
+
     pub fn increment_requested_deadline_missed_status(&mut self, instance_handle: InstanceHandle) {
         self.requested_deadline_missed_status.total_count += 1;
         self.requested_deadline_missed_status.total_count_change += 1;
@@ -762,6 +1285,101 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         status
     }
 
+    /// Handles of the matched publications of the participant with the given GUID prefix whose
+    /// liveliness is of the given kind.
+    pub fn get_matched_publications_with_liveliness(
+        &self,
+        guid_prefix: [u8; 12],
+        kind: LivelinessQosPolicyKind,
+    ) -> Vec<InstanceHandle> {
+        self.matched_publication_list
+            .iter()
+            .filter(|x| x.key().value[..12] == guid_prefix && x.liveliness().kind == kind)
+            .map(|x| InstanceHandle::new(x.key().value))
+            .collect()
+    }
+
+    /// Records an assertion of the liveliness of a matched writer. Returns true if the writer was
+    /// not considered alive before, in which case the liveliness changed status is updated.
+    pub fn assert_writer_liveliness(
+        &mut self,
+        publication_handle: InstanceHandle,
+        now: Time,
+    ) -> bool {
+        if !self
+            .matched_publication_list
+            .iter()
+            .any(|x| &x.key().value == publication_handle.as_ref())
+        {
+            return false;
+        }
+        match self
+            .writer_liveliness
+            .iter_mut()
+            .find(|x| x.publication_handle == publication_handle)
+        {
+            Some(x) => {
+                x.last_assertion = now;
+                if x.alive {
+                    return false;
+                }
+                x.alive = true;
+                self.liveliness_changed_status.not_alive_count -= 1;
+                self.liveliness_changed_status.not_alive_count_change -= 1;
+            }
+            None => self.writer_liveliness.push(MatchedWriterLiveliness {
+                publication_handle,
+                last_assertion: now,
+                alive: true,
+            }),
+        }
+        self.liveliness_changed_status.alive_count += 1;
+        self.liveliness_changed_status.alive_count_change += 1;
+        self.liveliness_changed_status.last_publication_handle = publication_handle;
+        true
+    }
+
+    /// Checks whether a matched writer asserted its liveliness within the lease duration it offers.
+    pub fn check_writer_liveliness(
+        &mut self,
+        publication_handle: InstanceHandle,
+        now: Time,
+    ) -> WriterLivelinessCheck {
+        let Some(DurationKind::Finite(lease_duration)) = self
+            .matched_publication_list
+            .iter()
+            .find(|x| &x.key().value == publication_handle.as_ref())
+            .map(|x| x.liveliness().lease_duration)
+        else {
+            return WriterLivelinessCheck::Unchanged;
+        };
+        let Some(writer_liveliness) = self
+            .writer_liveliness
+            .iter_mut()
+            .find(|x| x.publication_handle == publication_handle && x.alive)
+        else {
+            return WriterLivelinessCheck::Unchanged;
+        };
+        let elapsed = now - writer_liveliness.last_assertion;
+        if elapsed < lease_duration {
+            return WriterLivelinessCheck::Alive(lease_duration - elapsed);
+        }
+        writer_liveliness.alive = false;
+        self.liveliness_changed_status.alive_count -= 1;
+        self.liveliness_changed_status.alive_count_change -= 1;
+        self.liveliness_changed_status.not_alive_count += 1;
+        self.liveliness_changed_status.not_alive_count_change += 1;
+        self.liveliness_changed_status.last_publication_handle = publication_handle;
+        WriterLivelinessCheck::Lost
+    }
+
+    pub fn get_liveliness_changed_status(&mut self) -> LivelinessChangedStatus {
+        let status = self.liveliness_changed_status.clone();
+        self.liveliness_changed_status.alive_count_change = 0;
+        self.liveliness_changed_status.not_alive_count_change = 0;
+        status
+    }
+
     pub fn remove_instance_ownership(&mut self, instance_handle: &InstanceHandle) {
         if let Some(i) = self
             .instance_ownership
@@ -830,6 +1448,52 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         status
     }
 
+    pub fn increment_sample_lost_status(&mut self, lost_samples: i32) {
+        self.sample_lost_status.total_count += lost_samples;
+        self.sample_lost_status.total_count_change += lost_samples;
+    }
+
+    pub fn get_sample_lost_status(&mut self) -> SampleLostStatus {
+        let status = self.sample_lost_status.clone();
+        self.sample_lost_status.total_count_change = 0;
+
+        status
+    }
+
+    pub fn get_writer_restarted_status(&mut self) -> WriterRestartedStatus {
+        let status = self.writer_restarted_status.clone();
+        self.writer_restarted_status.total_count_change = 0;
+
+        status
+    }
+
+    /// Checks whether a newly discovered durable publication replaces a previously matched durable
+    /// publication of the same partition which was lost. In that case the writer restarted status is updated.
+    pub fn detect_writer_restart(
+        &mut self,
+        publication_builtin_topic_data: &PublicationBuiltinTopicData,
+    ) -> bool {
+        if publication_builtin_topic_data.durability().kind == DurabilityQosPolicyKind::Volatile {
+            return false;
+        }
+        let Some(i) = self
+            .lost_durable_publication_list
+            .iter()
+            .position(|x| x.partition() == publication_builtin_topic_data.partition())
+        else {
+            return false;
+        };
+        let previous_publication = self.lost_durable_publication_list.remove(i);
+        self.writer_restarted_status.total_count += 1;
+        self.writer_restarted_status.total_count_change += 1;
+        self.writer_restarted_status
+            .last_previous_publication_handle =
+            InstanceHandle::new(previous_publication.key().value);
+        self.writer_restarted_status.last_publication_handle =
+            InstanceHandle::new(publication_builtin_topic_data.key().value);
+        true
+    }
+
     pub fn get_subscription_matched_status(&mut self) -> SubscriptionMatchedStatus {
         let status = self.subscription_matched_status.clone();
 
@@ -856,8 +1520,65 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             .find(|x| &x.key().value == handle.as_ref())
     }
 
-    pub fn get_matched_publications(&self) -> Vec<InstanceHandle> {
-        self.matched_publication_list
+    pub fn get_matched_publication_high_watermark(
+        &self,
+        publication_handle: &InstanceHandle,
+    ) -> Option<SequenceNumber> {
+        self.get_matched_publication_data(publication_handle)?;
+        Some(
+            self.publication_high_watermarks
+                .iter()
+                .find(|x| &x.publication_handle == publication_handle)
+                .map_or(0, |x| x.sequence_number),
+        )
+    }
+
+    pub fn get_matched_publication_locators(
+        &self,
+        publication_handle: &InstanceHandle,
+    ) -> Option<&[Locator]> {
+        self.get_matched_publication_data(publication_handle)?;
+        Some(
+            self.matched_publication_locators
+                .iter()
+                .find(|x| &x.publication_handle == publication_handle)
+                .map_or(&[], |x| x.locator_list.as_slice()),
+        )
+    }
+
+    pub fn lookup_instance(&self, serialized_data: &[u8]) -> DdsResult<Option<InstanceHandle>> {
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(serialized_data, self.type_support.as_ref())?;
+        Ok(self
+            .instances
+            .iter()
+            .any(|x| x.handle() == instance_handle)
+            .then_some(instance_handle))
+    }
+
+    pub fn get_key_value(
+        &self,
+        serialized_key_holder: &[u8],
+        instance_handle: InstanceHandle,
+    ) -> DdsResult<Vec<u8>> {
+        let Some(key_holder) = self
+            .instances
+            .iter()
+            .find(|x| x.handle() == instance_handle)
+            .and_then(|x| x.key_holder.as_ref())
+        else {
+            return Err(DdsError::BadParameter);
+        };
+
+        Ok(replace_key_members(
+            serialized_key_holder,
+            key_holder,
+            self.type_support.as_ref(),
+        )?)
+    }
+
+    pub fn get_matched_publications(&self) -> Vec<InstanceHandle> {
+        self.matched_publication_list
             .iter()
             .map(|x| InstanceHandle::new(x.key().value))
             .collect()
@@ -867,8 +1588,13 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         &self.listener_sender
     }
 
+    /// Statuses handled by the listener of the reader. An entity without listener handles no
+    /// status so that its statuses propagate to the listener of its parent.
     pub fn listener_mask(&self) -> &[StatusKind] {
-        &self.listener_mask
+        match self.listener_sender {
+            Some(_) => &self.listener_mask,
+            None => &[],
+        }
     }
 
     pub fn set_listener(
@@ -880,6 +1606,594 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         self.listener_mask = listener_mask;
     }
 
+    pub fn set_read_ahead_sender(
+        &mut self,
+        read_ahead_sender: Option<R::ChannelSender<Arc<[u8]>>>,
+    ) {
+        if read_ahead_sender.is_none() {
+            for sample in self.sample_list.iter_mut() {
+                sample.read_ahead_value = None;
+            }
+            self.read_ahead_requests.clear();
+        }
+        self.read_ahead_sender = read_ahead_sender;
+    }
+
+    /// Sends the serialized data of the samples added since the last call to the task deserializing them ahead of
+    /// being read.
+    pub async fn send_read_ahead_requests(&mut self) {
+        if let Some(read_ahead_sender) = &self.read_ahead_sender {
+            for serialized_data in self.read_ahead_requests.drain(..) {
+                read_ahead_sender.send(serialized_data).await.ok();
+            }
+        }
+    }
+
+    pub fn set_read_ahead_value(
+        &mut self,
+        serialized_data: &Arc<[u8]>,
+        read_ahead_value: ReadAheadValue,
+    ) {
+        // Values deserialized before the read-ahead deserialization was disabled are discarded
+        if self.read_ahead_sender.is_none() {
+            return;
+        }
+        if let Some(sample) = self
+            .sample_list
+            .iter_mut()
+            .find(|s| Arc::ptr_eq(&s.data_value, serialized_data))
+        {
+            sample.read_ahead_value = Some(read_ahead_value);
+        }
+    }
+
+    pub fn set_deserialization_error_policy(
+        &mut self,
+        deserialization_error_policy: DeserializationErrorPolicy,
+        deserialization_validator: Option<DeserializationValidator>,
+    ) {
+        self.deserialization_error_policy = deserialization_error_policy;
+        self.deserialization_validator = deserialization_validator;
+    }
+
+    pub fn deserialization_error_policy(&self) -> DeserializationErrorPolicy {
+        self.deserialization_error_policy
+    }
+
+    pub fn is_deserialization_failure(&self, cache_change: &CacheChange) -> bool {
+        matches!(
+            cache_change.kind,
+            ChangeKind::Alive | ChangeKind::AliveFiltered
+        ) && self
+            .deserialization_validator
+            .as_ref()
+            .is_some_and(|validator| !validator(cache_change.data_value.as_ref()))
+    }
+
+    pub fn incomplete_coherent_set_timeout(&self) -> DurationKind {
+        self.incomplete_coherent_set_timeout
+    }
+
+    pub fn incomplete_coherent_set_policy(&self) -> IncompleteCoherentSetPolicy {
+        self.incomplete_coherent_set_policy
+    }
+
+    pub fn set_incomplete_coherent_set_timeout(
+        &mut self,
+        timeout: DurationKind,
+        policy: IncompleteCoherentSetPolicy,
+    ) {
+        self.incomplete_coherent_set_timeout = timeout;
+        self.incomplete_coherent_set_policy = policy;
+    }
+
+    pub fn instance_freshness_period(&self) -> DurationKind {
+        self.instance_freshness_period
+    }
+
+    pub fn set_instance_freshness_watchdog(
+        &mut self,
+        period: DurationKind,
+        observer: Option<Arc<dyn InstanceFreshnessObserver>>,
+    ) {
+        self.instance_freshness_period = period;
+        self.instance_freshness_observer = observer;
+        for instance in self.instances.iter_mut() {
+            instance.stale = false;
+        }
+    }
+
+    /// Returns the handle and the time of the last received sample of the alive instances
+    /// whose freshness is watched.
+    pub fn get_instance_last_reception_times(&self) -> Vec<(InstanceHandle, Time)> {
+        self.instances
+            .iter()
+            .filter(|x| x.instance_state == InstanceStateKind::Alive)
+            .filter_map(|x| Some((x.handle(), x.last_reception_timestamp?)))
+            .collect()
+    }
+
+    pub fn check_instance_freshness(&mut self, instance_handle: InstanceHandle, now: Time) {
+        let DurationKind::Finite(period) = self.instance_freshness_period else {
+            return;
+        };
+        let Some(instance) = self
+            .instances
+            .iter_mut()
+            .find(|x| x.handle() == instance_handle)
+        else {
+            return;
+        };
+        if instance.stale || instance.instance_state != InstanceStateKind::Alive {
+            return;
+        }
+        match instance.last_reception_timestamp {
+            Some(t) if now - t >= period => (),
+            _ => return,
+        }
+
+        instance.stale = true;
+        self.instance_freshness_status.total_count += 1;
+        self.instance_freshness_status.total_count_change += 1;
+        self.instance_freshness_status.last_instance_handle = instance_handle;
+        if let Some(observer) = &self.instance_freshness_observer {
+            observer.on_instance_stale(instance_handle);
+        }
+    }
+
+    pub fn get_instance_freshness_status(&mut self) -> InstanceFreshnessStatus {
+        let mut status = self.instance_freshness_status.clone();
+        status.stale_instance_handles = self
+            .instances
+            .iter()
+            .filter(|x| x.stale)
+            .map(|x| x.handle())
+            .collect();
+        self.instance_freshness_status.total_count_change = 0;
+        status
+    }
+
+    /// Waits for a change to be added to the reader after the given number of changes was
+    /// observed. The waiter is replied immediately if a change was added in the meantime.
+    pub fn add_sample_waiter(
+        &mut self,
+        added_change_count: u64,
+        waiter: R::OneshotSender<DdsResult<u64>>,
+    ) {
+        if added_change_count != self.added_change_count {
+            waiter.send(Ok(self.added_change_count));
+        } else {
+            self.sample_waiters.push(waiter);
+        }
+    }
+
+    pub fn notify_sample_waiters(&mut self) {
+        self.added_change_count += 1;
+        for waiter in self.sample_waiters.drain(..) {
+            waiter.send(Ok(self.added_change_count));
+        }
+    }
+
+    pub fn gap_free_delivery(&self) -> bool {
+        self.gap_free_delivery
+    }
+
+    pub fn set_gap_free_delivery(&mut self, enabled: bool) -> DdsResult<()> {
+        if enabled && self.qos.reliability.kind != ReliabilityQosPolicyKind::Reliable {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Gap-free delivery requires a reliable DataReader",
+            )));
+        }
+        if !enabled
+            && self
+                .head_of_line_queues
+                .iter()
+                .any(|x| !x.held_changes.is_empty())
+        {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Samples are held waiting to be delivered",
+            )));
+        }
+        if !enabled {
+            self.head_of_line_queues.clear();
+        }
+        self.gap_free_delivery = enabled;
+        Ok(())
+    }
+
+    /// Holds the change behind the blocked change of the same writer, if any. The change is returned
+    /// if it can be delivered.
+    pub fn hold_blocked_writer_change(
+        &mut self,
+        cache_change: CacheChange,
+        reception_timestamp: Time,
+    ) -> Option<CacheChange> {
+        match self
+            .head_of_line_queues
+            .iter_mut()
+            .find(|x| x.writer_guid == cache_change.writer_guid)
+        {
+            Some(q) if !q.held_changes.is_empty() => {
+                q.held_changes
+                    .push_back((cache_change, reception_timestamp));
+                None
+            }
+            _ => Some(cache_change),
+        }
+    }
+
+    /// Blocks the delivery of the changes of the writer until the rejected change is delivered. Returns
+    /// false if the change was already blocking the writer.
+    pub fn block_writer_change(
+        &mut self,
+        cache_change: CacheChange,
+        reception_timestamp: Time,
+    ) -> bool {
+        let sequence_number = cache_change.sequence_number;
+        let q = self.head_of_line_queue_mut(cache_change.writer_guid);
+        let newly_blocked = q.blocked_sequence_number != Some(sequence_number);
+        q.blocked_sequence_number = Some(sequence_number);
+        q.held_changes
+            .push_front((cache_change, reception_timestamp));
+        newly_blocked
+    }
+
+    pub fn set_writer_change_delivered(
+        &mut self,
+        writer_guid: Guid,
+        sequence_number: SequenceNumber,
+    ) {
+        let q = self.head_of_line_queue_mut(writer_guid);
+        q.last_delivered_sequence_number = sequence_number;
+        q.blocked_sequence_number = None;
+    }
+
+    /// Removes the changes held by all the writers so that their delivery is attempted again in order.
+    pub fn take_held_changes(&mut self) -> Vec<(CacheChange, Time)> {
+        self.head_of_line_queues
+            .iter_mut()
+            .flat_map(|x| x.held_changes.drain(..))
+            .collect()
+    }
+
+    pub fn get_head_of_line_status(&self) -> Vec<HeadOfLineStatus> {
+        self.matched_publication_list
+            .iter()
+            .map(|p| {
+                let publication_handle = InstanceHandle::new(p.key().value);
+                match self
+                    .head_of_line_queues
+                    .iter()
+                    .find(|x| InstanceHandle::new(x.writer_guid.into()) == publication_handle)
+                {
+                    Some(q) => HeadOfLineStatus {
+                        publication_handle,
+                        last_delivered_sequence_number: q.last_delivered_sequence_number,
+                        blocked_sequence_number: q.blocked_sequence_number,
+                        held_sample_count: q.held_changes.len(),
+                    },
+                    None => HeadOfLineStatus {
+                        publication_handle,
+                        last_delivered_sequence_number: 0,
+                        blocked_sequence_number: None,
+                        held_sample_count: 0,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_instance_statistics(&self, now: Time) -> InstanceStatistics {
+        let count_instances = |instance_state| {
+            self.instances
+                .iter()
+                .filter(|x| x.instance_state == instance_state)
+                .count()
+        };
+        let mut instances: Vec<InstanceActivity> = self
+            .instances
+            .iter()
+            .map(|x| {
+                let sample_rate = match x.first_reception_timestamp {
+                    Some(first_reception_timestamp) => {
+                        let elapsed = now - first_reception_timestamp;
+                        let elapsed_seconds =
+                            elapsed.sec() as f64 + elapsed.nanosec() as f64 / 1_000_000_000.0;
+                        if elapsed_seconds > 0.0 {
+                            x.received_sample_count as f64 / elapsed_seconds
+                        } else {
+                            0.0
+                        }
+                    }
+                    None => 0.0,
+                };
+                InstanceActivity {
+                    instance_handle: x.handle(),
+                    instance_state: x.instance_state,
+                    received_sample_count: x.received_sample_count,
+                    sample_rate,
+                }
+            })
+            .collect();
+        instances.sort_by_key(|x| core::cmp::Reverse(x.received_sample_count));
+
+        InstanceStatistics {
+            alive_instance_count: count_instances(InstanceStateKind::Alive),
+            not_alive_disposed_instance_count: count_instances(InstanceStateKind::NotAliveDisposed),
+            not_alive_no_writers_instance_count: count_instances(
+                InstanceStateKind::NotAliveNoWriters,
+            ),
+            instances,
+        }
+    }
+
+    fn head_of_line_queue_mut(&mut self, writer_guid: Guid) -> &mut HeadOfLineQueue {
+        if let Some(i) = self
+            .head_of_line_queues
+            .iter()
+            .position(|x| x.writer_guid == writer_guid)
+        {
+            &mut self.head_of_line_queues[i]
+        } else {
+            self.head_of_line_queues.push(HeadOfLineQueue {
+                writer_guid,
+                last_delivered_sequence_number: 0,
+                blocked_sequence_number: None,
+                held_changes: VecDeque::new(),
+            });
+            self.head_of_line_queues
+                .last_mut()
+                .expect("Queue was just added")
+        }
+    }
+
+    /// Handle of the MultiTopic reader which this reader feeds with the samples of one of its related topics.
+    pub fn multitopic_reader(&self) -> Option<InstanceHandle> {
+        self.multitopic_reader
+    }
+
+    pub fn set_multitopic_reader(&mut self, multitopic_reader: InstanceHandle) {
+        self.multitopic_reader = Some(multitopic_reader);
+    }
+
+    /// Handles of the readers of the related topics of a MultiTopic reader.
+    pub fn multitopic_constituent_list(&self) -> &[InstanceHandle] {
+        &self.multitopic_constituent_list
+    }
+
+    pub fn add_multitopic_constituent(&mut self, constituent_reader: InstanceHandle) {
+        self.multitopic_constituent_list.push(constituent_reader);
+    }
+
+    pub fn next_multitopic_sequence_number(&mut self) -> i64 {
+        self.multitopic_sequence_number += 1;
+        self.multitopic_sequence_number
+    }
+
+    pub fn content_filter(&self) -> Option<&ContentFilter> {
+        self.content_filter.as_ref()
+    }
+
+    /// Sets the filter which the alive samples must pass to be added to the reader. An empty
+    /// expression removes the filter.
+    pub fn set_content_filter(
+        &mut self,
+        filter_expression: String,
+        filter_parameters: Vec<String>,
+    ) -> DdsResult<()> {
+        if filter_expression.is_empty() {
+            self.content_filter = None;
+            return Ok(());
+        }
+        let query_expression = QueryExpression::parse(&filter_expression)?;
+        self.validate_query(&query_expression, &filter_parameters)?;
+        self.content_filter = Some(ContentFilter {
+            filter_expression,
+            filter_parameters,
+            query_expression,
+        });
+        Ok(())
+    }
+
+    /// Creates a read condition, which is a query condition if a query expression and its parameters
+    /// are given, and returns its identifier.
+    pub fn create_read_condition(
+        &mut self,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        query: Option<(String, Vec<String>)>,
+    ) -> DdsResult<u32> {
+        let query = match query {
+            Some((query_expression, query_parameters)) => {
+                let query_expression = QueryExpression::parse(&query_expression)?;
+                self.validate_query(&query_expression, &query_parameters)?;
+                Some((query_expression, query_parameters))
+            }
+            None => None,
+        };
+        self.read_condition_counter += 1;
+        self.read_conditions.push(ReadConditionState {
+            condition_id: self.read_condition_counter,
+            sample_states,
+            view_states,
+            instance_states,
+            query,
+        });
+        Ok(self.read_condition_counter)
+    }
+
+    pub fn delete_read_condition(&mut self, condition_id: u32) -> DdsResult<()> {
+        let Some(index) = self
+            .read_conditions
+            .iter()
+            .position(|x| x.condition_id == condition_id)
+        else {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Condition does not belong to this data reader",
+            )));
+        };
+        self.read_conditions.remove(index);
+        Ok(())
+    }
+
+    /// A read condition is triggered when the reader has at least one sample with a sample state,
+    /// view state and instance state in the masks of the condition and, for a query condition,
+    /// whose data fulfills the query expression.
+    pub fn get_read_condition_trigger_value(&self, condition_id: u32) -> DdsResult<bool> {
+        let read_condition = self.read_condition(condition_id)?;
+        Ok(self.sample_list.iter().any(|sample| {
+            let Some(instance) = self
+                .instances
+                .iter()
+                .find(|x| x.handle() == sample.instance_handle)
+            else {
+                return false;
+            };
+            if !(read_condition.sample_states.contains(&sample.sample_state)
+                && read_condition.view_states.contains(&instance.view_state)
+                && read_condition
+                    .instance_states
+                    .contains(&instance.instance_state))
+            {
+                return false;
+            }
+            match &read_condition.query {
+                Some((query_expression, query_parameters)) => {
+                    sample.kind == ChangeKind::Alive
+                        && deserialize_sample_members(
+                            sample.data_value.as_ref(),
+                            self.type_support.as_ref(),
+                        )
+                        .is_ok_and(|members| query_expression.evaluate(&members, query_parameters))
+                }
+                None => true,
+            }
+        }))
+    }
+
+    pub fn get_query_parameters(&self, condition_id: u32) -> DdsResult<Vec<String>> {
+        match &self.read_condition(condition_id)?.query {
+            Some((_, query_parameters)) => Ok(query_parameters.clone()),
+            None => Err(DdsError::IllegalOperation),
+        }
+    }
+
+    pub fn set_query_parameters(
+        &mut self,
+        condition_id: u32,
+        query_parameters: Vec<String>,
+    ) -> DdsResult<()> {
+        let Some((query_expression, _)) = &self.read_condition(condition_id)?.query else {
+            return Err(DdsError::IllegalOperation);
+        };
+        self.validate_query(query_expression, &query_parameters)?;
+        if let Some(ReadConditionState {
+            query: Some((_, current_query_parameters)),
+            ..
+        }) = self
+            .read_conditions
+            .iter_mut()
+            .find(|x| x.condition_id == condition_id)
+        {
+            *current_query_parameters = query_parameters;
+        }
+        Ok(())
+    }
+
+    fn read_condition(&self, condition_id: u32) -> DdsResult<&ReadConditionState> {
+        self.read_conditions
+            .iter()
+            .find(|x| x.condition_id == condition_id)
+            .ok_or(DdsError::AlreadyDeleted)
+    }
+
+    fn validate_query(
+        &self,
+        query_expression: &QueryExpression,
+        query_parameters: &[String],
+    ) -> DdsResult<()> {
+        // The members of the samples can only be decoded generically for final types
+        if !is_final_type(self.type_support.as_ref()) {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Query and filter expressions can only be used with types with final extensibility",
+            )));
+        }
+        let mut member_names = Vec::new();
+        let type_support: &dyn DynamicType = self.type_support.as_ref();
+        for member_descriptor in type_support.into_iter() {
+            member_names.push(member_descriptor?.name);
+        }
+        query_expression.validate(&member_names, query_parameters)
+    }
+
+    /// Serialized data of the most recent samples of the instances which are alive.
+    pub fn alive_sample_data_list(&self) -> Vec<Arc<[u8]>> {
+        self.instances
+            .iter()
+            .filter(|x| x.instance_state == InstanceStateKind::Alive)
+            .filter_map(|x| {
+                self.sample_list
+                    .iter()
+                    .rfind(|s| s.instance_handle == x.handle() && s.kind == ChangeKind::Alive)
+            })
+            .map(|s| s.data_value.clone())
+            .collect()
+    }
+
+    pub fn is_coherent_set_pending(&self, writer_guid: Guid, coherent_set: i64) -> bool {
+        self.pending_coherent_sets
+            .iter()
+            .any(|x| x.writer_guid == writer_guid && x.coherent_set == coherent_set)
+    }
+
+    /// Holds back the changes which belong to a coherent set until the set is complete. The set of a
+    /// writer is complete when a change from the same writer which does not belong to it is received, which
+    /// includes the change marking the end of the set. Returns the list of changes which can be added to the reader.
+    pub fn add_coherent_set_change(
+        &mut self,
+        cache_change: CacheChange,
+        reception_timestamp: Time,
+    ) -> Vec<(CacheChange, Time)> {
+        let mut complete_changes = Vec::new();
+        if let Some(i) = self
+            .pending_coherent_sets
+            .iter()
+            .position(|x| x.writer_guid == cache_change.writer_guid)
+        {
+            if Some(self.pending_coherent_sets[i].coherent_set) == cache_change.coherent_set {
+                self.pending_coherent_sets[i]
+                    .changes
+                    .push((cache_change, reception_timestamp));
+                return complete_changes;
+            }
+            complete_changes.append(&mut self.pending_coherent_sets.remove(i).changes);
+        }
+
+        match cache_change.coherent_set {
+            _ if cache_change.is_coherent_set_end() => (),
+            Some(coherent_set) => self.pending_coherent_sets.push(PendingCoherentSet {
+                writer_guid: cache_change.writer_guid,
+                coherent_set,
+                changes: vec![(cache_change, reception_timestamp)],
+            }),
+            None => complete_changes.push((cache_change, reception_timestamp)),
+        }
+        complete_changes
+    }
+
+    pub fn remove_pending_coherent_set(
+        &mut self,
+        writer_guid: Guid,
+        coherent_set: i64,
+    ) -> Option<Vec<(CacheChange, Time)>> {
+        let i = self
+            .pending_coherent_sets
+            .iter()
+            .position(|x| x.writer_guid == writer_guid && x.coherent_set == coherent_set)?;
+        Some(self.pending_coherent_sets.remove(i).changes)
+    }
+
     pub fn get_instance_received_time(&self, instance_handle: &InstanceHandle) -> Option<Time> {
         self.instance_ownership
             .iter()
@@ -888,19 +2202,51 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
     }
 
     pub async fn remove_matched_publication(&mut self, publication_handle: &InstanceHandle) {
-        let Some(i) = self
-            .matched_publication_list
-            .iter()
-            .position(|x| &x.key().value == publication_handle.as_ref())
-        else {
+        let Some(publication) = self.unmatch_publication(publication_handle).await else {
             return;
         };
-        self.matched_publication_list.remove(i);
+        if publication.durability().kind != DurabilityQosPolicyKind::Volatile {
+            self.lost_durable_publication_list
+                .retain(|x| x.partition() != publication.partition());
+            self.lost_durable_publication_list.push(publication);
+        }
+    }
+
+    /// Removes a matched publication without considering it lost, as done when the writer is still
+    /// alive but no longer compatible. The removed publication is returned.
+    pub async fn unmatch_publication(
+        &mut self,
+        publication_handle: &InstanceHandle,
+    ) -> Option<PublicationBuiltinTopicData> {
+        let i = self
+            .matched_publication_list
+            .iter()
+            .position(|x| &x.key().value == publication_handle.as_ref())?;
+        let publication = self.matched_publication_list.remove(i);
+        self.publication_high_watermarks
+            .retain(|x| &x.publication_handle != publication_handle);
+        self.matched_publication_locators
+            .retain(|x| &x.publication_handle != publication_handle);
+        if let Some(i) = self
+            .writer_liveliness
+            .iter()
+            .position(|x| &x.publication_handle == publication_handle)
+        {
+            // A deleted writer is neither alive nor not alive anymore
+            if self.writer_liveliness.remove(i).alive {
+                self.liveliness_changed_status.alive_count -= 1;
+                self.liveliness_changed_status.alive_count_change -= 1;
+            } else {
+                self.liveliness_changed_status.not_alive_count -= 1;
+                self.liveliness_changed_status.not_alive_count_change -= 1;
+            }
+        }
         self.subscription_matched_status.current_count = self.matched_publication_list.len() as i32;
         self.subscription_matched_status.current_count_change -= 1;
         self.status_condition
             .add_state(StatusKind::SubscriptionMatched)
             .await;
+        Some(publication)
     }
 
     pub async fn read(
@@ -981,6 +2327,50 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
         Ok(samples)
     }
 
+    /// Same as [`Self::read`] but the data of the samples is loaned to the application directly from the history
+    /// instead of being copied. The loans are returned when the application drops the returned references.
+    pub async fn read_loaned(
+        &mut self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<LoanedSampleList> {
+        let samples = self
+            .read(
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+            )
+            .await?;
+        Ok(self.loan_samples(samples))
+    }
+
+    /// Same as [`Self::take`] but the data of the samples is loaned to the application. The storage of the taken
+    /// samples keeps counting against the resource limits of the reader until the loans are returned.
+    pub async fn take_loaned(
+        &mut self,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<LoanedSampleList> {
+        let samples = self
+            .take(
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+            )
+            .await?;
+        Ok(self.loan_samples(samples))
+    }
+
     pub async fn take_next_instance(
         &mut self,
         max_samples: i32,
@@ -993,19 +2383,24 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             return Err(DdsError::NotEnabled);
         }
 
-        match self.next_instance(previous_handle) {
-            Some(next_handle) => {
-                self.take(
+        // Instances without samples matching the states are skipped
+        let mut previous_handle = previous_handle;
+        while let Some(next_handle) = self.next_instance(previous_handle) {
+            match self
+                .take(
                     max_samples,
-                    sample_states,
-                    view_states,
-                    instance_states,
+                    sample_states.clone(),
+                    view_states.clone(),
+                    instance_states.clone(),
                     Some(next_handle),
                 )
                 .await
+            {
+                Err(DdsError::NoData) => previous_handle = Some(next_handle),
+                result => return result,
             }
-            None => Err(DdsError::NoData),
         }
+        Err(DdsError::NoData)
     }
 
     pub async fn read_next_instance(
@@ -1020,9 +2415,11 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
             return Err(DdsError::NotEnabled);
         }
 
-        match self.next_instance(previous_handle) {
-            Some(next_handle) => {
-                self.read(
+        // Instances without samples matching the states are skipped
+        let mut previous_handle = previous_handle;
+        while let Some(next_handle) = self.next_instance(previous_handle) {
+            match self
+                .read(
                     max_samples,
                     sample_states,
                     view_states,
@@ -1030,8 +2427,11 @@ impl<R: DdsRuntime> DataReaderEntity<R> {
                     Some(next_handle),
                 )
                 .await
+            {
+                Err(DdsError::NoData) => previous_handle = Some(next_handle),
+                result => return result,
             }
-            None => Err(DdsError::NoData),
         }
+        Err(DdsError::NoData)
     }
 }
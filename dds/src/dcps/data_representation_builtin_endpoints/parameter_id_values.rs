@@ -1,5 +1,16 @@
 use crate::{
-    infrastructure::time::Duration,
+    infrastructure::{
+        qos_policy::{
+            QosPolicyId, DATA_REPRESENTATION_QOS_POLICY_ID, DEADLINE_QOS_POLICY_ID,
+            DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID, GROUPDATA_QOS_POLICY_ID,
+            HISTORY_QOS_POLICY_ID, LATENCYBUDGET_QOS_POLICY_ID, LIFESPAN_QOS_POLICY_ID,
+            LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID, OWNERSHIP_STRENGTH_QOS_POLICY_ID,
+            PARTITION_QOS_POLICY_ID, PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID,
+            RESOURCELIMITS_QOS_POLICY_ID, TIMEBASEDFILTER_QOS_POLICY_ID, TOPICDATA_QOS_POLICY_ID,
+            TRANSPORTPRIORITY_QOS_POLICY_ID, USERDATA_QOS_POLICY_ID,
+        },
+        time::Duration,
+    },
     transport::types::{EntityId, Short, ENTITYID_UNKNOWN},
 };
 
@@ -71,3 +82,74 @@ pub const DEFAULT_DOMAIN_TAG: &str = "";
 pub const DEFAULT_EXPECTS_INLINE_QOS: bool = false;
 pub const DEFAULT_PARTICIPANT_LEASE_DURATION: Duration = Duration::new(100, 0);
 pub const _DEFAULT_GROUP_ENTITYID: EntityId = ENTITYID_UNKNOWN;
+
+// Parameters of the discovery data which are replaced by the default value of
+// their QoS policy when missing
+pub const PARTICIPANT_QOS_PARAMETER_LIST: &[(ParameterId, QosPolicyId)] =
+    &[(PID_USER_DATA, USERDATA_QOS_POLICY_ID)];
+pub const TOPIC_QOS_PARAMETER_LIST: &[(ParameterId, QosPolicyId)] = &[
+    (PID_DURABILITY, DURABILITY_QOS_POLICY_ID),
+    (PID_DEADLINE, DEADLINE_QOS_POLICY_ID),
+    (PID_LATENCY_BUDGET, LATENCYBUDGET_QOS_POLICY_ID),
+    (PID_LIVELINESS, LIVELINESS_QOS_POLICY_ID),
+    (PID_RELIABILITY, RELIABILITY_QOS_POLICY_ID),
+    (PID_TRANSPORT_PRIORITY, TRANSPORTPRIORITY_QOS_POLICY_ID),
+    (PID_LIFESPAN, LIFESPAN_QOS_POLICY_ID),
+    (PID_DESTINATION_ORDER, DESTINATIONORDER_QOS_POLICY_ID),
+    (PID_HISTORY, HISTORY_QOS_POLICY_ID),
+    (PID_RESOURCE_LIMITS, RESOURCELIMITS_QOS_POLICY_ID),
+    (PID_OWNERSHIP, OWNERSHIP_QOS_POLICY_ID),
+    (PID_TOPIC_DATA, TOPICDATA_QOS_POLICY_ID),
+    (PID_DATA_REPRESENTATION, DATA_REPRESENTATION_QOS_POLICY_ID),
+];
+pub const PUBLICATION_QOS_PARAMETER_LIST: &[(ParameterId, QosPolicyId)] = &[
+    (PID_DURABILITY, DURABILITY_QOS_POLICY_ID),
+    (PID_DEADLINE, DEADLINE_QOS_POLICY_ID),
+    (PID_LATENCY_BUDGET, LATENCYBUDGET_QOS_POLICY_ID),
+    (PID_LIVELINESS, LIVELINESS_QOS_POLICY_ID),
+    (PID_RELIABILITY, RELIABILITY_QOS_POLICY_ID),
+    (PID_LIFESPAN, LIFESPAN_QOS_POLICY_ID),
+    (PID_USER_DATA, USERDATA_QOS_POLICY_ID),
+    (PID_OWNERSHIP, OWNERSHIP_QOS_POLICY_ID),
+    (PID_OWNERSHIP_STRENGTH, OWNERSHIP_STRENGTH_QOS_POLICY_ID),
+    (PID_DESTINATION_ORDER, DESTINATIONORDER_QOS_POLICY_ID),
+    (PID_PRESENTATION, PRESENTATION_QOS_POLICY_ID),
+    (PID_PARTITION, PARTITION_QOS_POLICY_ID),
+    (PID_TOPIC_DATA, TOPICDATA_QOS_POLICY_ID),
+    (PID_GROUP_DATA, GROUPDATA_QOS_POLICY_ID),
+    (PID_DATA_REPRESENTATION, DATA_REPRESENTATION_QOS_POLICY_ID),
+];
+pub const SUBSCRIPTION_QOS_PARAMETER_LIST: &[(ParameterId, QosPolicyId)] = &[
+    (PID_DURABILITY, DURABILITY_QOS_POLICY_ID),
+    (PID_DEADLINE, DEADLINE_QOS_POLICY_ID),
+    (PID_LATENCY_BUDGET, LATENCYBUDGET_QOS_POLICY_ID),
+    (PID_LIVELINESS, LIVELINESS_QOS_POLICY_ID),
+    (PID_RELIABILITY, RELIABILITY_QOS_POLICY_ID),
+    (PID_OWNERSHIP, OWNERSHIP_QOS_POLICY_ID),
+    (PID_DESTINATION_ORDER, DESTINATIONORDER_QOS_POLICY_ID),
+    (PID_USER_DATA, USERDATA_QOS_POLICY_ID),
+    (PID_TIME_BASED_FILTER, TIMEBASEDFILTER_QOS_POLICY_ID),
+    (PID_PRESENTATION, PRESENTATION_QOS_POLICY_ID),
+    (PID_PARTITION, PARTITION_QOS_POLICY_ID),
+    (PID_TOPIC_DATA, TOPICDATA_QOS_POLICY_ID),
+    (PID_GROUP_DATA, GROUPDATA_QOS_POLICY_ID),
+    (PID_DATA_REPRESENTATION, DATA_REPRESENTATION_QOS_POLICY_ID),
+];
+
+// Optional parameters of the discovery data which are not related to a QoS policy
+pub const PARTICIPANT_OPTIONAL_PARAMETER_LIST: &[ParameterId] = &[
+    PID_DOMAIN_ID,
+    PID_DOMAIN_TAG,
+    PID_EXPECTS_INLINE_QOS,
+    PID_PARTICIPANT_MANUAL_LIVELINESS_COUNT,
+    PID_BUILTIN_ENDPOINT_QOS,
+    PID_PARTICIPANT_LEASE_DURATION,
+];
+pub const TOPIC_OPTIONAL_PARAMETER_LIST: &[ParameterId] = &[];
+pub const PUBLICATION_OPTIONAL_PARAMETER_LIST: &[ParameterId] =
+    &[PID_PARTICIPANT_GUID, PID_GROUP_ENTITYID];
+pub const SUBSCRIPTION_OPTIONAL_PARAMETER_LIST: &[ParameterId] = &[
+    PID_PARTICIPANT_GUID,
+    PID_GROUP_ENTITYID,
+    PID_EXPECTS_INLINE_QOS,
+];
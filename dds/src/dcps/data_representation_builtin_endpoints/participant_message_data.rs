@@ -0,0 +1,53 @@
+use crate::infrastructure::type_support::DdsType;
+use alloc::vec::Vec;
+
+pub const DCPS_PARTICIPANT_MESSAGE: &str = "DCPSParticipantMessage";
+
+pub type ParticipantMessageDataKind = [u8; 4];
+
+pub const PARTICIPANT_MESSAGE_DATA_KIND_UNKNOWN: ParticipantMessageDataKind = [0, 0, 0, 0];
+pub const PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE: ParticipantMessageDataKind =
+    [0, 0, 0, 1];
+pub const PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE: ParticipantMessageDataKind =
+    [0, 0, 0, 2];
+
+/// Data exchanged by the BuiltinParticipantMessageWriter and BuiltinParticipantMessageReader
+/// to assert the liveliness of the writers of a participant (RTPS 8.4.13.5)
+#[derive(Debug, PartialEq, Eq, Clone, DdsType)]
+pub struct ParticipantMessageData {
+    #[dust_dds(key)]
+    pub(crate) participant_guid_prefix: [u8; 12],
+    #[dust_dds(key)]
+    pub(crate) kind: [u8; 4],
+    pub(crate) data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::type_support::{DdsDeserialize, DdsSerialize};
+    use alloc::vec;
+
+    #[test]
+    fn serialize_participant_message_data() {
+        let data = ParticipantMessageData {
+            participant_guid_prefix: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            kind: PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            data: vec![],
+        };
+        let expected = vec![
+            0x00, 0x01, 0x00, 0x00, // CDR_LE
+            1, 2, 3, 4, // participant_guid_prefix
+            5, 6, 7, 8, // participant_guid_prefix
+            9, 10, 11, 12, // participant_guid_prefix
+            0, 0, 0, 2, // kind
+            0, 0, 0, 0, // data length
+        ];
+        let serialized = data.serialize_data().unwrap();
+        assert_eq!(serialized, expected);
+        assert_eq!(
+            ParticipantMessageData::deserialize_data(&serialized).unwrap(),
+            data
+        );
+    }
+}
@@ -142,4 +142,13 @@ impl<'de> ParameterListCdrDeserializer<'de> {
         }
         Ok(default)
     }
+
+    pub fn parameter_id_list(&self) -> Result<Vec<ParameterId>, RtpsError> {
+        let mut parameter_id_list = Vec::new();
+        let mut iterator = self.iter();
+        while let Some(parameter) = iterator.next()? {
+            parameter_id_list.push(parameter.pid);
+        }
+        Ok(parameter_id_list)
+    }
 }
@@ -5,30 +5,43 @@ use super::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::DataWriterQos,
-        qos_policy::{HistoryQosPolicyKind, Length, QosPolicyId, ReliabilityQosPolicyKind},
+        qos_policy::{
+            DataRepresentationId, DurabilityQosPolicyKind, HistoryQosPolicyKind, Length,
+            LivelinessQosPolicyKind, PublishModeQosPolicyKind, QosPolicyId,
+            ReliabilityQosPolicyKind, XCDR_DATA_REPRESENTATION,
+        },
         status::{
-            OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
-            QosPolicyCount, StatusKind,
+            LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
+            PublicationMatchedStatus, PublicationThrottledStatus, QosPolicyCount, StatusKind,
         },
-        time::{DurationKind, Time},
+        time::{Duration, DurationKind, Time},
     },
     listeners::domain_participant_listener::ListenerMail,
     status_condition::StatusCondition,
     status_condition_actor::StatusConditionActor,
-    xtypes_glue::key_and_instance_handle::{
-        get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+    xtypes_glue::{
+        key_and_instance_handle::{
+            get_instance_handle_from_serialized_foo, get_instance_handle_from_serialized_key,
+            get_serialized_key_from_serialized_foo,
+        },
+        sample_members::replace_key_members,
     },
 };
 use crate::{
+    domain::memory_usage::DataWriterMemoryUsage,
+    publication::data_writer::{
+        DuplicateSuppressionMode, KeepLastReplacementPolicy, PublicationThrottling,
+    },
     runtime::{Clock, DdsRuntime},
     transport::{
-        history_cache::{CacheChange, HistoryCache},
-        types::{ChangeKind, Guid},
+        history_cache::{CacheChange, HistoryCache, OriginalWriterInfo},
+        types::{ChangeKind, Guid, Locator, SEQUENCENUMBER_UNKNOWN},
         writer::{TransportStatefulWriter, TransportStatelessWriter},
     },
     xtypes::dynamic_type::DynamicType,
 };
-use alloc::{boxed::Box, collections::VecDeque, string::String, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::VecDeque, format, string::String, sync::Arc, vec::Vec};
+use core::any::Any;
 
 pub enum TransportWriterKind {
     Stateful(Box<dyn TransportStatefulWriter>),
@@ -61,13 +74,95 @@ pub struct InstanceSamples {
     samples: VecDeque<i64>,
 }
 
+#[derive(PartialEq, Eq)]
+pub enum SampleDigest {
+    SerializedData(Vec<u8>),
+    Hash(u64),
+}
+
+pub struct LastPublishedSample {
+    instance: InstanceHandle,
+    digest: SampleDigest,
+}
+
+pub struct CoalescedWrite {
+    pub instance: InstanceHandle,
+    pub window_id: u64,
+    pub serialized_data: Vec<u8>,
+    pub timestamp: Time,
+    pub sample_hash: Option<u64>,
+}
+
+pub struct RegisteredInstance {
+    instance: InstanceHandle,
+    key_holder: Arc<[u8]>,
+    serialized_key: Option<Arc<[u8]>>,
+}
+
+/// An instance which was unregistered but whose resources are kept until all its changes are acknowledged.
+pub struct UnregisteredInstance {
+    instance: InstanceHandle,
+    unregister_sequence_number: i64,
+}
+
+struct MatchedSubscriptionLocators {
+    subscription_handle: InstanceHandle,
+    locator_list: Vec<Locator>,
+}
+
+/// Storage of a data writer allocated before any of its other state is created, so that a writer
+/// whose resources can not be allocated is rejected without leaving anything behind.
+pub struct DataWriterResources {
+    reserved_samples: usize,
+    registered_instance_list: Vec<RegisteredInstance>,
+    instance_samples: Vec<InstanceSamples>,
+    matched_subscription_list: Vec<SubscriptionBuiltinTopicData>,
+    max_matched_readers: usize,
+}
+
+impl DataWriterResources {
+    pub fn allocate(
+        reserved_samples: Length,
+        max_instances: Length,
+        max_matched_readers: usize,
+    ) -> DdsResult<Self> {
+        let mut resources = Self {
+            reserved_samples: 0,
+            registered_instance_list: Vec::new(),
+            instance_samples: Vec::new(),
+            matched_subscription_list: Vec::new(),
+            max_matched_readers,
+        };
+        if let Length::Limited(reserved_samples) = reserved_samples {
+            resources.reserved_samples = reserved_samples as usize;
+        }
+        if let Length::Limited(max_instances) = max_instances {
+            resources
+                .registered_instance_list
+                .try_reserve_exact(max_instances as usize)
+                .map_err(|_| DdsError::OutOfResources)?;
+            resources
+                .instance_samples
+                .try_reserve_exact(max_instances as usize)
+                .map_err(|_| DdsError::OutOfResources)?;
+        }
+        resources
+            .matched_subscription_list
+            .try_reserve_exact(max_matched_readers)
+            .map_err(|_| DdsError::OutOfResources)?;
+        Ok(resources)
+    }
+}
+
 pub struct DataWriterEntity<R: DdsRuntime> {
     instance_handle: InstanceHandle,
     transport_writer: TransportWriterKind,
     topic_name: String,
     type_name: String,
     type_support: Arc<dyn DynamicType + Send + Sync>,
+    has_key: bool,
     matched_subscription_list: Vec<SubscriptionBuiltinTopicData>,
+    matched_subscription_locators: Vec<MatchedSubscriptionLocators>,
     publication_matched_status: PublicationMatchedStatus,
     incompatible_subscription_list: Vec<InstanceHandle>,
     offered_incompatible_qos_status: OfferedIncompatibleQosStatus,
@@ -78,15 +173,39 @@ pub struct DataWriterEntity<R: DdsRuntime> {
     max_seq_num: Option<i64>,
     last_change_sequence_number: i64,
     qos: DataWriterQos,
-    registered_instance_list: Vec<InstanceHandle>,
+    registered_instance_list: Vec<RegisteredInstance>,
+    unregistered_instance_list: Vec<UnregisteredInstance>,
     offered_deadline_missed_status: OfferedDeadlineMissedStatus,
     instance_publication_time: Vec<InstancePublicationTime>,
     instance_samples: Vec<InstanceSamples>,
+    reserved_samples: usize,
+    liveliness_lost_status: LivelinessLostStatus,
+    last_liveliness_assertion: Option<Time>,
+    alive: bool,
+    keep_last_replacement_policy: KeepLastReplacementPolicy,
+    replaced_unacknowledged_sample_count: u64,
+    duplicate_suppression_mode: DuplicateSuppressionMode,
+    last_published_sample_list: Vec<LastPublishedSample>,
+    draining: bool,
+    batch_id: u64,
+    batched_samples: usize,
+    batched_data_bytes: usize,
+    coherent_set_open: bool,
+    coherent_set: Option<i64>,
+    last_coherent_set_end: Option<i64>,
+    write_coalescing_window: Option<Duration>,
+    coalescing_window_id: u64,
+    coalesced_write_list: Vec<CoalescedWrite>,
+    publication_throttling: Option<PublicationThrottling>,
+    publication_throttling_check_scheduled: bool,
+    publication_throttled_status: PublicationThrottledStatus,
+    // Storage of the samples returned after being loaned and written, reused by the next loans
+    loaned_sample_storage: Vec<Box<dyn Any + Send>>,
 }
 
 impl<R: DdsRuntime> DataWriterEntity<R> {
     #[allow(clippy::too_many_arguments)]
-    pub const fn new(
+    pub fn new(
         instance_handle: InstanceHandle,
         transport_writer: TransportWriterKind,
         topic_name: String,
@@ -97,13 +216,16 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         listener_mask: Vec<StatusKind>,
         qos: DataWriterQos,
     ) -> Self {
+        let has_key = type_has_key(type_support.as_ref());
         Self {
             instance_handle,
             transport_writer,
             topic_name,
             type_name,
             type_support,
+            has_key,
             matched_subscription_list: Vec::new(),
+            matched_subscription_locators: Vec::new(),
             publication_matched_status: PublicationMatchedStatus::const_default(),
             incompatible_subscription_list: Vec::new(),
             offered_incompatible_qos_status: OfferedIncompatibleQosStatus::const_default(),
@@ -115,9 +237,32 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
             last_change_sequence_number: 0,
             qos,
             registered_instance_list: Vec::new(),
+            unregistered_instance_list: Vec::new(),
             offered_deadline_missed_status: OfferedDeadlineMissedStatus::const_default(),
             instance_publication_time: Vec::new(),
             instance_samples: Vec::new(),
+            reserved_samples: 0,
+            liveliness_lost_status: LivelinessLostStatus::const_default(),
+            last_liveliness_assertion: None,
+            alive: false,
+            keep_last_replacement_policy: KeepLastReplacementPolicy::BlockUntilAcknowledged,
+            replaced_unacknowledged_sample_count: 0,
+            duplicate_suppression_mode: DuplicateSuppressionMode::Disabled,
+            last_published_sample_list: Vec::new(),
+            draining: false,
+            batch_id: 0,
+            batched_samples: 0,
+            batched_data_bytes: 0,
+            coherent_set_open: false,
+            coherent_set: None,
+            last_coherent_set_end: None,
+            write_coalescing_window: None,
+            coalescing_window_id: 0,
+            coalesced_write_list: Vec::new(),
+            publication_throttling: None,
+            publication_throttling_check_scheduled: false,
+            publication_throttled_status: PublicationThrottledStatus::const_default(),
+            loaned_sample_storage: Vec::new(),
         }
     }
 
@@ -141,12 +286,55 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         &mut self.transport_writer
     }
 
+    /// Assigns the writer to the flow controller pacing the writers of its topic when the topic has a bandwidth
+    /// quota, otherwise to the flow controller of its QoS.
+    pub fn set_bandwidth_quota_flow_controller(&mut self, has_bandwidth_quota: bool) {
+        if let TransportWriterKind::Stateful(w) = &mut self.transport_writer {
+            if has_bandwidth_quota {
+                w.set_flow_controller(&bandwidth_quota_flow_controller_name(&self.topic_name));
+            } else {
+                w.set_flow_controller(&self.qos.flow_controller.name);
+            }
+        }
+    }
+
+    pub fn take_data_bytes_sent(&mut self) -> Vec<(Guid, u64)> {
+        match &mut self.transport_writer {
+            TransportWriterKind::Stateful(w) => w.take_data_bytes_sent(),
+            TransportWriterKind::Stateless(_) => Vec::new(),
+        }
+    }
+
     pub fn enabled(&self) -> bool {
         self.enabled
     }
 
     pub fn enable(&mut self) {
         self.enabled = true;
+        if let TransportWriterKind::Stateful(w) = &mut self.transport_writer {
+            if self.qos.batch.enable {
+                w.set_batching(true);
+            }
+            let writer_protocol = &self.qos.writer_protocol;
+            w.set_reliability_timing(
+                writer_protocol.heartbeat_period.into(),
+                writer_protocol.nack_response_delay.into(),
+                writer_protocol.nack_suppression_duration.into(),
+                writer_protocol.min_resend_interval.into(),
+            );
+            w.set_samples_per_heartbeat(match writer_protocol.samples_per_heartbeat {
+                Length::Limited(samples) => Some(samples as usize),
+                Length::Unlimited => None,
+            });
+            if self.qos.disable_positive_acks.enable {
+                w.set_disable_positive_acks(Some(self.qos.disable_positive_acks.duration.into()));
+            }
+            w.set_flow_controller(&self.qos.flow_controller.name);
+            w.set_transport_priority(self.qos.transport_priority.value);
+            w.set_asynchronous_publishing(
+                self.qos.publish_mode.kind == PublishModeQosPolicyKind::Asynchronous,
+            );
+        }
     }
 
     pub fn qos(&self) -> &DataWriterQos {
@@ -157,91 +345,665 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         qos.is_consistent()?;
         if self.enabled {
             self.qos.check_immutability(&qos)?;
+            if let TransportWriterKind::Stateful(w) = &mut self.transport_writer {
+                w.set_transport_priority(qos.transport_priority.value);
+            }
         }
         self.qos = qos;
         Ok(())
     }
 
-    pub fn contains_instance(&mut self, instance_handle: &InstanceHandle) -> bool {
-        self.registered_instance_list.contains(instance_handle)
+    pub fn is_draining(&self) -> bool {
+        self.draining
     }
 
-    pub async fn write_w_timestamp(
+    pub fn start_draining(&mut self) {
+        self.draining = true;
+    }
+
+    fn is_batching(&self) -> bool {
+        self.qos.batch.enable && matches!(self.transport_writer, TransportWriterKind::Stateful(_))
+    }
+
+    /// Sends the samples held in the current batch and starts a new one.
+    pub fn flush(&mut self) {
+        if !self.is_batching() {
+            return;
+        }
+        if let TransportWriterKind::Stateful(w) = &mut self.transport_writer {
+            w.flush();
+        }
+        self.batch_id += 1;
+        self.batched_samples = 0;
+        self.batched_data_bytes = 0;
+    }
+
+    /// Identifier of the batch holding samples which have not been sent yet, if any.
+    pub fn open_batch_id(&self) -> Option<u64> {
+        (self.batched_samples > 0).then_some(self.batch_id)
+    }
+
+    /// Identifier and flush delay of the batch if it was opened by the last written sample.
+    pub fn batch_flush_delay(&self) -> Option<(u64, Duration)> {
+        match self.qos.batch.max_flush_delay {
+            DurationKind::Finite(max_flush_delay) if self.batched_samples == 1 => {
+                Some((self.batch_id, max_flush_delay))
+            }
+            _ => None,
+        }
+    }
+
+    fn add_to_batch(&mut self, data_bytes: usize) {
+        self.batched_samples += 1;
+        self.batched_data_bytes += data_bytes;
+        let max_samples_reached = matches!(self.qos.batch.max_samples,
+            Length::Limited(max_samples) if self.batched_samples >= max_samples as usize);
+        let max_data_bytes_reached = matches!(self.qos.batch.max_data_bytes,
+            Length::Limited(max_data_bytes) if self.batched_data_bytes >= max_data_bytes as usize);
+        if max_samples_reached || max_data_bytes_reached {
+            self.flush();
+        }
+    }
+
+    /// Starts a coherent set which includes all the changes written until [`Self::end_coherent_set`] is called.
+    pub fn begin_coherent_set(&mut self) {
+        self.coherent_set_open = true;
+    }
+
+    /// Ends the open coherent set. If any change was written in the set, a change without data is added to
+    /// mark its end so that the readers can deliver the set without waiting for the next change of the writer.
+    pub async fn end_coherent_set(&mut self) {
+        self.coherent_set_open = false;
+        if self.coherent_set.take().is_none() || !self.enabled {
+            return;
+        }
+
+        self.last_change_sequence_number += 1;
+        let cache_change = CacheChange {
+            kind: ChangeKind::Alive,
+            writer_guid: self.transport_writer().guid(),
+            sequence_number: self.last_change_sequence_number,
+            source_timestamp: None,
+            instance_handle: None,
+            coherent_set: Some(SEQUENCENUMBER_UNKNOWN),
+            directed_write: Vec::new(),
+            original_writer_info: None,
+            data_value: Arc::from([]),
+        };
+        self.transport_writer
+            .history_cache()
+            .add_change(cache_change)
+            .await;
+        // The first change of the next set also ends the previous one so only the last end marker is kept
+        if let Some(sequence_number) = self
+            .last_coherent_set_end
+            .replace(self.last_change_sequence_number)
+        {
+            self.transport_writer
+                .history_cache()
+                .remove_change(sequence_number)
+                .await;
+        }
+        self.flush();
+    }
+
+    // The coherent set is identified by the sequence number of its first change
+    fn next_change_coherent_set(&mut self) -> Option<i64> {
+        if !self.coherent_set_open {
+            return None;
+        }
+        Some(
+            *self
+                .coherent_set
+                .get_or_insert(self.last_change_sequence_number),
+        )
+    }
+
+    pub fn set_keep_last_replacement_policy(&mut self, policy: KeepLastReplacementPolicy) {
+        self.keep_last_replacement_policy = policy;
+    }
+
+    pub fn replaced_unacknowledged_sample_count(&self) -> u64 {
+        self.replaced_unacknowledged_sample_count
+    }
+
+    pub fn set_write_coalescing_window(&mut self, window: Option<Duration>) -> DdsResult<()> {
+        if window.is_some() && self.qos.history.kind != HistoryQosPolicyKind::KeepLast(1) {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Write coalescing requires a KeepLast(1) history",
+            )));
+        }
+        self.write_coalescing_window = window;
+        Ok(())
+    }
+
+    /// Window during which the writes of an instance are collapsed into the latest one. The qos could have
+    /// been changed after setting the window so the history is checked again.
+    pub fn write_coalescing_window(&self) -> Option<Duration> {
+        self.write_coalescing_window
+            .filter(|_| self.qos.history.kind == HistoryQosPolicyKind::KeepLast(1))
+    }
+
+    /// Window during which the writes of an instance are currently held and collapsed into the latest one. While the
+    /// publication is throttled the writes are held at least for the throttled publication period.
+    pub fn write_holding_window(&self) -> Option<Duration> {
+        let throttled_publication_period = self
+            .publication_throttling
+            .filter(|_| {
+                self.publication_throttled_status.throttled
+                    && matches!(self.qos.history.kind, HistoryQosPolicyKind::KeepLast(_))
+            })
+            .map(|t| t.throttled_publication_period);
+        match (self.write_coalescing_window(), throttled_publication_period) {
+            (Some(window), Some(period)) if period > window => Some(period),
+            (window, period) => window.or(period),
+        }
+    }
+
+    pub fn set_publication_throttling(
+        &mut self,
+        publication_throttling: Option<PublicationThrottling>,
+    ) -> DdsResult<()> {
+        if let Some(t) = &publication_throttling {
+            if t.release_threshold >= t.throttle_threshold {
+                return Err(DdsError::BadParameter);
+            }
+            if !matches!(self.qos.history.kind, HistoryQosPolicyKind::KeepLast(_)) {
+                return Err(DdsError::PreconditionNotMet(String::from(
+                    "Publication throttling requires a KeepLast history",
+                )));
+            }
+        }
+        self.publication_throttling = publication_throttling;
+        if self.publication_throttling.is_none() {
+            self.publication_throttled_status.throttled = false;
+        }
+        Ok(())
+    }
+
+    pub fn publication_throttling(&self) -> Option<PublicationThrottling> {
+        self.publication_throttling
+    }
+
+    /// Marks whether the periodic check of the lag of the matched reliable readers is scheduled. Returns the previous
+    /// value so that a single check is kept running.
+    pub fn set_publication_throttling_check_scheduled(&mut self, scheduled: bool) -> bool {
+        core::mem::replace(&mut self.publication_throttling_check_scheduled, scheduled)
+    }
+
+    /// Number of changes written after the last change acknowledged by all the matched reliable readers.
+    fn unacknowledged_change_count(&self) -> i64 {
+        let TransportWriterKind::Stateful(w) = &self.transport_writer else {
+            return 0;
+        };
+        // The acknowledgments are cumulative so the last acknowledged change is found with a binary search
+        let (mut acknowledged, mut unacknowledged) = (0, self.last_change_sequence_number + 1);
+        while unacknowledged - acknowledged > 1 {
+            let sequence_number = acknowledged + (unacknowledged - acknowledged) / 2;
+            if w.is_change_acknowledged(sequence_number) {
+                acknowledged = sequence_number;
+            } else {
+                unacknowledged = sequence_number;
+            }
+        }
+        self.last_change_sequence_number - acknowledged
+    }
+
+    /// Starts throttling the publication when the matched reliable readers lag behind by more than the throttle
+    /// threshold and stops once they have caught up to the release threshold. Returns true if the throttling
+    /// started or stopped.
+    pub fn update_publication_throttling(&mut self) -> bool {
+        let Some(publication_throttling) = self.publication_throttling else {
+            return false;
+        };
+        let unacknowledged_change_count = self.unacknowledged_change_count();
+        let throttled = if self.publication_throttled_status.throttled {
+            unacknowledged_change_count > publication_throttling.release_threshold as i64
+        } else {
+            unacknowledged_change_count > publication_throttling.throttle_threshold as i64
+        };
+        if throttled == self.publication_throttled_status.throttled {
+            return false;
+        }
+        if throttled {
+            self.publication_throttled_status.total_count += 1;
+            self.publication_throttled_status.total_count_change += 1;
+        }
+        self.publication_throttled_status.throttled = throttled;
+        self.publication_throttled_status
+            .unacknowledged_change_count = unacknowledged_change_count as i32;
+        true
+    }
+
+    pub async fn get_publication_throttled_status(&mut self) -> PublicationThrottledStatus {
+        let status = self.publication_throttled_status.clone();
+        self.publication_throttled_status.total_count_change = 0;
+        self.status_condition
+            .remove_state(StatusKind::PublicationThrottled)
+            .await;
+
+        status
+    }
+
+    /// Holds the write until the coalescing window of the instance expires, replacing the value written
+    /// previously in the same window. Returns the identifier of the window if the write opened a new one.
+    pub fn coalesce_write(
         &mut self,
+        instance: InstanceHandle,
         serialized_data: Vec<u8>,
         timestamp: Time,
-        clock: &impl Clock,
-    ) -> DdsResult<i64> {
-        if !self.enabled {
-            return Err(DdsError::NotEnabled);
+        sample_hash: Option<u64>,
+    ) -> Option<u64> {
+        match self
+            .coalesced_write_list
+            .iter_mut()
+            .find(|x| x.instance == instance)
+        {
+            Some(x) => {
+                x.serialized_data = serialized_data;
+                x.timestamp = timestamp;
+                x.sample_hash = sample_hash;
+                None
+            }
+            None => {
+                self.coalescing_window_id += 1;
+                self.coalesced_write_list.push(CoalescedWrite {
+                    instance,
+                    window_id: self.coalescing_window_id,
+                    serialized_data,
+                    timestamp,
+                    sample_hash,
+                });
+                Some(self.coalescing_window_id)
+            }
         }
+    }
 
-        self.last_change_sequence_number += 1;
+    /// Identifier of the coalescing window holding a write of the instance, if any.
+    pub fn open_coalescing_window_id(&self, instance: &InstanceHandle) -> Option<u64> {
+        self.coalesced_write_list
+            .iter()
+            .find(|x| &x.instance == instance)
+            .map(|x| x.window_id)
+    }
 
-        let instance_handle =
-            get_instance_handle_from_serialized_foo(&serialized_data, self.type_support.as_ref())?;
+    pub fn take_coalesced_write(&mut self, instance: &InstanceHandle) -> Option<CoalescedWrite> {
+        let index = self
+            .coalesced_write_list
+            .iter()
+            .position(|x| &x.instance == instance)?;
+        Some(self.coalesced_write_list.remove(index))
+    }
 
-        if !self.registered_instance_list.contains(&instance_handle) {
-            if self.registered_instance_list.len() < self.qos.resource_limits.max_instances {
-                self.registered_instance_list.push(instance_handle);
-            } else {
-                return Err(DdsError::OutOfResources);
+    /// Removes the write held for the longest time, if any.
+    pub fn pop_coalesced_write(&mut self) -> Option<CoalescedWrite> {
+        (!self.coalesced_write_list.is_empty()).then(|| self.coalesced_write_list.remove(0))
+    }
+
+    pub fn set_duplicate_suppression_mode(&mut self, mode: DuplicateSuppressionMode) {
+        self.duplicate_suppression_mode = mode;
+        self.last_published_sample_list.clear();
+    }
+
+    pub fn sample_digest(
+        &self,
+        serialized_data: &[u8],
+        sample_hash: Option<u64>,
+    ) -> Option<SampleDigest> {
+        match self.duplicate_suppression_mode {
+            DuplicateSuppressionMode::Disabled => None,
+            DuplicateSuppressionMode::SerializedData => {
+                Some(SampleDigest::SerializedData(serialized_data.to_vec()))
             }
+            DuplicateSuppressionMode::UserProvidedHash => sample_hash.map(SampleDigest::Hash),
+        }
+    }
+
+    pub fn is_last_published_sample(
+        &self,
+        instance_handle: &InstanceHandle,
+        sample_digest: &SampleDigest,
+    ) -> bool {
+        self.last_published_sample_list
+            .iter()
+            .any(|x| &x.instance == instance_handle && &x.digest == sample_digest)
+    }
+
+    pub fn set_last_published_sample(
+        &mut self,
+        instance_handle: InstanceHandle,
+        sample_digest: Option<SampleDigest>,
+    ) {
+        self.last_published_sample_list
+            .retain(|x| x.instance != instance_handle);
+        if let Some(digest) = sample_digest {
+            self.last_published_sample_list.push(LastPublishedSample {
+                instance: instance_handle,
+                digest,
+            });
         }
+    }
 
-        if let Length::Limited(max_instances) = self.qos.resource_limits.max_instances {
-            if !self
+    pub fn reserved_samples(&self) -> usize {
+        self.reserved_samples
+    }
+
+    pub fn memory_usage(&self) -> DataWriterMemoryUsage {
+        let (history_bytes, fragment_buffer_bytes) = match &self.transport_writer {
+            TransportWriterKind::Stateful(w) => {
+                (w.history_memory_usage(), w.fragment_buffer_memory_usage())
+            }
+            TransportWriterKind::Stateless(_) => (0, 0),
+        };
+
+        DataWriterMemoryUsage {
+            writer_handle: self.instance_handle,
+            topic_name: self.topic_name.clone(),
+            sample_count: self
                 .instance_samples
                 .iter()
-                .any(|x| x.instance == instance_handle)
-                && self.instance_samples.len() == max_instances as usize
-            {
-                return Err(DdsError::OutOfResources);
-            }
+                .map(|x| x.samples.len() as u64)
+                .sum(),
+            instance_count: self.instance_count() as u64,
+            history_bytes: history_bytes as u64,
+            fragment_buffer_bytes: fragment_buffer_bytes as u64,
         }
+    }
 
-        if let Length::Limited(max_samples_per_instance) =
-            self.qos.resource_limits.max_samples_per_instance
+    /// Takes the storage allocated for the writer before it was created and reserves the history, the
+    /// fragment buffers and the proxies of the matched readers in the transport.
+    pub fn reserve_resources(&mut self, resources: DataWriterResources) -> DdsResult<()> {
+        self.registered_instance_list = resources.registered_instance_list;
+        self.instance_samples = resources.instance_samples;
+        self.matched_subscription_list = resources.matched_subscription_list;
+        self.reserved_samples = resources.reserved_samples;
+        if let TransportWriterKind::Stateful(w) = &mut self.transport_writer {
+            w.reserve_resources(self.reserved_samples, resources.max_matched_readers)
+                .map_err(|_| DdsError::OutOfResources)?;
+        }
+        Ok(())
+    }
+
+    /// Number of instances holding resources, which includes the unregistered instances not yet reclaimed.
+    fn instance_count(&self) -> usize {
+        self.registered_instance_list.len() + self.unregistered_instance_list.len()
+    }
+
+    /// Adds the instance to the registered instances unless it is already registered. An instance which was
+    /// unregistered but not yet reclaimed is registered again without requiring additional resources.
+    async fn add_registered_instance(
+        &mut self,
+        instance_handle: InstanceHandle,
+        key_holder: &[u8],
+    ) -> DdsResult<()> {
+        if self.contains_instance(&instance_handle) {
+            return Ok(());
+        }
+        if let Some(i) = self
+            .unregistered_instance_list
+            .iter()
+            .position(|x| x.instance == instance_handle)
         {
-            // If the history Qos guarantess that the number of samples
-            // is below the limit there is no need to check
-            match self.qos.history.kind {
-                HistoryQosPolicyKind::KeepLast(depth) if depth <= max_samples_per_instance => {}
-                _ => {
-                    if let Some(s) = self
-                        .instance_samples
-                        .iter()
-                        .find(|x| x.instance == instance_handle)
-                    {
-                        // Only Alive changes count towards the resource limits
-                        if s.samples.len() >= max_samples_per_instance as usize {
-                            return Err(DdsError::OutOfResources);
-                        }
-                    }
+            self.unregistered_instance_list.remove(i);
+        } else {
+            if self.instance_count() >= self.qos.resource_limits.max_instances {
+                self.reclaim_unregistered_instances().await;
+                if self.instance_count() >= self.qos.resource_limits.max_instances {
+                    return Err(DdsError::OutOfResources);
                 }
             }
         }
+        self.registered_instance_list.push(RegisteredInstance {
+            instance: instance_handle,
+            key_holder: key_holder.into(),
+            serialized_key: None,
+        });
+        Ok(())
+    }
 
-        if let Length::Limited(max_samples) = self.qos.resource_limits.max_samples {
-            let total_samples = self
+    /// Releases the samples kept for the unregistered instances whose changes, including the unregistration
+    /// itself, have been acknowledged by all the matched reliable readers.
+    async fn reclaim_unregistered_instances(&mut self) {
+        let mut reclaimed_instances = Vec::new();
+        let transport_writer = &self.transport_writer;
+        self.unregistered_instance_list.retain(|x| {
+            // The acknowledgments are cumulative so the previous changes of the instance are acknowledged as well
+            let is_acknowledged = match transport_writer {
+                TransportWriterKind::Stateful(w) => {
+                    w.is_change_acknowledged(x.unregister_sequence_number)
+                }
+                TransportWriterKind::Stateless(_) => true,
+            };
+            if is_acknowledged {
+                reclaimed_instances.push((x.instance, x.unregister_sequence_number));
+            }
+            !is_acknowledged
+        });
+        for (instance, unregister_sequence_number) in reclaimed_instances {
+            let mut reclaimed_changes = Vec::from([unregister_sequence_number]);
+            if let Some(i) = self
                 .instance_samples
                 .iter()
-                .fold(0, |acc, x| acc + x.samples.len());
+                .position(|x| x.instance == instance)
+            {
+                reclaimed_changes.extend(self.instance_samples.remove(i).samples);
+            }
+            for sequence_number in reclaimed_changes {
+                self.transport_writer
+                    .history_cache()
+                    .remove_change(sequence_number)
+                    .await;
+            }
+        }
+    }
 
-            if total_samples >= max_samples as usize {
+    pub fn contains_instance(&self, instance_handle: &InstanceHandle) -> bool {
+        self.registered_instance_list
+            .iter()
+            .any(|x| &x.instance == instance_handle)
+    }
+
+    /// Checks that the handle given by the application for an operation on an instance
+    /// corresponds to the instance identified by the key of the sample.
+    pub fn check_instance_handle(
+        &self,
+        instance_handle: &InstanceHandle,
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<()> {
+        match handle {
+            None => Ok(()),
+            Some(h) if self.contains_instance(&h) => {
+                if &h == instance_handle {
+                    Ok(())
+                } else {
+                    Err(DdsError::PreconditionNotMet(String::from(
+                        "Handle does not correspond to the instance of the sample",
+                    )))
+                }
+            }
+            Some(_) => Err(DdsError::BadParameter),
+        }
+    }
+
+    pub async fn register_instance(
+        &mut self,
+        serialized_data: Vec<u8>,
+    ) -> DdsResult<Option<InstanceHandle>> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+        if self.draining {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "DataWriter is draining",
+            )));
+        }
+
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(&serialized_data, self.type_support.as_ref())?;
+        self.add_registered_instance(instance_handle, &serialized_data)
+            .await?;
+        Ok(Some(instance_handle))
+    }
+
+    pub fn loan_sample_storage(&mut self) -> Option<Box<dyn Any + Send>> {
+        self.loaned_sample_storage.pop()
+    }
+
+    pub fn return_loaned_sample_storage(&mut self, storage: Box<dyn Any + Send>) {
+        self.loaned_sample_storage.push(storage);
+    }
+
+    pub fn get_key_value(
+        &self,
+        serialized_key_holder: &[u8],
+        instance_handle: InstanceHandle,
+    ) -> DdsResult<Vec<u8>> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        let Some(registered_instance) = self
+            .registered_instance_list
+            .iter()
+            .find(|x| x.instance == instance_handle)
+        else {
+            return Err(DdsError::BadParameter);
+        };
+
+        Ok(replace_key_members(
+            serialized_key_holder,
+            &registered_instance.key_holder,
+            self.type_support.as_ref(),
+        )?)
+    }
+
+    /// Returns the serialized key of a registered instance. The key is extracted from the data
+    /// the instance was registered with the first time it is needed and kept for later use.
+    pub fn registered_serialized_key(
+        &mut self,
+        instance_handle: &InstanceHandle,
+    ) -> DdsResult<Option<Vec<u8>>> {
+        let Some(registered_instance) = self
+            .registered_instance_list
+            .iter_mut()
+            .find(|x| &x.instance == instance_handle)
+        else {
+            return Ok(None);
+        };
+        let serialized_key = match &registered_instance.serialized_key {
+            Some(serialized_key) => serialized_key.clone(),
+            None => {
+                let serialized_key: Arc<[u8]> = get_serialized_key_from_serialized_foo(
+                    &registered_instance.key_holder,
+                    self.type_support.as_ref(),
+                )?
+                .into();
+                registered_instance.serialized_key = Some(serialized_key.clone());
+                serialized_key
+            }
+        };
+        Ok(Some(serialized_key.to_vec()))
+    }
+
+    pub async fn write_w_timestamp(
+        &mut self,
+        serialized_data: Vec<u8>,
+        timestamp: Time,
+        clock: &impl Clock,
+    ) -> DdsResult<i64> {
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(&serialized_data, self.type_support.as_ref())?;
+        self.write_instance_w_timestamp(
+            instance_handle,
+            serialized_data,
+            timestamp,
+            Vec::new(),
+            None,
+            clock,
+        )
+        .await
+    }
+
+    /// Writes the sample of an instance whose handle was already obtained from the serialized data. A
+    /// non-empty `directed_write` restricts the readers which deliver the sample to the ones in the list
+    /// and `original_writer_info` identifies the writer of a forwarded sample.
+    pub async fn write_instance_w_timestamp(
+        &mut self,
+        instance_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+        timestamp: Time,
+        directed_write: Vec<Guid>,
+        original_writer_info: Option<OriginalWriterInfo>,
+        clock: &impl Clock,
+    ) -> DdsResult<i64> {
+        if !self.enabled {
+            return Err(DdsError::NotEnabled);
+        }
+
+        if self.is_sample_limit_reached(&instance_handle) {
+            // A reliable writer keeping all the samples waits until samples are acknowledged
+            // by all the matched readers so that they can be removed from the history
+            if self.qos.reliability.kind != ReliabilityQosPolicyKind::Reliable
+                || self.qos.history.kind != HistoryQosPolicyKind::KeepAll
+            {
                 return Err(DdsError::OutOfResources);
             }
+            // The held samples can only be acknowledged once they are sent
+            self.flush();
+            // The acknowledged samples of a durable writer are still served to the late-joining readers
+            if self.qos.durability.kind == DurabilityQosPolicyKind::Volatile {
+                self.remove_acknowledged_samples().await;
+            }
+            // The writer doesn't wait here so that the participant keeps processing the acknowledgments.
+            // The caller retries the write until the max blocking time elapses.
+            if self.is_sample_limit_reached(&instance_handle) {
+                return Err(DdsError::Timeout);
+            }
         }
 
+        self.last_change_sequence_number += 1;
+
+        if !self.unregistered_instance_list.is_empty() {
+            self.reclaim_unregistered_instances().await;
+        }
+        self.add_registered_instance(instance_handle, &serialized_data)
+            .await?;
+
+        if let Length::Limited(max_instances) = self.qos.resource_limits.max_instances {
+            if !self
+                .instance_samples
+                .iter()
+                .any(|x| x.instance == instance_handle)
+                && self.instance_samples.len() == max_instances as usize
+            {
+                return Err(DdsError::OutOfResources);
+            }
+        }
+
+        // The key hash is only sent for keyed topics since the instance of an unkeyed topic is implied
         let change = CacheChange {
             kind: ChangeKind::Alive,
             writer_guid: self.transport_writer().guid(),
             sequence_number: self.last_change_sequence_number,
             source_timestamp: Some(timestamp.into()),
-            instance_handle: Some(instance_handle.into()),
+            instance_handle: self.has_key.then_some(instance_handle.into()),
+            coherent_set: self.next_change_coherent_set(),
+            directed_write,
+            original_writer_info,
             data_value: serialized_data.into(),
         };
         if let HistoryQosPolicyKind::KeepLast(depth) = self.qos.history.kind {
+            // The oldest sample can only be acknowledged once the held samples are sent
+            if self.qos.reliability.kind == ReliabilityQosPolicyKind::Reliable
+                && self.keep_last_replacement_policy
+                    == KeepLastReplacementPolicy::BlockUntilAcknowledged
+                && self
+                    .instance_samples
+                    .iter()
+                    .any(|x| x.instance == instance_handle && x.samples.len() == depth as usize)
+            {
+                self.flush();
+            }
             if let Some(s) = self
                 .instance_samples
                 .iter_mut()
@@ -250,17 +1012,31 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
                 if s.samples.len() == depth as usize {
                     if let Some(&smallest_seq_num_instance) = s.samples.front() {
                         if self.qos.reliability.kind == ReliabilityQosPolicyKind::Reliable {
-                            let start_time = clock.now();
-                            while let TransportWriterKind::Stateful(w) = &self.transport_writer {
-                                if w.is_change_acknowledged(smallest_seq_num_instance) {
-                                    break;
+                            match self.keep_last_replacement_policy {
+                                KeepLastReplacementPolicy::BlockUntilAcknowledged => {
+                                    let start_time = clock.now();
+                                    while let TransportWriterKind::Stateful(w) =
+                                        &self.transport_writer
+                                    {
+                                        if w.is_change_acknowledged(smallest_seq_num_instance) {
+                                            break;
+                                        }
+
+                                        if let DurationKind::Finite(t) =
+                                            self.qos.reliability.max_blocking_time
+                                        {
+                                            if (clock.now() - start_time) > t {
+                                                return Err(DdsError::Timeout);
+                                            }
+                                        }
+                                    }
                                 }
-
-                                if let DurationKind::Finite(t) =
-                                    self.qos.reliability.max_blocking_time
-                                {
-                                    if (clock.now() - start_time) > t {
-                                        return Err(DdsError::Timeout);
+                                KeepLastReplacementPolicy::OverwriteOldest => {
+                                    if let TransportWriterKind::Stateful(w) = &self.transport_writer
+                                    {
+                                        if !w.is_change_acknowledged(smallest_seq_num_instance) {
+                                            self.replaced_unacknowledged_sample_count += 1;
+                                        }
                                     }
                                 }
                             }
@@ -314,44 +1090,108 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
                 self.instance_samples.push(s);
             }
         }
+        let data_bytes = change.data_value().len();
         self.transport_writer
             .history_cache()
             .add_change(change)
             .await;
+        if self.is_batching() {
+            self.add_to_batch(data_bytes);
+        }
         Ok(self.last_change_sequence_number)
     }
 
+    fn is_sample_limit_reached(&self, instance_handle: &InstanceHandle) -> bool {
+        if let Length::Limited(max_samples_per_instance) =
+            self.qos.resource_limits.max_samples_per_instance
+        {
+            // If the history Qos guarantess that the number of samples
+            // is below the limit there is no need to check
+            match self.qos.history.kind {
+                HistoryQosPolicyKind::KeepLast(depth) if depth <= max_samples_per_instance => {}
+                _ => {
+                    if let Some(s) = self
+                        .instance_samples
+                        .iter()
+                        .find(|x| &x.instance == instance_handle)
+                    {
+                        // Only Alive changes count towards the resource limits
+                        if s.samples.len() >= max_samples_per_instance as usize {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Length::Limited(max_samples) = self.qos.resource_limits.max_samples {
+            let total_samples = self
+                .instance_samples
+                .iter()
+                .fold(0, |acc, x| acc + x.samples.len());
+
+            if total_samples >= max_samples as usize {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    async fn remove_acknowledged_samples(&mut self) {
+        let TransportWriterKind::Stateful(w) = &self.transport_writer else {
+            return;
+        };
+        let mut acknowledged_samples = Vec::new();
+        for s in self.instance_samples.iter_mut() {
+            s.samples.retain(|&sequence_number| {
+                let is_acknowledged = w.is_change_acknowledged(sequence_number);
+                if is_acknowledged {
+                    acknowledged_samples.push(sequence_number);
+                }
+                !is_acknowledged
+            });
+        }
+        for sequence_number in acknowledged_samples {
+            self.transport_writer
+                .history_cache()
+                .remove_change(sequence_number)
+                .await;
+        }
+    }
+
     pub async fn dispose_w_timestamp(
         &mut self,
         serialized_key: Vec<u8>,
         timestamp: Time,
+    ) -> DdsResult<()> {
+        let instance_handle =
+            get_instance_handle_from_serialized_key(&serialized_key, self.type_support.as_ref())?;
+        self.dispose_instance_w_timestamp(instance_handle, serialized_key, timestamp)
+            .await
+    }
+
+    /// Disposes an instance whose handle was already obtained from the serialized key.
+    pub async fn dispose_instance_w_timestamp(
+        &mut self,
+        instance_handle: InstanceHandle,
+        serialized_key: Vec<u8>,
+        timestamp: Time,
     ) -> DdsResult<()> {
         if !self.enabled {
             return Err(DdsError::NotEnabled);
         }
+        if self.draining {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "DataWriter is draining",
+            )));
+        }
 
-        let has_key = {
-            let mut has_key = false;
-            for index in 0..self.type_support.get_member_count() {
-                if self
-                    .type_support
-                    .get_member_by_index(index)?
-                    .get_descriptor()?
-                    .is_key
-                {
-                    has_key = true;
-                    break;
-                }
-            }
-            has_key
-        };
-        if !has_key {
+        if !self.has_key {
             return Err(DdsError::IllegalOperation);
         }
 
-        let instance_handle =
-            get_instance_handle_from_serialized_key(&serialized_key, self.type_support.as_ref())?;
-        if !self.registered_instance_list.contains(&instance_handle) {
+        if !self.contains_instance(&instance_handle) {
             return Err(DdsError::BadParameter);
         }
 
@@ -362,6 +1202,8 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         {
             self.instance_publication_time.remove(i);
         }
+        self.last_published_sample_list
+            .retain(|x| x.instance != instance_handle);
 
         self.last_change_sequence_number += 1;
 
@@ -371,12 +1213,16 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
             sequence_number: self.last_change_sequence_number,
             source_timestamp: Some(timestamp.into()),
             instance_handle: Some(instance_handle.into()),
+            coherent_set: self.next_change_coherent_set(),
+            directed_write: Vec::new(),
+            original_writer_info: None,
             data_value: serialized_key.into(),
         };
         self.transport_writer
             .history_cache()
             .add_change(cache_change)
             .await;
+        self.flush();
 
         Ok(())
     }
@@ -385,33 +1231,29 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         &mut self,
         serialized_key: Vec<u8>,
         timestamp: Time,
+    ) -> DdsResult<()> {
+        let instance_handle =
+            get_instance_handle_from_serialized_key(&serialized_key, self.type_support.as_ref())?;
+        self.unregister_instance_w_timestamp(instance_handle, serialized_key, timestamp)
+            .await
+    }
+
+    /// Unregisters an instance whose handle was already obtained from the serialized key.
+    pub async fn unregister_instance_w_timestamp(
+        &mut self,
+        instance_handle: InstanceHandle,
+        serialized_key: Vec<u8>,
+        timestamp: Time,
     ) -> DdsResult<()> {
         if !self.enabled {
             return Err(DdsError::NotEnabled);
         }
 
-        let has_key = {
-            let mut has_key = false;
-            for index in 0..self.type_support.get_member_count() {
-                if self
-                    .type_support
-                    .get_member_by_index(index)?
-                    .get_descriptor()?
-                    .is_key
-                {
-                    has_key = true;
-                    break;
-                }
-            }
-            has_key
-        };
-        if !has_key {
+        if !self.has_key {
             return Err(DdsError::IllegalOperation);
         }
 
-        let instance_handle =
-            get_instance_handle_from_serialized_key(&serialized_key, self.type_support.as_ref())?;
-        if !self.registered_instance_list.contains(&instance_handle) {
+        if !self.contains_instance(&instance_handle) {
             return Err(DdsError::BadParameter);
         }
 
@@ -422,21 +1264,65 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         {
             self.instance_publication_time.remove(i);
         }
+        self.last_published_sample_list
+            .retain(|x| x.instance != instance_handle);
 
         self.last_change_sequence_number += 1;
 
+        let kind = if self
+            .qos
+            .writer_data_lifecycle
+            .autodispose_unregistered_instances
+        {
+            ChangeKind::NotAliveDisposedUnregistered
+        } else {
+            ChangeKind::NotAliveUnregistered
+        };
         let cache_change = CacheChange {
-            kind: ChangeKind::NotAliveDisposed,
+            kind,
             writer_guid: self.transport_writer().guid(),
             sequence_number: self.last_change_sequence_number,
             source_timestamp: Some(timestamp.into()),
             instance_handle: Some(instance_handle.into()),
+            coherent_set: self.next_change_coherent_set(),
+            directed_write: Vec::new(),
+            original_writer_info: None,
             data_value: serialized_key.into(),
         };
         self.transport_writer
             .history_cache()
             .add_change(cache_change)
             .await;
+        self.flush();
+
+        // The handle of the instance is no longer valid but its samples are kept until they are acknowledged
+        self.registered_instance_list
+            .retain(|x| x.instance != instance_handle);
+        self.reclaim_unregistered_instances().await;
+        self.unregistered_instance_list.push(UnregisteredInstance {
+            instance: instance_handle,
+            unregister_sequence_number: self.last_change_sequence_number,
+        });
+        Ok(())
+    }
+
+    /// Unregisters all the instances registered by the writer. The instances are also disposed
+    /// if the writer data lifecycle requires unregistered instances to be disposed.
+    pub async fn unregister_all_instances(&mut self, timestamp: Time) -> DdsResult<()> {
+        if !self.has_key {
+            return Ok(());
+        }
+        let instance_handles: Vec<InstanceHandle> = self
+            .registered_instance_list
+            .iter()
+            .map(|x| x.instance)
+            .collect();
+        for instance_handle in instance_handles {
+            if let Some(serialized_key) = self.registered_serialized_key(&instance_handle)? {
+                self.unregister_instance_w_timestamp(instance_handle, serialized_key, timestamp)
+                    .await?;
+            }
+        }
         Ok(())
     }
 
@@ -467,6 +1353,75 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
         self.publication_matched_status.total_count_change += 1;
     }
 
+    pub fn set_matched_subscription_locators(
+        &mut self,
+        subscription_handle: InstanceHandle,
+        locator_list: Vec<Locator>,
+    ) {
+        let previous_reader_locator_list = self.reader_locator_list();
+        match self
+            .matched_subscription_locators
+            .iter_mut()
+            .find(|x| x.subscription_handle == subscription_handle)
+        {
+            Some(x) => x.locator_list = locator_list,
+            None => self
+                .matched_subscription_locators
+                .push(MatchedSubscriptionLocators {
+                    subscription_handle,
+                    locator_list,
+                }),
+        }
+        self.update_reader_locators(&previous_reader_locator_list);
+    }
+
+    // A stateless writer sends each change once to the first locator of each matched subscription. The readers
+    // keep no state to discard the copies received through their other locators and the readers of a participant
+    // share the same locators so they are all reached with a single message.
+    fn reader_locator_list(&self) -> Vec<Locator> {
+        let mut reader_locator_list = Vec::new();
+        for locator in self
+            .matched_subscription_locators
+            .iter()
+            .filter_map(|x| x.locator_list.first())
+        {
+            if !reader_locator_list.contains(locator) {
+                reader_locator_list.push(*locator);
+            }
+        }
+        reader_locator_list
+    }
+
+    fn update_reader_locators(&mut self, previous_reader_locator_list: &[Locator]) {
+        let reader_locator_list = self.reader_locator_list();
+        let TransportWriterKind::Stateless(w) = &mut self.transport_writer else {
+            return;
+        };
+        for locator in &reader_locator_list {
+            if !previous_reader_locator_list.contains(locator) {
+                w.add_reader_locator(*locator);
+            }
+        }
+        for locator in previous_reader_locator_list {
+            if !reader_locator_list.contains(locator) {
+                w.remove_reader_locator(locator);
+            }
+        }
+    }
+
+    pub fn update_matched_subscription(
+        &mut self,
+        subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
+    ) {
+        if let Some(x) = self
+            .matched_subscription_list
+            .iter_mut()
+            .find(|x| x.key() == subscription_builtin_topic_data.key())
+        {
+            *x = subscription_builtin_topic_data;
+        }
+    }
+
     pub fn remove_matched_subscription(&mut self, subscription_handle: &InstanceHandle) {
         let Some(i) = self
             .matched_subscription_list
@@ -476,6 +1431,10 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
             return;
         };
         self.matched_subscription_list.remove(i);
+        let previous_reader_locator_list = self.reader_locator_list();
+        self.matched_subscription_locators
+            .retain(|x| &x.subscription_handle != subscription_handle);
+        self.update_reader_locators(&previous_reader_locator_list);
         self.publication_matched_status.current_count = self.matched_subscription_list.len() as i32;
         self.publication_matched_status.current_count_change -= 1;
     }
@@ -533,6 +1492,19 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
             .find(|x| subscription_handle.as_ref() == &x.key().value)
     }
 
+    pub fn get_matched_subscription_locators(
+        &self,
+        subscription_handle: &InstanceHandle,
+    ) -> Option<&[Locator]> {
+        self.get_matched_subscription_data(subscription_handle)?;
+        Some(
+            self.matched_subscription_locators
+                .iter()
+                .find(|x| &x.subscription_handle == subscription_handle)
+                .map_or(&[], |x| x.locator_list.as_slice()),
+        )
+    }
+
     pub fn get_publication_matched_status(&mut self) -> PublicationMatchedStatus {
         let status = self.publication_matched_status.clone();
         self.publication_matched_status.current_count_change = 0;
@@ -576,7 +1548,10 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
     }
 
     pub fn listener_mask(&self) -> &[StatusKind] {
-        &self.listener_mask
+        match self.listener_sender {
+            Some(_) => &self.listener_mask,
+            None => &[],
+        }
     }
 
     pub fn are_all_changes_acknowledged(&self) -> bool {
@@ -597,4 +1572,62 @@ impl<R: DdsRuntime> DataWriterEntity<R> {
 
         status
     }
+
+    pub fn assert_liveliness(&mut self, timestamp: Time) {
+        self.last_liveliness_assertion = Some(timestamp);
+        self.alive = true;
+    }
+
+    /// Checks whether a writer with manual liveliness has failed to assert its liveliness within
+    /// the lease duration. Returns true only when a previously alive writer becomes not alive.
+    pub fn check_liveliness_lost(&mut self, now: Time) -> bool {
+        if self.qos.liveliness.kind == LivelinessQosPolicyKind::Automatic {
+            return false;
+        }
+        let DurationKind::Finite(lease_duration) = self.qos.liveliness.lease_duration else {
+            return false;
+        };
+        match self.last_liveliness_assertion {
+            Some(t) if self.alive && now - t >= lease_duration => {
+                self.alive = false;
+                self.liveliness_lost_status.total_count += 1;
+                self.liveliness_lost_status.total_count_change += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub async fn get_liveliness_lost_status(&mut self) -> LivelinessLostStatus {
+        let status = self.liveliness_lost_status.clone();
+        self.liveliness_lost_status.total_count_change = 0;
+        self.status_condition
+            .remove_state(StatusKind::LivelinessLost)
+            .await;
+
+        status
+    }
+}
+
+/// Name of the flow controller of the transport pacing the writers of a topic with a bandwidth quota.
+pub fn bandwidth_quota_flow_controller_name(topic_name: &str) -> String {
+    format!("dust_dds::bandwidth_quota::{topic_name}")
+}
+
+/// Representation used to encode the samples of a writer with the given QoS. The writer offers a single representation.
+pub fn offered_representation(qos: &DataWriterQos) -> DataRepresentationId {
+    qos.representation
+        .value
+        .first()
+        .copied()
+        .unwrap_or(XCDR_DATA_REPRESENTATION)
+}
+
+fn type_has_key(type_support: &(dyn DynamicType + Send + Sync)) -> bool {
+    (0..type_support.get_member_count()).any(|index| {
+        type_support
+            .get_member_by_index(index)
+            .and_then(|member| member.get_descriptor())
+            .is_ok_and(|descriptor| descriptor.is_key)
+    })
 }
@@ -1,38 +1,60 @@
 use crate::{
-    builtin_topics::TopicBuiltinTopicData,
+    builtin_topics::{ParticipantBuiltinTopicData, TopicBuiltinTopicData},
     dcps::{
         data_representation_builtin_endpoints::{
             discovered_reader_data::DiscoveredReaderData,
-            discovered_writer_data::DiscoveredWriterData,
+            discovered_writer_data::DiscoveredWriterData, parameter_id_values::ParameterId,
+            participant_message_data::DCPS_PARTICIPANT_MESSAGE,
             spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
         },
         publisher::PublisherEntity,
         subscriber::SubscriberEntity,
         topic::TopicEntity,
     },
+    domain::{
+        bandwidth_statistics::{
+            BandwidthStatistics, ParticipantBandwidthStatistics, TopicBandwidthStatistics,
+        },
+        discovery_conformance::DiscoveredEntityKind,
+        discovery_history::RemovedDiscoveredEntity,
+        domain_participant::EndpointAuthorizationHook,
+        memory_usage::MemoryUsageStatistics,
+    },
     infrastructure::{
         domain::DomainId,
         error::DdsResult,
         instance::InstanceHandle,
         qos::{DomainParticipantQos, PublisherQos, SubscriberQos, TopicQos},
+        qos_policy::Length,
         status::StatusKind,
+        time::{Duration, Time},
     },
     runtime::DdsRuntime,
+    transport::types::{Guid, GuidPrefix, ENTITYID_PARTICIPANT},
 };
-use alloc::{string::String, vec::Vec};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::mem::size_of;
 
 use super::{
     builtin_topics::{DCPS_PARTICIPANT, DCPS_PUBLICATION, DCPS_SUBSCRIPTION, DCPS_TOPIC},
     listeners::domain_participant_listener::ListenerMail,
 };
 
-pub const BUILT_IN_TOPIC_NAME_LIST: [&str; 4] = [
+pub const BUILT_IN_TOPIC_NAME_LIST: [&str; 5] = [
     DCPS_PARTICIPANT,
     DCPS_TOPIC,
     DCPS_PUBLICATION,
     DCPS_SUBSCRIPTION,
+    DCPS_PARTICIPANT_MESSAGE,
 ];
 
+/// Parameters present in the last discovery data received for a remote entity
+pub struct DiscoveredParameterIdList {
+    pub entity_kind: DiscoveredEntityKind,
+    pub entity_handle: InstanceHandle,
+    pub parameter_id_list: Vec<ParameterId>,
+}
+
 pub struct DomainParticipantEntity<R: DdsRuntime> {
     domain_id: DomainId,
     domain_tag: String,
@@ -50,6 +72,12 @@ pub struct DomainParticipantEntity<R: DdsRuntime> {
     discovered_topic_list: Vec<TopicBuiltinTopicData>,
     discovered_reader_list: Vec<DiscoveredReaderData>,
     discovered_writer_list: Vec<DiscoveredWriterData>,
+    discovered_parameter_id_list: Vec<DiscoveredParameterIdList>,
+    removed_entity_list: Vec<RemovedDiscoveredEntity>,
+    discovery_history_retention: Duration,
+    lease_duration: Duration,
+    topic_bandwidth_statistics: Vec<TopicBandwidthStatistics>,
+    participant_bandwidth_statistics: Vec<ParticipantBandwidthStatistics>,
     enabled: bool,
     ignored_participants: Vec<InstanceHandle>,
     ignored_publications: Vec<InstanceHandle>,
@@ -57,6 +85,8 @@ pub struct DomainParticipantEntity<R: DdsRuntime> {
     _ignored_topic_list: Vec<InstanceHandle>,
     listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     listener_mask: Vec<StatusKind>,
+    max_samples: Length,
+    endpoint_authorization_hook: Option<Arc<dyn EndpointAuthorizationHook>>,
 }
 
 impl<R: DdsRuntime> DomainParticipantEntity<R> {
@@ -71,6 +101,9 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         builtin_subscriber: SubscriberEntity<R>,
         topic_list: Vec<TopicEntity<R>>,
         domain_tag: String,
+        max_samples: Length,
+        discovery_history_retention: Duration,
+        lease_duration: Duration,
     ) -> Self {
         Self {
             domain_id,
@@ -88,6 +121,12 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
             discovered_topic_list: Vec::new(),
             discovered_reader_list: Vec::new(),
             discovered_writer_list: Vec::new(),
+            discovered_parameter_id_list: Vec::new(),
+            removed_entity_list: Vec::new(),
+            discovery_history_retention,
+            lease_duration,
+            topic_bandwidth_statistics: Vec::new(),
+            participant_bandwidth_statistics: Vec::new(),
             enabled: false,
             ignored_participants: Vec::new(),
             ignored_publications: Vec::new(),
@@ -96,6 +135,8 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
             listener_sender,
             listener_mask,
             domain_tag,
+            max_samples,
+            endpoint_authorization_hook: None,
         }
     }
 
@@ -107,6 +148,26 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         self.instance_handle
     }
 
+    pub fn max_samples(&self) -> Length {
+        self.max_samples
+    }
+
+    pub fn reserved_samples(&self) -> usize {
+        let writer_samples: usize = self
+            .user_defined_publisher_list
+            .iter()
+            .flat_map(|p| p.data_writer_list())
+            .map(|dw| dw.reserved_samples())
+            .sum();
+        let reader_samples: usize = self
+            .user_defined_subscriber_list
+            .iter()
+            .flat_map(|s| s.data_reader_list())
+            .map(|dr| dr.reserved_samples())
+            .sum();
+        writer_samples + reader_samples
+    }
+
     pub fn builtin_subscriber(&self) -> &SubscriberEntity<R> {
         &self.builtin_subscriber
     }
@@ -122,17 +183,34 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         }
     }
 
-    pub fn remove_discovered_writer(&mut self, discovered_writer_handle: &InstanceHandle) {
+    pub fn remove_discovered_writer(
+        &mut self,
+        discovered_writer_handle: &InstanceHandle,
+        removal_time: Time,
+    ) {
+        let discovered_writer_count = self.discovered_writer_list.len();
         self.discovered_writer_list
             .retain(|x| &x.dds_publication_data.key().value != discovered_writer_handle.as_ref());
+        self.discovered_parameter_id_list
+            .retain(|x| &x.entity_handle != discovered_writer_handle);
+        if self.discovered_writer_list.len() != discovered_writer_count {
+            self.add_removed_entity(
+                DiscoveredEntityKind::Publication,
+                *discovered_writer_handle,
+                removal_time,
+            );
+        }
     }
 
     pub fn qos(&self) -> &DomainParticipantQos {
         &self.qos
     }
 
-    pub fn set_qos(&mut self, qos: DomainParticipantQos) {
+    pub fn set_qos(&mut self, qos: DomainParticipantQos) -> DdsResult<()> {
+        // The GUID prefix is given to the participant when it is created, before it is enabled
+        self.qos.check_immutability(&qos)?;
         self.qos = qos;
+        Ok(())
     }
 
     pub fn enabled(&self) -> bool {
@@ -217,6 +295,10 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         &mut self,
         discovered_participant_data: SpdpDiscoveredParticipantData,
     ) {
+        self.removed_entity_list.retain(|x| {
+            x.entity_handle.as_ref()
+                != &discovered_participant_data.dds_participant_data.key().value
+        });
         match self.discovered_participant_list.iter_mut().find(|p| {
             p.dds_participant_data.key() == discovered_participant_data.dds_participant_data.key()
         }) {
@@ -230,13 +312,214 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
     pub fn remove_discovered_participant(
         &mut self,
         discovered_participant_handle: &InstanceHandle,
+        removal_time: Time,
     ) {
+        let discovered_participant_count = self.discovered_participant_list.len();
         self.discovered_participant_list.retain(|p| {
             &p.dds_participant_data.key().value != discovered_participant_handle.as_ref()
         });
+        // The parameters of all the entities of the participant are removed with it
+        self.discovered_parameter_id_list.retain(|x| {
+            x.entity_handle.as_ref()[..12] != discovered_participant_handle.as_ref()[..12]
+        });
+        self.participant_bandwidth_statistics
+            .retain(|x| &x.participant_handle != discovered_participant_handle);
+        if self.discovered_participant_list.len() != discovered_participant_count {
+            self.add_removed_entity(
+                DiscoveredEntityKind::Participant,
+                *discovered_participant_handle,
+                removal_time,
+            );
+        }
+    }
+
+    fn add_removed_entity(
+        &mut self,
+        entity_kind: DiscoveredEntityKind,
+        entity_handle: InstanceHandle,
+        removal_time: Time,
+    ) {
+        self.removed_entity_list
+            .retain(|x| x.entity_handle != entity_handle);
+        if self.discovery_history_retention > Duration::new(0, 0) {
+            self.removed_entity_list.push(RemovedDiscoveredEntity {
+                entity_kind,
+                entity_handle,
+                removal_time,
+            });
+        }
+        self.prune_removed_entities(removal_time);
+    }
+
+    fn prune_removed_entities(&mut self, now: Time) {
+        let retention = self.discovery_history_retention;
+        self.removed_entity_list
+            .retain(|x| now - x.removal_time < retention);
+    }
+
+    pub fn get_removed_discovered_entities(&mut self, now: Time) -> Vec<RemovedDiscoveredEntity> {
+        self.prune_removed_entities(now);
+        self.removed_entity_list.clone()
+    }
+
+    pub fn add_discovered_parameter_id_list(
+        &mut self,
+        discovered_parameter_id_list: DiscoveredParameterIdList,
+    ) {
+        match self
+            .discovered_parameter_id_list
+            .iter_mut()
+            .find(|x| x.entity_handle == discovered_parameter_id_list.entity_handle)
+        {
+            Some(x) => *x = discovered_parameter_id_list,
+            None => self
+                .discovered_parameter_id_list
+                .push(discovered_parameter_id_list),
+        }
+    }
+
+    pub fn discovered_parameter_id_list(&self) -> impl Iterator<Item = &DiscoveredParameterIdList> {
+        self.discovered_parameter_id_list.iter()
+    }
+
+    fn get_mut_topic_bandwidth_statistics(
+        &mut self,
+        topic_name: &str,
+    ) -> &mut TopicBandwidthStatistics {
+        let index = match self
+            .topic_bandwidth_statistics
+            .iter()
+            .position(|x| x.topic_name == topic_name)
+        {
+            Some(index) => index,
+            None => {
+                self.topic_bandwidth_statistics
+                    .push(TopicBandwidthStatistics {
+                        topic_name: String::from(topic_name),
+                        bytes_sent: 0,
+                        bytes_received: 0,
+                    });
+                self.topic_bandwidth_statistics.len() - 1
+            }
+        };
+        &mut self.topic_bandwidth_statistics[index]
+    }
+
+    // Only the discovered participants are accounted so that the statistics of a removed participant are not
+    // created again by the data exchanged with it which is accounted after its removal
+    fn get_mut_participant_bandwidth_statistics(
+        &mut self,
+        participant_handle: InstanceHandle,
+    ) -> Option<&mut ParticipantBandwidthStatistics> {
+        if !self
+            .discovered_participant_list
+            .iter()
+            .any(|p| &p.dds_participant_data.key().value == participant_handle.as_ref())
+        {
+            return None;
+        }
+        let index = match self
+            .participant_bandwidth_statistics
+            .iter()
+            .position(|x| x.participant_handle == participant_handle)
+        {
+            Some(index) => index,
+            None => {
+                self.participant_bandwidth_statistics
+                    .push(ParticipantBandwidthStatistics {
+                        participant_handle,
+                        bytes_sent: 0,
+                        bytes_received: 0,
+                    });
+                self.participant_bandwidth_statistics.len() - 1
+            }
+        };
+        Some(&mut self.participant_bandwidth_statistics[index])
+    }
+
+    pub fn add_bytes_sent(
+        &mut self,
+        topic_name: &str,
+        participant_handle: InstanceHandle,
+        bytes: u64,
+    ) {
+        self.get_mut_topic_bandwidth_statistics(topic_name)
+            .bytes_sent += bytes;
+        if let Some(s) = self.get_mut_participant_bandwidth_statistics(participant_handle) {
+            s.bytes_sent += bytes;
+        }
+    }
+
+    pub fn add_bytes_received(
+        &mut self,
+        topic_name: &str,
+        participant_handle: InstanceHandle,
+        bytes: u64,
+    ) {
+        self.get_mut_topic_bandwidth_statistics(topic_name)
+            .bytes_received += bytes;
+        if let Some(s) = self.get_mut_participant_bandwidth_statistics(participant_handle) {
+            s.bytes_received += bytes;
+        }
+    }
+
+    pub fn bandwidth_statistics(&self) -> BandwidthStatistics {
+        BandwidthStatistics {
+            topics: self.topic_bandwidth_statistics.clone(),
+            participants: self.participant_bandwidth_statistics.clone(),
+        }
+    }
+
+    pub fn memory_usage_statistics(&self) -> MemoryUsageStatistics {
+        let data_writers = self
+            .user_defined_publisher_list
+            .iter()
+            .flat_map(|p| p.data_writer_list())
+            .map(|dw| dw.memory_usage())
+            .collect();
+        let data_readers = self
+            .user_defined_subscriber_list
+            .iter()
+            .flat_map(|s| s.data_reader_list())
+            .map(|dr| dr.memory_usage())
+            .collect();
+
+        // The serialized discovery data is held by the built-in readers and the data decoded from it by the
+        // discovered entity lists
+        let builtin_reader_bytes: u64 = self
+            .builtin_subscriber
+            .data_reader_list()
+            .map(|dr| {
+                let memory_usage = dr.memory_usage();
+                memory_usage.cache_bytes + memory_usage.fragment_buffer_bytes
+            })
+            .sum();
+        let discovered_entity_bytes = self.discovered_participant_list.len()
+            * size_of::<SpdpDiscoveredParticipantData>()
+            + self.discovered_topic_list.len() * size_of::<TopicBuiltinTopicData>()
+            + self.discovered_reader_list.len() * size_of::<DiscoveredReaderData>()
+            + self.discovered_writer_list.len() * size_of::<DiscoveredWriterData>()
+            + self
+                .discovered_parameter_id_list
+                .iter()
+                .map(|x| {
+                    size_of::<DiscoveredParameterIdList>()
+                        + x.parameter_id_list.len() * size_of::<ParameterId>()
+                })
+                .sum::<usize>()
+            + self.removed_entity_list.len() * size_of::<RemovedDiscoveredEntity>();
+
+        MemoryUsageStatistics {
+            data_writers,
+            data_readers,
+            discovery_bytes: builtin_reader_bytes + discovered_entity_bytes as u64,
+        }
     }
 
     pub fn add_discovered_reader(&mut self, discovered_reader_data: DiscoveredReaderData) {
+        self.removed_entity_list.retain(|x| {
+            x.entity_handle.as_ref() != &discovered_reader_data.dds_subscription_data.key().value
+        });
         match self.discovered_reader_list.iter_mut().find(|x| {
             x.dds_subscription_data.key() == discovered_reader_data.dds_subscription_data.key()
         }) {
@@ -245,16 +528,41 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         }
     }
 
-    pub fn remove_discovered_reader(&mut self, discovered_reader_handle: &InstanceHandle) {
+    pub fn remove_discovered_reader(
+        &mut self,
+        discovered_reader_handle: &InstanceHandle,
+        removal_time: Time,
+    ) {
+        let discovered_reader_count = self.discovered_reader_list.len();
         self.discovered_reader_list
             .retain(|x| &x.dds_subscription_data.key().value != discovered_reader_handle.as_ref());
+        self.discovered_parameter_id_list
+            .retain(|x| &x.entity_handle != discovered_reader_handle);
+        if self.discovered_reader_list.len() != discovered_reader_count {
+            self.add_removed_entity(
+                DiscoveredEntityKind::Subscription,
+                *discovered_reader_handle,
+                removal_time,
+            );
+        }
     }
 
     pub fn discovered_reader_data_list(&self) -> impl Iterator<Item = &DiscoveredReaderData> {
         self.discovered_reader_list.iter()
     }
 
+    /// Number of discovered readers of the topic, which a new writer of the topic can be matched with.
+    pub fn discovered_reader_count(&self, topic_name: &str) -> usize {
+        self.discovered_reader_list
+            .iter()
+            .filter(|x| x.dds_subscription_data.topic_name() == topic_name)
+            .count()
+    }
+
     pub fn add_discovered_writer(&mut self, discovered_writer_data: DiscoveredWriterData) {
+        self.removed_entity_list.retain(|x| {
+            x.entity_handle.as_ref() != &discovered_writer_data.dds_publication_data.key().value
+        });
         match self.discovered_writer_list.iter_mut().find(|x| {
             x.dds_publication_data.key() == discovered_writer_data.dds_publication_data.key()
         }) {
@@ -269,6 +577,14 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         self.discovered_writer_list.iter()
     }
 
+    /// Number of discovered writers of the topic, which a new reader of the topic can be matched with.
+    pub fn discovered_writer_count(&self, topic_name: &str) -> usize {
+        self.discovered_writer_list
+            .iter()
+            .filter(|x| x.dds_publication_data.topic_name() == topic_name)
+            .count()
+    }
+
     pub fn default_subscriber_qos(&self) -> &SubscriberQos {
         &self.default_subscriber_qos
     }
@@ -285,7 +601,15 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         self.default_publisher_qos = default_publisher_qos;
     }
 
+    pub fn is_builtin_subscriber(&self, handle: InstanceHandle) -> bool {
+        // Built-in subscriber is identified by the handle of the participant itself
+        handle == self.instance_handle
+    }
+
     pub fn get_subscriber(&self, handle: InstanceHandle) -> Option<&SubscriberEntity<R>> {
+        if self.is_builtin_subscriber(handle) {
+            return Some(&self.builtin_subscriber);
+        }
         self.user_defined_subscriber_list
             .iter()
             .find(|x| x.instance_handle() == handle)
@@ -295,6 +619,9 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         &mut self,
         handle: InstanceHandle,
     ) -> Option<&mut SubscriberEntity<R>> {
+        if self.is_builtin_subscriber(handle) {
+            return Some(&mut self.builtin_subscriber);
+        }
         self.user_defined_subscriber_list
             .iter_mut()
             .find(|x| x.instance_handle() == handle)
@@ -412,7 +739,10 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
     }
 
     pub fn listener_mask(&self) -> &[StatusKind] {
-        &self.listener_mask
+        match self.listener_sender {
+            Some(_) => &self.listener_mask,
+            None => &[],
+        }
     }
 
     pub fn listener(&self) -> &Option<R::ChannelSender<ListenerMail<R>>> {
@@ -432,10 +762,68 @@ impl<R: DdsRuntime> DomainParticipantEntity<R> {
         self.domain_id
     }
 
+    pub fn set_endpoint_authorization_hook(
+        &mut self,
+        hook: Option<Arc<dyn EndpointAuthorizationHook>>,
+    ) {
+        self.endpoint_authorization_hook = hook;
+    }
+
+    pub fn is_publication_authorized(&self, discovered_writer_data: &DiscoveredWriterData) -> bool {
+        let guid_prefix = discovered_writer_data
+            .writer_proxy
+            .remote_writer_guid
+            .prefix();
+        match &self.endpoint_authorization_hook {
+            Some(hook) if !self.is_local_participant(guid_prefix) => hook.authorize_publication(
+                self.discovered_participant_data(guid_prefix),
+                &discovered_writer_data.dds_publication_data,
+            ),
+            _ => true,
+        }
+    }
+
+    pub fn is_subscription_authorized(
+        &self,
+        discovered_reader_data: &DiscoveredReaderData,
+    ) -> bool {
+        let guid_prefix = discovered_reader_data
+            .reader_proxy
+            .remote_reader_guid
+            .prefix();
+        match &self.endpoint_authorization_hook {
+            Some(hook) if !self.is_local_participant(guid_prefix) => hook.authorize_subscription(
+                self.discovered_participant_data(guid_prefix),
+                &discovered_reader_data.dds_subscription_data,
+            ),
+            _ => true,
+        }
+    }
+
+    // The endpoints of the participant itself are also discovered but are never subject to the authorization
+    fn is_local_participant(&self, guid_prefix: GuidPrefix) -> bool {
+        InstanceHandle::new(Guid::new(guid_prefix, ENTITYID_PARTICIPANT).into())
+            == self.instance_handle
+    }
+
+    fn discovered_participant_data(
+        &self,
+        guid_prefix: GuidPrefix,
+    ) -> Option<&ParticipantBuiltinTopicData> {
+        self.discovered_participant_list
+            .iter()
+            .find(|p| p.participant_proxy.guid_prefix == guid_prefix)
+            .map(|p| &p.dds_participant_data)
+    }
+
     pub fn domain_tag(&self) -> &str {
         &self.domain_tag
     }
 
+    pub fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+
     pub fn discovered_participant_list(
         &self,
     ) -> impl Iterator<Item = &SpdpDiscoveredParticipantData> {
@@ -1,6 +1,7 @@
 use super::{
     domain_participant_actor_mail::{DomainParticipantMail, EventServiceMail, MessageServiceMail},
     handle::InstanceHandleCounter,
+    task_monitor::{task_watchdog, TaskMonitor},
 };
 use crate::{
     builtin_topics::{
@@ -8,33 +9,63 @@ use crate::{
         SubscriptionBuiltinTopicData, TopicBuiltinTopicData, DCPS_PARTICIPANT, DCPS_PUBLICATION,
         DCPS_SUBSCRIPTION, DCPS_TOPIC,
     },
+    configuration::EntityKeyAllocation,
     dcps::{
         actor::{Actor, ActorAddress},
-        data_reader::{AddChangeResult, DataReaderEntity, TransportReaderKind},
+        data_reader::{
+            AddChangeResult, DataReaderEntity, DataReaderResources, DeserializationValidator,
+            LoanedSampleList, ReadAheadValue, SampleData, TransportReaderKind,
+            WriterLivelinessCheck,
+        },
         data_representation_builtin_endpoints::{
             discovered_reader_data::{DiscoveredReaderData, ReaderProxy},
             discovered_writer_data::{DiscoveredWriterData, WriterProxy},
+            parameter_id_values::{
+                PARTICIPANT_OPTIONAL_PARAMETER_LIST, PARTICIPANT_QOS_PARAMETER_LIST,
+                PUBLICATION_OPTIONAL_PARAMETER_LIST, PUBLICATION_QOS_PARAMETER_LIST,
+                SUBSCRIPTION_OPTIONAL_PARAMETER_LIST, SUBSCRIPTION_QOS_PARAMETER_LIST,
+                TOPIC_OPTIONAL_PARAMETER_LIST, TOPIC_QOS_PARAMETER_LIST,
+            },
+            participant_message_data::{
+                ParticipantMessageData, ParticipantMessageDataKind, DCPS_PARTICIPANT_MESSAGE,
+                PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE,
+                PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            },
+            payload_serializer_deserializer::parameter_list_deserializer::ParameterListCdrDeserializer,
             spdp_discovered_participant_data::{
                 BuiltinEndpointQos, BuiltinEndpointSet, ParticipantProxy,
                 SpdpDiscoveredParticipantData,
             },
         },
-        data_writer::{DataWriterEntity, TransportWriterKind},
-        domain_participant::{DomainParticipantEntity, BUILT_IN_TOPIC_NAME_LIST},
+        data_writer::{
+            bandwidth_quota_flow_controller_name, offered_representation, DataWriterEntity,
+            DataWriterResources, TransportWriterKind,
+        },
+        domain_participant::{
+            DiscoveredParameterIdList, DomainParticipantEntity, BUILT_IN_TOPIC_NAME_LIST,
+        },
         domain_participant_factory_actor::{
-            DdsTransportParticipant, ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+            DdsTransportParticipant, ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR, ENTITYID_SEDP_BUILTIN_TOPICS_ANNOUNCER,
             ENTITYID_SEDP_BUILTIN_TOPICS_DETECTOR,
         },
         listeners::domain_participant_listener::ListenerMail,
+        multitopic::{
+            validate_multitopic_types, MultiTopicDescription, SampleMembers, SubscriptionExpression,
+        },
         publisher::PublisherEntity,
         status_condition_actor::{StatusConditionActor, StatusConditionMail},
         subscriber::SubscriberEntity,
         topic::TopicEntity,
-        xtypes_glue::key_and_instance_handle::{
-            get_instance_handle_from_serialized_foo, get_serialized_key_from_serialized_foo,
+        xtypes_glue::{
+            key_and_instance_handle::{
+                get_instance_handle_from_serialized_foo, get_serialized_key_from_serialized_foo,
+            },
+            sample_members::{deserialize_sample_members, serialize_sample_members},
         },
     },
     dds_async::{
@@ -42,6 +73,16 @@ use crate::{
         domain_participant::DomainParticipantAsync, publisher::PublisherAsync,
         subscriber::SubscriberAsync, topic::TopicAsync,
     },
+    domain::{
+        bandwidth_statistics::{BandwidthQuota, BandwidthStatistics},
+        discovery_conformance::{
+            BuiltinEndpointConformance, BuiltinEndpointKind, DefaultedQosPolicy,
+            DiscoveredEntityKind, DiscoveryConformanceReport, MissingParameter,
+        },
+        discovery_history::RemovedDiscoveredEntity,
+        domain_participant::EndpointAuthorizationHook,
+        memory_usage::MemoryUsageStatistics,
+    },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
@@ -50,30 +91,49 @@ use crate::{
             SubscriberQos, TopicQos,
         },
         qos_policy::{
-            DurabilityQosPolicyKind, HistoryQosPolicy, LifespanQosPolicy, QosPolicyId,
-            ReliabilityQosPolicyKind, ResourceLimitsQosPolicy, TransportPriorityQosPolicy,
-            DATA_REPRESENTATION_QOS_POLICY_ID, DEADLINE_QOS_POLICY_ID,
-            DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID, LATENCYBUDGET_QOS_POLICY_ID,
-            LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID, PRESENTATION_QOS_POLICY_ID,
-            RELIABILITY_QOS_POLICY_ID, XCDR_DATA_REPRESENTATION,
+            DataRepresentationId, DurabilityQosPolicyKind, EndpointBehaviorQosPolicyKind,
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, LifespanQosPolicy,
+            LivelinessQosPolicyKind, QosPolicyId, ReliabilityQosPolicyKind,
+            ResourceLimitsQosPolicy, TransportPriorityQosPolicy, DATA_REPRESENTATION_QOS_POLICY_ID,
+            DEADLINE_QOS_POLICY_ID, DESTINATIONORDER_QOS_POLICY_ID, DURABILITY_QOS_POLICY_ID,
+            LATENCYBUDGET_QOS_POLICY_ID, LIVELINESS_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID,
+            PRESENTATION_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID, XCDR_DATA_REPRESENTATION,
+        },
+        sample_info::{
+            InstanceStateKind, SampleInfo, SampleStateKind, SequenceNumber, ViewStateKind,
         },
-        sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
         status::{
-            InconsistentTopicStatus, OfferedDeadlineMissedStatus, PublicationMatchedStatus,
-            StatusKind, SubscriptionMatchedStatus,
+            InconsistentTopicStatus, LivelinessChangedStatus, LivelinessLostStatus,
+            OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
+            PublicationThrottledStatus, RequestedDeadlineMissedStatus,
+            RequestedIncompatibleQosStatus, SampleLostStatus, SampleRejectedStatus, StatusKind,
+            SubscriptionMatchedStatus, WriterRestartedStatus,
         },
         time::{Duration, DurationKind, Time},
-        type_support::{DdsDeserialize, DdsSerialize},
+        type_support::{
+            clear_representation_options, validate_representation_options, DdsDeserialize,
+            DdsSerialize,
+        },
+    },
+    publication::data_writer::{
+        DuplicateSuppressionMode, KeepLastReplacementPolicy, OriginalWriterInfo,
+        PublicationThrottling,
+    },
+    runtime::{ChannelSend, Clock, DdsRuntime, OneshotReceive, OneshotSend, Spawner, Timer},
+    subscription::data_reader::{
+        DeserializationErrorPolicy, HeadOfLineStatus, IncompleteCoherentSetPolicy,
+        InstanceFreshnessObserver, InstanceFreshnessStatus, InstanceStatistics,
     },
-    runtime::{ChannelSend, Clock, DdsRuntime, OneshotReceive, Spawner, Timer},
     transport::{
         self,
-        history_cache::{CacheChange, HistoryCache},
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
+        history_cache::{self, CacheChange, HistoryCache},
         types::{
-            ChangeKind, DurabilityKind, EntityId, Guid, ReliabilityKind, TopicKind,
-            ENTITYID_UNKNOWN, USER_DEFINED_READER_NO_KEY, USER_DEFINED_READER_WITH_KEY,
-            USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY,
+            ChangeKind, DurabilityKind, EntityId, Guid, GuidPrefix, Locator, ReliabilityKind,
+            TopicKind, ENTITYID_PARTICIPANT, ENTITYID_UNKNOWN, USER_DEFINED_READER_NO_KEY,
+            USER_DEFINED_READER_WITH_KEY, USER_DEFINED_WRITER_NO_KEY, USER_DEFINED_WRITER_WITH_KEY,
         },
+        writer::ChangeLifecycleObserver,
     },
     xtypes::dynamic_type::DynamicType,
 };
@@ -86,6 +146,7 @@ use alloc::{
     vec::Vec,
 };
 use core::{
+    any::Any,
     future::{poll_fn, Future},
     pin::{pin, Pin},
     task::Poll,
@@ -113,7 +174,14 @@ pub struct DomainParticipantActor<R: DdsRuntime> {
     pub transport: DdsTransportParticipant,
     pub instance_handle_counter: InstanceHandleCounter,
     pub entity_counter: u16,
+    pub entity_key_allocation: EntityKeyAllocation,
+    pub entity_key_random_state: u64,
+    pub encapsulation_padding_encoding: bool,
+    pub strict_encapsulation_options: bool,
     pub domain_participant: DomainParticipantEntity<R>,
+    pub task_monitor: Arc<TaskMonitor>,
+    pub automatic_liveliness_assertion_id: u64,
+    pub automatic_liveliness_assertion_period: Option<core::time::Duration>,
     pub clock_handle: R::ClockHandle,
     pub timer_handle: R::TimerHandle,
     pub spawner_handle: R::SpawnerHandle,
@@ -123,19 +191,46 @@ impl<R> DomainParticipantActor<R>
 where
     R: DdsRuntime,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         domain_participant: DomainParticipantEntity<R>,
         transport: DdsTransportParticipant,
         instance_handle_counter: InstanceHandleCounter,
+        entity_key_allocation: EntityKeyAllocation,
+        encapsulation_padding_encoding: bool,
+        strict_encapsulation_options: bool,
+        task_monitor: Arc<TaskMonitor>,
         clock_handle: R::ClockHandle,
         timer_handle: R::TimerHandle,
         spawner_handle: R::SpawnerHandle,
     ) -> Self {
+        // Seed the random entity keys with the app and instance id of the participant and the creation time
+        // so that different participants generate different sequences
+        let guid_prefix = transport.guid().prefix();
+        let now = clock_handle.now();
+        let entity_key_random_state = (u64::from_ne_bytes([
+            guid_prefix[4],
+            guid_prefix[5],
+            guid_prefix[6],
+            guid_prefix[7],
+            guid_prefix[8],
+            guid_prefix[9],
+            guid_prefix[10],
+            guid_prefix[11],
+        ]) ^ ((now.sec() as u64) << 32 | now.nanosec() as u64))
+            | 1;
         Self {
             transport,
             instance_handle_counter,
             entity_counter: 0,
+            entity_key_allocation,
+            entity_key_random_state,
+            encapsulation_padding_encoding,
+            strict_encapsulation_options,
             domain_participant,
+            task_monitor,
+            automatic_liveliness_assertion_id: 0,
+            automatic_liveliness_assertion_period: None,
             clock_handle,
             timer_handle,
             spawner_handle,
@@ -154,12 +249,42 @@ where
                 .address(),
             self.domain_participant.domain_id(),
             self.domain_participant.instance_handle(),
+            self.task_monitor.clone(),
             self.spawner_handle.clone(),
             self.clock_handle.clone(),
             self.timer_handle.clone(),
         )
     }
 
+    /// Starts the watchdog supervising the tasks of the participant, replacing the previous one so that the stalled
+    /// tasks are notified to the current listener of the participant.
+    pub fn start_task_watchdog(
+        &self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        if !self.task_monitor.is_enabled() {
+            return;
+        }
+        let listener_sender = if self
+            .domain_participant
+            .listener_mask()
+            .contains(&StatusKind::TaskStalled)
+        {
+            self.domain_participant.listener().clone()
+        } else {
+            None
+        };
+        self.spawner_handle.spawn(task_watchdog::<R>(
+            participant_address,
+            self.spawner_handle.clone(),
+            self.timer_handle.clone(),
+            self.clock_handle.clone(),
+            self.task_monitor.clone(),
+            listener_sender,
+            self.task_monitor.restart_watchdog(),
+        ));
+    }
+
     pub fn get_subscriber_async(
         &self,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
@@ -223,6 +348,7 @@ where
 
         Ok(DataWriterAsync::new(
             data_writer_handle,
+            offered_representation(data_writer.qos()),
             data_writer.status_condition().address(),
             self.get_publisher_async(participant_address.clone(), publisher_handle)?,
             self.get_topic_async(participant_address, String::from(data_writer.topic_name()))?,
@@ -259,7 +385,7 @@ where
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn set_topic_qos(
+    pub async fn set_topic_qos(
         &mut self,
         topic_name: String,
         topic_qos: QosKind<TopicQos>,
@@ -272,7 +398,39 @@ where
             return Err(DdsError::AlreadyDeleted);
         };
 
-        topic.set_qos(qos)
+        topic.set_qos(qos)?;
+
+        // The topic data is propagated as part of the topic and of the endpoint discovery data
+        if topic.enabled() {
+            self.announce_topic(topic_name.clone()).await;
+        }
+        let data_writer_list: Vec<_> = self
+            .domain_participant
+            .publisher_list()
+            .flat_map(|p| {
+                p.data_writer_list()
+                    .filter(|dw| dw.enabled() && dw.topic_name() == topic_name)
+                    .map(|dw| (p.instance_handle(), dw.instance_handle()))
+            })
+            .collect();
+        for (publisher_handle, data_writer_handle) in data_writer_list {
+            self.announce_data_writer(publisher_handle, data_writer_handle)
+                .await;
+        }
+        let data_reader_list: Vec<_> = self
+            .domain_participant
+            .subscriber_list()
+            .flat_map(|s| {
+                s.data_reader_list()
+                    .filter(|dr| dr.enabled() && dr.topic_name() == topic_name)
+                    .map(|dr| (s.instance_handle(), dr.instance_handle()))
+            })
+            .collect();
+        for (subscriber_handle, data_reader_handle) in data_reader_list {
+            self.announce_data_reader(subscriber_handle, data_reader_handle)
+                .await;
+        }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
@@ -286,6 +444,12 @@ where
 
     #[tracing::instrument(skip(self))]
     pub async fn enable_topic(&mut self, topic_name: String) -> DdsResult<()> {
+        if !self.domain_participant.enabled() {
+            return Err(DdsError::PreconditionNotMet(
+                "Parent participant is disabled".to_string(),
+            ));
+        }
+
         let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -497,6 +661,72 @@ where
         Ok(topic_handle)
     }
 
+    #[tracing::instrument(skip(self, status_condition, type_support))]
+    pub async fn create_multitopic(
+        &mut self,
+        topic_name: String,
+        type_name: String,
+        subscription_expression: String,
+        expression_parameters: Vec<String>,
+        status_condition: Actor<R, StatusConditionActor<R>>,
+        type_support: Arc<dyn DynamicType + Send + Sync>,
+    ) -> DdsResult<InstanceHandle> {
+        if self.domain_participant.get_topic(&topic_name).is_some() {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "Topic with name {} already exists.
+         To access this topic call the lookup_topicdescription method.",
+                topic_name
+            )));
+        }
+
+        let subscription_expression = SubscriptionExpression::parse(&subscription_expression)?;
+        let mut related_topic_types = Vec::new();
+        for related_topic_name in subscription_expression.related_topic_names() {
+            match self.domain_participant.get_topic(related_topic_name) {
+                Some(related_topic) if related_topic.multitopic().is_none() => {
+                    related_topic_types.push(related_topic.type_support().clone())
+                }
+                _ => {
+                    return Err(DdsError::PreconditionNotMet(format!(
+                        "Related topic {} does not exist",
+                        related_topic_name
+                    )))
+                }
+            }
+        }
+        let related_topic_types: Vec<&dyn DynamicType> = related_topic_types
+            .iter()
+            .map(|t| t.as_ref() as &dyn DynamicType)
+            .collect();
+        validate_multitopic_types(
+            &subscription_expression,
+            &expression_parameters,
+            &related_topic_types,
+            type_support.as_ref(),
+        )?;
+
+        let topic_handle = self.instance_handle_counter.generate_new_instance_handle();
+        let mut topic = TopicEntity::new(
+            self.domain_participant.get_default_topic_qos().clone(),
+            type_name,
+            topic_name,
+            topic_handle,
+            status_condition,
+            None,
+            vec![],
+            type_support,
+        );
+        topic.set_multitopic(MultiTopicDescription::new(
+            subscription_expression,
+            expression_parameters,
+        ));
+        // A MultiTopic only exists locally so it is never announced
+        topic.enable();
+        self.domain_participant.insert_topic(topic);
+
+        Ok(topic_handle)
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn delete_user_defined_topic(
         &mut self,
@@ -513,16 +743,34 @@ where
             return Ok(());
         }
 
-        let Some(topic) = self.domain_participant.get_topic(&topic_name) else {
+        let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) else {
             return Err(DdsError::AlreadyDeleted);
         };
 
+        // A topic obtained several times is only removed once it is deleted as many times
+        if topic.reference_count() > 1 {
+            topic.decrement_reference_count();
+            return Ok(());
+        }
+
         if Arc::strong_count(topic.type_support()) > 1 {
             return Err(DdsError::PreconditionNotMet(
                 "Topic still attached to some data writer or data reader".to_string(),
             ));
         }
 
+        if self.domain_participant.topic_list_mut().any(|t| {
+            t.multitopic().is_some_and(|m| {
+                m.subscription_expression()
+                    .related_topic_names()
+                    .contains(&topic_name)
+            })
+        }) {
+            return Err(DdsError::PreconditionNotMet(
+                "Topic still related to some MultiTopic".to_string(),
+            ));
+        }
+
         let Some(_) = self.domain_participant.remove_topic(&topic_name) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -544,7 +792,8 @@ where
             String,
         )>,
     > {
-        if let Some(topic) = self.domain_participant.get_topic(&topic_name) {
+        if let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) {
+            topic.increment_reference_count();
             Ok(Some((
                 topic.instance_handle(),
                 topic.status_condition().address(),
@@ -648,6 +897,17 @@ where
 
     #[tracing::instrument(skip(self))]
     pub async fn delete_participant_contained_entities(&mut self) -> DdsResult<()> {
+        if self
+            .domain_participant
+            .subscriber_list()
+            .flat_map(|s| s.data_reader_list())
+            .any(|dr| dr.has_outstanding_loans())
+        {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "DataReader has outstanding loans",
+            )));
+        }
+
         let deleted_publisher_list: Vec<PublisherEntity<R>> =
             self.domain_participant.drain_publisher_list().collect();
         for mut publisher in deleted_publisher_list {
@@ -737,11 +997,225 @@ where
             .domain_participant
             .get_discovered_participant_data(&participant_handle)
         else {
-            return Err(DdsError::BadParameter);
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Participant with this handle not discovered",
+            )));
         };
         Ok(handle.dds_participant_data.clone())
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_discovery_conformance_report(
+        &mut self,
+        participant_handle: InstanceHandle,
+    ) -> DdsResult<DiscoveryConformanceReport> {
+        let Some(discovered_participant_data) = self
+            .domain_participant
+            .get_discovered_participant_data(&participant_handle)
+        else {
+            return Err(DdsError::BadParameter);
+        };
+        let participant_proxy = &discovered_participant_data.participant_proxy;
+
+        // The builtin endpoints are only matched when the participant is in the same domain
+        let is_domain_id_matching = match participant_proxy.domain_id {
+            Some(id) => id == self.domain_participant.domain_id(),
+            None => true,
+        };
+        let is_domain_matching = is_domain_id_matching
+            && participant_proxy.domain_tag == self.domain_participant.domain_tag();
+        let builtin_endpoints = [
+            (
+                BuiltinEndpointKind::ParticipantAnnouncer,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_ANNOUNCER,
+            ),
+            (
+                BuiltinEndpointKind::ParticipantDetector,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_DETECTOR,
+            ),
+            (
+                BuiltinEndpointKind::PublicationsAnnouncer,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_ANNOUNCER,
+            ),
+            (
+                BuiltinEndpointKind::PublicationsDetector,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PUBLICATIONS_DETECTOR,
+            ),
+            (
+                BuiltinEndpointKind::SubscriptionsAnnouncer,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_ANNOUNCER,
+            ),
+            (
+                BuiltinEndpointKind::SubscriptionsDetector,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_SUBSCRIPTIONS_DETECTOR,
+            ),
+            (
+                BuiltinEndpointKind::ParticipantMessageWriter,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER,
+            ),
+            (
+                BuiltinEndpointKind::ParticipantMessageReader,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER,
+            ),
+            (
+                BuiltinEndpointKind::TopicsAnnouncer,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_ANNOUNCER,
+            ),
+            (
+                BuiltinEndpointKind::TopicsDetector,
+                BuiltinEndpointSet::BUILTIN_ENDPOINT_TOPICS_DETECTOR,
+            ),
+        ]
+        .into_iter()
+        .map(|(endpoint, flag)| {
+            let announced = participant_proxy.available_builtin_endpoints.has(flag);
+            BuiltinEndpointConformance {
+                endpoint,
+                announced,
+                matched: announced && is_domain_matching,
+            }
+        })
+        .collect();
+
+        let mut defaulted_qos_policies = Vec::new();
+        let mut missing_parameters = Vec::new();
+        for discovered_parameter_id_list in self
+            .domain_participant
+            .discovered_parameter_id_list()
+            .filter(|x| x.entity_handle.as_ref()[..12] == participant_handle.as_ref()[..12])
+        {
+            let (qos_parameter_list, optional_parameter_list) =
+                match discovered_parameter_id_list.entity_kind {
+                    DiscoveredEntityKind::Participant => (
+                        PARTICIPANT_QOS_PARAMETER_LIST,
+                        PARTICIPANT_OPTIONAL_PARAMETER_LIST,
+                    ),
+                    DiscoveredEntityKind::Topic => {
+                        (TOPIC_QOS_PARAMETER_LIST, TOPIC_OPTIONAL_PARAMETER_LIST)
+                    }
+                    DiscoveredEntityKind::Publication => (
+                        PUBLICATION_QOS_PARAMETER_LIST,
+                        PUBLICATION_OPTIONAL_PARAMETER_LIST,
+                    ),
+                    DiscoveredEntityKind::Subscription => (
+                        SUBSCRIPTION_QOS_PARAMETER_LIST,
+                        SUBSCRIPTION_OPTIONAL_PARAMETER_LIST,
+                    ),
+                };
+            let parameter_id_list = &discovered_parameter_id_list.parameter_id_list;
+            for &(parameter_id, policy_id) in qos_parameter_list {
+                if !parameter_id_list.contains(&parameter_id) {
+                    defaulted_qos_policies.push(DefaultedQosPolicy {
+                        entity_kind: discovered_parameter_id_list.entity_kind,
+                        entity_handle: discovered_parameter_id_list.entity_handle,
+                        policy_id,
+                        parameter_id,
+                    });
+                }
+            }
+            for &parameter_id in optional_parameter_list {
+                if !parameter_id_list.contains(&parameter_id) {
+                    missing_parameters.push(MissingParameter {
+                        entity_kind: discovered_parameter_id_list.entity_kind,
+                        entity_handle: discovered_parameter_id_list.entity_handle,
+                        parameter_id,
+                    });
+                }
+            }
+        }
+
+        Ok(DiscoveryConformanceReport {
+            vendor_id: participant_proxy.vendor_id,
+            builtin_endpoints,
+            defaulted_qos_policies,
+            missing_parameters,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_removed_discovered_entities(&mut self) -> DdsResult<Vec<RemovedDiscoveredEntity>> {
+        let now = self.clock_handle.now();
+        Ok(self.domain_participant.get_removed_discovered_entities(now))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_bandwidth_statistics(&mut self) -> DdsResult<BandwidthStatistics> {
+        self.account_data_bytes_sent();
+        Ok(self.domain_participant.bandwidth_statistics())
+    }
+
+    // The data sent by the writers is kept by the transport until it is accounted when the statistics are needed
+    fn account_data_bytes_sent(&mut self) {
+        let mut data_bytes_sent = Vec::new();
+        for publisher in self.domain_participant.publisher_list_mut() {
+            for data_writer in publisher.data_writer_list_mut() {
+                data_bytes_sent.push((
+                    String::from(data_writer.topic_name()),
+                    data_writer.take_data_bytes_sent(),
+                ));
+            }
+        }
+        for (topic_name, data_bytes_sent) in data_bytes_sent {
+            self.add_data_bytes_sent(&topic_name, data_bytes_sent);
+        }
+    }
+
+    fn add_data_bytes_sent(&mut self, topic_name: &str, data_bytes_sent: Vec<(Guid, u64)>) {
+        for (reader_guid, bytes) in data_bytes_sent {
+            self.domain_participant.add_bytes_sent(
+                topic_name,
+                get_participant_instance_handle(reader_guid.prefix()),
+                bytes,
+            );
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_memory_usage_statistics(&mut self) -> DdsResult<MemoryUsageStatistics> {
+        Ok(self.domain_participant.memory_usage_statistics())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_topic_bandwidth_quota(
+        &mut self,
+        topic_name: String,
+        bandwidth_quota: Option<BandwidthQuota>,
+    ) -> DdsResult<()> {
+        if bandwidth_quota.is_some_and(|q| q.max_bytes == 0 || q.period <= Duration::new(0, 0)) {
+            return Err(DdsError::BadParameter);
+        }
+        let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) else {
+            return Err(DdsError::BadParameter);
+        };
+        topic.set_bandwidth_quota(bandwidth_quota);
+        if let Some(bandwidth_quota) = bandwidth_quota {
+            self.transport.set_flow_controller_limit(
+                &bandwidth_quota_flow_controller_name(&topic_name),
+                usize::try_from(bandwidth_quota.max_bytes).unwrap_or(usize::MAX),
+                bandwidth_quota.period.into(),
+            );
+        }
+        for publisher in self.domain_participant.publisher_list_mut() {
+            for data_writer in publisher
+                .data_writer_list_mut()
+                .filter(|dw| dw.enabled() && dw.topic_name() == topic_name)
+            {
+                data_writer.set_bandwidth_quota_flow_controller(bandwidth_quota.is_some());
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, hook))]
+    pub fn set_endpoint_authorization_hook(
+        &mut self,
+        hook: Option<Arc<dyn EndpointAuthorizationHook>>,
+    ) -> DdsResult<()> {
+        self.domain_participant
+            .set_endpoint_authorization_hook(hook);
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_discovered_topics(&mut self) -> DdsResult<Vec<InstanceHandle>> {
         Ok(self.domain_participant.get_discovered_topics())
@@ -769,6 +1243,63 @@ where
         self.clock_handle.now()
     }
 
+    #[tracing::instrument(skip(self))]
+    fn create_entity_id(&mut self, topic_name: &str, entity_kind: u8) -> DdsResult<EntityId> {
+        let mut used_entity_ids = Vec::new();
+        for publisher in self.domain_participant.publisher_list() {
+            used_entity_ids.extend(
+                publisher
+                    .data_writer_list()
+                    .map(|dw| dw.transport_writer().guid().entity_id()),
+            );
+        }
+        for subscriber in self.domain_participant.subscriber_list() {
+            used_entity_ids.extend(
+                subscriber
+                    .data_reader_list()
+                    .map(|dr| dr.transport_reader().guid().entity_id()),
+            );
+        }
+
+        if let EntityKeyAllocation::ApplicationProvided(entity_keys) = &self.entity_key_allocation {
+            if let Some(&entity_key) = entity_keys.get(topic_name) {
+                let entity_id = EntityId::new(entity_key, entity_kind);
+                if used_entity_ids.contains(&entity_id) {
+                    return Err(DdsError::PreconditionNotMet(format!(
+                        "Entity key {:?} of topic {} already in use",
+                        entity_key, topic_name
+                    )));
+                }
+                return Ok(entity_id);
+            }
+        }
+
+        loop {
+            let entity_key = match self.entity_key_allocation {
+                EntityKeyAllocation::Random => {
+                    // xorshift64
+                    self.entity_key_random_state ^= self.entity_key_random_state << 13;
+                    self.entity_key_random_state ^= self.entity_key_random_state >> 7;
+                    self.entity_key_random_state ^= self.entity_key_random_state << 17;
+                    let bytes = self.entity_key_random_state.to_le_bytes();
+                    [bytes[0], bytes[1], bytes[2]]
+                }
+                EntityKeyAllocation::Sequential | EntityKeyAllocation::ApplicationProvided(_) => {
+                    self.entity_counter = self.entity_counter.wrapping_add(1);
+                    [
+                        0,
+                        self.entity_counter.to_le_bytes()[0],
+                        self.entity_counter.to_le_bytes()[1],
+                    ]
+                }
+            };
+            let entity_id = EntityId::new(entity_key, entity_kind);
+            if entity_key != [0, 0, 0] && !used_entity_ids.contains(&entity_id) {
+                return Ok(entity_id);
+            }
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn set_domain_participant_qos(
         &mut self,
@@ -779,7 +1310,7 @@ where
             QosKind::Specific(q) => q,
         };
 
-        self.domain_participant.set_qos(qos);
+        self.domain_participant.set_qos(qos)?;
         if self.domain_participant.enabled() {
             self.announce_participant().await;
         }
@@ -791,23 +1322,64 @@ where
         Ok(self.domain_participant.qos().clone())
     }
 
-    #[tracing::instrument(skip(self, listener_sender))]
+    #[tracing::instrument(skip(self, listener_sender, participant_address))]
     pub fn set_domain_participant_listener(
         &mut self,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         status_kind: Vec<StatusKind>,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) -> DdsResult<()> {
         self.domain_participant
             .set_listener(listener_sender, status_kind);
+        self.start_task_watchdog(participant_address);
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn enable_domain_participant(&mut self) -> DdsResult<()> {
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn enable_domain_participant(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
         if !self.domain_participant.enabled() {
             self.domain_participant.enable();
 
             self.announce_participant().await;
+
+            if self
+                .domain_participant
+                .qos()
+                .entity_factory
+                .autoenable_created_entities
+            {
+                let topic_name_list: Vec<_> = self
+                    .domain_participant
+                    .topic_list_mut()
+                    .map(|t| t.topic_name().to_string())
+                    .collect();
+                for topic_name in topic_name_list {
+                    self.enable_topic(topic_name).await?;
+                }
+
+                let publisher_handle_list: Vec<_> = self
+                    .domain_participant
+                    .publisher_list()
+                    .map(|p| p.instance_handle())
+                    .collect();
+                for publisher_handle in publisher_handle_list {
+                    self.enable_publisher(publisher_handle, participant_address.clone())
+                        .await?;
+                }
+
+                let subscriber_handle_list: Vec<_> = self
+                    .domain_participant
+                    .subscriber_list()
+                    .map(|s| s.instance_handle())
+                    .collect();
+                for subscriber_handle in subscriber_handle_list {
+                    self.enable_subscriber(subscriber_handle, participant_address.clone())
+                        .await?;
+                }
+            }
         }
 
         Ok(())
@@ -818,6 +1390,27 @@ where
         self.domain_participant.is_empty()
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn assert_participant_liveliness(&mut self) -> DdsResult<()> {
+        if !self.domain_participant.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
+
+        let timestamp = self.get_current_time();
+        for publisher in self.domain_participant.publisher_list_mut() {
+            for data_writer in publisher.data_writer_list_mut() {
+                if data_writer.qos().liveliness.kind == LivelinessQosPolicyKind::ManualByParticipant
+                {
+                    data_writer.assert_liveliness(timestamp);
+                }
+            }
+        }
+        self.announce_participant_message(PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE)
+            .await;
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip(
         self,
@@ -876,8 +1469,40 @@ where
             ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 todo!()
             }
+
+            fn changes_lost(
+                &mut self,
+                writer_guid: Guid,
+                lost_change_count: i64,
+            ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+                let participant_address = self.domain_participant_address.clone();
+                let subscriber_handle = self.subscriber_handle;
+                let data_reader_handle = self.data_reader_handle;
+                Box::pin(async move {
+                    participant_address
+                        .send(DomainParticipantMail::Message(
+                            MessageServiceMail::AddLostChanges {
+                                participant_address: participant_address.clone(),
+                                writer_guid,
+                                lost_change_count,
+                                subscriber_handle,
+                                data_reader_handle,
+                            },
+                        ))
+                        .await
+                        .ok();
+                })
+            }
         }
 
+        if self
+            .domain_participant
+            .is_builtin_subscriber(subscriber_handle)
+        {
+            return Err(DdsError::PreconditionNotMet(
+                "Data readers can not be created in the built-in subscriber".to_string(),
+            ));
+        }
         let Some(topic) = self.domain_participant.get_topic(&topic_name) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -885,9 +1510,14 @@ where
         let topic_kind = get_topic_kind(topic.type_support().as_ref());
         let topic_name = String::from(topic.topic_name());
         let type_name = String::from(topic.type_name());
-        let reader_handle = self.instance_handle_counter.generate_new_instance_handle();
+        let related_topic_names = topic
+            .multitopic()
+            .map(|m| m.subscription_expression().related_topic_names().to_vec());
 
         let type_support = topic.type_support().clone();
+        let participant_max_samples = self.domain_participant.max_samples();
+        let participant_reserved_samples = self.domain_participant.reserved_samples();
+        let discovered_writer_count = self.domain_participant.discovered_writer_count(&topic_name);
         let Some(subscriber) = self
             .domain_participant
             .get_mut_subscriber(subscriber_handle)
@@ -905,37 +1535,54 @@ where
                 }
             }
         };
-        self.entity_counter += 1;
-
+        let reserved_samples = get_reserved_samples(&qos.history, &qos.resource_limits, topic_kind);
+        check_participant_resource_budget(
+            participant_max_samples,
+            participant_reserved_samples,
+            reserved_samples,
+        )?;
+        let resources = DataReaderResources::allocate(
+            reserved_samples,
+            qos.resource_limits.max_instances,
+            discovered_writer_count,
+        )?;
         let entity_kind = match topic_kind {
             TopicKind::NoKey => USER_DEFINED_READER_NO_KEY,
             TopicKind::WithKey => USER_DEFINED_READER_WITH_KEY,
         };
-        let entity_id = EntityId::new(
-            [
-                0,
-                self.entity_counter.to_le_bytes()[0],
-                self.entity_counter.to_le_bytes()[1],
-            ],
-            entity_kind,
-        );
+        let entity_id = self.create_entity_id(&topic_name, entity_kind)?;
+        let reader_handle = self.instance_handle_counter.generate_new_instance_handle();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
         let reliablity_kind = match qos.reliability.kind {
             ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
             ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
         };
-        let transport_reader =
-            TransportReaderKind::Stateful(self.transport.create_stateful_reader(
-                entity_id,
-                reliablity_kind,
-                Box::new(UserDefinedReaderHistoryCache::<R> {
-                    domain_participant_address: domain_participant_address.clone(),
-                    subscriber_handle: subscriber.instance_handle(),
-                    data_reader_handle: reader_handle,
-                }),
-            ));
+        let reader_history_cache = Box::new(UserDefinedReaderHistoryCache::<R> {
+            domain_participant_address: domain_participant_address.clone(),
+            subscriber_handle: subscriber.instance_handle(),
+            data_reader_handle: reader_handle,
+        });
+        let transport_reader = match qos.endpoint_behavior.kind {
+            EndpointBehaviorQosPolicyKind::Stateful => {
+                TransportReaderKind::Stateful(self.transport.create_stateful_reader(
+                    entity_id,
+                    reliablity_kind,
+                    reader_history_cache,
+                ))
+            }
+            EndpointBehaviorQosPolicyKind::Stateless => TransportReaderKind::Stateless(
+                self.transport
+                    .create_stateless_reader(entity_id, reader_history_cache),
+            ),
+        };
 
         let listener_mask = mask.to_vec();
-        let data_reader = DataReaderEntity::new(
+        let mut data_reader = DataReaderEntity::new(
             reader_handle,
             qos,
             topic_name,
@@ -946,11 +1593,37 @@ where
             listener_mask,
             transport_reader,
         );
+        data_reader.reserve_resources(resources)?;
 
         let data_reader_handle = data_reader.instance_handle();
+        let data_reader_qos = data_reader.qos().clone();
 
         subscriber.insert_data_reader(data_reader);
 
+        if let Some(related_topic_names) = related_topic_names {
+            if let Err(e) = self
+                .create_multitopic_constituent_readers(
+                    subscriber_handle,
+                    data_reader_handle,
+                    related_topic_names,
+                    data_reader_qos,
+                    domain_participant_address.clone(),
+                )
+                .await
+            {
+                self.delete_data_reader(subscriber_handle, data_reader_handle)
+                    .await
+                    .ok();
+                return Err(e);
+            }
+        }
+
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
         if subscriber.enabled() && subscriber.qos().entity_factory.autoenable_created_entities {
             self.enable_data_reader(
                 subscriber_handle,
@@ -962,22 +1635,135 @@ where
         Ok(data_reader_handle)
     }
 
+    /// Creates a reader for each of the related topics of a MultiTopic. These readers keep the most recent
+    /// sample of each instance from which the samples of the MultiTopic reader are assembled.
+    async fn create_multitopic_constituent_readers(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        multitopic_reader_handle: InstanceHandle,
+        related_topic_names: Vec<String>,
+        multitopic_reader_qos: DataReaderQos,
+        domain_participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        let qos = DataReaderQos {
+            history: HistoryQosPolicy {
+                kind: HistoryQosPolicyKind::KeepLast(1),
+            },
+            resource_limits: ResourceLimitsQosPolicy::default(),
+            ..multitopic_reader_qos
+        };
+        for related_topic_name in related_topic_names {
+            let status_condition =
+                Actor::spawn(StatusConditionActor::default(), &self.spawner_handle);
+            let constituent_reader_handle = Box::pin(self.create_data_reader(
+                subscriber_handle,
+                related_topic_name,
+                QosKind::Specific(qos.clone()),
+                status_condition,
+                None,
+                Vec::new(),
+                domain_participant_address.clone(),
+            ))
+            .await?;
+
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return Err(DdsError::AlreadyDeleted);
+            };
+            if let Some(constituent_reader) =
+                subscriber.get_mut_data_reader(constituent_reader_handle)
+            {
+                constituent_reader.set_multitopic_reader(multitopic_reader_handle);
+            }
+            if let Some(multitopic_reader) =
+                subscriber.get_mut_data_reader(multitopic_reader_handle)
+            {
+                multitopic_reader.add_multitopic_constituent(constituent_reader_handle);
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn delete_data_reader(
         &mut self,
         subscriber_handle: InstanceHandle,
         datareader_handle: InstanceHandle,
     ) -> DdsResult<()> {
+        if self
+            .domain_participant
+            .is_builtin_subscriber(subscriber_handle)
+        {
+            return Err(DdsError::PreconditionNotMet(
+                "Built-in data readers can not be deleted".to_string(),
+            ));
+        }
         let Some(subscriber) = self
             .domain_participant
             .get_mut_subscriber(subscriber_handle)
         else {
             return Err(DdsError::AlreadyDeleted);
         };
+        if subscriber
+            .get_data_reader(datareader_handle)
+            .is_some_and(|x| x.has_outstanding_loans())
+        {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "DataReader has outstanding loans",
+            )));
+        }
         let Some(data_reader) = subscriber.remove_data_reader(datareader_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        self.announce_deleted_data_reader(data_reader).await;
+        let mut deleted_data_reader_list = Vec::new();
+        for constituent_reader_handle in data_reader.multitopic_constituent_list() {
+            if let Some(constituent_reader) =
+                subscriber.remove_data_reader(*constituent_reader_handle)
+            {
+                deleted_data_reader_list.push(constituent_reader);
+            }
+        }
+        deleted_data_reader_list.push(data_reader);
+        for data_reader in deleted_data_reader_list {
+            self.announce_deleted_data_reader(data_reader).await;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn delete_subscriber_contained_entities(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        if self
+            .domain_participant
+            .is_builtin_subscriber(subscriber_handle)
+        {
+            return Err(DdsError::PreconditionNotMet(
+                "Built-in data readers can not be deleted".to_string(),
+            ));
+        }
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if subscriber
+            .data_reader_list()
+            .any(|dr| dr.has_outstanding_loans())
+        {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "DataReader has outstanding loans",
+            )));
+        }
+        let deleted_data_reader_list: Vec<DataReaderEntity<R>> =
+            subscriber.drain_data_reader_list().collect();
+        for data_reader in deleted_data_reader_list {
+            self.announce_deleted_data_reader(data_reader).await;
+        }
         Ok(())
     }
 
@@ -992,25 +1778,15 @@ where
             return Err(DdsError::BadParameter);
         }
 
-        // Built-in subscriber is identified by the handle of the participant itself
-        if self.domain_participant.instance_handle() == subscriber_handle {
-            Ok(self
-                .domain_participant
-                .builtin_subscriber_mut()
-                .data_reader_list_mut()
-                .find(|dr| dr.topic_name() == topic_name)
-                .map(|x| (x.instance_handle(), x.status_condition().address())))
-        } else {
-            let Some(s) = self
-                .domain_participant
-                .get_mut_subscriber(subscriber_handle)
-            else {
-                return Err(DdsError::AlreadyDeleted);
-            };
-            Ok(s.data_reader_list_mut()
-                .find(|dr| dr.topic_name() == topic_name)
-                .map(|x| (x.instance_handle(), x.status_condition().address())))
-        }
+        let Some(s) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(s.data_reader_list_mut()
+            .find(|dr| dr.topic_name() == topic_name && dr.multitopic_reader().is_none())
+            .map(|x| (x.instance_handle(), x.status_condition().address())))
     }
 
     #[tracing::instrument(skip(self))]
@@ -1048,7 +1824,7 @@ where
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn set_subscriber_qos(
+    pub async fn set_subscriber_qos(
         &mut self,
         subscriber_handle: InstanceHandle,
         qos: QosKind<SubscriberQos>,
@@ -1065,7 +1841,19 @@ where
             return Err(DdsError::AlreadyDeleted);
         };
 
-        subscriber.set_qos(qos)
+        subscriber.set_qos(qos)?;
+
+        // The group data is propagated as part of the discovery data of the data readers
+        let data_reader_list: Vec<_> = subscriber
+            .data_reader_list()
+            .filter(|dr| dr.enabled())
+            .map(|dr| dr.instance_handle())
+            .collect();
+        for data_reader_handle in data_reader_list {
+            self.announce_data_reader(subscriber_handle, data_reader_handle)
+                .await;
+        }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
@@ -1101,6 +1889,45 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn enable_subscriber(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        if !self.domain_participant.enabled() {
+            return Err(DdsError::PreconditionNotMet(
+                "Parent participant is disabled".to_string(),
+            ));
+        }
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        if !subscriber.enabled() {
+            subscriber.enable();
+
+            if subscriber.qos().entity_factory.autoenable_created_entities {
+                let data_reader_handle_list: Vec<_> = subscriber
+                    .data_reader_list()
+                    .map(|dr| dr.instance_handle())
+                    .collect();
+                for data_reader_handle in data_reader_handle_list {
+                    self.enable_data_reader(
+                        subscriber_handle,
+                        data_reader_handle,
+                        participant_address.clone(),
+                    )
+                    .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip(self, status_condition, listener_sender, participant_address))]
     pub async fn create_data_writer(
@@ -1112,7 +1939,7 @@ where
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-    ) -> DdsResult<InstanceHandle> {
+    ) -> DdsResult<(InstanceHandle, DataRepresentationId)> {
         let Some(topic) = self.domain_participant.get_topic(&topic_name) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -1125,17 +1952,9 @@ where
             TopicKind::NoKey => USER_DEFINED_WRITER_NO_KEY,
         };
 
-        self.entity_counter += 1;
-        let entity_id = EntityId::new(
-            [
-                0,
-                self.entity_counter.to_le_bytes()[0],
-                self.entity_counter.to_le_bytes()[1],
-            ],
-            entity_kind,
-        );
-
-        let writer_handle = self.instance_handle_counter.generate_new_instance_handle();
+        let participant_max_samples = self.domain_participant.max_samples();
+        let participant_reserved_samples = self.domain_participant.reserved_samples();
+        let discovered_reader_count = self.domain_participant.discovered_reader_count(&topic_name);
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -1150,17 +1969,41 @@ where
                 }
             }
         };
+        let reserved_samples = get_reserved_samples(&qos.history, &qos.resource_limits, topic_kind);
+        check_participant_resource_budget(
+            participant_max_samples,
+            participant_reserved_samples,
+            reserved_samples,
+        )?;
+        let resources = DataWriterResources::allocate(
+            reserved_samples,
+            qos.resource_limits.max_instances,
+            discovered_reader_count,
+        )?;
+
+        let entity_id = self.create_entity_id(&topic_name, entity_kind)?;
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let writer_handle = self.instance_handle_counter.generate_new_instance_handle();
         let reliablity_kind = match qos.reliability.kind {
             ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
             ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
         };
-        let transport_writer = self
-            .transport
-            .create_stateful_writer(entity_id, reliablity_kind);
+        let transport_writer = match qos.endpoint_behavior.kind {
+            EndpointBehaviorQosPolicyKind::Stateful => TransportWriterKind::Stateful(
+                self.transport
+                    .create_stateful_writer(entity_id, reliablity_kind),
+            ),
+            EndpointBehaviorQosPolicyKind::Stateless => {
+                TransportWriterKind::Stateless(self.transport.create_stateless_writer(entity_id))
+            }
+        };
 
-        let data_writer = DataWriterEntity::new(
+        let mut data_writer = DataWriterEntity::new(
             writer_handle,
-            TransportWriterKind::Stateful(transport_writer),
+            transport_writer,
             topic_name,
             type_name,
             type_support,
@@ -1169,7 +2012,9 @@ where
             mask,
             qos,
         );
+        data_writer.reserve_resources(resources)?;
         let data_writer_handle = data_writer.instance_handle();
+        let representation = offered_representation(data_writer.qos());
 
         publisher.insert_data_writer(data_writer);
 
@@ -1178,7 +2023,7 @@ where
                 .await?;
         }
 
-        Ok(data_writer_handle)
+        Ok((data_writer_handle, representation))
     }
 
     #[tracing::instrument(skip(self))]
@@ -1198,6 +2043,22 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn delete_publisher_contained_entities(
+        &mut self,
+        publisher_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let deleted_data_writer_list: Vec<DataWriterEntity<R>> =
+            publisher.drain_data_writer_list().collect();
+        for data_writer in deleted_data_writer_list {
+            self.announce_deleted_data_writer(data_writer).await;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_default_datawriter_qos(
         &mut self,
@@ -1227,7 +2088,7 @@ where
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn set_publisher_qos(
+    pub async fn set_publisher_qos(
         &mut self,
         publisher_handle: InstanceHandle,
         qos: QosKind<PublisherQos>,
@@ -1240,7 +2101,104 @@ where
             return Err(DdsError::AlreadyDeleted);
         };
 
-        publisher.set_qos(qos)
+        publisher.set_qos(qos)?;
+
+        // The group data is propagated as part of the discovery data of the data writers
+        self.announce_publisher_data_writers(publisher_handle).await;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn begin_partition_session(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        partition_list: Vec<String>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let partition = publisher.partition();
+        publisher.begin_partition_session(partition_list)?;
+        if publisher.partition() != partition {
+            self.announce_publisher_data_writers(publisher_handle).await;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn end_partition_session(
+        &mut self,
+        publisher_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let partition = publisher.partition();
+        publisher.end_partition_session()?;
+        if publisher.partition() != partition {
+            self.announce_publisher_data_writers(publisher_handle).await;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn begin_coherent_changes(&mut self, publisher_handle: InstanceHandle) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        publisher.begin_coherent_changes();
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn end_coherent_changes(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !publisher.end_coherent_changes()? {
+            return Ok(());
+        }
+        let data_writer_handle_list: Vec<_> = publisher
+            .data_writer_list()
+            .map(|dw| dw.instance_handle())
+            .collect();
+        for data_writer_handle in data_writer_handle_list {
+            // The writes held by the coalescing windows were done within the set
+            self.publish_coalesced_writes(
+                &participant_address,
+                publisher_handle,
+                data_writer_handle,
+            )
+            .await?;
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return Err(DdsError::AlreadyDeleted);
+            };
+            if let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) {
+                data_writer.end_coherent_set().await;
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn announce_publisher_data_writers(&mut self, publisher_handle: InstanceHandle) {
+        let Some(publisher) = self.domain_participant.get_publisher(publisher_handle) else {
+            return;
+        };
+        let data_writer_list: Vec<_> = publisher
+            .data_writer_list()
+            .filter(|dw| dw.enabled())
+            .map(|dw| dw.instance_handle())
+            .collect();
+        for data_writer_handle in data_writer_list {
+            self.announce_data_writer(publisher_handle, data_writer_handle)
+                .await;
+        }
     }
 
     #[tracing::instrument(skip(self))]
@@ -1269,6 +2227,42 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn enable_publisher(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        if !self.domain_participant.enabled() {
+            return Err(DdsError::PreconditionNotMet(
+                "Parent participant is disabled".to_string(),
+            ));
+        }
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        if !publisher.enabled() {
+            publisher.enable();
+
+            if publisher.qos().entity_factory.autoenable_created_entities {
+                let data_writer_handle_list: Vec<_> = publisher
+                    .data_writer_list()
+                    .map(|dw| dw.instance_handle())
+                    .collect();
+                for data_writer_handle in data_writer_handle_list {
+                    self.enable_data_writer(
+                        publisher_handle,
+                        data_writer_handle,
+                        participant_address.clone(),
+                    )
+                    .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn get_publication_matched_status(
         &mut self,
@@ -1350,6 +2344,9 @@ where
         else {
             return Err(DdsError::AlreadyDeleted);
         };
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
         Ok(data_writer.get_matched_subscriptions())
     }
 
@@ -1376,13 +2373,12 @@ where
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn unregister_instance(
+    pub fn get_matched_subscription_locators(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        serialized_data: Vec<u8>,
-        timestamp: Time,
-    ) -> DdsResult<()> {
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
@@ -1392,294 +2388,236 @@ where
         else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let serialized_key = match get_serialized_key_from_serialized_foo(
-            &serialized_data,
-            data_writer.type_support(),
-        ) {
-            Ok(k) => k,
-            Err(e) => {
-                return Err(e.into());
-            }
-        };
         data_writer
-            .unregister_w_timestamp(serialized_key, timestamp)
-            .await
+            .get_matched_subscription_locators(&subscription_handle)
+            .map(<[Locator]>::to_vec)
+            .ok_or(DdsError::BadParameter)
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn lookup_instance(
+    pub fn check_subscription_compatibility(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        serialized_data: Vec<u8>,
-    ) -> DdsResult<Option<InstanceHandle>> {
+        subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
+        let publisher_qos = publisher.qos().clone();
         let Some(data_writer) = publisher
             .data_writer_list_mut()
             .find(|x| x.instance_handle() == data_writer_handle)
         else {
             return Err(DdsError::AlreadyDeleted);
         };
+        Ok(get_discovered_reader_incompatible_qos_policy_list(
+            data_writer.qos(),
+            &subscription_builtin_topic_data,
+            &publisher_qos,
+        ))
+    }
 
-        if !data_writer.enabled() {
-            return Err(DdsError::NotEnabled);
-        }
-
-        let instance_handle = match get_instance_handle_from_serialized_foo(
-            &serialized_data,
-            data_writer.type_support(),
-        ) {
-            Ok(k) => k,
-            Err(e) => {
-                return Err(e.into());
-            }
+    #[tracing::instrument(skip(self))]
+    pub async fn register_instance(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+    ) -> DdsResult<Option<InstanceHandle>> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-
-        Ok(data_writer
-            .contains_instance(&instance_handle)
-            .then_some(instance_handle))
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_writer.register_instance(serialized_data).await
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub async fn write_w_timestamp(
+    #[tracing::instrument(skip(self))]
+    pub fn start_data_writer_draining(
         &mut self,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        serialized_data: Vec<u8>,
-        timestamp: Time,
     ) -> DdsResult<()> {
-        let now = self.get_current_time();
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_writer) = publisher
-            .data_writer_list_mut()
-            .find(|x| x.instance_handle() == data_writer_handle)
-        else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let instance_handle = match get_instance_handle_from_serialized_foo(
-            &serialized_data,
-            data_writer.type_support(),
-        ) {
-            Ok(k) => k,
-            Err(e) => {
-                return Err(e.into());
-            }
-        };
-
-        match data_writer.qos().lifespan.duration {
-            DurationKind::Finite(lifespan_duration) => {
-                let mut timer_handle = self.timer_handle.clone();
-                let sleep_duration = timestamp - now + lifespan_duration;
-                if sleep_duration > Duration::new(0, 0) {
-                    let sequence_number = match data_writer
-                        .write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                        .await
-                    {
-                        Ok(s) => s,
-                        Err(e) => {
-                            return Err(e);
-                        }
-                    };
-
-                    let participant_address = participant_address.clone();
-                    self.spawner_handle.spawn(async move {
-                        timer_handle.delay(sleep_duration.into()).await;
-                        participant_address
-                            .send(DomainParticipantMail::Message(
-                                MessageServiceMail::RemoveWriterChange {
-                                    publisher_handle,
-                                    data_writer_handle,
-                                    sequence_number,
-                                },
-                            ))
-                            .await
-                            .ok();
-                    });
-                }
-            }
-            DurationKind::Infinite => {
-                match data_writer
-                    .write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                    .await
-                {
-                    Ok(_) => (),
-                    Err(e) => {
-                        return Err(e);
-                    }
-                };
-            }
-        }
-
-        if let DurationKind::Finite(deadline_missed_period) = data_writer.qos().deadline.period {
-            let mut timer_handle = self.timer_handle.clone();
-            self.spawner_handle.spawn(async move {
-                loop {
-                    timer_handle.delay(deadline_missed_period.into()).await;
-                    participant_address
-                        .send(DomainParticipantMail::Event(
-                            EventServiceMail::OfferedDeadlineMissed {
-                                publisher_handle,
-                                data_writer_handle,
-                                change_instance_handle: instance_handle,
-                                participant_address: participant_address.clone(),
-                            },
-                        ))
-                        .await
-                        .ok();
-                }
-            });
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
         }
-
+        data_writer.start_draining();
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn dispose_w_timestamp(
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn flush_data_writer(
         &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        serialized_data: Vec<u8>,
-        timestamp: Time,
     ) -> DdsResult<()> {
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_writer) = publisher
-            .data_writer_list_mut()
-            .find(|x| x.instance_handle() == data_writer_handle)
-        else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let serialized_key = match get_serialized_key_from_serialized_foo(
-            &serialized_data,
-            data_writer.type_support(),
-        ) {
-            Ok(k) => k,
-            Err(e) => {
-                return Err(e.into());
-            }
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
+        self.publish_coalesced_writes(&participant_address, publisher_handle, data_writer_handle)
+            .await?;
+
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        data_writer
-            .dispose_w_timestamp(serialized_key, timestamp)
-            .await
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_writer.flush();
+        Ok(())
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub fn wait_for_acknowledgments(
+    /// Publishes the writes held by the coalescing windows of the data writer without waiting for the windows to expire.
+    async fn publish_coalesced_writes(
         &mut self,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        participant_address: &R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        timeout: Duration,
-    ) -> Pin<Box<dyn Future<Output = DdsResult<()>> + Send>> {
-        let timer_handle = self.timer_handle.clone();
-        Box::pin(async move {
-            poll_timeout(
-                timer_handle,
-                timeout.into(),
-                Box::pin(async move {
-                    loop {
-                        let (reply_sender, mut reply_receiver) = R::oneshot();
-                        participant_address
-                            .send(DomainParticipantMail::Message(
-                                MessageServiceMail::AreAllChangesAcknowledged {
-                                    publisher_handle,
-                                    data_writer_handle,
-                                    reply_sender,
-                                },
-                            ))
-                            .await
-                            .ok();
-                        let reply = reply_receiver.receive().await;
-                        match reply {
-                            Ok(are_changes_acknowledged) => match are_changes_acknowledged {
-                                Ok(true) => return Ok(()),
-                                Ok(false) => (),
-                                Err(e) => return Err(e),
-                            },
-                            Err(_) => return Err(DdsError::Error(String::from("Channel error"))),
-                        }
-                    }
-                }),
+    ) -> DdsResult<()> {
+        loop {
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return Err(DdsError::AlreadyDeleted);
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return Err(DdsError::AlreadyDeleted);
+            };
+            let Some(coalesced_write) = data_writer.pop_coalesced_write() else {
+                return Ok(());
+            };
+            self.publish_data_writer_sample(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+                coalesced_write.instance,
+                coalesced_write.serialized_data,
+                coalesced_write.timestamp,
+                coalesced_write.sample_hash,
+                Vec::new(),
+                None,
             )
-            .await?
-        })
+            .await?;
+        }
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn get_offered_deadline_missed_status(
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn publish_coalesced_write(
         &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-    ) -> DdsResult<OfferedDeadlineMissedStatus> {
+        instance_handle: InstanceHandle,
+        window_id: u64,
+    ) {
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
-            return Err(DdsError::AlreadyDeleted);
+            return;
         };
-        let Some(data_writer) = publisher
-            .data_writer_list_mut()
-            .find(|x| x.instance_handle() == data_writer_handle)
-        else {
-            return Err(DdsError::AlreadyDeleted);
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
         };
-
-        Ok(data_writer.get_offered_deadline_missed_status().await)
+        // The write might have already been published and a new window opened since the timer was started
+        if data_writer.open_coalescing_window_id(&instance_handle) != Some(window_id) {
+            return;
+        }
+        let Some(coalesced_write) = data_writer.take_coalesced_write(&instance_handle) else {
+            return;
+        };
+        if let Err(error) = self
+            .publish_data_writer_sample(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+                instance_handle,
+                coalesced_write.serialized_data,
+                coalesced_write.timestamp,
+                coalesced_write.sample_hash,
+                Vec::new(),
+                None,
+            )
+            .await
+        {
+            tracing::warn!(
+                ?instance_handle,
+                ?error,
+                "Failed to publish coalesced write"
+            );
+        }
+        self.update_data_writer_publication_throttling(
+            &participant_address,
+            publisher_handle,
+            data_writer_handle,
+        )
+        .await;
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub async fn enable_data_writer(
+    #[tracing::instrument(skip(self))]
+    pub fn flush_data_writer_batch(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-    ) -> DdsResult<()> {
+        batch_id: u64,
+    ) {
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
-            return Err(DdsError::AlreadyDeleted);
+            return;
         };
         let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
-            return Err(DdsError::AlreadyDeleted);
+            return;
         };
-        if !data_writer.enabled() {
-            data_writer.enable();
-
-            let discovered_reader_list: Vec<_> = self
-                .domain_participant
-                .discovered_reader_data_list()
-                .cloned()
-                .collect();
-            for discovered_reader_data in discovered_reader_list {
-                self.add_discovered_reader(
-                    discovered_reader_data,
-                    publisher_handle,
-                    data_writer_handle,
-                    participant_address.clone(),
-                )
-                .await;
-            }
-
-            self.announce_data_writer(publisher_handle, data_writer_handle)
-                .await;
+        // The batch might have already been flushed and a new one opened since the timer was started
+        if data_writer.open_batch_id() == Some(batch_id) {
+            data_writer.flush();
         }
-        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn set_data_writer_qos(
+    pub async fn unregister_data_writer_instances(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-        qos: QosKind<DataWriterQos>,
     ) -> DdsResult<()> {
+        let timestamp = self.get_current_time();
         let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let qos = match qos {
-            QosKind::Default => publisher.default_datawriter_qos().clone(),
-            QosKind::Specific(q) => q,
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_writer.unregister_all_instances(timestamp).await
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn unregister_instance(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        mut serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+    ) -> DdsResult<()> {
+        if !self.encapsulation_padding_encoding {
+            clear_representation_options(&mut serialized_data);
+        }
+        // The held writes are published first so that they are not received after the unregistration
+        self.publish_coalesced_writes(&participant_address, publisher_handle, data_writer_handle)
+            .await?;
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
         let Some(data_writer) = publisher
             .data_writer_list_mut()
@@ -1687,224 +2625,439 @@ where
         else {
             return Err(DdsError::AlreadyDeleted);
         };
-
-        match data_writer.set_qos(qos) {
-            Ok(_) => (),
-            Err(e) => {
-                return Err(e);
-            }
-        }
-        if data_writer.enabled() {
-            self.announce_data_writer(publisher_handle, data_writer_handle)
-                .await;
-        }
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(&serialized_data, data_writer.type_support())?;
+        data_writer.check_instance_handle(&instance_handle, handle)?;
+        // The key of a registered instance is only extracted from the sample once
+        let serialized_key = match data_writer.registered_serialized_key(&instance_handle)? {
+            Some(k) => k,
+            None => get_serialized_key_from_serialized_foo(
+                &serialized_data,
+                data_writer.type_support(),
+            )?,
+        };
+        let now = self.clock_handle.now();
+        data_writer
+            .unregister_instance_w_timestamp(instance_handle, serialized_key, timestamp)
+            .await?;
+        data_writer.assert_liveliness(now);
         Ok(())
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn are_all_changes_acknowledged(
+    pub fn lookup_instance(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-    ) -> DdsResult<bool> {
-        let Some(publisher) = self.domain_participant.get_publisher(publisher_handle) else {
+        serialized_data: Vec<u8>,
+    ) -> DdsResult<Option<InstanceHandle>> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-
-        let Some(data_writer) = publisher.get_data_writer(data_writer_handle) else {
+        let Some(data_writer) = publisher
+            .data_writer_list_mut()
+            .find(|x| x.instance_handle() == data_writer_handle)
+        else {
             return Err(DdsError::AlreadyDeleted);
         };
-        Ok(data_writer.are_all_changes_acknowledged())
-    }
-
-    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
-    #[tracing::instrument(skip(self))]
-    pub async fn read(
-        &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        max_samples: i32,
-        sample_states: Vec<SampleStateKind>,
-        view_states: Vec<ViewStateKind>,
-        instance_states: Vec<InstanceStateKind>,
-        specific_instance_handle: Option<InstanceHandle>,
-    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
-        let subscriber = if subscriber_handle == self.domain_participant.instance_handle() {
-            Some(self.domain_participant.builtin_subscriber_mut())
-        } else {
-            self.domain_participant
-                .get_mut_subscriber(subscriber_handle)
-        };
 
-        let Some(subscriber) = subscriber else {
-            return Err(DdsError::AlreadyDeleted);
-        };
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
 
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
-            return Err(DdsError::AlreadyDeleted);
+        let instance_handle = match get_instance_handle_from_serialized_foo(
+            &serialized_data,
+            data_writer.type_support(),
+        ) {
+            Ok(k) => k,
+            Err(e) => {
+                return Err(e.into());
+            }
         };
 
-        data_reader
-            .read(
-                max_samples,
-                &sample_states,
-                &view_states,
-                &instance_states,
-                specific_instance_handle,
-            )
-            .await
+        Ok(data_writer
+            .contains_instance(&instance_handle)
+            .then_some(instance_handle))
     }
 
-    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     #[tracing::instrument(skip(self))]
-    pub async fn take(
+    pub fn loan_data_writer_sample(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        max_samples: i32,
-        sample_states: Vec<SampleStateKind>,
-        view_states: Vec<ViewStateKind>,
-        instance_states: Vec<InstanceStateKind>,
-        specific_instance_handle: Option<InstanceHandle>,
-    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<Option<Box<dyn Any + Send>>> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        data_reader
-            .take(
-                max_samples,
-                sample_states,
-                view_states,
-                instance_states,
-                specific_instance_handle,
-            )
-            .await
+
+        Ok(data_writer.loan_sample_storage())
     }
 
-    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
-    #[tracing::instrument(skip(self))]
-    pub async fn read_next_instance(
+    #[tracing::instrument(skip(self, sample))]
+    pub fn return_data_writer_loaned_sample(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        max_samples: i32,
-        previous_handle: Option<InstanceHandle>,
-        sample_states: Vec<SampleStateKind>,
-        view_states: Vec<ViewStateKind>,
-        instance_states: Vec<InstanceStateKind>,
-    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        sample: Box<dyn Any + Send>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        data_reader
-            .read_next_instance(
-                max_samples,
-                previous_handle,
-                &sample_states,
-                &view_states,
-                &instance_states,
-            )
-            .await
+
+        data_writer.return_loaned_sample_storage(sample);
+        Ok(())
     }
 
-    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     #[tracing::instrument(skip(self))]
-    pub async fn take_next_instance(
+    pub fn get_data_writer_key_value(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        max_samples: i32,
-        previous_handle: Option<InstanceHandle>,
-        sample_states: Vec<SampleStateKind>,
-        view_states: Vec<ViewStateKind>,
-        instance_states: Vec<InstanceStateKind>,
-    ) -> DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        serialized_key_holder: Vec<u8>,
+        handle: InstanceHandle,
+    ) -> DdsResult<Vec<u8>> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        data_reader
-            .take_next_instance(
-                max_samples,
-                previous_handle,
-                sample_states,
-                view_states,
-                instance_states,
-            )
-            .await
+
+        data_writer.get_key_value(&serialized_key_holder, handle)
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn get_subscription_matched_status(
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn write_w_timestamp(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-    ) -> DdsResult<SubscriptionMatchedStatus> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        mut serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+        sample_hash: Option<u64>,
+        reader_handle_list: Vec<InstanceHandle>,
+        original_writer_info: Option<OriginalWriterInfo>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        if !self.encapsulation_padding_encoding {
+            clear_representation_options(&mut serialized_data);
+        }
+        self.update_data_writer_publication_throttling(
+            &participant_address,
+            publisher_handle,
+            data_writer_handle,
+        )
+        .await;
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher
+            .data_writer_list_mut()
+            .find(|x| x.instance_handle() == data_writer_handle)
+        else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let status = data_reader.get_subscription_matched_status();
-        data_reader
-            .status_condition()
-            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
-                state: StatusKind::SubscriptionMatched,
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
+        if data_writer.is_draining() {
+            return Err(DdsError::PreconditionNotMet(
+                "DataWriter is draining".to_string(),
+            ));
+        }
+        let instance_handle = match get_instance_handle_from_serialized_foo(
+            &serialized_data,
+            data_writer.type_support(),
+        ) {
+            Ok(k) => k,
+            Err(e) => {
+                return Err(e.into());
+            }
+        };
+        data_writer.check_instance_handle(&instance_handle, handle)?;
+        let directed_write = reader_handle_list
+            .iter()
+            .map(|reader_handle| {
+                data_writer
+                    .get_matched_subscription_data(reader_handle)
+                    .map(|s| Guid::from(s.key().value))
+                    .ok_or(DdsError::BadParameter)
             })
-            .await;
-        Ok(status)
+            .collect::<DdsResult<Vec<_>>>()?;
+        let original_writer_info =
+            original_writer_info.map(|x| history_cache::OriginalWriterInfo {
+                original_writer_guid: Guid::from(<[u8; 16]>::from(x.original_writer)),
+                original_writer_sn: x.original_sequence_number,
+            });
+
+        // The directed and forwarded writes are not held since they could be replaced by a write addressed
+        // to other readers or from another original writer
+        if let Some(write_holding_window) = data_writer
+            .write_holding_window()
+            .filter(|_| directed_write.is_empty() && original_writer_info.is_none())
+        {
+            if let Some(window_id) =
+                data_writer.coalesce_write(instance_handle, serialized_data, timestamp, sample_hash)
+            {
+                let mut timer_handle = self.timer_handle.clone();
+                self.spawner_handle.spawn(async move {
+                    timer_handle.delay(write_holding_window.into()).await;
+                    participant_address
+                        .send(DomainParticipantMail::Event(
+                            EventServiceMail::PublishCoalescedWrite {
+                                participant_address: participant_address.clone(),
+                                publisher_handle,
+                                data_writer_handle,
+                                instance_handle,
+                                window_id,
+                            },
+                        ))
+                        .await
+                        .ok();
+                });
+            }
+            return Ok(None);
+        }
+        // A write still held from before the throttling stopped or the window was disabled is superseded by this one
+        data_writer.take_coalesced_write(&instance_handle);
+
+        self.publish_data_writer_sample(
+            participant_address,
+            publisher_handle,
+            data_writer_handle,
+            instance_handle,
+            serialized_data,
+            timestamp,
+            sample_hash,
+            directed_write,
+            original_writer_info,
+        )
+        .await
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub fn wait_for_historical_data(
+    #[allow(clippy::too_many_arguments)]
+    async fn publish_data_writer_sample(
         &mut self,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        max_wait: Duration,
-    ) -> Pin<Box<dyn Future<Output = DdsResult<()>> + Send>> {
-        let timer_handle = self.timer_handle.clone();
-        Box::pin(async move {
-            poll_timeout(
-                timer_handle,
-                max_wait.into(),
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+        timestamp: Time,
+        sample_hash: Option<u64>,
+        directed_write: Vec<Guid>,
+        original_writer_info: Option<history_cache::OriginalWriterInfo>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let now = self.get_current_time();
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        // A directed or forwarded write is not the last sample published by the writer to all the readers so it
+        // is not suppressed
+        let sample_digest = if directed_write.is_empty() && original_writer_info.is_none() {
+            data_writer.sample_digest(&serialized_data, sample_hash)
+        } else {
+            None
+        };
+        if let Some(sample_digest) = &sample_digest {
+            if data_writer.is_last_published_sample(&instance_handle, sample_digest) {
+                data_writer.assert_liveliness(now);
+                return Ok(None);
+            }
+        }
+
+        let sequence_number = match data_writer.qos().lifespan.duration {
+            DurationKind::Finite(lifespan_duration) => {
+                let mut timer_handle = self.timer_handle.clone();
+                let sleep_duration = timestamp - now + lifespan_duration;
+                if sleep_duration > Duration::new(0, 0) {
+                    let sequence_number = match data_writer
+                        .write_instance_w_timestamp(
+                            instance_handle,
+                            serialized_data,
+                            timestamp,
+                            directed_write,
+                            original_writer_info,
+                            &self.clock_handle,
+                        )
+                        .await
+                    {
+                        Ok(s) => s,
+                        Err(e) => {
+                            return Err(e);
+                        }
+                    };
+
+                    let participant_address = participant_address.clone();
+                    self.spawner_handle.spawn(async move {
+                        timer_handle.delay(sleep_duration.into()).await;
+                        participant_address
+                            .send(DomainParticipantMail::Message(
+                                MessageServiceMail::RemoveWriterChange {
+                                    publisher_handle,
+                                    data_writer_handle,
+                                    sequence_number,
+                                },
+                            ))
+                            .await
+                            .ok();
+                    });
+                    Some(sequence_number)
+                } else {
+                    None
+                }
+            }
+            DurationKind::Infinite => {
+                match data_writer
+                    .write_instance_w_timestamp(
+                        instance_handle,
+                        serialized_data,
+                        timestamp,
+                        directed_write,
+                        original_writer_info,
+                        &self.clock_handle,
+                    )
+                    .await
+                {
+                    Ok(s) => Some(s),
+                    Err(e) => {
+                        return Err(e);
+                    }
+                }
+            }
+        };
+
+        if sequence_number.is_some() {
+            data_writer.set_last_published_sample(instance_handle, sample_digest);
+        }
+
+        data_writer.assert_liveliness(now);
+
+        if let Some((batch_id, max_flush_delay)) = data_writer.batch_flush_delay() {
+            let mut timer_handle = self.timer_handle.clone();
+            let participant_address = participant_address.clone();
+            self.spawner_handle.spawn(async move {
+                timer_handle.delay(max_flush_delay.into()).await;
+                participant_address
+                    .send(DomainParticipantMail::Event(
+                        EventServiceMail::FlushDataWriterBatch {
+                            publisher_handle,
+                            data_writer_handle,
+                            batch_id,
+                        },
+                    ))
+                    .await
+                    .ok();
+            });
+        }
+
+        if let DurationKind::Finite(deadline_missed_period) = data_writer.qos().deadline.period {
+            let mut timer_handle = self.timer_handle.clone();
+            self.spawner_handle.spawn(async move {
+                loop {
+                    timer_handle.delay(deadline_missed_period.into()).await;
+                    participant_address
+                        .send(DomainParticipantMail::Event(
+                            EventServiceMail::OfferedDeadlineMissed {
+                                publisher_handle,
+                                data_writer_handle,
+                                change_instance_handle: instance_handle,
+                                participant_address: participant_address.clone(),
+                            },
+                        ))
+                        .await
+                        .ok();
+                }
+            });
+        }
+
+        Ok(sequence_number)
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn dispose_w_timestamp(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        mut serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+    ) -> DdsResult<()> {
+        if !self.encapsulation_padding_encoding {
+            clear_representation_options(&mut serialized_data);
+        }
+        // The held writes are published first so that they are not received after the disposal
+        self.publish_coalesced_writes(&participant_address, publisher_handle, data_writer_handle)
+            .await?;
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list_mut()
+            .find(|x| x.instance_handle() == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let instance_handle =
+            get_instance_handle_from_serialized_foo(&serialized_data, data_writer.type_support())?;
+        data_writer.check_instance_handle(&instance_handle, handle)?;
+        // The key of a registered instance is only extracted from the sample once
+        let serialized_key = match data_writer.registered_serialized_key(&instance_handle)? {
+            Some(k) => k,
+            None => get_serialized_key_from_serialized_foo(
+                &serialized_data,
+                data_writer.type_support(),
+            )?,
+        };
+        let now = self.clock_handle.now();
+        data_writer
+            .dispose_instance_w_timestamp(instance_handle, serialized_key, timestamp)
+            .await?;
+        data_writer.assert_liveliness(now);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub fn wait_for_acknowledgments(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = DdsResult<()>> + Send>> {
+        let timer_handle = self.timer_handle.clone();
+        Box::pin(async move {
+            poll_timeout(
+                timer_handle,
+                timeout.into(),
                 Box::pin(async move {
                     loop {
                         let (reply_sender, mut reply_receiver) = R::oneshot();
                         participant_address
                             .send(DomainParticipantMail::Message(
-                                MessageServiceMail::IsHistoricalDataReceived {
-                                    subscriber_handle,
-                                    data_reader_handle,
+                                MessageServiceMail::AreAllChangesAcknowledged {
+                                    publisher_handle,
+                                    data_writer_handle,
                                     reply_sender,
                                 },
                             ))
-                            .await?;
-
+                            .await
+                            .ok();
                         let reply = reply_receiver.receive().await;
                         match reply {
-                            Ok(historical_data_received) => match historical_data_received {
+                            Ok(are_changes_acknowledged) => match are_changes_acknowledged {
                                 Ok(true) => return Ok(()),
                                 Ok(false) => (),
                                 Err(e) => return Err(e),
@@ -1919,1151 +3072,3119 @@ where
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn get_matched_publication_data(
+    pub async fn get_offered_deadline_missed_status(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        publication_handle: InstanceHandle,
-    ) -> DdsResult<PublicationBuiltinTopicData> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<OfferedDeadlineMissedStatus> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher
+            .data_writer_list_mut()
+            .find(|x| x.instance_handle() == data_writer_handle)
+        else {
             return Err(DdsError::AlreadyDeleted);
         };
-        if !data_reader.enabled() {
-            return Err(DdsError::NotEnabled);
-        }
 
-        data_reader
-            .get_matched_publication_data(&publication_handle)
-            .cloned()
-            .ok_or(DdsError::BadParameter)
+        Ok(data_writer.get_offered_deadline_missed_status().await)
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn get_matched_publications(
+    pub async fn get_offered_incompatible_qos_status(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-    ) -> DdsResult<Vec<InstanceHandle>> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<OfferedIncompatibleQosStatus> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
 
-        Ok(data_reader.get_matched_publications())
+        let status = data_writer.get_offered_incompatible_qos_status();
+
+        data_writer
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::OfferedIncompatibleQos,
+            })
+            .await;
+        Ok(status)
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn set_data_reader_qos(
+    pub async fn get_liveliness_lost_status(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        qos: QosKind<DataReaderQos>,
-    ) -> DdsResult<()> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<LivelinessLostStatus> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let qos = match qos {
-            QosKind::Default => subscriber.default_data_reader_qos().clone(),
-            QosKind::Specific(q) => q,
-        };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
 
-        match data_reader.set_qos(qos) {
-            Ok(_) => (),
-            Err(e) => {
-                return Err(e);
-            }
-        };
-
-        if data_reader.enabled() {
-            self.announce_data_reader(subscriber_handle, data_reader_handle)
-                .await;
-        }
-
-        Ok(())
+        Ok(data_writer.get_liveliness_lost_status().await)
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn get_data_reader_qos(
+    pub async fn get_publication_throttled_status(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-    ) -> DdsResult<DataReaderQos> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<PublicationThrottledStatus> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        Ok(data_reader.qos().clone())
+
+        Ok(data_writer.get_publication_throttled_status().await)
     }
 
-    #[tracing::instrument(skip(self, listener_sender))]
-    pub fn set_data_reader_listener(
+    #[tracing::instrument(skip(self))]
+    pub fn set_data_writer_keep_last_replacement_policy(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-        listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
-        listener_mask: Vec<StatusKind>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        policy: KeepLastReplacementPolicy,
     ) -> DdsResult<()> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        data_reader.set_listener(listener_sender, listener_mask);
+        data_writer.set_keep_last_replacement_policy(policy);
         Ok(())
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn is_historical_data_received(
+    pub fn set_data_writer_write_coalescing_window(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-    ) -> DdsResult<bool> {
-        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        window: Option<Duration>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
+        data_writer.set_write_coalescing_window(window)
+    }
 
-        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+    #[tracing::instrument(skip(self, participant_address))]
+    pub fn set_data_writer_publication_throttling(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        publication_throttling: Option<PublicationThrottling>,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        if !data_reader.enabled() {
-            return Err(DdsError::NotEnabled);
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-
-        match data_reader.qos().durability.kind {
-            DurabilityQosPolicyKind::Volatile => {
-                return Err(DdsError::IllegalOperation);
+        data_writer.set_publication_throttling(publication_throttling)?;
+        // The lag of the readers is also checked periodically so that the throttling stops once the readers
+        // caught up even if nothing is written anymore
+        if let Some(publication_throttling) = publication_throttling {
+            if !data_writer.set_publication_throttling_check_scheduled(true) {
+                self.spawn_publication_throttling_check(
+                    publisher_handle,
+                    data_writer_handle,
+                    publication_throttling.throttled_publication_period.into(),
+                    participant_address,
+                );
             }
-            DurabilityQosPolicyKind::TransientLocal
-            | DurabilityQosPolicyKind::Transient
-            | DurabilityQosPolicyKind::Persistent => (),
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_data_writer_duplicate_suppression_mode(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        mode: DuplicateSuppressionMode,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
+        data_writer.set_duplicate_suppression_mode(mode);
+        Ok(())
+    }
 
-        if let TransportReaderKind::Stateful(r) = data_reader.transport_reader() {
-            Ok(r.is_historical_data_received())
-        } else {
-            Ok(true)
+    #[tracing::instrument(skip(self, change_lifecycle_observer))]
+    pub fn set_data_writer_change_lifecycle_observer(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        // Stateless writers are only used for the builtin participant discovery
+        if let TransportWriterKind::Stateful(w) = data_writer.transport_writer_mut() {
+            w.set_change_lifecycle_observer(change_lifecycle_observer);
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_replaced_unacknowledged_sample_count(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<u64> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(data_writer.replaced_unacknowledged_sample_count())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn assert_data_writer_liveliness(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let timestamp = self.get_current_time();
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_writer.enabled() {
+            return Err(DdsError::NotEnabled);
         }
+
+        data_writer.assert_liveliness(timestamp);
+        if data_writer.qos().liveliness.kind == LivelinessQosPolicyKind::ManualByParticipant {
+            self.announce_participant_message(
+                PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE,
+            )
+            .await;
+        }
+
+        Ok(())
     }
 
     #[tracing::instrument(skip(self, participant_address))]
-    pub async fn enable_data_reader(
+    pub async fn enable_data_writer(
         &mut self,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) -> DdsResult<()> {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        if !publisher.enabled() {
+            return Err(DdsError::PreconditionNotMet(
+                "Parent publisher is disabled".to_string(),
+            ));
+        }
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return Err(DdsError::AlreadyDeleted);
         };
-        if !data_reader.enabled() {
-            data_reader.enable();
+        if !data_writer.enabled() {
+            data_writer.enable();
+            // The writers of a topic with a bandwidth quota are paced by the flow controller of the quota
+            let topic_name = String::from(data_writer.topic_name());
+            if self
+                .domain_participant
+                .get_mut_topic(&topic_name)
+                .is_some_and(|t| t.bandwidth_quota().is_some())
+            {
+                if let Some(data_writer) = self
+                    .domain_participant
+                    .get_mut_publisher(publisher_handle)
+                    .and_then(|p| p.get_mut_data_writer(data_writer_handle))
+                {
+                    data_writer.set_bandwidth_quota_flow_controller(true);
+                }
+            }
 
-            let discovered_writer_list: Vec<_> = self
+            let discovered_reader_list: Vec<_> = self
                 .domain_participant
-                .publication_builtin_topic_data_list()
+                .discovered_reader_data_list()
                 .cloned()
                 .collect();
-            for discovered_writer_data in discovered_writer_list {
-                self.add_discovered_writer(
-                    discovered_writer_data,
-                    subscriber_handle,
-                    data_reader_handle,
+            for discovered_reader_data in discovered_reader_list {
+                self.add_discovered_reader(
+                    discovered_reader_data,
+                    publisher_handle,
+                    data_writer_handle,
                     participant_address.clone(),
                 )
                 .await;
             }
 
-            self.announce_data_reader(subscriber_handle, data_reader_handle)
+            self.announce_data_writer(publisher_handle, data_writer_handle)
                 .await;
-        }
-        Ok(())
-    }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn announce_participant(&mut self) {
-        if self.domain_participant.enabled() {
-            let participant_builtin_topic_data = ParticipantBuiltinTopicData {
-                key: BuiltInTopicKey {
-                    value: self.transport.guid().into(),
-                },
-                user_data: self.domain_participant.qos().user_data.clone(),
-            };
-            let participant_proxy = ParticipantProxy {
-                domain_id: Some(self.domain_participant.domain_id()),
-                domain_tag: String::from(self.domain_participant.domain_tag()),
-                protocol_version: self.transport.protocol_version(),
-                guid_prefix: self.transport.guid().prefix(),
-                vendor_id: self.transport.vendor_id(),
-                expects_inline_qos: false,
-                metatraffic_unicast_locator_list: self
-                    .transport
-                    .metatraffic_unicast_locator_list()
-                    .to_vec(),
-                metatraffic_multicast_locator_list: self
-                    .transport
-                    .metatraffic_multicast_locator_list()
-                    .to_vec(),
-                default_unicast_locator_list: self
-                    .transport
-                    .default_unicast_locator_list()
-                    .to_vec(),
-                default_multicast_locator_list: self
-                    .transport
-                    .default_multicast_locator_list()
-                    .to_vec(),
-                available_builtin_endpoints: BuiltinEndpointSet::default(),
-                manual_liveliness_count: 0,
-                builtin_endpoint_qos: BuiltinEndpointQos::default(),
+            let timestamp = self.get_current_time();
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return Err(DdsError::AlreadyDeleted);
             };
-            let spdp_discovered_participant_data = SpdpDiscoveredParticipantData {
-                dds_participant_data: participant_builtin_topic_data,
-                participant_proxy,
-                lease_duration: Duration::new(100, 0),
-                discovered_participant_list: self.domain_participant.get_discovered_participants(),
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return Err(DdsError::AlreadyDeleted);
             };
-            let timestamp = self.get_current_time();
-
-            if let Some(dw) = self
-                .domain_participant
-                .builtin_publisher_mut()
-                .lookup_datawriter_mut(DCPS_PARTICIPANT)
-            {
-                if let Ok(serialized_data) = spdp_discovered_participant_data.serialize_data() {
-                    dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                        .await
-                        .ok();
-                }
+            data_writer.assert_liveliness(timestamp);
+            let liveliness = data_writer.qos().liveliness.clone();
+            if liveliness.kind == LivelinessQosPolicyKind::Automatic {
+                self.start_automatic_liveliness_assertion(participant_address);
+            } else if let DurationKind::Finite(lease_duration) = liveliness.lease_duration {
+                self.spawn_data_writer_liveliness_check(
+                    publisher_handle,
+                    data_writer_handle,
+                    core::time::Duration::from(lease_duration) / 2,
+                    participant_address,
+                );
             }
         }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn announce_deleted_participant(&mut self) {
-        if self.domain_participant.enabled() {
-            let timestamp = self.get_current_time();
-            if let Some(dw) = self
-                .domain_participant
-                .builtin_publisher_mut()
-                .lookup_datawriter_mut(DCPS_PARTICIPANT)
-            {
-                let key = InstanceHandle::new(self.transport.guid().into());
-                if let Ok(serialized_data) = key.serialize_data() {
-                    dw.dispose_w_timestamp(serialized_data, timestamp)
-                        .await
-                        .ok();
-                }
+    pub async fn set_data_writer_qos(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        qos: QosKind<DataWriterQos>,
+    ) -> DdsResult<()> {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let qos = match qos {
+            QosKind::Default => publisher.default_datawriter_qos().clone(),
+            QosKind::Specific(q) => q,
+        };
+        let Some(data_writer) = publisher
+            .data_writer_list_mut()
+            .find(|x| x.instance_handle() == data_writer_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        match data_writer.set_qos(qos) {
+            Ok(_) => (),
+            Err(e) => {
+                return Err(e);
             }
         }
+        if data_writer.enabled() {
+            self.announce_data_writer(publisher_handle, data_writer_handle)
+                .await;
+        }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn announce_data_writer(
+    pub fn are_all_changes_acknowledged(
         &mut self,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
-    ) {
+    ) -> DdsResult<bool> {
         let Some(publisher) = self.domain_participant.get_publisher(publisher_handle) else {
-            return;
+            return Err(DdsError::AlreadyDeleted);
         };
+
         let Some(data_writer) = publisher.get_data_writer(data_writer_handle) else {
-            return;
-        };
-        let Some(topic) = self.domain_participant.get_topic(data_writer.topic_name()) else {
-            return;
+            return Err(DdsError::AlreadyDeleted);
         };
+        Ok(data_writer.are_all_changes_acknowledged())
+    }
 
-        let topic_data = topic.qos().topic_data.clone();
-
-        let dds_publication_data = PublicationBuiltinTopicData {
-            key: BuiltInTopicKey {
-                value: data_writer.transport_writer().guid().into(),
-            },
-            participant_key: BuiltInTopicKey { value: [0; 16] },
-            topic_name: String::from(data_writer.topic_name()),
-            type_name: String::from(data_writer.type_name()),
-            durability: data_writer.qos().durability.clone(),
-            deadline: data_writer.qos().deadline.clone(),
-            latency_budget: data_writer.qos().latency_budget.clone(),
-            liveliness: data_writer.qos().liveliness.clone(),
-            reliability: data_writer.qos().reliability.clone(),
-            lifespan: data_writer.qos().lifespan.clone(),
-            user_data: data_writer.qos().user_data.clone(),
-            ownership: data_writer.qos().ownership.clone(),
-            ownership_strength: data_writer.qos().ownership_strength.clone(),
-            destination_order: data_writer.qos().destination_order.clone(),
-            presentation: publisher.qos().presentation.clone(),
-            partition: publisher.qos().partition.clone(),
-            topic_data,
-            group_data: publisher.qos().group_data.clone(),
-            representation: data_writer.qos().representation.clone(),
-        };
-        let writer_proxy = WriterProxy {
-            remote_writer_guid: data_writer.transport_writer().guid(),
-            remote_group_entity_id: ENTITYID_UNKNOWN,
-            unicast_locator_list: vec![],
-            multicast_locator_list: vec![],
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[tracing::instrument(skip(self))]
+    pub async fn read(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<Vec<(Option<SampleData>, SampleInfo)>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let discovered_writer_data = DiscoveredWriterData {
-            dds_publication_data,
-            writer_proxy,
+
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let timestamp = self.get_current_time();
-        if let Some(dw) = self
-            .domain_participant
-            .builtin_publisher_mut()
-            .lookup_datawriter_mut(DCPS_PUBLICATION)
-        {
-            if let Ok(serialized_data) = discovered_writer_data.serialize_data() {
-                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                    .await
-                    .ok();
-            }
-        }
+
+        let samples = data_reader
+            .read(
+                max_samples,
+                &sample_states,
+                &view_states,
+                &instance_states,
+                specific_instance_handle,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        samples
     }
 
-    #[tracing::instrument(skip(self, data_writer))]
-    async fn announce_deleted_data_writer(&mut self, data_writer: DataWriterEntity<R>) {
-        let timestamp = self.get_current_time();
-        if let Some(dw) = self
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn take(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<Vec<(Option<SampleData>, SampleInfo)>> {
+        let Some(subscriber) = self
             .domain_participant
-            .builtin_publisher_mut()
-            .lookup_datawriter_mut(DCPS_PUBLICATION)
-        {
-            let key = InstanceHandle::new(data_writer.transport_writer().guid().into());
-            if let Ok(serialized_data) = key.serialize_data() {
-                dw.dispose_w_timestamp(serialized_data, timestamp)
-                    .await
-                    .ok();
-            }
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let samples = data_reader
+            .take(
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        if samples.is_ok() {
+            self.deliver_held_changes(participant_address, subscriber_handle, data_reader_handle)
+                .await;
         }
+        samples
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[tracing::instrument(skip(self))]
-    async fn announce_data_reader(
+    pub async fn read_loaned(
         &mut self,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
-    ) {
-        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
-            return;
-        };
-        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
-            return;
-        };
-        let Some(topic) = self.domain_participant.get_topic(data_reader.topic_name()) else {
-            return;
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<LoanedSampleList> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
 
-        let guid = data_reader.transport_reader().guid();
-        let dds_subscription_data = SubscriptionBuiltinTopicData {
-            key: BuiltInTopicKey { value: guid.into() },
-            participant_key: BuiltInTopicKey { value: [0; 16] },
-            topic_name: String::from(data_reader.topic_name()),
-            type_name: String::from(data_reader.type_name()),
-            durability: data_reader.qos().durability.clone(),
-            deadline: data_reader.qos().deadline.clone(),
-            latency_budget: data_reader.qos().latency_budget.clone(),
-            liveliness: data_reader.qos().liveliness.clone(),
-            reliability: data_reader.qos().reliability.clone(),
-            ownership: data_reader.qos().ownership.clone(),
-            destination_order: data_reader.qos().destination_order.clone(),
-            user_data: data_reader.qos().user_data.clone(),
-            time_based_filter: data_reader.qos().time_based_filter.clone(),
-            presentation: subscriber.qos().presentation.clone(),
-            partition: subscriber.qos().partition.clone(),
-            topic_data: topic.qos().topic_data.clone(),
-            group_data: subscriber.qos().group_data.clone(),
-            representation: data_reader.qos().representation.clone(),
-        };
-        let reader_proxy = ReaderProxy {
-            remote_reader_guid: data_reader.transport_reader().guid(),
-            remote_group_entity_id: ENTITYID_UNKNOWN,
-            unicast_locator_list: vec![],
-            multicast_locator_list: vec![],
-            expects_inline_qos: false,
-        };
-        let discovered_reader_data = DiscoveredReaderData {
-            dds_subscription_data,
-            reader_proxy,
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let timestamp = self.get_current_time();
-        if let Some(dw) = self
-            .domain_participant
-            .builtin_publisher_mut()
-            .lookup_datawriter_mut(DCPS_SUBSCRIPTION)
-        {
-            if let Ok(serialized_data) = discovered_reader_data.serialize_data() {
-                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                    .await
-                    .ok();
-            }
-        }
+
+        let samples = data_reader
+            .read_loaned(
+                max_samples,
+                &sample_states,
+                &view_states,
+                &instance_states,
+                specific_instance_handle,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        samples
     }
 
-    #[tracing::instrument(skip(self, data_reader))]
-    async fn announce_deleted_data_reader(&mut self, data_reader: DataReaderEntity<R>) {
-        let timestamp = self.get_current_time();
-        if let Some(dw) = self
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn take_loaned(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+    ) -> DdsResult<LoanedSampleList> {
+        let Some(subscriber) = self
             .domain_participant
-            .builtin_publisher_mut()
-            .lookup_datawriter_mut(DCPS_SUBSCRIPTION)
-        {
-            let guid = data_reader.transport_reader().guid();
-            let key = InstanceHandle::new(guid.into());
-            if let Ok(serialized_data) = key.serialize_data() {
-                dw.dispose_w_timestamp(serialized_data, timestamp)
-                    .await
-                    .ok();
-            }
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let samples = data_reader
+            .take_loaned(
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        if samples.is_ok() {
+            self.deliver_held_changes(participant_address, subscriber_handle, data_reader_handle)
+                .await;
         }
+        samples
     }
 
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     #[tracing::instrument(skip(self))]
-    async fn announce_topic(&mut self, topic_name: String) {
-        let Some(topic) = self.domain_participant.get_topic(&topic_name) else {
-            return;
+    pub async fn read_next_instance(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> DdsResult<Vec<(Option<SampleData>, SampleInfo)>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
-
-        let topic_builtin_topic_data = TopicBuiltinTopicData {
-            key: BuiltInTopicKey {
-                value: topic.instance_handle().into(),
-            },
-            name: String::from(topic.topic_name()),
-            type_name: String::from(topic.type_name()),
-            durability: topic.qos().durability.clone(),
-            deadline: topic.qos().deadline.clone(),
-            latency_budget: topic.qos().latency_budget.clone(),
-            liveliness: topic.qos().liveliness.clone(),
-            reliability: topic.qos().reliability.clone(),
-            transport_priority: topic.qos().transport_priority.clone(),
-            lifespan: topic.qos().lifespan.clone(),
-            destination_order: topic.qos().destination_order.clone(),
-            history: topic.qos().history.clone(),
-            resource_limits: topic.qos().resource_limits.clone(),
-            ownership: topic.qos().ownership.clone(),
-            topic_data: topic.qos().topic_data.clone(),
-            representation: topic.qos().representation.clone(),
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
+        let samples = data_reader
+            .read_next_instance(
+                max_samples,
+                previous_handle,
+                &sample_states,
+                &view_states,
+                &instance_states,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        samples
+    }
 
-        let timestamp = self.get_current_time();
-        if let Some(dw) = self
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn take_next_instance(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+    ) -> DdsResult<Vec<(Option<SampleData>, SampleInfo)>> {
+        let Some(subscriber) = self
             .domain_participant
-            .builtin_publisher_mut()
-            .lookup_datawriter_mut(DCPS_TOPIC)
-        {
-            if let Ok(serialized_data) = topic_builtin_topic_data.serialize_data() {
-                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
-                    .await
-                    .ok();
-            }
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let samples = data_reader
+            .take_next_instance(
+                max_samples,
+                previous_handle,
+                sample_states,
+                view_states,
+                instance_states,
+            )
+            .await;
+        subscriber
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::DataOnReaders,
+            })
+            .await;
+        if samples.is_ok() {
+            self.deliver_held_changes(participant_address, subscriber_handle, data_reader_handle)
+                .await;
         }
+        samples
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    async fn add_discovered_reader(
+    async fn deliver_held_changes(
         &mut self,
-        discovered_reader_data: DiscoveredReaderData,
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
     ) {
-        let default_unicast_locator_list = if let Some(p) = self
+        let Some(subscriber) = self
             .domain_participant
-            .discovered_participant_list()
-            .find(|p| {
-                p.participant_proxy.guid_prefix
-                    == discovered_reader_data
-                        .reader_proxy
-                        .remote_reader_guid
-                        .prefix()
-            }) {
-            p.participant_proxy.default_unicast_locator_list.clone()
-        } else {
-            vec![]
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
         };
-        let default_multicast_locator_list = if let Some(p) = self
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        for (cache_change, reception_timestamp) in data_reader.take_held_changes() {
+            self.add_data_reader_change(
+                participant_address.clone(),
+                cache_change,
+                reception_timestamp,
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_subscription_matched_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<SubscriptionMatchedStatus> {
+        let Some(subscriber) = self
             .domain_participant
-            .discovered_participant_list()
-            .find(|p| {
-                p.participant_proxy.guid_prefix
-                    == discovered_reader_data
-                        .reader_proxy
-                        .remote_reader_guid
-                        .prefix()
-            }) {
-            p.participant_proxy.default_multicast_locator_list.clone()
-        } else {
-            vec![]
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
-            return;
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
+        let status = data_reader.get_subscription_matched_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::SubscriptionMatched,
+            })
+            .await;
+        Ok(status)
+    }
 
-        let is_any_name_matched = discovered_reader_data
-            .dds_subscription_data
-            .partition
-            .name
-            .iter()
-            .any(|n| publisher.qos().partition.name.contains(n));
+    #[tracing::instrument(skip(self))]
+    pub async fn get_requested_incompatible_qos_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<RequestedIncompatibleQosStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_requested_incompatible_qos_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::RequestedIncompatibleQos,
+            })
+            .await;
+        Ok(status)
+    }
 
-        let is_any_received_regex_matched_with_partition_qos = true;
-        // discovered_reader_data
-        //     .dds_subscription_data
-        //     .partition
-        //     .name
-        //     .iter()
-        //     // .filter_map(|n| glob_to_regex(n).ok())
-        //     .any(|regex| {
-        //         publisher
-        //             .qos()
-        //             .partition
-        //             .name
-        //             .iter()
-        //             .any(|n| regex.is_match(n))
-        //     });
+    #[tracing::instrument(skip(self))]
+    pub async fn get_sample_lost_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<SampleLostStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_sample_lost_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::SampleLost,
+            })
+            .await;
+        Ok(status)
+    }
 
-        let is_any_local_regex_matched_with_received_partition_qos = true;
-        // publisher
-        //     .qos()
-        //     .partition
-        //     .name
-        //     .iter()
-        //     .filter_map(|n| glob_to_regex(n).ok())
-        //     .any(|regex| {
-        //         discovered_reader_data
-        //             .dds_subscription_data
-        //             .partition
-        //             .name
-        //             .iter()
-        //             .any(|n| regex.is_match(n))
-        //     });
+    #[tracing::instrument(skip(self))]
+    pub async fn get_writer_restarted_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<WriterRestartedStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_writer_restarted_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::WriterRestarted,
+            })
+            .await;
+        Ok(status)
+    }
 
-        let is_partition_matched = discovered_reader_data.dds_subscription_data.partition
-            == publisher.qos().partition
-            || is_any_name_matched
-            || is_any_received_regex_matched_with_partition_qos
-            || is_any_local_regex_matched_with_received_partition_qos;
-        if is_partition_matched {
-            let publisher_qos = publisher.qos().clone();
-            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
-                return;
-            };
+    #[tracing::instrument(skip(self))]
+    pub async fn get_requested_deadline_missed_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<RequestedDeadlineMissedStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_requested_deadline_missed_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::RequestedDeadlineMissed,
+            })
+            .await;
+        Ok(status)
+    }
 
-            let is_matched_topic_name = discovered_reader_data.dds_subscription_data.topic_name()
-                == data_writer.topic_name();
-            let is_matched_type_name = discovered_reader_data.dds_subscription_data.get_type_name()
-                == data_writer.type_name();
+    #[tracing::instrument(skip(self))]
+    pub async fn get_liveliness_changed_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<LivelinessChangedStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_liveliness_changed_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::LivelinessChanged,
+            })
+            .await;
+        Ok(status)
+    }
 
-            if is_matched_topic_name && is_matched_type_name {
-                let incompatible_qos_policy_list =
-                    get_discovered_reader_incompatible_qos_policy_list(
-                        data_writer.qos(),
-                        &discovered_reader_data.dds_subscription_data,
-                        &publisher_qos,
-                    );
-                if incompatible_qos_policy_list.is_empty() {
-                    data_writer.add_matched_subscription(
-                        discovered_reader_data.dds_subscription_data.clone(),
-                    );
+    #[tracing::instrument(skip(self))]
+    pub async fn get_sample_rejected_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<SampleRejectedStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let status = data_reader.get_sample_rejected_status();
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::RemoveCommunicationState {
+                state: StatusKind::SampleRejected,
+            })
+            .await;
+        Ok(status)
+    }
 
-                    let unicast_locator_list = if discovered_reader_data
-                        .reader_proxy
-                        .unicast_locator_list
-                        .is_empty()
-                    {
-                        default_unicast_locator_list
-                    } else {
-                        discovered_reader_data.reader_proxy.unicast_locator_list
-                    };
-                    let multicast_locator_list = if discovered_reader_data
-                        .reader_proxy
-                        .multicast_locator_list
-                        .is_empty()
-                    {
-                        default_multicast_locator_list
-                    } else {
-                        discovered_reader_data.reader_proxy.multicast_locator_list
-                    };
-                    let reliability_kind = match discovered_reader_data
-                        .dds_subscription_data
-                        .reliability
-                        .kind
-                    {
-                        ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
-                        ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
-                    };
-                    let durability_kind =
-                        match discovered_reader_data.dds_subscription_data.durability.kind {
-                            DurabilityQosPolicyKind::Volatile => DurabilityKind::Volatile,
-                            DurabilityQosPolicyKind::TransientLocal => {
-                                DurabilityKind::TransientLocal
-                            }
-                            DurabilityQosPolicyKind::Transient => DurabilityKind::Transient,
-                            DurabilityQosPolicyKind::Persistent => DurabilityKind::Persistent,
-                        };
+    #[tracing::instrument(skip(self))]
+    pub fn request_historical_data(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
 
-                    let reader_proxy = transport::writer::ReaderProxy {
-                        remote_reader_guid: discovered_reader_data.reader_proxy.remote_reader_guid,
-                        remote_group_entity_id: discovered_reader_data
-                            .reader_proxy
-                            .remote_group_entity_id,
-                        reliability_kind,
-                        durability_kind,
-                        unicast_locator_list,
-                        multicast_locator_list,
-                        expects_inline_qos: false,
-                    };
-                    if let TransportWriterKind::Stateful(w) = data_writer.transport_writer_mut() {
-                        w.add_matched_reader(reader_proxy);
-                    }
+        if let TransportReaderKind::Stateful(r) = data_reader.transport_reader_mut() {
+            r.request_historical_data();
+        }
+        Ok(())
+    }
 
-                    if data_writer
-                        .listener_mask()
-                        .contains(&StatusKind::PublicationMatched)
-                    {
-                        let status = data_writer.get_publication_matched_status();
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        if let Some(l) = data_writer.listener() {
-                            l.send(ListenerMail::PublicationMatched { the_writer, status })
-                                .await
-                                .ok();
-                        }
-                    } else if publisher
-                        .listener_mask()
-                        .contains(&StatusKind::PublicationMatched)
-                    {
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_writer.get_publication_matched_status();
-                        if let Some(l) = publisher.listener() {
-                            l.send(ListenerMail::PublicationMatched { the_writer, status })
-                                .await
-                                .ok();
-                        }
-                    } else if self
-                        .domain_participant
-                        .listener_mask()
-                        .contains(&StatusKind::PublicationMatched)
-                    {
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_writer.get_publication_matched_status();
-                        if let Some(l) = self.domain_participant.listener() {
-                            l.send(ListenerMail::PublicationMatched { the_writer, status })
-                                .await
-                                .ok();
+    #[tracing::instrument(skip(self, participant_address))]
+    pub fn wait_for_historical_data(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_wait: Duration,
+    ) -> Pin<Box<dyn Future<Output = DdsResult<()>> + Send>> {
+        let timer_handle = self.timer_handle.clone();
+        Box::pin(async move {
+            poll_timeout(
+                timer_handle,
+                max_wait.into(),
+                Box::pin(async move {
+                    loop {
+                        let (reply_sender, mut reply_receiver) = R::oneshot();
+                        participant_address
+                            .send(DomainParticipantMail::Message(
+                                MessageServiceMail::IsHistoricalDataReceived {
+                                    subscriber_handle,
+                                    data_reader_handle,
+                                    reply_sender,
+                                },
+                            ))
+                            .await?;
+
+                        let reply = reply_receiver.receive().await;
+                        match reply {
+                            Ok(historical_data_received) => match historical_data_received {
+                                Ok(true) => return Ok(()),
+                                Ok(false) => (),
+                                Err(e) => return Err(e),
+                            },
+                            Err(_) => return Err(DdsError::Error(String::from("Channel error"))),
                         }
                     }
+                }),
+            )
+            .await?
+        })
+    }
 
-                    let Some(publisher) =
-                        self.domain_participant.get_mut_publisher(publisher_handle)
-                    else {
-                        return;
-                    };
-                    let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                    else {
-                        return;
-                    };
-                    data_writer
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::PublicationMatched,
-                        })
-                        .await;
-                } else {
-                    data_writer.add_incompatible_subscription(
-                        InstanceHandle::new(
-                            discovered_reader_data.dds_subscription_data.key().value,
-                        ),
-                        incompatible_qos_policy_list,
-                    );
-
-                    if data_writer
-                        .listener_mask()
-                        .contains(&StatusKind::OfferedIncompatibleQos)
-                    {
-                        let status = data_writer.get_offered_incompatible_qos_status();
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        if let Some(l) = data_writer.listener() {
-                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
-                                .await
-                                .ok();
-                        }
-                    } else if publisher
-                        .listener_mask()
-                        .contains(&StatusKind::OfferedIncompatibleQos)
-                    {
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_writer.get_offered_incompatible_qos_status();
-                        if let Some(l) = publisher.listener() {
-                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
-                                .await
-                                .ok();
-                        }
-                    } else if self
-                        .domain_participant
-                        .listener_mask()
-                        .contains(&StatusKind::OfferedIncompatibleQos)
-                    {
-                        let Ok(the_writer) = self.get_data_writer_async(
-                            participant_address,
-                            publisher_handle,
-                            data_writer_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(publisher) =
-                            self.domain_participant.get_mut_publisher(publisher_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_writer.get_offered_incompatible_qos_status();
-                        if let Some(l) = self.domain_participant.listener() {
-                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
-                                .await
-                                .ok();
-                        }
-                    }
+    #[tracing::instrument(skip(self))]
+    pub fn check_publication_compatibility(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_builtin_topic_data: PublicationBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
+        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(get_discovered_writer_incompatible_qos_policy_list(
+            data_reader,
+            &publication_builtin_topic_data,
+            subscriber.qos(),
+        ))
+    }
 
-                    let Some(publisher) =
-                        self.domain_participant.get_mut_publisher(publisher_handle)
-                    else {
-                        return;
-                    };
-                    let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
-                    else {
-                        return;
-                    };
-                    data_writer
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::OfferedIncompatibleQos,
-                        })
-                        .await;
-                }
-            }
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_publication_data(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<PublicationBuiltinTopicData> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
         }
+
+        data_reader
+            .get_matched_publication_data(&publication_handle)
+            .cloned()
+            .ok_or(DdsError::BadParameter)
     }
 
     #[tracing::instrument(skip(self))]
-    async fn remove_discovered_reader(
+    pub fn get_matched_publication_high_watermark(
         &mut self,
-        subscription_handle: InstanceHandle,
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-    ) {
-        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
-            return;
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<SequenceNumber> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
-            return;
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        if data_writer
-            .get_matched_subscription_data(&subscription_handle)
-            .is_some()
-        {
-            data_writer.remove_matched_subscription(&subscription_handle);
-
-            data_writer
-                .status_condition()
-                .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                    state: StatusKind::PublicationMatched,
-                })
-                .await;
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
         }
+
+        data_reader
+            .get_matched_publication_high_watermark(&publication_handle)
+            .ok_or(DdsError::BadParameter)
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    async fn add_discovered_writer(
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_publication_locators(
         &mut self,
-        discovered_writer_data: DiscoveredWriterData,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-    ) {
-        let default_unicast_locator_list = if let Some(p) = self
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
+        let Some(subscriber) = self
             .domain_participant
-            .discovered_participant_list()
-            .find(|p| {
-                p.participant_proxy.guid_prefix
-                    == discovered_writer_data
-                        .writer_proxy
-                        .remote_writer_guid
-                        .prefix()
-            }) {
-            p.participant_proxy.default_unicast_locator_list.clone()
-        } else {
-            vec![]
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
         };
-        let default_multicast_locator_list = if let Some(p) = self
-            .domain_participant
-            .discovered_participant_list()
-            .find(|p| {
-                p.participant_proxy.guid_prefix
-                    == discovered_writer_data
-                        .writer_proxy
-                        .remote_writer_guid
-                        .prefix()
-            }) {
-            p.participant_proxy.default_multicast_locator_list.clone()
-        } else {
-            vec![]
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
         };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
+
+        data_reader
+            .get_matched_publication_locators(&publication_handle)
+            .map(<[Locator]>::to_vec)
+            .ok_or(DdsError::BadParameter)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_publications(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<Vec<InstanceHandle>> {
         let Some(subscriber) = self
             .domain_participant
             .get_mut_subscriber(subscriber_handle)
         else {
-            return;
+            return Err(DdsError::AlreadyDeleted);
         };
-        let is_any_name_matched = discovered_writer_data
-            .dds_publication_data
-            .partition
-            .name
-            .iter()
-            .any(|n| subscriber.qos().partition.name.contains(n));
-
-        let is_any_received_regex_matched_with_partition_qos = true;
-        // discovered_writer_data
-        //     .dds_publication_data
-        //     .partition
-        //     .name
-        //     .iter()
-        //     .filter_map(|n| glob_to_regex(n).ok())
-        //     .any(|regex| {
-        //         subscriber
-        //             .qos()
-        //             .partition
-        //             .name
-        //             .iter()
-        //             .any(|n| regex.is_match(n))
-        //     });
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
 
-        let is_any_local_regex_matched_with_received_partition_qos = true;
-        // subscriber
-        //     .qos()
-        //     .partition
-        //     .name
-        //     .iter()
-        //     .filter_map(|n| glob_to_regex(n).ok())
-        //     .any(|regex| {
-        //         discovered_writer_data
-        //             .dds_publication_data
-        //             .partition
-        //             .name
-        //             .iter()
-        //             .any(|n| regex.is_match(n))
-        //     });
+        Ok(data_reader.get_matched_publications())
+    }
 
-        let is_partition_matched = discovered_writer_data.dds_publication_data.partition
-            == subscriber.qos().partition
-            || is_any_name_matched
-            || is_any_received_regex_matched_with_partition_qos
-            || is_any_local_regex_matched_with_received_partition_qos;
-        if is_partition_matched {
-            let subscriber_qos = subscriber.qos().clone();
-            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
-                return;
-            };
-            let is_matched_topic_name = discovered_writer_data.dds_publication_data.topic_name()
-                == data_reader.topic_name();
-            let is_matched_type_name = discovered_writer_data.dds_publication_data.get_type_name()
-                == data_reader.type_name();
+    #[tracing::instrument(skip(self))]
+    pub fn lookup_data_reader_instance(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+    ) -> DdsResult<Option<InstanceHandle>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
 
-            if is_matched_topic_name && is_matched_type_name {
-                let incompatible_qos_policy_list =
-                    get_discovered_writer_incompatible_qos_policy_list::<R>(
-                        data_reader,
-                        &discovered_writer_data.dds_publication_data,
-                        &subscriber_qos,
-                    );
-                if incompatible_qos_policy_list.is_empty() {
-                    data_reader.add_matched_publication(
-                        discovered_writer_data.dds_publication_data.clone(),
-                    );
-                    let unicast_locator_list = if discovered_writer_data
-                        .writer_proxy
-                        .unicast_locator_list
-                        .is_empty()
-                    {
-                        default_unicast_locator_list
-                    } else {
-                        discovered_writer_data.writer_proxy.unicast_locator_list
-                    };
-                    let multicast_locator_list = if discovered_writer_data
-                        .writer_proxy
-                        .multicast_locator_list
-                        .is_empty()
-                    {
-                        default_multicast_locator_list
-                    } else {
-                        discovered_writer_data.writer_proxy.multicast_locator_list
-                    };
-                    let reliability_kind = match data_reader.qos().reliability.kind {
-                        ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
-                        ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
-                    };
-                    let durability_kind = match data_reader.qos().durability.kind {
-                        DurabilityQosPolicyKind::Volatile => DurabilityKind::Volatile,
-                        DurabilityQosPolicyKind::TransientLocal => DurabilityKind::TransientLocal,
-                        DurabilityQosPolicyKind::Transient => DurabilityKind::Transient,
-                        DurabilityQosPolicyKind::Persistent => DurabilityKind::Persistent,
-                    };
-                    let writer_proxy = transport::reader::WriterProxy {
-                        remote_writer_guid: discovered_writer_data.writer_proxy.remote_writer_guid,
-                        remote_group_entity_id: discovered_writer_data
-                            .writer_proxy
-                            .remote_group_entity_id,
-                        unicast_locator_list,
-                        multicast_locator_list,
-                        reliability_kind,
-                        durability_kind,
-                    };
-                    if let TransportReaderKind::Stateful(r) = data_reader.transport_reader_mut() {
-                        r.add_matched_writer(writer_proxy);
-                    }
+        data_reader.lookup_instance(&serialized_data)
+    }
 
-                    if data_reader
-                        .listener_mask()
-                        .contains(&StatusKind::SubscriptionMatched)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_subscription_matched_status();
-                        if let Some(l) = data_reader.listener() {
-                            l.send(ListenerMail::SubscriptionMatched { the_reader, status })
-                                .await
-                                .ok();
-                        }
-                    } else if subscriber
-                        .listener_mask()
-                        .contains(&StatusKind::SubscriptionMatched)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_subscription_matched_status();
-                        if let Some(l) = subscriber.listener() {
-                            l.send(ListenerMail::SubscriptionMatched { the_reader, status })
-                                .await
-                                .ok();
-                        }
-                    } else if self
-                        .domain_participant
-                        .listener_mask()
-                        .contains(&StatusKind::SubscriptionMatched)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_subscription_matched_status();
-                        if let Some(l) = self.domain_participant.listener() {
-                            l.send(ListenerMail::SubscriptionMatched { the_reader, status })
-                                .await
-                                .ok();
-                        }
-                    }
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_reader_key_value(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_key_holder: Vec<u8>,
+        handle: InstanceHandle,
+    ) -> DdsResult<Vec<u8>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        }
 
-                    let Some(subscriber) = self
-                        .domain_participant
-                        .get_mut_subscriber(subscriber_handle)
-                    else {
-                        return;
-                    };
-                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                    else {
-                        return;
-                    };
-                    data_reader
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::SubscriptionMatched,
-                        })
-                        .await;
-                } else {
-                    data_reader.add_requested_incompatible_qos(
-                        InstanceHandle::new(
-                            discovered_writer_data.dds_publication_data.key().value,
-                        ),
-                        incompatible_qos_policy_list,
-                    );
+        data_reader.get_key_value(&serialized_key_holder, handle)
+    }
 
-                    if data_reader
-                        .listener_mask()
-                        .contains(&StatusKind::RequestedIncompatibleQos)
-                    {
-                        let status = data_reader.get_requested_incompatible_qos_status();
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        if let Some(l) = data_reader.listener() {
-                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
+    #[tracing::instrument(skip(self))]
+    pub async fn set_data_reader_qos(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        qos: QosKind<DataReaderQos>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let qos = match qos {
+            QosKind::Default => subscriber.default_data_reader_qos().clone(),
+            QosKind::Specific(q) => q,
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        match data_reader.set_qos(qos) {
+            Ok(_) => (),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        if data_reader.enabled() {
+            self.announce_data_reader(subscriber_handle, data_reader_handle)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_reader_qos(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<DataReaderQos> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(data_reader.qos().clone())
+    }
+
+    #[tracing::instrument(skip(self, listener_sender))]
+    pub fn set_data_reader_listener(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
+        listener_mask: Vec<StatusKind>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_listener(listener_sender, listener_mask);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, read_ahead_sender))]
+    pub fn set_data_reader_read_ahead_sender(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        read_ahead_sender: Option<R::ChannelSender<Arc<[u8]>>>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_read_ahead_sender(read_ahead_sender);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, deserialization_validator))]
+    pub fn set_data_reader_deserialization_error_policy(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        policy: DeserializationErrorPolicy,
+        deserialization_validator: Option<DeserializationValidator>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_deserialization_error_policy(policy, deserialization_validator);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_data_reader_incomplete_coherent_set_timeout(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        timeout: DurationKind,
+        policy: IncompleteCoherentSetPolicy,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_incomplete_coherent_set_timeout(timeout, policy);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_data_reader_gap_free_delivery(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        enabled: bool,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_gap_free_delivery(enabled)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_data_reader_content_filter(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        filter_expression: String,
+        filter_parameters: Vec<String>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_content_filter(filter_expression, filter_parameters)?;
+        subscriber.remove_unused_content_filter_verdicts();
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_reader_head_of_line_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<Vec<HeadOfLineStatus>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(data_reader.get_head_of_line_status())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_reader_instance_statistics(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<InstanceStatistics> {
+        let now = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(data_reader.get_instance_statistics(now))
+    }
+
+    #[tracing::instrument(skip(self, observer, participant_address))]
+    pub fn set_data_reader_instance_freshness_watchdog(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        period: DurationKind,
+        observer: Option<Arc<dyn InstanceFreshnessObserver>>,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        let now = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_instance_freshness_watchdog(period, observer);
+
+        if let DurationKind::Finite(freshness_period) = period {
+            // The instances already received are checked when their last sample becomes older than the period
+            let mut pending_checks = Vec::new();
+            for (change_instance_handle, last_reception_time) in
+                data_reader.get_instance_last_reception_times()
+            {
+                let elapsed = now - last_reception_time;
+                if elapsed >= freshness_period {
+                    data_reader.check_instance_freshness(change_instance_handle, now);
+                } else {
+                    pending_checks.push((change_instance_handle, freshness_period - elapsed));
+                }
+            }
+            for (change_instance_handle, delay) in pending_checks {
+                self.spawn_instance_freshness_check(
+                    subscriber_handle,
+                    data_reader_handle,
+                    change_instance_handle,
+                    delay,
+                    participant_address.clone(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_data_reader_instance_freshness_status(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<InstanceFreshnessStatus> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        Ok(data_reader.get_instance_freshness_status())
+    }
+
+    fn spawn_instance_freshness_check(
+        &self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+        delay: Duration,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            timer_handle.delay(delay.into()).await;
+            participant_address
+                .send(DomainParticipantMail::Event(
+                    EventServiceMail::CheckInstanceFreshness {
+                        subscriber_handle,
+                        data_reader_handle,
+                        change_instance_handle,
+                    },
+                ))
+                .await
+                .ok();
+        });
+    }
+
+    fn spawn_publication_throttling_check(
+        &self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        delay: core::time::Duration,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            timer_handle.delay(delay).await;
+            participant_address
+                .clone()
+                .send(DomainParticipantMail::Event(
+                    EventServiceMail::CheckPublicationThrottling {
+                        publisher_handle,
+                        data_writer_handle,
+                        participant_address,
+                    },
+                ))
+                .await
+                .ok();
+        });
+    }
+
+    fn spawn_data_writer_liveliness_check(
+        &self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        delay: core::time::Duration,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            timer_handle.delay(delay).await;
+            participant_address
+                .clone()
+                .send(DomainParticipantMail::Event(
+                    EventServiceMail::CheckDataWriterLiveliness {
+                        publisher_handle,
+                        data_writer_handle,
+                        participant_address,
+                    },
+                ))
+                .await
+                .ok();
+        });
+    }
+
+    // The automatic liveliness of all the writers is asserted with a single participant message
+    // sent twice per the shortest lease so that remote readers don't consider any writer not alive
+    fn automatic_liveliness_assertion_period(&mut self) -> Option<core::time::Duration> {
+        self.domain_participant
+            .publisher_list()
+            .flat_map(|p| p.data_writer_list())
+            .filter(|dw| {
+                dw.enabled() && dw.qos().liveliness.kind == LivelinessQosPolicyKind::Automatic
+            })
+            .filter_map(|dw| match dw.qos().liveliness.lease_duration {
+                DurationKind::Finite(lease_duration) => {
+                    Some(core::time::Duration::from(lease_duration) / 2)
+                }
+                DurationKind::Infinite => None,
+            })
+            .min()
+    }
+
+    fn start_automatic_liveliness_assertion(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let period = self.automatic_liveliness_assertion_period();
+        if period == self.automatic_liveliness_assertion_period {
+            return;
+        }
+        // The running assertion stops once it sees that it has been replaced by one with a new period
+        self.automatic_liveliness_assertion_id += 1;
+        self.automatic_liveliness_assertion_period = period;
+        if let Some(period) = period {
+            self.spawn_automatic_liveliness_assertion(
+                self.automatic_liveliness_assertion_id,
+                period,
+                participant_address,
+            );
+        }
+    }
+
+    fn spawn_automatic_liveliness_assertion(
+        &self,
+        assertion_id: u64,
+        delay: core::time::Duration,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            timer_handle.delay(delay).await;
+            participant_address
+                .clone()
+                .send(DomainParticipantMail::Event(
+                    EventServiceMail::AssertAutomaticLiveliness {
+                        assertion_id,
+                        participant_address,
+                    },
+                ))
+                .await
+                .ok();
+        });
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn assert_automatic_liveliness(
+        &mut self,
+        assertion_id: u64,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        if assertion_id != self.automatic_liveliness_assertion_id {
+            return;
+        }
+        // The period changes as the writers with automatic liveliness are deleted and the
+        // assertion stops when none is left
+        let period = self.automatic_liveliness_assertion_period();
+        self.automatic_liveliness_assertion_period = period;
+        let Some(period) = period else {
+            return;
+        };
+
+        let timestamp = self.get_current_time();
+        for publisher in self.domain_participant.publisher_list_mut() {
+            for data_writer in publisher.data_writer_list_mut() {
+                if data_writer.qos().liveliness.kind == LivelinessQosPolicyKind::Automatic {
+                    data_writer.assert_liveliness(timestamp);
+                }
+            }
+        }
+        self.announce_participant_message(
+            PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE,
+        )
+        .await;
+
+        self.spawn_automatic_liveliness_assertion(assertion_id, period, participant_address);
+    }
+
+    fn spawn_data_reader_liveliness_check(
+        &self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        delay: Duration,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let mut timer_handle = self.timer_handle.clone();
+        self.spawner_handle.spawn(async move {
+            timer_handle.delay(delay.into()).await;
+            participant_address
+                .clone()
+                .send(DomainParticipantMail::Event(
+                    EventServiceMail::CheckDataReaderLiveliness {
+                        subscriber_handle,
+                        data_reader_handle,
+                        publication_handle,
+                        participant_address,
+                    },
+                ))
+                .await
+                .ok();
+        });
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn assert_matched_writer_liveliness(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+    ) {
+        let current_time = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if !data_reader.assert_writer_liveliness(publication_handle, current_time) {
+            return;
+        }
+        // While the writer stays alive a single check is pending which is rescheduled until the
+        // lease expires without any assertion in between
+        if let WriterLivelinessCheck::Alive(lease_remaining) =
+            data_reader.check_writer_liveliness(publication_handle, current_time)
+        {
+            self.spawn_data_reader_liveliness_check(
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                lease_remaining,
+                participant_address.clone(),
+            );
+        }
+        self.notify_liveliness_changed(participant_address, subscriber_handle, data_reader_handle)
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn check_data_reader_liveliness(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let current_time = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        match data_reader.check_writer_liveliness(publication_handle, current_time) {
+            WriterLivelinessCheck::Alive(lease_remaining) => self
+                .spawn_data_reader_liveliness_check(
+                    subscriber_handle,
+                    data_reader_handle,
+                    publication_handle,
+                    lease_remaining,
+                    participant_address,
+                ),
+            WriterLivelinessCheck::Lost => {
+                self.notify_liveliness_changed(
+                    participant_address,
+                    subscriber_handle,
+                    data_reader_handle,
+                )
+                .await
+            }
+            WriterLivelinessCheck::Unchanged => (),
+        }
+    }
+
+    #[tracing::instrument(skip(self, reply_sender))]
+    pub fn wait_for_data_reader_samples(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        added_change_count: u64,
+        reply_sender: R::OneshotSender<DdsResult<u64>>,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            reply_sender.send(Err(DdsError::AlreadyDeleted));
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            reply_sender.send(Err(DdsError::AlreadyDeleted));
+            return;
+        };
+        data_reader.add_sample_waiter(added_change_count, reply_sender);
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn create_read_condition(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        query: Option<(String, Vec<String>)>,
+    ) -> DdsResult<u32> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.create_read_condition(sample_states, view_states, instance_states, query)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn delete_read_condition(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.delete_read_condition(condition_id)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_read_condition_trigger_value(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+    ) -> DdsResult<bool> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.get_read_condition_trigger_value(condition_id)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_query_parameters(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+    ) -> DdsResult<Vec<String>> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.get_query_parameters(condition_id)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_query_parameters(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        query_parameters: Vec<String>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        data_reader.set_query_parameters(condition_id, query_parameters)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn is_historical_data_received(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) -> DdsResult<bool> {
+        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            return Err(DdsError::NotEnabled);
+        };
+
+        match data_reader.qos().durability.kind {
+            DurabilityQosPolicyKind::Volatile => {
+                return Err(DdsError::IllegalOperation);
+            }
+            DurabilityQosPolicyKind::TransientLocal
+            | DurabilityQosPolicyKind::Transient
+            | DurabilityQosPolicyKind::Persistent => (),
+        };
+
+        if let TransportReaderKind::Stateful(r) = data_reader.transport_reader() {
+            Ok(r.is_historical_data_received())
+        } else {
+            Ok(true)
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn enable_data_reader(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) -> DdsResult<()> {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !subscriber.enabled() {
+            return Err(DdsError::PreconditionNotMet(
+                "Parent subscriber is disabled".to_string(),
+            ));
+        }
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        if !data_reader.enabled() {
+            data_reader.enable();
+
+            // A MultiTopic reader receives its samples from the readers of its related topics so it
+            // is neither matched nor announced itself
+            let constituent_reader_list = data_reader.multitopic_constituent_list().to_vec();
+            if !constituent_reader_list.is_empty() {
+                for constituent_reader_handle in constituent_reader_list {
+                    Box::pin(self.enable_data_reader(
+                        subscriber_handle,
+                        constituent_reader_handle,
+                        participant_address.clone(),
+                    ))
+                    .await?;
+                }
+                return Ok(());
+            }
+
+            let discovered_writer_list: Vec<_> = self
+                .domain_participant
+                .publication_builtin_topic_data_list()
+                .cloned()
+                .collect();
+            for discovered_writer_data in discovered_writer_list {
+                self.add_discovered_writer(
+                    discovered_writer_data,
+                    subscriber_handle,
+                    data_reader_handle,
+                    participant_address.clone(),
+                )
+                .await;
+            }
+
+            self.announce_data_reader(subscriber_handle, data_reader_handle)
+                .await;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn announce_participant(&mut self) {
+        if self.domain_participant.enabled() {
+            let participant_builtin_topic_data = ParticipantBuiltinTopicData {
+                key: BuiltInTopicKey {
+                    value: self.transport.guid().into(),
+                },
+                user_data: self.domain_participant.qos().user_data.clone(),
+            };
+            let participant_proxy = ParticipantProxy {
+                domain_id: Some(self.domain_participant.domain_id()),
+                domain_tag: String::from(self.domain_participant.domain_tag()),
+                protocol_version: self.transport.protocol_version(),
+                guid_prefix: self.transport.guid().prefix(),
+                vendor_id: self.transport.vendor_id(),
+                expects_inline_qos: false,
+                metatraffic_unicast_locator_list: self
+                    .transport
+                    .metatraffic_unicast_locator_list()
+                    .to_vec(),
+                metatraffic_multicast_locator_list: self
+                    .transport
+                    .metatraffic_multicast_locator_list()
+                    .to_vec(),
+                default_unicast_locator_list: self
+                    .transport
+                    .default_unicast_locator_list()
+                    .to_vec(),
+                default_multicast_locator_list: self
+                    .transport
+                    .default_multicast_locator_list()
+                    .to_vec(),
+                available_builtin_endpoints: BuiltinEndpointSet::default(),
+                manual_liveliness_count: 0,
+                builtin_endpoint_qos: BuiltinEndpointQos::default(),
+            };
+            let spdp_discovered_participant_data = SpdpDiscoveredParticipantData {
+                dds_participant_data: participant_builtin_topic_data,
+                participant_proxy,
+                lease_duration: self.domain_participant.lease_duration(),
+                discovered_participant_list: self.domain_participant.get_discovered_participants(),
+            };
+            let timestamp = self.get_current_time();
+
+            if let Some(dw) = self
+                .domain_participant
+                .builtin_publisher_mut()
+                .lookup_datawriter_mut(DCPS_PARTICIPANT)
+            {
+                if let Ok(serialized_data) = spdp_discovered_participant_data.serialize_data() {
+                    dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
+                        .await
+                        .ok();
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn announce_deleted_participant(&mut self) {
+        if self.domain_participant.enabled() {
+            let timestamp = self.get_current_time();
+            if let Some(dw) = self
+                .domain_participant
+                .builtin_publisher_mut()
+                .lookup_datawriter_mut(DCPS_PARTICIPANT)
+            {
+                let key = InstanceHandle::new(self.transport.guid().into());
+                if let Ok(serialized_data) = key.serialize_data() {
+                    dw.dispose_w_timestamp(serialized_data, timestamp)
+                        .await
+                        .ok();
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn announce_data_writer(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) {
+        let Some(publisher) = self.domain_participant.get_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_data_writer(data_writer_handle) else {
+            return;
+        };
+        let Some(topic) = self.domain_participant.get_topic(data_writer.topic_name()) else {
+            return;
+        };
+
+        let topic_data = topic.qos().topic_data.clone();
+
+        let dds_publication_data = PublicationBuiltinTopicData {
+            key: BuiltInTopicKey {
+                value: data_writer.transport_writer().guid().into(),
+            },
+            participant_key: BuiltInTopicKey { value: [0; 16] },
+            topic_name: String::from(data_writer.topic_name()),
+            type_name: String::from(data_writer.type_name()),
+            durability: data_writer.qos().durability.clone(),
+            deadline: data_writer.qos().deadline.clone(),
+            latency_budget: data_writer.qos().latency_budget.clone(),
+            liveliness: data_writer.qos().liveliness.clone(),
+            reliability: data_writer.qos().reliability.clone(),
+            lifespan: data_writer.qos().lifespan.clone(),
+            user_data: data_writer.qos().user_data.clone(),
+            ownership: data_writer.qos().ownership.clone(),
+            ownership_strength: data_writer.qos().ownership_strength.clone(),
+            destination_order: data_writer.qos().destination_order.clone(),
+            presentation: publisher.qos().presentation.clone(),
+            partition: publisher.partition(),
+            topic_data,
+            group_data: publisher.qos().group_data.clone(),
+            representation: data_writer.qos().representation.clone(),
+        };
+        let writer_proxy = WriterProxy {
+            remote_writer_guid: data_writer.transport_writer().guid(),
+            remote_group_entity_id: ENTITYID_UNKNOWN,
+            unicast_locator_list: vec![],
+            multicast_locator_list: vec![],
+        };
+        let discovered_writer_data = DiscoveredWriterData {
+            dds_publication_data,
+            writer_proxy,
+        };
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_PUBLICATION)
+        {
+            if let Ok(serialized_data) = discovered_writer_data.serialize_data() {
+                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn announce_participant_message(&mut self, kind: ParticipantMessageDataKind) {
+        let participant_message_data = ParticipantMessageData {
+            participant_guid_prefix: self.transport.guid().prefix(),
+            kind,
+            data: vec![],
+        };
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_PARTICIPANT_MESSAGE)
+        {
+            if let Ok(serialized_data) = participant_message_data.serialize_data() {
+                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, data_writer))]
+    async fn announce_deleted_data_writer(&mut self, mut data_writer: DataWriterEntity<R>) {
+        let data_bytes_sent = data_writer.take_data_bytes_sent();
+        self.add_data_bytes_sent(data_writer.topic_name(), data_bytes_sent);
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_PUBLICATION)
+        {
+            let key = InstanceHandle::new(data_writer.transport_writer().guid().into());
+            if let Ok(serialized_data) = key.serialize_data() {
+                dw.dispose_w_timestamp(serialized_data, timestamp)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn announce_data_reader(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        // The built-in data readers are announced through the participant discovery
+        if self
+            .domain_participant
+            .is_builtin_subscriber(subscriber_handle)
+        {
+            return;
+        }
+        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_data_reader(data_reader_handle) else {
+            return;
+        };
+        let Some(topic) = self.domain_participant.get_topic(data_reader.topic_name()) else {
+            return;
+        };
+
+        let guid = data_reader.transport_reader().guid();
+        let dds_subscription_data = SubscriptionBuiltinTopicData {
+            key: BuiltInTopicKey { value: guid.into() },
+            participant_key: BuiltInTopicKey { value: [0; 16] },
+            topic_name: String::from(data_reader.topic_name()),
+            type_name: String::from(data_reader.type_name()),
+            durability: data_reader.qos().durability.clone(),
+            deadline: data_reader.qos().deadline.clone(),
+            latency_budget: data_reader.qos().latency_budget.clone(),
+            liveliness: data_reader.qos().liveliness.clone(),
+            reliability: data_reader.qos().reliability.clone(),
+            ownership: data_reader.qos().ownership.clone(),
+            destination_order: data_reader.qos().destination_order.clone(),
+            user_data: data_reader.qos().user_data.clone(),
+            time_based_filter: data_reader.qos().time_based_filter.clone(),
+            presentation: subscriber.qos().presentation.clone(),
+            partition: subscriber.qos().partition.clone(),
+            topic_data: topic.qos().topic_data.clone(),
+            group_data: subscriber.qos().group_data.clone(),
+            representation: data_reader.qos().representation.clone(),
+        };
+        let reader_proxy = ReaderProxy {
+            remote_reader_guid: data_reader.transport_reader().guid(),
+            remote_group_entity_id: ENTITYID_UNKNOWN,
+            unicast_locator_list: vec![],
+            multicast_locator_list: vec![],
+            expects_inline_qos: false,
+        };
+        let discovered_reader_data = DiscoveredReaderData {
+            dds_subscription_data,
+            reader_proxy,
+        };
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_SUBSCRIPTION)
+        {
+            if let Ok(serialized_data) = discovered_reader_data.serialize_data() {
+                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, data_reader))]
+    async fn announce_deleted_data_reader(&mut self, data_reader: DataReaderEntity<R>) {
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_SUBSCRIPTION)
+        {
+            let guid = data_reader.transport_reader().guid();
+            let key = InstanceHandle::new(guid.into());
+            if let Ok(serialized_data) = key.serialize_data() {
+                dw.dispose_w_timestamp(serialized_data, timestamp)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn announce_topic(&mut self, topic_name: String) {
+        let Some(topic) = self.domain_participant.get_topic(&topic_name) else {
+            return;
+        };
+
+        let topic_builtin_topic_data = TopicBuiltinTopicData {
+            key: BuiltInTopicKey {
+                value: topic.instance_handle().into(),
+            },
+            name: String::from(topic.topic_name()),
+            type_name: String::from(topic.type_name()),
+            durability: topic.qos().durability.clone(),
+            deadline: topic.qos().deadline.clone(),
+            latency_budget: topic.qos().latency_budget.clone(),
+            liveliness: topic.qos().liveliness.clone(),
+            reliability: topic.qos().reliability.clone(),
+            transport_priority: topic.qos().transport_priority.clone(),
+            lifespan: topic.qos().lifespan.clone(),
+            destination_order: topic.qos().destination_order.clone(),
+            history: topic.qos().history.clone(),
+            resource_limits: topic.qos().resource_limits.clone(),
+            ownership: topic.qos().ownership.clone(),
+            topic_data: topic.qos().topic_data.clone(),
+            representation: topic.qos().representation.clone(),
+        };
+
+        let timestamp = self.get_current_time();
+        if let Some(dw) = self
+            .domain_participant
+            .builtin_publisher_mut()
+            .lookup_datawriter_mut(DCPS_TOPIC)
+        {
+            if let Ok(serialized_data) = topic_builtin_topic_data.serialize_data() {
+                dw.write_w_timestamp(serialized_data, timestamp, &self.clock_handle)
+                    .await
+                    .ok();
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn add_discovered_reader(
+        &mut self,
+        discovered_reader_data: DiscoveredReaderData,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let default_unicast_locator_list = if let Some(p) = self
+            .domain_participant
+            .discovered_participant_list()
+            .find(|p| {
+                p.participant_proxy.guid_prefix
+                    == discovered_reader_data
+                        .reader_proxy
+                        .remote_reader_guid
+                        .prefix()
+            }) {
+            p.participant_proxy.default_unicast_locator_list.clone()
+        } else {
+            vec![]
+        };
+        let default_multicast_locator_list = if let Some(p) = self
+            .domain_participant
+            .discovered_participant_list()
+            .find(|p| {
+                p.participant_proxy.guid_prefix
+                    == discovered_reader_data
+                        .reader_proxy
+                        .remote_reader_guid
+                        .prefix()
+            }) {
+            p.participant_proxy.default_multicast_locator_list.clone()
+        } else {
+            vec![]
+        };
+        let is_authorized = self
+            .domain_participant
+            .is_subscription_authorized(&discovered_reader_data);
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+
+        let is_any_name_matched = discovered_reader_data
+            .dds_subscription_data
+            .partition
+            .name
+            .iter()
+            .any(|n| publisher.partition().name.contains(n));
+
+        let is_any_received_regex_matched_with_partition_qos = true;
+        // discovered_reader_data
+        //     .dds_subscription_data
+        //     .partition
+        //     .name
+        //     .iter()
+        //     // .filter_map(|n| glob_to_regex(n).ok())
+        //     .any(|regex| {
+        //         publisher
+        //             .qos()
+        //             .partition
+        //             .name
+        //             .iter()
+        //             .any(|n| regex.is_match(n))
+        //     });
+
+        let is_any_local_regex_matched_with_received_partition_qos = true;
+        // publisher
+        //     .qos()
+        //     .partition
+        //     .name
+        //     .iter()
+        //     .filter_map(|n| glob_to_regex(n).ok())
+        //     .any(|regex| {
+        //         discovered_reader_data
+        //             .dds_subscription_data
+        //             .partition
+        //             .name
+        //             .iter()
+        //             .any(|n| regex.is_match(n))
+        //     });
+
+        let is_partition_matched = discovered_reader_data.dds_subscription_data.partition
+            == publisher.partition()
+            || is_any_name_matched
+            || is_any_received_regex_matched_with_partition_qos
+            || is_any_local_regex_matched_with_received_partition_qos;
+
+        // A matched reader announces itself again when its mutable QoS policies change. The match is
+        // kept without resetting the reader proxy as long as the reader is still compatible, otherwise
+        // it is removed and the reader is evaluated as a newly discovered one
+        let subscription_handle =
+            InstanceHandle::new(discovered_reader_data.dds_subscription_data.key().value);
+        let publisher_qos = publisher.qos().clone();
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        if data_writer
+            .get_matched_subscription_data(&subscription_handle)
+            .is_some()
+        {
+            if is_authorized
+                && is_partition_matched
+                && get_discovered_reader_incompatible_qos_policy_list(
+                    data_writer.qos(),
+                    &discovered_reader_data.dds_subscription_data,
+                    &publisher_qos,
+                )
+                .is_empty()
+            {
+                data_writer
+                    .update_matched_subscription(discovered_reader_data.dds_subscription_data);
+                return;
+            }
+            data_writer.remove_matched_subscription(&subscription_handle);
+            if let TransportWriterKind::Stateful(w) = data_writer.transport_writer_mut() {
+                w.remove_matched_reader(discovered_reader_data.reader_proxy.remote_reader_guid);
+            }
+            self.announce_publication_matched(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+            )
+            .await;
+        }
+
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        if is_authorized && is_partition_matched {
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+
+            let is_matched_topic_name = discovered_reader_data.dds_subscription_data.topic_name()
+                == data_writer.topic_name();
+            let is_matched_type_name = discovered_reader_data.dds_subscription_data.get_type_name()
+                == data_writer.type_name();
+
+            if is_matched_topic_name
+                && is_matched_type_name
+                && !is_matched_topic_kind(
+                    discovered_reader_data
+                        .reader_proxy
+                        .remote_reader_guid
+                        .entity_id(),
+                    data_writer.transport_writer().guid().entity_id(),
+                )
+            {
+                let topic_name = String::from(data_writer.topic_name());
+                if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Warn) {
+                    tracing::warn!(
+                        target: DiagnosticSubsystem::Discovery.target(),
+                        topic_name,
+                        remote_reader_guid = ?discovered_reader_data.reader_proxy.remote_reader_guid,
+                        "Not matching remote reader since it does not agree on whether the type of the topic is keyed"
+                    );
+                }
+                if let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) {
+                    topic.increment_inconsistent_topic_status().await;
+                }
+                return;
+            }
+
+            if is_matched_topic_name && is_matched_type_name {
+                let incompatible_qos_policy_list =
+                    get_discovered_reader_incompatible_qos_policy_list(
+                        data_writer.qos(),
+                        &discovered_reader_data.dds_subscription_data,
+                        &publisher_qos,
+                    );
+                if incompatible_qos_policy_list.is_empty() {
+                    if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Debug) {
+                        tracing::debug!(
+                            target: DiagnosticSubsystem::Discovery.target(),
+                            topic_name = data_writer.topic_name(),
+                            remote_reader_guid = ?discovered_reader_data.reader_proxy.remote_reader_guid,
+                            "Matched remote reader"
+                        );
+                    }
+                    data_writer.add_matched_subscription(
+                        discovered_reader_data.dds_subscription_data.clone(),
+                    );
+
+                    let unicast_locator_list = if discovered_reader_data
+                        .reader_proxy
+                        .unicast_locator_list
+                        .is_empty()
+                    {
+                        default_unicast_locator_list
+                    } else {
+                        discovered_reader_data.reader_proxy.unicast_locator_list
+                    };
+                    let multicast_locator_list = if discovered_reader_data
+                        .reader_proxy
+                        .multicast_locator_list
+                        .is_empty()
+                    {
+                        default_multicast_locator_list
+                    } else {
+                        discovered_reader_data.reader_proxy.multicast_locator_list
+                    };
+                    data_writer.set_matched_subscription_locators(
+                        subscription_handle,
+                        unicast_locator_list
+                            .iter()
+                            .chain(multicast_locator_list.iter())
+                            .cloned()
+                            .collect(),
+                    );
+                    let reliability_kind = match discovered_reader_data
+                        .dds_subscription_data
+                        .reliability
+                        .kind
+                    {
+                        ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
+                        ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
+                    };
+                    let durability_kind =
+                        match discovered_reader_data.dds_subscription_data.durability.kind {
+                            DurabilityQosPolicyKind::Volatile => DurabilityKind::Volatile,
+                            DurabilityQosPolicyKind::TransientLocal => {
+                                DurabilityKind::TransientLocal
+                            }
+                            DurabilityQosPolicyKind::Transient => DurabilityKind::Transient,
+                            DurabilityQosPolicyKind::Persistent => DurabilityKind::Persistent,
+                        };
+
+                    let reader_proxy = transport::writer::ReaderProxy {
+                        remote_reader_guid: discovered_reader_data.reader_proxy.remote_reader_guid,
+                        remote_group_entity_id: discovered_reader_data
+                            .reader_proxy
+                            .remote_group_entity_id,
+                        reliability_kind,
+                        durability_kind,
+                        unicast_locator_list,
+                        multicast_locator_list,
+                        expects_inline_qos: false,
+                    };
+                    if let TransportWriterKind::Stateful(w) = data_writer.transport_writer_mut() {
+                        w.add_matched_reader(reader_proxy);
+                    }
+
+                    self.announce_publication_matched(
+                        participant_address,
+                        publisher_handle,
+                        data_writer_handle,
+                    )
+                    .await;
+                } else {
+                    data_writer.add_incompatible_subscription(
+                        InstanceHandle::new(
+                            discovered_reader_data.dds_subscription_data.key().value,
+                        ),
+                        incompatible_qos_policy_list,
+                    );
+
+                    if data_writer
+                        .listener_mask()
+                        .contains(&StatusKind::OfferedIncompatibleQos)
+                    {
+                        let status = data_writer.get_offered_incompatible_qos_status();
+                        let Ok(the_writer) = self.get_data_writer_async(
+                            participant_address,
+                            publisher_handle,
+                            data_writer_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(publisher) =
+                            self.domain_participant.get_mut_publisher(publisher_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
+                        else {
+                            return;
+                        };
+                        if let Some(l) = data_writer.listener() {
+                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
+                                .await
+                                .ok();
+                        }
+                    } else if publisher
+                        .listener_mask()
+                        .contains(&StatusKind::OfferedIncompatibleQos)
+                    {
+                        let Ok(the_writer) = self.get_data_writer_async(
+                            participant_address,
+                            publisher_handle,
+                            data_writer_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(publisher) =
+                            self.domain_participant.get_mut_publisher(publisher_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
+                        else {
+                            return;
+                        };
+                        let status = data_writer.get_offered_incompatible_qos_status();
+                        if let Some(l) = publisher.listener() {
+                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
+                                .await
+                                .ok();
+                        }
+                    } else if self
+                        .domain_participant
+                        .listener_mask()
+                        .contains(&StatusKind::OfferedIncompatibleQos)
+                    {
+                        let Ok(the_writer) = self.get_data_writer_async(
+                            participant_address,
+                            publisher_handle,
+                            data_writer_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(publisher) =
+                            self.domain_participant.get_mut_publisher(publisher_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
+                        else {
+                            return;
+                        };
+                        let status = data_writer.get_offered_incompatible_qos_status();
+                        if let Some(l) = self.domain_participant.listener() {
+                            l.send(ListenerMail::OfferedIncompatibleQos { the_writer, status })
+                                .await
+                                .ok();
+                        }
+                    }
+
+                    let Some(publisher) =
+                        self.domain_participant.get_mut_publisher(publisher_handle)
+                    else {
+                        return;
+                    };
+                    let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle)
+                    else {
+                        return;
+                    };
+                    data_writer
+                        .status_condition()
+                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                            state: StatusKind::OfferedIncompatibleQos,
+                        })
+                        .await;
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_discovered_reader(
+        &mut self,
+        subscription_handle: InstanceHandle,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        if data_writer
+            .get_matched_subscription_data(&subscription_handle)
+            .is_some()
+        {
+            data_writer.remove_matched_subscription(&subscription_handle);
+
+            data_writer
+                .status_condition()
+                .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                    state: StatusKind::PublicationMatched,
+                })
+                .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn announce_publication_matched(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        if data_writer
+            .listener_mask()
+            .contains(&StatusKind::PublicationMatched)
+        {
+            let status = data_writer.get_publication_matched_status();
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            if let Some(l) = data_writer.listener() {
+                l.send(ListenerMail::PublicationMatched { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if publisher
+            .listener_mask()
+            .contains(&StatusKind::PublicationMatched)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_publication_matched_status();
+            if let Some(l) = publisher.listener() {
+                l.send(ListenerMail::PublicationMatched { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if self
+            .domain_participant
+            .listener_mask()
+            .contains(&StatusKind::PublicationMatched)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_publication_matched_status();
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::PublicationMatched { the_writer, status })
+                    .await
+                    .ok();
+            }
+        }
+
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        data_writer
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::PublicationMatched,
+            })
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn announce_subscription_matched(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if data_reader
+            .listener_mask()
+            .contains(&StatusKind::SubscriptionMatched)
+        {
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address.clone(),
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let status = data_reader.get_subscription_matched_status();
+            if let Some(l) = data_reader.listener() {
+                l.send(ListenerMail::SubscriptionMatched { the_reader, status })
+                    .await
+                    .ok();
+            }
+        } else if subscriber
+            .listener_mask()
+            .contains(&StatusKind::SubscriptionMatched)
+        {
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address.clone(),
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let status = data_reader.get_subscription_matched_status();
+            if let Some(l) = subscriber.listener() {
+                l.send(ListenerMail::SubscriptionMatched { the_reader, status })
+                    .await
+                    .ok();
+            }
+        } else if self
+            .domain_participant
+            .listener_mask()
+            .contains(&StatusKind::SubscriptionMatched)
+        {
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address.clone(),
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let status = data_reader.get_subscription_matched_status();
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::SubscriptionMatched { the_reader, status })
+                    .await
+                    .ok();
+            }
+        }
+
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::SubscriptionMatched,
+            })
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn add_discovered_writer(
+        &mut self,
+        discovered_writer_data: DiscoveredWriterData,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let default_unicast_locator_list = if let Some(p) = self
+            .domain_participant
+            .discovered_participant_list()
+            .find(|p| {
+                p.participant_proxy.guid_prefix
+                    == discovered_writer_data
+                        .writer_proxy
+                        .remote_writer_guid
+                        .prefix()
+            }) {
+            p.participant_proxy.default_unicast_locator_list.clone()
+        } else {
+            vec![]
+        };
+        let default_multicast_locator_list = if let Some(p) = self
+            .domain_participant
+            .discovered_participant_list()
+            .find(|p| {
+                p.participant_proxy.guid_prefix
+                    == discovered_writer_data
+                        .writer_proxy
+                        .remote_writer_guid
+                        .prefix()
+            }) {
+            p.participant_proxy.default_multicast_locator_list.clone()
+        } else {
+            vec![]
+        };
+        let is_authorized = self
+            .domain_participant
+            .is_publication_authorized(&discovered_writer_data);
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let is_any_name_matched = discovered_writer_data
+            .dds_publication_data
+            .partition
+            .name
+            .iter()
+            .any(|n| subscriber.qos().partition.name.contains(n));
+
+        let is_any_received_regex_matched_with_partition_qos = true;
+        // discovered_writer_data
+        //     .dds_publication_data
+        //     .partition
+        //     .name
+        //     .iter()
+        //     .filter_map(|n| glob_to_regex(n).ok())
+        //     .any(|regex| {
+        //         subscriber
+        //             .qos()
+        //             .partition
+        //             .name
+        //             .iter()
+        //             .any(|n| regex.is_match(n))
+        //     });
+
+        let is_any_local_regex_matched_with_received_partition_qos = true;
+        // subscriber
+        //     .qos()
+        //     .partition
+        //     .name
+        //     .iter()
+        //     .filter_map(|n| glob_to_regex(n).ok())
+        //     .any(|regex| {
+        //         discovered_writer_data
+        //             .dds_publication_data
+        //             .partition
+        //             .name
+        //             .iter()
+        //             .any(|n| regex.is_match(n))
+        //     });
+
+        let is_partition_matched = discovered_writer_data.dds_publication_data.partition
+            == subscriber.qos().partition
+            || is_any_name_matched
+            || is_any_received_regex_matched_with_partition_qos
+            || is_any_local_regex_matched_with_received_partition_qos;
+
+        // A matched writer announces itself again when its mutable QoS policies change. The match is
+        // kept without resetting the writer proxy as long as the writer is still compatible so that the
+        // new values (e.g. the ownership strength) are used from now on, otherwise it is removed and the
+        // writer is evaluated as a newly discovered one
+        let publication_handle =
+            InstanceHandle::new(discovered_writer_data.dds_publication_data.key().value);
+        let subscriber_qos = subscriber.qos().clone();
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if data_reader
+            .get_matched_publication_data(&publication_handle)
+            .is_some()
+        {
+            if is_authorized
+                && is_partition_matched
+                && get_discovered_writer_incompatible_qos_policy_list::<R>(
+                    data_reader,
+                    &discovered_writer_data.dds_publication_data,
+                    &subscriber_qos,
+                )
+                .is_empty()
+            {
+                data_reader.update_matched_publication(discovered_writer_data.dds_publication_data);
+                return;
+            }
+            // The writer is still alive so it is not recorded as lost, which would otherwise be
+            // reported as a restart of the writer when it becomes compatible again
+            data_reader.unmatch_publication(&publication_handle).await;
+            if let TransportReaderKind::Stateful(r) = data_reader.transport_reader_mut() {
+                r.remove_matched_writer(discovered_writer_data.writer_proxy.remote_writer_guid);
+            }
+            self.announce_subscription_matched(
+                participant_address.clone(),
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+        }
+
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        if is_authorized && is_partition_matched {
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let is_matched_topic_name = discovered_writer_data.dds_publication_data.topic_name()
+                == data_reader.topic_name();
+            let is_matched_type_name = discovered_writer_data.dds_publication_data.get_type_name()
+                == data_reader.type_name();
+
+            if is_matched_topic_name
+                && is_matched_type_name
+                && !is_matched_topic_kind(
+                    discovered_writer_data
+                        .writer_proxy
+                        .remote_writer_guid
+                        .entity_id(),
+                    data_reader.transport_reader().guid().entity_id(),
+                )
+            {
+                let topic_name = String::from(data_reader.topic_name());
+                if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Warn) {
+                    tracing::warn!(
+                        target: DiagnosticSubsystem::Discovery.target(),
+                        topic_name,
+                        remote_writer_guid = ?discovered_writer_data.writer_proxy.remote_writer_guid,
+                        "Not matching remote writer since it does not agree on whether the type of the topic is keyed"
+                    );
+                }
+                if let Some(topic) = self.domain_participant.get_mut_topic(&topic_name) {
+                    topic.increment_inconsistent_topic_status().await;
+                }
+                return;
+            }
+
+            if is_matched_topic_name && is_matched_type_name {
+                let incompatible_qos_policy_list =
+                    get_discovered_writer_incompatible_qos_policy_list::<R>(
+                        data_reader,
+                        &discovered_writer_data.dds_publication_data,
+                        &subscriber_qos,
+                    );
+                if incompatible_qos_policy_list.is_empty() {
+                    if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Debug) {
+                        tracing::debug!(
+                            target: DiagnosticSubsystem::Discovery.target(),
+                            topic_name = data_reader.topic_name(),
+                            remote_writer_guid = ?discovered_writer_data.writer_proxy.remote_writer_guid,
+                            "Matched remote writer"
+                        );
+                    }
+                    let writer_restarted = data_reader
+                        .detect_writer_restart(&discovered_writer_data.dds_publication_data);
+                    data_reader.add_matched_publication(
+                        discovered_writer_data.dds_publication_data.clone(),
+                    );
+                    let unicast_locator_list = if discovered_writer_data
+                        .writer_proxy
+                        .unicast_locator_list
+                        .is_empty()
+                    {
+                        default_unicast_locator_list
+                    } else {
+                        discovered_writer_data.writer_proxy.unicast_locator_list
+                    };
+                    let multicast_locator_list = if discovered_writer_data
+                        .writer_proxy
+                        .multicast_locator_list
+                        .is_empty()
+                    {
+                        default_multicast_locator_list
+                    } else {
+                        discovered_writer_data.writer_proxy.multicast_locator_list
+                    };
+                    data_reader.set_matched_publication_locators(
+                        publication_handle,
+                        unicast_locator_list
+                            .iter()
+                            .chain(multicast_locator_list.iter())
+                            .cloned()
+                            .collect(),
+                    );
+                    let reliability_kind = match data_reader.qos().reliability.kind {
+                        ReliabilityQosPolicyKind::BestEffort => ReliabilityKind::BestEffort,
+                        ReliabilityQosPolicyKind::Reliable => ReliabilityKind::Reliable,
+                    };
+                    let durability_kind = match data_reader.qos().durability.kind {
+                        DurabilityQosPolicyKind::Volatile => DurabilityKind::Volatile,
+                        DurabilityQosPolicyKind::TransientLocal => DurabilityKind::TransientLocal,
+                        DurabilityQosPolicyKind::Transient => DurabilityKind::Transient,
+                        DurabilityQosPolicyKind::Persistent => DurabilityKind::Persistent,
+                    };
+                    let writer_proxy = transport::reader::WriterProxy {
+                        remote_writer_guid: discovered_writer_data.writer_proxy.remote_writer_guid,
+                        remote_group_entity_id: discovered_writer_data
+                            .writer_proxy
+                            .remote_group_entity_id,
+                        unicast_locator_list,
+                        multicast_locator_list,
+                        reliability_kind,
+                        durability_kind,
+                    };
+                    if let TransportReaderKind::Stateful(r) = data_reader.transport_reader_mut() {
+                        r.add_matched_writer(writer_proxy);
+                    }
+
+                    self.announce_subscription_matched(
+                        participant_address.clone(),
+                        subscriber_handle,
+                        data_reader_handle,
+                    )
+                    .await;
+
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+
+                    if writer_restarted {
+                        if data_reader
+                            .listener_mask()
+                            .contains(&StatusKind::WriterRestarted)
+                        {
+                            let Ok(the_reader) = self.get_data_reader_async(
+                                participant_address.clone(),
+                                subscriber_handle,
+                                data_reader_handle,
+                            ) else {
+                                return;
+                            };
+                            let Some(subscriber) = self
+                                .domain_participant
+                                .get_mut_subscriber(subscriber_handle)
+                            else {
+                                return;
+                            };
+                            let Some(data_reader) =
+                                subscriber.get_mut_data_reader(data_reader_handle)
+                            else {
+                                return;
+                            };
+                            let status = data_reader.get_writer_restarted_status();
+                            if let Some(l) = data_reader.listener() {
+                                l.send(ListenerMail::WriterRestarted { the_reader, status })
+                                    .await
+                                    .ok();
+                            }
+                        } else if subscriber
+                            .listener_mask()
+                            .contains(&StatusKind::WriterRestarted)
+                        {
+                            let Ok(the_reader) = self.get_data_reader_async(
+                                participant_address.clone(),
+                                subscriber_handle,
+                                data_reader_handle,
+                            ) else {
+                                return;
+                            };
+                            let Some(subscriber) = self
+                                .domain_participant
+                                .get_mut_subscriber(subscriber_handle)
+                            else {
+                                return;
+                            };
+                            let Some(data_reader) =
+                                subscriber.get_mut_data_reader(data_reader_handle)
+                            else {
+                                return;
+                            };
+                            let status = data_reader.get_writer_restarted_status();
+                            if let Some(l) = subscriber.listener() {
+                                l.send(ListenerMail::WriterRestarted { the_reader, status })
+                                    .await
+                                    .ok();
+                            }
+                        } else if self
+                            .domain_participant
+                            .listener_mask()
+                            .contains(&StatusKind::WriterRestarted)
+                        {
+                            let Ok(the_reader) = self.get_data_reader_async(
+                                participant_address.clone(),
+                                subscriber_handle,
+                                data_reader_handle,
+                            ) else {
+                                return;
+                            };
+                            let Some(subscriber) = self
+                                .domain_participant
+                                .get_mut_subscriber(subscriber_handle)
+                            else {
+                                return;
+                            };
+                            let Some(data_reader) =
+                                subscriber.get_mut_data_reader(data_reader_handle)
+                            else {
+                                return;
+                            };
+                            let status = data_reader.get_writer_restarted_status();
+                            if let Some(l) = self.domain_participant.listener() {
+                                l.send(ListenerMail::WriterRestarted { the_reader, status })
+                                    .await
+                                    .ok();
+                            }
+                        }
+
+                        let Some(subscriber) = self
+                            .domain_participant
+                            .get_mut_subscriber(subscriber_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                        else {
+                            return;
+                        };
+                        data_reader
+                            .status_condition()
+                            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                                state: StatusKind::WriterRestarted,
+                            })
+                            .await;
+                    }
+
+                    // A matched writer is considered alive until its first lease expires
+                    self.assert_matched_writer_liveliness(
+                        participant_address.clone(),
+                        subscriber_handle,
+                        data_reader_handle,
+                        publication_handle,
+                    )
+                    .await;
+                } else {
+                    data_reader.add_requested_incompatible_qos(
+                        InstanceHandle::new(
+                            discovered_writer_data.dds_publication_data.key().value,
+                        ),
+                        incompatible_qos_policy_list,
+                    );
+
+                    if data_reader
+                        .listener_mask()
+                        .contains(&StatusKind::RequestedIncompatibleQos)
+                    {
+                        let status = data_reader.get_requested_incompatible_qos_status();
+                        let Ok(the_reader) = self.get_data_reader_async(
+                            participant_address,
+                            subscriber_handle,
+                            data_reader_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(subscriber) = self
+                            .domain_participant
+                            .get_mut_subscriber(subscriber_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                        else {
+                            return;
+                        };
+                        if let Some(l) = data_reader.listener() {
+                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
                                 .await
                                 .ok();
                         }
@@ -3071,820 +6192,1907 @@ where
                         .listener_mask()
                         .contains(&StatusKind::RequestedIncompatibleQos)
                     {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
+                        let Ok(the_reader) = self.get_data_reader_async(
+                            participant_address,
+                            subscriber_handle,
+                            data_reader_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(subscriber) = self
+                            .domain_participant
+                            .get_mut_subscriber(subscriber_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                        else {
+                            return;
+                        };
+                        let status = data_reader.get_requested_incompatible_qos_status();
+                        if let Some(l) = subscriber.listener() {
+                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
+                                .await
+                                .ok();
+                        }
+                    } else if self
+                        .domain_participant
+                        .listener_mask()
+                        .contains(&StatusKind::RequestedIncompatibleQos)
+                    {
+                        let Ok(the_reader) = self.get_data_reader_async(
+                            participant_address,
+                            subscriber_handle,
+                            data_reader_handle,
+                        ) else {
+                            return;
+                        };
+                        let Some(subscriber) = self
+                            .domain_participant
+                            .get_mut_subscriber(subscriber_handle)
+                        else {
+                            return;
+                        };
+                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                        else {
+                            return;
+                        };
+                        let status = data_reader.get_requested_incompatible_qos_status();
+                        if let Some(l) = self.domain_participant.listener() {
+                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
+                                .await
+                                .ok();
+                        }
+                    }
+
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+                    data_reader
+                        .status_condition()
+                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                            state: StatusKind::RequestedIncompatibleQos,
+                        })
+                        .await;
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_discovered_writer(
+        &mut self,
+        publication_handle: InstanceHandle,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if data_reader
+            .get_matched_publication_data(&publication_handle)
+            .is_some()
+        {
+            data_reader
+                .remove_matched_publication(&publication_handle)
+                .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_builtin_participants_detector_cache_change(
+        &mut self,
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        match cache_change.kind {
+            ChangeKind::Alive => {
+                if let Ok(discovered_participant_data) =
+                    SpdpDiscoveredParticipantData::deserialize_data(
+                        cache_change.data_value.as_ref(),
+                    )
+                {
+                    self.add_discovered_parameter_id_list(
+                        DiscoveredEntityKind::Participant,
+                        InstanceHandle::new(
+                            discovered_participant_data.dds_participant_data.key.value,
+                        ),
+                        cache_change.data_value.as_ref(),
+                    );
+                    self.add_discovered_participant(discovered_participant_data)
+                        .await;
+                }
+            }
+            ChangeKind::NotAliveDisposed => {
+                if let Ok(discovered_participant_handle) =
+                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    self.remove_discovered_participant(discovered_participant_handle);
+                }
+            }
+            ChangeKind::AliveFiltered
+            | ChangeKind::NotAliveUnregistered
+            | ChangeKind::NotAliveDisposedUnregistered => (), // Do nothing,
+        }
+
+        self.add_builtin_data_reader_change(participant_address, cache_change, DCPS_PARTICIPANT)
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_builtin_publications_detector_cache_change(
+        &mut self,
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        match cache_change.kind {
+            ChangeKind::Alive => {
+                if let Ok(discovered_writer_data) =
+                    DiscoveredWriterData::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    let publication_builtin_topic_data =
+                        &discovered_writer_data.dds_publication_data;
+                    if self
+                        .domain_participant
+                        .find_topic(&publication_builtin_topic_data.topic_name)
+                        .is_none()
+                    {
+                        let writer_topic = TopicBuiltinTopicData {
+                            key: BuiltInTopicKey::default(),
+                            name: String::from(publication_builtin_topic_data.topic_name()),
+                            type_name: String::from(publication_builtin_topic_data.get_type_name()),
+                            durability: publication_builtin_topic_data.durability().clone(),
+                            deadline: publication_builtin_topic_data.deadline().clone(),
+                            latency_budget: publication_builtin_topic_data.latency_budget().clone(),
+                            liveliness: publication_builtin_topic_data.liveliness().clone(),
+                            reliability: publication_builtin_topic_data.reliability().clone(),
+                            transport_priority: TransportPriorityQosPolicy::default(),
+                            lifespan: publication_builtin_topic_data.lifespan().clone(),
+                            destination_order: publication_builtin_topic_data
+                                .destination_order()
+                                .clone(),
+                            history: HistoryQosPolicy::default(),
+                            resource_limits: ResourceLimitsQosPolicy::default(),
+                            ownership: publication_builtin_topic_data.ownership().clone(),
+                            topic_data: publication_builtin_topic_data.topic_data().clone(),
+                            representation: publication_builtin_topic_data.representation().clone(),
+                        };
+                        self.domain_participant.add_discovered_topic(writer_topic);
+                    }
+
+                    self.domain_participant
+                        .add_discovered_writer(discovered_writer_data.clone());
+                    self.add_discovered_parameter_id_list(
+                        DiscoveredEntityKind::Publication,
+                        InstanceHandle::new(discovered_writer_data.dds_publication_data.key.value),
+                        cache_change.data_value.as_ref(),
+                    );
+                    let mut handle_list = Vec::new();
+                    for subscriber in self.domain_participant.subscriber_list() {
+                        for data_reader in subscriber.data_reader_list().filter(|r| r.enabled()) {
+                            handle_list.push((
+                                subscriber.instance_handle(),
+                                data_reader.instance_handle(),
+                            ));
+                        }
+                    }
+                    for (subscriber_handle, data_reader_handle) in handle_list {
+                        self.add_discovered_writer(
+                            discovered_writer_data.clone(),
+                            subscriber_handle,
+                            data_reader_handle,
+                            participant_address.clone(),
+                        )
+                        .await;
+                    }
+                }
+            }
+            ChangeKind::NotAliveDisposed | ChangeKind::NotAliveDisposedUnregistered => {
+                if let Ok(discovered_writer_handle) =
+                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    let now = self.clock_handle.now();
+                    self.domain_participant
+                        .remove_discovered_writer(&discovered_writer_handle, now);
+
+                    let mut handle_list = Vec::new();
+                    for subscriber in self.domain_participant.subscriber_list() {
+                        for data_reader in subscriber.data_reader_list() {
+                            handle_list.push((
+                                subscriber.instance_handle(),
+                                data_reader.instance_handle(),
+                            ));
+                        }
+                    }
+                    for (subscriber_handle, data_reader_handle) in handle_list {
+                        self.remove_discovered_writer(
+                            discovered_writer_handle,
+                            subscriber_handle,
+                            data_reader_handle,
+                        )
+                        .await;
+                    }
+                }
+            }
+            ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
+        }
+
+        self.add_builtin_data_reader_change(participant_address, cache_change, DCPS_PUBLICATION)
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_builtin_subscriptions_detector_cache_change(
+        &mut self,
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        match cache_change.kind {
+            ChangeKind::Alive => {
+                if let Ok(discovered_reader_data) =
+                    DiscoveredReaderData::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    if self
+                        .domain_participant
+                        .find_topic(&discovered_reader_data.dds_subscription_data.topic_name)
+                        .is_none()
+                    {
+                        let reader_topic = TopicBuiltinTopicData {
+                            key: BuiltInTopicKey::default(),
+                            name: discovered_reader_data
+                                .dds_subscription_data
+                                .topic_name()
+                                .to_string(),
+                            type_name: discovered_reader_data
+                                .dds_subscription_data
+                                .get_type_name()
+                                .to_string(),
+
+                            topic_data: discovered_reader_data
+                                .dds_subscription_data
+                                .topic_data()
+                                .clone(),
+                            durability: discovered_reader_data
+                                .dds_subscription_data
+                                .durability()
+                                .clone(),
+                            deadline: discovered_reader_data
+                                .dds_subscription_data
+                                .deadline()
+                                .clone(),
+                            latency_budget: discovered_reader_data
+                                .dds_subscription_data
+                                .latency_budget()
+                                .clone(),
+                            liveliness: discovered_reader_data
+                                .dds_subscription_data
+                                .liveliness()
+                                .clone(),
+                            reliability: discovered_reader_data
+                                .dds_subscription_data
+                                .reliability()
+                                .clone(),
+                            destination_order: discovered_reader_data
+                                .dds_subscription_data
+                                .destination_order()
+                                .clone(),
+                            history: HistoryQosPolicy::default(),
+                            resource_limits: ResourceLimitsQosPolicy::default(),
+                            transport_priority: TransportPriorityQosPolicy::default(),
+                            lifespan: LifespanQosPolicy::default(),
+                            ownership: discovered_reader_data
+                                .dds_subscription_data
+                                .ownership()
+                                .clone(),
+                            representation: discovered_reader_data
+                                .dds_subscription_data
+                                .representation()
+                                .clone(),
+                        };
+                        self.domain_participant.add_discovered_topic(reader_topic);
+                    }
+
+                    self.domain_participant
+                        .add_discovered_reader(discovered_reader_data.clone());
+                    self.add_discovered_parameter_id_list(
+                        DiscoveredEntityKind::Subscription,
+                        InstanceHandle::new(discovered_reader_data.dds_subscription_data.key.value),
+                        cache_change.data_value.as_ref(),
+                    );
+                    let mut handle_list = Vec::new();
+                    for publisher in self.domain_participant.publisher_list() {
+                        for data_writer in publisher.data_writer_list().filter(|w| w.enabled()) {
+                            handle_list
+                                .push((publisher.instance_handle(), data_writer.instance_handle()));
+                        }
+                    }
+                    for (publisher_handle, data_writer_handle) in handle_list {
+                        self.add_discovered_reader(
+                            discovered_reader_data.clone(),
+                            publisher_handle,
+                            data_writer_handle,
+                            participant_address.clone(),
+                        )
+                        .await;
+                    }
+                }
+            }
+            ChangeKind::NotAliveDisposed | ChangeKind::NotAliveDisposedUnregistered => {
+                if let Ok(discovered_reader_handle) =
+                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    let now = self.clock_handle.now();
+                    self.domain_participant
+                        .remove_discovered_reader(&discovered_reader_handle, now);
+
+                    let mut handle_list = Vec::new();
+                    for publisher in self.domain_participant.publisher_list_mut() {
+                        for data_writer in publisher.data_writer_list() {
+                            handle_list
+                                .push((publisher.instance_handle(), data_writer.instance_handle()));
+                        }
+                    }
+
+                    for (publisher_handle, data_writer_handle) in handle_list {
+                        self.remove_discovered_reader(
+                            discovered_reader_handle,
+                            publisher_handle,
+                            data_writer_handle,
+                        )
+                        .await;
+                    }
+                }
+            }
+            ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
+        }
+
+        self.add_builtin_data_reader_change(participant_address, cache_change, DCPS_SUBSCRIPTION)
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_builtin_topics_detector_cache_change(
+        &mut self,
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        match cache_change.kind {
+            ChangeKind::Alive => {
+                if let Ok(topic_builtin_topic_data) =
+                    TopicBuiltinTopicData::deserialize_data(cache_change.data_value.as_ref())
+                {
+                    self.domain_participant
+                        .add_discovered_topic(topic_builtin_topic_data.clone());
+                    self.add_discovered_parameter_id_list(
+                        DiscoveredEntityKind::Topic,
+                        InstanceHandle::new(topic_builtin_topic_data.key.value),
+                        cache_change.data_value.as_ref(),
+                    );
+                    for topic in self.domain_participant.topic_list_mut() {
+                        if topic.topic_name() == topic_builtin_topic_data.name()
+                            && topic.type_name() == topic_builtin_topic_data.get_type_name()
+                            && !is_discovered_topic_consistent(
+                                topic.qos(),
+                                &topic_builtin_topic_data,
+                            )
+                        {
+                            topic.increment_inconsistent_topic_status().await;
+                        }
+                    }
+                }
+            }
+            ChangeKind::NotAliveDisposed
+            | ChangeKind::AliveFiltered
+            | ChangeKind::NotAliveUnregistered
+            | ChangeKind::NotAliveDisposedUnregistered => (),
+        }
+
+        self.add_builtin_data_reader_change(participant_address, cache_change, DCPS_TOPIC)
+            .await;
+    }
+
+    // The changes of the built-in topics are delivered to the built-in data readers the same way as those of
+    // the user-defined readers so that their listeners and conditions are notified
+    async fn add_builtin_data_reader_change(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        cache_change: CacheChange,
+        topic_name: &str,
+    ) {
+        let reception_timestamp = self.get_current_time();
+        let subscriber_handle = self.domain_participant.instance_handle();
+        let Some(data_reader_handle) = self
+            .domain_participant
+            .builtin_subscriber()
+            .data_reader_list()
+            .find(|dr| dr.topic_name() == topic_name)
+            .map(|dr| dr.instance_handle())
+        else {
+            return;
+        };
+        self.add_data_reader_change(
+            participant_address,
+            cache_change,
+            reception_timestamp,
+            subscriber_handle,
+            data_reader_handle,
+        )
+        .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_builtin_participant_message_cache_change(
+        &mut self,
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        if let Ok(participant_message_data) =
+            ParticipantMessageData::deserialize_data(cache_change.data_value.as_ref())
+        {
+            let liveliness_kind = match participant_message_data.kind {
+                PARTICIPANT_MESSAGE_DATA_KIND_AUTOMATIC_LIVELINESS_UPDATE => {
+                    Some(LivelinessQosPolicyKind::Automatic)
+                }
+                PARTICIPANT_MESSAGE_DATA_KIND_MANUAL_LIVELINESS_UPDATE => {
+                    Some(LivelinessQosPolicyKind::ManualByParticipant)
+                }
+                _ => None,
+            };
+            if let Some(liveliness_kind) = liveliness_kind {
+                let mut asserted_publication_list = Vec::new();
+                for subscriber in self.domain_participant.subscriber_list() {
+                    for data_reader in subscriber.data_reader_list() {
+                        for publication_handle in data_reader
+                            .get_matched_publications_with_liveliness(
+                                participant_message_data.participant_guid_prefix,
+                                liveliness_kind,
+                            )
+                        {
+                            asserted_publication_list.push((
+                                subscriber.instance_handle(),
+                                data_reader.instance_handle(),
+                                publication_handle,
+                            ));
+                        }
+                    }
+                }
+                for (subscriber_handle, data_reader_handle, publication_handle) in
+                    asserted_publication_list
+                {
+                    self.assert_matched_writer_liveliness(
+                        participant_address.clone(),
+                        subscriber_handle,
+                        data_reader_handle,
+                        publication_handle,
+                    )
+                    .await;
+                }
+            }
+        }
+
+        let reception_timestamp = self.get_current_time();
+        if let Some(reader) = self
+            .domain_participant
+            .builtin_subscriber_mut()
+            .data_reader_list_mut()
+            .find(|dr| dr.topic_name() == DCPS_PARTICIPANT_MESSAGE)
+        {
+            reader
+                .add_reader_change(cache_change, reception_timestamp)
+                .ok();
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_cache_change(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        cache_change: CacheChange,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        // Every change received from a writer asserts its liveliness
+        self.assert_matched_writer_liveliness(
+            participant_address.clone(),
+            subscriber_handle,
+            data_reader_handle,
+            InstanceHandle::new(cache_change.writer_guid.into()),
+        )
+        .await;
+
+        let reception_timestamp = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let coherent_access = subscriber.qos().presentation.coherent_access;
+
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        let writer_instance_handle = InstanceHandle::new(cache_change.writer_guid.into());
+
+        if data_reader
+            .get_matched_publication_data(&writer_instance_handle)
+            .is_none()
+        {
+            return;
+        }
+        if !cache_change.is_addressed_to(data_reader.transport_reader().guid()) {
+            return;
+        }
+        // The change marking the end of a coherent set carries no sample
+        let is_coherent_set_end = cache_change.is_coherent_set_end();
+        if is_coherent_set_end && !coherent_access {
+            return;
+        }
+        // The instance of a dispose or unregister carrying the key hash is found without its serialized key
+        let is_routed_by_key_hash = cache_change.instance_handle.is_some()
+            && matches!(
+                cache_change.kind,
+                ChangeKind::NotAliveDisposed
+                    | ChangeKind::NotAliveUnregistered
+                    | ChangeKind::NotAliveDisposedUnregistered
+            );
+        if !is_coherent_set_end
+            && !is_routed_by_key_hash
+            && self.strict_encapsulation_options
+            && validate_representation_options(cache_change.data_value.as_ref()).is_err()
+        {
+            tracing::warn!(
+                writer_guid = ?cache_change.writer_guid,
+                sequence_number = cache_change.sequence_number,
+                "Discarding sample with invalid encapsulation options"
+            );
+            data_reader.increment_sample_lost_status(1);
+            self.notify_sample_lost(participant_address, subscriber_handle, data_reader_handle)
+                .await;
+            return;
+        }
+        let deserialization_error_policy = data_reader.deserialization_error_policy();
+        if !is_coherent_set_end
+            && matches!(
+                deserialization_error_policy,
+                DeserializationErrorPolicy::Drop | DeserializationErrorPolicy::NotifyListener
+            )
+            && data_reader.is_deserialization_failure(&cache_change)
+        {
+            tracing::warn!(
+                writer_guid = ?cache_change.writer_guid,
+                sequence_number = cache_change.sequence_number,
+                "Discarding sample which can not be deserialized"
+            );
+            data_reader.increment_sample_lost_status(1);
+            if deserialization_error_policy == DeserializationErrorPolicy::NotifyListener {
+                self.notify_deserialization_error(
+                    participant_address.clone(),
+                    subscriber_handle,
+                    data_reader_handle,
+                    cache_change.data_value,
+                )
+                .await;
+            }
+            self.notify_sample_lost(participant_address, subscriber_handle, data_reader_handle)
+                .await;
+            return;
+        }
+        // The participant key is optional in the discovery data so the handle is derived from the GUID
+        let participant_handle = get_participant_instance_handle(cache_change.writer_guid.prefix());
+        let topic_name = String::from(data_reader.topic_name());
+        self.domain_participant.add_bytes_received(
+            &topic_name,
+            participant_handle,
+            cache_change.data_value.len() as u64,
+        );
+
+        if !coherent_access {
+            self.add_data_reader_change(
+                participant_address,
+                cache_change,
+                reception_timestamp,
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+            return;
+        }
+
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        let writer_guid = cache_change.writer_guid;
+        let new_coherent_set = cache_change.coherent_set.filter(|&x| {
+            !is_coherent_set_end && !data_reader.is_coherent_set_pending(writer_guid, x)
+        });
+        let complete_changes =
+            data_reader.add_coherent_set_change(cache_change, reception_timestamp);
+
+        if let (Some(coherent_set), DurationKind::Finite(timeout)) = (
+            new_coherent_set,
+            data_reader.incomplete_coherent_set_timeout(),
+        ) {
+            let mut timer_handle = self.timer_handle.clone();
+            let participant_address = participant_address.clone();
+            self.spawner_handle.spawn(async move {
+                timer_handle.delay(timeout.into()).await;
+                participant_address
+                    .send(DomainParticipantMail::Event(
+                        EventServiceMail::IncompleteCoherentSetTimeout {
                             subscriber_handle,
                             data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_requested_incompatible_qos_status();
-                        if let Some(l) = subscriber.listener() {
-                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
+                            writer_guid,
+                            coherent_set,
+                            participant_address: participant_address.clone(),
+                        },
+                    ))
+                    .await
+                    .ok();
+            });
+        }
+
+        for (cache_change, reception_timestamp) in complete_changes {
+            self.add_data_reader_change(
+                participant_address.clone(),
+                cache_change,
+                reception_timestamp,
+                subscriber_handle,
+                data_reader_handle,
+            )
+            .await;
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn add_lost_changes(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        writer_guid: Guid,
+        lost_change_count: i64,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        data_reader.increment_sample_lost_status(lost_change_count as i32);
+        self.notify_sample_lost(participant_address, subscriber_handle, data_reader_handle)
+            .await;
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn add_data_reader_change(
+        &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        cache_change: CacheChange,
+        reception_timestamp: Time,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    ) {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+
+        if !subscriber.content_filter_passes(data_reader_handle, &cache_change) {
+            return;
+        }
+
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if let Some(multitopic_reader_handle) = data_reader.multitopic_reader() {
+            let is_alive = cache_change.kind == ChangeKind::Alive;
+            let source_timestamp = cache_change.source_timestamp;
+            let data_value = cache_change.data_value.clone();
+            let add_change_result =
+                data_reader.add_reader_change(cache_change, reception_timestamp);
+            data_reader.send_read_ahead_requests().await;
+            if let (true, Ok(AddChangeResult::Added(_))) = (is_alive, add_change_result) {
+                let multitopic_change_list = self.join_multitopic_samples(
+                    subscriber_handle,
+                    multitopic_reader_handle,
+                    data_reader_handle,
+                    data_value,
+                    source_timestamp,
+                );
+                for multitopic_change in multitopic_change_list {
+                    Box::pin(self.add_data_reader_change(
+                        participant_address.clone(),
+                        multitopic_change,
+                        reception_timestamp,
+                        subscriber_handle,
+                        multitopic_reader_handle,
+                    ))
+                    .await;
+                }
+            }
+            return;
+        }
+        let gap_free_delivery = data_reader.gap_free_delivery();
+        let cache_change = if gap_free_delivery {
+            let Some(cache_change) =
+                data_reader.hold_blocked_writer_change(cache_change, reception_timestamp)
+            else {
+                return;
+            };
+            cache_change
+        } else {
+            cache_change
+        };
+        let writer_change = gap_free_delivery.then(|| cache_change.clone());
+        let add_change_result = data_reader.add_reader_change(cache_change, reception_timestamp);
+        data_reader.send_read_ahead_requests().await;
+        if let Some(writer_change) = writer_change {
+            if let Ok(AddChangeResult::Rejected(_, _)) = add_change_result {
+                // The rejection is only notified the first time the change blocks the writer
+                if !data_reader.block_writer_change(writer_change, reception_timestamp) {
+                    return;
+                }
+            } else {
+                data_reader.set_writer_change_delivered(
+                    writer_change.writer_guid,
+                    writer_change.sequence_number,
+                );
+            }
+        }
+        match add_change_result {
+            Ok(AddChangeResult::Added(change_instance_handle)) => {
+                data_reader.notify_sample_waiters();
+                if let DurationKind::Finite(freshness_period) =
+                    data_reader.instance_freshness_period()
+                {
+                    self.spawn_instance_freshness_check(
+                        subscriber_handle,
+                        data_reader_handle,
+                        change_instance_handle,
+                        freshness_period,
+                        participant_address.clone(),
+                    );
+                }
+                let Some(subscriber) = self
+                    .domain_participant
+                    .get_mut_subscriber(subscriber_handle)
+                else {
+                    return;
+                };
+                let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                    return;
+                };
+                if let DurationKind::Finite(deadline_missed_period) =
+                    data_reader.qos().deadline.period
+                {
+                    let mut timer_handle = self.timer_handle.clone();
+                    let participant_address = participant_address.clone();
+
+                    self.spawner_handle.spawn(async move {
+                        loop {
+                            timer_handle.delay(deadline_missed_period.into()).await;
+                            participant_address
+                                .send(DomainParticipantMail::Event(
+                                    EventServiceMail::RequestedDeadlineMissed {
+                                        subscriber_handle,
+                                        data_reader_handle,
+                                        change_instance_handle,
+                                        participant_address: participant_address.clone(),
+                                    },
+                                ))
                                 .await
                                 .ok();
                         }
-                    } else if self
+                    });
+                }
+                let deta_reader_on_data_available_active = data_reader
+                    .listener_mask()
+                    .contains(&StatusKind::DataAvailable);
+
+                let Some(subscriber) = self
+                    .domain_participant
+                    .get_mut_subscriber(subscriber_handle)
+                else {
+                    return;
+                };
+
+                if subscriber
+                    .listener_mask()
+                    .contains(&StatusKind::DataOnReaders)
+                {
+                    let Ok(the_subscriber) =
+                        self.get_subscriber_async(participant_address.clone(), subscriber_handle)
+                    else {
+                        return;
+                    };
+                    let Some(subscriber) = self
                         .domain_participant
-                        .listener_mask()
-                        .contains(&StatusKind::RequestedIncompatibleQos)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_requested_incompatible_qos_status();
-                        if let Some(l) = self.domain_participant.listener() {
-                            l.send(ListenerMail::RequestedIncompatibleQos { the_reader, status })
-                                .await
-                                .ok();
-                        }
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+
+                    if let Some(l) = subscriber.listener() {
+                        l.send(ListenerMail::DataOnReaders { the_subscriber })
+                            .await
+                            .ok();
+                    }
+                } else if deta_reader_on_data_available_active {
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+                    if let Some(l) = data_reader.listener() {
+                        l.send(ListenerMail::DataAvailable { the_reader })
+                            .await
+                            .ok();
+                    }
+                } else if subscriber
+                    .listener_mask()
+                    .contains(&StatusKind::DataAvailable)
+                {
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+                    if let Some(l) = subscriber.listener() {
+                        l.send(ListenerMail::DataAvailable { the_reader })
+                            .await
+                            .ok();
+                    }
+                } else if self
+                    .domain_participant
+                    .listener_mask()
+                    .contains(&StatusKind::DataAvailable)
+                {
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    if let Some(l) = self.domain_participant.listener() {
+                        l.send(ListenerMail::DataAvailable { the_reader })
+                            .await
+                            .ok();
+                    }
+                }
+
+                let Some(subscriber) = self
+                    .domain_participant
+                    .get_mut_subscriber(subscriber_handle)
+                else {
+                    return;
+                };
+
+                subscriber
+                    .status_condition()
+                    .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                        state: StatusKind::DataOnReaders,
+                    })
+                    .await;
+                let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                    return;
+                };
+                data_reader
+                    .status_condition()
+                    .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                        state: StatusKind::DataAvailable,
+                    })
+                    .await;
+            }
+            Ok(AddChangeResult::NotAdded) => (), // Do nothing
+            Ok(AddChangeResult::Rejected(instance_handle, sample_rejected_status_kind)) => {
+                data_reader
+                    .increment_sample_rejected_status(instance_handle, sample_rejected_status_kind);
+
+                if data_reader
+                    .listener_mask()
+                    .contains(&StatusKind::SampleRejected)
+                {
+                    let status = data_reader.get_sample_rejected_status();
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+                    if let Some(l) = data_reader.listener() {
+                        l.send(ListenerMail::SampleRejected { the_reader, status })
+                            .await
+                            .ok();
+                    };
+                } else if subscriber
+                    .listener_mask()
+                    .contains(&StatusKind::SampleRejected)
+                {
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+                    let status = data_reader.get_sample_rejected_status();
+                    if let Some(l) = subscriber.listener() {
+                        l.send(ListenerMail::SampleRejected { status, the_reader })
+                            .await
+                            .ok();
+                    }
+                } else if self
+                    .domain_participant
+                    .listener_mask()
+                    .contains(&StatusKind::SampleRejected)
+                {
+                    let Ok(the_reader) = self.get_data_reader_async(
+                        participant_address,
+                        subscriber_handle,
+                        data_reader_handle,
+                    ) else {
+                        return;
+                    };
+                    let Some(subscriber) = self
+                        .domain_participant
+                        .get_mut_subscriber(subscriber_handle)
+                    else {
+                        return;
+                    };
+
+                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
+                    else {
+                        return;
+                    };
+                    let status = data_reader.get_sample_rejected_status();
+                    if let Some(l) = self.domain_participant.listener() {
+                        l.send(ListenerMail::SampleRejected { status, the_reader })
+                            .await
+                            .ok();
                     }
+                }
+
+                let Some(subscriber) = self
+                    .domain_participant
+                    .get_mut_subscriber(subscriber_handle)
+                else {
+                    return;
+                };
+
+                let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                    return;
+                };
+                data_reader
+                    .status_condition()
+                    .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                        state: StatusKind::SampleRejected,
+                    })
+                    .await;
+            }
+            Err(_) => (),
+        }
+    }
 
-                    let Some(subscriber) = self
-                        .domain_participant
-                        .get_mut_subscriber(subscriber_handle)
-                    else {
-                        return;
-                    };
-                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                    else {
+    /// Assembles the samples of a MultiTopic reader resulting from a new sample received by the reader
+    /// of one of its related topics. The new sample is combined with the most recent samples received
+    /// by the readers of the other related topics.
+    fn join_multitopic_samples(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        multitopic_reader_handle: InstanceHandle,
+        constituent_reader_handle: InstanceHandle,
+        data_value: Arc<[u8]>,
+        source_timestamp: Option<transport::types::Time>,
+    ) -> Vec<CacheChange> {
+        let Some(subscriber) = self.domain_participant.get_subscriber(subscriber_handle) else {
+            return Vec::new();
+        };
+        let Some(multitopic_reader) = subscriber.get_data_reader(multitopic_reader_handle) else {
+            return Vec::new();
+        };
+        let Some(multitopic) = self
+            .domain_participant
+            .get_topic(multitopic_reader.topic_name())
+            .and_then(|t| t.multitopic())
+        else {
+            return Vec::new();
+        };
+
+        let mut related_topic_samples: Vec<Vec<SampleMembers>> = Vec::new();
+        for &reader_handle in multitopic_reader.multitopic_constituent_list() {
+            let Some(constituent_reader) = subscriber.get_data_reader(reader_handle) else {
+                return Vec::new();
+            };
+            let sample_data_list = if reader_handle == constituent_reader_handle {
+                vec![data_value.clone()]
+            } else {
+                constituent_reader.alive_sample_data_list()
+            };
+            related_topic_samples.push(
+                sample_data_list
+                    .iter()
+                    .filter_map(|d| {
+                        deserialize_sample_members(d, constituent_reader.type_support().as_ref())
+                            .ok()
+                    })
+                    .collect(),
+            );
+        }
+        let multitopic_data_list: Vec<Vec<u8>> = multitopic
+            .subscription_expression()
+            .join(multitopic.expression_parameters(), &related_topic_samples)
+            .iter()
+            .filter_map(|s| {
+                serialize_sample_members(multitopic_reader.type_support().as_ref(), s).ok()
+            })
+            .collect();
+
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return Vec::new();
+        };
+        let Some(multitopic_reader) = subscriber.get_mut_data_reader(multitopic_reader_handle)
+        else {
+            return Vec::new();
+        };
+        let writer_guid = multitopic_reader.transport_reader().guid();
+        multitopic_data_list
+            .into_iter()
+            .map(|data| CacheChange {
+                kind: ChangeKind::Alive,
+                writer_guid,
+                sequence_number: multitopic_reader.next_multitopic_sequence_number(),
+                source_timestamp,
+                instance_handle: None,
+                coherent_set: None,
+                directed_write: Vec::new(),
+                original_writer_info: None,
+                data_value: data.into(),
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self, serialized_data, read_ahead_value))]
+    pub fn add_read_ahead_value(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_data: Arc<[u8]>,
+        read_ahead_value: ReadAheadValue,
+    ) {
+        if let Some(s) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        {
+            if let Some(dr) = s.get_mut_data_reader(data_reader_handle) {
+                dr.set_read_ahead_value(&serialized_data, read_ahead_value);
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_writer_change(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        sequence_number: i64,
+    ) {
+        if let Some(p) = self.domain_participant.get_mut_publisher(publisher_handle) {
+            if let Some(dw) = p.get_mut_data_writer(data_writer_handle) {
+                dw.remove_change(sequence_number).await;
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn offered_deadline_missed(
+        &mut self,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    ) {
+        let current_time = self.get_current_time();
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+
+        if let DurationKind::Finite(deadline) = data_writer.qos().deadline.period {
+            match data_writer.get_instance_write_time(change_instance_handle) {
+                Some(t) => {
+                    if current_time - t < deadline {
                         return;
-                    };
-                    data_reader
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::RequestedIncompatibleQos,
-                        })
-                        .await;
+                    }
                 }
+                None => return,
+            }
+        } else {
+            return;
+        }
+
+        data_writer.increment_offered_deadline_missed_status(change_instance_handle);
+
+        if data_writer
+            .listener_mask()
+            .contains(&StatusKind::OfferedDeadlineMissed)
+        {
+            let status = data_writer.get_offered_deadline_missed_status().await;
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+
+            if let Some(l) = data_writer.listener() {
+                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if publisher
+            .listener_mask()
+            .contains(&StatusKind::OfferedDeadlineMissed)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_offered_deadline_missed_status().await;
+            if let Some(l) = publisher.listener() {
+                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if self
+            .domain_participant
+            .listener_mask()
+            .contains(&StatusKind::OfferedDeadlineMissed)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_offered_deadline_missed_status().await;
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+                    .await
+                    .ok();
+            }
+        }
+
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        data_writer
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::OfferedDeadlineMissed,
+            })
+            .await;
+    }
+
+    /// Starts or stops throttling the publication of the data writer depending on the lag of its matched reliable readers
+    /// and notifies the change.
+    async fn update_data_writer_publication_throttling(
+        &mut self,
+        participant_address: &R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+    ) {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        if !data_writer.update_publication_throttling() {
+            return;
+        }
+
+        if data_writer
+            .listener_mask()
+            .contains(&StatusKind::PublicationThrottled)
+        {
+            let status = data_writer.get_publication_throttled_status().await;
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            if let Some(l) = data_writer.listener() {
+                l.send(ListenerMail::PublicationThrottled { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if publisher
+            .listener_mask()
+            .contains(&StatusKind::PublicationThrottled)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_publication_throttled_status().await;
+            if let Some(l) = publisher.listener() {
+                l.send(ListenerMail::PublicationThrottled { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if self
+            .domain_participant
+            .listener_mask()
+            .contains(&StatusKind::PublicationThrottled)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address.clone(),
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_publication_throttled_status().await;
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::PublicationThrottled { the_writer, status })
+                    .await
+                    .ok();
             }
         }
-    }
 
-    #[tracing::instrument(skip(self))]
-    async fn remove_discovered_writer(
-        &mut self,
-        publication_handle: InstanceHandle,
-        subscriber_handle: InstanceHandle,
-        data_reader_handle: InstanceHandle,
-    ) {
-        let Some(subscriber) = self
-            .domain_participant
-            .get_mut_subscriber(subscriber_handle)
-        else {
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
             return;
         };
-        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
             return;
         };
-        if data_reader
-            .get_matched_publication_data(&publication_handle)
-            .is_some()
-        {
-            data_reader
-                .remove_matched_publication(&publication_handle)
-                .await;
-        }
+        data_writer
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::PublicationThrottled,
+            })
+            .await;
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn add_builtin_participants_detector_cache_change(
+    #[tracing::instrument(skip(self, participant_address))]
+    pub async fn check_publication_throttling(
         &mut self,
-        cache_change: CacheChange,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) {
-        match cache_change.kind {
-            ChangeKind::Alive => {
-                if let Ok(discovered_participant_data) =
-                    SpdpDiscoveredParticipantData::deserialize_data(
-                        cache_change.data_value.as_ref(),
-                    )
-                {
-                    self.add_discovered_participant(discovered_participant_data)
-                        .await;
-                }
-            }
-            ChangeKind::NotAliveDisposed => {
-                if let Ok(discovered_participant_handle) =
-                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    self.remove_discovered_participant(discovered_participant_handle);
-                }
-            }
-            ChangeKind::AliveFiltered
-            | ChangeKind::NotAliveUnregistered
-            | ChangeKind::NotAliveDisposedUnregistered => (), // Do nothing,
-        }
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
 
-        let reception_timestamp = self.get_current_time();
-        if let Some(reader) = self
-            .domain_participant
-            .builtin_subscriber_mut()
-            .data_reader_list_mut()
-            .find(|dr| dr.topic_name() == DCPS_PARTICIPANT)
-        {
-            reader
-                .add_reader_change(cache_change, reception_timestamp)
-                .ok();
-        }
+        // The check is repeated for as long as the publication throttling is enabled
+        let Some(publication_throttling) = data_writer.publication_throttling() else {
+            data_writer.set_publication_throttling_check_scheduled(false);
+            return;
+        };
+        self.spawn_publication_throttling_check(
+            publisher_handle,
+            data_writer_handle,
+            publication_throttling.throttled_publication_period.into(),
+            participant_address.clone(),
+        );
+
+        self.update_data_writer_publication_throttling(
+            &participant_address,
+            publisher_handle,
+            data_writer_handle,
+        )
+        .await;
     }
 
     #[tracing::instrument(skip(self, participant_address))]
-    pub async fn add_builtin_publications_detector_cache_change(
+    pub async fn check_data_writer_liveliness(
         &mut self,
-        cache_change: CacheChange,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) {
-        match cache_change.kind {
-            ChangeKind::Alive => {
-                if let Ok(discovered_writer_data) =
-                    DiscoveredWriterData::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    let publication_builtin_topic_data =
-                        &discovered_writer_data.dds_publication_data;
-                    if self
-                        .domain_participant
-                        .find_topic(&publication_builtin_topic_data.topic_name)
-                        .is_none()
-                    {
-                        let writer_topic = TopicBuiltinTopicData {
-                            key: BuiltInTopicKey::default(),
-                            name: String::from(publication_builtin_topic_data.topic_name()),
-                            type_name: String::from(publication_builtin_topic_data.get_type_name()),
-                            durability: publication_builtin_topic_data.durability().clone(),
-                            deadline: publication_builtin_topic_data.deadline().clone(),
-                            latency_budget: publication_builtin_topic_data.latency_budget().clone(),
-                            liveliness: publication_builtin_topic_data.liveliness().clone(),
-                            reliability: publication_builtin_topic_data.reliability().clone(),
-                            transport_priority: TransportPriorityQosPolicy::default(),
-                            lifespan: publication_builtin_topic_data.lifespan().clone(),
-                            destination_order: publication_builtin_topic_data
-                                .destination_order()
-                                .clone(),
-                            history: HistoryQosPolicy::default(),
-                            resource_limits: ResourceLimitsQosPolicy::default(),
-                            ownership: publication_builtin_topic_data.ownership().clone(),
-                            topic_data: publication_builtin_topic_data.topic_data().clone(),
-                            representation: publication_builtin_topic_data.representation().clone(),
-                        };
-                        self.domain_participant.add_discovered_topic(writer_topic);
-                    }
+        let current_time = self.get_current_time();
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
 
-                    self.domain_participant
-                        .add_discovered_writer(discovered_writer_data.clone());
-                    let mut handle_list = Vec::new();
-                    for subscriber in self.domain_participant.subscriber_list() {
-                        for data_reader in subscriber.data_reader_list() {
-                            handle_list.push((
-                                subscriber.instance_handle(),
-                                data_reader.instance_handle(),
-                            ));
-                        }
-                    }
-                    for (subscriber_handle, data_reader_handle) in handle_list {
-                        self.add_discovered_writer(
-                            discovered_writer_data.clone(),
-                            subscriber_handle,
-                            data_reader_handle,
-                            participant_address.clone(),
-                        )
-                        .await;
-                    }
-                }
-            }
-            ChangeKind::NotAliveDisposed | ChangeKind::NotAliveDisposedUnregistered => {
-                if let Ok(discovered_writer_handle) =
-                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    self.domain_participant
-                        .remove_discovered_writer(&discovered_writer_handle);
+        // The check is repeated for as long as the writer exists
+        if let DurationKind::Finite(lease_duration) = data_writer.qos().liveliness.lease_duration {
+            self.spawn_data_writer_liveliness_check(
+                publisher_handle,
+                data_writer_handle,
+                core::time::Duration::from(lease_duration) / 2,
+                participant_address.clone(),
+            );
+        }
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
 
-                    let mut handle_list = Vec::new();
-                    for subscriber in self.domain_participant.subscriber_list() {
-                        for data_reader in subscriber.data_reader_list() {
-                            handle_list.push((
-                                subscriber.instance_handle(),
-                                data_reader.instance_handle(),
-                            ));
-                        }
-                    }
-                    for (subscriber_handle, data_reader_handle) in handle_list {
-                        self.remove_discovered_writer(
-                            discovered_writer_handle,
-                            subscriber_handle,
-                            data_reader_handle,
-                        )
-                        .await;
-                    }
-                }
-            }
-            ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
+        if !data_writer.check_liveliness_lost(current_time) {
+            return;
         }
 
-        let reception_timestamp = self.get_current_time();
-        if let Some(reader) = self
+        if data_writer
+            .listener_mask()
+            .contains(&StatusKind::LivelinessLost)
+        {
+            let status = data_writer.get_liveliness_lost_status().await;
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+
+            if let Some(l) = data_writer.listener() {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if publisher
+            .listener_mask()
+            .contains(&StatusKind::LivelinessLost)
+        {
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_liveliness_lost_status().await;
+            if let Some(l) = publisher.listener() {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
+        } else if self
             .domain_participant
-            .builtin_subscriber_mut()
-            .data_reader_list_mut()
-            .find(|dr| dr.topic_name() == DCPS_PUBLICATION)
+            .listener_mask()
+            .contains(&StatusKind::LivelinessLost)
         {
-            reader
-                .add_reader_change(cache_change, reception_timestamp)
-                .ok();
+            let Ok(the_writer) = self.get_data_writer_async(
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+            ) else {
+                return;
+            };
+
+            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            else {
+                return;
+            };
+            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+                return;
+            };
+            let status = data_writer.get_liveliness_lost_status().await;
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::LivelinessLost { the_writer, status })
+                    .await
+                    .ok();
+            }
         }
+
+        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+            return;
+        };
+        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            return;
+        };
+        data_writer
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::LivelinessLost,
+            })
+            .await;
     }
 
     #[tracing::instrument(skip(self, participant_address))]
-    pub async fn add_builtin_subscriptions_detector_cache_change(
+    pub async fn requested_deadline_missed(
         &mut self,
-        cache_change: CacheChange,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) {
-        match cache_change.kind {
-            ChangeKind::Alive => {
-                if let Ok(discovered_reader_data) =
-                    DiscoveredReaderData::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    if self
-                        .domain_participant
-                        .find_topic(&discovered_reader_data.dds_subscription_data.topic_name)
-                        .is_none()
-                    {
-                        let reader_topic = TopicBuiltinTopicData {
-                            key: BuiltInTopicKey::default(),
-                            name: discovered_reader_data
-                                .dds_subscription_data
-                                .topic_name()
-                                .to_string(),
-                            type_name: discovered_reader_data
-                                .dds_subscription_data
-                                .get_type_name()
-                                .to_string(),
-
-                            topic_data: discovered_reader_data
-                                .dds_subscription_data
-                                .topic_data()
-                                .clone(),
-                            durability: discovered_reader_data
-                                .dds_subscription_data
-                                .durability()
-                                .clone(),
-                            deadline: discovered_reader_data
-                                .dds_subscription_data
-                                .deadline()
-                                .clone(),
-                            latency_budget: discovered_reader_data
-                                .dds_subscription_data
-                                .latency_budget()
-                                .clone(),
-                            liveliness: discovered_reader_data
-                                .dds_subscription_data
-                                .liveliness()
-                                .clone(),
-                            reliability: discovered_reader_data
-                                .dds_subscription_data
-                                .reliability()
-                                .clone(),
-                            destination_order: discovered_reader_data
-                                .dds_subscription_data
-                                .destination_order()
-                                .clone(),
-                            history: HistoryQosPolicy::default(),
-                            resource_limits: ResourceLimitsQosPolicy::default(),
-                            transport_priority: TransportPriorityQosPolicy::default(),
-                            lifespan: LifespanQosPolicy::default(),
-                            ownership: discovered_reader_data
-                                .dds_subscription_data
-                                .ownership()
-                                .clone(),
-                            representation: discovered_reader_data
-                                .dds_subscription_data
-                                .representation()
-                                .clone(),
-                        };
-                        self.domain_participant.add_discovered_topic(reader_topic);
-                    }
+        let current_time = self.get_current_time();
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
 
-                    self.domain_participant
-                        .add_discovered_reader(discovered_reader_data.clone());
-                    let mut handle_list = Vec::new();
-                    for publisher in self.domain_participant.publisher_list() {
-                        for data_writer in publisher.data_writer_list() {
-                            handle_list
-                                .push((publisher.instance_handle(), data_writer.instance_handle()));
-                        }
-                    }
-                    for (publisher_handle, data_writer_handle) in handle_list {
-                        self.add_discovered_reader(
-                            discovered_reader_data.clone(),
-                            publisher_handle,
-                            data_writer_handle,
-                            participant_address.clone(),
-                        )
-                        .await;
-                    }
+        if let DurationKind::Finite(deadline) = data_reader.qos().deadline.period {
+            if let Some(t) = data_reader.get_instance_received_time(&change_instance_handle) {
+                if current_time - t < deadline {
+                    return;
                 }
+            } else {
+                return;
             }
-            ChangeKind::NotAliveDisposed | ChangeKind::NotAliveDisposedUnregistered => {
-                if let Ok(discovered_reader_handle) =
-                    InstanceHandle::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    self.domain_participant
-                        .remove_discovered_reader(&discovered_reader_handle);
+        }
 
-                    let mut handle_list = Vec::new();
-                    for publisher in self.domain_participant.publisher_list_mut() {
-                        for data_writer in publisher.data_writer_list() {
-                            handle_list
-                                .push((publisher.instance_handle(), data_writer.instance_handle()));
-                        }
-                    }
+        data_reader.remove_instance_ownership(&change_instance_handle);
+        data_reader.increment_requested_deadline_missed_status(change_instance_handle);
 
-                    for (publisher_handle, data_writer_handle) in handle_list {
-                        self.remove_discovered_reader(
-                            discovered_reader_handle,
-                            publisher_handle,
-                            data_writer_handle,
-                        )
-                        .await;
-                    }
-                }
+        if data_reader
+            .listener_mask()
+            .contains(&StatusKind::RequestedDeadlineMissed)
+        {
+            let status = data_reader.get_requested_deadline_missed_status();
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address,
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            if let Some(l) = data_reader.listener() {
+                l.send(ListenerMail::RequestedDeadlineMissed { the_reader, status })
+                    .await
+                    .ok();
             }
-            ChangeKind::AliveFiltered | ChangeKind::NotAliveUnregistered => (),
-        }
+        } else if subscriber
+            .listener_mask()
+            .contains(&StatusKind::RequestedDeadlineMissed)
+        {
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address,
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
 
-        let reception_timestamp = self.get_current_time();
-        if let Some(reader) = self
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let status = data_reader.get_requested_deadline_missed_status();
+            if let Some(l) = subscriber.listener() {
+                l.send(ListenerMail::RequestedDeadlineMissed { status, the_reader })
+                    .await
+                    .ok();
+            }
+        } else if self
             .domain_participant
-            .builtin_subscriber_mut()
-            .data_reader_list_mut()
-            .find(|dr| dr.topic_name() == DCPS_SUBSCRIPTION)
+            .listener_mask()
+            .contains(&StatusKind::RequestedDeadlineMissed)
         {
-            reader
-                .add_reader_change(cache_change, reception_timestamp)
-                .ok();
-        }
-    }
+            let Ok(the_reader) = self.get_data_reader_async(
+                participant_address,
+                subscriber_handle,
+                data_reader_handle,
+            ) else {
+                return;
+            };
 
-    #[tracing::instrument(skip(self))]
-    pub async fn add_builtin_topics_detector_cache_change(&mut self, cache_change: CacheChange) {
-        match cache_change.kind {
-            ChangeKind::Alive => {
-                if let Ok(topic_builtin_topic_data) =
-                    TopicBuiltinTopicData::deserialize_data(cache_change.data_value.as_ref())
-                {
-                    self.domain_participant
-                        .add_discovered_topic(topic_builtin_topic_data.clone());
-                    for topic in self.domain_participant.topic_list_mut() {
-                        if topic.topic_name() == topic_builtin_topic_data.name()
-                            && topic.type_name() == topic_builtin_topic_data.get_type_name()
-                            && !is_discovered_topic_consistent(
-                                topic.qos(),
-                                &topic_builtin_topic_data,
-                            )
-                        {
-                            topic.increment_inconsistent_topic_status().await;
-                        }
-                    }
-                }
-            }
-            ChangeKind::NotAliveDisposed
-            | ChangeKind::AliveFiltered
-            | ChangeKind::NotAliveUnregistered
-            | ChangeKind::NotAliveDisposedUnregistered => (),
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
+            else {
+                return;
+            };
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+                return;
+            };
+            let status = data_reader.get_requested_deadline_missed_status();
+            if let Some(l) = self.domain_participant.listener() {
+                l.send(ListenerMail::RequestedDeadlineMissed { status, the_reader })
+                    .await
+                    .ok();
+            }
         }
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
 
-        let reception_timestamp = self.get_current_time();
-        if let Some(reader) = self
+        data_reader
+            .status_condition()
+            .send_actor_mail(StatusConditionMail::AddCommunicationState {
+                state: StatusKind::RequestedDeadlineMissed,
+            })
+            .await;
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn check_instance_freshness(
+        &mut self,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+    ) {
+        let now = self.get_current_time();
+        let Some(subscriber) = self
             .domain_participant
-            .builtin_subscriber_mut()
-            .data_reader_list_mut()
-            .find(|dr| dr.topic_name() == DCPS_TOPIC)
-        {
-            reader
-                .add_reader_change(cache_change, reception_timestamp)
-                .ok();
-        }
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        data_reader.check_instance_freshness(change_instance_handle, now);
     }
 
     #[tracing::instrument(skip(self, participant_address))]
-    pub async fn add_cache_change(
+    pub async fn incomplete_coherent_set_timeout(
         &mut self,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-        cache_change: CacheChange,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
+        writer_guid: Guid,
+        coherent_set: i64,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) {
-        let reception_timestamp = self.get_current_time();
         let Some(subscriber) = self
             .domain_participant
             .get_mut_subscriber(subscriber_handle)
         else {
             return;
         };
-
         let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
             return;
         };
-        let writer_instance_handle = InstanceHandle::new(cache_change.writer_guid.into());
-
-        if data_reader
-            .get_matched_publication_data(&writer_instance_handle)
-            .is_some()
-        {
-            match data_reader.add_reader_change(cache_change, reception_timestamp) {
-                Ok(AddChangeResult::Added(change_instance_handle)) => {
-                    if let DurationKind::Finite(deadline_missed_period) =
-                        data_reader.qos().deadline.period
-                    {
-                        let mut timer_handle = self.timer_handle.clone();
-                        let participant_address = participant_address.clone();
-
-                        self.spawner_handle.spawn(async move {
-                            loop {
-                                timer_handle.delay(deadline_missed_period.into()).await;
-                                participant_address
-                                    .send(DomainParticipantMail::Event(
-                                        EventServiceMail::RequestedDeadlineMissed {
-                                            subscriber_handle,
-                                            data_reader_handle,
-                                            change_instance_handle,
-                                            participant_address: participant_address.clone(),
-                                        },
-                                    ))
-                                    .await
-                                    .ok();
-                            }
-                        });
-                    }
-                    let deta_reader_on_data_available_active = data_reader
-                        .listener_mask()
-                        .contains(&StatusKind::DataAvailable);
-
-                    let Some(subscriber) = self
-                        .domain_participant
-                        .get_mut_subscriber(subscriber_handle)
-                    else {
-                        return;
-                    };
-
-                    if subscriber
-                        .listener_mask()
-                        .contains(&StatusKind::DataOnReaders)
-                    {
-                        let Ok(the_subscriber) = self
-                            .get_subscriber_async(participant_address.clone(), subscriber_handle)
-                        else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-
-                        if let Some(l) = subscriber.listener() {
-                            l.send(ListenerMail::DataOnReaders { the_subscriber })
-                                .await
-                                .ok();
-                        }
-                    } else if deta_reader_on_data_available_active {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        if let Some(l) = data_reader.listener() {
-                            l.send(ListenerMail::DataAvailable { the_reader })
-                                .await
-                                .ok();
-                        }
-                    }
-
-                    let Some(subscriber) = self
-                        .domain_participant
-                        .get_mut_subscriber(subscriber_handle)
-                    else {
-                        return;
-                    };
-
-                    subscriber
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::DataOnReaders,
-                        })
-                        .await;
-                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                    else {
-                        return;
-                    };
-                    data_reader
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::DataAvailable,
-                        })
-                        .await;
-                }
-                Ok(AddChangeResult::NotAdded) => (), // Do nothing
-                Ok(AddChangeResult::Rejected(instance_handle, sample_rejected_status_kind)) => {
-                    data_reader.increment_sample_rejected_status(
-                        instance_handle,
-                        sample_rejected_status_kind,
-                    );
-
-                    if data_reader
-                        .listener_mask()
-                        .contains(&StatusKind::SampleRejected)
-                    {
-                        let status = data_reader.get_sample_rejected_status();
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        if let Some(l) = data_reader.listener() {
-                            l.send(ListenerMail::SampleRejected { the_reader, status })
-                                .await
-                                .ok();
-                        };
-                    } else if subscriber
-                        .listener_mask()
-                        .contains(&StatusKind::SampleRejected)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_sample_rejected_status();
-                        if let Some(l) = subscriber.listener() {
-                            l.send(ListenerMail::SampleRejected { status, the_reader })
-                                .await
-                                .ok();
-                        }
-                    } else if self
-                        .domain_participant
-                        .listener_mask()
-                        .contains(&StatusKind::SampleRejected)
-                    {
-                        let Ok(the_reader) = self.get_data_reader_async(
-                            participant_address,
-                            subscriber_handle,
-                            data_reader_handle,
-                        ) else {
-                            return;
-                        };
-                        let Some(subscriber) = self
-                            .domain_participant
-                            .get_mut_subscriber(subscriber_handle)
-                        else {
-                            return;
-                        };
-
-                        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                        else {
-                            return;
-                        };
-                        let status = data_reader.get_sample_rejected_status();
-                        if let Some(l) = self.domain_participant.listener() {
-                            l.send(ListenerMail::SampleRejected { status, the_reader })
-                                .await
-                                .ok();
-                        }
-                    }
-
-                    let Some(subscriber) = self
-                        .domain_participant
-                        .get_mut_subscriber(subscriber_handle)
-                    else {
-                        return;
-                    };
+        let Some(changes) = data_reader.remove_pending_coherent_set(writer_guid, coherent_set)
+        else {
+            return;
+        };
 
-                    let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle)
-                    else {
-                        return;
-                    };
-                    data_reader
-                        .status_condition()
-                        .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                            state: StatusKind::SampleRejected,
-                        })
-                        .await;
+        match data_reader.incomplete_coherent_set_policy() {
+            IncompleteCoherentSetPolicy::Release => {
+                for (cache_change, reception_timestamp) in changes {
+                    self.add_data_reader_change(
+                        participant_address.clone(),
+                        cache_change,
+                        reception_timestamp,
+                        subscriber_handle,
+                        data_reader_handle,
+                    )
+                    .await;
                 }
-                Err(_) => (),
+                return;
+            }
+            IncompleteCoherentSetPolicy::Purge => {
+                data_reader.increment_sample_lost_status(changes.len() as i32);
             }
         }
+
+        self.notify_sample_lost(participant_address, subscriber_handle, data_reader_handle)
+            .await;
     }
 
-    #[tracing::instrument(skip(self))]
-    pub async fn remove_writer_change(
+    #[tracing::instrument(skip(self, participant_address))]
+    async fn notify_deserialization_error(
         &mut self,
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-        sequence_number: i64,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_data: Arc<[u8]>,
     ) {
-        if let Some(p) = self.domain_participant.get_mut_publisher(publisher_handle) {
-            if let Some(dw) = p.get_mut_data_writer(data_writer_handle) {
-                dw.remove_change(sequence_number).await;
-            }
+        let Ok(the_reader) =
+            self.get_data_reader_async(participant_address, subscriber_handle, data_reader_handle)
+        else {
+            return;
+        };
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
+            return;
+        };
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
+            return;
+        };
+        if let Some(l) = data_reader.listener() {
+            l.send(ListenerMail::DeserializationError {
+                the_reader,
+                serialized_data,
+            })
+            .await
+            .ok();
         }
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub async fn offered_deadline_missed(
+    async fn notify_sample_lost(
         &mut self,
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-        change_instance_handle: InstanceHandle,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
     ) {
-        let current_time = self.get_current_time();
-        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
             return;
         };
-        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
             return;
         };
-
-        if let DurationKind::Finite(deadline) = data_writer.qos().deadline.period {
-            match data_writer.get_instance_write_time(change_instance_handle) {
-                Some(t) => {
-                    if current_time - t < deadline {
-                        return;
-                    }
-                }
-                None => return,
-            }
-        } else {
-            return;
-        }
-
-        data_writer.increment_offered_deadline_missed_status(change_instance_handle);
-
-        if data_writer
+        if data_reader
             .listener_mask()
-            .contains(&StatusKind::OfferedDeadlineMissed)
+            .contains(&StatusKind::SampleLost)
         {
-            let status = data_writer.get_offered_deadline_missed_status().await;
-            let Ok(the_writer) = self.get_data_writer_async(
+            let status = data_reader.get_sample_lost_status();
+            let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
-                publisher_handle,
-                data_writer_handle,
+                subscriber_handle,
+                data_reader_handle,
             ) else {
                 return;
             };
-
-            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
             else {
                 return;
             };
-            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
                 return;
             };
-
-            if let Some(l) = data_writer.listener() {
-                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+            if let Some(l) = data_reader.listener() {
+                l.send(ListenerMail::SampleLost { the_reader, status })
                     .await
                     .ok();
             }
-        } else if publisher
-            .listener_mask()
-            .contains(&StatusKind::OfferedDeadlineMissed)
-        {
-            let Ok(the_writer) = self.get_data_writer_async(
+        } else if subscriber.listener_mask().contains(&StatusKind::SampleLost) {
+            let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
-                publisher_handle,
-                data_writer_handle,
+                subscriber_handle,
+                data_reader_handle,
             ) else {
                 return;
             };
-            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
             else {
                 return;
             };
-            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
                 return;
             };
-            let status = data_writer.get_offered_deadline_missed_status().await;
-            if let Some(l) = publisher.listener() {
-                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+            let status = data_reader.get_sample_lost_status();
+            if let Some(l) = subscriber.listener() {
+                l.send(ListenerMail::SampleLost { status, the_reader })
                     .await
                     .ok();
             }
         } else if self
             .domain_participant
             .listener_mask()
-            .contains(&StatusKind::OfferedDeadlineMissed)
+            .contains(&StatusKind::SampleLost)
         {
-            let Ok(the_writer) = self.get_data_writer_async(
+            let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
-                publisher_handle,
-                data_writer_handle,
+                subscriber_handle,
+                data_reader_handle,
             ) else {
                 return;
             };
-
-            let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle)
+            let Some(subscriber) = self
+                .domain_participant
+                .get_mut_subscriber(subscriber_handle)
             else {
                 return;
             };
-            let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+            let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
                 return;
             };
-            let status = data_writer.get_offered_deadline_missed_status().await;
+            let status = data_reader.get_sample_lost_status();
             if let Some(l) = self.domain_participant.listener() {
-                l.send(ListenerMail::OfferedDeadlineMissed { the_writer, status })
+                l.send(ListenerMail::SampleLost { status, the_reader })
                     .await
                     .ok();
             }
         }
-
-        let Some(publisher) = self.domain_participant.get_mut_publisher(publisher_handle) else {
+        let Some(subscriber) = self
+            .domain_participant
+            .get_mut_subscriber(subscriber_handle)
+        else {
             return;
         };
-        let Some(data_writer) = publisher.get_mut_data_writer(data_writer_handle) else {
+        let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
             return;
         };
-        data_writer
+
+        data_reader
             .status_condition()
             .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                state: StatusKind::OfferedDeadlineMissed,
+                state: StatusKind::SampleLost,
             })
             .await;
     }
 
-    #[tracing::instrument(skip(self, participant_address))]
-    pub async fn requested_deadline_missed(
+    async fn notify_liveliness_changed(
         &mut self,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
-        change_instance_handle: InstanceHandle,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
     ) {
-        let current_time = self.get_current_time();
         let Some(subscriber) = self
             .domain_participant
             .get_mut_subscriber(subscriber_handle)
@@ -3894,25 +8102,11 @@ where
         let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
             return;
         };
-
-        if let DurationKind::Finite(deadline) = data_reader.qos().deadline.period {
-            if let Some(t) = data_reader.get_instance_received_time(&change_instance_handle) {
-                if current_time - t < deadline {
-                    return;
-                }
-            } else {
-                return;
-            }
-        }
-
-        data_reader.remove_instance_ownership(&change_instance_handle);
-        data_reader.increment_requested_deadline_missed_status(change_instance_handle);
-
         if data_reader
             .listener_mask()
-            .contains(&StatusKind::RequestedDeadlineMissed)
+            .contains(&StatusKind::LivelinessChanged)
         {
-            let status = data_reader.get_requested_deadline_missed_status();
+            let status = data_reader.get_liveliness_changed_status();
             let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
                 subscriber_handle,
@@ -3930,13 +8124,13 @@ where
                 return;
             };
             if let Some(l) = data_reader.listener() {
-                l.send(ListenerMail::RequestedDeadlineMissed { the_reader, status })
+                l.send(ListenerMail::LivelinessChanged { the_reader, status })
                     .await
                     .ok();
             }
         } else if subscriber
             .listener_mask()
-            .contains(&StatusKind::RequestedDeadlineMissed)
+            .contains(&StatusKind::LivelinessChanged)
         {
             let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
@@ -3945,7 +8139,6 @@ where
             ) else {
                 return;
             };
-
             let Some(subscriber) = self
                 .domain_participant
                 .get_mut_subscriber(subscriber_handle)
@@ -3955,16 +8148,16 @@ where
             let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
                 return;
             };
-            let status = data_reader.get_requested_deadline_missed_status();
+            let status = data_reader.get_liveliness_changed_status();
             if let Some(l) = subscriber.listener() {
-                l.send(ListenerMail::RequestedDeadlineMissed { status, the_reader })
+                l.send(ListenerMail::LivelinessChanged { status, the_reader })
                     .await
                     .ok();
             }
         } else if self
             .domain_participant
             .listener_mask()
-            .contains(&StatusKind::RequestedDeadlineMissed)
+            .contains(&StatusKind::LivelinessChanged)
         {
             let Ok(the_reader) = self.get_data_reader_async(
                 participant_address,
@@ -3973,7 +8166,6 @@ where
             ) else {
                 return;
             };
-
             let Some(subscriber) = self
                 .domain_participant
                 .get_mut_subscriber(subscriber_handle)
@@ -3983,9 +8175,9 @@ where
             let Some(data_reader) = subscriber.get_mut_data_reader(data_reader_handle) else {
                 return;
             };
-            let status = data_reader.get_requested_deadline_missed_status();
+            let status = data_reader.get_liveliness_changed_status();
             if let Some(l) = self.domain_participant.listener() {
-                l.send(ListenerMail::RequestedDeadlineMissed { status, the_reader })
+                l.send(ListenerMail::LivelinessChanged { status, the_reader })
                     .await
                     .ok();
             }
@@ -4003,7 +8195,7 @@ where
         data_reader
             .status_condition()
             .send_actor_mail(StatusConditionMail::AddCommunicationState {
-                state: StatusKind::RequestedDeadlineMissed,
+                state: StatusKind::LivelinessChanged,
             })
             .await;
     }
@@ -4036,6 +8228,26 @@ where
             ))
             .is_some();
 
+        if !is_domain_id_matching || !is_domain_tag_matching {
+            if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Debug) {
+                tracing::debug!(
+                    target: DiagnosticSubsystem::Discovery.target(),
+                    guid_prefix = ?discovered_participant_data.participant_proxy.guid_prefix,
+                    domain_id = ?discovered_participant_data.participant_proxy.domain_id,
+                    domain_tag = discovered_participant_data.participant_proxy.domain_tag,
+                    "Not matching remote participant of another domain"
+                );
+            }
+        } else if !is_participant_discovered
+            && DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Info)
+        {
+            tracing::info!(
+                target: DiagnosticSubsystem::Discovery.target(),
+                guid_prefix = ?discovered_participant_data.participant_proxy.guid_prefix,
+                "Discovered remote participant"
+            );
+        }
+
         if is_domain_id_matching && is_domain_tag_matching && !is_participant_discovered {
             self.add_matched_publications_detector(&discovered_participant_data);
             self.add_matched_publications_announcer(&discovered_participant_data);
@@ -4043,6 +8255,8 @@ where
             self.add_matched_subscriptions_announcer(&discovered_participant_data);
             self.add_matched_topics_detector(&discovered_participant_data);
             self.add_matched_topics_announcer(&discovered_participant_data);
+            self.add_matched_participant_message_reader(&discovered_participant_data);
+            self.add_matched_participant_message_writer(&discovered_participant_data);
 
             self.announce_participant().await;
         }
@@ -4051,10 +8265,39 @@ where
             .add_discovered_participant(discovered_participant_data);
     }
 
+    #[tracing::instrument(skip(self, serialized_data))]
+    fn add_discovered_parameter_id_list(
+        &mut self,
+        entity_kind: DiscoveredEntityKind,
+        entity_handle: InstanceHandle,
+        serialized_data: &[u8],
+    ) {
+        if let Ok(parameter_id_list) = ParameterListCdrDeserializer::new(serialized_data)
+            .and_then(|pl_deserializer| pl_deserializer.parameter_id_list())
+        {
+            self.domain_participant
+                .add_discovered_parameter_id_list(DiscoveredParameterIdList {
+                    entity_kind,
+                    entity_handle,
+                    parameter_id_list,
+                });
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     fn remove_discovered_participant(&mut self, discovered_participant: InstanceHandle) {
+        if DiagnosticSubsystem::Discovery.is_enabled(DiagnosticVerbosity::Info) {
+            tracing::info!(
+                target: DiagnosticSubsystem::Discovery.target(),
+                ?discovered_participant,
+                "Removed remote participant"
+            );
+        }
+        // The data sent to the participant is accounted before its statistics are removed
+        self.account_data_bytes_sent();
+        let now = self.clock_handle.now();
         self.domain_participant
-            .remove_discovered_participant(&discovered_participant);
+            .remove_discovered_participant(&discovered_participant, now);
     }
 
     #[tracing::instrument(skip(self))]
@@ -4341,6 +8584,101 @@ where
             }
         }
     }
+
+    #[tracing::instrument(skip(self))]
+    fn add_matched_participant_message_reader(
+        &mut self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+    ) {
+        if discovered_participant_data
+            .participant_proxy
+            .available_builtin_endpoints
+            .has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_READER)
+        {
+            let remote_reader_guid = Guid::new(
+                discovered_participant_data.participant_proxy.guid_prefix,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            );
+            let remote_group_entity_id = ENTITYID_UNKNOWN;
+            let expects_inline_qos = false;
+            let reader_proxy = transport::writer::ReaderProxy {
+                remote_reader_guid,
+                remote_group_entity_id,
+                reliability_kind: ReliabilityKind::Reliable,
+                durability_kind: DurabilityKind::TransientLocal,
+                unicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_unicast_locator_list
+                    .to_vec(),
+                multicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_multicast_locator_list
+                    .to_vec(),
+                expects_inline_qos,
+            };
+            if let Some(dw) = self
+                .domain_participant
+                .builtin_publisher_mut()
+                .data_writer_list_mut()
+                .find(|dw| {
+                    dw.transport_writer().guid().entity_id()
+                        == ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER
+                })
+            {
+                match dw.transport_writer_mut() {
+                    TransportWriterKind::Stateful(w) => w.add_matched_reader(reader_proxy),
+                    TransportWriterKind::Stateless(_) => panic!("Invalid built-in writer type"),
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn add_matched_participant_message_writer(
+        &mut self,
+        discovered_participant_data: &SpdpDiscoveredParticipantData,
+    ) {
+        if discovered_participant_data
+            .participant_proxy
+            .available_builtin_endpoints
+            .has(BuiltinEndpointSet::BUILTIN_ENDPOINT_PARTICIPANT_MESSAGE_DATA_WRITER)
+        {
+            let remote_writer_guid = Guid::new(
+                discovered_participant_data.participant_proxy.guid_prefix,
+                ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            );
+            let remote_group_entity_id = ENTITYID_UNKNOWN;
+
+            let writer_proxy = transport::reader::WriterProxy {
+                remote_writer_guid,
+                remote_group_entity_id,
+                reliability_kind: ReliabilityKind::Reliable,
+                durability_kind: DurabilityKind::TransientLocal,
+                unicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_unicast_locator_list
+                    .to_vec(),
+                multicast_locator_list: discovered_participant_data
+                    .participant_proxy
+                    .metatraffic_multicast_locator_list
+                    .to_vec(),
+            };
+            if let Some(dr) = self
+                .domain_participant
+                .builtin_subscriber_mut()
+                .data_reader_list_mut()
+                .find(|dr| {
+                    dr.transport_reader().guid().entity_id()
+                        == ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER
+                })
+            {
+                match dr.transport_reader_mut() {
+                    TransportReaderKind::Stateful(r) => r.add_matched_writer(writer_proxy),
+                    TransportReaderKind::Stateless(_) => panic!("Invalid built-in reader type"),
+                }
+            }
+        }
+    }
 }
 
 #[tracing::instrument(skip(type_support))]
@@ -4357,6 +8695,61 @@ fn get_topic_kind(type_support: &dyn DynamicType) -> TopicKind {
     TopicKind::NoKey
 }
 
+fn get_participant_instance_handle(guid_prefix: GuidPrefix) -> InstanceHandle {
+    InstanceHandle::new(Guid::new(guid_prefix, ENTITYID_PARTICIPANT).into())
+}
+
+fn get_reserved_samples(
+    history: &HistoryQosPolicy,
+    resource_limits: &ResourceLimitsQosPolicy,
+    topic_kind: TopicKind,
+) -> Length {
+    if let Length::Limited(max_samples) = resource_limits.max_samples {
+        return Length::Limited(max_samples);
+    }
+    match (history.kind, topic_kind, resource_limits.max_instances) {
+        (HistoryQosPolicyKind::KeepLast(depth), TopicKind::NoKey, _) => Length::Limited(depth),
+        (
+            HistoryQosPolicyKind::KeepLast(depth),
+            TopicKind::WithKey,
+            Length::Limited(max_instances),
+        ) => Length::Limited(depth.saturating_mul(max_instances)),
+        _ => Length::Unlimited,
+    }
+}
+
+fn check_participant_resource_budget(
+    participant_max_samples: Length,
+    participant_reserved_samples: usize,
+    reserved_samples: Length,
+) -> DdsResult<()> {
+    match (participant_max_samples, reserved_samples) {
+        (Length::Unlimited, _) => Ok(()),
+        (Length::Limited(max_samples), Length::Limited(reserved_samples))
+            if participant_reserved_samples + reserved_samples as usize <= max_samples as usize =>
+        {
+            Ok(())
+        }
+        _ => Err(DdsError::OutOfResources),
+    }
+}
+
+// Whether a remote endpoint and a local endpoint agree on the kind of the topic as encoded in
+// their entity kind. Endpoints with an entity kind not telling the kind of the topic are matched.
+fn is_matched_topic_kind(remote_entity_id: EntityId, local_entity_id: EntityId) -> bool {
+    fn topic_kind(entity_id: EntityId) -> Option<TopicKind> {
+        match entity_id.entity_kind() {
+            USER_DEFINED_WRITER_WITH_KEY | USER_DEFINED_READER_WITH_KEY => Some(TopicKind::WithKey),
+            USER_DEFINED_WRITER_NO_KEY | USER_DEFINED_READER_NO_KEY => Some(TopicKind::NoKey),
+            _ => None,
+        }
+    }
+    match (topic_kind(remote_entity_id), topic_kind(local_entity_id)) {
+        (Some(remote_topic_kind), Some(local_topic_kind)) => remote_topic_kind == local_topic_kind,
+        _ => true,
+    }
+}
+
 #[tracing::instrument]
 fn get_discovered_reader_incompatible_qos_policy_list(
     writer_qos: &DataWriterQos,
@@ -6,9 +6,17 @@ use crate::{
     },
     dcps::{
         actor::{Actor, ActorAddress, MailHandler},
+        data_reader::{DeserializationValidator, LoanedSampleList, ReadAheadValue, SampleData},
         listeners::domain_participant_listener::ListenerMail,
         status_condition_actor::StatusConditionActor,
     },
+    domain::{
+        bandwidth_statistics::{BandwidthQuota, BandwidthStatistics},
+        discovery_conformance::DiscoveryConformanceReport,
+        discovery_history::RemovedDiscoveredEntity,
+        domain_participant::EndpointAuthorizationHook,
+        memory_usage::MemoryUsageStatistics,
+    },
     infrastructure::{
         error::DdsResult,
         instance::InstanceHandle,
@@ -16,19 +24,37 @@ use crate::{
             DataReaderQos, DataWriterQos, DomainParticipantQos, PublisherQos, QosKind,
             SubscriberQos, TopicQos,
         },
-        sample_info::{InstanceStateKind, SampleInfo, SampleStateKind, ViewStateKind},
+        qos_policy::{DataRepresentationId, QosPolicyId},
+        sample_info::{
+            InstanceStateKind, SampleInfo, SampleStateKind, SequenceNumber, ViewStateKind,
+        },
         status::{
-            InconsistentTopicStatus, OfferedDeadlineMissedStatus, PublicationMatchedStatus,
-            StatusKind, SubscriptionMatchedStatus,
+            InconsistentTopicStatus, LivelinessChangedStatus, LivelinessLostStatus,
+            OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
+            PublicationThrottledStatus, RequestedDeadlineMissedStatus,
+            RequestedIncompatibleQosStatus, SampleLostStatus, SampleRejectedStatus, StatusKind,
+            SubscriptionMatchedStatus, WriterRestartedStatus,
         },
-        time::{Duration, Time},
+        time::{Duration, DurationKind, Time},
+    },
+    publication::data_writer::{
+        DuplicateSuppressionMode, KeepLastReplacementPolicy, OriginalWriterInfo,
+        PublicationThrottling,
     },
     runtime::{DdsRuntime, OneshotSend},
-    transport::history_cache::CacheChange,
+    subscription::data_reader::{
+        DeserializationErrorPolicy, HeadOfLineStatus, IncompleteCoherentSetPolicy,
+        InstanceFreshnessObserver, InstanceFreshnessStatus, InstanceStatistics,
+    },
+    transport::{
+        history_cache::CacheChange,
+        types::{Guid, Locator},
+        writer::ChangeLifecycleObserver,
+    },
     xtypes::dynamic_type::DynamicType,
 };
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
-use core::{future::Future, pin::Pin};
+use core::{any::Any, future::Future, pin::Pin};
 
 pub enum ParticipantServiceMail<R: DdsRuntime> {
     CreateUserDefinedPublisher {
@@ -64,6 +90,15 @@ pub enum ParticipantServiceMail<R: DdsRuntime> {
         type_support: Arc<dyn DynamicType + Send + Sync>,
         reply_sender: R::OneshotSender<DdsResult<InstanceHandle>>,
     },
+    CreateMultitopic {
+        topic_name: String,
+        type_name: String,
+        subscription_expression: String,
+        expression_parameters: Vec<String>,
+        status_condition: Actor<R, StatusConditionActor<R>>,
+        type_support: Arc<dyn DynamicType + Send + Sync>,
+        reply_sender: R::OneshotSender<DdsResult<InstanceHandle>>,
+    },
     DeleteUserDefinedTopic {
         participant_handle: InstanceHandle,
         topic_name: String,
@@ -141,6 +176,28 @@ pub enum ParticipantServiceMail<R: DdsRuntime> {
 
         reply_sender: R::OneshotSender<DdsResult<ParticipantBuiltinTopicData>>,
     },
+    GetDiscoveryConformanceReport {
+        participant_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<DiscoveryConformanceReport>>,
+    },
+    GetRemovedDiscoveredEntities {
+        reply_sender: R::OneshotSender<DdsResult<Vec<RemovedDiscoveredEntity>>>,
+    },
+    GetBandwidthStatistics {
+        reply_sender: R::OneshotSender<DdsResult<BandwidthStatistics>>,
+    },
+    GetMemoryUsageStatistics {
+        reply_sender: R::OneshotSender<DdsResult<MemoryUsageStatistics>>,
+    },
+    SetTopicBandwidthQuota {
+        topic_name: String,
+        bandwidth_quota: Option<BandwidthQuota>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetEndpointAuthorizationHook {
+        hook: Option<Arc<dyn EndpointAuthorizationHook>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     GetDiscoveredTopics {
         reply_sender: R::OneshotSender<DdsResult<Vec<InstanceHandle>>>,
     },
@@ -161,14 +218,19 @@ pub enum ParticipantServiceMail<R: DdsRuntime> {
     SetListener {
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         status_kind: Vec<StatusKind>,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
     Enable {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
     IsEmpty {
         reply_sender: R::OneshotSender<bool>,
     },
+    AssertLiveliness {
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 pub enum TopicServiceMail<R: DdsRuntime> {
@@ -204,13 +266,17 @@ pub enum PublisherServiceMail<R: DdsRuntime> {
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-        reply_sender: R::OneshotSender<DdsResult<InstanceHandle>>,
+        reply_sender: R::OneshotSender<DdsResult<(InstanceHandle, DataRepresentationId)>>,
     },
     DeleteDataWriter {
         publisher_handle: InstanceHandle,
         datawriter_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    DeleteContainedEntities {
+        publisher_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     GetDefaultDataWriterQos {
         publisher_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<DataWriterQos>>,
@@ -229,12 +295,35 @@ pub enum PublisherServiceMail<R: DdsRuntime> {
         qos: QosKind<PublisherQos>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    BeginPartitionSession {
+        publisher_handle: InstanceHandle,
+        partition_list: Vec<String>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    EndPartitionSession {
+        publisher_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    BeginCoherentChanges {
+        publisher_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    EndCoherentChanges {
+        publisher_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     SetPublisherListener {
         publisher_handle: InstanceHandle,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    Enable {
+        publisher_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 pub enum SubscriberServiceMail<R: DdsRuntime> {
@@ -253,6 +342,10 @@ pub enum SubscriberServiceMail<R: DdsRuntime> {
         datareader_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    DeleteContainedEntities {
+        subscriber_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     LookupDataReader {
         subscriber_handle: InstanceHandle,
         topic_name: String,
@@ -285,6 +378,11 @@ pub enum SubscriberServiceMail<R: DdsRuntime> {
         mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    Enable {
+        subscriber_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 pub enum WriterServiceMail<R: DdsRuntime> {
@@ -311,15 +409,35 @@ pub enum WriterServiceMail<R: DdsRuntime> {
         subscription_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<SubscriptionBuiltinTopicData>>,
     },
+    GetMatchedSubscriptionLocators {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        subscription_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<Locator>>>,
+    },
+    CheckCompatibility {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        subscription_builtin_topic_data: SubscriptionBuiltinTopicData,
+        reply_sender: R::OneshotSender<DdsResult<Vec<QosPolicyId>>>,
+    },
     GetPublicationMatchedStatus {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<PublicationMatchedStatus>>,
     },
+    RegisterInstance {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+        reply_sender: R::OneshotSender<DdsResult<Option<InstanceHandle>>>,
+    },
     UnregisterInstance {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
         timestamp: Time,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
@@ -329,26 +447,122 @@ pub enum WriterServiceMail<R: DdsRuntime> {
         serialized_data: Vec<u8>,
         reply_sender: R::OneshotSender<DdsResult<Option<InstanceHandle>>>,
     },
+    StartDraining {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    UnregisterAllInstances {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    Flush {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    GetKeyValue {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        serialized_key_holder: Vec<u8>,
+        handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<u8>>>,
+    },
+    LoanSample {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Option<Box<dyn Any + Send>>>>,
+    },
+    ReturnLoanedSample {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        sample: Box<dyn Any + Send>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     WriteWTimestamp {
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
         timestamp: Time,
-        reply_sender: R::OneshotSender<DdsResult<()>>,
+        sample_hash: Option<u64>,
+        reader_handle_list: Vec<InstanceHandle>,
+        original_writer_info: Option<OriginalWriterInfo>,
+        reply_sender: R::OneshotSender<DdsResult<Option<SequenceNumber>>>,
     },
     DisposeWTimestamp {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         serialized_data: Vec<u8>,
+        handle: Option<InstanceHandle>,
         timestamp: Time,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
+    GetLivelinessLostStatus {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<LivelinessLostStatus>>,
+    },
+    GetPublicationThrottledStatus {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<PublicationThrottledStatus>>,
+    },
     GetOfferedDeadlineMissedStatus {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<OfferedDeadlineMissedStatus>>,
     },
+    AssertLiveliness {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetKeepLastReplacementPolicy {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        policy: KeepLastReplacementPolicy,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetDuplicateSuppressionMode {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        mode: DuplicateSuppressionMode,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetWriteCoalescingWindow {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        window: Option<Duration>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetPublicationThrottling {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        publication_throttling: Option<PublicationThrottling>,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    SetChangeLifecycleObserver {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    GetReplacedUnacknowledgedSampleCount {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<u64>>,
+    },
+    GetOfferedIncompatibleQosStatus {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<OfferedIncompatibleQosStatus>>,
+    },
     EnableDataWriter {
         publisher_handle: InstanceHandle,
         data_writer_handle: InstanceHandle,
@@ -379,9 +593,10 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
         #[allow(clippy::type_complexity)]
-        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>>>,
+        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<SampleData>, SampleInfo)>>>,
     },
     Take {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
         max_samples: i32,
@@ -390,7 +605,28 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         instance_states: Vec<InstanceStateKind>,
         specific_instance_handle: Option<InstanceHandle>,
         #[allow(clippy::type_complexity)]
-        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>>>,
+        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<SampleData>, SampleInfo)>>>,
+    },
+    ReadLoaned {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+        reply_sender: R::OneshotSender<DdsResult<LoanedSampleList>>,
+    },
+    TakeLoaned {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        max_samples: i32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        specific_instance_handle: Option<InstanceHandle>,
+        reply_sender: R::OneshotSender<DdsResult<LoanedSampleList>>,
     },
     ReadNextInstance {
         subscriber_handle: InstanceHandle,
@@ -401,9 +637,10 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         #[allow(clippy::type_complexity)]
-        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>>>,
+        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<SampleData>, SampleInfo)>>>,
     },
     TakeNextInstance {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
         max_samples: i32,
@@ -412,13 +649,48 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         view_states: Vec<ViewStateKind>,
         instance_states: Vec<InstanceStateKind>,
         #[allow(clippy::type_complexity)]
-        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<Arc<[u8]>>, SampleInfo)>>>,
+        reply_sender: R::OneshotSender<DdsResult<Vec<(Option<SampleData>, SampleInfo)>>>,
     },
     GetSubscriptionMatchedStatus {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<SubscriptionMatchedStatus>>,
     },
+    GetRequestedIncompatibleQosStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<RequestedIncompatibleQosStatus>>,
+    },
+    GetSampleLostStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<SampleLostStatus>>,
+    },
+    GetWriterRestartedStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<WriterRestartedStatus>>,
+    },
+    GetRequestedDeadlineMissedStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<RequestedDeadlineMissedStatus>>,
+    },
+    GetLivelinessChangedStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<LivelinessChangedStatus>>,
+    },
+    GetSampleRejectedStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<SampleRejectedStatus>>,
+    },
+    RequestHistoricalData {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
     WaitForHistoricalData {
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         subscriber_handle: InstanceHandle,
@@ -432,11 +704,42 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         publication_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<PublicationBuiltinTopicData>>,
     },
+    CheckCompatibility {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_builtin_topic_data: PublicationBuiltinTopicData,
+        reply_sender: R::OneshotSender<DdsResult<Vec<QosPolicyId>>>,
+    },
+    GetMatchedPublicationHighWatermark {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<SequenceNumber>>,
+    },
+    GetMatchedPublicationLocators {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<Locator>>>,
+    },
     GetMatchedPublications {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
         reply_sender: R::OneshotSender<DdsResult<Vec<InstanceHandle>>>,
     },
+    LookupInstance {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_data: Vec<u8>,
+        reply_sender: R::OneshotSender<DdsResult<Option<InstanceHandle>>>,
+    },
+    GetKeyValue {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_key_holder: Vec<u8>,
+        handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<u8>>>,
+    },
     SetQos {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
@@ -455,65 +758,224 @@ pub enum ReaderServiceMail<R: DdsRuntime> {
         listener_mask: Vec<StatusKind>,
         reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-}
-
-pub enum MessageServiceMail<R: DdsRuntime> {
-    AddCacheChange {
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
-        cache_change: CacheChange,
+    SetReadAheadSender {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
+        read_ahead_sender: Option<R::ChannelSender<Arc<[u8]>>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    RemoveWriterChange {
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-        sequence_number: i64,
-    },
-    AreAllChangesAcknowledged {
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-        reply_sender: R::OneshotSender<DdsResult<bool>>,
+    SetDeserializationErrorPolicy {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        policy: DeserializationErrorPolicy,
+        deserialization_validator: Option<DeserializationValidator>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    IsHistoricalDataReceived {
+    SetIncompleteCoherentSetTimeout {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
-        reply_sender: R::OneshotSender<DdsResult<bool>>,
+        timeout: DurationKind,
+        policy: IncompleteCoherentSetPolicy,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    AddBuiltinParticipantsDetectorCacheChange {
-        cache_change: CacheChange,
+    SetGapFreeDelivery {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        enabled: bool,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    AddBuiltinPublicationsDetectorCacheChange {
-        cache_change: CacheChange,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    SetContentFilter {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        filter_expression: String,
+        filter_parameters: Vec<String>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    AddBuiltinSubscriptionsDetectorCacheChange {
-        cache_change: CacheChange,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    GetHeadOfLineStatus {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<Vec<HeadOfLineStatus>>>,
     },
-    AddBuiltinTopicsDetectorCacheChange {
-        cache_change: CacheChange,
+    GetInstanceStatistics {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<InstanceStatistics>>,
     },
-}
-
-pub enum EventServiceMail<R: DdsRuntime> {
-    OfferedDeadlineMissed {
-        publisher_handle: InstanceHandle,
-        data_writer_handle: InstanceHandle,
-        change_instance_handle: InstanceHandle,
+    SetInstanceFreshnessWatchdog {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        period: DurationKind,
+        observer: Option<Arc<dyn InstanceFreshnessObserver>>,
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
     },
-    RequestedDeadlineMissed {
+    GetInstanceFreshnessStatus {
         subscriber_handle: InstanceHandle,
         data_reader_handle: InstanceHandle,
-        change_instance_handle: InstanceHandle,
-        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        reply_sender: R::OneshotSender<DdsResult<InstanceFreshnessStatus>>,
     },
-}
-
-pub enum DiscoveryServiceMail {
-    AnnounceParticipant,
-    AnnounceDeletedParticipant,
-}
+    CreateReadCondition {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        query: Option<(String, Vec<String>)>,
+        reply_sender: R::OneshotSender<DdsResult<u32>>,
+    },
+    DeleteReadCondition {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    GetReadConditionTriggerValue {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        reply_sender: R::OneshotSender<DdsResult<bool>>,
+    },
+    GetQueryParameters {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        reply_sender: R::OneshotSender<DdsResult<Vec<String>>>,
+    },
+    SetQueryParameters {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        query_parameters: Vec<String>,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
+    WaitForSamples {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        added_change_count: u64,
+        reply_sender: R::OneshotSender<DdsResult<u64>>,
+    },
+}
+
+pub enum MessageServiceMail<R: DdsRuntime> {
+    AddCacheChange {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        cache_change: CacheChange,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    },
+    AddLostChanges {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        writer_guid: Guid,
+        lost_change_count: i64,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+    },
+    RemoveWriterChange {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        sequence_number: i64,
+    },
+    AddReadAheadValue {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        serialized_data: Arc<[u8]>,
+        read_ahead_value: ReadAheadValue,
+    },
+    AreAllChangesAcknowledged {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<bool>>,
+    },
+    IsHistoricalDataReceived {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        reply_sender: R::OneshotSender<DdsResult<bool>>,
+    },
+    AddBuiltinParticipantsDetectorCacheChange {
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    AddBuiltinPublicationsDetectorCacheChange {
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    AddBuiltinSubscriptionsDetectorCacheChange {
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    AddBuiltinTopicsDetectorCacheChange {
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    AddBuiltinParticipantMessageCacheChange {
+        cache_change: CacheChange,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+}
+
+pub enum EventServiceMail<R: DdsRuntime> {
+    OfferedDeadlineMissed {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    RequestedDeadlineMissed {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    CheckDataWriterLiveliness {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    CheckPublicationThrottling {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    AssertAutomaticLiveliness {
+        assertion_id: u64,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    CheckDataReaderLiveliness {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        publication_handle: InstanceHandle,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    IncompleteCoherentSetTimeout {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        writer_guid: Guid,
+        coherent_set: i64,
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    },
+    CheckInstanceFreshness {
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        change_instance_handle: InstanceHandle,
+    },
+    FlushDataWriterBatch {
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        batch_id: u64,
+    },
+    PublishCoalescedWrite {
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        publisher_handle: InstanceHandle,
+        data_writer_handle: InstanceHandle,
+        instance_handle: InstanceHandle,
+        window_id: u64,
+    },
+}
+
+pub enum DiscoveryServiceMail {
+    AnnounceParticipant,
+    AnnounceDeletedParticipant,
+}
 
 pub enum DomainParticipantMail<R: DdsRuntime> {
     Participant(ParticipantServiceMail<R>),
@@ -621,6 +1083,25 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 )
                 .await,
             ),
+            ParticipantServiceMail::CreateMultitopic {
+                topic_name,
+                type_name,
+                subscription_expression,
+                expression_parameters,
+                status_condition,
+                type_support,
+                reply_sender,
+            } => reply_sender.send(
+                self.create_multitopic(
+                    topic_name,
+                    type_name,
+                    subscription_expression,
+                    expression_parameters,
+                    status_condition,
+                    type_support,
+                )
+                .await,
+            ),
             ParticipantServiceMail::DeleteUserDefinedTopic {
                 participant_handle,
                 topic_name,
@@ -679,6 +1160,27 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 participant_handle,
                 reply_sender,
             } => reply_sender.send(self.get_discovered_participant_data(participant_handle)),
+            ParticipantServiceMail::GetDiscoveryConformanceReport {
+                participant_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_discovery_conformance_report(participant_handle)),
+            ParticipantServiceMail::GetRemovedDiscoveredEntities { reply_sender } => {
+                reply_sender.send(self.get_removed_discovered_entities())
+            }
+            ParticipantServiceMail::GetBandwidthStatistics { reply_sender } => {
+                reply_sender.send(self.get_bandwidth_statistics())
+            }
+            ParticipantServiceMail::GetMemoryUsageStatistics { reply_sender } => {
+                reply_sender.send(self.get_memory_usage_statistics())
+            }
+            ParticipantServiceMail::SetTopicBandwidthQuota {
+                topic_name,
+                bandwidth_quota,
+                reply_sender,
+            } => reply_sender.send(self.set_topic_bandwidth_quota(topic_name, bandwidth_quota)),
+            ParticipantServiceMail::SetEndpointAuthorizationHook { hook, reply_sender } => {
+                reply_sender.send(self.set_endpoint_authorization_hook(hook))
+            }
             ParticipantServiceMail::GetDiscoveredTopics { reply_sender } => {
                 reply_sender.send(self.get_discovered_topics())
             }
@@ -695,15 +1197,23 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
             ParticipantServiceMail::SetListener {
                 listener_sender,
                 status_kind,
+                participant_address,
                 reply_sender,
-            } => reply_sender
-                .send(self.set_domain_participant_listener(listener_sender, status_kind)),
-            ParticipantServiceMail::Enable { reply_sender } => {
-                reply_sender.send(self.enable_domain_participant().await)
-            }
+            } => reply_sender.send(self.set_domain_participant_listener(
+                listener_sender,
+                status_kind,
+                participant_address,
+            )),
+            ParticipantServiceMail::Enable {
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(self.enable_domain_participant(participant_address).await),
             ParticipantServiceMail::IsEmpty { reply_sender } => {
                 reply_sender.send(self.is_participant_empty())
             }
+            ParticipantServiceMail::AssertLiveliness { reply_sender } => {
+                reply_sender.send(self.assert_participant_liveliness().await)
+            }
         }
     }
 
@@ -717,7 +1227,7 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 topic_name,
                 topic_qos,
                 reply_sender,
-            } => reply_sender.send(self.set_topic_qos(topic_name, topic_qos)),
+            } => reply_sender.send(self.set_topic_qos(topic_name, topic_qos).await),
             TopicServiceMail::GetQos {
                 topic_name,
                 reply_sender,
@@ -764,6 +1274,13 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 self.delete_data_writer(publisher_handle, datawriter_handle)
                     .await,
             ),
+            PublisherServiceMail::DeleteContainedEntities {
+                publisher_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.delete_publisher_contained_entities(publisher_handle)
+                    .await,
+            ),
             PublisherServiceMail::GetDefaultDataWriterQos {
                 publisher_handle,
                 reply_sender,
@@ -781,7 +1298,31 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 publisher_handle,
                 qos,
                 reply_sender,
-            } => reply_sender.send(self.set_publisher_qos(publisher_handle, qos)),
+            } => reply_sender.send(self.set_publisher_qos(publisher_handle, qos).await),
+            PublisherServiceMail::BeginPartitionSession {
+                publisher_handle,
+                partition_list,
+                reply_sender,
+            } => reply_sender.send(
+                self.begin_partition_session(publisher_handle, partition_list)
+                    .await,
+            ),
+            PublisherServiceMail::EndPartitionSession {
+                publisher_handle,
+                reply_sender,
+            } => reply_sender.send(self.end_partition_session(publisher_handle).await),
+            PublisherServiceMail::BeginCoherentChanges {
+                publisher_handle,
+                reply_sender,
+            } => reply_sender.send(self.begin_coherent_changes(publisher_handle)),
+            PublisherServiceMail::EndCoherentChanges {
+                publisher_handle,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(
+                self.end_coherent_changes(participant_address, publisher_handle)
+                    .await,
+            ),
             PublisherServiceMail::SetPublisherListener {
                 publisher_handle,
                 listener_sender,
@@ -792,6 +1333,14 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 listener_sender,
                 mask,
             )),
+            PublisherServiceMail::Enable {
+                publisher_handle,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(
+                self.enable_publisher(publisher_handle, participant_address)
+                    .await,
+            ),
         }
     }
 
@@ -830,6 +1379,26 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 data_writer_handle,
                 subscription_handle,
             )),
+            WriterServiceMail::GetMatchedSubscriptionLocators {
+                publisher_handle,
+                data_writer_handle,
+                subscription_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_matched_subscription_locators(
+                publisher_handle,
+                data_writer_handle,
+                subscription_handle,
+            )),
+            WriterServiceMail::CheckCompatibility {
+                publisher_handle,
+                data_writer_handle,
+                subscription_builtin_topic_data,
+                reply_sender,
+            } => reply_sender.send(self.check_subscription_compatibility(
+                publisher_handle,
+                data_writer_handle,
+                subscription_builtin_topic_data,
+            )),
             WriterServiceMail::GetPublicationMatchedStatus {
                 publisher_handle,
                 data_writer_handle,
@@ -838,17 +1407,30 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 self.get_publication_matched_status(publisher_handle, data_writer_handle)
                     .await,
             ),
+            WriterServiceMail::RegisterInstance {
+                publisher_handle,
+                data_writer_handle,
+                serialized_data,
+                reply_sender,
+            } => reply_sender.send(
+                self.register_instance(publisher_handle, data_writer_handle, serialized_data)
+                    .await,
+            ),
             WriterServiceMail::UnregisterInstance {
+                participant_address,
                 publisher_handle,
                 data_writer_handle,
                 serialized_data,
+                handle,
                 timestamp,
                 reply_sender,
             } => reply_sender.send(
                 self.unregister_instance(
+                    participant_address,
                     publisher_handle,
                     data_writer_handle,
                     serialized_data,
+                    handle,
                     timestamp,
                 )
                 .await,
@@ -863,12 +1445,67 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 data_writer_handle,
                 serialized_data,
             )),
+            WriterServiceMail::StartDraining {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.start_data_writer_draining(publisher_handle, data_writer_handle)),
+            WriterServiceMail::UnregisterAllInstances {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.unregister_data_writer_instances(publisher_handle, data_writer_handle)
+                    .await,
+            ),
+            WriterServiceMail::Flush {
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.flush_data_writer(participant_address, publisher_handle, data_writer_handle)
+                    .await,
+            ),
+            WriterServiceMail::GetKeyValue {
+                publisher_handle,
+                data_writer_handle,
+                serialized_key_holder,
+                handle,
+                reply_sender,
+            } => reply_sender.send(self.get_data_writer_key_value(
+                publisher_handle,
+                data_writer_handle,
+                serialized_key_holder,
+                handle,
+            )),
+            WriterServiceMail::LoanSample {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.loan_data_writer_sample(publisher_handle, data_writer_handle)),
+            WriterServiceMail::ReturnLoanedSample {
+                publisher_handle,
+                data_writer_handle,
+                sample,
+                reply_sender,
+            } => reply_sender.send(self.return_data_writer_loaned_sample(
+                publisher_handle,
+                data_writer_handle,
+                sample,
+            )),
             WriterServiceMail::WriteWTimestamp {
                 participant_address,
                 publisher_handle,
                 data_writer_handle,
                 serialized_data,
+                handle,
                 timestamp,
+                sample_hash,
+                reader_handle_list,
+                original_writer_info,
                 reply_sender,
             } => reply_sender.send(
                 self.write_w_timestamp(
@@ -876,52 +1513,151 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                     publisher_handle,
                     data_writer_handle,
                     serialized_data,
+                    handle,
                     timestamp,
+                    sample_hash,
+                    reader_handle_list,
+                    original_writer_info,
                 )
                 .await,
             ),
             WriterServiceMail::DisposeWTimestamp {
+                participant_address,
                 publisher_handle,
                 data_writer_handle,
                 serialized_data,
+                handle,
                 timestamp,
                 reply_sender,
             } => reply_sender.send(
                 self.dispose_w_timestamp(
+                    participant_address,
                     publisher_handle,
                     data_writer_handle,
                     serialized_data,
+                    handle,
                     timestamp,
                 )
                 .await,
             ),
-            WriterServiceMail::GetOfferedDeadlineMissedStatus {
+            WriterServiceMail::GetLivelinessLostStatus {
                 publisher_handle,
                 data_writer_handle,
                 reply_sender,
             } => reply_sender.send(
-                self.get_offered_deadline_missed_status(publisher_handle, data_writer_handle)
+                self.get_liveliness_lost_status(publisher_handle, data_writer_handle)
                     .await,
             ),
-            WriterServiceMail::EnableDataWriter {
+            WriterServiceMail::GetPublicationThrottledStatus {
                 publisher_handle,
                 data_writer_handle,
-                participant_address,
                 reply_sender,
             } => reply_sender.send(
-                self.enable_data_writer(publisher_handle, data_writer_handle, participant_address)
+                self.get_publication_throttled_status(publisher_handle, data_writer_handle)
                     .await,
             ),
-            WriterServiceMail::SetDataWriterQos {
+            WriterServiceMail::GetOfferedDeadlineMissedStatus {
                 publisher_handle,
                 data_writer_handle,
-                qos,
                 reply_sender,
             } => reply_sender.send(
-                self.set_data_writer_qos(publisher_handle, data_writer_handle, qos)
+                self.get_offered_deadline_missed_status(publisher_handle, data_writer_handle)
                     .await,
             ),
-        }
+            WriterServiceMail::AssertLiveliness {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.assert_data_writer_liveliness(publisher_handle, data_writer_handle)
+                    .await,
+            ),
+            WriterServiceMail::SetKeepLastReplacementPolicy {
+                publisher_handle,
+                data_writer_handle,
+                policy,
+                reply_sender,
+            } => reply_sender.send(self.set_data_writer_keep_last_replacement_policy(
+                publisher_handle,
+                data_writer_handle,
+                policy,
+            )),
+            WriterServiceMail::SetDuplicateSuppressionMode {
+                publisher_handle,
+                data_writer_handle,
+                mode,
+                reply_sender,
+            } => reply_sender.send(self.set_data_writer_duplicate_suppression_mode(
+                publisher_handle,
+                data_writer_handle,
+                mode,
+            )),
+            WriterServiceMail::SetWriteCoalescingWindow {
+                publisher_handle,
+                data_writer_handle,
+                window,
+                reply_sender,
+            } => reply_sender.send(self.set_data_writer_write_coalescing_window(
+                publisher_handle,
+                data_writer_handle,
+                window,
+            )),
+            WriterServiceMail::SetPublicationThrottling {
+                publisher_handle,
+                data_writer_handle,
+                publication_throttling,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(self.set_data_writer_publication_throttling(
+                publisher_handle,
+                data_writer_handle,
+                publication_throttling,
+                participant_address,
+            )),
+            WriterServiceMail::SetChangeLifecycleObserver {
+                publisher_handle,
+                data_writer_handle,
+                change_lifecycle_observer,
+                reply_sender,
+            } => reply_sender.send(self.set_data_writer_change_lifecycle_observer(
+                publisher_handle,
+                data_writer_handle,
+                change_lifecycle_observer,
+            )),
+            WriterServiceMail::GetReplacedUnacknowledgedSampleCount {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_replaced_unacknowledged_sample_count(publisher_handle, data_writer_handle),
+            ),
+            WriterServiceMail::GetOfferedIncompatibleQosStatus {
+                publisher_handle,
+                data_writer_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_offered_incompatible_qos_status(publisher_handle, data_writer_handle)
+                    .await,
+            ),
+            WriterServiceMail::EnableDataWriter {
+                publisher_handle,
+                data_writer_handle,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(
+                self.enable_data_writer(publisher_handle, data_writer_handle, participant_address)
+                    .await,
+            ),
+            WriterServiceMail::SetDataWriterQos {
+                publisher_handle,
+                data_writer_handle,
+                qos,
+                reply_sender,
+            } => reply_sender.send(
+                self.set_data_writer_qos(publisher_handle, data_writer_handle, qos)
+                    .await,
+            ),
+        }
     }
 
     async fn handle_subscriber_service(
@@ -958,6 +1694,13 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 self.delete_data_reader(subscriber_handle, datareader_handle)
                     .await,
             ),
+            SubscriberServiceMail::DeleteContainedEntities {
+                subscriber_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.delete_subscriber_contained_entities(subscriber_handle)
+                    .await,
+            ),
             SubscriberServiceMail::LookupDataReader {
                 subscriber_handle,
                 topic_name,
@@ -976,7 +1719,7 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 subscriber_handle,
                 qos,
                 reply_sender,
-            } => reply_sender.send(self.set_subscriber_qos(subscriber_handle, qos)),
+            } => reply_sender.send(self.set_subscriber_qos(subscriber_handle, qos).await),
             SubscriberServiceMail::GetSubscriberQos {
                 subscriber_handle,
                 reply_sender,
@@ -991,6 +1734,14 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 listener_sender,
                 mask,
             )),
+            SubscriberServiceMail::Enable {
+                subscriber_handle,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(
+                self.enable_subscriber(subscriber_handle, participant_address)
+                    .await,
+            ),
         }
     }
 
@@ -1018,6 +1769,7 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 .await,
             ),
             ReaderServiceMail::Take {
+                participant_address,
                 subscriber_handle,
                 data_reader_handle,
                 max_samples,
@@ -1028,6 +1780,51 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 reply_sender,
             } => reply_sender.send(
                 self.take(
+                    participant_address,
+                    subscriber_handle,
+                    data_reader_handle,
+                    max_samples,
+                    sample_states,
+                    view_states,
+                    instance_states,
+                    specific_instance_handle,
+                )
+                .await,
+            ),
+            ReaderServiceMail::ReadLoaned {
+                subscriber_handle,
+                data_reader_handle,
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.read_loaned(
+                    subscriber_handle,
+                    data_reader_handle,
+                    max_samples,
+                    sample_states,
+                    view_states,
+                    instance_states,
+                    specific_instance_handle,
+                )
+                .await,
+            ),
+            ReaderServiceMail::TakeLoaned {
+                participant_address,
+                subscriber_handle,
+                data_reader_handle,
+                max_samples,
+                sample_states,
+                view_states,
+                instance_states,
+                specific_instance_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.take_loaned(
+                    participant_address,
                     subscriber_handle,
                     data_reader_handle,
                     max_samples,
@@ -1060,6 +1857,7 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 .await,
             ),
             ReaderServiceMail::TakeNextInstance {
+                participant_address,
                 subscriber_handle,
                 data_reader_handle,
                 max_samples,
@@ -1070,6 +1868,7 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 reply_sender,
             } => reply_sender.send(
                 self.take_next_instance(
+                    participant_address,
                     subscriber_handle,
                     data_reader_handle,
                     max_samples,
@@ -1097,6 +1896,60 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 self.get_subscription_matched_status(subscriber_handle, data_reader_handle)
                     .await,
             ),
+            ReaderServiceMail::GetRequestedIncompatibleQosStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_requested_incompatible_qos_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::GetSampleLostStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_sample_lost_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::GetWriterRestartedStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_writer_restarted_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::GetRequestedDeadlineMissedStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_requested_deadline_missed_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::GetLivelinessChangedStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_liveliness_changed_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::GetSampleRejectedStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_sample_rejected_status(subscriber_handle, data_reader_handle)
+                    .await,
+            ),
+            ReaderServiceMail::RequestHistoricalData {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender
+                .send(self.request_historical_data(subscriber_handle, data_reader_handle)),
             ReaderServiceMail::WaitForHistoricalData {
                 participant_address,
                 subscriber_handle,
@@ -1109,6 +1962,16 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 data_reader_handle,
                 max_wait,
             )),
+            ReaderServiceMail::CheckCompatibility {
+                subscriber_handle,
+                data_reader_handle,
+                publication_builtin_topic_data,
+                reply_sender,
+            } => reply_sender.send(self.check_publication_compatibility(
+                subscriber_handle,
+                data_reader_handle,
+                publication_builtin_topic_data,
+            )),
             ReaderServiceMail::GetMatchedPublicationData {
                 subscriber_handle,
                 data_reader_handle,
@@ -1119,12 +1982,54 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 data_reader_handle,
                 publication_handle,
             )),
+            ReaderServiceMail::GetMatchedPublicationHighWatermark {
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_matched_publication_high_watermark(
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+            )),
+            ReaderServiceMail::GetMatchedPublicationLocators {
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                reply_sender,
+            } => reply_sender.send(self.get_matched_publication_locators(
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+            )),
             ReaderServiceMail::GetMatchedPublications {
                 subscriber_handle,
                 data_reader_handle,
                 reply_sender,
             } => reply_sender
                 .send(self.get_matched_publications(subscriber_handle, data_reader_handle)),
+            ReaderServiceMail::LookupInstance {
+                subscriber_handle,
+                data_reader_handle,
+                serialized_data,
+                reply_sender,
+            } => reply_sender.send(self.lookup_data_reader_instance(
+                subscriber_handle,
+                data_reader_handle,
+                serialized_data,
+            )),
+            ReaderServiceMail::GetKeyValue {
+                subscriber_handle,
+                data_reader_handle,
+                serialized_key_holder,
+                handle,
+                reply_sender,
+            } => reply_sender.send(self.get_data_reader_key_value(
+                subscriber_handle,
+                data_reader_handle,
+                serialized_key_holder,
+                handle,
+            )),
             ReaderServiceMail::GetQos {
                 subscriber_handle,
                 data_reader_handle,
@@ -1151,6 +2056,169 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 listener_sender,
                 listener_mask,
             )),
+            ReaderServiceMail::SetReadAheadSender {
+                subscriber_handle,
+                data_reader_handle,
+                read_ahead_sender,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_read_ahead_sender(
+                subscriber_handle,
+                data_reader_handle,
+                read_ahead_sender,
+            )),
+            ReaderServiceMail::SetDeserializationErrorPolicy {
+                subscriber_handle,
+                data_reader_handle,
+                policy,
+                deserialization_validator,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_deserialization_error_policy(
+                subscriber_handle,
+                data_reader_handle,
+                policy,
+                deserialization_validator,
+            )),
+            ReaderServiceMail::SetIncompleteCoherentSetTimeout {
+                subscriber_handle,
+                data_reader_handle,
+                timeout,
+                policy,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_incomplete_coherent_set_timeout(
+                subscriber_handle,
+                data_reader_handle,
+                timeout,
+                policy,
+            )),
+            ReaderServiceMail::SetGapFreeDelivery {
+                subscriber_handle,
+                data_reader_handle,
+                enabled,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_gap_free_delivery(
+                subscriber_handle,
+                data_reader_handle,
+                enabled,
+            )),
+            ReaderServiceMail::SetContentFilter {
+                subscriber_handle,
+                data_reader_handle,
+                filter_expression,
+                filter_parameters,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_content_filter(
+                subscriber_handle,
+                data_reader_handle,
+                filter_expression,
+                filter_parameters,
+            )),
+            ReaderServiceMail::GetHeadOfLineStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_data_reader_head_of_line_status(subscriber_handle, data_reader_handle),
+            ),
+            ReaderServiceMail::GetInstanceStatistics {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => reply_sender.send(
+                self.get_data_reader_instance_statistics(subscriber_handle, data_reader_handle),
+            ),
+            ReaderServiceMail::SetInstanceFreshnessWatchdog {
+                subscriber_handle,
+                data_reader_handle,
+                period,
+                observer,
+                participant_address,
+                reply_sender,
+            } => reply_sender.send(self.set_data_reader_instance_freshness_watchdog(
+                subscriber_handle,
+                data_reader_handle,
+                period,
+                observer,
+                participant_address,
+            )),
+            ReaderServiceMail::GetInstanceFreshnessStatus {
+                subscriber_handle,
+                data_reader_handle,
+                reply_sender,
+            } => {
+                reply_sender.send(self.get_data_reader_instance_freshness_status(
+                    subscriber_handle,
+                    data_reader_handle,
+                ))
+            }
+            ReaderServiceMail::CreateReadCondition {
+                subscriber_handle,
+                data_reader_handle,
+                sample_states,
+                view_states,
+                instance_states,
+                query,
+                reply_sender,
+            } => reply_sender.send(self.create_read_condition(
+                subscriber_handle,
+                data_reader_handle,
+                sample_states,
+                view_states,
+                instance_states,
+                query,
+            )),
+            ReaderServiceMail::DeleteReadCondition {
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+                reply_sender,
+            } => reply_sender.send(self.delete_read_condition(
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+            )),
+            ReaderServiceMail::GetReadConditionTriggerValue {
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+                reply_sender,
+            } => reply_sender.send(self.get_read_condition_trigger_value(
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+            )),
+            ReaderServiceMail::GetQueryParameters {
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+                reply_sender,
+            } => reply_sender.send(self.get_query_parameters(
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+            )),
+            ReaderServiceMail::SetQueryParameters {
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+                query_parameters,
+                reply_sender,
+            } => reply_sender.send(self.set_query_parameters(
+                subscriber_handle,
+                data_reader_handle,
+                condition_id,
+                query_parameters,
+            )),
+            ReaderServiceMail::WaitForSamples {
+                subscriber_handle,
+                data_reader_handle,
+                added_change_count,
+                reply_sender,
+            } => self.wait_for_data_reader_samples(
+                subscriber_handle,
+                data_reader_handle,
+                added_change_count,
+                reply_sender,
+            ),
         }
     }
 
@@ -1170,6 +2238,22 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 )
                 .await
             }
+            MessageServiceMail::AddLostChanges {
+                participant_address,
+                writer_guid,
+                lost_change_count,
+                subscriber_handle,
+                data_reader_handle,
+            } => {
+                self.add_lost_changes(
+                    participant_address,
+                    writer_guid,
+                    lost_change_count,
+                    subscriber_handle,
+                    data_reader_handle,
+                )
+                .await
+            }
             MessageServiceMail::RemoveWriterChange {
                 publisher_handle,
                 data_writer_handle,
@@ -1178,6 +2262,17 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 self.remove_writer_change(publisher_handle, data_writer_handle, sequence_number)
                     .await
             }
+            MessageServiceMail::AddReadAheadValue {
+                subscriber_handle,
+                data_reader_handle,
+                serialized_data,
+                read_ahead_value,
+            } => self.add_read_ahead_value(
+                subscriber_handle,
+                data_reader_handle,
+                serialized_data,
+                read_ahead_value,
+            ),
             MessageServiceMail::AreAllChangesAcknowledged {
                 publisher_handle,
                 data_writer_handle,
@@ -1190,9 +2285,15 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 reply_sender,
             } => reply_sender
                 .send(self.is_historical_data_received(subscriber_handle, data_reader_handle)),
-            MessageServiceMail::AddBuiltinParticipantsDetectorCacheChange { cache_change } => {
-                self.add_builtin_participants_detector_cache_change(cache_change)
-                    .await
+            MessageServiceMail::AddBuiltinParticipantsDetectorCacheChange {
+                cache_change,
+                participant_address,
+            } => {
+                self.add_builtin_participants_detector_cache_change(
+                    cache_change,
+                    participant_address,
+                )
+                .await
             }
             MessageServiceMail::AddBuiltinPublicationsDetectorCacheChange {
                 cache_change,
@@ -1214,8 +2315,18 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 )
                 .await
             }
-            MessageServiceMail::AddBuiltinTopicsDetectorCacheChange { cache_change } => {
-                self.add_builtin_topics_detector_cache_change(cache_change)
+            MessageServiceMail::AddBuiltinTopicsDetectorCacheChange {
+                cache_change,
+                participant_address,
+            } => {
+                self.add_builtin_topics_detector_cache_change(cache_change, participant_address)
+                    .await
+            }
+            MessageServiceMail::AddBuiltinParticipantMessageCacheChange {
+                cache_change,
+                participant_address,
+            } => {
+                self.add_builtin_participant_message_cache_change(cache_change, participant_address)
                     .await
             }
         }
@@ -1251,6 +2362,97 @@ impl<R: DdsRuntime> DomainParticipantActor<R> {
                 )
                 .await
             }
+            EventServiceMail::CheckDataWriterLiveliness {
+                publisher_handle,
+                data_writer_handle,
+                participant_address,
+            } => {
+                self.check_data_writer_liveliness(
+                    publisher_handle,
+                    data_writer_handle,
+                    participant_address,
+                )
+                .await
+            }
+            EventServiceMail::CheckPublicationThrottling {
+                publisher_handle,
+                data_writer_handle,
+                participant_address,
+            } => {
+                self.check_publication_throttling(
+                    publisher_handle,
+                    data_writer_handle,
+                    participant_address,
+                )
+                .await
+            }
+            EventServiceMail::AssertAutomaticLiveliness {
+                assertion_id,
+                participant_address,
+            } => {
+                self.assert_automatic_liveliness(assertion_id, participant_address)
+                    .await
+            }
+            EventServiceMail::CheckDataReaderLiveliness {
+                subscriber_handle,
+                data_reader_handle,
+                publication_handle,
+                participant_address,
+            } => {
+                self.check_data_reader_liveliness(
+                    subscriber_handle,
+                    data_reader_handle,
+                    publication_handle,
+                    participant_address,
+                )
+                .await
+            }
+            EventServiceMail::IncompleteCoherentSetTimeout {
+                subscriber_handle,
+                data_reader_handle,
+                writer_guid,
+                coherent_set,
+                participant_address,
+            } => {
+                self.incomplete_coherent_set_timeout(
+                    subscriber_handle,
+                    data_reader_handle,
+                    writer_guid,
+                    coherent_set,
+                    participant_address,
+                )
+                .await
+            }
+            EventServiceMail::CheckInstanceFreshness {
+                subscriber_handle,
+                data_reader_handle,
+                change_instance_handle,
+            } => self.check_instance_freshness(
+                subscriber_handle,
+                data_reader_handle,
+                change_instance_handle,
+            ),
+            EventServiceMail::FlushDataWriterBatch {
+                publisher_handle,
+                data_writer_handle,
+                batch_id,
+            } => self.flush_data_writer_batch(publisher_handle, data_writer_handle, batch_id),
+            EventServiceMail::PublishCoalescedWrite {
+                participant_address,
+                publisher_handle,
+                data_writer_handle,
+                instance_handle,
+                window_id,
+            } => {
+                self.publish_coalesced_write(
+                    participant_address,
+                    publisher_handle,
+                    data_writer_handle,
+                    instance_handle,
+                    window_id,
+                )
+                .await
+            }
         }
     }
 
@@ -11,21 +11,24 @@ use crate::{
             discovered_reader_data::DiscoveredReaderData,
             discovered_topic_data::DiscoveredTopicData,
             discovered_writer_data::DiscoveredWriterData,
+            participant_message_data::{ParticipantMessageData, DCPS_PARTICIPANT_MESSAGE},
             spdp_discovered_participant_data::SpdpDiscoveredParticipantData,
         },
         data_writer::{DataWriterEntity, TransportWriterKind},
         domain_participant::DomainParticipantEntity,
         domain_participant_actor::DomainParticipantActor,
         domain_participant_actor_mail::{
-            DiscoveryServiceMail, DomainParticipantMail, MessageServiceMail, ParticipantServiceMail,
+            DomainParticipantMail, MessageServiceMail, ParticipantServiceMail,
         },
         handle::InstanceHandleCounter,
         listeners::domain_participant_listener::ListenerMail,
         publisher::PublisherEntity,
         status_condition_actor::StatusConditionActor,
         subscriber::SubscriberEntity,
+        task_monitor::{participant_announcement_task, TaskMonitor, TransportTaskMonitor},
         topic::TopicEntity,
     },
+    domain::task_health::ParticipantTaskKind,
     infrastructure::{
         domain::DomainId,
         error::{DdsError, DdsResult},
@@ -35,21 +38,22 @@ use crate::{
             PublisherQos, QosKind, SubscriberQos, TopicQos,
         },
         qos_policy::{
-            DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind,
-            ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            DurabilityQosPolicy, DurabilityQosPolicyKind, GuidPrefixQosPolicyKind,
+            HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
         },
         status::StatusKind,
-        time::{Duration, DurationKind},
+        time::{Duration, DurationKind, Time},
         type_support::TypeSupport,
     },
-    runtime::{ChannelReceive, ChannelSend, DdsRuntime, OneshotSend, Spawner, Timer},
+    publication::data_writer::KeepLastReplacementPolicy,
+    runtime::{ChannelReceive, ChannelSend, Clock, DdsRuntime, OneshotSend, Spawner},
     transport::{
         factory::TransportParticipantFactory,
         history_cache::{CacheChange, HistoryCache},
         participant::TransportParticipant,
         reader::{TransportStatefulReader, TransportStatelessReader},
         types::{
-            EntityId, GuidPrefix, ReliabilityKind, BUILT_IN_READER_WITH_KEY,
+            EntityId, GuidPrefix, Locator, ReliabilityKind, BUILT_IN_READER_WITH_KEY,
             BUILT_IN_WRITER_WITH_KEY,
         },
         writer::{TransportStatefulWriter, TransportStatelessWriter},
@@ -99,13 +103,19 @@ pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER: EntityId =
 pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR: EntityId =
     EntityId::new([0, 0, 0x04], BUILT_IN_READER_WITH_KEY);
 
+pub const ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER: EntityId =
+    EntityId::new([0, 0x02, 0], BUILT_IN_WRITER_WITH_KEY);
+
+pub const ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER: EntityId =
+    EntityId::new([0, 0x02, 0], BUILT_IN_READER_WITH_KEY);
+
 pub struct DomainParticipantFactoryActor<R: DdsRuntime> {
     domain_participant_list: Vec<(InstanceHandle, R::ChannelSender<DomainParticipantMail<R>>)>,
     qos: DomainParticipantFactoryQos,
     default_participant_qos: DomainParticipantQos,
     configuration: DustDdsConfiguration,
     transport: DdsTransportParticipantFactory,
-    entity_counter: u32,
+    next_instance_id: Option<u32>,
     app_id: [u8; 4],
     host_id: [u8; 4],
 }
@@ -122,35 +132,48 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             default_participant_qos: Default::default(),
             configuration: Default::default(),
             transport,
-            entity_counter: 0,
+            next_instance_id: None,
             app_id,
             host_id,
         }
     }
 
-    fn get_unique_participant_id(&mut self) -> u32 {
-        let id = self.entity_counter;
-        self.entity_counter += 1;
+    /// The instance identifiers start at a value taken from the clock when the first participant is created so that
+    /// they differ from the ones of a previous run of the application which got the same process id.
+    fn get_unique_participant_id(&mut self, now: Time) -> u32 {
+        let id = *self.next_instance_id.get_or_insert(now.nanosec());
+        self.next_instance_id = Some(id.wrapping_add(1));
         id
     }
 
-    fn create_new_guid_prefix(&mut self) -> GuidPrefix {
-        let instance_id = self.get_unique_participant_id().to_ne_bytes();
-
-        [
-            self.host_id[0],
-            self.host_id[1],
-            self.host_id[2],
-            self.host_id[3], // Host ID
-            self.app_id[0],
-            self.app_id[1],
-            self.app_id[2],
-            self.app_id[3], // App ID
-            instance_id[0],
-            instance_id[1],
-            instance_id[2],
-            instance_id[3], // Instance ID
-        ]
+    fn is_guid_prefix_in_use(&self, guid_prefix: &GuidPrefix) -> bool {
+        self.domain_participant_list
+            .iter()
+            .any(|(handle, _)| &handle.as_ref()[..12] == guid_prefix)
+    }
+
+    fn create_new_guid_prefix(&mut self, now: Time) -> GuidPrefix {
+        loop {
+            let instance_id = self.get_unique_participant_id(now).to_ne_bytes();
+            let guid_prefix = [
+                self.host_id[0],
+                self.host_id[1],
+                self.host_id[2],
+                self.host_id[3], // Host ID
+                self.app_id[0],
+                self.app_id[1],
+                self.app_id[2],
+                self.app_id[3], // App ID
+                instance_id[0],
+                instance_id[1],
+                instance_id[2],
+                instance_id[3], // Instance ID
+            ];
+            // The prefix can only be in use if the instance identifiers wrapped around or if it was chosen manually
+            if !self.is_guid_prefix_in_use(&guid_prefix) {
+                return guid_prefix;
+            }
+        }
     }
 
     #[allow(clippy::type_complexity, clippy::too_many_arguments)]
@@ -161,22 +184,45 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
         status_kind: Vec<StatusKind>,
         clock_handle: R::ClockHandle,
-        mut timer_handle: R::TimerHandle,
+        timer_handle: R::TimerHandle,
         spawner_handle: R::SpawnerHandle,
     ) -> DdsResult<(
         R::ChannelSender<DomainParticipantMail<R>>,
         InstanceHandle,
         ActorAddress<R, StatusConditionActor<R>>,
+        Arc<TaskMonitor>,
     )> {
         let domain_participant_qos = match qos {
             QosKind::Default => self.default_participant_qos.clone(),
             QosKind::Specific(q) => q,
         };
 
-        let guid_prefix = self.create_new_guid_prefix();
+        let guid_prefix = match domain_participant_qos.guid_prefix.kind {
+            GuidPrefixQosPolicyKind::Automatic => self.create_new_guid_prefix(clock_handle.now()),
+            GuidPrefixQosPolicyKind::Manual(guid_prefix) => {
+                if self.is_guid_prefix_in_use(&guid_prefix) {
+                    return Err(DdsError::PreconditionNotMet(String::from(
+                        "GUID prefix already in use by another participant",
+                    )));
+                }
+                guid_prefix
+            }
+        };
         let (participant_sender, mut participant_receiver) = R::channel();
 
         let mut transport = self.transport.create_participant(guid_prefix, domain_id);
+        let task_monitor = Arc::new(TaskMonitor::new(
+            self.configuration.task_stall_threshold(),
+            self.configuration.participant_announcement_interval(),
+            self.configuration.restart_stalled_tasks(),
+            clock_handle.now(),
+        ));
+        if task_monitor.is_enabled() {
+            transport.set_task_observer(Some(Arc::new(TransportTaskMonitor::new(
+                task_monitor.clone(),
+                clock_handle.clone(),
+            ))));
+        }
 
         let mut instance_handle_counter = InstanceHandleCounter::default();
         fn sedp_data_reader_qos() -> DataReaderQos {
@@ -272,6 +318,21 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
         sedp_topic_subscriptions.enable();
         topic_list.push(sedp_topic_subscriptions);
 
+        let participant_message_topic_handle =
+            instance_handle_counter.generate_new_instance_handle();
+        let mut participant_message_topic = TopicEntity::new(
+            TopicQos::default(),
+            "ParticipantMessageData".to_string(),
+            String::from(DCPS_PARTICIPANT_MESSAGE),
+            participant_message_topic_handle,
+            Actor::spawn(StatusConditionActor::default(), &spawner_handle),
+            None,
+            vec![],
+            Arc::new(ParticipantMessageData::get_type()),
+        );
+        participant_message_topic.enable();
+        topic_list.push(participant_message_topic);
+
         let spdp_writer_qos = DataWriterQos {
             durability: DurabilityQosPolicy {
                 kind: DurabilityQosPolicyKind::TransientLocal,
@@ -374,12 +435,32 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             TransportReaderKind::Stateful(dcps_subscription_transport_reader),
         );
         dcps_subscription_reader.enable();
+        let participant_message_transport_reader = transport.create_stateful_reader(
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_READER,
+            ReliabilityKind::Reliable,
+            Box::new(DcpsParticipantMessageReaderHistoryCache::<R> {
+                participant_address: participant_sender.clone(),
+            }),
+        );
+        let mut participant_message_reader = DataReaderEntity::new(
+            instance_handle_counter.generate_new_instance_handle(),
+            sedp_data_reader_qos(),
+            String::from(DCPS_PARTICIPANT_MESSAGE),
+            "ParticipantMessageData".to_string(),
+            Arc::new(ParticipantMessageData::get_type()),
+            Actor::spawn(StatusConditionActor::default(), &spawner_handle),
+            None,
+            Vec::new(),
+            TransportReaderKind::Stateful(participant_message_transport_reader),
+        );
+        participant_message_reader.enable();
 
         let data_reader_list = vec![
             dcps_participant_reader,
             dcps_topic_reader,
             dcps_publication_reader,
             dcps_subscription_reader,
+            participant_message_reader,
         ];
         let mut builtin_subscriber = SubscriberEntity::new(
             instance_handle_counter.generate_new_instance_handle(),
@@ -395,6 +476,16 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             transport.create_stateless_writer(ENTITYID_SPDP_BUILTIN_PARTICIPANT_WRITER);
         for &discovery_locator in transport.metatraffic_multicast_locator_list() {
             dcps_participant_transport_writer.add_reader_locator(discovery_locator);
+            // The initial peers receive the announcements on the same port as the multicast discovery locator
+            for initial_peer in self.configuration.initial_peers() {
+                let mut address = [0; 16];
+                address[12..].copy_from_slice(&initial_peer.octets());
+                dcps_participant_transport_writer.add_reader_locator(Locator::new(
+                    discovery_locator.kind(),
+                    discovery_locator.port(),
+                    address,
+                ));
+            }
         }
         let mut dcps_participant_writer = DataWriterEntity::new(
             instance_handle_counter.generate_new_instance_handle(),
@@ -425,6 +516,10 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             sedp_data_writer_qos(),
         );
         dcps_topics_writer.enable();
+        // Only the latest announcement of each entity is relevant so it must never wait for the
+        // previous announcement to be acknowledged by all the remote participants
+        dcps_topics_writer
+            .set_keep_last_replacement_policy(KeepLastReplacementPolicy::OverwriteOldest);
         let dcps_publications_transport_writer = transport.create_stateful_writer(
             ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
             ReliabilityKind::Reliable,
@@ -441,6 +536,8 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             sedp_data_writer_qos(),
         );
         dcps_publications_writer.enable();
+        dcps_publications_writer
+            .set_keep_last_replacement_policy(KeepLastReplacementPolicy::OverwriteOldest);
 
         let dcps_subscriptions_transport_writer = transport.create_stateful_writer(
             ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
@@ -458,11 +555,34 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             sedp_data_writer_qos(),
         );
         dcps_subscriptions_writer.enable();
+        dcps_subscriptions_writer
+            .set_keep_last_replacement_policy(KeepLastReplacementPolicy::OverwriteOldest);
+
+        let participant_message_transport_writer = transport.create_stateful_writer(
+            ENTITYID_P2P_BUILTIN_PARTICIPANT_MESSAGE_WRITER,
+            ReliabilityKind::Reliable,
+        );
+        let mut participant_message_writer = DataWriterEntity::new(
+            instance_handle_counter.generate_new_instance_handle(),
+            TransportWriterKind::Stateful(participant_message_transport_writer),
+            String::from(DCPS_PARTICIPANT_MESSAGE),
+            "ParticipantMessageData".to_string(),
+            Arc::new(ParticipantMessageData::get_type()),
+            Actor::spawn(StatusConditionActor::default(), &spawner_handle),
+            None,
+            vec![],
+            sedp_data_writer_qos(),
+        );
+        participant_message_writer.enable();
+        participant_message_writer
+            .set_keep_last_replacement_policy(KeepLastReplacementPolicy::OverwriteOldest);
+
         let builtin_data_writer_list = vec![
             dcps_participant_writer,
             dcps_topics_writer,
             dcps_publications_writer,
             dcps_subscriptions_writer,
+            participant_message_writer,
         ];
         let mut builtin_publisher = PublisherEntity::new(
             PublisherQos::default(),
@@ -484,13 +604,20 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             builtin_subscriber,
             topic_list,
             String::from(self.configuration.domain_tag()),
+            self.configuration.participant_max_samples(),
+            self.configuration.discovery_history_retention().into(),
+            self.configuration.participant_lease_duration().into(),
         );
 
         let mut domain_participant_actor = DomainParticipantActor::new(
             domain_participant,
             transport,
             instance_handle_counter,
-            clock_handle,
+            self.configuration.entity_key_allocation().clone(),
+            self.configuration.encapsulation_padding_encoding(),
+            self.configuration.strict_encapsulation_options(),
+            task_monitor.clone(),
+            clock_handle.clone(),
             timer_handle.clone(),
             spawner_handle.clone(),
         );
@@ -504,36 +631,43 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             .status_condition()
             .address();
 
+        // Start the watchdog supervising the other tasks
+        domain_participant_actor.start_task_watchdog(participant_sender.clone());
+
+        let actor_task_monitor = task_monitor.clone();
+        let actor_clock_handle = clock_handle.clone();
         spawner_handle.spawn(async move {
             while let Some(m) = participant_receiver.receive().await {
+                if actor_task_monitor.is_enabled() {
+                    actor_task_monitor.begin_processing(
+                        ParticipantTaskKind::MessageProcessing,
+                        actor_clock_handle.now(),
+                    );
+                }
                 domain_participant_actor.handle(m).await;
+                actor_task_monitor.end_processing(ParticipantTaskKind::MessageProcessing);
             }
         });
 
         //****** Spawn the participant actor and tasks **********//
 
         // Start the regular participant announcement task
-        let participant_address = participant_sender.clone();
-        let participant_announcement_interval =
-            self.configuration.participant_announcement_interval();
-
-        spawner_handle.spawn(async move {
-            while participant_address
-                .send(DomainParticipantMail::Discovery(
-                    DiscoveryServiceMail::AnnounceParticipant,
-                ))
-                .await
-                .is_ok()
-            {
-                timer_handle.delay(participant_announcement_interval).await;
-            }
-        });
+        spawner_handle.spawn(participant_announcement_task::<R>(
+            participant_sender.clone(),
+            timer_handle,
+            clock_handle,
+            task_monitor.clone(),
+            task_monitor.announcement_generation(),
+        ));
 
         if self.qos.entity_factory.autoenable_created_entities {
             let (reply_sender, _reply_receiver) = R::oneshot();
             participant_sender
                 .send(DomainParticipantMail::Participant(
-                    ParticipantServiceMail::Enable { reply_sender },
+                    ParticipantServiceMail::Enable {
+                        participant_address: participant_sender.clone(),
+                        reply_sender,
+                    },
                 ))
                 .await
                 .ok();
@@ -547,6 +681,7 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
             participant_address,
             participant_handle,
             builtin_subscriber_status_condition_address,
+            task_monitor,
         ))
     }
 
@@ -610,6 +745,27 @@ impl<R: DdsRuntime> DomainParticipantFactoryActor<R> {
     pub fn set_transport(&mut self, transport: DdsTransportParticipantFactory) {
         self.transport = transport;
     }
+
+    #[cfg(feature = "std")]
+    pub fn apply_configuration_file(
+        &mut self,
+        configuration_file: &crate::configuration_file::ConfigurationFile,
+    ) -> DdsResult<()> {
+        use crate::rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder;
+
+        let configuration = configuration_file.update_configuration(self.configuration.clone())?;
+        if let Some(interface_name) = configuration_file.interface_name() {
+            let transport = RtpsUdpTransportParticipantFactoryBuilder::new()
+                .interface_name(Some(String::from(interface_name)))
+                .build()
+                .map_err(DdsError::Error)?;
+            self.transport = Box::new(transport);
+        }
+        self.configuration = configuration;
+        self.default_participant_qos =
+            configuration_file.update_participant_qos(self.default_participant_qos.clone());
+        Ok(())
+    }
 }
 
 pub enum DomainParticipantFactoryMail<R: DdsRuntime> {
@@ -627,6 +783,7 @@ pub enum DomainParticipantFactoryMail<R: DdsRuntime> {
                 R::ChannelSender<DomainParticipantMail<R>>,
                 InstanceHandle,
                 ActorAddress<R, StatusConditionActor<R>>,
+                Arc<TaskMonitor>,
             )>,
         >,
     },
@@ -657,6 +814,11 @@ pub enum DomainParticipantFactoryMail<R: DdsRuntime> {
     SetTransport {
         transport: DdsTransportParticipantFactory,
     },
+    #[cfg(feature = "std")]
+    ApplyConfigurationFile {
+        configuration_file: crate::configuration_file::ConfigurationFile,
+        reply_sender: R::OneshotSender<DdsResult<()>>,
+    },
 }
 
 impl<R: DdsRuntime> MailHandler for DomainParticipantFactoryActor<R> {
@@ -710,6 +872,11 @@ impl<R: DdsRuntime> MailHandler for DomainParticipantFactoryActor<R> {
             DomainParticipantFactoryMail::SetTransport { transport } => {
                 self.set_transport(transport)
             }
+            #[cfg(feature = "std")]
+            DomainParticipantFactoryMail::ApplyConfigurationFile {
+                configuration_file,
+                reply_sender,
+            } => reply_sender.send(self.apply_configuration_file(&configuration_file)),
         }
     }
 }
@@ -726,7 +893,10 @@ impl<R: DdsRuntime> HistoryCache for DcpsParticipantReaderHistoryCache<R> {
         let a = self.participant_address.clone();
         Box::pin(async move {
             a.send(DomainParticipantMail::Message(
-                MessageServiceMail::AddBuiltinParticipantsDetectorCacheChange { cache_change },
+                MessageServiceMail::AddBuiltinParticipantsDetectorCacheChange {
+                    cache_change,
+                    participant_address: a.clone(),
+                },
             ))
             .await
             .ok();
@@ -750,7 +920,10 @@ impl<R: DdsRuntime> HistoryCache for DcpsTopicsReaderHistoryCache<R> {
         let a = self.participant_address.clone();
         Box::pin(async move {
             a.send(DomainParticipantMail::Message(
-                MessageServiceMail::AddBuiltinTopicsDetectorCacheChange { cache_change },
+                MessageServiceMail::AddBuiltinTopicsDetectorCacheChange {
+                    cache_change,
+                    participant_address: a.clone(),
+                },
             ))
             .await
             .ok();
@@ -815,3 +988,30 @@ impl<R: DdsRuntime> HistoryCache for DcpsPublicationsReaderHistoryCache<R> {
         todo!()
     }
 }
+
+struct DcpsParticipantMessageReaderHistoryCache<R: DdsRuntime> {
+    pub participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+}
+
+impl<R: DdsRuntime> HistoryCache for DcpsParticipantMessageReaderHistoryCache<R> {
+    fn add_change(
+        &mut self,
+        cache_change: CacheChange,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        let a = self.participant_address.clone();
+        Box::pin(async move {
+            a.send(DomainParticipantMail::Message(
+                MessageServiceMail::AddBuiltinParticipantMessageCacheChange {
+                    cache_change,
+                    participant_address: a.clone(),
+                },
+            ))
+            .await
+            .ok();
+        })
+    }
+
+    fn remove_change(&mut self, _sequence_number: i64) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        todo!()
+    }
+}
@@ -5,13 +5,16 @@ use crate::{
 
 use super::{
     qos_policy::{
-        DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
-        DurabilityQosPolicy, EntityFactoryQosPolicy, GroupDataQosPolicy, HistoryQosPolicy,
-        HistoryQosPolicyKind, LatencyBudgetQosPolicy, LifespanQosPolicy, LivelinessQosPolicy,
-        OwnershipQosPolicy, OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
-        ReaderDataLifecycleQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
-        ResourceLimitsQosPolicy, TimeBasedFilterQosPolicy, TopicDataQosPolicy,
-        TransportPriorityQosPolicy, UserDataQosPolicy, WriterDataLifecycleQosPolicy,
+        BatchQosPolicy, DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
+        DisablePositiveAcksQosPolicy, DurabilityQosPolicy, EndpointBehaviorQosPolicy,
+        EndpointBehaviorQosPolicyKind, EntityFactoryQosPolicy, FlowControllerQosPolicy,
+        GroupDataQosPolicy, GuidPrefixQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind,
+        LatencyBudgetQosPolicy, Length, LifespanQosPolicy, LivelinessQosPolicy, OwnershipQosPolicy,
+        OwnershipStrengthQosPolicy, PartitionQosPolicy, PresentationQosPolicy,
+        PublishModeQosPolicy, ReaderDataLifecycleQosPolicy, ReaderProtocolQosPolicy,
+        ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+        TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportPriorityQosPolicy,
+        UserDataQosPolicy, WriterDataLifecycleQosPolicy, WriterProtocolQosPolicy,
     },
     time::DurationKind,
 };
@@ -39,6 +42,18 @@ pub struct DomainParticipantQos {
     pub user_data: UserDataQosPolicy,
     /// Value of the entity factory QoS policy.
     pub entity_factory: EntityFactoryQosPolicy,
+    /// Value of the GUID prefix QoS policy.
+    pub guid_prefix: GuidPrefixQosPolicy,
+}
+
+impl DomainParticipantQos {
+    pub(crate) fn check_immutability(&self, other: &Self) -> DdsResult<()> {
+        if self.guid_prefix != other.guid_prefix {
+            Err(DdsError::ImmutablePolicy)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// QoS policies applicable to the [`Publisher`](crate::publication::publisher::Publisher)
@@ -104,6 +119,18 @@ pub struct DataWriterQos {
     pub writer_data_lifecycle: WriterDataLifecycleQosPolicy,
     /// Value of the data representation QoS policy.
     pub representation: DataRepresentationQosPolicy,
+    /// Value of the vendor-specific batch QoS policy.
+    pub batch: BatchQosPolicy,
+    /// Value of the vendor-specific writer protocol QoS policy.
+    pub writer_protocol: WriterProtocolQosPolicy,
+    /// Value of the vendor-specific flow controller QoS policy.
+    pub flow_controller: FlowControllerQosPolicy,
+    /// Value of the vendor-specific publish mode QoS policy.
+    pub publish_mode: PublishModeQosPolicy,
+    /// Value of the vendor-specific endpoint behavior QoS policy.
+    pub endpoint_behavior: EndpointBehaviorQosPolicy,
+    /// Value of the vendor-specific disable positive acks QoS policy.
+    pub disable_positive_acks: DisablePositiveAcksQosPolicy,
 }
 
 impl DataWriterQos {
@@ -130,6 +157,12 @@ impl DataWriterQos {
             transport_priority: TransportPriorityQosPolicy::const_default(),
             writer_data_lifecycle: WriterDataLifecycleQosPolicy::const_default(),
             representation: DataRepresentationQosPolicy::const_default(),
+            batch: BatchQosPolicy::const_default(),
+            writer_protocol: WriterProtocolQosPolicy::const_default(),
+            flow_controller: FlowControllerQosPolicy::const_default(),
+            publish_mode: PublishModeQosPolicy::const_default(),
+            endpoint_behavior: EndpointBehaviorQosPolicy::const_default(),
+            disable_positive_acks: DisablePositiveAcksQosPolicy::const_default(),
         }
     }
 }
@@ -153,6 +186,35 @@ impl DataWriterQos {
             return Err(DdsError::InconsistentPolicy);
         }
 
+        // A batch must be able to hold at least one sample
+        if self.batch.enable
+            && (self.batch.max_samples == Length::Limited(0)
+                || self.batch.max_data_bytes == Length::Limited(0))
+        {
+            return Err(DdsError::InconsistentPolicy);
+        }
+
+        // The heartbeats must be periodic and the protocol delays can not be negative
+        let zero = Duration::new(0, 0);
+        if self.writer_protocol.heartbeat_period <= zero
+            || self.writer_protocol.nack_response_delay < zero
+            || self.writer_protocol.nack_suppression_duration < zero
+            || self.writer_protocol.min_resend_interval < zero
+            || self.writer_protocol.samples_per_heartbeat == Length::Limited(0)
+        {
+            return Err(DdsError::InconsistentPolicy);
+        }
+        if self.disable_positive_acks.duration < zero {
+            return Err(DdsError::InconsistentPolicy);
+        }
+
+        // A stateless writer keeps no state of its readers to repair their missing samples
+        if self.endpoint_behavior.kind == EndpointBehaviorQosPolicyKind::Stateless
+            && self.reliability.kind != ReliabilityQosPolicyKind::BestEffort
+        {
+            return Err(DdsError::InconsistentPolicy);
+        }
+
         // The setting of RESOURCE_LIMITS max_samples_per_instance must be consistent with the HISTORY depth. For these two
         // QoS to be consistent, they must verify that *depth <= max_samples_per_instance.*
         match self.history.kind {
@@ -169,12 +231,19 @@ impl DataWriterQos {
 
     pub(crate) fn check_immutability(&self, other: &Self) -> DdsResult<()> {
         if self.durability != other.durability
+            || self.batch != other.batch
+            || self.writer_protocol != other.writer_protocol
+            || self.flow_controller != other.flow_controller
+            || self.publish_mode != other.publish_mode
+            || self.endpoint_behavior != other.endpoint_behavior
+            || self.disable_positive_acks != other.disable_positive_acks
             || self.liveliness != other.liveliness
             || self.reliability != other.reliability
             || self.destination_order != other.destination_order
             || self.history != other.history
             || self.resource_limits != other.resource_limits
             || self.ownership != other.ownership
+            || self.representation != other.representation
         {
             Err(DdsError::ImmutablePolicy)
         } else {
@@ -249,6 +318,10 @@ pub struct DataReaderQos {
     pub reader_data_lifecycle: ReaderDataLifecycleQosPolicy,
     /// Value of the data representation QoS policy.
     pub representation: DataRepresentationQosPolicy,
+    /// Value of the vendor-specific reader protocol QoS policy.
+    pub reader_protocol: ReaderProtocolQosPolicy,
+    /// Value of the vendor-specific endpoint behavior QoS policy.
+    pub endpoint_behavior: EndpointBehaviorQosPolicy,
 }
 
 impl DataReaderQos {
@@ -273,6 +346,8 @@ impl DataReaderQos {
             time_based_filter: TimeBasedFilterQosPolicy::const_default(),
             reader_data_lifecycle: ReaderDataLifecycleQosPolicy::const_default(),
             representation: DataRepresentationQosPolicy::const_default(),
+            reader_protocol: ReaderProtocolQosPolicy::const_default(),
+            endpoint_behavior: EndpointBehaviorQosPolicy::const_default(),
         }
     }
 }
@@ -308,6 +383,21 @@ impl DataReaderQos {
             return Err(DdsError::InconsistentPolicy);
         }
 
+        // The protocol delays can not be negative
+        let zero = Duration::new(0, 0);
+        if self.reader_protocol.heartbeat_response_delay < zero
+            || self.reader_protocol.heartbeat_suppression_duration < zero
+        {
+            return Err(DdsError::InconsistentPolicy);
+        }
+
+        // A stateless reader keeps no state of its writers to request their missing samples
+        if self.endpoint_behavior.kind == EndpointBehaviorQosPolicyKind::Stateless
+            && self.reliability.kind != ReliabilityQosPolicyKind::BestEffort
+        {
+            return Err(DdsError::InconsistentPolicy);
+        }
+
         Ok(())
     }
 
@@ -319,6 +409,9 @@ impl DataReaderQos {
             || self.history != other.history
             || self.resource_limits != other.resource_limits
             || self.ownership != other.ownership
+            || self.representation != other.representation
+            || self.reader_protocol != other.reader_protocol
+            || self.endpoint_behavior != other.endpoint_behavior
         {
             Err(DdsError::ImmutablePolicy)
         } else {
@@ -448,6 +541,27 @@ mod tests {
             .is_consistent(),
             Err(DdsError::InconsistentPolicy)
         );
+        assert_eq!(
+            DataWriterQos {
+                endpoint_behavior: EndpointBehaviorQosPolicy {
+                    kind: EndpointBehaviorQosPolicyKind::Stateless,
+                },
+                ..Default::default()
+            }
+            .is_consistent(),
+            Err(DdsError::InconsistentPolicy)
+        );
+        assert_eq!(
+            DataWriterQos {
+                disable_positive_acks: DisablePositiveAcksQosPolicy {
+                    enable: true,
+                    duration: Duration::new(-1, 0),
+                },
+                ..Default::default()
+            }
+            .is_consistent(),
+            Err(DdsError::InconsistentPolicy)
+        );
     }
 
     #[test]
@@ -479,6 +593,20 @@ mod tests {
             .is_consistent(),
             Err(DdsError::InconsistentPolicy)
         );
+        assert_eq!(
+            DataReaderQos {
+                reliability: ReliabilityQosPolicy {
+                    kind: ReliabilityQosPolicyKind::Reliable,
+                    max_blocking_time: DurationKind::Infinite,
+                },
+                endpoint_behavior: EndpointBehaviorQosPolicy {
+                    kind: EndpointBehaviorQosPolicyKind::Stateless,
+                },
+                ..Default::default()
+            }
+            .is_consistent(),
+            Err(DdsError::InconsistentPolicy)
+        );
     }
 
     #[test]
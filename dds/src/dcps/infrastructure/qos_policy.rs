@@ -1,6 +1,10 @@
 use super::time::{DURATION_ZERO_NSEC, DURATION_ZERO_SEC};
 use crate::{
-    infrastructure::time::{Duration, DurationKind},
+    infrastructure::{
+        error::{DdsError, DdsResult},
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsSerialize, DdsType},
+    },
     transport::types::{DurabilityKind, ReliabilityKind},
     xtypes::{
         bytes::{ByteBuf, Bytes},
@@ -27,6 +31,8 @@ pub enum Length {
 }
 
 const LENGTH_UNLIMITED: i32 = -1;
+#[cfg(feature = "serde")]
+const LENGTH_UNLIMITED_NAME: &str = "LENGTH_UNLIMITED";
 impl XTypesSerialize for Length {
     fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
         match self {
@@ -46,6 +52,112 @@ impl<'de> XTypesDeserialize<'de> for Length {
     }
 }
 
+impl Length {
+    /// Create a [`Length::Limited`] from a count. Counts which do not fit the limit saturate at [`u32::MAX`].
+    pub fn saturating_from_usize(count: usize) -> Self {
+        Length::Limited(u32::try_from(count).unwrap_or(u32::MAX))
+    }
+
+    /// Get the limit as a count. [`Length::Unlimited`] and limits which do not fit a `usize` saturate at [`usize::MAX`].
+    pub fn saturating_as_usize(&self) -> usize {
+        match self {
+            Length::Unlimited => usize::MAX,
+            Length::Limited(value) => usize::try_from(*value).unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Get the number of elements which can still be added to a collection holding `count` elements
+    /// before reaching the limit. The result is [`Length::Limited(0)`](Length::Limited) if the limit is already
+    /// reached or exceeded and [`Length::Unlimited`] if there is no limit.
+    pub fn saturating_remaining(&self, count: usize) -> Self {
+        match self {
+            Length::Unlimited => Length::Unlimited,
+            Length::Limited(_) => {
+                Length::saturating_from_usize(self.saturating_as_usize().saturating_sub(count))
+            }
+        }
+    }
+
+    /// Get the value of a [`Length::Limited`] or the `default` limit if the length is [`Length::Unlimited`].
+    pub fn limited_or(&self, default: u32) -> u32 {
+        match self {
+            Length::Unlimited => default,
+            Length::Limited(value) => *value,
+        }
+    }
+}
+
+impl From<u32> for Length {
+    fn from(value: u32) -> Self {
+        Length::Limited(value)
+    }
+}
+
+impl TryFrom<i32> for Length {
+    type Error = DdsError;
+
+    /// The value `-1` corresponds to [`Length::Unlimited`] as in the DDS standard. Other negative values
+    /// are rejected with [`DdsError::BadParameter`].
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            LENGTH_UNLIMITED => Ok(Length::Unlimited),
+            value @ 0..=i32::MAX => Ok(Length::Limited(value as u32)),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+}
+
+/// The unlimited length is represented by the string `"LENGTH_UNLIMITED"` as in the XML QoS profiles and
+/// limited lengths by their value. When deserializing, the value `-1` is also accepted for the unlimited length.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Length {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Length::Unlimited => serializer.serialize_str(LENGTH_UNLIMITED_NAME),
+            Length::Limited(value) => serializer.serialize_u32(*value),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Length {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LengthVisitor;
+
+        impl serde::de::Visitor<'_> for LengthVisitor {
+            type Value = Length;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a non-negative integer, -1 or \"LENGTH_UNLIMITED\"")
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                match v {
+                    -1 => Ok(Length::Unlimited),
+                    _ => u32::try_from(v)
+                        .map(Length::Limited)
+                        .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self)),
+                }
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                u32::try_from(v)
+                    .map(Length::Limited)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                match v {
+                    LENGTH_UNLIMITED_NAME => Ok(Length::Unlimited),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(LengthVisitor)
+    }
+}
+
 impl PartialOrd for Length {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self {
@@ -65,7 +177,7 @@ impl PartialEq<usize> for Length {
     fn eq(&self, other: &usize) -> bool {
         match self {
             Length::Unlimited => false,
-            Length::Limited(value) => (*value as usize).eq(other),
+            Length::Limited(_) => self.saturating_as_usize().eq(other),
         }
     }
 }
@@ -74,7 +186,7 @@ impl PartialOrd<usize> for Length {
     fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
         match self {
             Length::Unlimited => Some(Ordering::Greater),
-            Length::Limited(value) => (*value as usize).partial_cmp(other),
+            Length::Limited(_) => self.saturating_as_usize().partial_cmp(other),
         }
     }
 }
@@ -83,7 +195,7 @@ impl PartialEq<Length> for usize {
     fn eq(&self, other: &Length) -> bool {
         match other {
             Length::Unlimited => false,
-            Length::Limited(value) => self.eq(&(*value as usize)),
+            Length::Limited(_) => self.eq(&other.saturating_as_usize()),
         }
     }
 }
@@ -92,7 +204,7 @@ impl PartialOrd<Length> for usize {
     fn partial_cmp(&self, other: &Length) -> Option<Ordering> {
         match other {
             Length::Unlimited => Some(Ordering::Less),
-            Length::Limited(value) => self.partial_cmp(&(*value as usize)),
+            Length::Limited(_) => self.partial_cmp(&other.saturating_as_usize()),
         }
     }
 }
@@ -141,6 +253,14 @@ const TRANSPORTPRIORITY_QOS_POLICY_NAME: &str = "TransportPriority";
 const GROUPDATA_QOS_POLICY_NAME: &str = "GroupData";
 const LIFESPAN_QOS_POLICY_NAME: &str = "Lifespan";
 const DATA_REPRESENTATION_QOS_POLICY_NAME: &str = "DataRepresentation";
+const BATCH_QOS_POLICY_NAME: &str = "Batch";
+const WRITER_PROTOCOL_QOS_POLICY_NAME: &str = "WriterProtocol";
+const FLOW_CONTROLLER_QOS_POLICY_NAME: &str = "FlowController";
+const PUBLISH_MODE_QOS_POLICY_NAME: &str = "PublishMode";
+const ENDPOINT_BEHAVIOR_QOS_POLICY_NAME: &str = "EndpointBehavior";
+const DISABLE_POSITIVE_ACKS_QOS_POLICY_NAME: &str = "DisablePositiveAcks";
+const READER_PROTOCOL_QOS_POLICY_NAME: &str = "ReaderProtocol";
+const GUID_PREFIX_QOS_POLICY_NAME: &str = "GuidPrefix";
 
 /// QosPolicy Id representing an invalid QoS policy
 pub const INVALID_QOS_POLICY_ID: QosPolicyId = 0;
@@ -250,6 +370,56 @@ impl Default for TopicDataQosPolicy {
     }
 }
 
+impl TopicDataQosPolicy {
+    /// Create a topic data QoS policy whose value is the CDR representation of the given [`TopicMetadata`].
+    pub fn from_metadata(metadata: &TopicMetadata) -> DdsResult<Self> {
+        Ok(Self {
+            value: metadata.serialize_data()?,
+        })
+    }
+
+    /// Get the [`TopicMetadata`] encoded in the value of this policy using [`TopicDataQosPolicy::from_metadata`].
+    /// An error is returned if the value does not contain topic metadata.
+    pub fn metadata(&self) -> DdsResult<TopicMetadata> {
+        TopicMetadata::deserialize_data(&self.value)
+    }
+}
+
+/// Structured metadata describing the data published on a Topic. It can be transmitted to remote applications as part
+/// of the [`TopicDataQosPolicy`] such that all the applications use the same representation for it.
+#[derive(Debug, PartialEq, Clone, DdsType)]
+pub struct TopicMetadata {
+    /// Units in which the values of the data are expressed (e.g. "m/s"). Empty if not specified.
+    pub units: String,
+    /// Identifier of the coordinate frame in which the data is expressed. Empty if not specified.
+    pub frame_id: String,
+    /// Version of the schema of the data type.
+    pub schema_version: u32,
+    /// Lowest valid value of the data. Negative infinity if the values are not bounded from below.
+    pub valid_min: f64,
+    /// Highest valid value of the data. Positive infinity if the values are not bounded from above.
+    pub valid_max: f64,
+}
+
+impl Default for TopicMetadata {
+    fn default() -> Self {
+        Self {
+            units: String::new(),
+            frame_id: String::new(),
+            schema_version: 0,
+            valid_min: f64::NEG_INFINITY,
+            valid_max: f64::INFINITY,
+        }
+    }
+}
+
+impl TopicMetadata {
+    /// Whether the value is within the range of valid values of the data.
+    pub fn is_valid(&self, value: f64) -> bool {
+        self.valid_min <= value && value <= self.valid_max
+    }
+}
+
 impl XTypesSerialize for TopicDataQosPolicy {
     fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
         let mut s = serializer.serialize_final_struct()?;
@@ -1366,7 +1536,8 @@ impl From<&DurabilityQosPolicy> for DurabilityKind {
 
 /*******  DDS X-TYPES Extension **********/
 
-type DataRepresentationId = u16;
+/// Identifier of a data representation
+pub type DataRepresentationId = u16;
 /// XCDR data representation
 pub const XCDR_DATA_REPRESENTATION: DataRepresentationId = 0;
 /// XML data representation
@@ -1403,10 +1574,368 @@ impl Default for DataRepresentationQosPolicy {
     }
 }
 
+/// This policy is a vendor-specific extension which controls the grouping of the samples written by a
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) into batches.
+/// When enabled, the samples are held by the writer and sent together once the batch is flushed, trading latency for throughput.
+/// A batch is flushed when it reaches [`BatchQosPolicy::max_samples`] or [`BatchQosPolicy::max_data_bytes`], when its first
+/// sample has been held for [`BatchQosPolicy::max_flush_delay`] or when the application calls
+/// [`DataWriter::flush()`](crate::publication::data_writer::DataWriter). Disposing or unregistering an instance also flushes the batch.
+/// Batching only applies to writers with a reliable or best-effort stateful transport, i.e. writers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct BatchQosPolicy {
+    /// Enables the batching of the samples.
+    pub enable: bool,
+    /// Maximum number of samples in a batch.
+    pub max_samples: Length,
+    /// Maximum number of bytes of serialized data in a batch.
+    pub max_data_bytes: Length,
+    /// Maximum time the first sample of a batch is held before the batch is flushed.
+    pub max_flush_delay: DurationKind,
+}
+
+impl BatchQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            enable: false,
+            max_samples: Length::Unlimited,
+            max_data_bytes: Length::Unlimited,
+            max_flush_delay: DurationKind::Infinite,
+        }
+    }
+}
+
+impl QosPolicy for BatchQosPolicy {
+    fn name(&self) -> &str {
+        BATCH_QOS_POLICY_NAME
+    }
+}
+
+impl Default for BatchQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// This policy is a vendor-specific extension which configures the timing of the reliable protocol of a
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) as described in sub clause 8.4.7 of the RTPS standard.
+/// The writer sends a HEARTBEAT to its reliable readers every [`WriterProtocolQosPolicy::heartbeat_period`] while
+/// they have not acknowledged all of its changes. The changes requested by a reader with an ACKNACK are repaired
+/// after [`WriterProtocolQosPolicy::nack_response_delay`] so that the requests arriving in the meantime are answered
+/// together, and the requests for a change arriving within [`WriterProtocolQosPolicy::nack_suppression_duration`]
+/// of it being sent to the reader are ignored. Once a change has been repaired for a reader, its repeated requests
+/// for it are ignored within [`WriterProtocolQosPolicy::min_resend_interval`] of the repair. The interval doubles
+/// with every repair of the change, up to the heartbeat period, so that a reader requesting the change faster than
+/// the repairs can reach it does not get it sent again for each of its requests.
+/// Besides the periodic ones, a HEARTBEAT is piggybacked on one out of [`WriterProtocolQosPolicy::samples_per_heartbeat`]
+/// samples sent to a reader and on the last of the samples sent to the reader at once.
+/// This policy only applies to writers with a stateful transport, i.e. writers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct WriterProtocolQosPolicy {
+    /// Period of the HEARTBEAT messages sent to the readers with unacknowledged changes.
+    pub heartbeat_period: Duration,
+    /// Time the writer waits before repairing the changes requested by a reader.
+    pub nack_response_delay: Duration,
+    /// Time after sending a change during which the requests of the reader for that change are ignored.
+    pub nack_suppression_duration: Duration,
+    /// Time after the first repair of a change for a reader during which the requests of the reader for that change
+    /// are ignored. It doubles with every further repair of the change up to the heartbeat period.
+    pub min_resend_interval: Duration,
+    /// Number of samples sent to a reader for each HEARTBEAT piggybacked on them. With [`Length::Unlimited`] a single
+    /// HEARTBEAT is piggybacked on the samples sent to the reader at once.
+    pub samples_per_heartbeat: Length,
+}
+
+impl WriterProtocolQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            heartbeat_period: Duration::new(0, 200_000_000),
+            nack_response_delay: Duration::new(DURATION_ZERO_SEC, DURATION_ZERO_NSEC),
+            nack_suppression_duration: Duration::new(DURATION_ZERO_SEC, DURATION_ZERO_NSEC),
+            min_resend_interval: Duration::new(0, 10_000_000),
+            samples_per_heartbeat: Length::Limited(1),
+        }
+    }
+}
+
+impl QosPolicy for WriterProtocolQosPolicy {
+    fn name(&self) -> &str {
+        WRITER_PROTOCOL_QOS_POLICY_NAME
+    }
+}
+
+impl Default for WriterProtocolQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// This policy is a vendor-specific extension which assigns a [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// to one of the flow controllers of its transport, such as the ones added with
+/// [`RtpsUdpTransportParticipantFactoryBuilder::flow_controller`](crate::rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder::flow_controller).
+/// The flow controller limits the number of bytes sent by the writers assigned to it in each of its periods so that
+/// writers of large data do not flood the network and starve the other traffic, e.g. the discovery.
+/// The data of a writer with an empty [`FlowControllerQosPolicy::name`] or with the name of a flow controller unknown to
+/// its transport is sent without limit.
+/// This policy only applies to writers with a stateful transport, i.e. writers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct FlowControllerQosPolicy {
+    /// Name of the flow controller of the writer.
+    pub name: String,
+}
+
+impl FlowControllerQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            name: String::new(),
+        }
+    }
+}
+
+impl QosPolicy for FlowControllerQosPolicy {
+    fn name(&self) -> &str {
+        FLOW_CONTROLLER_QOS_POLICY_NAME
+    }
+}
+
+impl Default for FlowControllerQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Enumeration representing the different modes in which a [`DataWriter`](crate::publication::data_writer::DataWriter) publishes its data.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, XTypesSerialize, XTypesDeserialize)]
+pub enum PublishModeQosPolicyKind {
+    /// The data is sent by the call writing it.
+    Synchronous,
+    /// The data is sent by a task of the transport after the call writing it returns.
+    Asynchronous,
+}
+
+/// This policy is a vendor-specific extension which selects how a [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// publishes its data.
+/// With [`PublishModeQosPolicyKind::Synchronous`] the data is sent to the matched readers by the call writing it. This is
+/// the default value.
+/// With [`PublishModeQosPolicyKind::Asynchronous`] the call writing the data only stores it in the history of the writer
+/// and a task of the transport sends it afterwards, so the time taken by the write does not depend on the number of
+/// matched readers nor on the size of the data. The task serves the writers in decreasing order of their
+/// [`TransportPriorityQosPolicy::value`] and sends their data through their flow controller, if any.
+/// This policy only applies to writers with a stateful transport, i.e. writers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct PublishModeQosPolicy {
+    /// Mode in which the writer publishes its data.
+    pub kind: PublishModeQosPolicyKind,
+}
+
+impl PublishModeQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            kind: PublishModeQosPolicyKind::Synchronous,
+        }
+    }
+}
+
+impl QosPolicy for PublishModeQosPolicy {
+    fn name(&self) -> &str {
+        PUBLISH_MODE_QOS_POLICY_NAME
+    }
+}
+
+impl Default for PublishModeQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Enumeration representing the different behaviors of the RTPS endpoint of an entity.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, XTypesSerialize, XTypesDeserialize)]
+pub enum EndpointBehaviorQosPolicyKind {
+    /// The endpoint keeps the state of each of its matched remote endpoints.
+    Stateful,
+    /// The endpoint keeps no state of its matched remote endpoints.
+    Stateless,
+}
+
+/// This policy is a vendor-specific extension which selects the behavior of the RTPS endpoint of a
+/// [`DataWriter`](crate::publication::data_writer::DataWriter) or a [`DataReader`](crate::subscription::data_reader::DataReader)
+/// as described in sub clauses 8.4.8 and 8.4.11 of the RTPS standard.
+/// With [`EndpointBehaviorQosPolicyKind::Stateful`] the endpoint keeps the state of each of its matched remote endpoints. This
+/// is the default value.
+/// With [`EndpointBehaviorQosPolicyKind::Stateless`] a writer only keeps the locators of its matched readers and sends each
+/// sample once to every locator, whatever the number of matched readers reached through it, and a reader accepts the
+/// samples of its matched writers without keeping any state for them. This suits the writers broadcasting data to many
+/// readers, which then need a constant amount of memory and serialize each sample once.
+/// Stateless endpoints only support the [`ReliabilityQosPolicyKind::BestEffort`] reliability and do not fragment nor
+/// reassemble the samples, so their samples must fit in a single datagram.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct EndpointBehaviorQosPolicy {
+    /// Behavior of the RTPS endpoint of the entity.
+    pub kind: EndpointBehaviorQosPolicyKind,
+}
+
+impl EndpointBehaviorQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            kind: EndpointBehaviorQosPolicyKind::Stateful,
+        }
+    }
+}
+
+impl QosPolicy for EndpointBehaviorQosPolicy {
+    fn name(&self) -> &str {
+        ENDPOINT_BEHAVIOR_QOS_POLICY_NAME
+    }
+}
+
+impl Default for EndpointBehaviorQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// This policy is a vendor-specific extension which disables the positive acknowledgments of the reliable readers
+/// matched with a [`DataWriter`](crate::publication::data_writer::DataWriter).
+/// When enabled, the HEARTBEAT messages of the writer do not request an ACKNACK from the readers which received all
+/// of its changes, so the readers only send ACKNACK messages to request the changes they are missing. Instead of
+/// waiting to be acknowledged by every reader, a change is considered acknowledged once it has been sent to all the
+/// readers for [`DisablePositiveAcksQosPolicy::duration`], during which the readers can still request its repair.
+/// This reduces the traffic of high-rate reliable streams at the cost of not knowing whether the readers actually
+/// received the changes.
+/// This policy only applies to writers with a stateful transport, i.e. writers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct DisablePositiveAcksQosPolicy {
+    /// Disables the positive acknowledgments of the readers.
+    pub enable: bool,
+    /// Time after being sent during which a change is kept available for repair.
+    pub duration: Duration,
+}
+
+impl DisablePositiveAcksQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            enable: false,
+            duration: Duration::new(0, 100_000_000),
+        }
+    }
+}
+
+impl QosPolicy for DisablePositiveAcksQosPolicy {
+    fn name(&self) -> &str {
+        DISABLE_POSITIVE_ACKS_QOS_POLICY_NAME
+    }
+}
+
+impl Default for DisablePositiveAcksQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// This policy is a vendor-specific extension which configures the timing of the reliable protocol of a
+/// [`DataReader`](crate::subscription::data_reader::DataReader) as described in sub clause 8.4.12 of the RTPS standard.
+/// The reader answers a HEARTBEAT of a matched writer with an ACKNACK after [`ReaderProtocolQosPolicy::heartbeat_response_delay`]
+/// and does not answer the HEARTBEATs of that writer arriving within [`ReaderProtocolQosPolicy::heartbeat_suppression_duration`]
+/// of the last one it answered. Delaying and suppressing the responses avoids a burst of ACKNACKs when many writers
+/// send their heartbeats at the same time.
+/// This policy only applies to readers with a stateful transport, i.e. readers of user topics.
+#[derive(Debug, PartialEq, Eq, Clone, XTypesSerialize, XTypesDeserialize)]
+pub struct ReaderProtocolQosPolicy {
+    /// Time the reader waits before answering a HEARTBEAT with an ACKNACK.
+    pub heartbeat_response_delay: Duration,
+    /// Time after answering a HEARTBEAT of a writer during which the following HEARTBEATs of the writer are not answered.
+    pub heartbeat_suppression_duration: Duration,
+}
+
+impl ReaderProtocolQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            heartbeat_response_delay: Duration::new(DURATION_ZERO_SEC, DURATION_ZERO_NSEC),
+            heartbeat_suppression_duration: Duration::new(DURATION_ZERO_SEC, DURATION_ZERO_NSEC),
+        }
+    }
+}
+
+impl QosPolicy for ReaderProtocolQosPolicy {
+    fn name(&self) -> &str {
+        READER_PROTOCOL_QOS_POLICY_NAME
+    }
+}
+
+impl Default for ReaderProtocolQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Enumeration representing the different ways of choosing the GUID prefix of a
+/// [`DomainParticipant`](crate::domain::domain_participant::DomainParticipant).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GuidPrefixQosPolicyKind {
+    /// The prefix is derived by the [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory).
+    Automatic,
+    /// The prefix is the given value.
+    Manual([u8; 12]),
+}
+
+/// This policy is a vendor-specific extension which selects the GUID prefix of a
+/// [`DomainParticipant`](crate::domain::domain_participant::DomainParticipant), which is shared by the GUIDs of the
+/// participant and of all its entities as described in sub clause 8.2.4.1 of the RTPS standard.
+/// With [`GuidPrefixQosPolicyKind::Automatic`] the prefix is made of the host identifier, the application identifier
+/// and an instance identifier given by the factory. The instance identifiers of a process start at a random value so
+/// that a restarted application does not announce the prefixes of its previous run again. This is the default value.
+/// With [`GuidPrefixQosPolicyKind::Manual`] the application chooses the prefix, for instance to keep the identity of the
+/// participant across restarts.
+/// The factory never creates two participants with the same prefix: the automatic prefixes in use are skipped and
+/// creating a participant with a manual prefix in use fails with [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
+/// Since the prefix is given to the participant when it is created, this policy can not be changed afterwards.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GuidPrefixQosPolicy {
+    /// Way of choosing the GUID prefix.
+    pub kind: GuidPrefixQosPolicyKind,
+}
+
+impl GuidPrefixQosPolicy {
+    pub const fn const_default() -> Self {
+        Self {
+            kind: GuidPrefixQosPolicyKind::Automatic,
+        }
+    }
+}
+
+impl QosPolicy for GuidPrefixQosPolicy {
+    fn name(&self) -> &str {
+        GUID_PREFIX_QOS_POLICY_NAME
+    }
+}
+
+impl Default for GuidPrefixQosPolicy {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn topic_data_qos_policy_metadata_round_trip() {
+        let metadata = TopicMetadata {
+            units: String::from("m/s"),
+            frame_id: String::from("base_link"),
+            schema_version: 2,
+            valid_min: 0.0,
+            valid_max: 50.0,
+        };
+        let topic_data = TopicDataQosPolicy::from_metadata(&metadata).unwrap();
+
+        assert_eq!(topic_data.metadata().unwrap(), metadata);
+        assert!(metadata.is_valid(12.5));
+        assert!(!metadata.is_valid(-1.0));
+        assert!(TopicMetadata::default().is_valid(f64::MAX));
+        assert!(TopicDataQosPolicy::default().metadata().is_err());
+    }
+
     #[test]
     fn durability_qos_policy_kind_ordering() {
         assert!(DurabilityQosPolicyKind::Volatile < DurabilityQosPolicyKind::TransientLocal);
@@ -1526,4 +2055,41 @@ mod tests {
         assert!(Length::Limited(10) == 10usize);
         assert!(10usize == Length::Limited(10));
     }
+
+    #[test]
+    fn length_conversions() {
+        assert_eq!(Length::try_from(-1), Ok(Length::Unlimited));
+        assert_eq!(Length::try_from(0), Ok(Length::Limited(0)));
+        assert_eq!(Length::try_from(-2), Err(DdsError::BadParameter));
+        assert_eq!(Length::from(5u32), Length::Limited(5));
+        assert_eq!(Length::Limited(10).saturating_remaining(4), Length::Limited(6));
+        assert_eq!(Length::Limited(10).saturating_remaining(12), Length::Limited(0));
+        assert_eq!(Length::Unlimited.saturating_remaining(12), Length::Unlimited);
+        assert_eq!(Length::Unlimited.saturating_as_usize(), usize::MAX);
+        assert_eq!(Length::Unlimited.limited_or(100), 100);
+        assert_eq!(Length::Limited(10).limited_or(100), 10);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn length_serde_round_trip() {
+        assert_eq!(
+            serde_json::to_string(&Length::Unlimited).unwrap(),
+            "\"LENGTH_UNLIMITED\""
+        );
+        assert_eq!(serde_json::to_string(&Length::Limited(10)).unwrap(), "10");
+        assert_eq!(
+            serde_json::from_str::<Length>("\"LENGTH_UNLIMITED\"").unwrap(),
+            Length::Unlimited
+        );
+        assert_eq!(
+            serde_json::from_str::<Length>("-1").unwrap(),
+            Length::Unlimited
+        );
+        assert_eq!(
+            serde_json::from_str::<Length>("10").unwrap(),
+            Length::Limited(10)
+        );
+        assert!(serde_json::from_str::<Length>("-2").is_err());
+    }
 }
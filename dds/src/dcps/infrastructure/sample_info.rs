@@ -2,7 +2,10 @@ use super::{
     error::{DdsError, DdsResult},
     type_support::DdsDeserialize,
 };
-use crate::infrastructure::{instance::InstanceHandle, time::Time};
+use crate::{
+    dcps::data_reader::SampleData,
+    infrastructure::{instance::InstanceHandle, time::Time},
+};
 use alloc::sync::Arc;
 use core::marker::PhantomData;
 
@@ -10,14 +13,14 @@ use core::marker::PhantomData;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Sample<Foo> {
     /// Data received by the [`DataReader`]. A sample might contain no valid data in which case this field is [`None`].
-    data: Option<Arc<[u8]>>,
+    data: Option<SampleData>,
     /// Information of the sample received by the [`DataReader`].
     sample_info: SampleInfo,
     phantom: PhantomData<Foo>,
 }
 
 impl<Foo> Sample<Foo> {
-    pub(crate) fn new(data: Option<Arc<[u8]>>, sample_info: SampleInfo) -> Self {
+    pub(crate) fn new(data: Option<SampleData>, sample_info: SampleInfo) -> Self {
         Self {
             data,
             sample_info,
@@ -33,19 +36,135 @@ where
     /// Get the Foo value associated with this sample.
     pub fn data(&'de self) -> DdsResult<Foo> {
         match self.data.as_ref() {
-            Some(data) => Ok(Foo::deserialize_data(data.as_ref())?),
+            Some(data) => Ok(Foo::deserialize_data(data.serialized_data.as_ref())?),
             None => Err(DdsError::NoData),
         }
     }
 }
 
+impl<Foo: 'static> Sample<Foo> {
+    /// Get a reference to the Foo value that was deserialized when the sample was received. The value is only available
+    /// if read-ahead deserialization was enabled on the [`DataReader`] at reception time, otherwise this returns [`None`] and
+    /// the value has to be obtained with [`Sample::data`].
+    pub fn read_ahead_data(&self) -> Option<&Foo> {
+        self.data
+            .as_ref()?
+            .read_ahead_value
+            .as_ref()?
+            .0
+            .downcast_ref()
+    }
+}
+
 impl<Foo> Sample<Foo> {
+    pub(crate) fn serialized_data(&self) -> Option<&[u8]> {
+        self.data.as_ref().map(|d| d.serialized_data.as_ref())
+    }
+
     /// Get the sample info associated with this sample.
     pub fn sample_info(&self) -> SampleInfo {
         self.sample_info.clone()
     }
 }
 
+/// A [`SampleRef`] gives access to a sample loaned by the [`DataReader`](crate::subscription::data_reader::DataReader)
+/// with [`read_loaned`](crate::subscription::data_reader::DataReader::read_loaned) or
+/// [`take_loaned`](crate::subscription::data_reader::DataReader::take_loaned). The serialized data references the
+/// buffer stored in the history of the reader instead of a copy and the loan is returned when the [`SampleRef`] is
+/// dropped. Taken samples keep counting against the resource limits of the reader until their loan is returned. A
+/// [`DataReader`](crate::subscription::data_reader::DataReader) with outstanding loans can not be deleted.
+#[derive(Debug)]
+pub struct SampleRef<Foo> {
+    data: Option<SampleData>,
+    sample_info: SampleInfo,
+    _loan: Option<Arc<()>>,
+    phantom: PhantomData<Foo>,
+}
+
+impl<Foo> SampleRef<Foo> {
+    pub(crate) fn new(
+        data: Option<SampleData>,
+        sample_info: SampleInfo,
+        loan: Option<Arc<()>>,
+    ) -> Self {
+        Self {
+            data,
+            sample_info,
+            _loan: loan,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get the serialized data of the sample as stored by the [`DataReader`](crate::subscription::data_reader::DataReader).
+    /// This operation returns [`DdsError::NoData`] if the sample contains no valid data.
+    pub fn serialized_data(&self) -> DdsResult<&[u8]> {
+        match self.data.as_ref() {
+            Some(data) => Ok(data.serialized_data.as_ref()),
+            None => Err(DdsError::NoData),
+        }
+    }
+
+    /// Get a reference to the sample info associated with this sample.
+    pub fn sample_info(&self) -> &SampleInfo {
+        &self.sample_info
+    }
+}
+
+impl<'de, Foo> SampleRef<Foo>
+where
+    Foo: DdsDeserialize<'de>,
+{
+    /// Get the Foo value associated with this sample. Types borrowing from the input when deserializing
+    /// reference the loaned serialized data directly.
+    pub fn data(&'de self) -> DdsResult<Foo> {
+        Foo::deserialize_data(self.serialized_data()?)
+    }
+}
+
+impl<Foo: 'static> SampleRef<Foo> {
+    /// Get a reference to the Foo value that was deserialized when the sample was received. See [`Sample::read_ahead_data`].
+    pub fn read_ahead_data(&self) -> Option<&Foo> {
+        self.data
+            .as_ref()?
+            .read_ahead_value
+            .as_ref()?
+            .0
+            .downcast_ref()
+    }
+}
+
+/// A [`SerializedSample`] contains the serialized data and [`SampleInfo`] taken from the
+/// [`DataReader`](crate::subscription::data_reader::DataReader) with
+/// [`take_serialized`](crate::subscription::data_reader::DataReader::take_serialized). The data is kept exactly as it was
+/// received, including its encapsulation header, so that it can be forwarded or recorded without knowledge of its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedSample {
+    data: Option<Arc<[u8]>>,
+    sample_info: SampleInfo,
+}
+
+impl SerializedSample {
+    pub(crate) fn new(data: Option<SampleData>, sample_info: SampleInfo) -> Self {
+        Self {
+            data: data.map(|d| d.serialized_data),
+            sample_info,
+        }
+    }
+
+    /// Get the serialized data of the sample. This operation returns [`DdsError::NoData`] if the sample contains no valid data.
+    pub fn serialized_data(&self) -> DdsResult<&[u8]> {
+        match self.data.as_ref() {
+            Some(data) => Ok(data.as_ref()),
+            None => Err(DdsError::NoData),
+        }
+    }
+
+    /// Get a reference to the sample info associated with this sample.
+    pub fn sample_info(&self) -> &SampleInfo {
+        &self.sample_info
+    }
+}
+
 /// Enumeration of the possible sample states
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SampleStateKind {
@@ -99,6 +218,10 @@ pub const NOT_ALIVE_INSTANCE_STATE: &[InstanceStateKind] = &[
     InstanceStateKind::NotAliveNoWriters,
 ];
 
+/// Sequence number assigned by a [`DataWriter`](crate::publication::data_writer::DataWriter) to each sample it publishes.
+/// Sequence numbers of a writer start at 1 and are strictly increasing.
+pub type SequenceNumber = i64;
+
 /// The [`SampleInfo`] contains the information associated with each received data value.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SampleInfo {
@@ -43,6 +43,19 @@ pub enum StatusKind {
     /// that matches the [`Topic`](crate::topic_definition::topic::Topic) and has compatible Qos, or has ceased to be matched with a
     /// [`DataWriter`](crate::publication::data_writer::DataWriter) that was previously considered to be matched.
     SubscriptionMatched,
+    /// A durable [`DataWriter`](crate::publication::data_writer::DataWriter) which was matched with the
+    /// [`DataReader`](crate::subscription::data_reader::DataReader) has been replaced by a new durable
+    /// [`DataWriter`](crate::publication::data_writer::DataWriter) with a different GUID in the same partition.
+    /// This status is not part of the DDS standard.
+    WriterRestarted,
+    /// The [`DataWriter`](crate::publication::data_writer::DataWriter) has started or stopped throttling its publication
+    /// because the matched reliable [`DataReader`](crate::subscription::data_reader::DataReader) entities lag behind.
+    /// This status is not part of the DDS standard.
+    PublicationThrottled,
+    /// One of the internal tasks of the [`DomainParticipant`](crate::domain::domain_participant::DomainParticipant)
+    /// has been detected as stalled by its watchdog.
+    /// This status is not part of the DDS standard.
+    TaskStalled,
 }
 
 /// Special constant representing an empty list of communication statuses
@@ -142,7 +155,7 @@ impl Default for SampleRejectedStatus {
 }
 
 /// Structure holding the values related to the Liveliness Lost communication status.
-#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LivelinessLostStatus {
     /// Total cumulative number of times that a previously-alive DataWriter
     /// became not alive due to a failure to actively signal its liveliness within
@@ -155,6 +168,21 @@ pub struct LivelinessLostStatus {
     pub total_count_change: i32,
 }
 
+impl LivelinessLostStatus {
+    pub const fn const_default() -> Self {
+        Self {
+            total_count: 0,
+            total_count_change: 0,
+        }
+    }
+}
+
+impl Default for LivelinessLostStatus {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// Structure holding the values related to the Liveliness Changed communication status.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LivelinessChangedStatus {
@@ -415,3 +443,67 @@ impl Default for SubscriptionMatchedStatus {
         Self::const_default()
     }
 }
+
+/// Structure holding the values related to the Writer Restarted communication status.
+/// This status is not part of the DDS standard.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WriterRestartedStatus {
+    /// Total cumulative count of durable DataWriters which were matched again with the DataReader after a previously
+    /// matched durable DataWriter in the same partition was lost.
+    pub total_count: i32,
+    /// The change in total_count since the last time the listener was called or the status was read.
+    pub total_count_change: i32,
+    /// Handle to the DataWriter which was lost and replaced by the last restarted DataWriter.
+    pub last_previous_publication_handle: InstanceHandle,
+    /// Handle to the last restarted DataWriter.
+    pub last_publication_handle: InstanceHandle,
+}
+
+impl WriterRestartedStatus {
+    pub const fn const_default() -> Self {
+        Self {
+            total_count: 0,
+            total_count_change: 0,
+            last_previous_publication_handle: HANDLE_NIL,
+            last_publication_handle: HANDLE_NIL,
+        }
+    }
+}
+
+impl Default for WriterRestartedStatus {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Structure holding the values related to the Publication Throttled communication status.
+/// This status is not part of the DDS standard.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PublicationThrottledStatus {
+    /// Total cumulative count of the times the DataWriter started throttling its publication.
+    pub total_count: i32,
+    /// The change in total_count since the last time the listener was called or the status was read.
+    pub total_count_change: i32,
+    /// Whether the publication of the DataWriter is currently throttled.
+    pub throttled: bool,
+    /// Number of changes written after the last change acknowledged by all the matched reliable DataReaders when
+    /// the throttling last started or stopped.
+    pub unacknowledged_change_count: i32,
+}
+
+impl PublicationThrottledStatus {
+    pub const fn const_default() -> Self {
+        Self {
+            total_count: 0,
+            total_count_change: 0,
+            throttled: false,
+            unacknowledged_change_count: 0,
+        }
+    }
+}
+
+impl Default for PublicationThrottledStatus {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
@@ -1,5 +1,12 @@
 use crate::{
-    infrastructure::error::DdsResult,
+    dcps::xtypes_glue::key_and_instance_handle::{
+        get_instance_handle_from_serialized_foo, get_serialized_key_from_serialized_foo,
+    },
+    infrastructure::{
+        error::{DdsError, DdsResult},
+        instance::InstanceHandle,
+        qos_policy::{DataRepresentationId, XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION},
+    },
     xtypes::{
         dynamic_type::DynamicType,
         xcdr_deserializer::{Xcdr2BeDeserializer, Xcdr2LeDeserializer},
@@ -28,6 +35,15 @@ pub trait TypeSupport {
 pub trait DdsSerialize {
     /// Method to serialize the instance of the type into the provided writer.
     fn serialize_data(&self) -> DdsResult<Vec<u8>>;
+
+    /// Method to serialize the instance of the type using the data representation negotiated by the writer.
+    /// The default implementation ignores the representation and calls [`DdsSerialize::serialize_data`].
+    fn serialize_data_with_representation(
+        &self,
+        _representation: DataRepresentationId,
+    ) -> DdsResult<Vec<u8>> {
+        self.serialize_data()
+    }
 }
 
 /// This trait describes how the bytes can be deserialize to construct the data structure.
@@ -48,7 +64,7 @@ use crate::xtypes::{
     error::XTypesError,
     serialize::XTypesSerialize,
     xcdr_deserializer::{Xcdr1BeDeserializer, Xcdr1LeDeserializer},
-    xcdr_serializer::{Xcdr1BeSerializer, Xcdr1LeSerializer},
+    xcdr_serializer::{Xcdr1BeSerializer, Xcdr1LeSerializer, Xcdr2BeSerializer, Xcdr2LeSerializer},
 };
 /// This is a convenience derive to allow the user to easily derive all the different traits needed for a type to be used for
 /// communication with Dust DDS. If the individual traits are manually derived then this derive should not be used.
@@ -97,6 +113,9 @@ const _D_CDR2_LE: RepresentationIdentifier = [0x00, 0x09];
 const _PL_CDR_BE: RepresentationIdentifier = [0x00, 0x02];
 const _PL_CDR_LE: RepresentationIdentifier = [0x00, 0x03];
 const REPRESENTATION_OPTIONS: RepresentationOptions = [0x00, 0x00];
+// The two least significant bits of the options hold the number of padding bytes appended to the
+// serialized data. The remaining bits are reserved and must be zero.
+const REPRESENTATION_OPTIONS_PADDING_MASK: u8 = 0x03;
 
 /// This is a helper function to serialize a type implementing [`XTypesSerialize`] using the XTypes defined XCDR1 representation with LittleEndian endianness.
 pub fn serialize_rtps_xtypes_xcdr1_le(value: &impl XTypesSerialize) -> DdsResult<Vec<u8>> {
@@ -122,6 +141,43 @@ pub fn serialize_rtps_xtypes_xcdr1_be(value: &impl XTypesSerialize) -> DdsResult
     Ok(writer)
 }
 
+/// This is a helper function to serialize a type implementing [`XTypesSerialize`] using the XTypes defined XCDR2 representation with LittleEndian endianness.
+pub fn serialize_rtps_xtypes_xcdr2_le(value: &impl XTypesSerialize) -> DdsResult<Vec<u8>> {
+    let padded_length = (Xcdr2LeSerializer::bytes_len(value)? + 3) & !3;
+    let mut writer = Vec::with_capacity(padded_length + 4);
+    writer.extend_from_slice(&CDR2_LE);
+    writer.extend_from_slice(&REPRESENTATION_OPTIONS);
+    let mut serializer = Xcdr2LeSerializer::new(&mut writer);
+    XTypesSerialize::serialize(value, &mut serializer)?;
+    pad(&mut writer);
+    Ok(writer)
+}
+
+/// This is a helper function to serialize a type implementing [`XTypesSerialize`] using the XTypes defined XCDR2 representation with BigEndian endianness.
+pub fn serialize_rtps_xtypes_xcdr2_be(value: &impl XTypesSerialize) -> DdsResult<Vec<u8>> {
+    let padded_length = (Xcdr2BeSerializer::bytes_len(value)? + 3) & !3;
+    let mut writer = Vec::with_capacity(padded_length + 4);
+    writer.extend_from_slice(&CDR2_BE);
+    writer.extend_from_slice(&REPRESENTATION_OPTIONS);
+    let mut serializer = Xcdr2BeSerializer::new(&mut writer);
+    XTypesSerialize::serialize(value, &mut serializer)?;
+    pad(&mut writer);
+    Ok(writer)
+}
+
+/// This is a helper function to serialize a type implementing [`XTypesSerialize`] with LittleEndian endianness using
+/// the given data representation. Only the XCDR and XCDR2 representations are supported.
+pub fn serialize_rtps_xtypes_le(
+    value: &impl XTypesSerialize,
+    representation: DataRepresentationId,
+) -> DdsResult<Vec<u8>> {
+    match representation {
+        XCDR_DATA_REPRESENTATION => serialize_rtps_xtypes_xcdr1_le(value),
+        XCDR2_DATA_REPRESENTATION => serialize_rtps_xtypes_xcdr2_le(value),
+        _ => Err(DdsError::Unsupported),
+    }
+}
+
 fn pad(writer: &mut Vec<u8>) {
     let padding = match writer.len() % 4 {
         1 => &[0, 0, 0][..],
@@ -133,6 +189,66 @@ fn pad(writer: &mut Vec<u8>) {
     writer[3] = padding.len() as u8;
 }
 
+/// Clear the representation options of the encapsulation header so that the number of padding bytes is not encoded.
+/// Used for interoperability with implementations which expect the options to always be zero.
+pub(crate) fn clear_representation_options(serialized_data: &mut [u8]) {
+    if serialized_data.len() >= 4 {
+        serialized_data[2..4].copy_from_slice(&REPRESENTATION_OPTIONS);
+    }
+}
+
+/// Check that the representation options of the encapsulation header are valid, that is that the reserved bits are zero
+/// and that the number of padding bytes does not exceed the length of the serialized data. Empty data, as sent for
+/// example by a dispose identified only by its key hash, carries no encapsulation header and is always valid.
+pub(crate) fn validate_representation_options(serialized_data: &[u8]) -> DdsResult<()> {
+    if serialized_data.is_empty() {
+        return Ok(());
+    }
+    if serialized_data.len() < 4 {
+        Err(XTypesError::InvalidData)?;
+    }
+    let representation_options = [serialized_data[2], serialized_data[3]];
+    if representation_options[0] != 0
+        || representation_options[1] & !REPRESENTATION_OPTIONS_PADDING_MASK != 0
+    {
+        Err(XTypesError::InvalidData)?;
+    }
+    let padding_length = (representation_options[1] & REPRESENTATION_OPTIONS_PADDING_MASK) as usize;
+    if padding_length > serialized_data.len() - 4 {
+        Err(XTypesError::InvalidData)?;
+    }
+    Ok(())
+}
+
+/// This is a helper function to compute the instance handle of a sample from its serialized data, including the
+/// encapsulation header, without deserializing it. Only the key fields described by `dynamic_type` are read, so
+/// a gateway holding only the serialized payload and the type of the topic can preserve the instance it belongs to.
+/// The `dynamic_type` is typically obtained with [`TypeSupport::get_type`].
+/// This operation is not part of the DDS standard.
+pub fn get_instance_handle_from_serialized_data(
+    serialized_data: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> DdsResult<InstanceHandle> {
+    Ok(get_instance_handle_from_serialized_foo(
+        serialized_data,
+        dynamic_type,
+    )?)
+}
+
+/// This is a helper function to extract the key fields of a sample from its serialized data, including the
+/// encapsulation header, without deserializing it. The key is returned in the XCDR1 LittleEndian representation with
+/// its own encapsulation header, which is the form in which it is sent for example when disposing an instance.
+/// This operation is not part of the DDS standard.
+pub fn get_serialized_key_from_serialized_data(
+    serialized_data: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> DdsResult<Vec<u8>> {
+    Ok(get_serialized_key_from_serialized_foo(
+        serialized_data,
+        dynamic_type,
+    )?)
+}
+
 /// This is a helper function to deserialize a type implementing [`CdrDeserialize`] using the RTPS classic CDR representation.
 /// The representation endianness to be used is automatically determined from the representation identifier and options
 pub fn deserialize_rtps_encapsulated_data<'de, T>(serialized_data: &mut &'de [u8]) -> DdsResult<T>
@@ -155,3 +271,133 @@ where
     }?;
     Ok(value)
 }
+
+/// This is a helper function to deserialize a type implementing [`XTypesDeserialize`] validating that the data conforms
+/// exactly to the alignment, padding and length rules of the CDR and XCDR2 representations. It is meant to certify
+/// the interoperability with payloads produced by other implementations, which [`deserialize_rtps_encapsulated_data`]
+/// may otherwise silently accept. Violations are reported with their offset from the start of the data following the
+/// encapsulation header as [`XTypesError::NonConformant`].
+/// This operation is not part of the DDS standard.
+pub fn deserialize_rtps_encapsulated_data_strict<'de, T>(
+    serialized_data: &mut &'de [u8],
+) -> DdsResult<T>
+where
+    T: XTypesDeserialize<'de>,
+{
+    if serialized_data.len() < 4 {
+        Err(XTypesError::InvalidData)?;
+    }
+    let representation_identifier = [serialized_data[0], serialized_data[1]];
+    *serialized_data = &serialized_data[4..];
+
+    let value = match representation_identifier {
+        CDR_BE => {
+            XTypesDeserialize::deserialize(&mut Xcdr1BeDeserializer::new_strict(serialized_data))
+        }
+        CDR_LE => {
+            XTypesDeserialize::deserialize(&mut Xcdr1LeDeserializer::new_strict(serialized_data))
+        }
+        CDR2_BE => {
+            XTypesDeserialize::deserialize(&mut Xcdr2BeDeserializer::new_strict(serialized_data))
+        }
+        CDR2_LE => {
+            XTypesDeserialize::deserialize(&mut Xcdr2LeDeserializer::new_strict(serialized_data))
+        }
+        _ => Err(XTypesError::InvalidData),
+    }?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::qos_policy::XML_DATA_REPRESENTATION;
+    use dust_dds_derive::TypeSupport;
+
+    #[derive(XTypesSerialize)]
+    struct AlignedData {
+        id: u8,
+        value: u64,
+    }
+
+    #[test]
+    fn serialize_with_xcdr2_representation() {
+        let value = AlignedData { id: 1, value: 2 };
+        assert_eq!(
+            serialize_rtps_xtypes_le(&value, XCDR_DATA_REPRESENTATION).unwrap(),
+            vec![
+                0x00, 0x01, 0x00, 0x00, // Representation header
+                1, 0, 0, 0, 0, 0, 0, 0, // u8 + padding to 8
+                2, 0, 0, 0, 0, 0, 0, 0, // u64
+            ]
+        );
+        assert_eq!(
+            serialize_rtps_xtypes_le(&value, XCDR2_DATA_REPRESENTATION).unwrap(),
+            vec![
+                0x00, 0x07, 0x00, 0x00, // Representation header
+                1, 0, 0, 0, // u8 + padding to 4
+                2, 0, 0, 0, 0, 0, 0, 0, // u64
+            ]
+        );
+        assert_eq!(
+            serialize_rtps_xtypes_le(&value, XML_DATA_REPRESENTATION),
+            Err(DdsError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn representation_options_encode_padding() {
+        let value = 1u8;
+        let serialized_data = serialize_rtps_xtypes_xcdr2_le(&value).unwrap();
+        assert_eq!(serialized_data, vec![0x00, 0x07, 0x00, 0x03, 1, 0, 0, 0]);
+        assert_eq!(validate_representation_options(&serialized_data), Ok(()));
+
+        let mut cleared_data = serialized_data.clone();
+        clear_representation_options(&mut cleared_data);
+        assert_eq!(cleared_data, vec![0x00, 0x07, 0x00, 0x00, 1, 0, 0, 0]);
+        assert_eq!(validate_representation_options(&cleared_data), Ok(()));
+    }
+
+    #[test]
+    fn invalid_representation_options() {
+        assert!(validate_representation_options(&[0x00, 0x07, 0x01, 0x00, 1, 0, 0, 0]).is_err());
+        assert!(validate_representation_options(&[0x00, 0x07, 0x00, 0x04, 1, 0, 0, 0]).is_err());
+        assert!(validate_representation_options(&[0x00, 0x07, 0x00, 0x03, 1, 0]).is_err());
+        assert!(validate_representation_options(&[0x00, 0x07]).is_err());
+        assert_eq!(validate_representation_options(&[]), Ok(()));
+    }
+
+    #[derive(XTypesSerialize, TypeSupport)]
+    struct KeyedData {
+        #[dust_dds(key)]
+        id: u16,
+        value: u32,
+    }
+
+    #[test]
+    fn key_from_serialized_data() {
+        let data = KeyedData { id: 3, value: 7 };
+        let le_data = serialize_rtps_xtypes_xcdr2_le(&data).unwrap();
+        let be_data = serialize_rtps_xtypes_xcdr1_be(&data).unwrap();
+        let instance_handle =
+            get_instance_handle_from_serialized_data(&le_data, &KeyedData::get_type()).unwrap();
+        assert_eq!(
+            get_instance_handle_from_serialized_data(&be_data, &KeyedData::get_type()),
+            Ok(instance_handle)
+        );
+        assert_eq!(
+            instance_handle,
+            InstanceHandle::new([0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            get_serialized_key_from_serialized_data(&be_data, &KeyedData::get_type()).unwrap(),
+            vec![0x00, 0x01, 0x00, 0x02, 3, 0, 0, 0]
+        );
+        assert!(get_instance_handle_from_serialized_data(&[0, 1], &KeyedData::get_type()).is_err());
+        assert!(get_serialized_key_from_serialized_data(
+            &[0x7f, 0x7f, 0, 0, 3, 0, 0, 0],
+            &KeyedData::get_type()
+        )
+        .is_err());
+    }
+}
@@ -31,6 +31,21 @@ impl DataReaderListenerActor {
                             .on_sample_rejected(the_reader.change_foo_type(), status)
                             .await;
                     }
+                    ListenerMail::SampleLost { the_reader, status } => {
+                        listener
+                            .on_sample_lost(the_reader.change_foo_type(), status)
+                            .await;
+                    }
+                    ListenerMail::WriterRestarted { the_reader, status } => {
+                        listener
+                            .on_writer_restarted(the_reader.change_foo_type(), status)
+                            .await;
+                    }
+                    ListenerMail::LivelinessChanged { the_reader, status } => {
+                        listener
+                            .on_liveliness_changed(the_reader.change_foo_type(), status)
+                            .await;
+                    }
                     ListenerMail::SubscriptionMatched { the_reader, status } => {
                         listener
                             .on_subscription_matched(the_reader.change_foo_type(), status)
@@ -41,6 +56,17 @@ impl DataReaderListenerActor {
                             .on_requested_incompatible_qos(the_reader.change_foo_type(), status)
                             .await;
                     }
+                    ListenerMail::DeserializationError {
+                        the_reader,
+                        serialized_data,
+                    } => {
+                        listener
+                            .on_deserialization_error(
+                                the_reader.change_foo_type(),
+                                serialized_data.as_ref(),
+                            )
+                            .await;
+                    }
                     ListenerMail::DataOnReaders { the_subscriber: _ } => {
                         panic!("Not valid for reader")
                     }
@@ -62,6 +88,21 @@ impl DataReaderListenerActor {
                     } => {
                         panic!("Not valid for reader")
                     }
+                    ListenerMail::LivelinessLost {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for reader")
+                    }
+                    ListenerMail::PublicationThrottled {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for reader")
+                    }
+                    ListenerMail::TaskStalled { status: _ } => {
+                        panic!("Not valid for reader")
+                    }
                 }
             }
         });
@@ -31,6 +31,16 @@ impl DataWriterListenerActor {
                             .on_offered_deadline_missed(the_writer.change_foo_type(), status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener
+                            .on_liveliness_lost(the_writer.change_foo_type(), status)
+                            .await;
+                    }
+                    ListenerMail::PublicationThrottled { the_writer, status } => {
+                        listener
+                            .on_publication_throttled(the_writer.change_foo_type(), status)
+                            .await;
+                    }
                     ListenerMail::DataAvailable { the_reader: _ } => {
                         panic!("Not valid for writer")
                     }
@@ -49,6 +59,33 @@ impl DataWriterListenerActor {
                     } => {
                         panic!("Not valid for writer")
                     }
+                    ListenerMail::SampleLost {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for writer")
+                    }
+                    ListenerMail::WriterRestarted {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for writer")
+                    }
+                    ListenerMail::LivelinessChanged {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for writer")
+                    }
+                    ListenerMail::DeserializationError {
+                        the_reader: _,
+                        serialized_data: _,
+                    } => {
+                        panic!("Not valid for writer")
+                    }
+                    ListenerMail::TaskStalled { status: _ } => {
+                        panic!("Not valid for writer")
+                    }
                     ListenerMail::SubscriptionMatched {
                         the_reader: _,
                         status: _,
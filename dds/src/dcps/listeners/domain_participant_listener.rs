@@ -3,13 +3,17 @@ use crate::{
     dds_async::{
         data_reader::DataReaderAsync, data_writer::DataWriterAsync, subscriber::SubscriberAsync,
     },
-    domain::domain_participant_listener::DomainParticipantListener,
+    domain::{
+        domain_participant_listener::DomainParticipantListener, task_health::TaskHealthStatus,
+    },
     infrastructure::status::{
-        OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
-        RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus, SampleRejectedStatus,
-        SubscriptionMatchedStatus,
+        LivelinessChangedStatus, LivelinessLostStatus, OfferedDeadlineMissedStatus,
+        OfferedIncompatibleQosStatus, PublicationMatchedStatus, PublicationThrottledStatus,
+        RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus, SampleLostStatus,
+        SampleRejectedStatus, SubscriptionMatchedStatus, WriterRestartedStatus,
     },
 };
+use alloc::sync::Arc;
 
 pub struct DomainParticipantListenerActor;
 
@@ -33,6 +37,15 @@ impl DomainParticipantListenerActor {
                     ListenerMail::SampleRejected { the_reader, status } => {
                         listener.on_sample_rejected(the_reader, status).await;
                     }
+                    ListenerMail::SampleLost { the_reader, status } => {
+                        listener.on_sample_lost(the_reader, status).await;
+                    }
+                    ListenerMail::WriterRestarted { the_reader, status } => {
+                        listener.on_writer_restarted(the_reader, status).await;
+                    }
+                    ListenerMail::LivelinessChanged { the_reader, status } => {
+                        listener.on_liveliness_changed(the_reader, status).await;
+                    }
                     ListenerMail::SubscriptionMatched { the_reader, status } => {
                         listener.on_subscription_matched(the_reader, status).await;
                     }
@@ -54,9 +67,24 @@ impl DomainParticipantListenerActor {
                             .on_offered_deadline_missed(the_writer, status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener.on_liveliness_lost(the_writer, status).await;
+                    }
+                    ListenerMail::PublicationThrottled { the_writer, status } => {
+                        listener.on_publication_throttled(the_writer, status).await;
+                    }
+                    ListenerMail::TaskStalled { status } => {
+                        listener.on_task_stalled(status).await;
+                    }
                     ListenerMail::DataOnReaders { the_subscriber: _ } => {
                         panic!("Not valid for domain participant")
                     }
+                    ListenerMail::DeserializationError {
+                        the_reader: _,
+                        serialized_data: _,
+                    } => {
+                        panic!("Not valid for domain participant")
+                    }
                 }
             }
         });
@@ -79,6 +107,18 @@ pub enum ListenerMail<R: DdsRuntime> {
         the_reader: DataReaderAsync<R, ()>,
         status: SampleRejectedStatus,
     },
+    SampleLost {
+        the_reader: DataReaderAsync<R, ()>,
+        status: SampleLostStatus,
+    },
+    WriterRestarted {
+        the_reader: DataReaderAsync<R, ()>,
+        status: WriterRestartedStatus,
+    },
+    LivelinessChanged {
+        the_reader: DataReaderAsync<R, ()>,
+        status: LivelinessChangedStatus,
+    },
     SubscriptionMatched {
         the_reader: DataReaderAsync<R, ()>,
         status: SubscriptionMatchedStatus,
@@ -99,4 +139,19 @@ pub enum ListenerMail<R: DdsRuntime> {
         the_writer: DataWriterAsync<R, ()>,
         status: OfferedDeadlineMissedStatus,
     },
+    LivelinessLost {
+        the_writer: DataWriterAsync<R, ()>,
+        status: LivelinessLostStatus,
+    },
+    PublicationThrottled {
+        the_writer: DataWriterAsync<R, ()>,
+        status: PublicationThrottledStatus,
+    },
+    DeserializationError {
+        the_reader: DataReaderAsync<R, ()>,
+        serialized_data: Arc<[u8]>,
+    },
+    TaskStalled {
+        status: TaskHealthStatus,
+    },
 }
@@ -4,3 +4,4 @@ pub mod domain_participant_listener;
 pub mod publisher_listener;
 pub mod subscriber_listener;
 pub mod topic_listener;
+pub mod wildcard_subscription_listener;
@@ -29,6 +29,12 @@ impl PublisherListenerActor {
                             .on_offered_deadline_missed(the_writer, status)
                             .await;
                     }
+                    ListenerMail::LivelinessLost { the_writer, status } => {
+                        listener.on_liveliness_lost(the_writer, status).await;
+                    }
+                    ListenerMail::PublicationThrottled { the_writer, status } => {
+                        listener.on_publication_throttled(the_writer, status).await;
+                    }
                     ListenerMail::DataOnReaders { the_subscriber: _ } => {
                         panic!("Not valid for publisher")
                     }
@@ -47,6 +53,33 @@ impl PublisherListenerActor {
                     } => {
                         panic!("Not valid for publisher")
                     }
+                    ListenerMail::SampleLost {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for publisher")
+                    }
+                    ListenerMail::WriterRestarted {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for publisher")
+                    }
+                    ListenerMail::LivelinessChanged {
+                        the_reader: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for publisher")
+                    }
+                    ListenerMail::DeserializationError {
+                        the_reader: _,
+                        serialized_data: _,
+                    } => {
+                        panic!("Not valid for publisher")
+                    }
+                    ListenerMail::TaskStalled { status: _ } => {
+                        panic!("Not valid for publisher")
+                    }
                     ListenerMail::SubscriptionMatched {
                         the_reader: _,
                         status: _,
@@ -27,6 +27,15 @@ impl SubscriberListenerActor {
                     ListenerMail::SampleRejected { the_reader, status } => {
                         listener.on_sample_rejected(the_reader, status).await;
                     }
+                    ListenerMail::SampleLost { the_reader, status } => {
+                        listener.on_sample_lost(the_reader, status).await;
+                    }
+                    ListenerMail::WriterRestarted { the_reader, status } => {
+                        listener.on_writer_restarted(the_reader, status).await;
+                    }
+                    ListenerMail::LivelinessChanged { the_reader, status } => {
+                        listener.on_liveliness_changed(the_reader, status).await;
+                    }
                     ListenerMail::SubscriptionMatched { the_reader, status } => {
                         listener.on_subscription_matched(the_reader, status).await;
                     }
@@ -35,8 +44,8 @@ impl SubscriberListenerActor {
                             .on_requested_incompatible_qos(the_reader, status)
                             .await;
                     }
-                    ListenerMail::DataAvailable { the_reader: _ } => {
-                        panic!("Not valid for subscriber")
+                    ListenerMail::DataAvailable { the_reader } => {
+                        listener.on_data_available(the_reader).await;
                     }
                     ListenerMail::PublicationMatched {
                         the_writer: _,
@@ -56,6 +65,27 @@ impl SubscriberListenerActor {
                     } => {
                         panic!("Not valid for subscriber")
                     }
+                    ListenerMail::LivelinessLost {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for subscriber")
+                    }
+                    ListenerMail::PublicationThrottled {
+                        the_writer: _,
+                        status: _,
+                    } => {
+                        panic!("Not valid for subscriber")
+                    }
+                    ListenerMail::DeserializationError {
+                        the_reader: _,
+                        serialized_data: _,
+                    } => {
+                        panic!("Not valid for subscriber")
+                    }
+                    ListenerMail::TaskStalled { status: _ } => {
+                        panic!("Not valid for subscriber")
+                    }
                 }
             }
         });
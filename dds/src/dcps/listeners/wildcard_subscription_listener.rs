@@ -0,0 +1,249 @@
+use crate::{
+    builtin_topics::PublicationBuiltinTopicData,
+    dds_async::{data_reader::DataReaderAsync, subscriber::SubscriberAsync},
+    infrastructure::{
+        error::DdsResult,
+        qos::{DataReaderQos, QosKind},
+        sample_info::{InstanceStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+    },
+    listener::NO_LISTENER,
+    runtime::{ChannelReceive, ChannelSend, DdsRuntime, Spawner},
+    subscription::{
+        data_reader_listener::DataReaderListener,
+        wildcard_subscription_listener::WildcardSubscriptionListener,
+    },
+    xtypes::dynamic_type::DynamicType,
+};
+use alloc::{string::String, sync::Arc, vec::Vec};
+
+pub enum WildcardSubscriptionMail<R: DdsRuntime> {
+    PublicationsAvailable {
+        the_reader: DataReaderAsync<R, PublicationBuiltinTopicData>,
+    },
+    DataAvailable {
+        topic_name: String,
+        the_reader: DataReaderAsync<R, ()>,
+    },
+}
+
+pub struct WildcardSubscriptionListenerActor;
+
+impl WildcardSubscriptionListenerActor {
+    pub fn spawn<R: DdsRuntime>(
+        mut listener: impl WildcardSubscriptionListener<R> + Send + 'static,
+        subscriber: SubscriberAsync<R>,
+        topic_name_pattern: String,
+        reader_qos: DataReaderQos,
+        spawner_handle: &R::SpawnerHandle,
+    ) -> R::ChannelSender<WildcardSubscriptionMail<R>> {
+        let (mail_sender, mut mail_receiver) = R::channel();
+        let reader_mail_sender = mail_sender.clone();
+        spawner_handle.spawn(async move {
+            let mut subscribed_topic_list: Vec<String> = Vec::new();
+            while let Some(m) = mail_receiver.receive().await {
+                match m {
+                    WildcardSubscriptionMail::PublicationsAvailable { the_reader } => {
+                        // The samples are read instead of taken so that they remain available to the application
+                        let Ok(samples) = the_reader
+                            .read(
+                                i32::MAX,
+                                ANY_SAMPLE_STATE,
+                                ANY_VIEW_STATE,
+                                &[InstanceStateKind::Alive],
+                            )
+                            .await
+                        else {
+                            continue;
+                        };
+                        for sample in samples {
+                            let Ok(publication_data) = sample.data() else {
+                                continue;
+                            };
+                            let topic_name = publication_data.topic_name();
+                            if subscribed_topic_list.iter().any(|t| t == topic_name)
+                                || !matches_topic_name_pattern(&topic_name_pattern, topic_name)
+                            {
+                                continue;
+                            }
+                            let Some(dynamic_type) = listener.get_topic_type(&publication_data)
+                            else {
+                                continue;
+                            };
+                            if subscribe(
+                                &subscriber,
+                                &publication_data,
+                                dynamic_type,
+                                &reader_qos,
+                                reader_mail_sender.clone(),
+                            )
+                            .await
+                            .is_ok()
+                            {
+                                subscribed_topic_list.push(String::from(topic_name));
+                            }
+                        }
+                    }
+                    WildcardSubscriptionMail::DataAvailable {
+                        topic_name,
+                        the_reader,
+                    } => {
+                        let Ok(samples) = the_reader
+                            .take(
+                                i32::MAX,
+                                ANY_SAMPLE_STATE,
+                                ANY_VIEW_STATE,
+                                ANY_INSTANCE_STATE,
+                            )
+                            .await
+                        else {
+                            continue;
+                        };
+                        for sample in samples {
+                            listener
+                                .on_sample(
+                                    &topic_name,
+                                    sample.serialized_data().unwrap_or(&[]),
+                                    &sample.sample_info(),
+                                )
+                                .await;
+                        }
+                    }
+                }
+            }
+        });
+        mail_sender
+    }
+}
+
+async fn subscribe<R: DdsRuntime>(
+    subscriber: &SubscriberAsync<R>,
+    publication_data: &PublicationBuiltinTopicData,
+    dynamic_type: Arc<dyn DynamicType + Send + Sync>,
+    reader_qos: &DataReaderQos,
+    mail_sender: R::ChannelSender<WildcardSubscriptionMail<R>>,
+) -> DdsResult<()> {
+    let participant = subscriber.get_participant();
+    let topic = match participant
+        .lookup_topicdescription(publication_data.topic_name())
+        .await?
+    {
+        Some(topic) => topic,
+        None => {
+            participant
+                .create_dynamic_topic(
+                    publication_data.topic_name(),
+                    publication_data.get_type_name(),
+                    QosKind::Default,
+                    NO_LISTENER,
+                    NO_STATUS,
+                    dynamic_type,
+                )
+                .await?
+        }
+    };
+    let reader_listener = WildcardSubscriptionReaderListener {
+        topic_name: String::from(publication_data.topic_name()),
+        mail_sender,
+    };
+    subscriber
+        .create_datareader::<()>(
+            &topic,
+            QosKind::Specific(reader_qos.clone()),
+            Some(reader_listener),
+            &[StatusKind::DataAvailable],
+        )
+        .await?;
+    Ok(())
+}
+
+// Matches the topic name with a pattern in which '*' matches any sequence of characters and '?' matches any
+// single character
+fn matches_topic_name_pattern(pattern: &str, topic_name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let topic_name: Vec<char> = topic_name.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut last_wildcard: Option<(usize, usize)> = None;
+    while t < topic_name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                last_wildcard = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == topic_name[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match last_wildcard {
+                Some((wildcard_p, wildcard_t)) => {
+                    last_wildcard = Some((wildcard_p, wildcard_t + 1));
+                    p = wildcard_p + 1;
+                    t = wildcard_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+pub struct WildcardSubscriptionPublicationsListener<R: DdsRuntime> {
+    pub mail_sender: R::ChannelSender<WildcardSubscriptionMail<R>>,
+}
+
+impl<R: DdsRuntime> DataReaderListener<R, PublicationBuiltinTopicData>
+    for WildcardSubscriptionPublicationsListener<R>
+{
+    async fn on_data_available(
+        &mut self,
+        the_reader: DataReaderAsync<R, PublicationBuiltinTopicData>,
+    ) {
+        self.mail_sender
+            .send(WildcardSubscriptionMail::PublicationsAvailable { the_reader })
+            .await
+            .ok();
+    }
+}
+
+struct WildcardSubscriptionReaderListener<R: DdsRuntime> {
+    topic_name: String,
+    mail_sender: R::ChannelSender<WildcardSubscriptionMail<R>>,
+}
+
+impl<R: DdsRuntime> DataReaderListener<R, ()> for WildcardSubscriptionReaderListener<R> {
+    async fn on_data_available(&mut self, the_reader: DataReaderAsync<R, ()>) {
+        self.mail_sender
+            .send(WildcardSubscriptionMail::DataAvailable {
+                topic_name: self.topic_name.clone(),
+                the_reader,
+            })
+            .await
+            .ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_name_pattern_matching() {
+        assert!(matches_topic_name_pattern("Square", "Square"));
+        assert!(!matches_topic_name_pattern("Square", "Circle"));
+        assert!(matches_topic_name_pattern("*", "Square"));
+        assert!(matches_topic_name_pattern("rt/*", "rt/chatter"));
+        assert!(!matches_topic_name_pattern("rt/*", "rq/chatter"));
+        assert!(matches_topic_name_pattern("*/chatter", "rt/chatter"));
+        assert!(matches_topic_name_pattern(
+            "rt/*/status",
+            "rt/robot/arm/status"
+        ));
+        assert!(!matches_topic_name_pattern(
+            "rt/*/status",
+            "rt/robot/arm/state"
+        ));
+        assert!(matches_topic_name_pattern("Sq?are", "Square"));
+        assert!(!matches_topic_name_pattern("Sq?are", "Sqare"));
+        assert!(!matches_topic_name_pattern("Square", "SquareTopic"));
+    }
+}
@@ -8,9 +8,11 @@ pub mod domain_participant_actor_mail;
 pub mod domain_participant_factory_actor;
 pub mod handle;
 pub mod listeners;
+pub mod multitopic;
 pub mod publisher;
 pub mod status_condition_actor;
 pub mod subscriber;
+pub mod task_monitor;
 pub mod topic;
 
 /// Contains the built-in topics used by the service to propagate information needed for discovery and other data.
@@ -0,0 +1,658 @@
+use super::xtypes_glue::sample_members::{is_final_type, MemberValue};
+use crate::{
+    infrastructure::error::{DdsError, DdsResult},
+    xtypes::dynamic_type::DynamicType,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+/// Members of a sample by name.
+pub type SampleMembers = Vec<(String, MemberValue)>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Parameter(usize),
+    Literal(String),
+    Asterisk,
+    Comma,
+    Operator(ComparisonOperator),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl ComparisonOperator {
+    fn evaluate(&self, ordering: Ordering) -> bool {
+        match self {
+            ComparisonOperator::Equal => ordering == Ordering::Equal,
+            ComparisonOperator::NotEqual => ordering != Ordering::Equal,
+            ComparisonOperator::Less => ordering == Ordering::Less,
+            ComparisonOperator::LessOrEqual => ordering != Ordering::Greater,
+            ComparisonOperator::Greater => ordering == Ordering::Greater,
+            ComparisonOperator::GreaterOrEqual => ordering != Ordering::Less,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Field(String),
+    Parameter(usize),
+    Literal(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Predicate {
+    left: Operand,
+    operator: ComparisonOperator,
+    right: Operand,
+}
+
+fn tokenize(expression: &str) -> DdsResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut identifier = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_alphanumeric() || c == '_' || c == '.' {
+                    identifier.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Identifier(identifier));
+        } else if c.is_ascii_digit() || c == '-' || c == '+' {
+            let mut number = String::from(c);
+            chars.next();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    number.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Literal(number));
+        } else if c == '\'' {
+            chars.next();
+            let mut literal = String::new();
+            loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => literal.push(c),
+                    None => return Err(DdsError::BadParameter),
+                }
+            }
+            tokens.push(Token::Literal(literal));
+        } else if c == '%' {
+            chars.next();
+            let mut index = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_digit() {
+                    index.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Parameter(
+                index.parse().map_err(|_| DdsError::BadParameter)?,
+            ));
+        } else {
+            chars.next();
+            let token = match (c, chars.peek()) {
+                ('*', _) => Token::Asterisk,
+                (',', _) => Token::Comma,
+                ('=', _) => Token::Operator(ComparisonOperator::Equal),
+                ('<', Some('>')) => {
+                    chars.next();
+                    Token::Operator(ComparisonOperator::NotEqual)
+                }
+                ('<', Some('=')) => {
+                    chars.next();
+                    Token::Operator(ComparisonOperator::LessOrEqual)
+                }
+                ('<', _) => Token::Operator(ComparisonOperator::Less),
+                ('>', Some('=')) => {
+                    chars.next();
+                    Token::Operator(ComparisonOperator::GreaterOrEqual)
+                }
+                ('>', _) => Token::Operator(ComparisonOperator::Greater),
+                _ => return Err(DdsError::BadParameter),
+            };
+            tokens.push(token);
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Identifier(i)) if i.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> DdsResult<()> {
+        if self.is_keyword(keyword) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(DdsError::BadParameter)
+        }
+    }
+
+    fn identifier(&mut self) -> DdsResult<String> {
+        match self.next() {
+            Some(Token::Identifier(i)) => Ok(i),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn operand(&mut self) -> DdsResult<Operand> {
+        match self.next() {
+            Some(Token::Identifier(i)) => Ok(Operand::Field(i)),
+            Some(Token::Parameter(p)) => Ok(Operand::Parameter(p)),
+            Some(Token::Literal(l)) => Ok(Operand::Literal(l)),
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn condition(&mut self) -> DdsResult<Vec<Predicate>> {
+        let mut condition = Vec::new();
+        loop {
+            let left = self.operand()?;
+            let Some(Token::Operator(operator)) = self.next() else {
+                return Err(DdsError::BadParameter);
+            };
+            let right = self.operand()?;
+            condition.push(Predicate {
+                left,
+                operator,
+                right,
+            });
+            if self.is_keyword("AND") {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        Ok(condition)
+    }
+}
+
+fn validate_condition(
+    condition: &[Predicate],
+    member_names: &[String],
+    expression_parameters: &[String],
+) -> DdsResult<()> {
+    for operand in condition.iter().flat_map(|p| [&p.left, &p.right]) {
+        match operand {
+            Operand::Field(f) if !member_names.contains(f) => return Err(DdsError::BadParameter),
+            Operand::Parameter(p) if *p >= expression_parameters.len() => {
+                return Err(DdsError::BadParameter)
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Subscription expression of a MultiTopic as defined in Annex B of the DDS standard. The supported
+/// subset has the form `SELECT * | field [AS name] {, field [AS name]} FROM topic {NATURAL JOIN topic}
+/// [WHERE operand op operand {AND operand op operand}]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubscriptionExpression {
+    selection: Option<Vec<(String, String)>>,
+    related_topic_names: Vec<String>,
+    condition: Vec<Predicate>,
+}
+
+impl SubscriptionExpression {
+    pub fn parse(expression: &str) -> DdsResult<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+        };
+
+        parser.expect_keyword("SELECT")?;
+        let selection = if parser.peek() == Some(&Token::Asterisk) {
+            parser.next();
+            None
+        } else {
+            let mut selection = Vec::new();
+            loop {
+                let field = parser.identifier()?;
+                let name = if parser.is_keyword("AS") {
+                    parser.next();
+                    parser.identifier()?
+                } else {
+                    field.clone()
+                };
+                selection.push((field, name));
+                if parser.peek() == Some(&Token::Comma) {
+                    parser.next();
+                } else {
+                    break;
+                }
+            }
+            Some(selection)
+        };
+
+        parser.expect_keyword("FROM")?;
+        let mut related_topic_names = vec![parser.identifier()?];
+        while parser.is_keyword("NATURAL") || parser.is_keyword("INNER") {
+            if parser.is_keyword("INNER") {
+                parser.next();
+                parser.expect_keyword("NATURAL")?;
+            } else {
+                parser.next();
+                if parser.is_keyword("INNER") {
+                    parser.next();
+                }
+            }
+            parser.expect_keyword("JOIN")?;
+            related_topic_names.push(parser.identifier()?);
+        }
+
+        let condition = if parser.is_keyword("WHERE") {
+            parser.next();
+            parser.condition()?
+        } else {
+            Vec::new()
+        };
+
+        if parser.peek().is_some() {
+            return Err(DdsError::BadParameter);
+        }
+
+        Ok(Self {
+            selection,
+            related_topic_names,
+            condition,
+        })
+    }
+
+    pub fn related_topic_names(&self) -> &[String] {
+        &self.related_topic_names
+    }
+
+    /// Checks that the fields and parameters used by the expression exist given the names of the
+    /// members of each of the related topics and returns the names of the members of the resulting samples.
+    pub fn validate(
+        &self,
+        related_topic_member_names: &[Vec<String>],
+        expression_parameters: &[String],
+    ) -> DdsResult<Vec<String>> {
+        let mut joined_member_names: Vec<String> = Vec::new();
+        for name in related_topic_member_names.iter().flatten() {
+            if !joined_member_names.contains(name) {
+                joined_member_names.push(name.clone());
+            }
+        }
+        validate_condition(&self.condition, &joined_member_names, expression_parameters)?;
+        match &self.selection {
+            None => Ok(joined_member_names),
+            Some(selection) => {
+                if selection
+                    .iter()
+                    .any(|(field, _)| !joined_member_names.contains(field))
+                {
+                    return Err(DdsError::BadParameter);
+                }
+                Ok(selection.iter().map(|(_, name)| name.clone()).collect())
+            }
+        }
+    }
+
+    /// Name of the member of the related topics from which the given member of the resulting samples is taken.
+    pub fn source_member_name<'a>(&'a self, name: &'a str) -> &'a str {
+        match &self.selection {
+            None => name,
+            Some(selection) => selection
+                .iter()
+                .find(|(_, n)| n == name)
+                .map_or(name, |(field, _)| field.as_str()),
+        }
+    }
+
+    /// Combines the samples of each of the related topics, given in the same order as the topic names,
+    /// into the resulting samples. Samples are joined on the members which have the same name and the
+    /// combinations not fulfilling the condition of the expression are discarded.
+    pub fn join(
+        &self,
+        expression_parameters: &[String],
+        related_topic_samples: &[Vec<SampleMembers>],
+    ) -> Vec<SampleMembers> {
+        let mut joined_samples: Vec<SampleMembers> = vec![Vec::new()];
+        for samples in related_topic_samples {
+            let mut next_joined_samples = Vec::new();
+            for joined_sample in &joined_samples {
+                for sample in samples {
+                    if let Some(s) = natural_join(joined_sample, sample) {
+                        next_joined_samples.push(s);
+                    }
+                }
+            }
+            joined_samples = next_joined_samples;
+        }
+
+        joined_samples
+            .into_iter()
+            .filter(|s| {
+                self.condition
+                    .iter()
+                    .all(|p| evaluate_predicate(p, s, expression_parameters))
+            })
+            .map(|s| match &self.selection {
+                None => s,
+                Some(selection) => selection
+                    .iter()
+                    .filter_map(|(field, name)| {
+                        s.iter()
+                            .find(|(n, _)| n == field)
+                            .map(|(_, v)| (name.clone(), v.clone()))
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Query expression of a QueryCondition. It has the same syntax as the WHERE clause of a
+/// [`SubscriptionExpression`], that is `operand op operand {AND operand op operand}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryExpression {
+    condition: Vec<Predicate>,
+}
+
+impl QueryExpression {
+    pub fn parse(expression: &str) -> DdsResult<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            position: 0,
+        };
+        let condition = parser.condition()?;
+        if parser.peek().is_some() {
+            return Err(DdsError::BadParameter);
+        }
+        Ok(Self { condition })
+    }
+
+    /// Checks that the fields and parameters used by the expression exist given the names of the
+    /// members of the type of the samples.
+    pub fn validate(&self, member_names: &[String], query_parameters: &[String]) -> DdsResult<()> {
+        validate_condition(&self.condition, member_names, query_parameters)
+    }
+
+    pub fn evaluate(&self, sample: &SampleMembers, query_parameters: &[String]) -> bool {
+        self.condition
+            .iter()
+            .all(|p| evaluate_predicate(p, sample, query_parameters))
+    }
+}
+
+fn natural_join(left: &SampleMembers, right: &SampleMembers) -> Option<SampleMembers> {
+    let mut joined = left.clone();
+    for (name, value) in right {
+        match left.iter().find(|(n, _)| n == name) {
+            Some((_, v)) if v != value => return None,
+            Some(_) => (),
+            None => joined.push((name.clone(), value.clone())),
+        }
+    }
+    Some(joined)
+}
+
+fn operand_text(
+    operand: &Operand,
+    sample: &SampleMembers,
+    expression_parameters: &[String],
+) -> Option<String> {
+    match operand {
+        Operand::Field(f) => sample
+            .iter()
+            .find(|(n, _)| n == f)
+            .and_then(|(_, v)| v.to_text()),
+        Operand::Parameter(p) => expression_parameters.get(*p).map(|p| {
+            p.strip_prefix('\'')
+                .and_then(|p| p.strip_suffix('\''))
+                .unwrap_or(p)
+                .to_string()
+        }),
+        Operand::Literal(l) => Some(l.clone()),
+    }
+}
+
+fn evaluate_predicate(
+    predicate: &Predicate,
+    sample: &SampleMembers,
+    expression_parameters: &[String],
+) -> bool {
+    let (Some(left), Some(right)) = (
+        operand_text(&predicate.left, sample, expression_parameters),
+        operand_text(&predicate.right, sample, expression_parameters),
+    ) else {
+        return false;
+    };
+    // Values are compared numerically when both sides are numbers
+    let ordering = match (left.parse::<f64>(), right.parse::<f64>()) {
+        (Ok(l), Ok(r)) => l.partial_cmp(&r),
+        _ => Some(left.cmp(&right)),
+    };
+    ordering.is_some_and(|o| predicate.operator.evaluate(o))
+}
+
+/// Checks that the samples of the related topics can be combined into samples of the MultiTopic type. All
+/// the types must have final extensibility and each member of the MultiTopic type must be produced by the
+/// subscription expression from a member of the same kind.
+pub fn validate_multitopic_types(
+    subscription_expression: &SubscriptionExpression,
+    expression_parameters: &[String],
+    related_topic_types: &[&dyn DynamicType],
+    multitopic_type: &dyn DynamicType,
+) -> DdsResult<()> {
+    if !is_final_type(multitopic_type) || !related_topic_types.iter().all(|t| is_final_type(*t)) {
+        return Err(DdsError::PreconditionNotMet(
+            "MultiTopic can only combine types with final extensibility".to_string(),
+        ));
+    }
+
+    let mut related_topic_member_names = Vec::new();
+    for related_topic_type in related_topic_types {
+        let mut member_names = Vec::new();
+        for member_descriptor in related_topic_type.into_iter() {
+            member_names.push(member_descriptor?.name);
+        }
+        related_topic_member_names.push(member_names);
+    }
+    let produced_member_names =
+        subscription_expression.validate(&related_topic_member_names, expression_parameters)?;
+
+    for member_descriptor in multitopic_type.into_iter() {
+        let member_descriptor = member_descriptor?;
+        let source_member_name =
+            subscription_expression.source_member_name(&member_descriptor.name);
+        let mut source_member = None;
+        for related_topic_type in related_topic_types {
+            for d in related_topic_type.into_iter() {
+                let d = d?;
+                if source_member.is_none() && d.name == source_member_name {
+                    source_member = Some(d);
+                }
+            }
+        }
+        let is_produced = produced_member_names.contains(&member_descriptor.name)
+            && source_member.is_some_and(|s| {
+                s.type_.get_kind() == member_descriptor.type_.get_kind()
+                    && s.type_.get_name() == member_descriptor.type_.get_name()
+            });
+        if !is_produced {
+            return Err(DdsError::PreconditionNotMet(format!(
+                "MultiTopic member {} is not produced by the subscription expression",
+                member_descriptor.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Definition of a MultiTopic as given when it is created.
+pub struct MultiTopicDescription {
+    subscription_expression: SubscriptionExpression,
+    expression_parameters: Vec<String>,
+}
+
+impl MultiTopicDescription {
+    pub fn new(
+        subscription_expression: SubscriptionExpression,
+        expression_parameters: Vec<String>,
+    ) -> Self {
+        Self {
+            subscription_expression,
+            expression_parameters,
+        }
+    }
+
+    pub fn subscription_expression(&self) -> &SubscriptionExpression {
+        &self.subscription_expression
+    }
+
+    pub fn expression_parameters(&self) -> &[String] {
+        &self.expression_parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(members: &[(&str, MemberValue)]) -> SampleMembers {
+        members
+            .iter()
+            .map(|(n, v)| (String::from(*n), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_subscription_expression() {
+        let expression = SubscriptionExpression::parse(
+            "SELECT id, name AS label FROM Position NATURAL JOIN Label WHERE x > %0 AND name <> 'none'",
+        )
+        .unwrap();
+        assert_eq!(
+            expression.related_topic_names(),
+            &[String::from("Position"), String::from("Label")]
+        );
+        assert_eq!(
+            expression.validate(
+                &[
+                    vec![String::from("id"), String::from("x")],
+                    vec![String::from("id"), String::from("name")]
+                ],
+                &[String::from("1")]
+            ),
+            Ok(vec![String::from("id"), String::from("label")])
+        );
+        assert_eq!(expression.source_member_name("label"), "name");
+        assert_eq!(
+            expression.validate(&[vec![String::from("id"), String::from("x")]], &[]),
+            Err(DdsError::BadParameter)
+        );
+
+        assert!(SubscriptionExpression::parse("SELECT * FROM A INNER NATURAL JOIN B").is_ok());
+        assert_eq!(
+            SubscriptionExpression::parse("SELECT * FROM A JOIN B"),
+            Err(DdsError::BadParameter)
+        );
+        assert_eq!(
+            SubscriptionExpression::parse("SELECT FROM A"),
+            Err(DdsError::BadParameter)
+        );
+    }
+
+    #[test]
+    fn join_samples_on_common_members() {
+        let expression =
+            SubscriptionExpression::parse("SELECT * FROM A NATURAL JOIN B WHERE x >= %0").unwrap();
+        let a = vec![
+            sample(&[("id", MemberValue::Uint8(1)), ("x", MemberValue::Int32(10))]),
+            sample(&[("id", MemberValue::Uint8(2)), ("x", MemberValue::Int32(-5))]),
+            sample(&[("id", MemberValue::Uint8(3)), ("x", MemberValue::Int32(7))]),
+        ];
+        let b = vec![
+            sample(&[
+                ("id", MemberValue::Uint8(1)),
+                ("y", MemberValue::Boolean(true)),
+            ]),
+            sample(&[
+                ("id", MemberValue::Uint8(2)),
+                ("y", MemberValue::Boolean(false)),
+            ]),
+        ];
+
+        assert_eq!(
+            expression.join(&[String::from("0")], &[a, b]),
+            vec![sample(&[
+                ("id", MemberValue::Uint8(1)),
+                ("x", MemberValue::Int32(10)),
+                ("y", MemberValue::Boolean(true))
+            ])]
+        );
+    }
+
+    #[test]
+    fn evaluate_query_expression() {
+        let expression = QueryExpression::parse("x > %0 AND name = 'a'").unwrap();
+        let member_names = vec![String::from("x"), String::from("name")];
+        assert_eq!(
+            expression.validate(&member_names, &[String::from("5")]),
+            Ok(())
+        );
+        assert_eq!(
+            expression.validate(&member_names, &[]),
+            Err(DdsError::BadParameter)
+        );
+        assert_eq!(
+            QueryExpression::parse("SELECT * FROM A"),
+            Err(DdsError::BadParameter)
+        );
+
+        let s = sample(&[
+            ("x", MemberValue::Int32(10)),
+            ("name", MemberValue::String(String::from("a"))),
+        ]);
+        assert!(expression.evaluate(&s, &[String::from("5")]));
+        assert!(!expression.evaluate(&s, &[String::from("20")]));
+    }
+}
@@ -1,14 +1,15 @@
 use super::{
     data_writer::DataWriterEntity,
     infrastructure::{
-        error::DdsResult,
+        error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataWriterQos, PublisherQos},
+        qos_policy::PartitionQosPolicy,
         status::StatusKind,
     },
     listeners::domain_participant_listener::ListenerMail,
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use crate::runtime::DdsRuntime;
 
@@ -20,6 +21,8 @@ pub struct PublisherEntity<R: DdsRuntime> {
     default_datawriter_qos: DataWriterQos,
     listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     listener_mask: Vec<StatusKind>,
+    session_partition_list: Option<Vec<String>>,
+    coherent_changes_depth: u32,
 }
 
 impl<R: DdsRuntime> PublisherEntity<R> {
@@ -38,6 +41,8 @@ impl<R: DdsRuntime> PublisherEntity<R> {
             default_datawriter_qos: DataWriterQos::const_default(),
             listener_sender,
             listener_mask,
+            session_partition_list: None,
+            coherent_changes_depth: 0,
         }
     }
 
@@ -53,7 +58,10 @@ impl<R: DdsRuntime> PublisherEntity<R> {
         self.data_writer_list.drain(..)
     }
 
-    pub fn insert_data_writer(&mut self, data_writer: DataWriterEntity<R>) {
+    pub fn insert_data_writer(&mut self, mut data_writer: DataWriterEntity<R>) {
+        if self.is_coherent_set_open() {
+            data_writer.begin_coherent_set();
+        }
         self.data_writer_list.push(data_writer);
     }
 
@@ -120,6 +128,64 @@ impl<R: DdsRuntime> PublisherEntity<R> {
         Ok(())
     }
 
+    /// Partitions in which the data writers currently publish, made of the partitions of the QoS and the ones
+    /// added by an open partition session
+    pub fn partition(&self) -> PartitionQosPolicy {
+        let mut partition = self.qos.partition.clone();
+        for name in self.session_partition_list.iter().flatten() {
+            if !partition.name.contains(name) {
+                partition.name.push(name.clone());
+            }
+        }
+        partition
+    }
+
+    pub fn begin_partition_session(&mut self, partition_list: Vec<String>) -> DdsResult<()> {
+        if self.session_partition_list.is_some() {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "A partition session is already open on the publisher",
+            )));
+        }
+        self.session_partition_list = Some(partition_list);
+        Ok(())
+    }
+
+    pub fn end_partition_session(&mut self) -> DdsResult<()> {
+        match self.session_partition_list.take() {
+            Some(_) => Ok(()),
+            None => Err(DdsError::PreconditionNotMet(String::from(
+                "No partition session is open on the publisher",
+            ))),
+        }
+    }
+
+    /// Whether the changes written by the data writers currently belong to a coherent set. The sets are only
+    /// formed if the presentation QoS provides coherent access.
+    pub fn is_coherent_set_open(&self) -> bool {
+        self.coherent_changes_depth > 0 && self.qos.presentation.coherent_access
+    }
+
+    pub fn begin_coherent_changes(&mut self) {
+        self.coherent_changes_depth += 1;
+        if self.coherent_changes_depth == 1 && self.is_coherent_set_open() {
+            for data_writer in self.data_writer_list.iter_mut() {
+                data_writer.begin_coherent_set();
+            }
+        }
+    }
+
+    /// Returns whether the call terminated the outermost coherent set, after which the sets of the data
+    /// writers have to be ended.
+    pub fn end_coherent_changes(&mut self) -> DdsResult<bool> {
+        if self.coherent_changes_depth == 0 {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "No coherent set is open on the publisher",
+            )));
+        }
+        self.coherent_changes_depth -= 1;
+        Ok(self.coherent_changes_depth == 0)
+    }
+
     pub fn set_listener(
         &mut self,
         listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
@@ -130,7 +196,10 @@ impl<R: DdsRuntime> PublisherEntity<R> {
     }
 
     pub fn listener_mask(&self) -> &[StatusKind] {
-        &self.listener_mask
+        match self.listener_sender {
+            Some(_) => &self.listener_mask,
+            None => &[],
+        }
     }
 
     pub fn listener(&self) -> &Option<R::ChannelSender<ListenerMail<R>>> {
@@ -3,14 +3,14 @@ use core::marker::PhantomData;
 use crate::{
     dcps::actor::{Actor, MailHandler},
     infrastructure::status::StatusKind,
-    runtime::{DdsRuntime, OneshotSend},
+    runtime::{ChannelSend, DdsRuntime, OneshotSend},
 };
 use alloc::{vec, vec::Vec};
 
-#[derive(Debug)]
 pub struct StatusConditionActor<R: DdsRuntime> {
     enabled_statuses: Vec<StatusKind>,
     status_changes: Vec<StatusKind>,
+    trigger_waiters: Vec<(usize, R::ChannelSender<()>)>,
     phantom: PhantomData<R>,
 }
 
@@ -31,8 +31,11 @@ impl<R: DdsRuntime> Default for StatusConditionActor<R> {
                 StatusKind::LivelinessChanged,
                 StatusKind::PublicationMatched,
                 StatusKind::SubscriptionMatched,
+                StatusKind::WriterRestarted,
+                StatusKind::PublicationThrottled,
             ],
             status_changes: Vec::new(),
+            trigger_waiters: Vec::new(),
             phantom: PhantomData,
         }
     }
@@ -55,6 +58,18 @@ impl<R: DdsRuntime> StatusConditionActor<R> {
         self.enabled_statuses = mask;
     }
 
+    /// Communication statuses which changed since they were last read, independently of the
+    /// enabled statuses.
+    pub fn get_status_changes(&self) -> Vec<StatusKind> {
+        let mut status_changes = Vec::new();
+        for status in &self.status_changes {
+            if !status_changes.contains(status) {
+                status_changes.push(*status);
+            }
+        }
+        status_changes
+    }
+
     pub fn get_trigger_value(&self) -> bool {
         for status in &self.status_changes {
             if self.enabled_statuses.contains(status) {
@@ -63,6 +78,24 @@ impl<R: DdsRuntime> StatusConditionActor<R> {
         }
         false
     }
+
+    pub fn add_trigger_waiter(&mut self, waiter_id: usize, waiter: R::ChannelSender<()>) {
+        self.remove_trigger_waiter(waiter_id);
+        self.trigger_waiters.push((waiter_id, waiter));
+    }
+
+    pub fn remove_trigger_waiter(&mut self, waiter_id: usize) {
+        self.trigger_waiters.retain(|(id, _)| id != &waiter_id);
+    }
+
+    // The waiters are notified whenever a state is added since the read conditions
+    // of a reader are also re-evaluated on any change of its communication states.
+    // Each waiter is notified once and registers again if it keeps waiting.
+    async fn notify_trigger_waiters(&mut self) {
+        for (_, waiter) in self.trigger_waiters.drain(..) {
+            waiter.send(()).await.ok();
+        }
+    }
 }
 
 pub enum StatusConditionMail<R: DdsRuntime> {
@@ -75,12 +108,22 @@ pub enum StatusConditionMail<R: DdsRuntime> {
     GetStatusConditionTriggerValue {
         reply_sender: R::OneshotSender<bool>,
     },
+    GetStatusChanges {
+        reply_sender: R::OneshotSender<Vec<StatusKind>>,
+    },
     AddCommunicationState {
         state: StatusKind,
     },
     RemoveCommunicationState {
         state: StatusKind,
     },
+    AddTriggerWaiter {
+        waiter_id: usize,
+        waiter: R::ChannelSender<()>,
+    },
+    RemoveTriggerWaiter {
+        waiter_id: usize,
+    },
 }
 
 impl<R: DdsRuntime> MailHandler for StatusConditionActor<R> {
@@ -91,17 +134,28 @@ impl<R: DdsRuntime> MailHandler for StatusConditionActor<R> {
                 reply_sender.send(self.get_enabled_statuses())
             }
             StatusConditionMail::SetStatusConditionEnabledStatuses { status_mask } => {
-                self.set_enabled_statuses(status_mask)
+                self.set_enabled_statuses(status_mask);
+                self.notify_trigger_waiters().await;
             }
             StatusConditionMail::GetStatusConditionTriggerValue { reply_sender } => {
                 reply_sender.send(self.get_trigger_value())
             }
+            StatusConditionMail::GetStatusChanges { reply_sender } => {
+                reply_sender.send(self.get_status_changes())
+            }
             StatusConditionMail::AddCommunicationState { state } => {
-                self.add_communication_state(state)
+                self.add_communication_state(state);
+                self.notify_trigger_waiters().await;
             }
             StatusConditionMail::RemoveCommunicationState { state } => {
                 self.remove_communication_state(state)
             }
+            StatusConditionMail::AddTriggerWaiter { waiter_id, waiter } => {
+                self.add_trigger_waiter(waiter_id, waiter)
+            }
+            StatusConditionMail::RemoveTriggerWaiter { waiter_id } => {
+                self.remove_trigger_waiter(waiter_id)
+            }
         }
     }
 }
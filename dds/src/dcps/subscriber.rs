@@ -1,5 +1,5 @@
 use crate::{
-    dcps::data_reader::DataReaderEntity,
+    dcps::data_reader::{ContentFilter, DataReaderEntity},
     infrastructure::{
         error::DdsResult,
         instance::InstanceHandle,
@@ -7,14 +7,37 @@ use crate::{
         status::StatusKind,
     },
     runtime::DdsRuntime,
+    transport::{
+        history_cache::CacheChange,
+        types::{ChangeKind, Guid},
+    },
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use super::{
     actor::Actor, listeners::domain_participant_listener::ListenerMail,
     status_condition_actor::StatusConditionActor,
 };
 
+/// Result of the evaluation of a content filter for the last change on which it was evaluated. It is
+/// shared by all the readers of the subscriber using the same filter on the same topic.
+struct ContentFilterVerdict {
+    topic_name: String,
+    filter_expression: String,
+    filter_parameters: Vec<String>,
+    writer_guid: Guid,
+    sequence_number: i64,
+    passes: bool,
+}
+
+impl ContentFilterVerdict {
+    fn is_verdict_of(&self, topic_name: &str, content_filter: &ContentFilter) -> bool {
+        self.topic_name == topic_name
+            && self.filter_expression == content_filter.filter_expression()
+            && self.filter_parameters == content_filter.filter_parameters()
+    }
+}
+
 pub struct SubscriberEntity<R: DdsRuntime> {
     instance_handle: InstanceHandle,
     qos: SubscriberQos,
@@ -24,6 +47,7 @@ pub struct SubscriberEntity<R: DdsRuntime> {
     status_condition: Actor<R, StatusConditionActor<R>>,
     listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     listener_mask: Vec<StatusKind>,
+    content_filter_verdicts: Vec<ContentFilterVerdict>,
 }
 
 impl<R: DdsRuntime> SubscriberEntity<R> {
@@ -44,6 +68,7 @@ impl<R: DdsRuntime> SubscriberEntity<R> {
             status_condition,
             listener_sender,
             listener_mask,
+            content_filter_verdicts: Vec::new(),
         }
     }
 
@@ -68,7 +93,79 @@ impl<R: DdsRuntime> SubscriberEntity<R> {
             .data_reader_list
             .iter()
             .position(|x| x.instance_handle() == handle)?;
-        Some(self.data_reader_list.remove(index))
+        let data_reader = self.data_reader_list.remove(index);
+        self.remove_unused_content_filter_verdicts();
+        Some(data_reader)
+    }
+
+    /// Returns true if the change passes the content filter of the reader. The filter is evaluated
+    /// only once per change for all the readers of the subscriber using the same filter on the
+    /// same topic.
+    pub fn content_filter_passes(
+        &mut self,
+        data_reader_handle: InstanceHandle,
+        cache_change: &CacheChange,
+    ) -> bool {
+        if cache_change.kind != ChangeKind::Alive {
+            return true;
+        }
+        let Some(data_reader) = self
+            .data_reader_list
+            .iter()
+            .find(|x| x.instance_handle() == data_reader_handle)
+        else {
+            return true;
+        };
+        let Some(content_filter) = data_reader.content_filter() else {
+            return true;
+        };
+        let topic_name = data_reader.topic_name();
+        let is_same_change = |v: &ContentFilterVerdict| {
+            v.writer_guid == cache_change.writer_guid
+                && v.sequence_number == cache_change.sequence_number
+        };
+        match self
+            .content_filter_verdicts
+            .iter_mut()
+            .find(|v| v.is_verdict_of(topic_name, content_filter))
+        {
+            Some(verdict) if is_same_change(verdict) => verdict.passes,
+            Some(verdict) => {
+                verdict.passes = content_filter.evaluate(
+                    cache_change.data_value.as_ref(),
+                    data_reader.type_support().as_ref(),
+                );
+                verdict.writer_guid = cache_change.writer_guid;
+                verdict.sequence_number = cache_change.sequence_number;
+                verdict.passes
+            }
+            None => {
+                let passes = content_filter.evaluate(
+                    cache_change.data_value.as_ref(),
+                    data_reader.type_support().as_ref(),
+                );
+                self.content_filter_verdicts.push(ContentFilterVerdict {
+                    topic_name: String::from(topic_name),
+                    filter_expression: String::from(content_filter.filter_expression()),
+                    filter_parameters: content_filter.filter_parameters().to_vec(),
+                    writer_guid: cache_change.writer_guid,
+                    sequence_number: cache_change.sequence_number,
+                    passes,
+                });
+                passes
+            }
+        }
+    }
+
+    /// Removes the verdicts of the content filters which are no longer used by any reader.
+    pub fn remove_unused_content_filter_verdicts(&mut self) {
+        let data_reader_list = &self.data_reader_list;
+        self.content_filter_verdicts.retain(|v| {
+            data_reader_list.iter().any(|r| {
+                r.content_filter()
+                    .is_some_and(|f| v.is_verdict_of(r.topic_name(), f))
+            })
+        });
     }
 
     pub fn get_data_reader(&self, handle: InstanceHandle) -> Option<&DataReaderEntity<R>> {
@@ -141,6 +238,9 @@ impl<R: DdsRuntime> SubscriberEntity<R> {
     }
 
     pub fn listener_mask(&self) -> &[StatusKind] {
-        &self.listener_mask
+        match self.listener_sender {
+            Some(_) => &self.listener_mask,
+            None => &[],
+        }
     }
 }
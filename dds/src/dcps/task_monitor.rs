@@ -0,0 +1,466 @@
+use super::{
+    domain_participant_actor_mail::{DiscoveryServiceMail, DomainParticipantMail},
+    listeners::domain_participant_listener::ListenerMail,
+};
+use crate::{
+    domain::task_health::{ParticipantTaskKind, StalledTask, TaskHealthStatus},
+    infrastructure::time::Time,
+    runtime::{ChannelSend, Clock, DdsRuntime, Spawner, Timer},
+    transport::participant::{TransportTaskKind, TransportTaskObserver},
+};
+use alloc::{sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
+
+const IDLE: i64 = i64::MIN;
+const NANOSEC_PER_SEC: i64 = 1_000_000_000;
+const TASK_KIND_COUNT: usize = ParticipantTaskKind::LIST.len();
+
+fn time_to_nanos(time: Time) -> i64 {
+    time.sec() as i64 * NANOSEC_PER_SEC + time.nanosec() as i64
+}
+
+fn nanos_to_time(nanos: i64) -> Time {
+    Time::new(
+        nanos.div_euclid(NANOSEC_PER_SEC) as i32,
+        nanos.rem_euclid(NANOSEC_PER_SEC) as u32,
+    )
+}
+
+fn duration_to_nanos(duration: core::time::Duration) -> i64 {
+    i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX)
+}
+
+impl From<TransportTaskKind> for ParticipantTaskKind {
+    fn from(value: TransportTaskKind) -> Self {
+        match value {
+            TransportTaskKind::Receiver => ParticipantTaskKind::TransportReceiver,
+            TransportTaskKind::Publisher => ParticipantTaskKind::TransportPublisher,
+        }
+    }
+}
+
+/// Progress record of the tasks running on behalf of a participant. The tasks record their progress with atomic
+/// operations only so that the health can be evaluated while any of them, including the participant actor, is
+/// stalled.
+pub struct TaskMonitor {
+    stall_threshold: i64,
+    announcement_interval: core::time::Duration,
+    restart_stalled_tasks: bool,
+    check_period: i64,
+    // Start of the event being processed by each of the tasks which only progress when they have events to process
+    processing_start: [AtomicI64; TASK_KIND_COUNT],
+    last_announcement: AtomicI64,
+    last_timer_tick: AtomicI64,
+    announcement_generation: AtomicU32,
+    watchdog_generation: AtomicU32,
+    stalled: [AtomicBool; TASK_KIND_COUNT],
+    total_stall_count: AtomicU32,
+    restart_count: AtomicU32,
+    stopped: AtomicBool,
+}
+
+impl TaskMonitor {
+    pub fn new(
+        stall_threshold: core::time::Duration,
+        announcement_interval: core::time::Duration,
+        restart_stalled_tasks: bool,
+        now: Time,
+    ) -> Self {
+        let now = time_to_nanos(now);
+        Self {
+            stall_threshold: duration_to_nanos(stall_threshold),
+            announcement_interval,
+            restart_stalled_tasks,
+            check_period: duration_to_nanos(stall_threshold / 2),
+            processing_start: core::array::from_fn(|_| AtomicI64::new(IDLE)),
+            last_announcement: AtomicI64::new(now),
+            last_timer_tick: AtomicI64::new(now),
+            announcement_generation: AtomicU32::new(0),
+            watchdog_generation: AtomicU32::new(0),
+            stalled: core::array::from_fn(|_| AtomicBool::new(false)),
+            total_stall_count: AtomicU32::new(0),
+            restart_count: AtomicU32::new(0),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.stall_threshold > 0
+    }
+
+    pub fn check_period(&self) -> core::time::Duration {
+        core::time::Duration::from_nanos(self.check_period as u64)
+    }
+
+    pub fn announcement_interval(&self) -> core::time::Duration {
+        self.announcement_interval
+    }
+
+    pub fn begin_processing(&self, task_kind: ParticipantTaskKind, now: Time) {
+        self.processing_start[task_kind as usize].store(time_to_nanos(now), Ordering::Relaxed);
+    }
+
+    pub fn end_processing(&self, task_kind: ParticipantTaskKind) {
+        self.processing_start[task_kind as usize].store(IDLE, Ordering::Relaxed);
+    }
+
+    pub fn announcement_generation(&self) -> u32 {
+        self.announcement_generation.load(Ordering::Relaxed)
+    }
+
+    pub fn is_current_announcement(&self, generation: u32) -> bool {
+        !self.is_stopped() && self.announcement_generation() == generation
+    }
+
+    pub fn record_announcement(&self, now: Time) {
+        self.last_announcement
+            .store(time_to_nanos(now), Ordering::Relaxed);
+    }
+
+    /// Replaces the announcement task by a new generation and returns it. The previous task exits as soon as it
+    /// resumes.
+    pub fn restart_announcement(&self, now: Time) -> u32 {
+        self.restart_count.fetch_add(1, Ordering::Relaxed);
+        self.record_announcement(now);
+        self.stalled[ParticipantTaskKind::ParticipantAnnouncement as usize]
+            .store(false, Ordering::Relaxed);
+        self.announcement_generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Replaces the watchdog by a new generation and returns it. The previous watchdog exits as soon as it resumes.
+    pub fn restart_watchdog(&self) -> u32 {
+        self.watchdog_generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn is_current_watchdog(&self, generation: u32) -> bool {
+        !self.is_stopped() && self.watchdog_generation.load(Ordering::Relaxed) == generation
+    }
+
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Evaluates the tasks on a tick of the watchdog, counting the ones which became stalled since the previous
+    /// tick, and returns the stalled tasks together with whether any of them became stalled on this tick. The timer
+    /// task is evaluated before recording the tick so that a late tick is reported.
+    pub fn check(&self, now: Time) -> (Vec<StalledTask>, bool) {
+        let stalled_task_list = self.stalled_tasks(now);
+        self.last_timer_tick
+            .store(time_to_nanos(now), Ordering::Relaxed);
+
+        let mut new_stall = false;
+        for task_kind in ParticipantTaskKind::LIST {
+            let is_stalled = stalled_task_list.iter().any(|t| t.task_kind == task_kind);
+            let was_stalled = self.stalled[task_kind as usize].swap(is_stalled, Ordering::Relaxed);
+            if is_stalled && !was_stalled {
+                self.total_stall_count.fetch_add(1, Ordering::Relaxed);
+                new_stall = true;
+            }
+        }
+        (stalled_task_list, new_stall)
+    }
+
+    pub fn task_health_status(&self, now: Time) -> TaskHealthStatus {
+        TaskHealthStatus {
+            stalled_task_list: self.stalled_tasks(now),
+            total_stall_count: self.total_stall_count.load(Ordering::Relaxed),
+            restart_count: self.restart_count.load(Ordering::Relaxed),
+        }
+    }
+
+    fn stalled_tasks(&self, now: Time) -> Vec<StalledTask> {
+        let mut stalled_task_list = Vec::new();
+        if !self.is_enabled() {
+            return stalled_task_list;
+        }
+        let now = time_to_nanos(now);
+        for task_kind in ParticipantTaskKind::LIST {
+            let (progress, expected_period) = match task_kind {
+                ParticipantTaskKind::ParticipantAnnouncement => (
+                    self.last_announcement.load(Ordering::Relaxed),
+                    duration_to_nanos(self.announcement_interval),
+                ),
+                ParticipantTaskKind::Timer => (
+                    self.last_timer_tick.load(Ordering::Relaxed),
+                    self.check_period,
+                ),
+                ParticipantTaskKind::MessageProcessing
+                | ParticipantTaskKind::TransportReceiver
+                | ParticipantTaskKind::TransportPublisher => (
+                    self.processing_start[task_kind as usize].load(Ordering::Relaxed),
+                    0,
+                ),
+            };
+            if progress != IDLE
+                && now.saturating_sub(progress)
+                    > expected_period.saturating_add(self.stall_threshold)
+            {
+                stalled_task_list.push(StalledTask {
+                    task_kind,
+                    stalled_since: nanos_to_time(progress),
+                });
+            }
+        }
+        stalled_task_list
+    }
+}
+
+/// Reports the progress of the tasks of the transport of a participant to its [`TaskMonitor`].
+pub struct TransportTaskMonitor<C> {
+    task_monitor: Arc<TaskMonitor>,
+    clock_handle: C,
+}
+
+impl<C> TransportTaskMonitor<C> {
+    pub fn new(task_monitor: Arc<TaskMonitor>, clock_handle: C) -> Self {
+        Self {
+            task_monitor,
+            clock_handle,
+        }
+    }
+}
+
+impl<C: Clock + Send + Sync> TransportTaskObserver for TransportTaskMonitor<C> {
+    fn on_task_busy(&self, task_kind: TransportTaskKind) {
+        self.task_monitor
+            .begin_processing(task_kind.into(), self.clock_handle.now());
+    }
+
+    fn on_task_idle(&self, task_kind: TransportTaskKind) {
+        self.task_monitor.end_processing(task_kind.into());
+    }
+}
+
+pub async fn participant_announcement_task<R: DdsRuntime>(
+    participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    mut timer_handle: R::TimerHandle,
+    clock_handle: R::ClockHandle,
+    task_monitor: Arc<TaskMonitor>,
+    generation: u32,
+) {
+    // A task replaced by the watchdog exits the next time it resumes
+    while task_monitor.is_current_announcement(generation)
+        && participant_address
+            .send(DomainParticipantMail::Discovery(
+                DiscoveryServiceMail::AnnounceParticipant,
+            ))
+            .await
+            .is_ok()
+    {
+        task_monitor.record_announcement(clock_handle.now());
+        timer_handle
+            .delay(task_monitor.announcement_interval())
+            .await;
+    }
+}
+
+/// Watchdog evaluating the tasks of the participant periodically. The participant listener is notified directly
+/// by the watchdog when a task becomes stalled since the participant actor might be the one which is stalled. The
+/// watchdog is restarted with a new generation when the listener changes.
+pub async fn task_watchdog<R: DdsRuntime>(
+    participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    spawner_handle: R::SpawnerHandle,
+    mut timer_handle: R::TimerHandle,
+    clock_handle: R::ClockHandle,
+    task_monitor: Arc<TaskMonitor>,
+    listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
+    generation: u32,
+) {
+    while task_monitor.is_current_watchdog(generation) {
+        timer_handle.delay(task_monitor.check_period()).await;
+        if !task_monitor.is_current_watchdog(generation) {
+            break;
+        }
+        let now = clock_handle.now();
+        let (stalled_task_list, new_stall) = task_monitor.check(now);
+        let is_stalled = |task_kind| stalled_task_list.iter().any(|t| t.task_kind == task_kind);
+
+        if new_stall {
+            if let Some(l) = &listener_sender {
+                l.send(ListenerMail::TaskStalled {
+                    status: task_monitor.task_health_status(now),
+                })
+                .await
+                .ok();
+            }
+        }
+
+        // The announcements go through the participant actor so restarting the task only helps if the actor
+        // is processing its messages. Otherwise every restart would add a task waiting on the actor.
+        if task_monitor.restart_stalled_tasks
+            && is_stalled(ParticipantTaskKind::ParticipantAnnouncement)
+            && !is_stalled(ParticipantTaskKind::MessageProcessing)
+        {
+            let generation = task_monitor.restart_announcement(now);
+            spawner_handle.spawn(participant_announcement_task::<R>(
+                participant_address.clone(),
+                timer_handle.clone(),
+                clock_handle.clone(),
+                task_monitor.clone(),
+                generation,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: core::time::Duration = core::time::Duration::from_secs(2);
+    const ANNOUNCEMENT_INTERVAL: core::time::Duration = core::time::Duration::from_secs(5);
+
+    #[test]
+    fn message_processing_stall_is_reported_and_counted_once() {
+        let monitor = TaskMonitor::new(THRESHOLD, ANNOUNCEMENT_INTERVAL, false, Time::new(10, 0));
+        monitor.begin_processing(ParticipantTaskKind::MessageProcessing, Time::new(10, 0));
+
+        assert_eq!(monitor.check(Time::new(11, 0)), (vec![], false));
+        let stalled_task_list = vec![StalledTask {
+            task_kind: ParticipantTaskKind::MessageProcessing,
+            stalled_since: Time::new(10, 0),
+        }];
+        assert_eq!(
+            monitor.check(Time::new(12, 500_000_000)),
+            (stalled_task_list.clone(), true)
+        );
+        assert_eq!(monitor.check(Time::new(13, 0)), (stalled_task_list, false));
+        assert_eq!(
+            monitor
+                .task_health_status(Time::new(13, 0))
+                .total_stall_count,
+            1
+        );
+
+        monitor.end_processing(ParticipantTaskKind::MessageProcessing);
+        let status = monitor.task_health_status(Time::new(13, 0));
+        assert!(status.stalled_task_list.is_empty());
+        assert_eq!(status.total_stall_count, 1);
+    }
+
+    #[test]
+    fn late_watchdog_tick_reports_timer_stall() {
+        let monitor = TaskMonitor::new(THRESHOLD, ANNOUNCEMENT_INTERVAL, false, Time::new(0, 0));
+        monitor.check(Time::new(1, 0));
+
+        let (stalled_task_list, _) = monitor.check(Time::new(5, 0));
+        assert_eq!(stalled_task_list.len(), 1);
+        assert_eq!(stalled_task_list[0].task_kind, ParticipantTaskKind::Timer);
+        assert!(monitor.check(Time::new(6, 0)).0.is_empty());
+    }
+
+    #[test]
+    fn restarted_announcement_replaces_previous_generation() {
+        let monitor = TaskMonitor::new(THRESHOLD, ANNOUNCEMENT_INTERVAL, false, Time::new(0, 0));
+        let first_generation = monitor.announcement_generation();
+
+        let (stalled_task_list, _) = monitor.check(Time::new(8, 0));
+        assert!(stalled_task_list
+            .iter()
+            .any(|t| t.task_kind == ParticipantTaskKind::ParticipantAnnouncement));
+
+        let second_generation = monitor.restart_announcement(Time::new(8, 0));
+        assert!(!monitor.is_current_announcement(first_generation));
+        assert!(monitor.is_current_announcement(second_generation));
+        let status = monitor.task_health_status(Time::new(8, 0));
+        assert!(status.stalled_task_list.is_empty());
+        assert_eq!(status.restart_count, 1);
+    }
+
+    #[test]
+    fn disabled_monitor_reports_nothing() {
+        let monitor = TaskMonitor::new(
+            core::time::Duration::ZERO,
+            ANNOUNCEMENT_INTERVAL,
+            false,
+            Time::new(0, 0),
+        );
+        monitor.begin_processing(ParticipantTaskKind::MessageProcessing, Time::new(0, 0));
+        assert_eq!(
+            monitor.task_health_status(Time::new(100, 0)),
+            TaskHealthStatus::default()
+        );
+    }
+
+    #[test]
+    fn transport_task_is_stalled_while_processing_an_event() {
+        struct TestClock(Time);
+        impl Clock for TestClock {
+            fn now(&self) -> Time {
+                self.0
+            }
+        }
+        let monitor = Arc::new(TaskMonitor::new(
+            THRESHOLD,
+            ANNOUNCEMENT_INTERVAL,
+            false,
+            Time::new(0, 0),
+        ));
+        let transport_task_monitor =
+            TransportTaskMonitor::new(monitor.clone(), TestClock(Time::new(1, 0)));
+
+        transport_task_monitor.on_task_busy(TransportTaskKind::Receiver);
+        assert_eq!(monitor.check(Time::new(2, 0)), (vec![], false));
+        let (stalled_task_list, new_stall) = monitor.check(Time::new(4, 0));
+        assert_eq!(
+            stalled_task_list,
+            vec![StalledTask {
+                task_kind: ParticipantTaskKind::TransportReceiver,
+                stalled_since: Time::new(1, 0),
+            }]
+        );
+        assert!(new_stall);
+
+        transport_task_monitor.on_task_idle(TransportTaskKind::Receiver);
+        assert_eq!(monitor.check(Time::new(5, 0)), (vec![], false));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn watchdog_notifies_the_listener_when_a_task_becomes_stalled() {
+        use crate::{
+            runtime::ChannelReceive,
+            std_runtime::{executor::Executor, timer::TimerDriver, StdRuntime},
+        };
+
+        let runtime = StdRuntime::new(Executor::new(), TimerDriver::new());
+        let monitor = Arc::new(TaskMonitor::new(
+            core::time::Duration::from_millis(100),
+            ANNOUNCEMENT_INTERVAL,
+            false,
+            runtime.clock().now(),
+        ));
+        let (participant_sender, _participant_receiver) = StdRuntime::channel();
+        let (listener_sender, mut listener_receiver) = StdRuntime::channel();
+        runtime.spawner().spawn(task_watchdog::<StdRuntime>(
+            participant_sender,
+            runtime.spawner(),
+            runtime.timer(),
+            runtime.clock(),
+            monitor.clone(),
+            Some(listener_sender),
+            monitor.restart_watchdog(),
+        ));
+
+        monitor.begin_processing(
+            ParticipantTaskKind::TransportPublisher,
+            runtime.clock().now(),
+        );
+        let Some(ListenerMail::TaskStalled { status }) =
+            StdRuntime::block_on(listener_receiver.receive())
+        else {
+            panic!("Listener should be notified of the stalled task")
+        };
+        assert_eq!(status.stalled_task_list.len(), 1);
+        assert_eq!(
+            status.stalled_task_list[0].task_kind,
+            ParticipantTaskKind::TransportPublisher
+        );
+        assert_eq!(status.total_stall_count, 1);
+        monitor.stop();
+    }
+}
@@ -2,9 +2,11 @@ use alloc::sync::Arc;
 
 use super::{
     actor::Actor, listeners::domain_participant_listener::ListenerMail,
-    status_condition::StatusCondition, status_condition_actor::StatusConditionActor,
+    multitopic::MultiTopicDescription, status_condition::StatusCondition,
+    status_condition_actor::StatusConditionActor,
 };
 use crate::{
+    domain::bandwidth_statistics::BandwidthQuota,
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
@@ -27,6 +29,9 @@ pub struct TopicEntity<R: DdsRuntime> {
     _listener_sender: Option<R::ChannelSender<ListenerMail<R>>>,
     _status_kind: Vec<StatusKind>,
     type_support: Arc<dyn DynamicType + Send + Sync>,
+    bandwidth_quota: Option<BandwidthQuota>,
+    multitopic: Option<MultiTopicDescription>,
+    reference_count: usize,
 }
 
 impl<R: DdsRuntime> TopicEntity<R> {
@@ -52,6 +57,9 @@ impl<R: DdsRuntime> TopicEntity<R> {
             _listener_sender: listener_sender,
             _status_kind: status_kind,
             type_support,
+            bandwidth_quota: None,
+            multitopic: None,
+            reference_count: 1,
         }
     }
 
@@ -59,6 +67,18 @@ impl<R: DdsRuntime> TopicEntity<R> {
         self.enabled = true;
     }
 
+    pub fn reference_count(&self) -> usize {
+        self.reference_count
+    }
+
+    pub fn increment_reference_count(&mut self) {
+        self.reference_count += 1;
+    }
+
+    pub fn decrement_reference_count(&mut self) {
+        self.reference_count = self.reference_count.saturating_sub(1);
+    }
+
     pub fn enabled(&mut self) -> bool {
         self.enabled
     }
@@ -87,6 +107,23 @@ impl<R: DdsRuntime> TopicEntity<R> {
         &self.qos
     }
 
+    pub fn set_multitopic(&mut self, multitopic: MultiTopicDescription) {
+        self.multitopic = Some(multitopic);
+    }
+
+    /// Definition of the topic if it is a MultiTopic combining the samples of other topics.
+    pub fn multitopic(&self) -> Option<&MultiTopicDescription> {
+        self.multitopic.as_ref()
+    }
+
+    pub fn bandwidth_quota(&self) -> Option<&BandwidthQuota> {
+        self.bandwidth_quota.as_ref()
+    }
+
+    pub fn set_bandwidth_quota(&mut self, bandwidth_quota: Option<BandwidthQuota>) {
+        self.bandwidth_quota = bandwidth_quota;
+    }
+
     pub fn set_qos(&mut self, qos: TopicQos) -> DdsResult<()> {
         qos.is_consistent()?;
 
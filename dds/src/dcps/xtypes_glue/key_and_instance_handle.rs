@@ -362,6 +362,9 @@ pub fn get_instance_handle_from_serialized_foo(
         length: 0,
     };
     {
+        if data.len() < 4 {
+            return Err(XTypesError::InvalidData);
+        }
         let representation_identifier = [data[0], data[1]];
         data = &data[4..];
         let mut serializer = Xcdr2BeSerializer::new(&mut md5_collection);
@@ -373,7 +376,8 @@ pub fn get_instance_handle_from_serialized_foo(
             CDR2_LE => push_to_key(dynamic_type, &mut s, &mut Xcdr2LeDeserializer::new(data))?,
             PL_CDR_BE => push_to_key_parameter_list_be(dynamic_type, &mut s, data)?,
             PL_CDR_LE => push_to_key_parameter_list_le(dynamic_type, &mut s, data)?,
-            _ => panic!("representation_identifier not supported"),
+            // The data written with write_serialized is not produced by the type support
+            _ => return Err(XTypesError::InvalidData),
         }
     }
     Ok(InstanceHandle::new(md5_collection.into_key()))
@@ -385,6 +389,9 @@ pub fn get_serialized_key_from_serialized_foo(
 ) -> Result<Vec<u8>, XTypesError> {
     let mut collection = Vec::new();
     {
+        if data.len() < 4 {
+            return Err(XTypesError::InvalidData);
+        }
         let representation_identifier = [data[0], data[1]];
         collection.extend_from_slice(&CDR_LE);
         collection.extend_from_slice(&[0, 0]);
@@ -400,7 +407,7 @@ pub fn get_serialized_key_from_serialized_foo(
             CDR2_LE => push_to_key(dynamic_type, &mut s, &mut Xcdr2LeDeserializer::new(data))?,
             PL_CDR_BE => push_to_key_parameter_list_be(dynamic_type, &mut s, data)?,
             PL_CDR_LE => push_to_key_parameter_list_le(dynamic_type, &mut s, data)?,
-            _ => panic!("representation_identifier not supported"),
+            _ => return Err(XTypesError::InvalidData),
         }
     }
     let padding_len = collection.len().div_ceil(4) * 4 - collection.len();
@@ -1,2 +1,3 @@
 pub mod key_and_instance_handle;
 pub mod dynamic_type;
+pub mod sample_members;
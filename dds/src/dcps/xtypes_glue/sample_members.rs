@@ -0,0 +1,386 @@
+use crate::xtypes::{
+    deserializer::{DeserializeSequence, XTypesDeserializer},
+    dynamic_type::{DynamicType, ExtensibilityKind},
+    error::XTypesError,
+    serialize::{XTypesSerialize, XTypesSerializer},
+    serializer::{SerializeCollection, SerializeFinalStruct},
+    type_object::TypeIdentifier,
+    xcdr_deserializer::{
+        Xcdr1BeDeserializer, Xcdr1LeDeserializer, Xcdr2BeDeserializer, Xcdr2LeDeserializer,
+    },
+    xcdr_serializer::Xcdr1LeSerializer,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Value of a member of a sample decoded without knowledge of the Rust type it was created from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberValue {
+    /// Boolean value.
+    Boolean(bool),
+    /// 8-bit signed integer value.
+    Int8(i8),
+    /// 16-bit signed integer value.
+    Int16(i16),
+    /// 32-bit signed integer value.
+    Int32(i32),
+    /// 64-bit signed integer value.
+    Int64(i64),
+    /// 8-bit unsigned integer value.
+    Uint8(u8),
+    /// 16-bit unsigned integer value.
+    Uint16(u16),
+    /// 32-bit unsigned integer value.
+    Uint32(u32),
+    /// 64-bit unsigned integer value.
+    Uint64(u64),
+    /// Single precision floating point value.
+    Float32(f32),
+    /// Double precision floating point value.
+    Float64(f64),
+    /// Character value.
+    Char8(char),
+    /// String value.
+    String(String),
+    /// Elements of a sequence.
+    Sequence(Vec<MemberValue>),
+    /// Elements of an array.
+    Array(Vec<MemberValue>),
+    /// Values of the members of a structure in the order in which they are declared.
+    Struct(Vec<MemberValue>),
+}
+
+impl MemberValue {
+    /// Textual representation of the value used to compare it with the parameters and literals
+    /// of an expression. Only primitive and string values have a textual representation.
+    pub fn to_text(&self) -> Option<String> {
+        match self {
+            MemberValue::Boolean(v) => Some(v.to_string()),
+            MemberValue::Int8(v) => Some(v.to_string()),
+            MemberValue::Int16(v) => Some(v.to_string()),
+            MemberValue::Int32(v) => Some(v.to_string()),
+            MemberValue::Int64(v) => Some(v.to_string()),
+            MemberValue::Uint8(v) => Some(v.to_string()),
+            MemberValue::Uint16(v) => Some(v.to_string()),
+            MemberValue::Uint32(v) => Some(v.to_string()),
+            MemberValue::Uint64(v) => Some(v.to_string()),
+            MemberValue::Float32(v) => Some(v.to_string()),
+            MemberValue::Float64(v) => Some(v.to_string()),
+            MemberValue::Char8(v) => Some(v.to_string()),
+            MemberValue::String(v) => Some(v.clone()),
+            MemberValue::Sequence(_) | MemberValue::Array(_) | MemberValue::Struct(_) => None,
+        }
+    }
+}
+
+impl XTypesSerialize for MemberValue {
+    fn serialize(&self, serializer: impl XTypesSerializer) -> Result<(), XTypesError> {
+        match self {
+            MemberValue::Boolean(v) => serializer.serialize_boolean(*v),
+            MemberValue::Int8(v) => serializer.serialize_int8(*v),
+            MemberValue::Int16(v) => serializer.serialize_int16(*v),
+            MemberValue::Int32(v) => serializer.serialize_int32(*v),
+            MemberValue::Int64(v) => serializer.serialize_int64(*v),
+            MemberValue::Uint8(v) => serializer.serialize_uint8(*v),
+            MemberValue::Uint16(v) => serializer.serialize_uint16(*v),
+            MemberValue::Uint32(v) => serializer.serialize_uint32(*v),
+            MemberValue::Uint64(v) => serializer.serialize_uint64(*v),
+            MemberValue::Float32(v) => serializer.serialize_float32(*v),
+            MemberValue::Float64(v) => serializer.serialize_float64(*v),
+            MemberValue::Char8(v) => serializer.serialize_char8(*v),
+            MemberValue::String(v) => serializer.serialize_string(v),
+            MemberValue::Sequence(v) => {
+                let mut s = serializer.serialize_sequence(v.len())?;
+                for e in v {
+                    s.serialize_element(e)?;
+                }
+                Ok(())
+            }
+            MemberValue::Array(v) => {
+                let mut s = serializer.serialize_array()?;
+                for e in v {
+                    s.serialize_element(e)?;
+                }
+                Ok(())
+            }
+            MemberValue::Struct(v) => {
+                let mut s = serializer.serialize_final_struct()?;
+                for e in v {
+                    s.serialize_field(e, "")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn deserialize_member_value<'a, T>(
+    type_identifier: &TypeIdentifier,
+    de: &mut T,
+) -> Result<MemberValue, XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+{
+    Ok(match type_identifier {
+        TypeIdentifier::TkBoolean => MemberValue::Boolean(de.deserialize_boolean()?),
+        TypeIdentifier::TkInt8Type => MemberValue::Int8(de.deserialize_int8()?),
+        TypeIdentifier::TkInt16Type => MemberValue::Int16(de.deserialize_int16()?),
+        TypeIdentifier::TkInt32Type => MemberValue::Int32(de.deserialize_int32()?),
+        TypeIdentifier::TkInt64Type => MemberValue::Int64(de.deserialize_int64()?),
+        TypeIdentifier::TkUint8Type => MemberValue::Uint8(de.deserialize_uint8()?),
+        TypeIdentifier::TkUint16Type => MemberValue::Uint16(de.deserialize_uint16()?),
+        TypeIdentifier::TkUint32Type => MemberValue::Uint32(de.deserialize_uint32()?),
+        TypeIdentifier::TkUint64Type => MemberValue::Uint64(de.deserialize_uint64()?),
+        TypeIdentifier::TkFloat32Type => MemberValue::Float32(de.deserialize_float32()?),
+        TypeIdentifier::TkFloat64Type => MemberValue::Float64(de.deserialize_float64()?),
+        TypeIdentifier::TkChar8Type => MemberValue::Char8(de.deserialize_char8()?),
+        TypeIdentifier::TiString8Small { .. } => {
+            MemberValue::String(String::from(de.deserialize_string()?))
+        }
+        TypeIdentifier::TiPlainSequenceSmall { seq_sdefn } => {
+            let len = de.deserialize_sequence()?.len();
+            let mut elements = Vec::with_capacity(len);
+            for _ in 0..len {
+                elements.push(deserialize_member_value(&seq_sdefn.element_identifier, de)?);
+            }
+            MemberValue::Sequence(elements)
+        }
+        TypeIdentifier::TiPlainArraySmall { array_sdefn } => {
+            let mut elements = Vec::new();
+            for _ in 0..array_sdefn.array_bound_seq[0] {
+                elements.push(deserialize_member_value(
+                    &array_sdefn.element_identifier,
+                    de,
+                )?);
+            }
+            MemberValue::Array(elements)
+        }
+        TypeIdentifier::EkComplete { complete } => MemberValue::Struct(
+            deserialize_struct_members(complete.as_ref(), de)?
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect(),
+        ),
+        // Remaining types can not be decoded generically
+        _ => return Err(XTypesError::InvalidData),
+    })
+}
+
+fn deserialize_struct_members<'a, T>(
+    dynamic_type: &dyn DynamicType,
+    de: &mut T,
+) -> Result<Vec<(String, MemberValue)>, XTypesError>
+where
+    for<'b> &'b mut T: XTypesDeserializer<'a>,
+{
+    let mut members = Vec::new();
+    for member_descriptor in dynamic_type.into_iter() {
+        let member_descriptor = member_descriptor?;
+        let value = deserialize_member_value(member_descriptor.type_, de)?;
+        members.push((member_descriptor.name, value));
+    }
+    Ok(members)
+}
+
+type RepresentationIdentifier = [u8; 2];
+const CDR_BE: RepresentationIdentifier = [0x00, 0x00];
+const CDR_LE: RepresentationIdentifier = [0x00, 0x01];
+const CDR2_BE: RepresentationIdentifier = [0x00, 0x06];
+const CDR2_LE: RepresentationIdentifier = [0x00, 0x07];
+
+/// Returns true if the members of samples of this type can be decoded and encoded generically.
+/// This is only possible for types with final extensibility.
+pub fn is_final_type(dynamic_type: &dyn DynamicType) -> bool {
+    matches!(
+        dynamic_type.get_descriptor().map(|d| d.extensibility_kind),
+        Ok(ExtensibilityKind::Final)
+    )
+}
+
+/// Decodes the top-level members of a serialized sample of a type with final extensibility
+/// returning them by name.
+pub fn deserialize_sample_members(
+    data: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> Result<Vec<(String, MemberValue)>, XTypesError> {
+    if data.len() < 4 {
+        return Err(XTypesError::InvalidData);
+    }
+    let representation_identifier = [data[0], data[1]];
+    let data = &data[4..];
+    match representation_identifier {
+        CDR_BE => deserialize_struct_members(dynamic_type, &mut Xcdr1BeDeserializer::new(data)),
+        CDR_LE => deserialize_struct_members(dynamic_type, &mut Xcdr1LeDeserializer::new(data)),
+        CDR2_BE => deserialize_struct_members(dynamic_type, &mut Xcdr2BeDeserializer::new(data)),
+        CDR2_LE => deserialize_struct_members(dynamic_type, &mut Xcdr2LeDeserializer::new(data)),
+        _ => Err(XTypesError::InvalidData),
+    }
+}
+
+/// Encodes a sample of a type with final extensibility in CDR_LE taking the value of each of its
+/// members from the given list by name.
+pub fn serialize_sample_members(
+    dynamic_type: &dyn DynamicType,
+    members: &[(String, MemberValue)],
+) -> Result<Vec<u8>, XTypesError> {
+    let mut collection = Vec::new();
+    collection.extend_from_slice(&CDR_LE);
+    collection.extend_from_slice(&[0, 0]);
+    {
+        let mut serializer = Xcdr1LeSerializer::new(&mut collection);
+        let mut s = serializer.serialize_final_struct()?;
+        for member_descriptor in dynamic_type.into_iter() {
+            let member_descriptor = member_descriptor?;
+            let Some((_, value)) = members
+                .iter()
+                .find(|(name, _)| name == &member_descriptor.name)
+            else {
+                return Err(XTypesError::InvalidData);
+            };
+            s.serialize_field(value, &member_descriptor.name)?;
+        }
+    }
+    let padding_len = collection.len().div_ceil(4) * 4 - collection.len();
+    const ZEROS: [u8; 4] = [0; 4];
+    collection.extend_from_slice(&ZEROS[..padding_len]);
+    collection[3] |= padding_len as u8;
+    Ok(collection)
+}
+
+/// Replaces the key members of a serialized sample by those of another serialized sample of the
+/// same type. The members of types other than final can not be encoded generically so in that
+/// case the whole sample holding the key is returned.
+pub fn replace_key_members(
+    data: &[u8],
+    key_holder: &[u8],
+    dynamic_type: &dyn DynamicType,
+) -> Result<Vec<u8>, XTypesError> {
+    if !is_final_type(dynamic_type) {
+        return Ok(key_holder.to_vec());
+    }
+    let mut members = deserialize_sample_members(data, dynamic_type)?;
+    let key_members = deserialize_sample_members(key_holder, dynamic_type)?;
+    for member_descriptor in dynamic_type.into_iter() {
+        let member_descriptor = member_descriptor?;
+        if member_descriptor.is_key {
+            if let (Some((_, value)), Some((_, key_value))) = (
+                members
+                    .iter_mut()
+                    .find(|(name, _)| name == &member_descriptor.name),
+                key_members
+                    .iter()
+                    .find(|(name, _)| name == &member_descriptor.name),
+            ) {
+                *value = key_value.clone();
+            }
+        }
+    }
+    serialize_sample_members(dynamic_type, &members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::type_support::{DdsSerialize, DdsType, TypeSupport};
+    use alloc::vec;
+
+    #[derive(DdsType)]
+    struct Nested {
+        x: u8,
+        y: i16,
+    }
+
+    #[derive(DdsType)]
+    struct Complex {
+        #[dust_dds(key)]
+        id: u16,
+        name: String,
+        nested: Nested,
+        values: Vec<u32>,
+    }
+
+    #[derive(DdsType)]
+    struct Projection {
+        values: Vec<u32>,
+        id: u16,
+    }
+
+    #[test]
+    fn deserialize_and_serialize_members() {
+        let data = Complex {
+            id: 7,
+            name: String::from("seven"),
+            nested: Nested { x: 1, y: -2 },
+            values: vec![10, 20],
+        }
+        .serialize_data()
+        .unwrap();
+
+        let members = deserialize_sample_members(&data, &Complex::get_type()).unwrap();
+        assert_eq!(
+            members,
+            vec![
+                (String::from("id"), MemberValue::Uint16(7)),
+                (
+                    String::from("name"),
+                    MemberValue::String(String::from("seven"))
+                ),
+                (
+                    String::from("nested"),
+                    MemberValue::Struct(vec![MemberValue::Uint8(1), MemberValue::Int16(-2)])
+                ),
+                (
+                    String::from("values"),
+                    MemberValue::Sequence(vec![MemberValue::Uint32(10), MemberValue::Uint32(20)])
+                ),
+            ]
+        );
+        assert_eq!(
+            serialize_sample_members(&Complex::get_type(), &members).unwrap(),
+            data
+        );
+        assert_eq!(
+            serialize_sample_members(&Projection::get_type(), &members).unwrap(),
+            Projection {
+                values: vec![10, 20],
+                id: 7
+            }
+            .serialize_data()
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn replace_only_key_members() {
+        let data = Complex {
+            id: 1,
+            name: String::from("one"),
+            nested: Nested { x: 1, y: 1 },
+            values: vec![1],
+        }
+        .serialize_data()
+        .unwrap();
+        let key_holder = Complex {
+            id: 2,
+            name: String::from("two"),
+            nested: Nested { x: 2, y: 2 },
+            values: vec![2, 2],
+        }
+        .serialize_data()
+        .unwrap();
+
+        assert_eq!(
+            replace_key_members(&data, &key_holder, &Complex::get_type()).unwrap(),
+            Complex {
+                id: 2,
+                name: String::from("one"),
+                nested: Nested { x: 1, y: 1 },
+                values: vec![1],
+            }
+            .serialize_data()
+            .unwrap()
+        );
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
     runtime::DdsRuntime,
-    dds_async::condition::StatusConditionAsync,
-    infrastructure::{error::DdsResult, status::StatusKind},
+    dds_async::condition::{
+        GuardConditionAsync, QueryConditionAsync, ReadConditionAsync, StatusConditionAsync,
+    },
+    infrastructure::{
+        error::DdsResult,
+        sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+        status::StatusKind,
+    },
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 /// A [`StatusCondition`] object is a specific Condition that is associated with each Entity.
 /// The *trigger_value* of the [`StatusCondition`] depends on the communication status of that entity (e.g., arrival of data, loss of
@@ -61,3 +67,150 @@ impl<R: DdsRuntime> StatusCondition<R> {
         R::block_on(self.condition_async.get_trigger_value())
     }
 }
+
+/// A [`GuardCondition`] object is a specific Condition whose *trigger_value* is completely under the control of the application.
+/// When it is created the *trigger_value* is [`false`]. The purpose of the [`GuardCondition`] is to provide the means for the
+/// application to manually wake up a [`WaitSet`](crate::infrastructure::wait_set::WaitSet).
+#[derive(Clone, Default)]
+pub struct GuardCondition {
+    condition_async: GuardConditionAsync,
+}
+
+impl GuardCondition {
+    /// Create a new [`GuardCondition`] whose *trigger_value* is [`false`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_async(condition_async: GuardConditionAsync) -> Self {
+        Self { condition_async }
+    }
+
+    pub(crate) fn condition_async(&self) -> &GuardConditionAsync {
+        &self.condition_async
+    }
+
+    /// This operation sets the *trigger_value* of the [`GuardCondition`].
+    /// [`WaitSet`](crate::infrastructure::wait_set::WaitSet) objects behavior depend on the changes of the *trigger_value* of their
+    /// attached conditions. Therefore, any [`WaitSet`](crate::infrastructure::wait_set::WaitSet) to which the [`GuardCondition`] is
+    /// attached is potentially affected by this operation.
+    pub fn set_trigger_value(&self, value: bool) -> DdsResult<()> {
+        self.condition_async.store_trigger_value(value);
+        Ok(())
+    }
+
+    /// This operation retrieves the *trigger_value* of the [`GuardCondition`].
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        Ok(self.condition_async.load_trigger_value())
+    }
+}
+
+/// A [`ReadCondition`] object is a specific Condition that is associated with a
+/// [`DataReader`](crate::subscription::data_reader::DataReader). Its *trigger_value* is [`true`] when the
+/// [`DataReader`](crate::subscription::data_reader::DataReader) has at least one sample whose sample state, view state and
+/// instance state are in the masks given when the [`ReadCondition`] was created with
+/// [`DataReader::create_readcondition`](crate::subscription::data_reader::DataReader::create_readcondition).
+pub struct ReadCondition<R: DdsRuntime> {
+    condition_async: ReadConditionAsync<R>,
+}
+
+impl<R: DdsRuntime> Clone for ReadCondition<R> {
+    fn clone(&self) -> Self {
+        Self {
+            condition_async: self.condition_async.clone(),
+        }
+    }
+}
+
+impl<R: DdsRuntime> ReadCondition<R> {
+    pub(crate) fn new(condition_async: ReadConditionAsync<R>) -> Self {
+        Self { condition_async }
+    }
+
+    pub(crate) fn condition_async(&self) -> &ReadConditionAsync<R> {
+        &self.condition_async
+    }
+
+    /// This operation returns the set of sample states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.condition_async.get_sample_state_mask().to_vec()
+    }
+
+    /// This operation returns the set of view states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.condition_async.get_view_state_mask().to_vec()
+    }
+
+    /// This operation returns the set of instance states that are taken into account to determine the *trigger_value* of the [`ReadCondition`].
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.condition_async.get_instance_state_mask().to_vec()
+    }
+
+    /// This operation retrieves the *trigger_value* of the [`ReadCondition`].
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        R::block_on(self.condition_async.get_trigger_value())
+    }
+}
+
+/// A [`QueryCondition`] object is a specialized [`ReadCondition`] that allows the application to also specify a filter on the
+/// data of the samples. Its *trigger_value* is [`true`] when the [`DataReader`](crate::subscription::data_reader::DataReader)
+/// has at least one sample whose states are in the masks and whose data fulfills the query expression given when the
+/// [`QueryCondition`] was created with [`DataReader::create_querycondition`](crate::subscription::data_reader::DataReader::create_querycondition).
+pub struct QueryCondition<R: DdsRuntime> {
+    condition_async: QueryConditionAsync<R>,
+}
+
+impl<R: DdsRuntime> Clone for QueryCondition<R> {
+    fn clone(&self) -> Self {
+        Self {
+            condition_async: self.condition_async.clone(),
+        }
+    }
+}
+
+impl<R: DdsRuntime> QueryCondition<R> {
+    pub(crate) fn new(condition_async: QueryConditionAsync<R>) -> Self {
+        Self { condition_async }
+    }
+
+    pub(crate) fn condition_async(&self) -> &QueryConditionAsync<R> {
+        &self.condition_async
+    }
+
+    /// This operation returns the set of sample states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    pub fn get_sample_state_mask(&self) -> Vec<SampleStateKind> {
+        self.condition_async.get_sample_state_mask().to_vec()
+    }
+
+    /// This operation returns the set of view states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    pub fn get_view_state_mask(&self) -> Vec<ViewStateKind> {
+        self.condition_async.get_view_state_mask().to_vec()
+    }
+
+    /// This operation returns the set of instance states that are taken into account to determine the *trigger_value* of the [`QueryCondition`].
+    pub fn get_instance_state_mask(&self) -> Vec<InstanceStateKind> {
+        self.condition_async.get_instance_state_mask().to_vec()
+    }
+
+    /// This operation returns the query expression specified when the [`QueryCondition`] was created.
+    pub fn get_query_expression(&self) -> String {
+        String::from(self.condition_async.get_query_expression())
+    }
+
+    /// This operation returns the query parameters associated with the [`QueryCondition`]. That is, the parameters specified on the
+    /// last successful call to [`QueryCondition::set_query_parameters`], or if [`QueryCondition::set_query_parameters`] was never called,
+    /// the arguments specified when the [`QueryCondition`] was created.
+    pub fn get_query_parameters(&self) -> DdsResult<Vec<String>> {
+        R::block_on(self.condition_async.get_query_parameters())
+    }
+
+    /// This operation changes the query parameters associated with the [`QueryCondition`].
+    pub fn set_query_parameters(&self, query_parameters: &[String]) -> DdsResult<()> {
+        R::block_on(self.condition_async.set_query_parameters(query_parameters))
+    }
+
+    /// This operation retrieves the *trigger_value* of the [`QueryCondition`].
+    pub fn get_trigger_value(&self) -> DdsResult<bool> {
+        R::block_on(self.condition_async.get_trigger_value())
+    }
+}
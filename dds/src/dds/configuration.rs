@@ -1,16 +1,58 @@
-use crate::infrastructure::error::DdsResult;
-use alloc::string::{String, ToString};
-use core::time::Duration;
+use crate::{
+    infrastructure::{domain::DomainId, error::DdsResult, qos_policy::Length},
+    ros2::Ros2Rmw,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{net::Ipv4Addr, time::Duration};
+
+/// Strategy used to allocate the entity key part of the GUID of the user-defined data writers and data readers.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum EntityKeyAllocation {
+    /// Entity keys are allocated from a counter incremented for every created data writer and data reader.
+    #[default]
+    Sequential,
+    /// Entity keys are allocated randomly.
+    Random,
+    /// Entity keys are provided by the application per topic name. The same key is used for the data writer and the
+    /// data reader of a topic since their entity kinds differ. Endpoints of topics which are not in the map get a
+    /// sequential key. Creating a second endpoint of the same kind for a topic in the map fails with
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
+    ApplicationProvided(BTreeMap<String, [u8; 3]>),
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 /// This struct specifies the high-level configuration for the DustDDS library. The configuration can be set for use by the
 /// [`DomainParticipantFactory::set_configuration`](dust_dds::domain::domain_participant_factory::DomainParticipantFactory::set_configuration) method.
 pub struct DustDdsConfiguration {
+    default_domain_id: DomainId,
+    initial_peers: Vec<Ipv4Addr>,
     domain_tag: String,
     participant_announcement_interval: Duration,
+    participant_lease_duration: Duration,
+    participant_max_samples: Length,
+    entity_key_allocation: EntityKeyAllocation,
+    encapsulation_padding_encoding: bool,
+    strict_encapsulation_options: bool,
+    discovery_history_retention: Duration,
+    task_stall_threshold: Duration,
+    restart_stalled_tasks: bool,
 }
 
 impl DustDdsConfiguration {
+    /// Domain in which the application creates its participants when the domain is not given otherwise.
+    pub fn default_domain_id(&self) -> DomainId {
+        self.default_domain_id
+    }
+
+    /// Addresses of the hosts to which the participants are announced in addition to the multicast discovery locators.
+    pub fn initial_peers(&self) -> &[Ipv4Addr] {
+        &self.initial_peers
+    }
+
     /// Domain tag to use for the participants
     pub fn domain_tag(&self) -> &str {
         self.domain_tag.as_ref()
@@ -20,13 +62,70 @@ impl DustDdsConfiguration {
     pub fn participant_announcement_interval(&self) -> Duration {
         self.participant_announcement_interval
     }
+
+    /// Lease duration announced by the participants, after which the remote participants may consider them gone if no
+    /// announcement is received.
+    pub fn participant_lease_duration(&self) -> Duration {
+        self.participant_lease_duration
+    }
+
+    /// Maximum number of samples that the user-defined data writers and data readers of a participant can reserve
+    /// in total. Endpoints reserve their storage at creation time according to their
+    /// [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy)
+    /// and creating an endpoint which would exceed this budget fails with
+    /// [`DdsError::OutOfResources`](crate::infrastructure::error::DdsError::OutOfResources).
+    pub fn participant_max_samples(&self) -> Length {
+        self.participant_max_samples
+    }
+
+    /// Strategy used to allocate the entity keys of the data writers and data readers created by the participants.
+    pub fn entity_key_allocation(&self) -> &EntityKeyAllocation {
+        &self.entity_key_allocation
+    }
+
+    /// Whether the number of padding bytes appended to the serialized data of the user-defined samples is encoded in
+    /// the options of the encapsulation header as specified by XTypes.
+    pub fn encapsulation_padding_encoding(&self) -> bool {
+        self.encapsulation_padding_encoding
+    }
+
+    /// Whether received user-defined samples are discarded if the options of their encapsulation header are invalid.
+    pub fn strict_encapsulation_options(&self) -> bool {
+        self.strict_encapsulation_options
+    }
+
+    /// Time during which the remote participants, publications and subscriptions removed from the network are kept in
+    /// the history of the discovery.
+    pub fn discovery_history_retention(&self) -> Duration {
+        self.discovery_history_retention
+    }
+
+    /// Time without progress after which an internal task of a participant is considered stalled.
+    pub fn task_stall_threshold(&self) -> Duration {
+        self.task_stall_threshold
+    }
+
+    /// Whether the periodic tasks of a participant detected as stalled are restarted.
+    pub fn restart_stalled_tasks(&self) -> bool {
+        self.restart_stalled_tasks
+    }
 }
 
 impl Default for DustDdsConfiguration {
     fn default() -> Self {
         Self {
+            default_domain_id: 0,
+            initial_peers: Vec::new(),
             domain_tag: "".to_string(),
             participant_announcement_interval: Duration::from_secs(5),
+            participant_lease_duration: Duration::from_secs(100),
+            participant_max_samples: Length::Unlimited,
+            entity_key_allocation: EntityKeyAllocation::Sequential,
+            encapsulation_padding_encoding: true,
+            strict_encapsulation_options: false,
+            discovery_history_retention: Duration::ZERO,
+            task_stall_threshold: Duration::ZERO,
+            restart_stalled_tasks: false,
         }
     }
 }
@@ -37,6 +136,12 @@ pub struct DustDdsConfigurationBuilder {
     configuration: DustDdsConfiguration,
 }
 
+impl From<DustDdsConfiguration> for DustDdsConfigurationBuilder {
+    fn from(configuration: DustDdsConfiguration) -> Self {
+        Self { configuration }
+    }
+}
+
 impl DustDdsConfigurationBuilder {
     /// Construct a configuration builder with all the default options.
     pub fn new() -> Self {
@@ -50,6 +155,21 @@ impl DustDdsConfigurationBuilder {
         Ok(self.configuration)
     }
 
+    /// Set the domain in which the application creates its participants when the domain is not given otherwise.
+    /// It can also be given at deployment time with the `domain_id` setting of the configuration file.
+    pub fn default_domain_id(mut self, default_domain_id: DomainId) -> Self {
+        self.configuration.default_domain_id = default_domain_id;
+        self
+    }
+
+    /// Set the addresses of the hosts to which the participants are announced. The announcements are sent to the
+    /// discovery port of the domain on each of the hosts, which allows discovering participants in networks where
+    /// multicast is not available.
+    pub fn initial_peers(mut self, initial_peers: Vec<Ipv4Addr>) -> Self {
+        self.configuration.initial_peers = initial_peers;
+        self
+    }
+
     /// Set the domain tag to use for the participants
     pub fn domain_tag(mut self, domain_tag: String) -> Self {
         self.configuration.domain_tag = domain_tag;
@@ -65,4 +185,76 @@ impl DustDdsConfigurationBuilder {
         self.configuration.participant_announcement_interval = participant_announcement_interval;
         self
     }
+
+    /// Set the lease duration announced by the participants. It must be longer than the participant announcement
+    /// interval for the remote participants to keep considering them alive.
+    pub fn participant_lease_duration(mut self, participant_lease_duration: Duration) -> Self {
+        self.configuration.participant_lease_duration = participant_lease_duration;
+        self
+    }
+
+    /// Set the participant lease duration and announcement interval to the ones of the given ROS 2 middleware
+    /// implementation so that the participants are discovered and expired by the peers of a mixed ROS 2 graph
+    /// the same way as the participants of that implementation. The endpoint QoS matching the ROS 2 defaults is
+    /// provided by [`Ros2QosProfile`](crate::ros2::Ros2QosProfile).
+    pub fn ros2_discovery_timing(self, rmw: Ros2Rmw) -> Self {
+        self.participant_announcement_interval(rmw.participant_announcement_interval())
+            .participant_lease_duration(rmw.participant_lease_duration())
+    }
+
+    /// Set the maximum number of samples that the user-defined data writers and data readers of a participant can
+    /// reserve in total.
+    pub fn participant_max_samples(mut self, participant_max_samples: Length) -> Self {
+        self.configuration.participant_max_samples = participant_max_samples;
+        self
+    }
+
+    /// Set the strategy used to allocate the entity keys of the data writers and data readers.
+    pub fn entity_key_allocation(mut self, entity_key_allocation: EntityKeyAllocation) -> Self {
+        self.configuration.entity_key_allocation = entity_key_allocation;
+        self
+    }
+
+    /// Set whether the number of padding bytes appended to the serialized data is encoded in the two least significant
+    /// bits of the encapsulation header options. This is required by XTypes and enabled by default. Disabling it always
+    /// sends the options as zero, as expected by some older implementations.
+    pub fn encapsulation_padding_encoding(mut self, encapsulation_padding_encoding: bool) -> Self {
+        self.configuration.encapsulation_padding_encoding = encapsulation_padding_encoding;
+        self
+    }
+
+    /// Set whether received samples with invalid encapsulation header options are discarded. The options are
+    /// invalid if any of the reserved bits is set or if the encoded number of padding bytes exceeds the length of the
+    /// serialized data. Discarded samples are reported in the
+    /// [`SampleLostStatus`](crate::infrastructure::status::SampleLostStatus) of the data reader. Disabled by default.
+    pub fn strict_encapsulation_options(mut self, strict_encapsulation_options: bool) -> Self {
+        self.configuration.strict_encapsulation_options = strict_encapsulation_options;
+        self
+    }
+
+    /// Set the time during which the remote participants, publications and subscriptions removed from the network are
+    /// kept in the history of the discovery. The entities in the history can be listed with
+    /// [`DomainParticipant::get_removed_discovered_entities`](crate::domain::domain_participant::DomainParticipant::get_removed_discovered_entities)
+    /// and leave it as soon as they are discovered again. A zero duration, which is the default, disables the history.
+    pub fn discovery_history_retention(mut self, discovery_history_retention: Duration) -> Self {
+        self.configuration.discovery_history_retention = discovery_history_retention;
+        self
+    }
+
+    /// Set the time without progress after which an internal task of a participant is considered stalled. The
+    /// tasks are supervised by a watchdog running on the timer of the participant and their health can be read with
+    /// [`DomainParticipant::get_task_health_status`](crate::domain::domain_participant::DomainParticipant::get_task_health_status).
+    /// A zero duration, which is the default, disables the watchdog.
+    pub fn task_stall_threshold(mut self, task_stall_threshold: Duration) -> Self {
+        self.configuration.task_stall_threshold = task_stall_threshold;
+        self
+    }
+
+    /// Set whether the watchdog restarts the participant announcement task when it is detected as stalled. The
+    /// task processing the messages of the participant cannot be restarted without losing its state and is only
+    /// reported. Disabled by default.
+    pub fn restart_stalled_tasks(mut self, restart_stalled_tasks: bool) -> Self {
+        self.configuration.restart_stalled_tasks = restart_stalled_tasks;
+        self
+    }
 }
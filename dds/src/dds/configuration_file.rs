@@ -0,0 +1,395 @@
+use crate::{
+    configuration::{DustDdsConfiguration, DustDdsConfigurationBuilder},
+    infrastructure::{
+        domain::DomainId,
+        error::{DdsError, DdsResult},
+        qos::DomainParticipantQos,
+    },
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::net::Ipv4Addr;
+use toml::{Table, Value};
+
+/// Name of the environment variable with the path of the configuration file read by
+/// [`ConfigurationFile::from_environment`].
+pub const CONFIGURATION_FILE_ENVIRONMENT_VARIABLE: &str = "DUST_DDS_CONFIG_FILE";
+
+// Settings which can be given in the configuration file together with the environment variable overriding them
+const DOMAIN_ID: (&str, &str) = ("domain_id", "DUST_DDS_DOMAIN_ID");
+const INTERFACE_NAME: (&str, &str) = ("interface_name", "DUST_DDS_INTERFACE_NAME");
+const INITIAL_PEERS: (&str, &str) = ("initial_peers", "DUST_DDS_INITIAL_PEERS");
+const PARTICIPANT_USER_DATA: (&str, &str) = (
+    "participant_qos.user_data",
+    "DUST_DDS_PARTICIPANT_USER_DATA",
+);
+const PARTICIPANT_AUTOENABLE_CREATED_ENTITIES: (&str, &str) = (
+    "participant_qos.autoenable_created_entities",
+    "DUST_DDS_PARTICIPANT_AUTOENABLE_CREATED_ENTITIES",
+);
+
+/// The [`ConfigurationFile`] holds the settings of the
+/// [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory) which can be
+/// tuned at deployment time. The settings are read with [`ConfigurationFile::from_environment`] and applied when the
+/// singleton is first obtained with
+/// [`DomainParticipantFactory::get_instance`](crate::domain::domain_participant_factory::DomainParticipantFactory::get_instance).
+/// Settings loaded by the application, for example with [`ConfigurationFile::from_file`], can be applied afterwards
+/// with [`DomainParticipantFactory::set_configuration_from_file`](crate::domain::domain_participant_factory::DomainParticipantFactory::set_configuration_from_file).
+/// Only the settings present in the file or in the environment are applied, the others keep their current value.
+///
+/// The supported settings and the environment variables overriding them are:
+///
+/// ```toml
+/// # DUST_DDS_DOMAIN_ID
+/// domain_id = 7
+/// # DUST_DDS_INTERFACE_NAME
+/// interface_name = "eth0"
+/// # DUST_DDS_INITIAL_PEERS, with the addresses separated by commas
+/// initial_peers = ["192.168.1.10", "192.168.1.11"]
+///
+/// [participant_qos]
+/// # DUST_DDS_PARTICIPANT_USER_DATA
+/// user_data = "robot-1"
+/// # DUST_DDS_PARTICIPANT_AUTOENABLE_CREATED_ENTITIES
+/// autoenable_created_entities = true
+/// ```
+///
+/// Unknown settings and values of the wrong type are rejected with an error.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfigurationFile {
+    domain_id: Option<DomainId>,
+    interface_name: Option<String>,
+    initial_peers: Vec<Ipv4Addr>,
+    participant_user_data: Option<Vec<u8>>,
+    participant_autoenable_created_entities: Option<bool>,
+}
+
+impl ConfigurationFile {
+    /// Read the configuration from the TOML file at `path`.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> DdsResult<Self> {
+        let path = path.as_ref();
+        let toml = std::fs::read_to_string(path).map_err(|e| {
+            DdsError::Error(format!(
+                "Failed to read configuration file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_toml(&toml)
+    }
+
+    /// Read the configuration from the environment. The settings are read from the file in the
+    /// `DUST_DDS_CONFIG_FILE` environment variable, if it is set, and the `DUST_DDS_*` environment variables take
+    /// precedence over the values of the file.
+    pub fn from_environment() -> DdsResult<Self> {
+        Self::load_with(|name| std::env::var(name).ok(), Self::from_file)
+    }
+
+    /// Create a new [`ConfigurationFile`] from the settings in the `toml` string.
+    pub fn from_toml(toml: &str) -> DdsResult<Self> {
+        let table: Table = toml
+            .parse()
+            .map_err(|e| DdsError::Error(format!("Invalid configuration file: {}", e)))?;
+        let mut configuration_file = Self::default();
+        for (key, value) in table {
+            match value {
+                Value::Table(table) => {
+                    for (table_key, value) in table {
+                        configuration_file.set(&format!("{}.{}", key, table_key), value)?;
+                    }
+                }
+                value => configuration_file.set(&key, value)?,
+            }
+        }
+        Ok(configuration_file)
+    }
+
+    /// Domain identifier used by the application when it is not given otherwise, or [`None`] if not configured.
+    pub fn domain_id(&self) -> Option<DomainId> {
+        self.domain_id
+    }
+
+    /// Name of the network interface used by the participants, or [`None`] if not configured.
+    pub fn interface_name(&self) -> Option<&str> {
+        self.interface_name.as_deref()
+    }
+
+    /// Addresses of the hosts to which the participants are announced in addition to the multicast discovery locators.
+    pub fn initial_peers(&self) -> &[Ipv4Addr] {
+        &self.initial_peers
+    }
+
+    /// Get the given [`DustDdsConfiguration`] with the settings of the file applied to it. The initial peers of the
+    /// file which are not yet in the configuration are appended to its initial peers.
+    pub fn update_configuration(
+        &self,
+        configuration: DustDdsConfiguration,
+    ) -> DdsResult<DustDdsConfiguration> {
+        let mut initial_peers = configuration.initial_peers().to_vec();
+        for peer in &self.initial_peers {
+            if !initial_peers.contains(peer) {
+                initial_peers.push(*peer);
+            }
+        }
+        let mut builder =
+            DustDdsConfigurationBuilder::from(configuration).initial_peers(initial_peers);
+        if let Some(domain_id) = self.domain_id {
+            builder = builder.default_domain_id(domain_id);
+        }
+        builder.build()
+    }
+
+    /// Get the given [`DomainParticipantQos`] with the participant QoS settings of the file applied to it.
+    pub fn update_participant_qos(&self, mut qos: DomainParticipantQos) -> DomainParticipantQos {
+        if let Some(user_data) = &self.participant_user_data {
+            qos.user_data.value = user_data.clone();
+        }
+        if let Some(autoenable_created_entities) = self.participant_autoenable_created_entities {
+            qos.entity_factory.autoenable_created_entities = autoenable_created_entities;
+        }
+        qos
+    }
+
+    fn load_with(
+        environment_variable: impl Fn(&str) -> Option<String>,
+        read_file: impl Fn(String) -> DdsResult<Self>,
+    ) -> DdsResult<Self> {
+        let mut configuration_file =
+            match environment_variable(CONFIGURATION_FILE_ENVIRONMENT_VARIABLE) {
+                Some(path) => read_file(path)?,
+                None => Self::default(),
+            };
+
+        for (key, name) in [
+            DOMAIN_ID,
+            INTERFACE_NAME,
+            INITIAL_PEERS,
+            PARTICIPANT_USER_DATA,
+            PARTICIPANT_AUTOENABLE_CREATED_ENTITIES,
+        ] {
+            if let Some(variable) = environment_variable(name) {
+                configuration_file.set(key, environment_variable_value(key, &variable))?;
+            }
+        }
+        Ok(configuration_file)
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> DdsResult<()> {
+        match (key, value) {
+            (k, Value::Integer(domain_id)) if k == DOMAIN_ID.0 => {
+                self.domain_id =
+                    Some(DomainId::try_from(domain_id).map_err(|_| invalid_value(key))?);
+            }
+            (k, Value::String(interface_name)) if k == INTERFACE_NAME.0 => {
+                self.interface_name = Some(interface_name);
+            }
+            (k, Value::Array(peer_list)) if k == INITIAL_PEERS.0 => {
+                self.initial_peers = peer_list
+                    .into_iter()
+                    .map(|peer| {
+                        peer.as_str()
+                            .and_then(|peer| peer.parse::<Ipv4Addr>().ok())
+                            .ok_or_else(|| invalid_value(key))
+                    })
+                    .collect::<DdsResult<_>>()?;
+            }
+            (k, Value::String(user_data)) if k == PARTICIPANT_USER_DATA.0 => {
+                self.participant_user_data = Some(user_data.into_bytes());
+            }
+            (k, Value::Boolean(autoenable_created_entities))
+                if k == PARTICIPANT_AUTOENABLE_CREATED_ENTITIES.0 =>
+            {
+                self.participant_autoenable_created_entities = Some(autoenable_created_entities);
+            }
+            (k, _)
+                if [
+                    DOMAIN_ID,
+                    INTERFACE_NAME,
+                    INITIAL_PEERS,
+                    PARTICIPANT_USER_DATA,
+                    PARTICIPANT_AUTOENABLE_CREATED_ENTITIES,
+                ]
+                .iter()
+                .any(|(setting, _)| setting == &k) =>
+            {
+                return Err(invalid_value(key))
+            }
+            _ => {
+                return Err(DdsError::Error(format!(
+                    "Unknown configuration setting {}",
+                    key
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid_value(key: &str) -> DdsError {
+    DdsError::Error(format!("Invalid value for configuration setting {}", key))
+}
+
+// Environment variables are plain strings so they are converted according to the type expected by the setting
+fn environment_variable_value(key: &str, variable: &str) -> Value {
+    if key == INITIAL_PEERS.0 {
+        Value::Array(
+            variable
+                .split(',')
+                .map(str::trim)
+                .filter(|peer| !peer.is_empty())
+                .map(|peer| Value::String(peer.to_string()))
+                .collect(),
+        )
+    } else if let Ok(integer) = variable.trim().parse::<i64>() {
+        Value::Integer(integer)
+    } else {
+        match variable.trim() {
+            "true" => Value::Boolean(true),
+            "false" => Value::Boolean(false),
+            _ => Value::String(variable.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIGURATION_TOML: &str = r#"
+        # Deployment settings
+        domain_id = 7
+        interface_name = "eth0" # Only the wired interface
+        initial_peers = ["192.168.1.10", '10.0.0.1', ]
+
+        [participant_qos]
+        user_data = "robot #1 é"
+        autoenable_created_entities = false
+    "#;
+
+    #[test]
+    fn settings_are_read_from_toml() {
+        let configuration_file = ConfigurationFile::from_toml(CONFIGURATION_TOML).unwrap();
+
+        assert_eq!(configuration_file.domain_id(), Some(7));
+        assert_eq!(configuration_file.interface_name(), Some("eth0"));
+        assert_eq!(
+            configuration_file.initial_peers(),
+            &[Ipv4Addr::new(192, 168, 1, 10), Ipv4Addr::new(10, 0, 0, 1)]
+        );
+        let participant_qos =
+            configuration_file.update_participant_qos(DomainParticipantQos::default());
+        assert_eq!(participant_qos.user_data.value, "robot #1 é".as_bytes());
+        assert!(!participant_qos.entity_factory.autoenable_created_entities);
+
+        let configuration = configuration_file
+            .update_configuration(
+                DustDdsConfigurationBuilder::new()
+                    .domain_tag(String::from("tag"))
+                    .initial_peers(vec![Ipv4Addr::new(10, 0, 0, 1)])
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(configuration.domain_tag(), "tag");
+        assert_eq!(configuration.default_domain_id(), 7);
+        assert_eq!(
+            configuration.initial_peers(),
+            &[Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(192, 168, 1, 10)]
+        );
+    }
+
+    #[test]
+    fn settings_missing_from_the_file_are_left_unchanged() {
+        let configuration_file =
+            ConfigurationFile::from_toml("[participant_qos]\nuser_data = \"robot-1\"").unwrap();
+        let configuration = DustDdsConfigurationBuilder::new()
+            .initial_peers(vec![Ipv4Addr::new(10, 0, 0, 1)])
+            .default_domain_id(3)
+            .build()
+            .unwrap();
+        let mut qos = DomainParticipantQos::default();
+        qos.entity_factory.autoenable_created_entities = false;
+
+        let updated_configuration = configuration_file
+            .update_configuration(configuration.clone())
+            .unwrap();
+        let updated_qos = configuration_file.update_participant_qos(qos);
+
+        assert_eq!(updated_configuration, configuration);
+        assert_eq!(updated_qos.user_data.value, b"robot-1");
+        assert!(!updated_qos.entity_factory.autoenable_created_entities);
+    }
+
+    #[test]
+    fn environment_variables_override_file_settings() {
+        let environment_variable = |name: &str| match name {
+            "DUST_DDS_CONFIG_FILE" => Some(String::from("/etc/dust_dds.toml")),
+            "DUST_DDS_DOMAIN_ID" => Some(String::from("12")),
+            "DUST_DDS_INITIAL_PEERS" => Some(String::from("10.0.0.2, 10.0.0.3")),
+            "DUST_DDS_PARTICIPANT_USER_DATA" => Some(String::from("robot-2")),
+            _ => None,
+        };
+        let read_file = |path: String| {
+            assert_eq!(path, "/etc/dust_dds.toml");
+            ConfigurationFile::from_toml(CONFIGURATION_TOML)
+        };
+
+        let configuration_file =
+            ConfigurationFile::load_with(environment_variable, read_file).unwrap();
+
+        assert_eq!(configuration_file.domain_id(), Some(12));
+        assert_eq!(configuration_file.interface_name(), Some("eth0"));
+        assert_eq!(
+            configuration_file.initial_peers(),
+            &[Ipv4Addr::new(10, 0, 0, 2), Ipv4Addr::new(10, 0, 0, 3)]
+        );
+        let participant_qos =
+            configuration_file.update_participant_qos(DomainParticipantQos::default());
+        assert_eq!(participant_qos.user_data.value, b"robot-2");
+        assert!(!participant_qos.entity_factory.autoenable_created_entities);
+    }
+
+    #[test]
+    fn no_file_is_read_without_environment_variable() {
+        let configuration_file = ConfigurationFile::load_with(
+            |_| None,
+            |_| panic!("No configuration file should be read"),
+        )
+        .unwrap();
+
+        assert_eq!(configuration_file, ConfigurationFile::default());
+    }
+
+    #[test]
+    fn invalid_settings_are_rejected() {
+        for toml in [
+            "unknown_setting = true",
+            "interface_name = true",
+            "interface_name = \"eth0\" \"eth1\"",
+            "interface_name = \"eth0\"\ninterface_name = \"eth1\"",
+            "domain_id = \"7\"",
+            "domain_id = 4294967296",
+            "initial_peers = [\"192.168.1\"]",
+            "initial_peers = \"192.168.1.10\"",
+            "[participant_qos",
+            "[participant_qos]\nautoenable_created_entities = 1",
+            "[participant_qos]\nunknown_setting = 1",
+        ] {
+            assert!(ConfigurationFile::from_toml(toml).is_err(), "{}", toml);
+        }
+        assert!(ConfigurationFile::from_file("missing.toml").is_err());
+        assert!(ConfigurationFile::load_with(
+            |name| (name == "DUST_DDS_CONFIG_FILE").then(|| String::from("missing.toml")),
+            ConfigurationFile::from_file
+        )
+        .is_err());
+        assert!(ConfigurationFile::load_with(
+            |name| (name == "DUST_DDS_DOMAIN_ID").then(|| String::from("seven")),
+            ConfigurationFile::from_file
+        )
+        .is_err());
+    }
+}
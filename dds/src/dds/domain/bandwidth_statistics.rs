@@ -0,0 +1,46 @@
+use crate::infrastructure::{instance::InstanceHandle, time::Duration};
+use alloc::{string::String, vec::Vec};
+
+/// Amount of serialized user data exchanged on a topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicBandwidthStatistics {
+    /// Name of the topic.
+    pub topic_name: String,
+    /// Number of bytes sent by the local writers of the topic. The data of a sample is counted each time it is
+    /// sent, including the repairs requested by the readers.
+    pub bytes_sent: u64,
+    /// Number of bytes received by the local readers of the topic.
+    pub bytes_received: u64,
+}
+
+/// Amount of serialized user data exchanged with a participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParticipantBandwidthStatistics {
+    /// Handle of the participant as returned by [`DomainParticipant::get_discovered_participants()`](crate::domain::domain_participant::DomainParticipant::get_discovered_participants).
+    pub participant_handle: InstanceHandle,
+    /// Number of bytes sent by the local writers to the readers of the participant. The data of a sample is counted
+    /// once for each reader it is sent to, including the repairs requested by the readers.
+    pub bytes_sent: u64,
+    /// Number of bytes received by the local readers from the writers of the participant.
+    pub bytes_received: u64,
+}
+
+/// Accounting of the serialized user data exchanged by a participant. Only the data of user-defined topics is taken
+/// into account, the discovery traffic and the protocol overhead are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BandwidthStatistics {
+    /// Data exchanged on each of the topics of the participant.
+    pub topics: Vec<TopicBandwidthStatistics>,
+    /// Data exchanged with each of the participants with which the local entities communicate.
+    pub participants: Vec<ParticipantBandwidthStatistics>,
+}
+
+/// Maximum amount of data which the writers of a topic can send during a period. The messages exceeding the quota
+/// are held and sent in the following periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BandwidthQuota {
+    /// Maximum number of bytes of the messages which can be sent during each period.
+    pub max_bytes: u64,
+    /// Duration of the period after which the quota is replenished.
+    pub period: Duration,
+}
@@ -0,0 +1 @@
+pub use crate::transport::diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity};
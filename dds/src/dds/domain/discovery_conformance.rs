@@ -0,0 +1,92 @@
+use crate::infrastructure::{instance::InstanceHandle, qos_policy::QosPolicyId};
+use alloc::vec::Vec;
+
+/// Identifier of a parameter of the discovery data as defined in the RTPS standard (Table 9.13 - ParameterId Values).
+pub type ParameterId = i16;
+
+/// Builtin endpoints which can be announced by a participant in its discovery data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinEndpointKind {
+    /// Writer of the participant discovery data (SPDPbuiltinParticipantWriter).
+    ParticipantAnnouncer,
+    /// Reader of the participant discovery data (SPDPbuiltinParticipantReader).
+    ParticipantDetector,
+    /// Writer of the publication discovery data (SEDPbuiltinPublicationsWriter).
+    PublicationsAnnouncer,
+    /// Reader of the publication discovery data (SEDPbuiltinPublicationsReader).
+    PublicationsDetector,
+    /// Writer of the subscription discovery data (SEDPbuiltinSubscriptionsWriter).
+    SubscriptionsAnnouncer,
+    /// Reader of the subscription discovery data (SEDPbuiltinSubscriptionsReader).
+    SubscriptionsDetector,
+    /// Writer of the participant liveliness messages (BuiltinParticipantMessageWriter).
+    ParticipantMessageWriter,
+    /// Reader of the participant liveliness messages (BuiltinParticipantMessageReader).
+    ParticipantMessageReader,
+    /// Writer of the topic discovery data (SEDPbuiltinTopicsWriter).
+    TopicsAnnouncer,
+    /// Reader of the topic discovery data (SEDPbuiltinTopicsReader).
+    TopicsDetector,
+}
+
+/// Kind of discovery data received from a remote participant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveredEntityKind {
+    /// Participant discovery data (DCPSParticipant).
+    Participant,
+    /// Topic discovery data (DCPSTopic).
+    Topic,
+    /// Publication discovery data (DCPSPublication).
+    Publication,
+    /// Subscription discovery data (DCPSSubscription).
+    Subscription,
+}
+
+/// State of a builtin endpoint of a discovered participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltinEndpointConformance {
+    /// Builtin endpoint.
+    pub endpoint: BuiltinEndpointKind,
+    /// Whether the endpoint is part of the builtin endpoint set announced by the remote participant.
+    pub announced: bool,
+    /// Whether the endpoint was matched with the corresponding local builtin endpoint.
+    pub matched: bool,
+}
+
+/// QoS policy of a discovered entity which was not transmitted and for which the default value was applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefaultedQosPolicy {
+    /// Kind of discovery data from which the QoS policy was missing.
+    pub entity_kind: DiscoveredEntityKind,
+    /// Handle of the discovered entity.
+    pub entity_handle: InstanceHandle,
+    /// Identifier of the QoS policy whose default value was applied.
+    pub policy_id: QosPolicyId,
+    /// Identifier of the missing parameter.
+    pub parameter_id: ParameterId,
+}
+
+/// Optional parameter, not related to a QoS policy, which was missing in the discovery data of an entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingParameter {
+    /// Kind of discovery data from which the parameter was missing.
+    pub entity_kind: DiscoveredEntityKind,
+    /// Handle of the discovered entity.
+    pub entity_handle: InstanceHandle,
+    /// Identifier of the missing parameter.
+    pub parameter_id: ParameterId,
+}
+
+/// Report describing how the discovery data received from a remote participant was interpreted. This information
+/// is intended for diagnosing interoperability issues with other DDS implementations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryConformanceReport {
+    /// Vendor identifier announced by the remote participant.
+    pub vendor_id: [u8; 2],
+    /// Announcement and matching state of each of the builtin endpoints.
+    pub builtin_endpoints: Vec<BuiltinEndpointConformance>,
+    /// QoS policies of the participant and its discovered entities for which the default value was applied.
+    pub defaulted_qos_policies: Vec<DefaultedQosPolicy>,
+    /// Optional parameters missing in the discovery data of the participant and its discovered entities.
+    pub missing_parameters: Vec<MissingParameter>,
+}
@@ -0,0 +1,14 @@
+use crate::infrastructure::{instance::InstanceHandle, time::Time};
+
+use super::discovery_conformance::DiscoveredEntityKind;
+
+/// Remote entity which was discovered by a participant and has since been removed from the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedDiscoveredEntity {
+    /// Kind of discovery data which announced the entity.
+    pub entity_kind: DiscoveredEntityKind,
+    /// Handle of the entity as it was returned while the entity was discovered.
+    pub entity_handle: InstanceHandle,
+    /// Time at which the removal of the entity was received.
+    pub removal_time: Time,
+}
@@ -1,6 +1,17 @@
-use super::domain_participant_listener::DomainParticipantListener;
+use super::{
+    bandwidth_statistics::{BandwidthQuota, BandwidthStatistics},
+    diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
+    discovery_conformance::DiscoveryConformanceReport,
+    discovery_history::RemovedDiscoveredEntity,
+    domain_participant_listener::DomainParticipantListener,
+    memory_usage::MemoryUsageStatistics,
+    task_health::TaskHealthStatus,
+};
 use crate::{
-    builtin_topics::{ParticipantBuiltinTopicData, TopicBuiltinTopicData},
+    builtin_topics::{
+        ParticipantBuiltinTopicData, PublicationBuiltinTopicData, SubscriptionBuiltinTopicData,
+        TopicBuiltinTopicData,
+    },
     runtime::DdsRuntime,
     dds_async::domain_participant::DomainParticipantAsync,
     infrastructure::{
@@ -17,7 +28,34 @@ use crate::{
     topic_definition::{topic::Topic, topic_listener::TopicListener},
     xtypes::dynamic_type::DynamicType,
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{string::String, sync::Arc, vec::Vec};
+
+/// Hook set with [`DomainParticipant::set_endpoint_authorization_hook`] to decide whether the remote endpoints discovered by
+/// a [`DomainParticipant`] may communicate with its local endpoints, based for example on the
+/// [`UserDataQosPolicy`](crate::infrastructure::qos_policy::UserDataQosPolicy) of the remote participant or on the user and group data
+/// of the remote endpoint. The remote participant data is [`None`] if the endpoint was discovered before its participant.
+/// The hook is called while the [`DomainParticipant`] processes the discovery so it should return quickly.
+pub trait EndpointAuthorizationHook: Send + Sync {
+    /// Called each time a discovered writer is evaluated for matching with a local reader. Returning `false`
+    /// prevents the writer from matching the reader.
+    fn authorize_publication(
+        &self,
+        _participant_data: Option<&ParticipantBuiltinTopicData>,
+        _publication_data: &PublicationBuiltinTopicData,
+    ) -> bool {
+        true
+    }
+
+    /// Called each time a discovered reader is evaluated for matching with a local writer. Returning `false`
+    /// prevents the reader from matching the writer.
+    fn authorize_subscription(
+        &self,
+        _participant_data: Option<&ParticipantBuiltinTopicData>,
+        _subscription_data: &SubscriptionBuiltinTopicData,
+    ) -> bool {
+        true
+    }
+}
 
 /// The [`DomainParticipant`] represents the participation of the application on a communication plane that isolates applications running on the
 /// same set of physical computers from each other. A domain establishes a *virtual network* linking all applications that
@@ -51,6 +89,14 @@ impl<R: DdsRuntime> DomainParticipant<R> {
     }
 }
 
+impl<R: DdsRuntime> Clone for DomainParticipant<R> {
+    fn clone(&self) -> Self {
+        Self {
+            participant_async: self.participant_async.clone(),
+        }
+    }
+}
+
 impl<R: DdsRuntime> DomainParticipant<R> {
     /// This operation creates a [`Publisher`] with the desired QoS policies and attaches to it the specified [`PublisherListener`].
     /// If the specified QoS policies are not consistent, the operation will fail and no [`Publisher`] will be created.
@@ -150,7 +196,12 @@ impl<R: DdsRuntime> DomainParticipant<R> {
         .map(Topic::from)
     }
 
-    #[doc(hidden)]
+    /// This operation creates a [`Topic`] in the same way as [`DomainParticipant::create_topic`] but the type of its
+    /// samples is described at runtime by `dynamic_type_representation` instead of being a type implementing
+    /// [`TypeSupport`]. Types built with a
+    /// [`DynamicStructTypeBuilder`](crate::topic_definition::dynamic_data::DynamicStructTypeBuilder) are published and
+    /// received on the topic as [`DynamicData`](crate::topic_definition::dynamic_data::DynamicData).
+    /// This operation is not part of the DDS standard.
     #[tracing::instrument(skip(self, a_listener, dynamic_type_representation))]
     pub fn create_dynamic_topic(
         &self,
@@ -172,6 +223,38 @@ impl<R: DdsRuntime> DomainParticipant<R> {
         .map(Topic::from)
     }
 
+    /// This operation creates a MultiTopic which combines the samples of several related topics into samples
+    /// of type `Foo`. The subscription expression has the form `SELECT * | field [AS name] {, field [AS name]}
+    /// FROM topic {NATURAL JOIN topic} [WHERE operand op operand {AND operand op operand}]` where the operands are
+    /// field names, parameters of the form `%n` or literals and `op` is one of `=`, `<>`, `<`, `<=`, `>` and `>=`.
+    /// The samples of the related topics are joined on the fields which have the same name and the fields of the
+    /// resulting samples are taken by name from the joined samples. The related topics must exist and all the
+    /// types must have final extensibility. If the expression is invalid, [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
+    /// is returned.
+    /// The returned [`Topic`] can only be used to create [`DataReader`](crate::subscription::data_reader::DataReader) objects which
+    /// receive a sample each time a sample is received for a related topic that can be combined with the most recent
+    /// samples of the other related topics. The MultiTopic uses the default Topic QoS, is not propagated to other participants
+    /// and must be deleted by means of [`DomainParticipant::delete_topic()`].
+    #[tracing::instrument(skip(self))]
+    pub fn create_multitopic<Foo>(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: &str,
+        expression_parameters: &[String],
+    ) -> DdsResult<Topic<R>>
+    where
+        Foo: TypeSupport,
+    {
+        R::block_on(self.participant_async.create_multitopic::<Foo>(
+            name,
+            type_name,
+            subscription_expression,
+            expression_parameters,
+        ))
+        .map(Topic::from)
+    }
+
     /// This operation deletes a [`Topic`].
     /// The deletion of a [`Topic`] is not allowed if there are any existing [`DataReader`](crate::subscription::data_reader::DataReader) or [`DataWriter`](crate::publication::data_writer::DataWriter)
     /// objects that are using the [`Topic`]. If the [`DomainParticipant::delete_topic()`] operation is called on a [`Topic`] with any of these existing objects attached to
@@ -228,6 +311,10 @@ impl<R: DdsRuntime> DomainParticipant<R> {
     /// well as corresponding [`DataReader`](crate::subscription::data_reader::DataReader) objects to access them. All these [`DataReader`](crate::subscription::data_reader::DataReader) objects belong to a single built-in [`Subscriber`].
     /// The built-in topics are used to communicate information about other [`DomainParticipant`], [`Topic`], [`DataReader`](crate::subscription::data_reader::DataReader), and [`DataWriter`](crate::publication::data_writer::DataWriter)
     /// objects.
+    /// The built-in [`DataReader`](crate::subscription::data_reader::DataReader) objects are obtained with
+    /// [`Subscriber::lookup_datareader`] using the builtin topic names and the corresponding builtin topic data types. They support the
+    /// same operations as any other reader, except that they can not be deleted and no additional readers can be created in the
+    /// built-in [`Subscriber`].
     #[tracing::instrument(skip(self))]
     pub fn get_builtin_subscriber(&self) -> Subscriber<R> {
         Subscriber::from(self.participant_async.get_builtin_subscriber())
@@ -400,6 +487,131 @@ impl<R: DdsRuntime> DomainParticipant<R> {
         )
     }
 
+    /// This operation returns a [`DiscoveryConformanceReport`] for a [`DomainParticipant`] that has been discovered on the network.
+    /// The report lists which of the builtin endpoints were announced by the remote participant and matched with the local ones, the
+    /// QoS policies of the participant and its discovered entities for which the default value was applied because it was not transmitted,
+    /// and the optional parameters which were missing in their discovery data. This is intended to help diagnosing interoperability
+    /// issues with other DDS implementations.
+    /// The participant_handle must correspond to a currently discovered participant as returned by [`DomainParticipant::get_discovered_participants()`].
+    /// Otherwise, the operation will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_discovery_conformance_report(
+        &self,
+        participant_handle: InstanceHandle,
+    ) -> DdsResult<DiscoveryConformanceReport> {
+        R::block_on(
+            self.participant_async
+                .get_discovery_conformance_report(participant_handle),
+        )
+    }
+
+    /// This operation returns the participants, publications and subscriptions which were discovered by the [`DomainParticipant`]
+    /// and removed from the network within the retention time configured with
+    /// [`DustDdsConfigurationBuilder::discovery_history_retention`](crate::configuration::DustDdsConfigurationBuilder::discovery_history_retention).
+    /// An entity leaves this list as soon as it is discovered again, so a peer which briefly disappears only shows up here while
+    /// it is absent. The list is empty if no retention is configured.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_removed_discovered_entities(&self) -> DdsResult<Vec<RemovedDiscoveredEntity>> {
+        R::block_on(self.participant_async.get_removed_discovered_entities())
+    }
+
+    /// This operation returns the [`BandwidthStatistics`] of the [`DomainParticipant`]. These contain the number of bytes of
+    /// serialized user data sent and received on each of the topics and exchanged with each of the participants with which the
+    /// local writers and readers communicate.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_bandwidth_statistics(&self) -> DdsResult<BandwidthStatistics> {
+        R::block_on(self.participant_async.get_bandwidth_statistics())
+    }
+
+    /// This operation returns the [`MemoryUsageStatistics`] of the [`DomainParticipant`]. These contain the approximate memory held
+    /// by the history of each of the data writers, the cache of each of the data readers, the fragments waiting to be sent or
+    /// reassembled and the discovery data. Comparing the sample counts with the
+    /// [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy) of the entities helps sizing the
+    /// limits, while a steady growth of the values points to a leak.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_memory_usage_statistics(&self) -> DdsResult<MemoryUsageStatistics> {
+        R::block_on(self.participant_async.get_memory_usage_statistics())
+    }
+
+    /// This operation returns the [`TaskHealthStatus`] of the internal tasks of the [`DomainParticipant`] as supervised by the
+    /// watchdog enabled with
+    /// [`DustDdsConfigurationBuilder::task_stall_threshold`](crate::configuration::DustDdsConfigurationBuilder::task_stall_threshold).
+    /// The status is read without going through the task processing the participant messages so it remains available while
+    /// that task is stalled. The tasks of the transport are supervised if the transport reports their progress, as the UDP transport does.
+    /// A task becoming stalled is also notified to the [`DomainParticipantListener::on_task_stalled`] method of the listener installed
+    /// with the [`StatusKind::TaskStalled`] status. The status is empty if the watchdog is disabled.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_task_health_status(&self) -> DdsResult<TaskHealthStatus> {
+        R::block_on(self.participant_async.get_task_health_status())
+    }
+
+    /// This operation sets the [`DiagnosticVerbosity`] of the diagnostics emitted by the given [`DiagnosticSubsystem`] as
+    /// [`tracing`] events. Raising the verbosity of a subsystem allows investigating a problem of a running application without
+    /// rebuilding it, provided its tracing subscriber lets the events through. Since the tracing subscriber is shared by the
+    /// whole application, the verbosity applies to all the participants. The default verbosity of every subsystem is
+    /// [`DiagnosticVerbosity::Warn`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn set_diagnostic_verbosity(
+        &self,
+        subsystem: DiagnosticSubsystem,
+        verbosity: DiagnosticVerbosity,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.participant_async
+                .set_diagnostic_verbosity(subsystem, verbosity),
+        )
+    }
+
+    /// This operation returns the [`DiagnosticVerbosity`] of the diagnostics emitted by the given [`DiagnosticSubsystem`], as
+    /// set with [`DomainParticipant::set_diagnostic_verbosity`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_diagnostic_verbosity(
+        &self,
+        subsystem: DiagnosticSubsystem,
+    ) -> DdsResult<DiagnosticVerbosity> {
+        R::block_on(self.participant_async.get_diagnostic_verbosity(subsystem))
+    }
+
+    /// This operation sets the [`BandwidthQuota`] limiting the amount of data which the writers of the topic with name
+    /// `topic_name` send. The writers are paced by a flow controller of the transport: once the quota of the current period is
+    /// used, their messages are held and sent in the following periods, and the flow controller assigned by their
+    /// [`FlowControllerQosPolicy`](crate::infrastructure::qos_policy::FlowControllerQosPolicy) is not used. Setting the value
+    /// [`None`] removes the limit.
+    /// The topic must have been created by this participant and the quota must allow sending some bytes in a period of
+    /// non-zero duration, otherwise the operation will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn set_topic_bandwidth_quota(
+        &self,
+        topic_name: &str,
+        bandwidth_quota: Option<BandwidthQuota>,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.participant_async
+                .set_topic_bandwidth_quota(topic_name, bandwidth_quota),
+        )
+    }
+
+    /// This operation sets the [`EndpointAuthorizationHook`] deciding whether the discovered endpoints may match the endpoints
+    /// of this participant. The hook applies to the endpoints evaluated for matching after it is set. Endpoints already matched
+    /// are only reevaluated when they announce themselves again. Setting the value [`None`] removes the hook so that all the
+    /// discovered endpoints are authorized.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, hook))]
+    pub fn set_endpoint_authorization_hook(
+        &self,
+        hook: Option<Arc<dyn EndpointAuthorizationHook>>,
+    ) -> DdsResult<()> {
+        R::block_on(self.participant_async.set_endpoint_authorization_hook(hook))
+    }
+
     /// This operation retrieves the list of Topics that have been discovered in the domain and that the application has not indicated
     /// should be *ignored* by means of the [`DomainParticipant::ignore_topic()`] operation.
     #[tracing::instrument(skip(self))]
@@ -128,12 +128,30 @@ impl<R: DdsRuntime> DomainParticipantFactory<R> {
     pub fn set_transport(&self, transport: DdsTransportParticipantFactory) -> DdsResult<()> {
         R::block_on(self.participant_factory_async.set_transport(transport))
     }
+
+    /// Apply the settings of the [`ConfigurationFile`](crate::configuration_file::ConfigurationFile) to the
+    /// [`DomainParticipantFactory`] singleton. Only the settings present in the file are applied: the initial peers
+    /// are appended to the ones of the current configuration, the default domain id and the given fields of the
+    /// default participant QoS are replaced and, if an interface name is given, the transport is replaced by one using
+    /// only that interface. The settings only apply to the [`DomainParticipant`] entities created afterwards.
+    #[cfg(feature = "std")]
+    pub fn set_configuration_from_file(
+        &self,
+        configuration_file: &crate::configuration_file::ConfigurationFile,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.participant_factory_async
+                .set_configuration_from_file(configuration_file),
+        )
+    }
 }
 
 #[cfg(feature = "std")]
 impl DomainParticipantFactory<crate::std_runtime::StdRuntime> {
     /// This operation returns the [`DomainParticipantFactory`] singleton. The operation is idempotent, that is, it can be called multiple
     /// times without side-effects and it will return the same [`DomainParticipantFactory`] instance.
+    /// The settings of the [`ConfigurationFile`](crate::configuration_file::ConfigurationFile) given in the environment
+    /// are applied when the singleton is created.
     #[tracing::instrument]
     pub fn get_instance() -> &'static Self {
         static PARTICIPANT_FACTORY: std::sync::OnceLock<
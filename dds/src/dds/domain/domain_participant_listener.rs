@@ -1,11 +1,12 @@
 use crate::{
     runtime::DdsRuntime,
     dds_async::{data_reader::DataReaderAsync, data_writer::DataWriterAsync, topic::TopicAsync},
+    domain::task_health::TaskHealthStatus,
     infrastructure::status::{
         InconsistentTopicStatus, LivelinessChangedStatus, LivelinessLostStatus,
         OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
-        RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus, SampleLostStatus,
-        SampleRejectedStatus, SubscriptionMatchedStatus,
+        PublicationThrottledStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
+        SampleLostStatus, SampleRejectedStatus, SubscriptionMatchedStatus, WriterRestartedStatus,
     },
 };
 use core::future::Future;
@@ -60,6 +61,16 @@ pub trait DomainParticipantListener<R: DdsRuntime> {
         core::future::ready(())
     }
 
+    /// Method that is called when any data reader in the domain participant reports a writer restarted status.
+    /// This method is not part of the DDS standard.
+    fn on_writer_restarted(
+        &mut self,
+        _the_reader: DataReaderAsync<R, ()>,
+        _status: WriterRestartedStatus,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
+
     /// Method that is called when any data reader in the domain participant reports a data available status.
     fn on_data_available(
         &mut self,
@@ -113,6 +124,16 @@ pub trait DomainParticipantListener<R: DdsRuntime> {
         core::future::ready(())
     }
 
+    /// Method that is called when any data writer in the domain participant reports a publication throttled status.
+    /// This method is not part of the DDS standard.
+    fn on_publication_throttled(
+        &mut self,
+        _the_writer: DataWriterAsync<R, ()>,
+        _status: PublicationThrottledStatus,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
+
     /// Method that is called when any data reader in the domain participant reports a subscription matched status.
     fn on_subscription_matched(
         &mut self,
@@ -121,4 +142,12 @@ pub trait DomainParticipantListener<R: DdsRuntime> {
     ) -> impl Future<Output = ()> + Send {
         core::future::ready(())
     }
+
+    /// Method that is called when the watchdog of the domain participant detects that one of its internal tasks became
+    /// stalled. The method is called by the watchdog directly so it is also called when the task processing the
+    /// messages of the participant is the stalled one.
+    /// This method is not part of the DDS standard.
+    fn on_task_stalled(&mut self, _status: TaskHealthStatus) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
 }
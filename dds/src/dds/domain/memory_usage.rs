@@ -0,0 +1,69 @@
+use crate::infrastructure::instance::InstanceHandle;
+use alloc::{string::String, vec::Vec};
+
+/// Approximate memory held by a data writer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataWriterMemoryUsage {
+    /// Handle of the data writer.
+    pub writer_handle: InstanceHandle,
+    /// Name of the topic of the data writer.
+    pub topic_name: String,
+    /// Number of samples kept in the history which count towards the
+    /// [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy) of the data writer.
+    pub sample_count: u64,
+    /// Number of instances registered by the data writer.
+    pub instance_count: u64,
+    /// Number of bytes held by the changes in the history of the data writer.
+    pub history_bytes: u64,
+    /// Number of bytes held by the fragments built in advance for the changes of the data writer.
+    pub fragment_buffer_bytes: u64,
+}
+
+/// Approximate memory held by a data reader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataReaderMemoryUsage {
+    /// Handle of the data reader.
+    pub reader_handle: InstanceHandle,
+    /// Name of the topic of the data reader.
+    pub topic_name: String,
+    /// Number of samples in the cache of the data reader, including the samples held back until their coherent set
+    /// is complete or until the previous samples of their writer are received.
+    pub sample_count: u64,
+    /// Number of instances known by the data reader.
+    pub instance_count: u64,
+    /// Number of bytes held by the samples in the cache of the data reader.
+    pub cache_bytes: u64,
+    /// Number of bytes held by the received fragments of the samples which are not yet complete.
+    pub fragment_buffer_bytes: u64,
+}
+
+/// Approximate memory consumption of a participant. The values account for the serialized data and the bookkeeping
+/// of the samples but not for the allocator overhead or the capacity reserved in advance, so they are meant to follow
+/// the trend of the consumption rather than to give an exact figure.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MemoryUsageStatistics {
+    /// Memory held by each of the user-defined data writers of the participant.
+    pub data_writers: Vec<DataWriterMemoryUsage>,
+    /// Memory held by each of the user-defined data readers of the participant.
+    pub data_readers: Vec<DataReaderMemoryUsage>,
+    /// Number of bytes held by the data of the discovered participants, topics, publications and subscriptions,
+    /// including the caches of the built-in data readers.
+    pub discovery_bytes: u64,
+}
+
+impl MemoryUsageStatistics {
+    /// Total number of bytes accounted for in the statistics.
+    pub fn total_bytes(&self) -> u64 {
+        let data_writer_bytes: u64 = self
+            .data_writers
+            .iter()
+            .map(|w| w.history_bytes + w.fragment_buffer_bytes)
+            .sum();
+        let data_reader_bytes: u64 = self
+            .data_readers
+            .iter()
+            .map(|r| r.cache_bytes + r.fragment_buffer_bytes)
+            .sum();
+        data_writer_bytes + data_reader_bytes + self.discovery_bytes
+    }
+}
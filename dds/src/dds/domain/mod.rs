@@ -6,3 +6,28 @@ pub mod domain_participant_factory;
 
 /// Contains the [`DomainParticipantListener`](crate::domain::domain_participant_listener::DomainParticipantListener) trait.
 pub mod domain_participant_listener;
+
+/// Contains the [`DiscoveryConformanceReport`](crate::domain::discovery_conformance::DiscoveryConformanceReport) and related types
+/// describing how the discovery data of a remote participant was interpreted.
+pub mod discovery_conformance;
+
+/// Contains the [`RemovedDiscoveredEntity`](crate::domain::discovery_history::RemovedDiscoveredEntity) describing the remote
+/// entities which were recently removed from the discovered entities of a participant.
+pub mod discovery_history;
+
+/// Contains the [`BandwidthStatistics`](crate::domain::bandwidth_statistics::BandwidthStatistics) and related types
+/// used for the accounting and limitation of the data exchanged by a participant.
+pub mod bandwidth_statistics;
+
+/// Contains the [`MemoryUsageStatistics`](crate::domain::memory_usage::MemoryUsageStatistics) and related types describing
+/// the approximate memory consumption of a participant and its entities.
+pub mod memory_usage;
+
+/// Contains the [`TaskHealthStatus`](crate::domain::task_health::TaskHealthStatus) and related types describing the
+/// health of the internal tasks of a participant.
+pub mod task_health;
+
+/// Contains the [`DiagnosticSubsystem`](crate::domain::diagnostics::DiagnosticSubsystem) and
+/// [`DiagnosticVerbosity`](crate::domain::diagnostics::DiagnosticVerbosity) used to adjust at runtime the diagnostics
+/// emitted by each subsystem of Dust DDS.
+pub mod diagnostics;
@@ -0,0 +1,47 @@
+use crate::infrastructure::time::Time;
+use alloc::vec::Vec;
+
+/// Kind of the internal tasks of a participant which are supervised by the watchdog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantTaskKind {
+    /// Task processing the operations, received messages and timed events of the participant and its entities.
+    MessageProcessing,
+    /// Task periodically announcing the participant on the network.
+    ParticipantAnnouncement,
+    /// Timer driving the periodic tasks of the participant, including the watchdog itself.
+    Timer,
+    /// Task of the transport processing the received messages and sending the periodic messages of the endpoints.
+    TransportReceiver,
+    /// Task of the transport sending the changes of the data writers which publish asynchronously.
+    TransportPublisher,
+}
+
+impl ParticipantTaskKind {
+    pub(crate) const LIST: [ParticipantTaskKind; 5] = [
+        ParticipantTaskKind::MessageProcessing,
+        ParticipantTaskKind::ParticipantAnnouncement,
+        ParticipantTaskKind::Timer,
+        ParticipantTaskKind::TransportReceiver,
+        ParticipantTaskKind::TransportPublisher,
+    ];
+}
+
+/// Internal task of a participant which made no progress within the configured threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StalledTask {
+    /// Kind of the stalled task.
+    pub task_kind: ParticipantTaskKind,
+    /// Time of the last progress recorded by the task.
+    pub stalled_since: Time,
+}
+
+/// Health of the internal tasks of a participant as evaluated by the watchdog.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TaskHealthStatus {
+    /// Tasks which are currently stalled.
+    pub stalled_task_list: Vec<StalledTask>,
+    /// Number of times any of the tasks has been detected as stalled by the watchdog.
+    pub total_stall_count: u32,
+    /// Number of times a stalled task has been restarted by the watchdog.
+    pub restart_count: u32,
+}
@@ -19,6 +19,16 @@ pub mod topic_definition;
 /// of the Dust DDS systems
 pub mod configuration;
 
+/// Contains the [`ConfigurationFile`](crate::configuration_file::ConfigurationFile) which allows setting up the
+/// [`DomainParticipantFactory`](crate::domain::domain_participant_factory::DomainParticipantFactory) from a TOML file
+/// and environment variables.
+#[cfg(feature = "std")]
+pub mod configuration_file;
+
+/// Contains the presets of the discovery timing and of the QoS used by ROS 2 which allow Dust DDS participants to take
+/// part in ROS 2 graphs alongside the middleware implementations of ROS 2.
+pub mod ros2;
+
 /// Classes related to the status conditions.
 pub mod condition;
 
@@ -27,3 +37,8 @@ pub mod listener;
 
 /// Classes related to WaitSet.
 pub mod wait_set;
+
+/// Contains the [`QosProvider`](crate::qos_provider::QosProvider) which allows loading the QoS of the entities
+/// from XML QoS profile files.
+#[cfg(feature = "std")]
+pub mod qos_provider;
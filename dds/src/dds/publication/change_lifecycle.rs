@@ -0,0 +1,4 @@
+pub use crate::transport::{
+    types::Guid,
+    writer::{ChangeLifecycleEvent, ChangeLifecycleEventKind, ChangeLifecycleObserver},
+};
@@ -1,5 +1,5 @@
 use crate::{
-    builtin_topics::SubscriptionBuiltinTopicData,
+    builtin_topics::{Locator, SubscriptionBuiltinTopicData},
     condition::StatusCondition,
     runtime::DdsRuntime,
     dds_async::data_writer::DataWriterAsync,
@@ -7,17 +7,113 @@ use crate::{
         error::DdsResult,
         instance::InstanceHandle,
         qos::{DataWriterQos, QosKind},
+        qos_policy::QosPolicyId,
+        sample_info::SequenceNumber,
         status::{
             LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
-            PublicationMatchedStatus, StatusKind,
+            PublicationMatchedStatus, PublicationThrottledStatus, StatusKind,
         },
         time::{Duration, Time},
-        type_support::DdsSerialize,
+        type_support::{DdsDeserialize, DdsSerialize},
+    },
+    publication::{
+        change_lifecycle::ChangeLifecycleObserver, data_writer_listener::DataWriterListener,
+        publisher::Publisher,
     },
-    publication::{data_writer_listener::DataWriterListener, publisher::Publisher},
     topic_definition::topic::Topic,
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::ops::{Deref, DerefMut};
+
+/// Policy applied by a [`DataWriter`] with [`HistoryQosPolicyKind::KeepLast`](crate::infrastructure::qos_policy::HistoryQosPolicyKind::KeepLast)
+/// history when a new sample has to replace the oldest sample of an instance which has not yet been acknowledged by all the
+/// matched reliable readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeepLastReplacementPolicy {
+    /// The write operation blocks until the oldest sample is acknowledged or the `max_blocking_time` of the
+    /// [`ReliabilityQosPolicy`](crate::infrastructure::qos_policy::ReliabilityQosPolicy) elapses, in which case
+    /// it returns [`DdsError::Timeout`](crate::infrastructure::error::DdsError::Timeout).
+    #[default]
+    BlockUntilAcknowledged,
+    /// The oldest sample is replaced immediately. Each replaced sample which was not yet acknowledged is counted and can be
+    /// retrieved with [`DataWriter::get_replaced_unacknowledged_sample_count`].
+    OverwriteOldest,
+}
+
+/// Mode used by a [`DataWriter`] to suppress the publication of a sample which is identical to the last sample
+/// published for the same instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateSuppressionMode {
+    /// All the written samples are published.
+    #[default]
+    Disabled,
+    /// A sample is not published if its serialized data is identical to the one of the last sample published for the instance.
+    SerializedData,
+    /// A sample written with [`DataWriter::write_w_hash`] is not published if its hash is equal to the hash of the last sample
+    /// published for the instance. Samples written without a hash are always published.
+    UserProvidedHash,
+}
+
+/// Configuration of the throttling of the publication of a [`DataWriter`] whose matched reliable readers lag behind.
+/// The lag is the number of changes written after the last change acknowledged by all the matched reliable readers.
+/// When it exceeds the `throttle_threshold` the writes of each instance are held and only the latest value is published
+/// once per `throttled_publication_period`, so that the intermediate values are dropped as with a
+/// [`HistoryQosPolicyKind::KeepLast`](crate::infrastructure::qos_policy::HistoryQosPolicyKind::KeepLast) history. The
+/// throttling stops once the lag falls to the `release_threshold`, which must be lower than the `throttle_threshold` so that
+/// the writer does not switch back and forth around a single value. The lag is evaluated on each write and once per
+/// `throttled_publication_period`, so that the throttling also stops when the readers caught up after the last write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicationThrottling {
+    /// Lag above which the publication is throttled.
+    pub throttle_threshold: u32,
+    /// Lag at or below which the publication is no longer throttled.
+    pub release_threshold: u32,
+    /// Period at which the latest value of each instance is published while the publication is throttled.
+    pub throttled_publication_period: Duration,
+}
+
+/// Identity of the writer which originally published a sample forwarded with [`DataWriter::write_forwarded`], like the
+/// writer of a sample stored by a persistence service or received by a relay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalWriterInfo {
+    /// Handle of the original writer, as given by the `publication_handle` of the
+    /// [`SampleInfo`](crate::infrastructure::sample_info::SampleInfo) of its samples.
+    pub original_writer: InstanceHandle,
+    /// Sequence number of the sample in the original writer.
+    pub original_sequence_number: SequenceNumber,
+}
+
+/// Sample loaned by a [`DataWriter`] with [`DataWriter::loan_sample`]. The application builds the value of the sample in
+/// place through the loan and publishes it with [`DataWriter::write_loaned`], which serializes the data directly from the
+/// loaned storage and gives the storage back to the [`DataWriter`] to be reused by the next loan.
+#[derive(Debug)]
+pub struct SampleLoan<Foo> {
+    value: Box<Foo>,
+}
+
+impl<Foo> SampleLoan<Foo> {
+    pub(crate) fn new(value: Box<Foo>) -> Self {
+        Self { value }
+    }
+
+    pub(crate) fn into_storage(self) -> Box<Foo> {
+        self.value
+    }
+}
+
+impl<Foo> Deref for SampleLoan<Foo> {
+    type Target = Foo;
+
+    fn deref(&self) -> &Foo {
+        &self.value
+    }
+}
+
+impl<Foo> DerefMut for SampleLoan<Foo> {
+    fn deref_mut(&mut self) -> &mut Foo {
+        &mut self.value
+    }
+}
 
 /// The [`DataWriter`] allows the application to set the value of the
 /// data to be published under a given [`Topic`].
@@ -65,6 +161,8 @@ where
     /// allocated [`InstanceHandle`]. This may be used to lookup and retrieve the handle allocated to a given instance.
     /// The explicit use of this operation is optional as the application may call directly [`DataWriter::write`]
     /// and specify no [`InstanceHandle`] to indicate that the *key* should be examined to identify the instance.
+    /// Passing the returned handle to [`DataWriter::write`], [`DataWriter::dispose`] and [`DataWriter::unregister_instance`]
+    /// lets the Service reuse the serialized key of the registered instance instead of extracting it from every sample.
     #[tracing::instrument(skip(self, instance))]
     pub fn register_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
         R::block_on(self.writer_async.register_instance(instance))
@@ -141,15 +239,6 @@ where
         )
     }
 
-    /// This operation can be used to retrieve the instance key that corresponds to an `handle`. The operation will only fill the
-    /// fields that form the key inside the `key_holder` instance.
-    /// This operation returns [`DdsError::BadParameter`](crate::infrastructure::error::DdsError) if the `handle` does not
-    /// correspond to an existing data object known to the [`DataWriter`].
-    #[tracing::instrument(skip(self, key_holder))]
-    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
-        R::block_on(self.writer_async.get_key_value(key_holder, handle))
-    }
-
     /// This operation takes as a parameter an instance and returns an [`InstanceHandle`] that can be used in subsequent operations
     /// that accept an [`InstanceHandle`] as an argument. The `instance` parameter is only used for the purpose of examining the
     /// fields that define the key.
@@ -192,11 +281,49 @@ where
     /// provided that the reason for blocking would be that the [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy)
     /// is exceeded and the service determines that even waiting the [`ReliabilityQosPolicy::max_waiting_time`](crate::infrastructure::qos_policy::ReliabilityQosPolicy) has no
     /// chance of freeing the necessary resources. For example, if the only way to gain the necessary resources would be for the user to unregister an instance.
+    ///
+    /// On success the operation returns the [`SequenceNumber`] assigned to the published sample. This value can be compared with
+    /// the one returned by [`DataReader::get_matched_publication_high_watermark`](crate::subscription::data_reader::DataReader::get_matched_publication_high_watermark).
+    /// [`None`] is returned if the sample is not published because its [`LifespanQosPolicy`](crate::infrastructure::qos_policy::LifespanQosPolicy)
+    /// has already expired at the time of writing, because it is suppressed as a duplicate according to the
+    /// [`DuplicateSuppressionMode`] of the [`DataWriter`] or because it is held in a write coalescing window.
     #[tracing::instrument(skip(self, data))]
-    pub fn write(&self, data: &Foo, handle: Option<InstanceHandle>) -> DdsResult<()> {
+    pub fn write(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<Option<SequenceNumber>> {
         R::block_on(self.writer_async.write(data, handle))
     }
 
+    /// This operation loans the storage of a sample from the [`DataWriter`]. The sample can then be modified in place and
+    /// published with [`DataWriter::write_loaned`] avoiding the construction of an intermediate value by the application
+    /// for large samples. The storage of the samples published with [`DataWriter::write_loaned`] is reused by the next loans
+    /// so the loaned sample holds the value of a previously written sample, or the default value if no storage is available.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn loan_sample(&self) -> DdsResult<SampleLoan<Foo>>
+    where
+        Foo: Default + Send + 'static,
+    {
+        R::block_on(self.writer_async.loan_sample())
+    }
+
+    /// This operation publishes a sample loaned with [`DataWriter::loan_sample`] in the same way as [`DataWriter::write`]
+    /// and returns the loan to the [`DataWriter`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, sample))]
+    pub fn write_loaned(
+        &self,
+        sample: SampleLoan<Foo>,
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<Option<SequenceNumber>>
+    where
+        Foo: Send + 'static,
+    {
+        R::block_on(self.writer_async.write_loaned(sample, handle))
+    }
+
     /// This operation performs the same function and returns the same values as [`DataWriter::write`] and can
     /// be used instead of [`DataWriter::write`] in the cases where the application desires to specify the
     /// value for the `source_timestamp`.
@@ -208,10 +335,71 @@ where
         data: &Foo,
         handle: Option<InstanceHandle>,
         timestamp: Time,
-    ) -> DdsResult<()> {
+    ) -> DdsResult<Option<SequenceNumber>> {
         R::block_on(self.writer_async.write_w_timestamp(data, handle, timestamp))
     }
 
+    /// This operation performs the same function and returns the same values as [`DataWriter::write`] and can
+    /// be used instead of [`DataWriter::write`] in the cases where the application provides a hash identifying the
+    /// value of the sample. When the [`DuplicateSuppressionMode::UserProvidedHash`] mode is set, the sample is not
+    /// published if `sample_hash` is equal to the hash of the last sample published for the same instance.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, data))]
+    pub fn write_w_hash(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        sample_hash: u64,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        R::block_on(self.writer_async.write_w_hash(data, handle, sample_hash))
+    }
+
+    /// This operation performs the same function and returns the same values as [`DataWriter::write`] but the sample is only
+    /// delivered by the matched [`DataReader`](crate::subscription::data_reader::DataReader) objects whose handles are in
+    /// `reader_handle_list`, as returned by [`DataWriter::get_matched_subscriptions`]. The readers are carried in the directed
+    /// write parameter of the RTPS message so the other readers receiving the message discard the sample.
+    /// An empty `reader_handle_list` addresses the sample to all the readers. The operation returns
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError) if any of the handles does not correspond to a
+    /// matched reader. A directed write is never held in a write coalescing window nor suppressed as a duplicate.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, data))]
+    pub fn write_directed(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        reader_handle_list: &[InstanceHandle],
+    ) -> DdsResult<Option<SequenceNumber>> {
+        R::block_on(
+            self.writer_async
+                .write_directed(data, handle, reader_handle_list),
+        )
+    }
+
+    /// This operation performs the same function and returns the same values as [`DataWriter::write_w_timestamp`] for a sample
+    /// which was originally published by another writer and is forwarded by this one, like a persistence service or a relay
+    /// does. The `original_writer_info` is carried in the original writer info parameter of the RTPS message so the readers
+    /// report the original writer as the `publication_handle` of the sample and use it for the
+    /// [`DestinationOrderQosPolicy`](crate::infrastructure::qos_policy::DestinationOrderQosPolicy) and the exclusive
+    /// [`OwnershipQosPolicy`](crate::infrastructure::qos_policy::OwnershipQosPolicy). The `timestamp` is usually the
+    /// `source_timestamp` of the original sample. A forwarded sample is never held in a write coalescing window nor
+    /// suppressed as a duplicate.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, data))]
+    pub fn write_forwarded(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+        original_writer_info: OriginalWriterInfo,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        R::block_on(self.writer_async.write_forwarded(
+            data,
+            handle,
+            timestamp,
+            original_writer_info,
+        ))
+    }
+
     /// This operation requests the middleware to delete the data (the actual deletion is postponed until there is no more use for that
     /// data in the whole system). In general, applications are made aware of the deletion by means of operations on the
     /// [`DataReader`](crate::subscription::data_reader::DataReader) objects that already knew the instance.
@@ -248,7 +436,36 @@ where
     }
 }
 
+impl<R: DdsRuntime, Foo> DataWriter<R, Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
+    /// This operation can be used to retrieve the instance key that corresponds to an `handle`. The operation will only fill the
+    /// fields that form the key inside the `key_holder` instance.
+    /// This operation returns [`DdsError::BadParameter`](crate::infrastructure::error::DdsError) if the `handle` does not
+    /// correspond to an existing data object known to the [`DataWriter`].
+    #[tracing::instrument(skip(self, key_holder))]
+    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
+        R::block_on(self.writer_async.get_key_value(key_holder, handle))
+    }
+}
+
 impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
+    /// This operation publishes a sample which is already serialized, bypassing the serialization of `Foo`. The
+    /// `serialized_data` must start with the encapsulation header followed by the payload in the corresponding representation,
+    /// as returned by [`SerializedSample::serialized_data`](crate::infrastructure::sample_info::SerializedSample::serialized_data).
+    /// The instance of the sample is obtained from the key fields in the payload, and a payload which can not be interpreted
+    /// as the type of the [`Topic`] makes the operation fail. Otherwise it behaves as [`DataWriter::write`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, serialized_data))]
+    pub fn write_serialized(
+        &self,
+        serialized_data: &[u8],
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        R::block_on(self.writer_async.write_serialized(serialized_data, handle))
+    }
+
     /// This operation blocks the calling thread until either all data written by the [`DataWriter`] is acknowledged by all
     /// matched [`DataReader`](crate::subscription::data_reader::DataReader) entities that have
     /// [`ReliabilityQosPolicyKind::Reliable`](crate::infrastructure::qos_policy::ReliabilityQosPolicyKind), or else the duration
@@ -262,12 +479,44 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
         R::block_on(self.writer_async.wait_for_acknowledgments(max_wait))
     }
 
+    /// This operation sends the samples held by the [`DataWriter`] in the current batch without waiting for the batch to reach its
+    /// limits or its flush delay as configured by the [`BatchQosPolicy`](crate::infrastructure::qos_policy::BatchQosPolicy).
+    /// It allows the application to decide when the latency of the batched samples should end. The values held by the coalescing
+    /// windows set with [`DataWriter::set_write_coalescing_window`] are published as well. The operation returns immediately
+    /// with [`Ok`] if batching is not enabled or no samples are being held.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn flush(&self) -> DdsResult<()> {
+        R::block_on(self.writer_async.flush())
+    }
+
+    /// This operation performs a clean shutdown of the [`DataWriter`]. From the moment it is called the [`DataWriter`] stops accepting
+    /// new samples and [`DataWriter::write`], [`DataWriter::dispose`], [`DataWriter::register_instance`] and their variants fail with
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError). The operation then waits until all the samples already written are
+    /// acknowledged by the matched reliable readers and unregisters all the instances of the [`DataWriter`], which are also disposed
+    /// according to its [`WriterDataLifecycleQosPolicy`](crate::infrastructure::qos_policy::WriterDataLifecycleQosPolicy). Finally it waits for the
+    /// acknowledgment of the unregistrations so that the [`DataWriter`] can be deleted without the readers missing any of its changes.
+    /// If the whole sequence does not complete within `timeout` the operation returns [`DdsError::Timeout`](crate::infrastructure::error::DdsError)
+    /// and the [`DataWriter`] remains in the draining state.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn drain(&self, timeout: Duration) -> DdsResult<()> {
+        R::block_on(self.writer_async.drain(timeout))
+    }
+
     /// This operation allows access to the [`LivelinessLostStatus`].
     #[tracing::instrument(skip(self))]
     pub fn get_liveliness_lost_status(&self) -> DdsResult<LivelinessLostStatus> {
         R::block_on(self.writer_async.get_liveliness_lost_status())
     }
 
+    /// This operation allows access to the [`PublicationThrottledStatus`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_publication_throttled_status(&self) -> DdsResult<PublicationThrottledStatus> {
+        R::block_on(self.writer_async.get_publication_throttled_status())
+    }
+
     /// This operation allows access to the [`OfferedDeadlineMissedStatus`].
     #[tracing::instrument(skip(self))]
     pub fn get_offered_deadline_missed_status(&self) -> DdsResult<OfferedDeadlineMissedStatus> {
@@ -311,6 +560,24 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
         R::block_on(self.writer_async.assert_liveliness())
     }
 
+    /// This operation returns the identifiers of the QoS policies of the [`DataWriter`] and its [`Publisher`](crate::publication::publisher::Publisher)
+    /// which are incompatible with the QoS policies requested by the subscription described by `subscription_builtin_topic_data`.
+    /// An empty list means that the QoS policies are compatible, so that the [`DataWriter`] would be matched with the subscription
+    /// provided that it uses the same [`Topic`]. This is intended to help diagnose why a [`DataWriter`] and a
+    /// [`DataReader`](crate::subscription::data_reader::DataReader) are not communicating, e.g. by passing the data obtained
+    /// from the *DCPSSubscription* builtin topic.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn check_compatibility(
+        &self,
+        subscription_builtin_topic_data: &SubscriptionBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
+        R::block_on(
+            self.writer_async
+                .check_compatibility(subscription_builtin_topic_data),
+        )
+    }
+
     /// This operation retrieves information on a subscription that is currently *associated* with the [`DataWriter`]; that is, a subscription
     /// with a matching [`Topic`] and compatible QoS that the application has not indicated should be ignored by means of the
     /// [`DomainParticipant::ignore_subscription`](crate::domain::domain_participant::DomainParticipant) operation.
@@ -328,6 +595,23 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
         )
     }
 
+    /// This operation retrieves the unicast and multicast locators at which a subscription that is currently *associated* with the
+    /// [`DataWriter`] receives the samples, as announced in its discovery information. If the subscription did not announce any
+    /// locators the default locators of its participant are returned.
+    /// The `subscription_handle` must correspond to a subscription currently associated with the [`DataWriter`], otherwise the operation
+    /// will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_subscription_locators(
+        &self,
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
+        R::block_on(
+            self.writer_async
+                .get_matched_subscription_locators(subscription_handle),
+        )
+    }
+
     /// This operation retrieves the list of subscriptions currently *associated* with the [`DataWriter`]]; that is, subscriptions that have a
     /// matching [`Topic`] and compatible QoS that the application has not indicated should be *ignored* by means of the
     ///  [`DomainParticipant::ignore_subscription`](crate::domain::domain_participant::DomainParticipant) operation.
@@ -432,3 +716,86 @@ impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
         R::block_on(self.writer_async.set_listener(a_listener, mask))
     }
 }
+
+impl<R: DdsRuntime, Foo> DataWriter<R, Foo> {
+    /// This operation sets the [`KeepLastReplacementPolicy`] used when the history of an instance is full and its oldest sample
+    /// has not yet been acknowledged by all the matched reliable readers. It only has an effect on writers with
+    /// [`HistoryQosPolicyKind::KeepLast`](crate::infrastructure::qos_policy::HistoryQosPolicyKind::KeepLast) history and
+    /// [`ReliabilityQosPolicyKind::Reliable`](crate::infrastructure::qos_policy::ReliabilityQosPolicyKind::Reliable) reliability.
+    /// This operation is not part of the DDS standard and the default is [`KeepLastReplacementPolicy::BlockUntilAcknowledged`].
+    #[tracing::instrument(skip(self))]
+    pub fn set_keep_last_replacement_policy(
+        &self,
+        policy: KeepLastReplacementPolicy,
+    ) -> DdsResult<()> {
+        R::block_on(self.writer_async.set_keep_last_replacement_policy(policy))
+    }
+
+    /// This operation sets the [`DuplicateSuppressionMode`] of the [`DataWriter`]. Suppressing samples identical to the last
+    /// sample published for the same instance reduces the traffic of applications which periodically publish a state that rarely
+    /// changes. Suppressed samples are not sent to the readers so they do not refresh the deadline of the instance on the readers.
+    /// Changing the mode forgets the samples previously published.
+    /// This operation is not part of the DDS standard and the default is [`DuplicateSuppressionMode::Disabled`].
+    #[tracing::instrument(skip(self))]
+    pub fn set_duplicate_suppression_mode(&self, mode: DuplicateSuppressionMode) -> DdsResult<()> {
+        R::block_on(self.writer_async.set_duplicate_suppression_mode(mode))
+    }
+
+    /// This operation sets a coalescing window during which the writes to the same instance are collapsed into the latest
+    /// value. The first write of an instance opens the window and the value it holds when the window expires is published,
+    /// so that fast control loops only put the most recent state on the wire. Disposing or unregistering an instance and
+    /// [`DataWriter::flush`] publish the held values immediately. Since the intermediate values are discarded, coalescing
+    /// is only allowed on writers with a [`HistoryQosPolicyKind::KeepLast`](crate::infrastructure::qos_policy::HistoryQosPolicyKind::KeepLast)
+    /// history of depth 1 and [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError) is returned otherwise.
+    /// Passing [`None`] disables the coalescing of subsequent writes.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn set_write_coalescing_window(&self, window: Option<Duration>) -> DdsResult<()> {
+        R::block_on(self.writer_async.set_write_coalescing_window(window))
+    }
+
+    /// This operation enables the throttling of the publication of the [`DataWriter`] when the matched reliable readers lag
+    /// behind, as configured by the [`PublicationThrottling`]. Each time the throttling starts or stops the
+    /// [`PublicationThrottledStatus`] is updated and the listeners and conditions are notified. Throttling drops the
+    /// intermediate values written to an instance so it is only allowed on writers with a
+    /// [`HistoryQosPolicyKind::KeepLast`](crate::infrastructure::qos_policy::HistoryQosPolicyKind::KeepLast) history and
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError) is returned otherwise. A `release_threshold`
+    /// which is not lower than the `throttle_threshold` returns [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    /// Passing [`None`] disables the throttling.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn set_publication_throttling(
+        &self,
+        publication_throttling: Option<PublicationThrottling>,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.writer_async
+                .set_publication_throttling(publication_throttling),
+        )
+    }
+
+    /// This operation registers an observer which is notified each time a change of the [`DataWriter`] is sent, retransmitted,
+    /// acknowledged by all the matched reliable readers, replaced by a GAP or dropped from the history before being acknowledged.
+    /// The events carry the source timestamp of the sample so that applications can measure the time spent by each sample in the
+    /// middleware. Passing [`None`] removes the registered observer.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, change_lifecycle_observer))]
+    pub fn set_change_lifecycle_observer(
+        &self,
+        change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.writer_async
+                .set_change_lifecycle_observer(change_lifecycle_observer),
+        )
+    }
+
+    /// This operation returns the total number of samples which were replaced in the history of the [`DataWriter`] before being
+    /// acknowledged by all the matched reliable readers. A growing count indicates that the history depth is too shallow for the
+    /// rate at which the samples are written, causing samples to be silently lost by some readers.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_replaced_unacknowledged_sample_count(&self) -> DdsResult<u64> {
+        R::block_on(self.writer_async.get_replaced_unacknowledged_sample_count())
+    }
+}
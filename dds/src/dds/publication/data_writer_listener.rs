@@ -3,7 +3,7 @@ use crate::{
     dds_async::data_writer::DataWriterAsync,
     infrastructure::status::{
         LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
-        PublicationMatchedStatus,
+        PublicationMatchedStatus, PublicationThrottledStatus,
     },
 };
 use core::future::Future;
@@ -45,4 +45,14 @@ pub trait DataWriterListener<R: DdsRuntime, Foo>: 'static {
     ) -> impl Future<Output = ()> + Send {
         core::future::ready(())
     }
+
+    /// Method that is called when this writer reports a publication throttled status.
+    /// This method is not part of the DDS standard.
+    fn on_publication_throttled(
+        &mut self,
+        _the_writer: DataWriterAsync<R, Foo>,
+        _status: PublicationThrottledStatus,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
 }
@@ -7,5 +7,9 @@ pub mod data_writer_listener;
 /// Contains the [`Publisher`](crate::publication::publisher::Publisher) and any related objects.
 pub mod publisher;
 
+/// Contains the [`ChangeLifecycleObserver`](crate::publication::change_lifecycle::ChangeLifecycleObserver) trait and the
+/// [`ChangeLifecycleEvent`](crate::publication::change_lifecycle::ChangeLifecycleEvent) it is notified with.
+pub mod change_lifecycle;
+
 /// Contains the [`PublisherListener`](crate::publication::publisher_listener::PublisherListener) trait.
 pub mod publisher_listener;
@@ -13,7 +13,7 @@ use crate::{
     publication::data_writer::DataWriter,
     topic_definition::topic::Topic,
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 /// The [`Publisher`] acts on the behalf of one or several [`DataWriter`] objects that belong to it. When it is informed of a change to the
 /// data associated with one of its [`DataWriter`] objects, it decides when it is appropriate to actually send the data-update message.
@@ -37,6 +37,14 @@ impl<R: DdsRuntime> From<PublisherAsync<R>> for Publisher<R> {
     }
 }
 
+impl<R: DdsRuntime> Clone for Publisher<R> {
+    fn clone(&self) -> Self {
+        Self {
+            publisher_async: self.publisher_async.clone(),
+        }
+    }
+}
+
 impl<R: DdsRuntime> Publisher<R> {
     /// This operation creates a [`DataWriter`]. The returned [`DataWriter`] will be attached and belongs to the [`Publisher`].
     /// The [`DataWriter`] returned by this operation has an associated [`Topic`] and a type `Foo`.
@@ -71,6 +79,30 @@ impl<R: DdsRuntime> Publisher<R> {
         .map(DataWriter::from)
     }
 
+    /// This operation creates a [`DataWriter`] in the same way as [`Publisher::create_datawriter`] but its listener
+    /// callbacks are executed by `listener_executor` rather than by the executor of the [`DomainParticipant`], which
+    /// also handles the RTPS message processing. This allows listeners with expensive callbacks to be isolated on their
+    /// own executor without stalling the communication.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, a_topic, a_listener, listener_executor))]
+    pub fn create_datawriter_with_executor<Foo>(
+        &self,
+        a_topic: &Topic<R>,
+        qos: QosKind<DataWriterQos>,
+        a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+        listener_executor: &R::SpawnerHandle,
+    ) -> DdsResult<DataWriter<R, Foo>> {
+        R::block_on(self.publisher_async.create_datawriter_with_executor::<Foo>(
+            a_topic.topic_async(),
+            qos,
+            a_listener,
+            mask,
+            listener_executor,
+        ))
+        .map(DataWriter::from)
+    }
+
     /// This operation deletes a [`DataWriter`] that belongs to the [`Publisher`]. This operation must be called on the
     /// same [`Publisher`] object used to create the [`DataWriter`]. If [`Publisher::delete_datawriter`] is called on a
     /// different [`Publisher`], the operation will have no effect and it will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
@@ -137,6 +169,10 @@ impl<R: DdsRuntime> Publisher<R> {
     /// the values are inter-related (for example, if there are two data-instances representing the 'altitude' and 'velocity vector' of the
     /// same aircraft and both are changed, it may be useful to communicate those values in a way the reader can see both together;
     /// otherwise, it may e.g., erroneously interpret that the aircraft is on a collision course).
+    /// The changes only form a *coherent set* if the [`PresentationQosPolicy`](crate::infrastructure::qos_policy::PresentationQosPolicy)
+    /// of the [`Publisher`] has `coherent_access` enabled. The changes of each [`DataWriter`] are currently delivered as a separate set,
+    /// which corresponds to the [`PresentationQosPolicyAccessScopeKind::Topic`](crate::infrastructure::qos_policy::PresentationQosPolicyAccessScopeKind)
+    /// access scope, to the readers whose [`Subscriber`](crate::subscription::subscriber::Subscriber) also has `coherent_access` enabled.
     #[tracing::instrument(skip(self))]
     pub fn begin_coherent_changes(&self) -> DdsResult<()> {
         R::block_on(self.publisher_async.begin_coherent_changes())
@@ -149,6 +185,28 @@ impl<R: DdsRuntime> Publisher<R> {
         R::block_on(self.publisher_async.end_coherent_changes())
     }
 
+    /// This operation opens a partition session on the [`Publisher`]. Until the matching call to [`Publisher::end_partition_session`],
+    /// the partitions in `partition_list` are added to the ones of the [`PartitionQosPolicy`](crate::infrastructure::qos_policy::PartitionQosPolicy)
+    /// of the [`Publisher`] so that the samples written by its [`DataWriter`] objects are also published in those partitions. The
+    /// [`DataWriter`] objects are announced again whenever the session changes the partitions in which they publish. The QoS returned
+    /// by [`Publisher::get_qos`] is not modified by the session.
+    /// Only one session can be open at a time on a [`Publisher`], otherwise the operation will return
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn begin_partition_session(&self, partition_list: Vec<String>) -> DdsResult<()> {
+        R::block_on(self.publisher_async.begin_partition_session(partition_list))
+    }
+
+    /// This operation closes the partition session opened by the matching call to [`Publisher::begin_partition_session`] and
+    /// restores the partitions of the [`PartitionQosPolicy`](crate::infrastructure::qos_policy::PartitionQosPolicy) of the [`Publisher`].
+    /// If there is no open session, the operation will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn end_partition_session(&self) -> DdsResult<()> {
+        R::block_on(self.publisher_async.end_partition_session())
+    }
+
     /// This operation blocks the calling thread until either all data written by the reliable [`DataWriter`] entities is acknowledged by all
     /// matched reliable [`DataReader`](crate::subscription::data_reader::DataReader) entities, or else the duration specified by
     /// the `max_wait` parameter elapses, whichever happens first. A return value of [`Ok`] indicates that all the samples written
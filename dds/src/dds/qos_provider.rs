@@ -0,0 +1,901 @@
+use crate::infrastructure::{
+    error::{DdsError, DdsResult},
+    qos::{
+        DataReaderQos, DataWriterQos, DomainParticipantQos, PublisherQos, SubscriberQos, TopicQos,
+    },
+    qos_policy::{
+        BatchQosPolicy, DataRepresentationQosPolicy, DeadlineQosPolicy, DestinationOrderQosPolicy,
+        DestinationOrderQosPolicyKind, DisablePositiveAcksQosPolicy, DurabilityQosPolicy,
+        DurabilityQosPolicyKind, EndpointBehaviorQosPolicy, EndpointBehaviorQosPolicyKind,
+        EntityFactoryQosPolicy, FlowControllerQosPolicy, GroupDataQosPolicy, GuidPrefixQosPolicy,
+        GuidPrefixQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind, LatencyBudgetQosPolicy,
+        Length, LifespanQosPolicy, LivelinessQosPolicy, LivelinessQosPolicyKind,
+        OwnershipQosPolicy, OwnershipQosPolicyKind, OwnershipStrengthQosPolicy, PartitionQosPolicy,
+        PresentationQosPolicy, PresentationQosPolicyAccessScopeKind, PublishModeQosPolicy,
+        PublishModeQosPolicyKind, ReaderDataLifecycleQosPolicy, ReaderProtocolQosPolicy,
+        ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+        TimeBasedFilterQosPolicy, TopicDataQosPolicy, TransportPriorityQosPolicy,
+        UserDataQosPolicy, WriterDataLifecycleQosPolicy, WriterProtocolQosPolicy,
+        XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION, XML_DATA_REPRESENTATION,
+    },
+    time::{Duration, DurationKind},
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use roxmltree::{Document, Node};
+
+/// Set of QoS values defined by a single `<qos_profile>` element.
+#[derive(Debug, Default, Clone)]
+struct QosProfile {
+    name: String,
+    domain_participant_qos: DomainParticipantQos,
+    publisher_qos: PublisherQos,
+    subscriber_qos: SubscriberQos,
+    topic_qos: TopicQos,
+    datawriter_qos: DataWriterQos,
+    datareader_qos: DataReaderQos,
+}
+
+/// The [`QosProvider`] gives access to the QoS profiles defined in a file following the OMG DDS-XML format,
+/// so that the QoS of the entities can be tuned without recompiling the application.
+///
+/// Profiles are defined inside `<qos_library>` elements and are looked up using their fully qualified name
+/// `"library_name::profile_name"`. A profile can derive from another profile using the `base_name` attribute,
+/// in which case only the policies explicitly listed in the derived profile override the base values.
+///
+/// ```xml
+/// <dds>
+///     <qos_library name="MyLibrary">
+///         <qos_profile name="Reliable">
+///             <datawriter_qos>
+///                 <reliability>
+///                     <kind>RELIABLE_RELIABILITY_QOS</kind>
+///                 </reliability>
+///                 <history>
+///                     <kind>KEEP_LAST_HISTORY_QOS</kind>
+///                     <depth>10</depth>
+///                 </history>
+///             </datawriter_qos>
+///         </qos_profile>
+///     </qos_library>
+/// </dds>
+/// ```
+#[derive(Debug, Clone)]
+pub struct QosProvider {
+    profiles: Vec<QosProfile>,
+}
+
+impl QosProvider {
+    /// Create a new [`QosProvider`] by reading the QoS profiles from the XML file in the given `path`.
+    pub fn new(path: impl AsRef<std::path::Path>) -> DdsResult<Self> {
+        let xml = std::fs::read_to_string(path)
+            .map_err(|e| DdsError::Error(format!("Failed to read QoS profile file: {}", e)))?;
+        Self::from_xml(&xml)
+    }
+
+    /// Create a new [`QosProvider`] from the QoS profiles defined in the `xml` string.
+    pub fn from_xml(xml: &str) -> DdsResult<Self> {
+        let document = Document::parse(xml)
+            .map_err(|e| DdsError::Error(format!("Failed to parse QoS profile XML: {}", e)))?;
+
+        let mut profile_nodes = Vec::new();
+        for library in document
+            .descendants()
+            .filter(|n| n.has_tag_name("qos_library"))
+        {
+            let library_name = required_attribute(library, "name")?;
+            for profile in library.children().filter(|n| n.has_tag_name("qos_profile")) {
+                let profile_name = required_attribute(profile, "name")?;
+                profile_nodes.push((format!("{}::{}", library_name, profile_name), profile));
+            }
+        }
+
+        let mut profiles = Vec::new();
+        for (name, _) in &profile_nodes {
+            resolve_profile(name, &profile_nodes, &mut profiles, &mut Vec::new())?;
+        }
+
+        Ok(Self { profiles })
+    }
+
+    /// Get the names of all the profiles available in this [`QosProvider`].
+    pub fn profile_names(&self) -> Vec<String> {
+        self.profiles.iter().map(|p| p.name.clone()).collect()
+    }
+
+    /// Get the [`DomainParticipantQos`] defined in the profile with the given `profile_name`.
+    pub fn get_domain_participant_qos(
+        &self,
+        profile_name: &str,
+    ) -> DdsResult<DomainParticipantQos> {
+        Ok(self
+            .get_profile(profile_name)?
+            .domain_participant_qos
+            .clone())
+    }
+
+    /// Get the [`PublisherQos`] defined in the profile with the given `profile_name`.
+    pub fn get_publisher_qos(&self, profile_name: &str) -> DdsResult<PublisherQos> {
+        Ok(self.get_profile(profile_name)?.publisher_qos.clone())
+    }
+
+    /// Get the [`SubscriberQos`] defined in the profile with the given `profile_name`.
+    pub fn get_subscriber_qos(&self, profile_name: &str) -> DdsResult<SubscriberQos> {
+        Ok(self.get_profile(profile_name)?.subscriber_qos.clone())
+    }
+
+    /// Get the [`TopicQos`] defined in the profile with the given `profile_name`.
+    pub fn get_topic_qos(&self, profile_name: &str) -> DdsResult<TopicQos> {
+        Ok(self.get_profile(profile_name)?.topic_qos.clone())
+    }
+
+    /// Get the [`DataWriterQos`] defined in the profile with the given `profile_name`.
+    pub fn get_datawriter_qos(&self, profile_name: &str) -> DdsResult<DataWriterQos> {
+        Ok(self.get_profile(profile_name)?.datawriter_qos.clone())
+    }
+
+    /// Get the [`DataReaderQos`] defined in the profile with the given `profile_name`.
+    pub fn get_datareader_qos(&self, profile_name: &str) -> DdsResult<DataReaderQos> {
+        Ok(self.get_profile(profile_name)?.datareader_qos.clone())
+    }
+
+    fn get_profile(&self, profile_name: &str) -> DdsResult<&QosProfile> {
+        self.profiles
+            .iter()
+            .find(|p| p.name == profile_name)
+            .ok_or(DdsError::BadParameter)
+    }
+}
+
+fn resolve_profile(
+    name: &str,
+    profile_nodes: &[(String, Node)],
+    profiles: &mut Vec<QosProfile>,
+    resolving: &mut Vec<String>,
+) -> DdsResult<QosProfile> {
+    if let Some(profile) = profiles.iter().find(|p| p.name == name) {
+        return Ok(profile.clone());
+    }
+    if resolving.iter().any(|n| n == name) {
+        return Err(DdsError::Error(format!(
+            "Circular base_name reference in QoS profile {}",
+            name
+        )));
+    }
+    let Some((_, node)) = profile_nodes.iter().find(|(n, _)| n == name) else {
+        return Err(DdsError::Error(format!("Unknown QoS profile {}", name)));
+    };
+
+    resolving.push(name.to_string());
+    let mut profile = match node.attribute("base_name") {
+        Some(base_name) => resolve_profile(base_name, profile_nodes, profiles, resolving)?,
+        None => QosProfile::default(),
+    };
+    resolving.pop();
+
+    profile.name = name.to_string();
+    for qos in node.children().filter(Node::is_element) {
+        match qos.tag_name().name() {
+            "domain_participant_qos" | "participant_qos" => {
+                parse_domain_participant_qos(qos, &mut profile.domain_participant_qos)?
+            }
+            "publisher_qos" => parse_publisher_qos(qos, &mut profile.publisher_qos)?,
+            "subscriber_qos" => parse_subscriber_qos(qos, &mut profile.subscriber_qos)?,
+            "topic_qos" => parse_topic_qos(qos, &mut profile.topic_qos)?,
+            "datawriter_qos" => parse_datawriter_qos(qos, &mut profile.datawriter_qos)?,
+            "datareader_qos" => parse_datareader_qos(qos, &mut profile.datareader_qos)?,
+            _ => (),
+        }
+    }
+    profiles.push(profile.clone());
+    Ok(profile)
+}
+
+fn parse_domain_participant_qos(node: Node, qos: &mut DomainParticipantQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "user_data" => qos.user_data = parse_user_data(policy)?,
+            "entity_factory" => qos.entity_factory = parse_entity_factory(policy)?,
+            "guid_prefix" => qos.guid_prefix = parse_guid_prefix(policy)?,
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_publisher_qos(node: Node, qos: &mut PublisherQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "presentation" => parse_presentation(policy, &mut qos.presentation)?,
+            "partition" => qos.partition = parse_partition(policy),
+            "group_data" => qos.group_data = parse_group_data(policy)?,
+            "entity_factory" => qos.entity_factory = parse_entity_factory(policy)?,
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_subscriber_qos(node: Node, qos: &mut SubscriberQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "presentation" => parse_presentation(policy, &mut qos.presentation)?,
+            "partition" => qos.partition = parse_partition(policy),
+            "group_data" => qos.group_data = parse_group_data(policy)?,
+            "entity_factory" => qos.entity_factory = parse_entity_factory(policy)?,
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_topic_qos(node: Node, qos: &mut TopicQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "topic_data" => qos.topic_data = parse_topic_data(policy)?,
+            "durability" => qos.durability = parse_durability(policy)?,
+            "deadline" => qos.deadline = parse_deadline(policy)?,
+            "latency_budget" => qos.latency_budget = parse_latency_budget(policy)?,
+            "liveliness" => parse_liveliness(policy, &mut qos.liveliness)?,
+            "reliability" => parse_reliability(policy, &mut qos.reliability)?,
+            "destination_order" => qos.destination_order = parse_destination_order(policy)?,
+            "history" => parse_history(policy, &mut qos.history)?,
+            "resource_limits" => parse_resource_limits(policy, &mut qos.resource_limits)?,
+            "transport_priority" => qos.transport_priority = parse_transport_priority(policy)?,
+            "lifespan" => qos.lifespan = parse_lifespan(policy)?,
+            "ownership" => qos.ownership = parse_ownership(policy)?,
+            "representation" => qos.representation = parse_representation(policy)?,
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_datawriter_qos(node: Node, qos: &mut DataWriterQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "durability" => qos.durability = parse_durability(policy)?,
+            "deadline" => qos.deadline = parse_deadline(policy)?,
+            "latency_budget" => qos.latency_budget = parse_latency_budget(policy)?,
+            "liveliness" => parse_liveliness(policy, &mut qos.liveliness)?,
+            "reliability" => parse_reliability(policy, &mut qos.reliability)?,
+            "destination_order" => qos.destination_order = parse_destination_order(policy)?,
+            "history" => parse_history(policy, &mut qos.history)?,
+            "resource_limits" => parse_resource_limits(policy, &mut qos.resource_limits)?,
+            "transport_priority" => qos.transport_priority = parse_transport_priority(policy)?,
+            "lifespan" => qos.lifespan = parse_lifespan(policy)?,
+            "user_data" => qos.user_data = parse_user_data(policy)?,
+            "ownership" => qos.ownership = parse_ownership(policy)?,
+            "ownership_strength" => qos.ownership_strength = parse_ownership_strength(policy)?,
+            "writer_data_lifecycle" => {
+                qos.writer_data_lifecycle = parse_writer_data_lifecycle(policy)?
+            }
+            "representation" => qos.representation = parse_representation(policy)?,
+            "batch" => qos.batch = parse_batch(policy)?,
+            "writer_protocol" => qos.writer_protocol = parse_writer_protocol(policy)?,
+            "flow_controller" => qos.flow_controller = parse_flow_controller(policy),
+            "publish_mode" => qos.publish_mode = parse_publish_mode(policy)?,
+            "endpoint_behavior" => qos.endpoint_behavior = parse_endpoint_behavior(policy)?,
+            "disable_positive_acks" => {
+                qos.disable_positive_acks = parse_disable_positive_acks(policy)?
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_datareader_qos(node: Node, qos: &mut DataReaderQos) -> DdsResult<()> {
+    for policy in node.children().filter(Node::is_element) {
+        match policy.tag_name().name() {
+            "durability" => qos.durability = parse_durability(policy)?,
+            "deadline" => qos.deadline = parse_deadline(policy)?,
+            "latency_budget" => qos.latency_budget = parse_latency_budget(policy)?,
+            "liveliness" => parse_liveliness(policy, &mut qos.liveliness)?,
+            "reliability" => parse_reliability(policy, &mut qos.reliability)?,
+            "destination_order" => qos.destination_order = parse_destination_order(policy)?,
+            "history" => parse_history(policy, &mut qos.history)?,
+            "resource_limits" => parse_resource_limits(policy, &mut qos.resource_limits)?,
+            "user_data" => qos.user_data = parse_user_data(policy)?,
+            "ownership" => qos.ownership = parse_ownership(policy)?,
+            "time_based_filter" => qos.time_based_filter = parse_time_based_filter(policy)?,
+            "reader_data_lifecycle" => {
+                parse_reader_data_lifecycle(policy, &mut qos.reader_data_lifecycle)?
+            }
+            "representation" => qos.representation = parse_representation(policy)?,
+            "reader_protocol" => qos.reader_protocol = parse_reader_protocol(policy)?,
+            "endpoint_behavior" => qos.endpoint_behavior = parse_endpoint_behavior(policy)?,
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn parse_user_data(node: Node) -> DdsResult<UserDataQosPolicy> {
+    Ok(UserDataQosPolicy {
+        value: parse_octet_sequence(node)?,
+    })
+}
+
+fn parse_topic_data(node: Node) -> DdsResult<TopicDataQosPolicy> {
+    Ok(TopicDataQosPolicy {
+        value: parse_octet_sequence(node)?,
+    })
+}
+
+fn parse_group_data(node: Node) -> DdsResult<GroupDataQosPolicy> {
+    Ok(GroupDataQosPolicy {
+        value: parse_octet_sequence(node)?,
+    })
+}
+
+fn parse_entity_factory(node: Node) -> DdsResult<EntityFactoryQosPolicy> {
+    let mut policy = EntityFactoryQosPolicy::default();
+    if let Some(value) = child_text(node, "autoenable_created_entities") {
+        policy.autoenable_created_entities = parse_bool(value)?;
+    }
+    Ok(policy)
+}
+
+fn parse_presentation(node: Node, policy: &mut PresentationQosPolicy) -> DdsResult<()> {
+    if let Some(kind) = child_text(node, "access_scope") {
+        policy.access_scope = match kind {
+            "INSTANCE_PRESENTATION_QOS" => PresentationQosPolicyAccessScopeKind::Instance,
+            "TOPIC_PRESENTATION_QOS" => PresentationQosPolicyAccessScopeKind::Topic,
+            _ => return Err(invalid_value("access_scope", kind)),
+        };
+    }
+    if let Some(value) = child_text(node, "coherent_access") {
+        policy.coherent_access = parse_bool(value)?;
+    }
+    if let Some(value) = child_text(node, "ordered_access") {
+        policy.ordered_access = parse_bool(value)?;
+    }
+    Ok(())
+}
+
+fn parse_partition(node: Node) -> PartitionQosPolicy {
+    PartitionQosPolicy {
+        name: node
+            .children()
+            .filter(|n| n.has_tag_name("name"))
+            .flat_map(|n| n.children().filter(|n| n.has_tag_name("element")))
+            .filter_map(|n| n.text())
+            .map(|n| n.trim().to_string())
+            .collect(),
+    }
+}
+
+fn parse_durability(node: Node) -> DdsResult<DurabilityQosPolicy> {
+    let mut policy = DurabilityQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "VOLATILE_DURABILITY_QOS" => DurabilityQosPolicyKind::Volatile,
+            "TRANSIENT_LOCAL_DURABILITY_QOS" => DurabilityQosPolicyKind::TransientLocal,
+            "TRANSIENT_DURABILITY_QOS" => DurabilityQosPolicyKind::Transient,
+            "PERSISTENT_DURABILITY_QOS" => DurabilityQosPolicyKind::Persistent,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_deadline(node: Node) -> DdsResult<DeadlineQosPolicy> {
+    let mut policy = DeadlineQosPolicy::default();
+    if let Some(period) = child(node, "period") {
+        policy.period = parse_duration(period)?;
+    }
+    Ok(policy)
+}
+
+fn parse_latency_budget(node: Node) -> DdsResult<LatencyBudgetQosPolicy> {
+    let mut policy = LatencyBudgetQosPolicy::default();
+    if let Some(duration) = child(node, "duration") {
+        policy.duration = parse_duration(duration)?;
+    }
+    Ok(policy)
+}
+
+fn parse_liveliness(node: Node, policy: &mut LivelinessQosPolicy) -> DdsResult<()> {
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "AUTOMATIC_LIVELINESS_QOS" => LivelinessQosPolicyKind::Automatic,
+            "MANUAL_BY_PARTICIPANT_LIVELINESS_QOS" => LivelinessQosPolicyKind::ManualByParticipant,
+            "MANUAL_BY_TOPIC_LIVELINESS_QOS" => LivelinessQosPolicyKind::ManualByTopic,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    if let Some(lease_duration) = child(node, "lease_duration") {
+        policy.lease_duration = parse_duration(lease_duration)?;
+    }
+    Ok(())
+}
+
+fn parse_reliability(node: Node, policy: &mut ReliabilityQosPolicy) -> DdsResult<()> {
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "BEST_EFFORT_RELIABILITY_QOS" => ReliabilityQosPolicyKind::BestEffort,
+            "RELIABLE_RELIABILITY_QOS" => ReliabilityQosPolicyKind::Reliable,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    if let Some(max_blocking_time) = child(node, "max_blocking_time") {
+        policy.max_blocking_time = parse_duration(max_blocking_time)?;
+    }
+    Ok(())
+}
+
+fn parse_destination_order(node: Node) -> DdsResult<DestinationOrderQosPolicy> {
+    let mut policy = DestinationOrderQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "BY_RECEPTION_TIMESTAMP_DESTINATIONORDER_QOS" => {
+                DestinationOrderQosPolicyKind::ByReceptionTimestamp
+            }
+            "BY_SOURCE_TIMESTAMP_DESTINATIONORDER_QOS" => {
+                DestinationOrderQosPolicyKind::BySourceTimestamp
+            }
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_history(node: Node, policy: &mut HistoryQosPolicy) -> DdsResult<()> {
+    let depth = match child_text(node, "depth") {
+        Some(depth) => Some(parse_number("depth", depth)?),
+        None => None,
+    };
+    let kind = child_text(node, "kind");
+    policy.kind = match (kind, policy.kind) {
+        (Some("KEEP_ALL_HISTORY_QOS"), _) => HistoryQosPolicyKind::KeepAll,
+        (Some("KEEP_LAST_HISTORY_QOS"), HistoryQosPolicyKind::KeepLast(current_depth)) => {
+            HistoryQosPolicyKind::KeepLast(depth.unwrap_or(current_depth))
+        }
+        (Some("KEEP_LAST_HISTORY_QOS"), HistoryQosPolicyKind::KeepAll) => {
+            HistoryQosPolicyKind::KeepLast(depth.unwrap_or(1))
+        }
+        (Some(kind), _) => return Err(invalid_value("kind", kind)),
+        (None, HistoryQosPolicyKind::KeepLast(current_depth)) => {
+            HistoryQosPolicyKind::KeepLast(depth.unwrap_or(current_depth))
+        }
+        (None, HistoryQosPolicyKind::KeepAll) => HistoryQosPolicyKind::KeepAll,
+    };
+    Ok(())
+}
+
+fn parse_resource_limits(node: Node, policy: &mut ResourceLimitsQosPolicy) -> DdsResult<()> {
+    if let Some(value) = child_text(node, "max_samples") {
+        policy.max_samples = parse_length("max_samples", value)?;
+    }
+    if let Some(value) = child_text(node, "max_instances") {
+        policy.max_instances = parse_length("max_instances", value)?;
+    }
+    if let Some(value) = child_text(node, "max_samples_per_instance") {
+        policy.max_samples_per_instance = parse_length("max_samples_per_instance", value)?;
+    }
+    Ok(())
+}
+
+fn parse_transport_priority(node: Node) -> DdsResult<TransportPriorityQosPolicy> {
+    let mut policy = TransportPriorityQosPolicy::default();
+    if let Some(value) = child_text(node, "value") {
+        policy.value = parse_number("value", value)?;
+    }
+    Ok(policy)
+}
+
+fn parse_lifespan(node: Node) -> DdsResult<LifespanQosPolicy> {
+    let mut policy = LifespanQosPolicy::default();
+    if let Some(duration) = child(node, "duration") {
+        policy.duration = parse_duration(duration)?;
+    }
+    Ok(policy)
+}
+
+fn parse_ownership(node: Node) -> DdsResult<OwnershipQosPolicy> {
+    let mut policy = OwnershipQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "SHARED_OWNERSHIP_QOS" => OwnershipQosPolicyKind::Shared,
+            "EXCLUSIVE_OWNERSHIP_QOS" => OwnershipQosPolicyKind::Exclusive,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_ownership_strength(node: Node) -> DdsResult<OwnershipStrengthQosPolicy> {
+    let mut policy = OwnershipStrengthQosPolicy::default();
+    if let Some(value) = child_text(node, "value") {
+        policy.value = parse_number("value", value)?;
+    }
+    Ok(policy)
+}
+
+fn parse_writer_data_lifecycle(node: Node) -> DdsResult<WriterDataLifecycleQosPolicy> {
+    let mut policy = WriterDataLifecycleQosPolicy::default();
+    if let Some(value) = child_text(node, "autodispose_unregistered_instances") {
+        policy.autodispose_unregistered_instances = parse_bool(value)?;
+    }
+    Ok(policy)
+}
+
+fn parse_batch(node: Node) -> DdsResult<BatchQosPolicy> {
+    let mut policy = BatchQosPolicy::default();
+    if let Some(value) = child_text(node, "enable") {
+        policy.enable = parse_bool(value)?;
+    }
+    if let Some(value) = child_text(node, "max_samples") {
+        policy.max_samples = parse_length("max_samples", value)?;
+    }
+    if let Some(value) = child_text(node, "max_data_bytes") {
+        policy.max_data_bytes = parse_length("max_data_bytes", value)?;
+    }
+    if let Some(max_flush_delay) = child(node, "max_flush_delay") {
+        policy.max_flush_delay = parse_duration(max_flush_delay)?;
+    }
+    Ok(policy)
+}
+
+fn parse_writer_protocol(node: Node) -> DdsResult<WriterProtocolQosPolicy> {
+    let mut policy = WriterProtocolQosPolicy::default();
+    if let Some(heartbeat_period) = child(node, "heartbeat_period") {
+        policy.heartbeat_period = parse_finite_duration("heartbeat_period", heartbeat_period)?;
+    }
+    if let Some(nack_response_delay) = child(node, "nack_response_delay") {
+        policy.nack_response_delay =
+            parse_finite_duration("nack_response_delay", nack_response_delay)?;
+    }
+    if let Some(nack_suppression_duration) = child(node, "nack_suppression_duration") {
+        policy.nack_suppression_duration =
+            parse_finite_duration("nack_suppression_duration", nack_suppression_duration)?;
+    }
+    if let Some(min_resend_interval) = child(node, "min_resend_interval") {
+        policy.min_resend_interval =
+            parse_finite_duration("min_resend_interval", min_resend_interval)?;
+    }
+    if let Some(value) = child_text(node, "samples_per_heartbeat") {
+        policy.samples_per_heartbeat = parse_length("samples_per_heartbeat", value)?;
+    }
+    Ok(policy)
+}
+
+fn parse_flow_controller(node: Node) -> FlowControllerQosPolicy {
+    FlowControllerQosPolicy {
+        name: child_text(node, "name").unwrap_or_default().to_string(),
+    }
+}
+
+fn parse_publish_mode(node: Node) -> DdsResult<PublishModeQosPolicy> {
+    let mut policy = PublishModeQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "SYNCHRONOUS_PUBLISH_MODE_QOS" => PublishModeQosPolicyKind::Synchronous,
+            "ASYNCHRONOUS_PUBLISH_MODE_QOS" => PublishModeQosPolicyKind::Asynchronous,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_endpoint_behavior(node: Node) -> DdsResult<EndpointBehaviorQosPolicy> {
+    let mut policy = EndpointBehaviorQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "STATEFUL_ENDPOINT_BEHAVIOR_QOS" => EndpointBehaviorQosPolicyKind::Stateful,
+            "STATELESS_ENDPOINT_BEHAVIOR_QOS" => EndpointBehaviorQosPolicyKind::Stateless,
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_disable_positive_acks(node: Node) -> DdsResult<DisablePositiveAcksQosPolicy> {
+    let mut policy = DisablePositiveAcksQosPolicy::default();
+    if let Some(value) = child_text(node, "enable") {
+        policy.enable = parse_bool(value)?;
+    }
+    if let Some(duration) = child(node, "duration") {
+        policy.duration = parse_finite_duration("duration", duration)?;
+    }
+    Ok(policy)
+}
+
+fn parse_reader_protocol(node: Node) -> DdsResult<ReaderProtocolQosPolicy> {
+    let mut policy = ReaderProtocolQosPolicy::default();
+    if let Some(heartbeat_response_delay) = child(node, "heartbeat_response_delay") {
+        policy.heartbeat_response_delay =
+            parse_finite_duration("heartbeat_response_delay", heartbeat_response_delay)?;
+    }
+    if let Some(heartbeat_suppression_duration) = child(node, "heartbeat_suppression_duration") {
+        policy.heartbeat_suppression_duration = parse_finite_duration(
+            "heartbeat_suppression_duration",
+            heartbeat_suppression_duration,
+        )?;
+    }
+    Ok(policy)
+}
+
+fn parse_guid_prefix(node: Node) -> DdsResult<GuidPrefixQosPolicy> {
+    let mut policy = GuidPrefixQosPolicy::default();
+    if let Some(kind) = child_text(node, "kind") {
+        policy.kind = match kind {
+            "AUTOMATIC_GUID_PREFIX_QOS" => GuidPrefixQosPolicyKind::Automatic,
+            "MANUAL_GUID_PREFIX_QOS" => {
+                let value = parse_octet_sequence(node)?;
+                let guid_prefix = value.as_slice().try_into().map_err(|_| {
+                    invalid_value("value", child_text(node, "value").unwrap_or_default())
+                })?;
+                GuidPrefixQosPolicyKind::Manual(guid_prefix)
+            }
+            _ => return Err(invalid_value("kind", kind)),
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_time_based_filter(node: Node) -> DdsResult<TimeBasedFilterQosPolicy> {
+    let mut policy = TimeBasedFilterQosPolicy::default();
+    if let Some(minimum_separation) = child(node, "minimum_separation") {
+        policy.minimum_separation = parse_duration(minimum_separation)?;
+    }
+    Ok(policy)
+}
+
+fn parse_reader_data_lifecycle(
+    node: Node,
+    policy: &mut ReaderDataLifecycleQosPolicy,
+) -> DdsResult<()> {
+    if let Some(delay) = child(node, "autopurge_nowriter_samples_delay") {
+        policy.autopurge_nowriter_samples_delay = parse_duration(delay)?;
+    }
+    if let Some(delay) = child(node, "autopurge_disposed_samples_delay") {
+        policy.autopurge_disposed_samples_delay = parse_duration(delay)?;
+    }
+    Ok(())
+}
+
+fn parse_representation(node: Node) -> DdsResult<DataRepresentationQosPolicy> {
+    let mut value = Vec::new();
+    for element in node
+        .children()
+        .filter(|n| n.has_tag_name("value"))
+        .flat_map(|n| n.children().filter(|n| n.has_tag_name("element")))
+    {
+        let representation = element.text().unwrap_or_default().trim();
+        value.push(match representation {
+            "XCDR_DATA_REPRESENTATION" => XCDR_DATA_REPRESENTATION,
+            "XML_DATA_REPRESENTATION" => XML_DATA_REPRESENTATION,
+            "XCDR2_DATA_REPRESENTATION" => XCDR2_DATA_REPRESENTATION,
+            _ => return Err(invalid_value("representation", representation)),
+        });
+    }
+    Ok(DataRepresentationQosPolicy { value })
+}
+
+fn parse_duration(node: Node) -> DdsResult<DurationKind> {
+    let sec = child_text(node, "sec").unwrap_or("0");
+    let nanosec = child_text(node, "nanosec").unwrap_or("0");
+    if matches!(sec, "DURATION_INFINITY" | "DURATION_INFINITE_SEC")
+        || matches!(nanosec, "DURATION_INFINITY" | "DURATION_INFINITE_NSEC")
+    {
+        return Ok(DurationKind::Infinite);
+    }
+    Ok(DurationKind::Finite(Duration::new(
+        parse_number("sec", sec)?,
+        parse_number("nanosec", nanosec)?,
+    )))
+}
+
+fn parse_finite_duration(name: &str, node: Node) -> DdsResult<Duration> {
+    match parse_duration(node)? {
+        DurationKind::Finite(duration) => Ok(duration),
+        DurationKind::Infinite => Err(invalid_value(name, "DURATION_INFINITY")),
+    }
+}
+
+fn parse_length(name: &str, value: &str) -> DdsResult<Length> {
+    match value {
+        "LENGTH_UNLIMITED" | "-1" => Ok(Length::Unlimited),
+        _ => Ok(Length::Limited(parse_number(name, value)?)),
+    }
+}
+
+/// Octet sequences are written as a list of values separated by commas or whitespace, e.g. `1, 2, 0x0A`.
+fn parse_octet_sequence(node: Node) -> DdsResult<Vec<u8>> {
+    let Some(value) = child_text(node, "value") else {
+        return Ok(Vec::new());
+    };
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|x| !x.is_empty())
+        .map(|x| match x.strip_prefix("0x").or(x.strip_prefix("0X")) {
+            Some(hex) => u8::from_str_radix(hex, 16).map_err(|_| invalid_value("value", x)),
+            None => parse_number("value", x),
+        })
+        .collect()
+}
+
+fn parse_bool(value: &str) -> DdsResult<bool> {
+    match value {
+        "true" | "TRUE" | "1" => Ok(true),
+        "false" | "FALSE" | "0" => Ok(false),
+        _ => Err(invalid_value("boolean", value)),
+    }
+}
+
+fn parse_number<T: core::str::FromStr>(name: &str, value: &str) -> DdsResult<T> {
+    value.parse().map_err(|_| invalid_value(name, value))
+}
+
+fn invalid_value(name: &str, value: &str) -> DdsError {
+    DdsError::Error(format!(
+        "Invalid value {} for {} in QoS profile",
+        value, name
+    ))
+}
+
+fn required_attribute<'a>(node: Node<'a, '_>, name: &str) -> DdsResult<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        DdsError::Error(format!(
+            "Missing {} attribute in {}",
+            name,
+            node.tag_name().name()
+        ))
+    })
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name).and_then(|n| n.text()).map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES_XML: &str = r#"
+        <dds xmlns="http://www.omg.org/spec/DDS-XML">
+            <qos_library name="TestLibrary">
+                <qos_profile name="Base">
+                    <domain_participant_qos>
+                        <user_data><value>1, 2, 0x0A</value></user_data>
+                        <guid_prefix>
+                            <kind>MANUAL_GUID_PREFIX_QOS</kind>
+                            <value>1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0x0C</value>
+                        </guid_prefix>
+                    </domain_participant_qos>
+                    <datawriter_qos>
+                        <reliability>
+                            <kind>RELIABLE_RELIABILITY_QOS</kind>
+                            <max_blocking_time><sec>1</sec><nanosec>500</nanosec></max_blocking_time>
+                        </reliability>
+                        <history>
+                            <kind>KEEP_LAST_HISTORY_QOS</kind>
+                            <depth>5</depth>
+                        </history>
+                    </datawriter_qos>
+                </qos_profile>
+                <qos_profile name="Derived" base_name="TestLibrary::Base">
+                    <publisher_qos>
+                        <partition><name><element>A</element><element>B*</element></name></partition>
+                    </publisher_qos>
+                    <datawriter_qos>
+                        <durability><kind>TRANSIENT_LOCAL_DURABILITY_QOS</kind></durability>
+                        <deadline><period><sec>DURATION_INFINITY</sec><nanosec>DURATION_INFINITY</nanosec></period></deadline>
+                        <resource_limits>
+                            <max_samples>LENGTH_UNLIMITED</max_samples>
+                            <max_instances>10</max_instances>
+                        </resource_limits>
+                    </datawriter_qos>
+                    <datareader_qos>
+                        <history><kind>KEEP_ALL_HISTORY_QOS</kind></history>
+                        <representation><value><element>XCDR2_DATA_REPRESENTATION</element></value></representation>
+                    </datareader_qos>
+                </qos_profile>
+            </qos_library>
+        </dds>
+    "#;
+
+    #[test]
+    fn profile_values_override_defaults() {
+        let provider = QosProvider::from_xml(PROFILES_XML).unwrap();
+
+        let participant_qos = provider
+            .get_domain_participant_qos("TestLibrary::Base")
+            .unwrap();
+        assert_eq!(participant_qos.user_data.value, vec![1, 2, 10]);
+        assert_eq!(
+            participant_qos.guid_prefix.kind,
+            GuidPrefixQosPolicyKind::Manual([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+        );
+
+        let writer_qos = provider.get_datawriter_qos("TestLibrary::Base").unwrap();
+        assert_eq!(
+            writer_qos.reliability,
+            ReliabilityQosPolicy {
+                kind: ReliabilityQosPolicyKind::Reliable,
+                max_blocking_time: DurationKind::Finite(Duration::new(1, 500)),
+            }
+        );
+        assert_eq!(writer_qos.history.kind, HistoryQosPolicyKind::KeepLast(5));
+        assert_eq!(writer_qos.durability, DurabilityQosPolicy::default());
+    }
+
+    #[test]
+    fn derived_profile_inherits_base_values() {
+        let provider = QosProvider::from_xml(PROFILES_XML).unwrap();
+
+        let writer_qos = provider.get_datawriter_qos("TestLibrary::Derived").unwrap();
+        assert_eq!(
+            writer_qos.reliability.kind,
+            ReliabilityQosPolicyKind::Reliable
+        );
+        assert_eq!(writer_qos.history.kind, HistoryQosPolicyKind::KeepLast(5));
+        assert_eq!(
+            writer_qos.durability.kind,
+            DurabilityQosPolicyKind::TransientLocal
+        );
+        assert_eq!(writer_qos.deadline.period, DurationKind::Infinite);
+        assert_eq!(
+            writer_qos.resource_limits.max_instances,
+            Length::Limited(10)
+        );
+
+        let publisher_qos = provider.get_publisher_qos("TestLibrary::Derived").unwrap();
+        assert_eq!(
+            publisher_qos.partition.name,
+            vec![String::from("A"), String::from("B*")]
+        );
+
+        let reader_qos = provider.get_datareader_qos("TestLibrary::Derived").unwrap();
+        assert_eq!(reader_qos.history.kind, HistoryQosPolicyKind::KeepAll);
+        assert_eq!(
+            reader_qos.representation.value,
+            vec![XCDR2_DATA_REPRESENTATION]
+        );
+    }
+
+    #[test]
+    fn unknown_profile_is_bad_parameter() {
+        let provider = QosProvider::from_xml(PROFILES_XML).unwrap();
+        assert_eq!(
+            provider.get_topic_qos("TestLibrary::Unknown"),
+            Err(DdsError::BadParameter)
+        );
+    }
+
+    #[test]
+    fn invalid_policy_value_fails_to_load() {
+        let xml = r#"
+            <dds>
+                <qos_library name="L">
+                    <qos_profile name="P">
+                        <topic_qos><reliability><kind>SOMETIMES</kind></reliability></topic_qos>
+                    </qos_profile>
+                </qos_library>
+            </dds>
+        "#;
+        assert!(QosProvider::from_xml(xml).is_err());
+    }
+
+    #[test]
+    fn circular_base_name_fails_to_load() {
+        let xml = r#"
+            <dds>
+                <qos_library name="L">
+                    <qos_profile name="A" base_name="L::B"/>
+                    <qos_profile name="B" base_name="L::A"/>
+                </qos_library>
+            </dds>
+        "#;
+        assert!(QosProvider::from_xml(xml).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::infrastructure::{
+    qos::{DataReaderQos, DataWriterQos, TopicQos},
+    qos_policy::{
+        DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind,
+        LifespanQosPolicy, LivelinessQosPolicy, LivelinessQosPolicyKind, ReliabilityQosPolicy,
+        ReliabilityQosPolicyKind,
+    },
+    time::{Duration, DurationKind},
+};
+
+/// ROS 2 middleware implementations whose discovery timing can be reproduced with
+/// [`DustDdsConfigurationBuilder::ros2_discovery_timing`](crate::configuration::DustDdsConfigurationBuilder::ros2_discovery_timing).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ros2Rmw {
+    /// `rmw_cyclonedds_cpp`, which announces a lease duration of 10 seconds.
+    CycloneDds,
+    /// `rmw_fastrtps_cpp`, which announces a lease duration of 20 seconds and sends its participant announcements
+    /// every 3 seconds.
+    FastDds,
+}
+
+impl Ros2Rmw {
+    /// Lease duration announced by the participants of the middleware.
+    pub fn participant_lease_duration(&self) -> core::time::Duration {
+        match self {
+            Ros2Rmw::CycloneDds => core::time::Duration::from_secs(10),
+            Ros2Rmw::FastDds => core::time::Duration::from_secs(20),
+        }
+    }
+
+    /// Interval between the participant announcements of the middleware. The announcements of Cyclone DDS are
+    /// sent every 8 seconds so that they are received well within its short lease duration.
+    pub fn participant_announcement_interval(&self) -> core::time::Duration {
+        match self {
+            Ros2Rmw::CycloneDds => core::time::Duration::from_secs(8),
+            Ros2Rmw::FastDds => core::time::Duration::from_secs(3),
+        }
+    }
+}
+
+/// QoS profiles predefined by ROS 2 in `rmw/qos_profiles.h` and used by `rclcpp` and `rclpy` for their publishers
+/// and subscriptions. The QoS returned by the profile only differs from the default QoS of Dust DDS in the policies
+/// set by the profile. The liveliness is always automatic with an infinite lease duration, as the
+/// `SYSTEM_DEFAULT` liveliness of the DDS implementations used by ROS 2, so that the endpoints are compatible with
+/// the ones of ROS 2 nodes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ros2QosProfile {
+    /// `rmw_qos_profile_default`: reliable and volatile, keeping the last 10 samples.
+    Default,
+    /// `rmw_qos_profile_sensor_data`: best effort and volatile, keeping the last 5 samples.
+    SensorData,
+    /// `rmw_qos_profile_services_default`: reliable and volatile, keeping the last 10 samples.
+    Services,
+    /// `rmw_qos_profile_parameters`: reliable and volatile, keeping the last 1000 samples.
+    Parameters,
+    /// `rmw_qos_profile_parameter_events`: reliable and volatile, keeping the last 1000 samples.
+    ParameterEvents,
+    /// `rcl_qos_profile_rosout_default`: reliable and transient local, keeping the last 1000 samples which
+    /// expire after 10 seconds.
+    Rosout,
+}
+
+impl Ros2QosProfile {
+    /// QoS of the topics using the profile.
+    pub fn topic_qos(&self) -> TopicQos {
+        TopicQos {
+            durability: self.durability(),
+            liveliness: Self::liveliness(),
+            reliability: self.reliability(),
+            history: self.history(),
+            lifespan: self.lifespan(),
+            ..Default::default()
+        }
+    }
+
+    /// QoS of the data writers using the profile.
+    pub fn data_writer_qos(&self) -> DataWriterQos {
+        DataWriterQos {
+            durability: self.durability(),
+            liveliness: Self::liveliness(),
+            reliability: self.reliability(),
+            history: self.history(),
+            lifespan: self.lifespan(),
+            ..Default::default()
+        }
+    }
+
+    /// QoS of the data readers using the profile.
+    pub fn data_reader_qos(&self) -> DataReaderQos {
+        DataReaderQos {
+            durability: self.durability(),
+            liveliness: Self::liveliness(),
+            reliability: self.reliability(),
+            history: self.history(),
+            ..Default::default()
+        }
+    }
+
+    fn durability(&self) -> DurabilityQosPolicy {
+        let kind = match self {
+            Ros2QosProfile::Rosout => DurabilityQosPolicyKind::TransientLocal,
+            _ => DurabilityQosPolicyKind::Volatile,
+        };
+        DurabilityQosPolicy { kind }
+    }
+
+    fn liveliness() -> LivelinessQosPolicy {
+        LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::Automatic,
+            lease_duration: DurationKind::Infinite,
+        }
+    }
+
+    fn reliability(&self) -> ReliabilityQosPolicy {
+        let kind = match self {
+            Ros2QosProfile::SensorData => ReliabilityQosPolicyKind::BestEffort,
+            _ => ReliabilityQosPolicyKind::Reliable,
+        };
+        ReliabilityQosPolicy {
+            kind,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100_000_000)),
+        }
+    }
+
+    fn history(&self) -> HistoryQosPolicy {
+        let depth = match self {
+            Ros2QosProfile::Default | Ros2QosProfile::Services => 10,
+            Ros2QosProfile::SensorData => 5,
+            Ros2QosProfile::Parameters
+            | Ros2QosProfile::ParameterEvents
+            | Ros2QosProfile::Rosout => 1000,
+        };
+        HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(depth),
+        }
+    }
+
+    fn lifespan(&self) -> LifespanQosPolicy {
+        match self {
+            Ros2QosProfile::Rosout => LifespanQosPolicy {
+                duration: DurationKind::Finite(Duration::new(10, 0)),
+            },
+            _ => LifespanQosPolicy::default(),
+        }
+    }
+}
@@ -1,24 +1,151 @@
 use super::subscriber::Subscriber;
 use crate::{
-    builtin_topics::PublicationBuiltinTopicData,
-    condition::StatusCondition,
+    builtin_topics::{Locator, PublicationBuiltinTopicData},
+    condition::{QueryCondition, ReadCondition, StatusCondition},
     runtime::DdsRuntime,
-    dds_async::data_reader::DataReaderAsync,
+    dds_async::data_reader::{DataReaderAsync, SampleStream},
     infrastructure::{
         error::DdsResult,
-        instance::InstanceHandle,
+        instance::{InstanceHandle, HANDLE_NIL},
         qos::{DataReaderQos, QosKind},
-        sample_info::{InstanceStateKind, Sample, SampleStateKind, ViewStateKind},
+        qos_policy::QosPolicyId,
+        sample_info::{
+            InstanceStateKind, Sample, SampleRef, SampleStateKind, SequenceNumber,
+            SerializedSample, ViewStateKind,
+        },
         status::{
             LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
             SampleLostStatus, SampleRejectedStatus, StatusKind, SubscriptionMatchedStatus,
+            WriterRestartedStatus,
         },
-        time::Duration,
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsSerialize},
     },
     subscription::data_reader_listener::DataReaderListener,
     topic_definition::topic::Topic,
 };
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+
+/// Policy applied by a [`DataReader`] to the samples of a coherent set which is not completed by the writer within the
+/// timeout set with [`DataReader::set_incomplete_coherent_set_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IncompleteCoherentSetPolicy {
+    /// The samples received as part of the incomplete coherent set are made available to the application and the
+    /// [`StatusKind::DataAvailable`] status is notified.
+    #[default]
+    Release,
+    /// The samples received as part of the incomplete coherent set are discarded and notified as lost with the
+    /// [`StatusKind::SampleLost`] status.
+    Purge,
+}
+
+/// Policy applied by a [`DataReader`] to the received samples whose serialized data can not be deserialized into its type,
+/// for example because the writer uses an incompatible type or the data is corrupted. It is set with
+/// [`DataReader::set_deserialization_error_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeserializationErrorPolicy {
+    /// The samples are made available to the application and the error is returned when accessing their data with
+    /// [`Sample::data`](crate::infrastructure::sample_info::Sample::data).
+    #[default]
+    Deliver,
+    /// The samples are discarded and notified as lost with the [`StatusKind::SampleLost`] status.
+    Drop,
+    /// The samples are made available to the application without data, that is with
+    /// [`SampleInfo::valid_data`](crate::infrastructure::sample_info::SampleInfo::valid_data) set to `false`.
+    DeliverAsInvalidData,
+    /// The samples are discarded as with [`DeserializationErrorPolicy::Drop`] and their serialized data is passed to
+    /// [`DataReaderListener::on_deserialization_error`] of the listener of the [`DataReader`].
+    NotifyListener,
+}
+
+/// Delivery state of the samples of a matched publication for a [`DataReader`] with gap-free delivery enabled
+/// with [`DataReader::set_gap_free_delivery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadOfLineStatus {
+    /// Handle of the matched publication.
+    pub publication_handle: InstanceHandle,
+    /// Sequence number of the last sample of the publication delivered to the [`DataReader`]. The value is 0 if no
+    /// sample has been delivered since gap-free delivery was enabled.
+    pub last_delivered_sequence_number: SequenceNumber,
+    /// Sequence number of the sample which was rejected by the [`DataReader`] and which is blocking the delivery
+    /// of the following samples of the publication.
+    pub blocked_sequence_number: Option<SequenceNumber>,
+    /// Number of samples of the publication, including the blocked sample, held waiting to be delivered.
+    pub held_sample_count: usize,
+}
+
+/// Activity of one of the instances known by a [`DataReader`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceActivity {
+    /// Handle of the instance.
+    pub instance_handle: InstanceHandle,
+    /// Current state of the instance.
+    pub instance_state: InstanceStateKind,
+    /// Number of samples of the instance, including the changes of its state, stored by the [`DataReader`] since
+    /// the instance was first received.
+    pub received_sample_count: u64,
+    /// Average number of samples per second received for the instance since its first sample was received.
+    pub sample_rate: f64,
+}
+
+/// Statistics of the instances known by a [`DataReader`], intended to help dimensioning the
+/// [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy) and
+/// [`HistoryQosPolicy`](crate::infrastructure::qos_policy::HistoryQosPolicy) for the actual workload.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InstanceStatistics {
+    /// Number of instances in the [`InstanceStateKind::Alive`] state.
+    pub alive_instance_count: usize,
+    /// Number of instances in the [`InstanceStateKind::NotAliveDisposed`] state.
+    pub not_alive_disposed_instance_count: usize,
+    /// Number of instances in the [`InstanceStateKind::NotAliveNoWriters`] state.
+    pub not_alive_no_writers_instance_count: usize,
+    /// Activity of each of the instances, ordered from the most to the least active according to the number
+    /// of received samples.
+    pub instances: Vec<InstanceActivity>,
+}
+
+/// Status of the freshness watchdog of the instances of a [`DataReader`] set with
+/// [`DataReader::set_instance_freshness_watchdog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceFreshnessStatus {
+    /// Total cumulative number of times an instance of the [`DataReader`] became stale.
+    pub total_count: i32,
+    /// The incremental number of times an instance became stale since the last time the status was read.
+    pub total_count_change: i32,
+    /// Handle of the last instance which became stale.
+    pub last_instance_handle: InstanceHandle,
+    /// Handles of the instances which are currently stale, that is which did not receive any sample since
+    /// they became stale.
+    pub stale_instance_handles: Vec<InstanceHandle>,
+}
+
+impl InstanceFreshnessStatus {
+    pub(crate) const fn const_default() -> Self {
+        Self {
+            total_count: 0,
+            total_count_change: 0,
+            last_instance_handle: HANDLE_NIL,
+            stale_instance_handles: Vec::new(),
+        }
+    }
+}
+
+impl Default for InstanceFreshnessStatus {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Observer of the freshness of the instances of a [`DataReader`] set with
+/// [`DataReader::set_instance_freshness_watchdog`]. The observer is called while the [`DataReader`] processes
+/// the expiration of the watchdog or the reception of a sample so it should return quickly.
+pub trait InstanceFreshnessObserver: Send + Sync {
+    /// Called when no sample of the instance was received within the freshness period.
+    fn on_instance_stale(&self, instance_handle: InstanceHandle);
+
+    /// Called when a sample of a stale instance is received.
+    fn on_instance_refreshed(&self, _instance_handle: InstanceHandle) {}
+}
 
 /// A [`DataReader`] allows the application (1) to declare the data it wishes to receive (i.e., make a subscription) and (2) to access the
 /// data received by the attached [`Subscriber`].
@@ -127,6 +254,80 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         ))
     }
 
+    /// This operation takes samples from the [`DataReader`] in the same way as [`DataReader::take`] but returns them as
+    /// [`SerializedSample`] holding the received serialized data instead of deserializing them into `Foo`. This allows
+    /// gateways and recorders to move samples without knowledge of their type, for example by writing them unchanged
+    /// with [`DataWriter::write_serialized`](crate::publication::data_writer::DataWriter::write_serialized).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn take_serialized(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SerializedSample>> {
+        R::block_on(self.reader_async.take_serialized(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+    }
+
+    /// This operation takes up to `max_samples` [`Sample`] from the [`DataReader`] in the same way as
+    /// [`DataReader::take`] with any sample, view and instance state. If no sample is available, the operation
+    /// waits until at least one sample is received or the `timeout` elapses, in which case it returns
+    /// [`DdsError::Timeout`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn take_timeout(&self, max_samples: i32, timeout: Duration) -> DdsResult<Vec<Sample<Foo>>> {
+        R::block_on(self.reader_async.take_timeout(max_samples, timeout))
+    }
+
+    /// This operation accesses a collection of samples from the [`DataReader`] in the same way as [`DataReader::read`]
+    /// but returns them as [`SampleRef`] loaned from the [`DataReader`] instead of [`Sample`]. The serialized data of
+    /// the loaned samples is shared with the history of the [`DataReader`] so that large samples can be accessed without
+    /// being copied. The loan of each sample is returned when its [`SampleRef`] is dropped. Deleting the [`DataReader`]
+    /// while there are outstanding loans fails with [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn read_loaned(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SampleRef<Foo>>> {
+        R::block_on(self.reader_async.read_loaned(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+    }
+
+    /// This operation takes a collection of samples from the [`DataReader`] in the same way as [`DataReader::take`]
+    /// but returns them as [`SampleRef`] loaned from the [`DataReader`] as described in [`DataReader::read_loaned`].
+    /// The taken samples keep counting against the [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy)
+    /// of the [`DataReader`] until their loan is returned.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn take_loaned(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SampleRef<Foo>>> {
+        R::block_on(self.reader_async.take_loaned(
+            max_samples,
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+    }
+
     /// This operation reads the next, non-previously accessed [`Sample`] value from the [`DataReader`].
     /// The implied order among the samples stored in the [`DataReader`] is the same as for the [`DataReader::read`]
     /// operation. This operation is semantically equivalent to the read operation where the input Data sequence has
@@ -265,24 +466,122 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         ))
     }
 
-    /// This operation can be used to retrieve the instance key that corresponds to an `handle`.
-    /// The operation will only fill the fields that form the key inside the `key_holder` instance.
-    /// This operation may return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
-    /// if the [`InstanceHandle`] `handle` does not correspond to an existing data object known to the [`DataReader`].
-    #[tracing::instrument(skip(self, key_holder))]
-    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
-        R::block_on(self.reader_async.get_key_value(key_holder, handle))
+    /// This operation accesses a collection of [`Sample`] from the [`DataReader`] in the same way as [`DataReader::read_next_instance`]
+    /// except that only the samples whose sample, view and instance states are in the masks of the [`ReadCondition`] `a_condition`
+    /// are returned. The iteration over the instances is done in the same way as with [`DataReader::read_next_instance`].
+    /// If the [`ReadCondition`] is not attached to the [`DataReader`], the operation will return the error
+    /// [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn read_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadCondition<R>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        R::block_on(self.reader_async.read_next_instance_w_condition(
+            max_samples,
+            previous_handle,
+            a_condition.condition_async(),
+        ))
     }
 
-    /// This operation takes as a parameter an instance and returns an [`InstanceHandle`] handle
-    /// that can be used in subsequent operations that accept an instance handle as an argument.
-    /// The instance parameter is only used for the purpose of examining the fields that define the
-    /// key. This operation does not register the instance in question. If the instance has not
-    /// been previously registered, or if for any other reason the Service is unable to provide
-    /// an instance handle, the operation will succeed and return [`None`].
-    #[tracing::instrument(skip(self, instance))]
-    pub fn lookup_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
-        R::block_on(self.reader_async.lookup_instance(instance))
+    /// This operation has the same behavior as [`DataReader::read_next_instance_w_condition`] except that the samples are 'taken'
+    /// from the [`DataReader`] such that they are no longer accessible via subsequent 'read' or 'take' operations.
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn take_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadCondition<R>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        R::block_on(self.reader_async.take_next_instance_w_condition(
+            max_samples,
+            previous_handle,
+            a_condition.condition_async(),
+        ))
+    }
+
+    /// This operation takes all the [`Sample`] values available in the [`DataReader`] that match the `sample_states`,
+    /// `view_states` and `instance_states` and returns them grouped by the [`InstanceHandle`] of the instance they belong to.
+    /// Within each instance the samples keep the same order as returned by [`DataReader::take`].
+    /// The samples are 'taken' from the [`DataReader`] such that they are no longer accessible via subsequent 'read' or 'take' operations.
+    /// If the DataReader has no samples that meet the constraints, the return value will be
+    /// [`DdsError::NoData`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn take_all_by_instance(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<BTreeMap<InstanceHandle, Vec<Sample<Foo>>>> {
+        R::block_on(self.reader_async.take_all_by_instance(
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+    }
+
+    /// This operation creates a [`ReadCondition`]. The returned [`ReadCondition`] will be attached and belong to the [`DataReader`].
+    /// Its *trigger_value* is [`true`] when the [`DataReader`] has samples whose sample state, view state and instance state are
+    /// respectively in `sample_states`, `view_states` and `instance_states`.
+    #[tracing::instrument(skip(self))]
+    pub fn create_readcondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<ReadCondition<R>> {
+        R::block_on(self.reader_async.create_readcondition(
+            sample_states,
+            view_states,
+            instance_states,
+        ))
+        .map(ReadCondition::new)
+    }
+
+    /// This operation creates a [`QueryCondition`]. The returned [`QueryCondition`] will be attached and belong to the [`DataReader`].
+    /// Besides the states of the samples, its *trigger_value* takes into account the `query_expression` which has the syntax of the
+    /// WHERE clause of a SQL query, e.g. `x > %0 AND name = 'label'`, where `%n` refers to the n-th element of `query_parameters`.
+    /// If the expression is not valid or uses members which do not exist in the type of the [`DataReader`] the operation fails with
+    /// [`DdsError::BadParameter`](crate::infrastructure::error::DdsError). Query conditions can only be created for types with final
+    /// extensibility, otherwise the operation fails with [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn create_querycondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query_expression: &str,
+        query_parameters: &[String],
+    ) -> DdsResult<QueryCondition<R>> {
+        R::block_on(self.reader_async.create_querycondition(
+            sample_states,
+            view_states,
+            instance_states,
+            query_expression,
+            query_parameters,
+        ))
+        .map(QueryCondition::new)
+    }
+
+    /// This operation deletes a [`ReadCondition`] attached to the [`DataReader`]. If the [`ReadCondition`] is not attached to
+    /// the [`DataReader`], the operation will return the error [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn delete_readcondition(&self, a_condition: &ReadCondition<R>) -> DdsResult<()> {
+        R::block_on(
+            self.reader_async
+                .delete_readcondition(a_condition.condition_async()),
+        )
+    }
+
+    /// This operation deletes a [`QueryCondition`] attached to the [`DataReader`]. If the [`QueryCondition`] is not attached to
+    /// the [`DataReader`], the operation will return the error [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub fn delete_querycondition(&self, a_condition: &QueryCondition<R>) -> DdsResult<()> {
+        R::block_on(
+            self.reader_async
+                .delete_querycondition(a_condition.condition_async()),
+        )
     }
 }
 
@@ -313,6 +612,13 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         R::block_on(self.reader_async.get_sample_lost_status())
     }
 
+    /// This operation allows access to the [`WriterRestartedStatus`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_writer_restarted_status(&self) -> DdsResult<WriterRestartedStatus> {
+        R::block_on(self.reader_async.get_writer_restarted_status())
+    }
+
     /// This operation allows access to the [`SampleRejectedStatus`].
     #[tracing::instrument(skip(self))]
     pub fn get_sample_rejected_status(&self) -> DdsResult<SampleRejectedStatus> {
@@ -355,6 +661,34 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         R::block_on(self.reader_async.wait_for_historical_data(max_wait))
     }
 
+    /// This operation requests the matched [`DataWriter`](crate::publication::data_writer::DataWriter) entities to send
+    /// again all the samples available in their history. This allows an application to obtain again the *historical* data,
+    /// e.g. after a matched writer restarted as notified by the [`WriterRestartedStatus`].
+    /// The samples are delivered as new samples and [`DataReader::wait_for_historical_data`] can be used to wait until they are received.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn request_historical_data(&self) -> DdsResult<()> {
+        R::block_on(self.reader_async.request_historical_data())
+    }
+
+    /// This operation returns the identifiers of the QoS policies of the [`DataReader`] and its [`Subscriber`] which are
+    /// incompatible with the QoS policies offered by the publication described by `publication_builtin_topic_data`.
+    /// An empty list means that the QoS policies are compatible, so that the [`DataReader`] would be matched with the publication
+    /// provided that it uses the same [`Topic`]. This is intended to help diagnose why a [`DataReader`] and a
+    /// [`DataWriter`](crate::publication::data_writer::DataWriter) are not communicating, e.g. by passing the data obtained
+    /// from the *DCPSPublication* builtin topic.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn check_compatibility(
+        &self,
+        publication_builtin_topic_data: &PublicationBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
+        R::block_on(
+            self.reader_async
+                .check_compatibility(publication_builtin_topic_data),
+        )
+    }
+
     /// This operation retrieves information on a publication that is currently *associated* with the [`DataReader`];
     /// that is, a publication with a matching [`Topic`] and compatible qos that the application  has not indicated should be ignored by means of the
     /// [`DomainParticipant::ignore_publication`](crate::domain::domain_participant::DomainParticipant) operation.
@@ -373,6 +707,40 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         )
     }
 
+    /// This operation retrieves the highest [`SequenceNumber`] of the samples received by the [`DataReader`] from a publication
+    /// that is currently *associated* with it. The value is 0 if no sample has been received yet from that publication.
+    /// Together with the sequence number returned by [`DataWriter::write`](crate::publication::data_writer::DataWriter::write) this
+    /// allows the application to checkpoint which samples of each writer have already been processed.
+    /// The `publication_handle` must correspond to a publication currently associated with the [`DataReader`] otherwise the operation
+    /// will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_publication_high_watermark(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<SequenceNumber> {
+        R::block_on(
+            self.reader_async
+                .get_matched_publication_high_watermark(publication_handle),
+        )
+    }
+
+    /// This operation retrieves the unicast and multicast locators at which a publication that is currently *associated* with the
+    /// [`DataReader`] can be reached, as announced in its discovery information. These are the locators to which the acknowledgments
+    /// are sent. If the publication did not announce any locators the default locators of its participant are returned.
+    /// The `publication_handle` must correspond to a publication currently associated with the [`DataReader`] otherwise the operation
+    /// will fail and return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_matched_publication_locators(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
+        R::block_on(
+            self.reader_async
+                .get_matched_publication_locators(publication_handle),
+        )
+    }
+
     /// This operation retrieves the list of publications currently *associated* with the [`DataReader`]; that is, publications that have a
     /// matching [`Topic`] and compatible qos that the application has not indicated should be ignored by means of the
     /// [`DomainParticipant::ignore_publication`](crate::domain::domain_participant::DomainParticipant) operation.
@@ -476,3 +844,171 @@ impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
         R::block_on(self.reader_async.set_listener(a_listener, mask))
     }
 }
+
+impl<R: DdsRuntime, Foo> DataReader<R, Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
+    /// This operation can be used to retrieve the instance key that corresponds to an `handle`.
+    /// The operation will only fill the fields that form the key inside the `key_holder` instance.
+    /// This operation may return [`DdsError::BadParameter`](crate::infrastructure::error::DdsError)
+    /// if the [`InstanceHandle`] `handle` does not correspond to an existing data object known to the [`DataReader`].
+    #[tracing::instrument(skip(self, key_holder))]
+    pub fn get_key_value(&self, key_holder: &mut Foo, handle: InstanceHandle) -> DdsResult<()> {
+        R::block_on(self.reader_async.get_key_value(key_holder, handle))
+    }
+
+    /// This operation takes as a parameter an instance and returns an [`InstanceHandle`] handle
+    /// that can be used in subsequent operations that accept an instance handle as an argument.
+    /// The instance parameter is only used for the purpose of examining the fields that define the
+    /// key. This operation does not register the instance in question. If the instance has not
+    /// been previously registered, or if for any other reason the Service is unable to provide
+    /// an instance handle, the operation will succeed and return [`None`].
+    #[tracing::instrument(skip(self, instance))]
+    pub fn lookup_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
+        R::block_on(self.reader_async.lookup_instance(instance))
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReader<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    /// This operation enables or disables the read-ahead deserialization of the samples received by the [`DataReader`].
+    /// When enabled, each sample is deserialized by a background task as soon as it is received and the resulting value is kept
+    /// together with it so that it can be accessed with [`Sample::read_ahead_data`] after a read or take operation without paying
+    /// the deserialization cost at that point. A sample read before its deserialization completes has no read-ahead value. Since every received sample is deserialized, this is only useful for latency-critical
+    /// readers which consume all the samples. Samples received while the option is disabled are not deserialized ahead and
+    /// disabling the option discards the values already cached.
+    /// This operation is not part of the DDS standard and it is disabled by default.
+    #[tracing::instrument(skip(self))]
+    pub fn set_read_ahead_deserialization(&self, enabled: bool) -> DdsResult<()> {
+        R::block_on(self.reader_async.set_read_ahead_deserialization(enabled))
+    }
+
+    /// This operation sets the [`DeserializationErrorPolicy`] applied to the samples received by the [`DataReader`] which
+    /// can not be deserialized. With any policy other than [`DeserializationErrorPolicy::Deliver`], each received sample is
+    /// deserialized on arrival to detect the errors.
+    /// This operation is not part of the DDS standard and by default the samples are delivered.
+    #[tracing::instrument(skip(self))]
+    pub fn set_deserialization_error_policy(
+        &self,
+        policy: DeserializationErrorPolicy,
+    ) -> DdsResult<()> {
+        R::block_on(self.reader_async.set_deserialization_error_policy(policy))
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReader<R, Foo>
+where
+    Foo: Send + Sync + 'static,
+{
+    /// This operation returns a [`SampleStream`] which yields the samples received by the [`DataReader`] as they become
+    /// available, so that they can be consumed from an async context without polling the [`DataReader::take`] operation
+    /// or installing a listener. Each sample is taken from the [`DataReader`] only when the stream is polled, so the
+    /// samples not yet consumed are kept according to the History and ResourceLimits QoS policies of the [`DataReader`].
+    /// The stream ends when the [`DataReader`] is deleted.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn sample_stream(&self) -> SampleStream<R, Foo> {
+        self.reader_async.sample_stream()
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReader<R, Foo> {
+    /// This operation sets the maximum time that the samples of a coherent set are held by a [`DataReader`] belonging to a
+    /// [`Subscriber`] with [`PresentationQosPolicy::coherent_access`](crate::infrastructure::qos_policy::PresentationQosPolicy)
+    /// enabled. A coherent set of a writer is complete when a sample which is not part of it is received from the same writer.
+    /// If the set is still incomplete when the `timeout` expires, for example because the writer stopped in the middle of the
+    /// set, its samples are handled according to the given [`IncompleteCoherentSetPolicy`].
+    /// This operation is not part of the DDS standard and by default incomplete coherent sets are released after 10 seconds.
+    #[tracing::instrument(skip(self))]
+    pub fn set_incomplete_coherent_set_timeout(
+        &self,
+        timeout: DurationKind,
+        policy: IncompleteCoherentSetPolicy,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.reader_async
+                .set_incomplete_coherent_set_timeout(timeout, policy),
+        )
+    }
+
+    /// This operation enables or disables the gap-free delivery of the samples received by a [`DataReader`] with
+    /// [`ReliabilityQosPolicyKind::Reliable`](crate::infrastructure::qos_policy::ReliabilityQosPolicyKind::Reliable) reliability.
+    /// The reliable protocol delivers the samples of each writer in order and only skips samples which the writer has definitively
+    /// reported as no longer available. With gap-free delivery enabled, a sample which is rejected by the [`DataReader`], for example
+    /// because of its [`ResourceLimitsQosPolicy`](crate::infrastructure::qos_policy::ResourceLimitsQosPolicy), is not lost. Instead it blocks
+    /// the delivery of the following samples of the same writer, which are held until the application takes samples from the [`DataReader`]
+    /// and the blocked sample can be delivered. The delivery state of each publication is available with [`DataReader::get_head_of_line_status`].
+    /// Enabling gap-free delivery on a best-effort [`DataReader`] or disabling it while samples are held fails
+    /// with [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
+    /// This operation is not part of the DDS standard and gap-free delivery is disabled by default.
+    #[tracing::instrument(skip(self))]
+    pub fn set_gap_free_delivery(&self, enabled: bool) -> DdsResult<()> {
+        R::block_on(self.reader_async.set_gap_free_delivery(enabled))
+    }
+
+    /// This operation sets a filter on the content of the samples received by the [`DataReader`]. Only the samples whose
+    /// data satisfies the filter expression are made available to the application. The filter expression has the same
+    /// syntax as the query expression of a [`QueryCondition`] and `filter_parameters` provides the values of its `%n`
+    /// parameters. The filter is evaluated once per received sample for all the [`DataReader`] objects of the same
+    /// [`Subscriber`] which use the same filter expression and parameters on the same [`Topic`], so that many readers
+    /// with identical filters do not multiply the evaluation cost. An empty filter expression removes the filter.
+    /// If the expression is invalid, [`DdsError::BadParameter`](crate::infrastructure::error::DdsError) is returned
+    /// and filters can only be set for types with final extensibility.
+    /// This operation is not part of the DDS standard and the samples are not filtered by default.
+    #[tracing::instrument(skip(self))]
+    pub fn set_content_filter(
+        &self,
+        filter_expression: &str,
+        filter_parameters: &[String],
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.reader_async
+                .set_content_filter(filter_expression, filter_parameters),
+        )
+    }
+
+    /// This operation retrieves the [`HeadOfLineStatus`] of each of the publications currently *associated* with the [`DataReader`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_head_of_line_status(&self) -> DdsResult<Vec<HeadOfLineStatus>> {
+        R::block_on(self.reader_async.get_head_of_line_status())
+    }
+
+    /// This operation retrieves the [`InstanceStatistics`] of the [`DataReader`] with the number of instances in each of
+    /// the instance states and the activity of each of the instances.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_statistics(&self) -> DdsResult<InstanceStatistics> {
+        R::block_on(self.reader_async.get_instance_statistics())
+    }
+
+    /// This operation sets a watchdog on the freshness of the instances of the [`DataReader`]. An alive instance becomes stale
+    /// when no sample of it is received within `period` of the last one, independently of the liveliness of the writers and of the
+    /// [`DeadlineQosPolicy`](crate::infrastructure::qos_policy::DeadlineQosPolicy). Each time an instance becomes stale the
+    /// [`InstanceFreshnessStatus`] is updated and [`InstanceFreshnessObserver::on_instance_stale`] is called, and
+    /// [`InstanceFreshnessObserver::on_instance_refreshed`] is called when a sample of the stale instance is received again.
+    /// The instances already known by the [`DataReader`] are watched from the time of the last sample they received.
+    /// Setting the period to [`DurationKind::Infinite`] disables the watchdog.
+    /// This operation is not part of the DDS standard and the watchdog is disabled by default.
+    #[tracing::instrument(skip(self, observer))]
+    pub fn set_instance_freshness_watchdog(
+        &self,
+        period: DurationKind,
+        observer: Option<Arc<dyn InstanceFreshnessObserver>>,
+    ) -> DdsResult<()> {
+        R::block_on(
+            self.reader_async
+                .set_instance_freshness_watchdog(period, observer),
+        )
+    }
+
+    /// This operation retrieves the [`InstanceFreshnessStatus`] of the [`DataReader`].
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self))]
+    pub fn get_instance_freshness_status(&self) -> DdsResult<InstanceFreshnessStatus> {
+        R::block_on(self.reader_async.get_instance_freshness_status())
+    }
+}
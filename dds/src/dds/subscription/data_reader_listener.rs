@@ -3,7 +3,7 @@ use crate::{
     dds_async::data_reader::DataReaderAsync,
     infrastructure::status::{
         LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-        SampleLostStatus, SampleRejectedStatus, SubscriptionMatchedStatus,
+        SampleLostStatus, SampleRejectedStatus, SubscriptionMatchedStatus, WriterRestartedStatus,
     },
 };
 use core::future::Future;
@@ -70,4 +70,25 @@ pub trait DataReaderListener<R: DdsRuntime, Foo>: 'static {
     ) -> impl Future<Output = ()> + Send {
         core::future::ready(())
     }
+
+    /// Method that is called when this reader reports a writer restarted status.
+    /// This method is not part of the DDS standard.
+    fn on_writer_restarted(
+        &mut self,
+        _the_reader: DataReaderAsync<R, Foo>,
+        _status: WriterRestartedStatus,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
+
+    /// Method that is called with the serialized data of a received sample which can not be deserialized when the
+    /// reader uses the [`DeserializationErrorPolicy::NotifyListener`](crate::subscription::data_reader::DeserializationErrorPolicy::NotifyListener) policy.
+    /// This method is not part of the DDS standard.
+    fn on_deserialization_error(
+        &mut self,
+        _the_reader: DataReaderAsync<R, Foo>,
+        _serialized_data: &[u8],
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
 }
@@ -9,3 +9,6 @@ pub mod subscriber;
 
 /// Contains the [`SubscriberListener`](crate::subscription::subscriber_listener::SubscriberListener) trait.
 pub mod subscriber_listener;
+
+/// Contains the [`WildcardSubscriptionListener`](crate::subscription::wildcard_subscription_listener::WildcardSubscriptionListener) trait.
+pub mod wildcard_subscription_listener;
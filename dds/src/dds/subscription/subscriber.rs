@@ -1,6 +1,7 @@
 use super::{
     data_reader::DataReader, data_reader_listener::DataReaderListener,
     subscriber_listener::SubscriberListener,
+    wildcard_subscription_listener::WildcardSubscriptionListener,
 };
 use crate::{
     condition::StatusCondition,
@@ -40,6 +41,14 @@ impl<R: DdsRuntime> From<SubscriberAsync<R>> for Subscriber<R> {
     }
 }
 
+impl<R: DdsRuntime> Clone for Subscriber<R> {
+    fn clone(&self) -> Self {
+        Self {
+            subscriber_async: self.subscriber_async.clone(),
+        }
+    }
+}
+
 impl<R: DdsRuntime> Subscriber<R> {
     /// This operation creates a [`DataReader`]. The returned [`DataReader`] will be attached and belong to the [`Subscriber`].
     /// The [`DataReader`] returned by this operation has an associated [`Topic`] and a type `Foo`.
@@ -74,6 +83,35 @@ impl<R: DdsRuntime> Subscriber<R> {
         .map(DataReader::from)
     }
 
+    /// This operation creates a [`DataReader`] in the same way as [`Subscriber::create_datareader`] but the listener is run
+    /// on `listener_executor` instead of on the executor of the [`DomainParticipant`]. The executor of the
+    /// [`DomainParticipant`] is also the one processing the received messages, so a listener doing heavy or blocking work
+    /// delays the communication of all the entities of the [`DomainParticipant`]. Running such a listener on a separate
+    /// executor, for example a dedicated `Executor` thread of the standard runtime, keeps the reception of data going
+    /// while the listener is busy.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, a_topic, a_listener, listener_executor))]
+    pub fn create_datareader_with_executor<Foo>(
+        &self,
+        a_topic: &Topic<R>,
+        qos: QosKind<DataReaderQos>,
+        a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+        listener_executor: &R::SpawnerHandle,
+    ) -> DdsResult<DataReader<R, Foo>> {
+        R::block_on(
+            self.subscriber_async
+                .create_datareader_with_executor::<Foo>(
+                    a_topic.topic_async(),
+                    qos,
+                    a_listener,
+                    mask,
+                    listener_executor,
+                ),
+        )
+        .map(DataReader::from)
+    }
+
     /// This operation deletes a [`DataReader`] that belongs to the [`Subscriber`]. This operation must be called on the
     /// same [`Subscriber`] object used to create the [`DataReader`]. If [`Subscriber::delete_datareader`] is called on a
     /// different [`Subscriber`], the operation will have no effect and it will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError).
@@ -101,6 +139,29 @@ impl<R: DdsRuntime> Subscriber<R> {
         )
     }
 
+    /// This operation watches the discovered publications for topics whose name matches the `topic_name_pattern` and creates
+    /// a [`DataReader`] belonging to the [`Subscriber`] for each of them. The pattern can contain the wildcards `*`, which matches
+    /// any sequence of characters, and `?`, which matches any single character.
+    /// The type of each matching topic is obtained with [`WildcardSubscriptionListener::get_topic_type`] and the samples received by all
+    /// the readers are delivered to [`WildcardSubscriptionListener::on_sample`]. If a topic with the same name exists already in the
+    /// [`DomainParticipant`] it is used instead of creating a new one. The readers are created with the QoS given by `qos`.
+    /// The discovered publications are obtained by setting a listener on the built-in reader of the publications, replacing any listener
+    /// set on it by the application.
+    /// This operation is not part of the DDS standard.
+    #[tracing::instrument(skip(self, a_listener))]
+    pub fn create_wildcard_subscription(
+        &self,
+        topic_name_pattern: &str,
+        qos: QosKind<DataReaderQos>,
+        a_listener: impl WildcardSubscriptionListener<R> + Send + 'static,
+    ) -> DdsResult<()> {
+        R::block_on(self.subscriber_async.create_wildcard_subscription(
+            topic_name_pattern,
+            qos,
+            a_listener,
+        ))
+    }
+
     /// This operation invokes the operation [`DataReaderListener::on_data_available`] on the listener objects attached to contained [`DataReader`]
     /// entities with a [`StatusKind::DataAvailable`] that is considered changed.
     /// This operation is typically invoked from the [`SubscriberListener::on_data_on_readers`] operation. That way the
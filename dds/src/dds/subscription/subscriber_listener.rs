@@ -3,7 +3,7 @@ use crate::{
     dds_async::{data_reader::DataReaderAsync, subscriber::SubscriberAsync},
     infrastructure::status::{
         LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-        SampleLostStatus, SampleRejectedStatus, SubscriptionMatchedStatus,
+        SampleLostStatus, SampleRejectedStatus, SubscriptionMatchedStatus, WriterRestartedStatus,
     },
 };
 use core::future::Future;
@@ -79,4 +79,14 @@ pub trait SubscriberListener<R: DdsRuntime> {
     ) -> impl Future<Output = ()> + Send {
         core::future::ready(())
     }
+
+    /// Method that is called when any reader belonging to this subcriber reports a writer restarted status.
+    /// This method is not part of the DDS standard.
+    fn on_writer_restarted(
+        &mut self,
+        _the_reader: DataReaderAsync<R, ()>,
+        _status: WriterRestartedStatus,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
 }
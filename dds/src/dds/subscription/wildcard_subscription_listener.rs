@@ -0,0 +1,32 @@
+use crate::{
+    builtin_topics::PublicationBuiltinTopicData, infrastructure::sample_info::SampleInfo,
+    runtime::DdsRuntime, xtypes::dynamic_type::DynamicType,
+};
+use alloc::sync::Arc;
+use core::future::Future;
+
+/// This trait represents a listener object which receives the samples of all the topics of a wildcard subscription
+/// created with [`Subscriber::create_wildcard_subscription`](super::subscriber::Subscriber::create_wildcard_subscription).
+pub trait WildcardSubscriptionListener<R: DdsRuntime>: 'static {
+    /// Method that is called when a publication is discovered on a topic whose name matches the pattern of the subscription
+    /// and which is not subscribed yet. It returns the type used to create the topic and the reader for the discovered
+    /// publication or [`None`] if the topic should not be subscribed.
+    fn get_topic_type(
+        &mut self,
+        _publication_data: &PublicationBuiltinTopicData,
+    ) -> Option<Arc<dyn DynamicType + Send + Sync>> {
+        None
+    }
+
+    /// Method that is called for each sample received on any of the subscribed topics. The serialized data includes the
+    /// encapsulation header so it can be deserialized with [`DdsDeserialize::deserialize_data`](crate::infrastructure::type_support::DdsDeserialize::deserialize_data).
+    /// Samples without valid data, such as the disposal of an instance, are received with empty serialized data.
+    fn on_sample(
+        &mut self,
+        _topic_name: &str,
+        _serialized_data: &[u8],
+        _sample_info: &SampleInfo,
+    ) -> impl Future<Output = ()> + Send {
+        core::future::ready(())
+    }
+}
@@ -0,0 +1,499 @@
+use crate::{
+    dcps::xtypes_glue::sample_members::{deserialize_sample_members, serialize_sample_members},
+    infrastructure::{
+        error::{DdsError, DdsResult},
+        type_support::DdsSerialize,
+    },
+    xtypes::{
+        dynamic_type::{
+            DynamicType, DynamicTypeMember, ObjectName, TryConstructKind, TypeDescriptor,
+        },
+        error::XTypesError,
+        type_object::{
+            CollectionElementFlag, CommonStructMember, CompleteMemberDetail, CompleteStructHeader,
+            CompleteStructMember, CompleteStructType, CompleteTypeDetail, CompleteTypeObject,
+            PlainArraySElemDefn, PlainCollectionHeader, PlainSequenceSElemDefn, StringSTypeDefn,
+            StructMemberFlag, StructTypeFlag, TypeIdentifier, TypeKind, EK_COMPLETE,
+        },
+    },
+};
+use alloc::{boxed::Box, string::String, sync::Arc, vec, vec::Vec};
+
+pub use crate::dcps::xtypes_glue::sample_members::MemberValue;
+
+/// Type of a member of a [`DynamicStructType`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicMemberType {
+    /// Boolean member, holding [`MemberValue::Boolean`] values.
+    Boolean,
+    /// 8-bit signed integer member, holding [`MemberValue::Int8`] values.
+    Int8,
+    /// 16-bit signed integer member, holding [`MemberValue::Int16`] values.
+    Int16,
+    /// 32-bit signed integer member, holding [`MemberValue::Int32`] values.
+    Int32,
+    /// 64-bit signed integer member, holding [`MemberValue::Int64`] values.
+    Int64,
+    /// 8-bit unsigned integer member, holding [`MemberValue::Uint8`] values.
+    Uint8,
+    /// 16-bit unsigned integer member, holding [`MemberValue::Uint16`] values.
+    Uint16,
+    /// 32-bit unsigned integer member, holding [`MemberValue::Uint32`] values.
+    Uint32,
+    /// 64-bit unsigned integer member, holding [`MemberValue::Uint64`] values.
+    Uint64,
+    /// Single precision floating point member, holding [`MemberValue::Float32`] values.
+    Float32,
+    /// Double precision floating point member, holding [`MemberValue::Float64`] values.
+    Float64,
+    /// Character member, holding [`MemberValue::Char8`] values.
+    Char8,
+    /// Unbounded string member, holding [`MemberValue::String`] values.
+    String,
+    /// Unbounded sequence of elements of the given type, holding [`MemberValue::Sequence`] values.
+    Sequence(Box<DynamicMemberType>),
+    /// Array with the given number of elements of the given type, holding [`MemberValue::Array`] values.
+    Array(Box<DynamicMemberType>, u8),
+    /// Nested structure, holding [`MemberValue::Struct`] values with the value of each of its members in order.
+    Struct(DynamicStructType),
+}
+
+impl DynamicMemberType {
+    fn type_identifier(&self) -> TypeIdentifier {
+        match self {
+            DynamicMemberType::Boolean => TypeIdentifier::TkBoolean,
+            DynamicMemberType::Int8 => TypeIdentifier::TkInt8Type,
+            DynamicMemberType::Int16 => TypeIdentifier::TkInt16Type,
+            DynamicMemberType::Int32 => TypeIdentifier::TkInt32Type,
+            DynamicMemberType::Int64 => TypeIdentifier::TkInt64Type,
+            DynamicMemberType::Uint8 => TypeIdentifier::TkUint8Type,
+            DynamicMemberType::Uint16 => TypeIdentifier::TkUint16Type,
+            DynamicMemberType::Uint32 => TypeIdentifier::TkUint32Type,
+            DynamicMemberType::Uint64 => TypeIdentifier::TkUint64Type,
+            DynamicMemberType::Float32 => TypeIdentifier::TkFloat32Type,
+            DynamicMemberType::Float64 => TypeIdentifier::TkFloat64Type,
+            DynamicMemberType::Char8 => TypeIdentifier::TkChar8Type,
+            DynamicMemberType::String => TypeIdentifier::TiString8Small {
+                string_sdefn: StringSTypeDefn { bound: 0 },
+            },
+            DynamicMemberType::Sequence(element_type) => TypeIdentifier::TiPlainSequenceSmall {
+                seq_sdefn: Box::new(PlainSequenceSElemDefn {
+                    header: collection_header(),
+                    bound: 0,
+                    element_identifier: element_type.type_identifier(),
+                }),
+            },
+            DynamicMemberType::Array(element_type, length) => TypeIdentifier::TiPlainArraySmall {
+                array_sdefn: Box::new(PlainArraySElemDefn {
+                    header: collection_header(),
+                    array_bound_seq: vec![*length],
+                    element_identifier: element_type.type_identifier(),
+                }),
+            },
+            DynamicMemberType::Struct(struct_type) => TypeIdentifier::EkComplete {
+                complete: Box::new(struct_type_object(
+                    &struct_type.type_name,
+                    &struct_type.members,
+                )),
+            },
+        }
+    }
+
+    fn default_value(&self) -> MemberValue {
+        match self {
+            DynamicMemberType::Boolean => MemberValue::Boolean(false),
+            DynamicMemberType::Int8 => MemberValue::Int8(0),
+            DynamicMemberType::Int16 => MemberValue::Int16(0),
+            DynamicMemberType::Int32 => MemberValue::Int32(0),
+            DynamicMemberType::Int64 => MemberValue::Int64(0),
+            DynamicMemberType::Uint8 => MemberValue::Uint8(0),
+            DynamicMemberType::Uint16 => MemberValue::Uint16(0),
+            DynamicMemberType::Uint32 => MemberValue::Uint32(0),
+            DynamicMemberType::Uint64 => MemberValue::Uint64(0),
+            DynamicMemberType::Float32 => MemberValue::Float32(0.0),
+            DynamicMemberType::Float64 => MemberValue::Float64(0.0),
+            DynamicMemberType::Char8 => MemberValue::Char8('\0'),
+            DynamicMemberType::String => MemberValue::String(String::new()),
+            DynamicMemberType::Sequence(_) => MemberValue::Sequence(Vec::new()),
+            DynamicMemberType::Array(element_type, length) => {
+                MemberValue::Array(vec![element_type.default_value(); *length as usize])
+            }
+            DynamicMemberType::Struct(struct_type) => MemberValue::Struct(
+                struct_type
+                    .members
+                    .iter()
+                    .map(|m| m.member_type.default_value())
+                    .collect(),
+            ),
+        }
+    }
+
+    fn is_type_of(&self, value: &MemberValue) -> bool {
+        match (self, value) {
+            (DynamicMemberType::Boolean, MemberValue::Boolean(_))
+            | (DynamicMemberType::Int8, MemberValue::Int8(_))
+            | (DynamicMemberType::Int16, MemberValue::Int16(_))
+            | (DynamicMemberType::Int32, MemberValue::Int32(_))
+            | (DynamicMemberType::Int64, MemberValue::Int64(_))
+            | (DynamicMemberType::Uint8, MemberValue::Uint8(_))
+            | (DynamicMemberType::Uint16, MemberValue::Uint16(_))
+            | (DynamicMemberType::Uint32, MemberValue::Uint32(_))
+            | (DynamicMemberType::Uint64, MemberValue::Uint64(_))
+            | (DynamicMemberType::Float32, MemberValue::Float32(_))
+            | (DynamicMemberType::Float64, MemberValue::Float64(_))
+            | (DynamicMemberType::Char8, MemberValue::Char8(_))
+            | (DynamicMemberType::String, MemberValue::String(_)) => true,
+            (DynamicMemberType::Sequence(element_type), MemberValue::Sequence(elements)) => {
+                elements.iter().all(|e| element_type.is_type_of(e))
+            }
+            (DynamicMemberType::Array(element_type, length), MemberValue::Array(elements)) => {
+                elements.len() == *length as usize
+                    && elements.iter().all(|e| element_type.is_type_of(e))
+            }
+            (DynamicMemberType::Struct(struct_type), MemberValue::Struct(values)) => {
+                values.len() == struct_type.members.len()
+                    && struct_type
+                        .members
+                        .iter()
+                        .zip(values)
+                        .all(|(m, v)| m.member_type.is_type_of(v))
+            }
+            _ => false,
+        }
+    }
+}
+
+fn collection_header() -> PlainCollectionHeader {
+    PlainCollectionHeader {
+        equiv_kind: EK_COMPLETE,
+        element_flags: CollectionElementFlag {
+            try_construct: TryConstructKind::Discard,
+            is_external: false,
+        },
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DynamicStructMember {
+    name: String,
+    member_type: DynamicMemberType,
+    is_key: bool,
+}
+
+/// Builder of a [`DynamicStructType`]. The members are added in the order in which they are serialized and each of
+/// them gets as member id its position in the structure, starting from 0.
+#[derive(Debug, Clone)]
+pub struct DynamicStructTypeBuilder {
+    type_name: String,
+    members: Vec<DynamicStructMember>,
+}
+
+impl DynamicStructTypeBuilder {
+    /// Create a builder of a structure with the given type name and no members.
+    pub fn new(type_name: &str) -> Self {
+        Self {
+            type_name: String::from(type_name),
+            members: Vec::new(),
+        }
+    }
+
+    /// Add a member with the given name and type at the end of the structure.
+    pub fn member(mut self, name: &str, member_type: DynamicMemberType) -> Self {
+        self.members.push(DynamicStructMember {
+            name: String::from(name),
+            member_type,
+            is_key: false,
+        });
+        self
+    }
+
+    /// Add a member with the given name and type at the end of the structure which is part of the key of the type.
+    pub fn key_member(mut self, name: &str, member_type: DynamicMemberType) -> Self {
+        self.members.push(DynamicStructMember {
+            name: String::from(name),
+            member_type,
+            is_key: true,
+        });
+        self
+    }
+
+    /// Build the [`DynamicStructType`]. This operation returns [`DdsError::BadParameter`] if the type name or the name
+    /// of any of the members is empty, or if several members have the same name.
+    pub fn build(self) -> DdsResult<DynamicStructType> {
+        if self.type_name.is_empty() {
+            return Err(DdsError::BadParameter);
+        }
+        for (index, member) in self.members.iter().enumerate() {
+            if member.name.is_empty() || self.members[..index].iter().any(|m| m.name == member.name)
+            {
+                return Err(DdsError::BadParameter);
+            }
+        }
+        let type_object = Arc::new(struct_type_object(&self.type_name, &self.members));
+        Ok(DynamicStructType {
+            type_name: self.type_name,
+            members: self.members,
+            type_object,
+        })
+    }
+}
+
+fn struct_type_object(type_name: &str, members: &[DynamicStructMember]) -> CompleteTypeObject {
+    CompleteTypeObject::TkStructure {
+        struct_type: CompleteStructType {
+            struct_flags: StructTypeFlag {
+                is_final: true,
+                is_appendable: false,
+                is_mutable: false,
+                is_nested: false,
+                is_autoid_hash: false,
+            },
+            header: CompleteStructHeader {
+                base_type: TypeIdentifier::TkNone,
+                detail: CompleteTypeDetail {
+                    ann_builtin: None,
+                    ann_custom: None,
+                    type_name: String::from(type_name),
+                },
+            },
+            member_seq: members
+                .iter()
+                .enumerate()
+                .map(|(index, member)| CompleteStructMember {
+                    common: CommonStructMember {
+                        member_id: index as u32,
+                        member_flags: StructMemberFlag {
+                            try_construct: TryConstructKind::Discard,
+                            is_external: false,
+                            is_optional: false,
+                            is_must_undestand: true,
+                            is_key: member.is_key,
+                        },
+                        member_type_id: member.member_type.type_identifier(),
+                    },
+                    detail: CompleteMemberDetail {
+                        name: member.name.clone(),
+                        ann_builtin: None,
+                        ann_custom: None,
+                    },
+                })
+                .collect(),
+        },
+    }
+}
+
+/// Structure type with final extensibility defined at runtime with a [`DynamicStructTypeBuilder`]. It describes the
+/// samples of a topic created with
+/// [`DomainParticipant::create_dynamic_topic`](crate::domain::domain_participant::DomainParticipant::create_dynamic_topic)
+/// which are published and received as [`DynamicData`]. The samples are serialized in the same way as those of a
+/// structure with the same members deriving [`DdsType`](crate::infrastructure::type_support::DdsType), so they are
+/// exchanged with the applications using it.
+#[derive(Clone)]
+pub struct DynamicStructType {
+    type_name: String,
+    members: Vec<DynamicStructMember>,
+    type_object: Arc<CompleteTypeObject>,
+}
+
+impl DynamicStructType {
+    /// Name of the type.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Number of members of the structure.
+    pub fn member_count(&self) -> u32 {
+        self.members.len() as u32
+    }
+
+    /// Get the id of the member with the given name, or [`None`] if the structure has no such member.
+    pub fn get_member_id_by_name(&self, name: &str) -> Option<u32> {
+        self.members
+            .iter()
+            .position(|m| m.name == name)
+            .map(|index| index as u32)
+    }
+
+    /// Get the name of the member with the given id, or [`None`] if the structure has no such member.
+    pub fn get_member_name(&self, id: u32) -> Option<&str> {
+        self.members.get(id as usize).map(|m| m.name.as_str())
+    }
+
+    /// Get the type of the member with the given id, or [`None`] if the structure has no such member.
+    pub fn get_member_type(&self, id: u32) -> Option<&DynamicMemberType> {
+        self.members.get(id as usize).map(|m| &m.member_type)
+    }
+
+    /// Returns whether the member with the given id is part of the key of the type.
+    pub fn is_key_member(&self, id: u32) -> bool {
+        self.members.get(id as usize).is_some_and(|m| m.is_key)
+    }
+}
+
+impl core::fmt::Debug for DynamicStructType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynamicStructType")
+            .field("type_name", &self.type_name)
+            .field("members", &self.members)
+            .finish()
+    }
+}
+
+impl PartialEq for DynamicStructType {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_name == other.type_name && self.members == other.members
+    }
+}
+
+impl DynamicType for DynamicStructType {
+    fn get_descriptor(&self) -> Result<TypeDescriptor, XTypesError> {
+        self.type_object.get_descriptor()
+    }
+
+    fn get_name(&self) -> ObjectName {
+        self.type_object.get_name()
+    }
+
+    fn get_kind(&self) -> TypeKind {
+        self.type_object.get_kind()
+    }
+
+    fn get_member_count(&self) -> u32 {
+        self.type_object.get_member_count()
+    }
+
+    fn get_member_by_index(&self, index: u32) -> Result<&dyn DynamicTypeMember, XTypesError> {
+        self.type_object.get_member_by_index(index)
+    }
+}
+
+/// Sample of a [`DynamicStructType`] whose members are accessed by name or by id. A [`DataWriter`](crate::publication::data_writer::DataWriter)
+/// of [`DynamicData`] publishes the samples with the usual write operations, whereas the samples received by a
+/// [`DataReader`](crate::subscription::data_reader::DataReader) are obtained with
+/// [`DataReader::take_serialized`](crate::subscription::data_reader::DataReader::take_serialized) and decoded with
+/// [`DynamicData::from_serialized_data`] since their type is not known at compile time.
+/// This type is not part of the DDS standard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicData {
+    dynamic_type: DynamicStructType,
+    values: Vec<(String, MemberValue)>,
+}
+
+impl DynamicData {
+    /// Create a sample of the given type in which every member holds its default value: zero for the numbers, `false`,
+    /// the null character, empty strings and sequences, and arrays and structures of default values.
+    pub fn new(dynamic_type: DynamicStructType) -> Self {
+        let values = dynamic_type
+            .members
+            .iter()
+            .map(|m| (m.name.clone(), m.member_type.default_value()))
+            .collect();
+        Self {
+            dynamic_type,
+            values,
+        }
+    }
+
+    /// Decode a serialized sample of the given type, as returned by
+    /// [`SerializedSample::serialized_data`](crate::infrastructure::sample_info::SerializedSample::serialized_data).
+    pub fn from_serialized_data(
+        dynamic_type: DynamicStructType,
+        serialized_data: &[u8],
+    ) -> DdsResult<Self> {
+        let values = deserialize_sample_members(serialized_data, &dynamic_type)?;
+        Ok(Self {
+            dynamic_type,
+            values,
+        })
+    }
+
+    /// Type of the sample.
+    pub fn dynamic_type(&self) -> &DynamicStructType {
+        &self.dynamic_type
+    }
+
+    /// Get the value of the member with the given name. This operation returns [`DdsError::BadParameter`] if the type
+    /// has no such member.
+    pub fn get_value(&self, name: &str) -> DdsResult<&MemberValue> {
+        let id = self.member_id(name)?;
+        self.get_value_by_id(id)
+    }
+
+    /// Get the value of the member with the given id. This operation returns [`DdsError::BadParameter`] if the type
+    /// has no such member.
+    pub fn get_value_by_id(&self, id: u32) -> DdsResult<&MemberValue> {
+        self.values
+            .get(id as usize)
+            .map(|(_, value)| value)
+            .ok_or(DdsError::BadParameter)
+    }
+
+    /// Set the value of the member with the given name. This operation returns [`DdsError::BadParameter`] if the type
+    /// has no such member or if the value does not match the type of the member.
+    pub fn set_value(&mut self, name: &str, value: MemberValue) -> DdsResult<()> {
+        let id = self.member_id(name)?;
+        self.set_value_by_id(id, value)
+    }
+
+    /// Set the value of the member with the given id. This operation returns [`DdsError::BadParameter`] if the type
+    /// has no such member or if the value does not match the type of the member.
+    pub fn set_value_by_id(&mut self, id: u32, value: MemberValue) -> DdsResult<()> {
+        match self.dynamic_type.get_member_type(id) {
+            Some(member_type) if member_type.is_type_of(&value) => {
+                self.values[id as usize].1 = value;
+                Ok(())
+            }
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    /// Get the value of the nested structure member with the given name as [`DynamicData`]. This operation returns
+    /// [`DdsError::BadParameter`] if the type has no such member or if the member is not a structure.
+    pub fn get_complex_value(&self, name: &str) -> DdsResult<DynamicData> {
+        let id = self.member_id(name)?;
+        match (
+            self.dynamic_type.get_member_type(id),
+            self.get_value_by_id(id)?,
+        ) {
+            (Some(DynamicMemberType::Struct(struct_type)), MemberValue::Struct(values)) => {
+                Ok(DynamicData {
+                    dynamic_type: struct_type.clone(),
+                    values: struct_type
+                        .members
+                        .iter()
+                        .map(|m| m.name.clone())
+                        .zip(values.iter().cloned())
+                        .collect(),
+                })
+            }
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    /// Set the value of the nested structure member with the given name from a [`DynamicData`]. This operation returns
+    /// [`DdsError::BadParameter`] if the type has no such member or if the type of the value is not the type of the
+    /// member.
+    pub fn set_complex_value(&mut self, name: &str, value: DynamicData) -> DdsResult<()> {
+        let id = self.member_id(name)?;
+        match self.dynamic_type.get_member_type(id) {
+            Some(DynamicMemberType::Struct(struct_type)) if struct_type == &value.dynamic_type => {
+                self.values[id as usize].1 =
+                    MemberValue::Struct(value.values.into_iter().map(|(_, v)| v).collect());
+                Ok(())
+            }
+            _ => Err(DdsError::BadParameter),
+        }
+    }
+
+    fn member_id(&self, name: &str) -> DdsResult<u32> {
+        self.dynamic_type
+            .get_member_id_by_name(name)
+            .ok_or(DdsError::BadParameter)
+    }
+}
+
+impl DdsSerialize for DynamicData {
+    fn serialize_data(&self) -> DdsResult<Vec<u8>> {
+        Ok(serialize_sample_members(&self.dynamic_type, &self.values)?)
+    }
+}
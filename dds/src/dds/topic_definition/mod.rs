@@ -3,3 +3,8 @@ pub mod topic;
 
 /// Contains the [`TopicListener`](crate::topic_definition::topic_listener::TopicListener) trait.
 pub mod topic_listener;
+
+/// Contains the [`DynamicStructTypeBuilder`](crate::topic_definition::dynamic_data::DynamicStructTypeBuilder) and
+/// [`DynamicData`](crate::topic_definition::dynamic_data::DynamicData) which allow defining types and publishing and
+/// receiving their samples at runtime without generated structs.
+pub mod dynamic_data;
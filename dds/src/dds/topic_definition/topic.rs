@@ -33,6 +33,14 @@ impl<R: DdsRuntime> From<TopicAsync<R>> for Topic<R> {
     }
 }
 
+impl<R: DdsRuntime> Clone for Topic<R> {
+    fn clone(&self) -> Self {
+        Self {
+            topic_async: self.topic_async.clone(),
+        }
+    }
+}
+
 impl<R: DdsRuntime> Topic<R> {
     /// This method allows the application to retrieve the [`InconsistentTopicStatus`] of the [`Topic`].
     #[tracing::instrument(skip(self))]
@@ -1,4 +1,4 @@
-use super::condition::StatusCondition;
+use super::condition::{GuardCondition, QueryCondition, ReadCondition, StatusCondition};
 use crate::{
     runtime::DdsRuntime,
     dds_async::wait_set::{ConditionAsync, WaitSetAsync},
@@ -11,6 +11,12 @@ use alloc::vec::Vec;
 pub enum Condition<R: DdsRuntime> {
     /// Status condition variant
     StatusCondition(StatusCondition<R>),
+    /// Guard condition variant
+    GuardCondition(GuardCondition),
+    /// Read condition variant
+    ReadCondition(ReadCondition<R>),
+    /// Query condition variant
+    QueryCondition(QueryCondition<R>),
 }
 impl<R: DdsRuntime> Condition<R> {
     #[tracing::instrument(skip(self))]
@@ -18,6 +24,39 @@ impl<R: DdsRuntime> Condition<R> {
     pub fn get_trigger_value(&self) -> DdsResult<bool> {
         match self {
             Condition::StatusCondition(c) => c.get_trigger_value(),
+            Condition::GuardCondition(c) => c.get_trigger_value(),
+            Condition::ReadCondition(c) => c.get_trigger_value(),
+            Condition::QueryCondition(c) => c.get_trigger_value(),
+        }
+    }
+
+    fn into_async(self) -> ConditionAsync<R> {
+        match self {
+            Condition::StatusCondition(c) => {
+                ConditionAsync::StatusCondition(c.condition_async().clone())
+            }
+            Condition::GuardCondition(c) => {
+                ConditionAsync::GuardCondition(c.condition_async().clone())
+            }
+            Condition::ReadCondition(c) => {
+                ConditionAsync::ReadCondition(c.condition_async().clone())
+            }
+            Condition::QueryCondition(c) => {
+                ConditionAsync::QueryCondition(c.condition_async().clone())
+            }
+        }
+    }
+
+    fn from_async(condition: ConditionAsync<R>) -> Self {
+        match condition {
+            ConditionAsync::StatusCondition(c) => {
+                Condition::StatusCondition(StatusCondition::new(c))
+            }
+            ConditionAsync::GuardCondition(c) => {
+                Condition::GuardCondition(GuardCondition::from_async(c))
+            }
+            ConditionAsync::ReadCondition(c) => Condition::ReadCondition(ReadCondition::new(c)),
+            ConditionAsync::QueryCondition(c) => Condition::QueryCondition(QueryCondition::new(c)),
         }
     }
 }
@@ -53,15 +92,15 @@ impl<R: DdsRuntime> WaitSet<R> {
     /// none of the attached [`Condition`] objects is [`true`], wait will return with [`DdsError::Timeout`](crate::infrastructure::error::DdsError::Timeout).
     /// It is not allowed for more than one application thread to be waiting on the same [`WaitSet`]. If the wait operation is invoked on a
     /// [`WaitSet`] that already has a thread blocking on it, the operation will return immediately with the value [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
+    /// The timeout is measured with the clock of the entities associated with the attached conditions. A [`GuardCondition`] is not associated
+    /// with any entity so waiting on a [`WaitSet`] with only [`GuardCondition`] objects attached measures the timeout with the system clock.
+    /// Without the `std` feature there is no system clock and such a wait, if none of the conditions has a `trigger_value` of [`true`],
+    /// returns [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
     #[tracing::instrument(skip(self))]
     pub fn wait(&self, timeout: Duration) -> DdsResult<Vec<Condition<R>>> {
         Ok(R::block_on(self.waitset_async.wait(timeout))?
             .into_iter()
-            .map(|c| match c {
-                ConditionAsync::StatusCondition(sc) => {
-                    Condition::StatusCondition(StatusCondition::new(sc))
-                }
-            })
+            .map(Condition::from_async)
             .collect())
     }
 
@@ -71,18 +110,14 @@ impl<R: DdsRuntime> WaitSet<R> {
     /// Adding a [`Condition`] that is already attached to the [`WaitSet`] has no effect.
     #[tracing::instrument(skip(self, cond))]
     pub fn attach_condition(&mut self, cond: Condition<R>) -> DdsResult<()> {
-        match cond {
-            Condition::StatusCondition(sc) => R::block_on(self.waitset_async.attach_condition(
-                ConditionAsync::StatusCondition(sc.condition_async().clone()),
-            )),
-        }
+        R::block_on(self.waitset_async.attach_condition(cond.into_async()))
     }
 
     /// Detaches a [`Condition`] from the [`WaitSet`].
     /// If the [`Condition`] was not attached to the [`WaitSet`], the operation will return [`DdsError::PreconditionNotMet`](crate::infrastructure::error::DdsError::PreconditionNotMet).
-    #[tracing::instrument(skip(self, _cond))]
-    pub fn detach_condition(&self, _cond: Condition<R>) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub fn detach_condition(&mut self, cond: Condition<R>) -> DdsResult<()> {
+        R::block_on(self.waitset_async.detach_condition(cond.into_async()))
     }
 
     /// This operation retrieves the list of attached conditions.
@@ -90,11 +125,7 @@ impl<R: DdsRuntime> WaitSet<R> {
     pub fn get_conditions(&self) -> DdsResult<Vec<Condition<R>>> {
         Ok(R::block_on(self.waitset_async.get_conditions())?
             .into_iter()
-            .map(|c| match c {
-                ConditionAsync::StatusCondition(sc) => {
-                    Condition::StatusCondition(StatusCondition::new(sc))
-                }
-            })
+            .map(Condition::from_async)
             .collect())
     }
 }
@@ -1,24 +1,38 @@
 use crate::{
     dcps::{
         actor::ActorAddress,
+        domain_participant_actor_mail::{DomainParticipantMail, ReaderServiceMail},
         status_condition_actor::{StatusConditionActor, StatusConditionMail},
     },
-    infrastructure::{error::DdsResult, status::StatusKind},
-    runtime::{DdsRuntime, OneshotReceive},
+    infrastructure::{
+        error::DdsResult,
+        instance::InstanceHandle,
+        sample_info::{InstanceStateKind, SampleStateKind, ViewStateKind},
+        status::StatusKind,
+    },
+    runtime::{ChannelSend, DdsRuntime, OneshotReceive},
+};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    task::Waker,
 };
-use alloc::vec::Vec;
 
 /// Async version of [`StatusCondition`](crate::infrastructure::condition::StatusCondition).
 pub struct StatusConditionAsync<R: DdsRuntime> {
     address: ActorAddress<R, StatusConditionActor<R>>,
-    clock_handle: R::ClockHandle,
+    participant_handle: InstanceHandle,
+    entity_handle: InstanceHandle,
+    timer_handle: R::TimerHandle,
 }
 
 impl<R: DdsRuntime> Clone for StatusConditionAsync<R> {
     fn clone(&self) -> Self {
         Self {
             address: self.address.clone(),
-            clock_handle: self.clock_handle.clone(),
+            participant_handle: self.participant_handle,
+            entity_handle: self.entity_handle,
+            timer_handle: self.timer_handle.clone(),
         }
     }
 }
@@ -26,16 +40,42 @@ impl<R: DdsRuntime> Clone for StatusConditionAsync<R> {
 impl<R: DdsRuntime> StatusConditionAsync<R> {
     pub(crate) fn new(
         address: ActorAddress<R, StatusConditionActor<R>>,
-        clock_handle: R::ClockHandle,
+        participant_handle: InstanceHandle,
+        entity_handle: InstanceHandle,
+        timer_handle: R::TimerHandle,
     ) -> Self {
         Self {
             address,
-            clock_handle,
+            participant_handle,
+            entity_handle,
+            timer_handle,
         }
     }
 
-    pub(crate) fn clock_handle(&self) -> &R::ClockHandle {
-        &self.clock_handle
+    // The entity handles are only unique within a participant
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        self.participant_handle == other.participant_handle
+            && self.entity_handle == other.entity_handle
+    }
+
+    pub(crate) fn timer_handle(&self) -> &R::TimerHandle {
+        &self.timer_handle
+    }
+
+    pub(crate) async fn add_trigger_waiter(
+        &self,
+        waiter_id: usize,
+        waiter: R::ChannelSender<()>,
+    ) -> DdsResult<()> {
+        self.address
+            .send_actor_mail(StatusConditionMail::AddTriggerWaiter { waiter_id, waiter })
+            .await
+    }
+
+    pub(crate) async fn remove_trigger_waiter(&self, waiter_id: usize) -> DdsResult<()> {
+        self.address
+            .send_actor_mail(StatusConditionMail::RemoveTriggerWaiter { waiter_id })
+            .await
     }
 }
 
@@ -81,3 +121,284 @@ impl<R: DdsRuntime> StatusConditionAsync<R> {
         reply_receiver.receive().await
     }
 }
+
+/// Async version of [`GuardCondition`](crate::condition::GuardCondition).
+#[derive(Clone, Default)]
+pub struct GuardConditionAsync {
+    inner: Arc<GuardConditionInner>,
+}
+
+#[derive(Default)]
+struct GuardConditionInner {
+    trigger_value: AtomicBool,
+    wakers: TriggerWakers,
+}
+
+impl GuardConditionAsync {
+    /// Create a new [`GuardConditionAsync`] whose `trigger_value` is [`false`].
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    pub(crate) fn store_trigger_value(&self, value: bool) {
+        self.inner.trigger_value.store(value, Ordering::Release);
+        if value {
+            self.inner.wakers.wake_all();
+        }
+    }
+
+    pub(crate) fn load_trigger_value(&self) -> bool {
+        self.inner.trigger_value.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn register_waker(&self, waker: &Waker) {
+        self.inner.wakers.register(waker);
+    }
+
+    /// Async version of [`set_trigger_value`](crate::condition::GuardCondition::set_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_trigger_value(&self, value: bool) -> DdsResult<()> {
+        self.store_trigger_value(value);
+        Ok(())
+    }
+
+    /// Async version of [`get_trigger_value`](crate::condition::GuardCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        Ok(self.load_trigger_value())
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct TriggerWakers(std::sync::Mutex<Vec<Waker>>);
+
+#[cfg(feature = "std")]
+impl TriggerWakers {
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.0.lock().expect("Mutex shouldn't be poisoned");
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    fn wake_all(&self) {
+        let wakers = core::mem::take(&mut *self.0.lock().expect("Mutex shouldn't be poisoned"));
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+// Without a lock the waiting tasks can not be registered so a guard condition
+// is only noticed when any other attached condition wakes the WaitSet.
+#[cfg(not(feature = "std"))]
+#[derive(Default)]
+struct TriggerWakers;
+
+#[cfg(not(feature = "std"))]
+impl TriggerWakers {
+    fn register(&self, _waker: &Waker) {}
+
+    fn wake_all(&self) {}
+}
+
+/// Async version of [`ReadCondition`](crate::condition::ReadCondition).
+pub struct ReadConditionAsync<R: DdsRuntime> {
+    participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+    subscriber_handle: InstanceHandle,
+    data_reader_handle: InstanceHandle,
+    condition_id: u32,
+    sample_states: Vec<SampleStateKind>,
+    view_states: Vec<ViewStateKind>,
+    instance_states: Vec<InstanceStateKind>,
+    reader_status_condition: StatusConditionAsync<R>,
+}
+
+impl<R: DdsRuntime> Clone for ReadConditionAsync<R> {
+    fn clone(&self) -> Self {
+        Self {
+            participant_address: self.participant_address.clone(),
+            subscriber_handle: self.subscriber_handle,
+            data_reader_handle: self.data_reader_handle,
+            condition_id: self.condition_id,
+            sample_states: self.sample_states.clone(),
+            view_states: self.view_states.clone(),
+            instance_states: self.instance_states.clone(),
+            reader_status_condition: self.reader_status_condition.clone(),
+        }
+    }
+}
+
+impl<R: DdsRuntime> ReadConditionAsync<R> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        participant_address: R::ChannelSender<DomainParticipantMail<R>>,
+        subscriber_handle: InstanceHandle,
+        data_reader_handle: InstanceHandle,
+        condition_id: u32,
+        sample_states: Vec<SampleStateKind>,
+        view_states: Vec<ViewStateKind>,
+        instance_states: Vec<InstanceStateKind>,
+        reader_status_condition: StatusConditionAsync<R>,
+    ) -> Self {
+        Self {
+            participant_address,
+            subscriber_handle,
+            data_reader_handle,
+            condition_id,
+            sample_states,
+            view_states,
+            instance_states,
+            reader_status_condition,
+        }
+    }
+
+    pub(crate) fn data_reader_handle(&self) -> InstanceHandle {
+        self.data_reader_handle
+    }
+
+    pub(crate) fn condition_id(&self) -> u32 {
+        self.condition_id
+    }
+
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        self.reader_status_condition
+            .is_same(&other.reader_status_condition)
+            && self.condition_id == other.condition_id
+    }
+
+    // The read conditions are re-evaluated on the changes of the communication
+    // states of their reader
+    pub(crate) fn reader_status_condition(&self) -> &StatusConditionAsync<R> {
+        &self.reader_status_condition
+    }
+
+    /// Async version of [`get_sample_state_mask`](crate::condition::ReadCondition::get_sample_state_mask).
+    pub fn get_sample_state_mask(&self) -> &[SampleStateKind] {
+        &self.sample_states
+    }
+
+    /// Async version of [`get_view_state_mask`](crate::condition::ReadCondition::get_view_state_mask).
+    pub fn get_view_state_mask(&self) -> &[ViewStateKind] {
+        &self.view_states
+    }
+
+    /// Async version of [`get_instance_state_mask`](crate::condition::ReadCondition::get_instance_state_mask).
+    pub fn get_instance_state_mask(&self) -> &[InstanceStateKind] {
+        &self.instance_states
+    }
+
+    /// Async version of [`get_trigger_value`](crate::condition::ReadCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetReadConditionTriggerValue {
+                    subscriber_handle: self.subscriber_handle,
+                    data_reader_handle: self.data_reader_handle,
+                    condition_id: self.condition_id,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+}
+
+/// Async version of [`QueryCondition`](crate::condition::QueryCondition).
+pub struct QueryConditionAsync<R: DdsRuntime> {
+    read_condition: ReadConditionAsync<R>,
+    query_expression: String,
+}
+
+impl<R: DdsRuntime> Clone for QueryConditionAsync<R> {
+    fn clone(&self) -> Self {
+        Self {
+            read_condition: self.read_condition.clone(),
+            query_expression: self.query_expression.clone(),
+        }
+    }
+}
+
+impl<R: DdsRuntime> QueryConditionAsync<R> {
+    pub(crate) fn new(read_condition: ReadConditionAsync<R>, query_expression: String) -> Self {
+        Self {
+            read_condition,
+            query_expression,
+        }
+    }
+
+    pub(crate) fn read_condition(&self) -> &ReadConditionAsync<R> {
+        &self.read_condition
+    }
+
+    /// Async version of [`get_sample_state_mask`](crate::condition::QueryCondition::get_sample_state_mask).
+    pub fn get_sample_state_mask(&self) -> &[SampleStateKind] {
+        self.read_condition.get_sample_state_mask()
+    }
+
+    /// Async version of [`get_view_state_mask`](crate::condition::QueryCondition::get_view_state_mask).
+    pub fn get_view_state_mask(&self) -> &[ViewStateKind] {
+        self.read_condition.get_view_state_mask()
+    }
+
+    /// Async version of [`get_instance_state_mask`](crate::condition::QueryCondition::get_instance_state_mask).
+    pub fn get_instance_state_mask(&self) -> &[InstanceStateKind] {
+        self.read_condition.get_instance_state_mask()
+    }
+
+    /// Async version of [`get_query_expression`](crate::condition::QueryCondition::get_query_expression).
+    pub fn get_query_expression(&self) -> &str {
+        &self.query_expression
+    }
+
+    /// Async version of [`get_query_parameters`](crate::condition::QueryCondition::get_query_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_query_parameters(&self) -> DdsResult<Vec<String>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.read_condition
+            .participant_address
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetQueryParameters {
+                    subscriber_handle: self.read_condition.subscriber_handle,
+                    data_reader_handle: self.read_condition.data_reader_handle,
+                    condition_id: self.read_condition.condition_id,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_query_parameters`](crate::condition::QueryCondition::set_query_parameters).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_query_parameters(&self, query_parameters: &[String]) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.read_condition
+            .participant_address
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetQueryParameters {
+                    subscriber_handle: self.read_condition.subscriber_handle,
+                    data_reader_handle: self.read_condition.data_reader_handle,
+                    condition_id: self.read_condition.condition_id,
+                    query_parameters: query_parameters.to_vec(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_trigger_value`](crate::condition::QueryCondition::get_trigger_value).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_trigger_value(&self) -> DdsResult<bool> {
+        self.read_condition.get_trigger_value().await
+    }
+}
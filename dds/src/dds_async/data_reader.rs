@@ -1,33 +1,56 @@
 use tracing::warn;
 
-use super::{condition::StatusConditionAsync, subscriber::SubscriberAsync, topic::TopicAsync};
+use super::{
+    condition::{QueryConditionAsync, ReadConditionAsync, StatusConditionAsync},
+    subscriber::SubscriberAsync,
+    topic::TopicAsync,
+    wait_set::new_waiter_id,
+};
 use crate::{
-    builtin_topics::PublicationBuiltinTopicData,
+    builtin_topics::{Locator, PublicationBuiltinTopicData},
     dcps::{
         actor::ActorAddress,
-        domain_participant_actor_mail::{DomainParticipantMail, ReaderServiceMail},
+        data_reader::ReadAheadValue,
+        domain_participant_actor_mail::{
+            DomainParticipantMail, MessageServiceMail, ReaderServiceMail,
+        },
         listeners::data_reader_listener::DataReaderListenerActor,
-        status_condition_actor::StatusConditionActor,
+        status_condition_actor::{StatusConditionActor, StatusConditionMail},
     },
     infrastructure::{
-        error::DdsResult,
+        error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataReaderQos, QosKind},
+        qos_policy::QosPolicyId,
         sample_info::{
-            InstanceStateKind, Sample, SampleStateKind, ViewStateKind, ANY_INSTANCE_STATE,
-            ANY_VIEW_STATE,
+            InstanceStateKind, Sample, SampleRef, SampleStateKind, SequenceNumber,
+            SerializedSample, ViewStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE,
         },
         status::{
             LivelinessChangedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
             SampleLostStatus, SampleRejectedStatus, StatusKind, SubscriptionMatchedStatus,
+            WriterRestartedStatus,
+        },
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsSerialize},
+    },
+    runtime::{ChannelReceive, ChannelSend, Clock, DdsRuntime, OneshotReceive, Spawner, Timer},
+    subscription::{
+        data_reader::{
+            DeserializationErrorPolicy, HeadOfLineStatus, IncompleteCoherentSetPolicy,
+            InstanceFreshnessObserver, InstanceFreshnessStatus, InstanceStatistics,
         },
-        time::Duration,
+        data_reader_listener::DataReaderListener,
     },
-    runtime::{ChannelSend, DdsRuntime, OneshotReceive},
-    subscription::data_reader_listener::DataReaderListener,
 };
-use alloc::{vec, vec::Vec};
-use core::marker::PhantomData;
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec};
+use core::{
+    future::{poll_fn, Future},
+    marker::PhantomData,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+use futures_core::Stream;
 
 /// Async version of [`DataReader`](crate::subscription::data_reader::DataReader).
 pub struct DataReaderAsync<R: DdsRuntime, Foo> {
@@ -35,7 +58,9 @@ pub struct DataReaderAsync<R: DdsRuntime, Foo> {
     status_condition_address: ActorAddress<R, StatusConditionActor<R>>,
     subscriber: SubscriberAsync<R>,
     topic: TopicAsync<R>,
-    phantom: PhantomData<Foo>,
+    // The handle does not hold any value of type Foo so it can be shared between threads
+    // independently of the type
+    phantom: PhantomData<fn() -> Foo>,
 }
 
 impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
@@ -124,6 +149,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DomainParticipantMail::Reader(ReaderServiceMail::Take {
+                participant_address: self.participant_address().clone(),
                 subscriber_handle: self.subscriber.get_instance_handle().await,
                 data_reader_handle: self.handle,
                 max_samples,
@@ -142,6 +168,161 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
             .collect())
     }
 
+    /// Async version of [`take_serialized`](crate::subscription::data_reader::DataReader::take_serialized).
+    #[tracing::instrument(skip(self))]
+    pub async fn take_serialized(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SerializedSample>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(ReaderServiceMail::Take {
+                participant_address: self.participant_address().clone(),
+                subscriber_handle: self.subscriber.get_instance_handle().await,
+                data_reader_handle: self.handle,
+                max_samples,
+                sample_states: sample_states.to_vec(),
+                view_states: view_states.to_vec(),
+                instance_states: instance_states.to_vec(),
+                specific_instance_handle: None,
+                reply_sender,
+            }))
+            .await?;
+        let samples = reply_receiver.receive().await??;
+
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info)| SerializedSample::new(data, sample_info))
+            .collect())
+    }
+
+    /// Async version of [`take_timeout`](crate::subscription::data_reader::DataReader::take_timeout).
+    #[tracing::instrument(skip(self))]
+    pub async fn take_timeout(
+        &self,
+        max_samples: i32,
+        timeout: Duration,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        let clock_handle = self.subscriber.get_participant().clock_handle().clone();
+        let start = clock_handle.now();
+        let status_condition = self.get_statuscondition();
+        let mut timer_handle = status_condition.timer_handle().clone();
+        let waiter_id = new_waiter_id();
+        let (waiter_sender, mut waiter_receiver) = R::channel();
+        let result = loop {
+            // The waiter is registered before taking so that no sample received
+            // in between is missed
+            status_condition
+                .add_trigger_waiter(waiter_id, waiter_sender.clone())
+                .await?;
+
+            match self
+                .take(
+                    max_samples,
+                    ANY_SAMPLE_STATE,
+                    ANY_VIEW_STATE,
+                    ANY_INSTANCE_STATE,
+                )
+                .await
+            {
+                Err(DdsError::NoData) => (),
+                result => break result,
+            }
+
+            let elapsed = clock_handle.now() - start;
+            if elapsed >= timeout {
+                break Err(DdsError::Timeout);
+            }
+
+            // The take is retried when the communication state of the reader changes or the
+            // timeout elapses
+            let mut notification = pin!(waiter_receiver.receive());
+            let mut timeout_delay = pin!(timer_handle.delay((timeout - elapsed).into()));
+            poll_fn(|cx| {
+                if notification.as_mut().poll(cx).is_ready()
+                    || timeout_delay.as_mut().poll(cx).is_ready()
+                {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+        };
+
+        status_condition.remove_trigger_waiter(waiter_id).await.ok();
+
+        result
+    }
+
+    /// Async version of [`read_loaned`](crate::subscription::data_reader::DataReader::read_loaned).
+    #[tracing::instrument(skip(self))]
+    pub async fn read_loaned(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SampleRef<Foo>>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::ReadLoaned {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    max_samples,
+                    sample_states: sample_states.to_vec(),
+                    view_states: view_states.to_vec(),
+                    instance_states: instance_states.to_vec(),
+                    specific_instance_handle: None,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let samples = reply_receiver.receive().await??;
+
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info, loan)| SampleRef::new(data, sample_info, loan))
+            .collect())
+    }
+
+    /// Async version of [`take_loaned`](crate::subscription::data_reader::DataReader::take_loaned).
+    #[tracing::instrument(skip(self))]
+    pub async fn take_loaned(
+        &self,
+        max_samples: i32,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<Vec<SampleRef<Foo>>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::TakeLoaned {
+                    participant_address: self.participant_address().clone(),
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    max_samples,
+                    sample_states: sample_states.to_vec(),
+                    view_states: view_states.to_vec(),
+                    instance_states: instance_states.to_vec(),
+                    specific_instance_handle: None,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let samples = reply_receiver.receive().await??;
+
+        Ok(samples
+            .into_iter()
+            .map(|(data, sample_info, loan)| SampleRef::new(data, sample_info, loan))
+            .collect())
+    }
+
     /// Async version of [`read_next_sample`](crate::subscription::data_reader::DataReader::read_next_sample).
     #[tracing::instrument(skip(self))]
     pub async fn read_next_sample(&self) -> DdsResult<Sample<Foo>> {
@@ -169,6 +350,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DomainParticipantMail::Reader(ReaderServiceMail::Take {
+                participant_address: self.participant_address().clone(),
                 subscriber_handle: self.subscriber.get_instance_handle().await,
                 data_reader_handle: self.handle,
                 max_samples: 1,
@@ -227,6 +409,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DomainParticipantMail::Reader(ReaderServiceMail::Take {
+                participant_address: self.participant_address().clone(),
                 subscriber_handle: self.subscriber.get_instance_handle().await,
                 data_reader_handle: self.handle,
                 max_samples,
@@ -291,6 +474,7 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         self.participant_address()
             .send(DomainParticipantMail::Reader(
                 ReaderServiceMail::TakeNextInstance {
+                    participant_address: self.participant_address().clone(),
                     subscriber_handle: self.subscriber.get_instance_handle().await,
                     data_reader_handle: self.handle,
                     max_samples,
@@ -309,20 +493,64 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
             .collect())
     }
 
-    /// Async version of [`get_key_value`](crate::subscription::data_reader::DataReader::get_key_value).
-    #[tracing::instrument(skip(self, _key_holder))]
-    pub async fn get_key_value(
+    /// Async version of [`read_next_instance_w_condition`](crate::subscription::data_reader::DataReader::read_next_instance_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn read_next_instance_w_condition(
         &self,
-        _key_holder: &mut Foo,
-        _handle: InstanceHandle,
-    ) -> DdsResult<()> {
-        todo!()
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadConditionAsync<R>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        self.check_read_condition(a_condition)?;
+        self.read_next_instance(
+            max_samples,
+            previous_handle,
+            a_condition.get_sample_state_mask(),
+            a_condition.get_view_state_mask(),
+            a_condition.get_instance_state_mask(),
+        )
+        .await
     }
 
-    /// Async version of [`lookup_instance`](crate::subscription::data_reader::DataReader::lookup_instance).
-    #[tracing::instrument(skip(self, _instance))]
-    pub async fn lookup_instance(&self, _instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
-        todo!()
+    /// Async version of [`take_next_instance_w_condition`](crate::subscription::data_reader::DataReader::take_next_instance_w_condition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn take_next_instance_w_condition(
+        &self,
+        max_samples: i32,
+        previous_handle: Option<InstanceHandle>,
+        a_condition: &ReadConditionAsync<R>,
+    ) -> DdsResult<Vec<Sample<Foo>>> {
+        self.check_read_condition(a_condition)?;
+        self.take_next_instance(
+            max_samples,
+            previous_handle,
+            a_condition.get_sample_state_mask(),
+            a_condition.get_view_state_mask(),
+            a_condition.get_instance_state_mask(),
+        )
+        .await
+    }
+
+    /// Async version of [`take_all_by_instance`](crate::subscription::data_reader::DataReader::take_all_by_instance).
+    #[tracing::instrument(skip(self))]
+    pub async fn take_all_by_instance(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<BTreeMap<InstanceHandle, Vec<Sample<Foo>>>> {
+        let samples = self
+            .take(i32::MAX, sample_states, view_states, instance_states)
+            .await?;
+
+        let mut samples_by_instance = BTreeMap::<InstanceHandle, Vec<Sample<Foo>>>::new();
+        for sample in samples {
+            samples_by_instance
+                .entry(sample.sample_info().instance_handle)
+                .or_default()
+                .push(sample);
+        }
+        Ok(samples_by_instance)
     }
 }
 
@@ -330,7 +558,17 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     /// Async version of [`get_liveliness_changed_status`](crate::subscription::data_reader::DataReader::get_liveliness_changed_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_liveliness_changed_status(&self) -> DdsResult<LivelinessChangedStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetLivelinessChangedStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_requested_deadline_missed_status`](crate::subscription::data_reader::DataReader::get_requested_deadline_missed_status).
@@ -338,7 +576,17 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn get_requested_deadline_missed_status(
         &self,
     ) -> DdsResult<RequestedDeadlineMissedStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetRequestedDeadlineMissedStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_requested_incompatible_qos_status`](crate::subscription::data_reader::DataReader::get_requested_incompatible_qos_status).
@@ -346,19 +594,65 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub async fn get_requested_incompatible_qos_status(
         &self,
     ) -> DdsResult<RequestedIncompatibleQosStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetRequestedIncompatibleQosStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_sample_lost_status`](crate::subscription::data_reader::DataReader::get_sample_lost_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_sample_lost_status(&self) -> DdsResult<SampleLostStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetSampleLostStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_writer_restarted_status`](crate::subscription::data_reader::DataReader::get_writer_restarted_status).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_writer_restarted_status(&self) -> DdsResult<WriterRestartedStatus> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetWriterRestartedStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_sample_rejected_status`](crate::subscription::data_reader::DataReader::get_sample_rejected_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_sample_rejected_status(&self) -> DdsResult<SampleRejectedStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetSampleRejectedStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_subscription_matched_status`](crate::subscription::data_reader::DataReader::get_subscription_matched_status).
@@ -407,6 +701,42 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         reply_receiver.receive().await?.await
     }
 
+    /// Async version of [`request_historical_data`](crate::subscription::data_reader::DataReader::request_historical_data).
+    #[tracing::instrument(skip(self))]
+    pub async fn request_historical_data(&self) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::RequestHistoricalData {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`check_compatibility`](crate::subscription::data_reader::DataReader::check_compatibility).
+    #[tracing::instrument(skip(self))]
+    pub async fn check_compatibility(
+        &self,
+        publication_builtin_topic_data: &PublicationBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::CheckCompatibility {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    publication_builtin_topic_data: publication_builtin_topic_data.clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_matched_publication_data`](crate::subscription::data_reader::DataReader::get_matched_publication_data).
     #[tracing::instrument(skip(self))]
     pub async fn get_matched_publication_data(
@@ -428,6 +758,48 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
         reply_receiver.receive().await?
     }
 
+    /// Async version of [`get_matched_publication_high_watermark`](crate::subscription::data_reader::DataReader::get_matched_publication_high_watermark).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_matched_publication_high_watermark(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<SequenceNumber> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetMatchedPublicationHighWatermark {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    publication_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_matched_publication_locators`](crate::subscription::data_reader::DataReader::get_matched_publication_locators).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_matched_publication_locators(
+        &self,
+        publication_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetMatchedPublicationLocators {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    publication_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_matched_publications`](crate::subscription::data_reader::DataReader::get_matched_publications).
     #[tracing::instrument(skip(self))]
     pub async fn get_matched_publications(&self) -> DdsResult<Vec<InstanceHandle>> {
@@ -479,14 +851,132 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
-            self.subscriber.get_participant().clock_handle().clone(),
+            self.subscriber.get_participant().instance_handle(),
+            self.handle,
+            self.subscriber.get_participant().timer_handle().clone(),
         )
     }
 
+    async fn create_read_condition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query: Option<(String, Vec<String>)>,
+    ) -> DdsResult<ReadConditionAsync<R>> {
+        let subscriber_handle = self.subscriber.get_instance_handle().await;
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::CreateReadCondition {
+                    subscriber_handle,
+                    data_reader_handle: self.handle,
+                    sample_states: sample_states.to_vec(),
+                    view_states: view_states.to_vec(),
+                    instance_states: instance_states.to_vec(),
+                    query,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let condition_id = reply_receiver.receive().await??;
+        Ok(ReadConditionAsync::new(
+            self.participant_address().clone(),
+            subscriber_handle,
+            self.handle,
+            condition_id,
+            sample_states.to_vec(),
+            view_states.to_vec(),
+            instance_states.to_vec(),
+            self.get_statuscondition(),
+        ))
+    }
+
+    fn check_read_condition(&self, read_condition: &ReadConditionAsync<R>) -> DdsResult<()> {
+        if read_condition.data_reader_handle() != self.handle {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Condition does not belong to this data reader",
+            )));
+        }
+        Ok(())
+    }
+
+    async fn delete_read_condition(&self, read_condition: &ReadConditionAsync<R>) -> DdsResult<()> {
+        self.check_read_condition(read_condition)?;
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::DeleteReadCondition {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    condition_id: read_condition.condition_id(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`create_readcondition`](crate::subscription::data_reader::DataReader::create_readcondition).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_readcondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+    ) -> DdsResult<ReadConditionAsync<R>> {
+        self.create_read_condition(sample_states, view_states, instance_states, None)
+            .await
+    }
+
+    /// Async version of [`create_querycondition`](crate::subscription::data_reader::DataReader::create_querycondition).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_querycondition(
+        &self,
+        sample_states: &[SampleStateKind],
+        view_states: &[ViewStateKind],
+        instance_states: &[InstanceStateKind],
+        query_expression: &str,
+        query_parameters: &[String],
+    ) -> DdsResult<QueryConditionAsync<R>> {
+        let read_condition = self
+            .create_read_condition(
+                sample_states,
+                view_states,
+                instance_states,
+                Some((String::from(query_expression), query_parameters.to_vec())),
+            )
+            .await?;
+        Ok(QueryConditionAsync::new(
+            read_condition,
+            String::from(query_expression),
+        ))
+    }
+
+    /// Async version of [`delete_readcondition`](crate::subscription::data_reader::DataReader::delete_readcondition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn delete_readcondition(&self, a_condition: &ReadConditionAsync<R>) -> DdsResult<()> {
+        self.delete_read_condition(a_condition).await
+    }
+
+    /// Async version of [`delete_querycondition`](crate::subscription::data_reader::DataReader::delete_querycondition).
+    #[tracing::instrument(skip(self, a_condition))]
+    pub async fn delete_querycondition(
+        &self,
+        a_condition: &QueryConditionAsync<R>,
+    ) -> DdsResult<()> {
+        self.delete_read_condition(a_condition.read_condition())
+            .await
+    }
+
     /// Async version of [`get_status_changes`](crate::subscription::data_reader::DataReader::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.status_condition_address
+            .send_actor_mail(StatusConditionMail::GetStatusChanges { reply_sender })
+            .await?;
+        reply_receiver.receive().await
     }
 
     /// Async version of [`enable`](crate::subscription::data_reader::DataReader::enable).
@@ -539,4 +1029,362 @@ impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo> {
             .await?;
         reply_receiver.receive().await?
     }
+
+    /// Async version of [`set_incomplete_coherent_set_timeout`](crate::subscription::data_reader::DataReader::set_incomplete_coherent_set_timeout).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_incomplete_coherent_set_timeout(
+        &self,
+        timeout: DurationKind,
+        policy: IncompleteCoherentSetPolicy,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetIncompleteCoherentSetTimeout {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    timeout,
+                    policy,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_gap_free_delivery`](crate::subscription::data_reader::DataReader::set_gap_free_delivery).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_gap_free_delivery(&self, enabled: bool) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetGapFreeDelivery {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    enabled,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_content_filter`](crate::subscription::data_reader::DataReader::set_content_filter).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_content_filter(
+        &self,
+        filter_expression: &str,
+        filter_parameters: &[String],
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetContentFilter {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    filter_expression: String::from(filter_expression),
+                    filter_parameters: filter_parameters.to_vec(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_head_of_line_status`](crate::subscription::data_reader::DataReader::get_head_of_line_status).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_head_of_line_status(&self) -> DdsResult<Vec<HeadOfLineStatus>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetHeadOfLineStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_instance_statistics`](crate::subscription::data_reader::DataReader::get_instance_statistics).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_instance_statistics(&self) -> DdsResult<InstanceStatistics> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetInstanceStatistics {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_instance_freshness_watchdog`](crate::subscription::data_reader::DataReader::set_instance_freshness_watchdog).
+    #[tracing::instrument(skip(self, observer))]
+    pub async fn set_instance_freshness_watchdog(
+        &self,
+        period: DurationKind,
+        observer: Option<Arc<dyn InstanceFreshnessObserver>>,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetInstanceFreshnessWatchdog {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    period,
+                    observer,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_instance_freshness_status`](crate::subscription::data_reader::DataReader::get_instance_freshness_status).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_instance_freshness_status(&self) -> DdsResult<InstanceFreshnessStatus> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetInstanceFreshnessStatus {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
+    /// Async version of [`get_key_value`](crate::subscription::data_reader::DataReader::get_key_value).
+    #[tracing::instrument(skip(self, key_holder))]
+    pub async fn get_key_value(
+        &self,
+        key_holder: &mut Foo,
+        handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        let serialized_key_holder = key_holder.serialize_data()?;
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::GetKeyValue {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    serialized_key_holder,
+                    handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let serialized_key_holder = reply_receiver.receive().await??;
+        *key_holder = Foo::deserialize_data(&serialized_key_holder)?;
+        Ok(())
+    }
+
+    /// Async version of [`lookup_instance`](crate::subscription::data_reader::DataReader::lookup_instance).
+    #[tracing::instrument(skip(self, instance))]
+    pub async fn lookup_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        let serialized_data = instance.serialize_data()?;
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::LookupInstance {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    serialized_data,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo>
+where
+    Foo: for<'de> DdsDeserialize<'de> + Send + Sync + 'static,
+{
+    /// Async version of [`set_read_ahead_deserialization`](crate::subscription::data_reader::DataReader::set_read_ahead_deserialization).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_read_ahead_deserialization(&self, enabled: bool) -> DdsResult<()> {
+        let subscriber_handle = self.subscriber.get_instance_handle().await;
+        let read_ahead_sender = if enabled {
+            // The samples are deserialized by a task of their own so that the participant keeps processing
+            // messages meanwhile. The task finishes when the reader drops the sender.
+            let (read_ahead_sender, mut read_ahead_receiver) = R::channel::<Arc<[u8]>>();
+            let participant_address = self.participant_address().clone();
+            let data_reader_handle = self.handle;
+            self.get_subscriber()
+                .get_participant()
+                .spawner_handle()
+                .spawn(async move {
+                    while let Some(serialized_data) = read_ahead_receiver.receive().await {
+                        let Ok(value) = Foo::deserialize_data(serialized_data.as_ref()) else {
+                            continue;
+                        };
+                        participant_address
+                            .send(DomainParticipantMail::Message(
+                                MessageServiceMail::AddReadAheadValue {
+                                    subscriber_handle,
+                                    data_reader_handle,
+                                    serialized_data,
+                                    read_ahead_value: ReadAheadValue(Arc::new(value)),
+                                },
+                            ))
+                            .await
+                            .ok();
+                    }
+                });
+            Some(read_ahead_sender)
+        } else {
+            None
+        };
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetReadAheadSender {
+                    subscriber_handle,
+                    data_reader_handle: self.handle,
+                    read_ahead_sender,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_deserialization_error_policy`](crate::subscription::data_reader::DataReader::set_deserialization_error_policy).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_deserialization_error_policy(
+        &self,
+        policy: DeserializationErrorPolicy,
+    ) -> DdsResult<()> {
+        let deserialization_validator = if policy != DeserializationErrorPolicy::Deliver {
+            Some(
+                Box::new(|serialized_data: &[u8]| Foo::deserialize_data(serialized_data).is_ok())
+                    as _,
+            )
+        } else {
+            None
+        };
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::SetDeserializationErrorPolicy {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    policy,
+                    deserialization_validator,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+}
+
+impl<R: DdsRuntime, Foo> DataReaderAsync<R, Foo>
+where
+    Foo: Send + Sync + 'static,
+{
+    /// Async version of [`sample_stream`](crate::subscription::data_reader::DataReader::sample_stream).
+    #[tracing::instrument(skip(self))]
+    pub fn sample_stream(&self) -> SampleStream<R, Foo> {
+        SampleStream {
+            data_reader: self.clone(),
+            next_sample: Some(Box::pin(self.clone().take_next_stream_sample(0))),
+        }
+    }
+
+    async fn take_next_stream_sample(
+        self,
+        mut added_change_count: u64,
+    ) -> Option<(Sample<Foo>, u64)> {
+        loop {
+            match self
+                .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+                .await
+            {
+                Ok(mut samples) => return samples.pop().map(|s| (s, added_change_count)),
+                Err(DdsError::NoData) => {
+                    added_change_count = self.wait_for_samples(added_change_count).await.ok()?;
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+
+    async fn wait_for_samples(&self, added_change_count: u64) -> DdsResult<u64> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Reader(
+                ReaderServiceMail::WaitForSamples {
+                    subscriber_handle: self.subscriber.get_instance_handle().await,
+                    data_reader_handle: self.handle,
+                    added_change_count,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+}
+
+type NextStreamSample<Foo> = Pin<Box<dyn Future<Output = Option<(Sample<Foo>, u64)>> + Send>>;
+
+/// Stream of the samples received by a [`DataReaderAsync`] created with
+/// [`sample_stream`](DataReaderAsync::sample_stream).
+///
+/// The samples are taken one at a time from the reader only when the stream is polled. Samples
+/// which are not yet consumed remain in the reader so the History and ResourceLimits QoS policies
+/// of the reader determine what happens when the application consumes them slower than they are
+/// received: with KEEP_LAST history the oldest samples of each instance are replaced and with
+/// KEEP_ALL history the samples are rejected once the resource limits are reached, which
+/// makes reliable writers wait until the stream is polled. The stream ends when the reader is
+/// deleted.
+pub struct SampleStream<R: DdsRuntime, Foo> {
+    data_reader: DataReaderAsync<R, Foo>,
+    next_sample: Option<NextStreamSample<Foo>>,
+}
+
+// The pending take operation is boxed so the stream itself is never pinned
+impl<R: DdsRuntime, Foo> Unpin for SampleStream<R, Foo> {}
+
+impl<R: DdsRuntime, Foo> Stream for SampleStream<R, Foo>
+where
+    Foo: Send + Sync + 'static,
+{
+    type Item = Sample<Foo>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some(next_sample) = this.next_sample.as_mut() else {
+            return Poll::Ready(None);
+        };
+        match next_sample.as_mut().poll(cx) {
+            Poll::Ready(Some((sample, added_change_count))) => {
+                let data_reader = this.data_reader.clone();
+                this.next_sample = Some(Box::pin(
+                    data_reader.take_next_stream_sample(added_change_count),
+                ));
+                Poll::Ready(Some(sample))
+            }
+            Poll::Ready(None) => {
+                this.next_sample = None;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
@@ -2,46 +2,67 @@ use tracing::warn;
 
 use super::{condition::StatusConditionAsync, publisher::PublisherAsync, topic::TopicAsync};
 use crate::{
-    builtin_topics::SubscriptionBuiltinTopicData,
+    builtin_topics::{Locator, SubscriptionBuiltinTopicData},
     dcps::{
         actor::ActorAddress,
+        data_writer::offered_representation,
         domain_participant_actor::poll_timeout,
         domain_participant_actor_mail::{
             DomainParticipantMail, MessageServiceMail, WriterServiceMail,
         },
         listeners::data_writer_listener::DataWriterListenerActor,
-        status_condition_actor::StatusConditionActor,
+        status_condition_actor::{StatusConditionActor, StatusConditionMail},
     },
     infrastructure::{
         error::{DdsError, DdsResult},
         instance::InstanceHandle,
         qos::{DataWriterQos, QosKind},
+        qos_policy::{DataRepresentationId, QosPolicyId},
+        sample_info::SequenceNumber,
         status::{
             LivelinessLostStatus, OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus,
-            PublicationMatchedStatus, StatusKind,
+            PublicationMatchedStatus, PublicationThrottledStatus, StatusKind,
         },
-        time::{Duration, Time},
-        type_support::DdsSerialize,
+        time::{Duration, DurationKind, Time},
+        type_support::{DdsDeserialize, DdsSerialize},
     },
-    publication::data_writer_listener::DataWriterListener,
-    runtime::{ChannelSend, DdsRuntime, OneshotReceive},
+    publication::{
+        change_lifecycle::ChangeLifecycleObserver,
+        data_writer::{
+            DuplicateSuppressionMode, KeepLastReplacementPolicy, OriginalWriterInfo,
+            PublicationThrottling, SampleLoan,
+        },
+        data_writer_listener::DataWriterListener,
+    },
+    runtime::{ChannelSend, Clock, DdsRuntime, OneshotReceive, Timer},
+};
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU16, Ordering},
 };
-use alloc::{boxed::Box, string::String, vec::Vec};
-use core::marker::PhantomData;
+
+const BLOCKED_WRITE_RETRY_PERIOD: core::time::Duration = core::time::Duration::from_millis(1);
 
 /// Async version of [`DataWriter`](crate::publication::data_writer::DataWriter).
 pub struct DataWriterAsync<R: DdsRuntime, Foo> {
     handle: InstanceHandle,
+    // Representation offered by the writer which is used to encode the samples. It can only change
+    // while the writer is not enabled so it is kept here instead of being requested on each operation.
+    representation: Arc<AtomicU16>,
     status_condition_address: ActorAddress<R, StatusConditionActor<R>>,
     publisher: PublisherAsync<R>,
     topic: TopicAsync<R>,
-    phantom: PhantomData<Foo>,
+    // The handle does not hold any value of type Foo so it can be shared between threads
+    // independently of the type
+    phantom: PhantomData<fn() -> Foo>,
 }
 
 impl<R: DdsRuntime, Foo> Clone for DataWriterAsync<R, Foo> {
     fn clone(&self) -> Self {
         Self {
             handle: self.handle,
+            representation: self.representation.clone(),
             status_condition_address: self.status_condition_address.clone(),
             publisher: self.publisher.clone(),
             topic: self.topic.clone(),
@@ -53,12 +74,14 @@ impl<R: DdsRuntime, Foo> Clone for DataWriterAsync<R, Foo> {
 impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     pub(crate) fn new(
         handle: InstanceHandle,
+        representation: DataRepresentationId,
         status_condition_address: ActorAddress<R, StatusConditionActor<R>>,
         publisher: PublisherAsync<R>,
         topic: TopicAsync<R>,
     ) -> Self {
         Self {
             handle,
+            representation: Arc::new(AtomicU16::new(representation)),
             status_condition_address,
             publisher,
             topic,
@@ -70,9 +93,14 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
         self.publisher.participant_address()
     }
 
+    fn representation(&self) -> DataRepresentationId {
+        self.representation.load(Ordering::Relaxed)
+    }
+
     pub(crate) fn change_foo_type<T>(self) -> DataWriterAsync<R, T> {
         DataWriterAsync {
             handle: self.handle,
+            representation: self.representation,
             status_condition_address: self.status_condition_address,
             publisher: self.publisher,
             topic: self.topic,
@@ -98,13 +126,26 @@ where
     }
 
     /// Async version of [`register_instance_w_timestamp`](crate::publication::data_writer::DataWriter::register_instance_w_timestamp).
-    #[tracing::instrument(skip(self, _instance))]
+    #[tracing::instrument(skip(self, instance))]
     pub async fn register_instance_w_timestamp(
         &self,
-        _instance: &Foo,
-        timestamp: Time,
+        instance: &Foo,
+        _timestamp: Time,
     ) -> DdsResult<Option<InstanceHandle>> {
-        todo!()
+        // Registering an instance is not communicated to the readers so the timestamp is not used
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        let serialized_data = instance.serialize_data_with_representation(self.representation())?;
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::RegisterInstance {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    serialized_data,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`unregister_instance`](crate::publication::data_writer::DataWriter::unregister_instance).
@@ -132,13 +173,15 @@ where
         timestamp: Time,
     ) -> DdsResult<()> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
-        let serialized_data = instance.serialize_data()?;
+        let serialized_data = instance.serialize_data_with_representation(self.representation())?;
         self.participant_address()
             .send(DomainParticipantMail::Writer(
                 WriterServiceMail::UnregisterInstance {
+                    participant_address: self.participant_address().clone(),
                     publisher_handle: self.publisher.get_instance_handle().await,
                     data_writer_handle: self.handle,
                     serialized_data,
+                    handle,
                     timestamp,
                     reply_sender,
                 },
@@ -147,21 +190,11 @@ where
         reply_receiver.receive().await?
     }
 
-    /// Async version of [`get_key_value`](crate::publication::data_writer::DataWriter::get_key_value).
-    #[tracing::instrument(skip(self, _key_holder))]
-    pub async fn get_key_value(
-        &self,
-        _key_holder: &mut Foo,
-        _handle: InstanceHandle,
-    ) -> DdsResult<()> {
-        todo!()
-    }
-
     /// Async version of [`lookup_instance`](crate::publication::data_writer::DataWriter::lookup_instance).
     #[tracing::instrument(skip(self, instance))]
     pub async fn lookup_instance(&self, instance: &Foo) -> DdsResult<Option<InstanceHandle>> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
-        let serialized_data = instance.serialize_data()?;
+        let serialized_data = instance.serialize_data_with_representation(self.representation())?;
         self.participant_address()
             .send(DomainParticipantMail::Writer(
                 WriterServiceMail::LookupInstance {
@@ -177,7 +210,11 @@ where
 
     /// Async version of [`write`](crate::publication::data_writer::DataWriter::write).
     #[tracing::instrument(skip(self, data))]
-    pub async fn write(&self, data: &Foo, handle: Option<InstanceHandle>) -> DdsResult<()> {
+    pub async fn write(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<Option<SequenceNumber>> {
         let timestamp = self
             .get_publisher()
             .get_participant()
@@ -186,29 +223,149 @@ where
         self.write_w_timestamp(data, handle, timestamp).await
     }
 
-    /// Async version of [`write_w_timestamp`](crate::publication::data_writer::DataWriter::write_w_timestamp).
-    #[tracing::instrument(skip(self, data))]
-    pub async fn write_w_timestamp(
+    /// Async version of [`loan_sample`](crate::publication::data_writer::DataWriter::loan_sample).
+    #[tracing::instrument(skip(self))]
+    pub async fn loan_sample(&self) -> DdsResult<SampleLoan<Foo>>
+    where
+        Foo: Default + Send + 'static,
+    {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::LoanSample {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let value = match reply_receiver.receive().await?? {
+            Some(storage) => storage.downcast().unwrap_or_default(),
+            None => Box::default(),
+        };
+        Ok(SampleLoan::new(value))
+    }
+
+    /// Async version of [`write_loaned`](crate::publication::data_writer::DataWriter::write_loaned).
+    #[tracing::instrument(skip(self, sample))]
+    pub async fn write_loaned(
         &self,
-        data: &Foo,
+        sample: SampleLoan<Foo>,
         handle: Option<InstanceHandle>,
-        timestamp: Time,
-    ) -> DdsResult<()> {
+    ) -> DdsResult<Option<SequenceNumber>>
+    where
+        Foo: Send + 'static,
+    {
+        let result = self.write(&sample, handle).await;
+
         let (reply_sender, mut reply_receiver) = R::oneshot();
-        let serialized_data = data.serialize_data()?;
         self.participant_address()
             .send(DomainParticipantMail::Writer(
-                WriterServiceMail::WriteWTimestamp {
-                    participant_address: self.participant_address().clone(),
+                WriterServiceMail::ReturnLoanedSample {
                     publisher_handle: self.publisher.get_instance_handle().await,
                     data_writer_handle: self.handle,
-                    serialized_data,
-                    timestamp,
+                    sample: sample.into_storage(),
                     reply_sender,
                 },
             ))
             .await?;
-        reply_receiver.receive().await?
+        reply_receiver.receive().await??;
+
+        result
+    }
+
+    /// Async version of [`write_w_timestamp`](crate::publication::data_writer::DataWriter::write_w_timestamp).
+    #[tracing::instrument(skip(self, data))]
+    pub async fn write_w_timestamp(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        self.write_sample(data, handle, timestamp, None, Vec::new(), None)
+            .await
+    }
+
+    /// Async version of [`write_w_hash`](crate::publication::data_writer::DataWriter::write_w_hash).
+    #[tracing::instrument(skip(self, data))]
+    pub async fn write_w_hash(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        sample_hash: u64,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let timestamp = self
+            .get_publisher()
+            .get_participant()
+            .get_current_time()
+            .await?;
+        self.write_sample(data, handle, timestamp, Some(sample_hash), Vec::new(), None)
+            .await
+    }
+
+    /// Async version of [`write_directed`](crate::publication::data_writer::DataWriter::write_directed).
+    #[tracing::instrument(skip(self, data))]
+    pub async fn write_directed(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        reader_handle_list: &[InstanceHandle],
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let timestamp = self
+            .get_publisher()
+            .get_participant()
+            .get_current_time()
+            .await?;
+        self.write_sample(
+            data,
+            handle,
+            timestamp,
+            None,
+            reader_handle_list.to_vec(),
+            None,
+        )
+        .await
+    }
+
+    /// Async version of [`write_forwarded`](crate::publication::data_writer::DataWriter::write_forwarded).
+    #[tracing::instrument(skip(self, data))]
+    pub async fn write_forwarded(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+        original_writer_info: OriginalWriterInfo,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        self.write_sample(
+            data,
+            handle,
+            timestamp,
+            None,
+            Vec::new(),
+            Some(original_writer_info),
+        )
+        .await
+    }
+
+    async fn write_sample(
+        &self,
+        data: &Foo,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+        sample_hash: Option<u64>,
+        reader_handle_list: Vec<InstanceHandle>,
+        original_writer_info: Option<OriginalWriterInfo>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let representation = self.representation();
+        self.write_serialized_w_timestamp(
+            || data.serialize_data_with_representation(representation),
+            handle,
+            timestamp,
+            sample_hash,
+            reader_handle_list,
+            original_writer_info,
+        )
+        .await
     }
 
     /// Async version of [`dispose`](crate::publication::data_writer::DataWriter::dispose).
@@ -231,13 +388,15 @@ where
         timestamp: Time,
     ) -> DdsResult<()> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
-        let serialized_data = data.serialize_data()?;
+        let serialized_data = data.serialize_data_with_representation(self.representation())?;
         self.participant_address()
             .send(DomainParticipantMail::Writer(
                 WriterServiceMail::DisposeWTimestamp {
+                    participant_address: self.participant_address().clone(),
                     publisher_handle: self.publisher.get_instance_handle().await,
                     data_writer_handle: self.handle,
                     serialized_data,
+                    handle,
                     timestamp,
                     reply_sender,
                 },
@@ -247,10 +406,122 @@ where
     }
 }
 
+impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo>
+where
+    Foo: DdsSerialize + for<'de> DdsDeserialize<'de>,
+{
+    /// Async version of [`get_key_value`](crate::publication::data_writer::DataWriter::get_key_value).
+    #[tracing::instrument(skip(self, key_holder))]
+    pub async fn get_key_value(
+        &self,
+        key_holder: &mut Foo,
+        handle: InstanceHandle,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        let serialized_key_holder = key_holder.serialize_data()?;
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetKeyValue {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    serialized_key_holder,
+                    handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let serialized_key_holder = reply_receiver.receive().await??;
+        *key_holder = Foo::deserialize_data(&serialized_key_holder)?;
+        Ok(())
+    }
+}
+
 impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
+    /// Async version of [`write_serialized`](crate::publication::data_writer::DataWriter::write_serialized).
+    #[tracing::instrument(skip(self, serialized_data))]
+    pub async fn write_serialized(
+        &self,
+        serialized_data: &[u8],
+        handle: Option<InstanceHandle>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let timestamp = self
+            .get_publisher()
+            .get_participant()
+            .get_current_time()
+            .await?;
+        self.write_serialized_w_timestamp(
+            || Ok(serialized_data.to_vec()),
+            handle,
+            timestamp,
+            None,
+            Vec::new(),
+            None,
+        )
+        .await
+    }
+
+    // A write which finds the history full of samples not yet acknowledged is rejected
+    // by the participant with a timeout. It is retried here until the max blocking time
+    // elapses so that the participant keeps processing the acknowledgments meanwhile.
+    async fn write_serialized_w_timestamp(
+        &self,
+        serialize_data: impl Fn() -> DdsResult<Vec<u8>>,
+        handle: Option<InstanceHandle>,
+        timestamp: Time,
+        sample_hash: Option<u64>,
+        reader_handle_list: Vec<InstanceHandle>,
+        original_writer_info: Option<OriginalWriterInfo>,
+    ) -> DdsResult<Option<SequenceNumber>> {
+        let publisher_handle = self.publisher.get_instance_handle().await;
+        let mut blocking_start: Option<(Time, DurationKind)> = None;
+        loop {
+            let (reply_sender, mut reply_receiver) = R::oneshot();
+            self.participant_address()
+                .send(DomainParticipantMail::Writer(
+                    WriterServiceMail::WriteWTimestamp {
+                        participant_address: self.participant_address().clone(),
+                        publisher_handle,
+                        data_writer_handle: self.handle,
+                        serialized_data: serialize_data()?,
+                        handle,
+                        timestamp,
+                        sample_hash,
+                        reader_handle_list: reader_handle_list.clone(),
+                        original_writer_info,
+                        reply_sender,
+                    },
+                ))
+                .await?;
+            let result = reply_receiver.receive().await?;
+            if !matches!(result, Err(DdsError::Timeout)) {
+                return result;
+            }
+
+            let participant = self.publisher.get_participant();
+            let now = participant.clock_handle().now();
+            let (start_time, max_blocking_time) = match blocking_start {
+                Some(s) => s,
+                None => *blocking_start
+                    .insert((now, self.get_qos().await?.reliability.max_blocking_time)),
+            };
+            if let DurationKind::Finite(t) = max_blocking_time {
+                if now - start_time > t {
+                    return result;
+                }
+            }
+            participant
+                .timer_handle()
+                .clone()
+                .delay(BLOCKED_WRITE_RETRY_PERIOD)
+                .await;
+        }
+    }
+
     /// Async version of [`wait_for_acknowledgments`](crate::publication::data_writer::DataWriter::wait_for_acknowledgments).
     #[tracing::instrument(skip(self))]
     pub async fn wait_for_acknowledgments(&self, max_wait: Duration) -> DdsResult<()> {
+        // The samples held in a batch are only acknowledged once they are sent
+        self.flush().await?;
         let publisher_handle = self.get_publisher().get_instance_handle().await;
         let timer_handle = self
             .get_publisher()
@@ -291,10 +562,92 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
         .await?
     }
 
+    /// Async version of [`flush`](crate::publication::data_writer::DataWriter::flush).
+    #[tracing::instrument(skip(self))]
+    pub async fn flush(&self) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(WriterServiceMail::Flush {
+                participant_address: self.participant_address().clone(),
+                publisher_handle: self.publisher.get_instance_handle().await,
+                data_writer_handle: self.handle,
+                reply_sender,
+            }))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`drain`](crate::publication::data_writer::DataWriter::drain).
+    #[tracing::instrument(skip(self))]
+    pub async fn drain(&self, timeout: Duration) -> DdsResult<()> {
+        let participant = self.get_publisher().get_participant();
+        let start_time = participant.get_current_time().await?;
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::StartDraining {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await??;
+
+        self.wait_for_acknowledgments(timeout).await?;
+
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::UnregisterAllInstances {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await??;
+
+        // The acknowledgment of the unregistered instances is awaited for the rest of the timeout
+        let elapsed = participant.get_current_time().await? - start_time;
+        let remaining = if elapsed < timeout {
+            timeout - elapsed
+        } else {
+            Duration::new(0, 0)
+        };
+        self.wait_for_acknowledgments(remaining).await
+    }
+
     /// Async version of [`get_liveliness_lost_status`](crate::publication::data_writer::DataWriter::get_liveliness_lost_status).
     #[tracing::instrument(skip(self))]
     pub async fn get_liveliness_lost_status(&self) -> DdsResult<LivelinessLostStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetLivelinessLostStatus {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_publication_throttled_status`](crate::publication::data_writer::DataWriter::get_publication_throttled_status).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_publication_throttled_status(&self) -> DdsResult<PublicationThrottledStatus> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetPublicationThrottledStatus {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_offered_deadline_missed_status`](crate::publication::data_writer::DataWriter::get_offered_deadline_missed_status).
@@ -320,7 +673,17 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     pub async fn get_offered_incompatible_qos_status(
         &self,
     ) -> DdsResult<OfferedIncompatibleQosStatus> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetOfferedIncompatibleQosStatus {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_publication_matched_status`](crate::publication::data_writer::DataWriter::get_publication_matched_status).
@@ -354,7 +717,151 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     /// Async version of [`assert_liveliness`](crate::publication::data_writer::DataWriter::assert_liveliness).
     #[tracing::instrument(skip(self))]
     pub async fn assert_liveliness(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::AssertLiveliness {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_keep_last_replacement_policy`](crate::publication::data_writer::DataWriter::set_keep_last_replacement_policy).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_keep_last_replacement_policy(
+        &self,
+        policy: KeepLastReplacementPolicy,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::SetKeepLastReplacementPolicy {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    policy,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_duplicate_suppression_mode`](crate::publication::data_writer::DataWriter::set_duplicate_suppression_mode).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_duplicate_suppression_mode(
+        &self,
+        mode: DuplicateSuppressionMode,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::SetDuplicateSuppressionMode {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    mode,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_write_coalescing_window`](crate::publication::data_writer::DataWriter::set_write_coalescing_window).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_write_coalescing_window(&self, window: Option<Duration>) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::SetWriteCoalescingWindow {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    window,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_publication_throttling`](crate::publication::data_writer::DataWriter::set_publication_throttling).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_publication_throttling(
+        &self,
+        publication_throttling: Option<PublicationThrottling>,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::SetPublicationThrottling {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    publication_throttling,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_change_lifecycle_observer`](crate::publication::data_writer::DataWriter::set_change_lifecycle_observer).
+    #[tracing::instrument(skip(self, change_lifecycle_observer))]
+    pub async fn set_change_lifecycle_observer(
+        &self,
+        change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::SetChangeLifecycleObserver {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    change_lifecycle_observer,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_replaced_unacknowledged_sample_count`](crate::publication::data_writer::DataWriter::get_replaced_unacknowledged_sample_count).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_replaced_unacknowledged_sample_count(&self) -> DdsResult<u64> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetReplacedUnacknowledgedSampleCount {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`check_compatibility`](crate::publication::data_writer::DataWriter::check_compatibility).
+    #[tracing::instrument(skip(self))]
+    pub async fn check_compatibility(
+        &self,
+        subscription_builtin_topic_data: &SubscriptionBuiltinTopicData,
+    ) -> DdsResult<Vec<QosPolicyId>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::CheckCompatibility {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    subscription_builtin_topic_data: subscription_builtin_topic_data.clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_matched_subscription_data`](crate::publication::data_writer::DataWriter::get_matched_subscription_data).
@@ -377,6 +884,26 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
         reply_receiver.receive().await?
     }
 
+    /// Async version of [`get_matched_subscription_locators`](crate::publication::data_writer::DataWriter::get_matched_subscription_locators).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_matched_subscription_locators(
+        &self,
+        subscription_handle: InstanceHandle,
+    ) -> DdsResult<Vec<Locator>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Writer(
+                WriterServiceMail::GetMatchedSubscriptionLocators {
+                    publisher_handle: self.publisher.get_instance_handle().await,
+                    data_writer_handle: self.handle,
+                    subscription_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_matched_subscriptions`](crate::publication::data_writer::DataWriter::get_matched_subscriptions).
     #[tracing::instrument(skip(self))]
     pub async fn get_matched_subscriptions(&self) -> DdsResult<Vec<InstanceHandle>> {
@@ -409,7 +936,12 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
                 },
             ))
             .await?;
-        reply_receiver.receive().await?
+        reply_receiver.receive().await??;
+        self.representation.store(
+            offered_representation(&self.get_qos().await?),
+            Ordering::Relaxed,
+        );
+        Ok(())
     }
 
     /// Async version of [`get_qos`](crate::publication::data_writer::DataWriter::get_qos).
@@ -433,14 +965,20 @@ impl<R: DdsRuntime, Foo> DataWriterAsync<R, Foo> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
-            self.publisher.get_participant().clock_handle().clone(),
+            self.publisher.get_participant().instance_handle(),
+            self.handle,
+            self.publisher.get_participant().timer_handle().clone(),
         )
     }
 
     /// Async version of [`get_status_changes`](crate::publication::data_writer::DataWriter::get_status_changes).
     #[tracing::instrument(skip(self))]
     pub async fn get_status_changes(&self) -> DdsResult<Vec<StatusKind>> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.status_condition_address
+            .send_actor_mail(StatusConditionMail::GetStatusChanges { reply_sender })
+            .await?;
+        reply_receiver.receive().await
     }
 
     /// Async version of [`enable`](crate::publication::data_writer::DataWriter::enable).
@@ -11,8 +11,18 @@ use crate::{
             subscriber_listener::SubscriberListenerActor, topic_listener::TopicListenerActor,
         },
         status_condition_actor::StatusConditionActor,
+        task_monitor::TaskMonitor,
+    },
+    domain::{
+        bandwidth_statistics::{BandwidthQuota, BandwidthStatistics},
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
+        discovery_conformance::DiscoveryConformanceReport,
+        discovery_history::RemovedDiscoveredEntity,
+        domain_participant::EndpointAuthorizationHook,
+        domain_participant_listener::DomainParticipantListener,
+        memory_usage::MemoryUsageStatistics,
+        task_health::TaskHealthStatus,
     },
-    domain::domain_participant_listener::DomainParticipantListener,
     infrastructure::{
         domain::DomainId,
         error::{DdsError, DdsResult},
@@ -23,9 +33,10 @@ use crate::{
         type_support::TypeSupport,
     },
     publication::publisher_listener::PublisherListener,
-    runtime::{ChannelSend, DdsRuntime, OneshotReceive},
+    runtime::{ChannelSend, Clock, DdsRuntime, OneshotReceive},
     subscription::subscriber_listener::SubscriberListener,
     topic_definition::topic_listener::TopicListener,
+    transport::diagnostics,
     xtypes::dynamic_type::DynamicType,
 };
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
@@ -36,6 +47,7 @@ pub struct DomainParticipantAsync<R: DdsRuntime> {
     builtin_subscriber_status_condition_address: ActorAddress<R, StatusConditionActor<R>>,
     domain_id: DomainId,
     handle: InstanceHandle,
+    task_monitor: Arc<TaskMonitor>,
     spawner_handle: R::SpawnerHandle,
     clock_handle: R::ClockHandle,
     timer_handle: R::TimerHandle,
@@ -50,6 +62,7 @@ impl<R: DdsRuntime> Clone for DomainParticipantAsync<R> {
                 .clone(),
             domain_id: self.domain_id,
             handle: self.handle,
+            task_monitor: self.task_monitor.clone(),
             spawner_handle: self.spawner_handle.clone(),
             clock_handle: self.clock_handle.clone(),
             timer_handle: self.timer_handle.clone(),
@@ -58,11 +71,13 @@ impl<R: DdsRuntime> Clone for DomainParticipantAsync<R> {
 }
 
 impl<R: DdsRuntime> DomainParticipantAsync<R> {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         participant_address: R::ChannelSender<DomainParticipantMail<R>>,
         builtin_subscriber_status_condition_address: ActorAddress<R, StatusConditionActor<R>>,
         domain_id: DomainId,
         handle: InstanceHandle,
+        task_monitor: Arc<TaskMonitor>,
         spawner_handle: R::SpawnerHandle,
         clock_handle: R::ClockHandle,
         timer_handle: R::TimerHandle,
@@ -72,6 +87,7 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
             builtin_subscriber_status_condition_address,
             domain_id,
             handle,
+            task_monitor,
             spawner_handle,
             clock_handle,
             timer_handle,
@@ -82,6 +98,10 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
         &self.participant_address
     }
 
+    pub(crate) fn task_monitor(&self) -> &TaskMonitor {
+        &self.task_monitor
+    }
+
     pub(crate) fn spawner_handle(&self) -> &R::SpawnerHandle {
         &self.spawner_handle
     }
@@ -93,6 +113,10 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
     pub(crate) fn timer_handle(&self) -> &R::TimerHandle {
         &self.timer_handle
     }
+
+    pub(crate) fn instance_handle(&self) -> InstanceHandle {
+        self.handle
+    }
 }
 
 impl<R: DdsRuntime> DomainParticipantAsync<R> {
@@ -205,7 +229,7 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
             .await
     }
 
-    #[doc(hidden)]
+    /// Async version of [`create_dynamic_topic`](crate::domain::domain_participant::DomainParticipant::create_dynamic_topic).
     #[tracing::instrument(skip(self, a_listener, dynamic_type_representation))]
     pub async fn create_dynamic_topic(
         &self,
@@ -246,6 +270,45 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
         ))
     }
 
+    /// Async version of [`create_multitopic`](crate::domain::domain_participant::DomainParticipant::create_multitopic).
+    #[tracing::instrument(skip(self))]
+    pub async fn create_multitopic<Foo>(
+        &self,
+        name: &str,
+        type_name: &str,
+        subscription_expression: &str,
+        expression_parameters: &[String],
+    ) -> DdsResult<TopicAsync<R>>
+    where
+        Foo: TypeSupport,
+    {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        let status_condition = Actor::spawn(StatusConditionActor::default(), &self.spawner_handle);
+        let topic_status_condition_address = status_condition.address();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::CreateMultitopic {
+                    topic_name: String::from(name),
+                    type_name: String::from(type_name),
+                    subscription_expression: String::from(subscription_expression),
+                    expression_parameters: expression_parameters.to_vec(),
+                    status_condition,
+                    type_support: Arc::new(Foo::get_type()),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        let guid = reply_receiver.receive().await??;
+
+        Ok(TopicAsync::new(
+            guid,
+            topic_status_condition_address,
+            String::from(type_name),
+            String::from(name),
+            self.clone(),
+        ))
+    }
+
     /// Async version of [`delete_topic`](crate::domain::domain_participant::DomainParticipant::delete_topic).
     #[tracing::instrument(skip(self, a_topic))]
     pub async fn delete_topic(&self, a_topic: &TopicAsync<R>) -> DdsResult<()> {
@@ -426,7 +489,13 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
     /// Async version of [`assert_liveliness`](crate::domain::domain_participant::DomainParticipant::assert_liveliness).
     #[tracing::instrument(skip(self))]
     pub async fn assert_liveliness(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::AssertLiveliness { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`set_default_publisher_qos`](crate::domain::domain_participant::DomainParticipant::set_default_publisher_qos).
@@ -531,6 +600,123 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
         reply_receiver.receive().await?
     }
 
+    /// Async version of [`get_discovery_conformance_report`](crate::domain::domain_participant::DomainParticipant::get_discovery_conformance_report).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_discovery_conformance_report(
+        &self,
+        participant_handle: InstanceHandle,
+    ) -> DdsResult<DiscoveryConformanceReport> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::GetDiscoveryConformanceReport {
+                    participant_handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_removed_discovered_entities`](crate::domain::domain_participant::DomainParticipant::get_removed_discovered_entities).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_removed_discovered_entities(&self) -> DdsResult<Vec<RemovedDiscoveredEntity>> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::GetRemovedDiscoveredEntities { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_bandwidth_statistics`](crate::domain::domain_participant::DomainParticipant::get_bandwidth_statistics).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_bandwidth_statistics(&self) -> DdsResult<BandwidthStatistics> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::GetBandwidthStatistics { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_memory_usage_statistics`](crate::domain::domain_participant::DomainParticipant::get_memory_usage_statistics).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_memory_usage_statistics(&self) -> DdsResult<MemoryUsageStatistics> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::GetMemoryUsageStatistics { reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`get_task_health_status`](crate::domain::domain_participant::DomainParticipant::get_task_health_status).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_task_health_status(&self) -> DdsResult<TaskHealthStatus> {
+        Ok(self
+            .task_monitor
+            .task_health_status(self.clock_handle.now()))
+    }
+
+    /// Async version of [`set_diagnostic_verbosity`](crate::domain::domain_participant::DomainParticipant::set_diagnostic_verbosity).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_diagnostic_verbosity(
+        &self,
+        subsystem: DiagnosticSubsystem,
+        verbosity: DiagnosticVerbosity,
+    ) -> DdsResult<()> {
+        diagnostics::set_verbosity(subsystem, verbosity);
+        Ok(())
+    }
+
+    /// Async version of [`get_diagnostic_verbosity`](crate::domain::domain_participant::DomainParticipant::get_diagnostic_verbosity).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_diagnostic_verbosity(
+        &self,
+        subsystem: DiagnosticSubsystem,
+    ) -> DdsResult<DiagnosticVerbosity> {
+        Ok(diagnostics::get_verbosity(subsystem))
+    }
+
+    /// Async version of [`set_topic_bandwidth_quota`](crate::domain::domain_participant::DomainParticipant::set_topic_bandwidth_quota).
+    #[tracing::instrument(skip(self))]
+    pub async fn set_topic_bandwidth_quota(
+        &self,
+        topic_name: &str,
+        bandwidth_quota: Option<BandwidthQuota>,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::SetTopicBandwidthQuota {
+                    topic_name: String::from(topic_name),
+                    bandwidth_quota,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`set_endpoint_authorization_hook`](crate::domain::domain_participant::DomainParticipant::set_endpoint_authorization_hook).
+    #[tracing::instrument(skip(self, hook))]
+    pub async fn set_endpoint_authorization_hook(
+        &self,
+        hook: Option<Arc<dyn EndpointAuthorizationHook>>,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address
+            .send(DomainParticipantMail::Participant(
+                ParticipantServiceMail::SetEndpointAuthorizationHook { hook, reply_sender },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
     /// Async version of [`get_discovered_topics`](crate::domain::domain_participant::DomainParticipant::get_discovered_topics).
     #[tracing::instrument(skip(self))]
     pub async fn get_discovered_topics(&self) -> DdsResult<Vec<InstanceHandle>> {
@@ -620,6 +806,7 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
                 ParticipantServiceMail::SetListener {
                     listener_sender,
                     status_kind: mask.to_vec(),
+                    participant_address: self.participant_address.clone(),
                     reply_sender,
                 },
             ))
@@ -639,7 +826,10 @@ impl<R: DdsRuntime> DomainParticipantAsync<R> {
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address
             .send(DomainParticipantMail::Participant(
-                ParticipantServiceMail::Enable { reply_sender },
+                ParticipantServiceMail::Enable {
+                    participant_address: self.participant_address.clone(),
+                    reply_sender,
+                },
             ))
             .await?;
         reply_receiver.receive().await?
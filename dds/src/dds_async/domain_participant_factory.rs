@@ -61,14 +61,19 @@ impl<R: DdsRuntime> DomainParticipantFactoryAsync<R> {
             })
             .await;
 
-        let (participant_address, participant_handle, builtin_subscriber_status_condition_address) =
-            reply_receiver.receive().await??;
+        let (
+            participant_address,
+            participant_handle,
+            builtin_subscriber_status_condition_address,
+            task_monitor,
+        ) = reply_receiver.receive().await??;
 
         let domain_participant = DomainParticipantAsync::new(
             participant_address.clone(),
             builtin_subscriber_status_condition_address,
             domain_id,
             participant_handle,
+            task_monitor,
             spawner_handle,
             clock_handle,
             timer_handle,
@@ -107,6 +112,7 @@ impl<R: DdsRuntime> DomainParticipantFactoryAsync<R> {
                 ))
                 .await
                 .ok();
+            participant.task_monitor().stop();
             Ok(())
         } else {
             Err(DdsError::PreconditionNotMet(String::from(
@@ -191,6 +197,22 @@ impl<R: DdsRuntime> DomainParticipantFactoryAsync<R> {
             .await;
         Ok(())
     }
+
+    /// Async version of [`set_configuration_from_file`](crate::domain::domain_participant_factory::DomainParticipantFactory::set_configuration_from_file).
+    #[cfg(feature = "std")]
+    pub async fn set_configuration_from_file(
+        &self,
+        configuration_file: &crate::configuration_file::ConfigurationFile,
+    ) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.domain_participant_factory_actor
+            .send_actor_mail(DomainParticipantFactoryMail::ApplyConfigurationFile {
+                configuration_file: configuration_file.clone(),
+                reply_sender,
+            })
+            .await;
+        reply_receiver.receive().await?
+    }
 }
 
 impl<R: DdsRuntime> DomainParticipantFactoryAsync<R> {
@@ -216,6 +238,8 @@ impl<R: DdsRuntime> DomainParticipantFactoryAsync<R> {
 impl DomainParticipantFactoryAsync<crate::std_runtime::StdRuntime> {
     /// This operation returns the [`DomainParticipantFactoryAsync`] singleton. The operation is idempotent, that is, it can be called multiple
     /// times without side-effects and it will return the same [`DomainParticipantFactoryAsync`] instance.
+    /// The settings of the [`ConfigurationFile`](crate::configuration_file::ConfigurationFile) given in the environment
+    /// are applied when the singleton is created.
     #[tracing::instrument]
     pub fn get_instance() -> &'static DomainParticipantFactoryAsync<crate::std_runtime::StdRuntime>
     {
@@ -252,12 +276,27 @@ impl DomainParticipantFactoryAsync<crate::std_runtime::StdRuntime> {
 
             let app_id = std::process::id().to_ne_bytes();
             let transport = crate::rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactory::default();
-            DomainParticipantFactoryAsync::new(
+            let mut domain_participant_factory_actor =
+                DomainParticipantFactoryActor::new(app_id, host_id, Box::new(transport));
+
+            // The deployment settings are applied before any other operation can reach the factory
+            match crate::configuration_file::ConfigurationFile::from_environment() {
+                Ok(configuration_file) => {
+                    if let Err(e) =
+                        domain_participant_factory_actor.apply_configuration_file(&configuration_file)
+                    {
+                        warn!("Failed to apply the configuration file settings: {:?}", e);
+                    }
+                }
+                Err(e) => warn!("Failed to read the configuration file settings: {:?}", e),
+            }
+
+            let domain_participant_factory_actor =
+                Actor::spawn(domain_participant_factory_actor, &runtime.spawner());
+            DomainParticipantFactoryAsync {
                 runtime,
-                app_id,
-                host_id,
-                Box::new(transport),
-            )
+                domain_participant_factory_actor,
+            }
         })
     }
 }
@@ -23,7 +23,7 @@ use crate::{
     },
     runtime::{ChannelSend, DdsRuntime, OneshotReceive},
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 /// Async version of [`Publisher`](crate::publication::publisher::Publisher).
 pub struct PublisherAsync<R: DdsRuntime> {
@@ -62,6 +62,26 @@ impl<R: DdsRuntime> PublisherAsync<R> {
         qos: QosKind<DataWriterQos>,
         a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
         mask: &[StatusKind],
+    ) -> DdsResult<DataWriterAsync<R, Foo>> {
+        self.create_datawriter_with_executor(
+            a_topic,
+            qos,
+            a_listener,
+            mask,
+            self.participant.spawner_handle(),
+        )
+        .await
+    }
+
+    /// Async version of [`create_datawriter_with_executor`](crate::publication::publisher::Publisher::create_datawriter_with_executor).
+    #[tracing::instrument(skip(self, a_topic, a_listener, listener_executor))]
+    pub async fn create_datawriter_with_executor<Foo>(
+        &self,
+        a_topic: &TopicAsync<R>,
+        qos: QosKind<DataWriterQos>,
+        a_listener: Option<impl DataWriterListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+        listener_executor: &R::SpawnerHandle,
     ) -> DdsResult<DataWriterAsync<R, Foo>> {
         let topic_name = a_topic.get_name();
         let status_condition = Actor::spawn(
@@ -69,8 +89,8 @@ impl<R: DdsRuntime> PublisherAsync<R> {
             self.participant.spawner_handle(),
         );
         let writer_status_condition_address = status_condition.address();
-        let listener_sender = a_listener
-            .map(|l| DataWriterListenerActor::spawn(l, self.participant.spawner_handle()));
+        let listener_sender =
+            a_listener.map(|l| DataWriterListenerActor::spawn(l, listener_executor));
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DomainParticipantMail::Publisher(
@@ -86,10 +106,11 @@ impl<R: DdsRuntime> PublisherAsync<R> {
                 },
             ))
             .await?;
-        let guid = reply_receiver.receive().await??;
+        let (guid, representation) = reply_receiver.receive().await??;
 
         Ok(DataWriterAsync::new(
             guid,
+            representation,
             writer_status_condition_address,
             self.clone(),
             a_topic.clone(),
@@ -136,16 +157,66 @@ impl<R: DdsRuntime> PublisherAsync<R> {
         todo!()
     }
 
-    /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::begin_coherent_changes).
+    /// Async version of [`begin_coherent_changes`](crate::publication::publisher::Publisher::begin_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn begin_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::BeginCoherentChanges {
+                    publisher_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
-    /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::end_coherent_changes).
+    /// Async version of [`end_coherent_changes`](crate::publication::publisher::Publisher::end_coherent_changes).
     #[tracing::instrument(skip(self))]
     pub async fn end_coherent_changes(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::EndCoherentChanges {
+                    publisher_handle: self.handle,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`begin_partition_session`](crate::publication::publisher::Publisher::begin_partition_session).
+    #[tracing::instrument(skip(self))]
+    pub async fn begin_partition_session(&self, partition_list: Vec<String>) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::BeginPartitionSession {
+                    publisher_handle: self.handle,
+                    partition_list,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
+    }
+
+    /// Async version of [`end_partition_session`](crate::publication::publisher::Publisher::end_partition_session).
+    #[tracing::instrument(skip(self))]
+    pub async fn end_partition_session(&self) -> DdsResult<()> {
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::EndPartitionSession {
+                    publisher_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`delete_datawriter`](crate::publication::publisher::Publisher::wait_for_acknowledgments).
@@ -163,7 +234,16 @@ impl<R: DdsRuntime> PublisherAsync<R> {
     /// Async version of [`delete_contained_entities`](crate::publication::publisher::Publisher::delete_contained_entities).
     #[tracing::instrument(skip(self))]
     pub async fn delete_contained_entities(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::DeleteContainedEntities {
+                    publisher_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`set_default_datawriter_qos`](crate::publication::publisher::Publisher::set_default_datawriter_qos).
@@ -272,7 +352,17 @@ impl<R: DdsRuntime> PublisherAsync<R> {
     /// Async version of [`enable`](crate::publication::publisher::Publisher::enable).
     #[tracing::instrument(skip(self))]
     pub async fn enable(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Publisher(
+                PublisherServiceMail::Enable {
+                    publisher_handle: self.handle,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_instance_handle`](crate::publication::publisher::Publisher::get_instance_handle).
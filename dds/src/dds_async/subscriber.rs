@@ -3,12 +3,18 @@ use super::{
     domain_participant::DomainParticipantAsync, topic::TopicAsync,
 };
 use crate::{
+    builtin_topics::PublicationBuiltinTopicData,
     dcps::{
         actor::{Actor, ActorAddress},
+        builtin_topics::DCPS_PUBLICATION,
         domain_participant_actor_mail::{DomainParticipantMail, SubscriberServiceMail},
         listeners::{
             data_reader_listener::DataReaderListenerActor,
             subscriber_listener::SubscriberListenerActor,
+            wildcard_subscription_listener::{
+                WildcardSubscriptionListenerActor, WildcardSubscriptionMail,
+                WildcardSubscriptionPublicationsListener,
+            },
         },
         status_condition_actor::StatusConditionActor,
     },
@@ -21,6 +27,7 @@ use crate::{
     runtime::{ChannelSend, DdsRuntime, OneshotReceive},
     subscription::{
         data_reader_listener::DataReaderListener, subscriber_listener::SubscriberListener,
+        wildcard_subscription_listener::WildcardSubscriptionListener,
     },
 };
 use alloc::{string::String, vec::Vec};
@@ -69,14 +76,34 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
         qos: QosKind<DataReaderQos>,
         a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
         mask: &[StatusKind],
+    ) -> DdsResult<DataReaderAsync<R, Foo>> {
+        self.create_datareader_with_executor(
+            a_topic,
+            qos,
+            a_listener,
+            mask,
+            self.participant.spawner_handle(),
+        )
+        .await
+    }
+
+    /// Async version of [`create_datareader_with_executor`](crate::subscription::subscriber::Subscriber::create_datareader_with_executor).
+    #[tracing::instrument(skip(self, a_topic, a_listener, listener_executor))]
+    pub async fn create_datareader_with_executor<Foo>(
+        &self,
+        a_topic: &TopicAsync<R>,
+        qos: QosKind<DataReaderQos>,
+        a_listener: Option<impl DataReaderListener<R, Foo> + Send + 'static>,
+        mask: &[StatusKind],
+        listener_executor: &R::SpawnerHandle,
     ) -> DdsResult<DataReaderAsync<R, Foo>> {
         let status_condition = Actor::spawn(
             StatusConditionActor::default(),
             self.participant.spawner_handle(),
         );
         let reader_status_condition_address = status_condition.address();
-        let listener_sender = a_listener
-            .map(|l| DataReaderListenerActor::spawn(l, self.participant.spawner_handle()));
+        let listener_sender =
+            a_listener.map(|l| DataReaderListenerActor::spawn(l, listener_executor));
         let (reply_sender, mut reply_receiver) = R::oneshot();
         self.participant_address()
             .send(DomainParticipantMail::Subscriber(
@@ -155,6 +182,49 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
         }
     }
 
+    /// Async version of [`create_wildcard_subscription`](crate::subscription::subscriber::Subscriber::create_wildcard_subscription).
+    #[tracing::instrument(skip(self, a_listener))]
+    pub async fn create_wildcard_subscription(
+        &self,
+        topic_name_pattern: &str,
+        qos: QosKind<DataReaderQos>,
+        a_listener: impl WildcardSubscriptionListener<R> + Send + 'static,
+    ) -> DdsResult<()> {
+        let reader_qos = match qos {
+            QosKind::Default => self.get_default_datareader_qos().await?,
+            QosKind::Specific(q) => q,
+        };
+        let Some(publications_reader) = self
+            .participant
+            .get_builtin_subscriber()
+            .lookup_datareader::<PublicationBuiltinTopicData>(DCPS_PUBLICATION)
+            .await?
+        else {
+            return Err(DdsError::AlreadyDeleted);
+        };
+        let mail_sender = WildcardSubscriptionListenerActor::spawn(
+            a_listener,
+            self.clone(),
+            String::from(topic_name_pattern),
+            reader_qos,
+            self.participant.spawner_handle(),
+        );
+        publications_reader
+            .set_listener(
+                Some(WildcardSubscriptionPublicationsListener {
+                    mail_sender: mail_sender.clone(),
+                }),
+                &[StatusKind::DataAvailable],
+            )
+            .await?;
+        // The publications discovered before the listener was set are not notified to it
+        mail_sender
+            .send(WildcardSubscriptionMail::PublicationsAvailable {
+                the_reader: publications_reader,
+            })
+            .await
+    }
+
     /// Async version of [`notify_datareaders`](crate::subscription::subscriber::Subscriber::notify_datareaders).
     #[tracing::instrument(skip(self))]
     pub async fn notify_datareaders(&self) -> DdsResult<()> {
@@ -176,7 +246,16 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     /// Async version of [`delete_contained_entities`](crate::subscription::subscriber::Subscriber::delete_contained_entities).
     #[tracing::instrument(skip(self))]
     pub async fn delete_contained_entities(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Subscriber(
+                SubscriberServiceMail::DeleteContainedEntities {
+                    subscriber_handle: self.handle,
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`set_default_datareader_qos`](crate::subscription::subscriber::Subscriber::set_default_datareader_qos).
@@ -279,7 +358,9 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
-            self.participant.clock_handle().clone(),
+            self.participant.instance_handle(),
+            self.handle,
+            self.participant.timer_handle().clone(),
         )
     }
 
@@ -292,7 +373,17 @@ impl<R: DdsRuntime> SubscriberAsync<R> {
     /// Async version of [`enable`](crate::subscription::subscriber::Subscriber::enable).
     #[tracing::instrument(skip(self))]
     pub async fn enable(&self) -> DdsResult<()> {
-        todo!()
+        let (reply_sender, mut reply_receiver) = R::oneshot();
+        self.participant_address()
+            .send(DomainParticipantMail::Subscriber(
+                SubscriberServiceMail::Enable {
+                    subscriber_handle: self.handle,
+                    participant_address: self.participant_address().clone(),
+                    reply_sender,
+                },
+            ))
+            .await?;
+        reply_receiver.receive().await?
     }
 
     /// Async version of [`get_instance_handle`](crate::subscription::subscriber::Subscriber::get_instance_handle).
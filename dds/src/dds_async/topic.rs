@@ -131,7 +131,9 @@ impl<R: DdsRuntime> TopicAsync<R> {
     pub fn get_statuscondition(&self) -> StatusConditionAsync<R> {
         StatusConditionAsync::new(
             self.status_condition_address.clone(),
-            self.participant.clock_handle().clone(),
+            self.participant.instance_handle(),
+            self.handle,
+            self.participant.timer_handle().clone(),
         )
     }
 
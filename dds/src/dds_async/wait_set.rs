@@ -1,23 +1,47 @@
-use super::condition::StatusConditionAsync;
+use super::condition::{
+    GuardConditionAsync, QueryConditionAsync, ReadConditionAsync, StatusConditionAsync,
+};
 use crate::{
-    runtime::{Clock, DdsRuntime},
     infrastructure::{
         error::{DdsError, DdsResult},
         time::Duration,
     },
+    runtime::{ChannelReceive, DdsRuntime, Timer},
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    sync::atomic::{AtomicUsize, Ordering},
+    task::Poll,
 };
-use alloc::{string::String, vec::Vec};
+
+// Identifies the wait operations registered on the status conditions
+static WAITER_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn new_waiter_id() -> usize {
+    WAITER_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+}
 
 /// Async version of [`Condition`](crate::infrastructure::wait_set::Condition).
 pub enum ConditionAsync<R: DdsRuntime> {
     /// Status condition variant
     StatusCondition(StatusConditionAsync<R>),
+    /// Guard condition variant
+    GuardCondition(GuardConditionAsync),
+    /// Read condition variant
+    ReadCondition(ReadConditionAsync<R>),
+    /// Query condition variant
+    QueryCondition(QueryConditionAsync<R>),
 }
 
 impl<R: DdsRuntime> Clone for ConditionAsync<R> {
     fn clone(&self) -> Self {
         match self {
             Self::StatusCondition(arg0) => Self::StatusCondition(arg0.clone()),
+            Self::GuardCondition(arg0) => Self::GuardCondition(arg0.clone()),
+            Self::ReadCondition(arg0) => Self::ReadCondition(arg0.clone()),
+            Self::QueryCondition(arg0) => Self::QueryCondition(arg0.clone()),
         }
     }
 }
@@ -28,6 +52,33 @@ impl<R: DdsRuntime> ConditionAsync<R> {
     pub async fn get_trigger_value(&self) -> DdsResult<bool> {
         match self {
             ConditionAsync::StatusCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::GuardCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::ReadCondition(c) => c.get_trigger_value().await,
+            ConditionAsync::QueryCondition(c) => c.get_trigger_value().await,
+        }
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ConditionAsync::StatusCondition(a), ConditionAsync::StatusCondition(b)) => {
+                a.is_same(b)
+            }
+            (ConditionAsync::GuardCondition(a), ConditionAsync::GuardCondition(b)) => a.is_same(b),
+            (ConditionAsync::ReadCondition(a), ConditionAsync::ReadCondition(b)) => a.is_same(b),
+            (ConditionAsync::QueryCondition(a), ConditionAsync::QueryCondition(b)) => {
+                a.read_condition().is_same(b.read_condition())
+            }
+            _ => false,
+        }
+    }
+
+    // The status condition which notifies the changes of the trigger value of this condition
+    fn status_condition(&self) -> Option<&StatusConditionAsync<R>> {
+        match self {
+            ConditionAsync::StatusCondition(c) => Some(c),
+            ConditionAsync::GuardCondition(_) => None,
+            ConditionAsync::ReadCondition(c) => Some(c.reader_status_condition()),
+            ConditionAsync::QueryCondition(c) => Some(c.read_condition().reader_status_condition()),
         }
     }
 }
@@ -61,42 +112,98 @@ impl<R: DdsRuntime> WaitSetAsync<R> {
             )));
         };
 
-        let clock_handle = match &self.conditions[0] {
-            ConditionAsync::StatusCondition(c) => c.clock_handle().clone(),
+        // The timeout is measured with the timer of the entities to which the conditions are
+        // associated. Guard conditions are not associated with any entity so the system timer is
+        // used when only guard conditions are attached.
+        let timer_handle = self
+            .conditions
+            .iter()
+            .find_map(|c| c.status_condition())
+            .map(|c| c.timer_handle().clone());
+        let mut timeout_delay: Pin<Box<dyn Future<Output = ()> + Send>> = match timer_handle {
+            Some(mut timer_handle) => {
+                Box::pin(async move { timer_handle.delay(timeout.into()).await })
+            }
+            None => system_delay(timeout).ok_or_else(|| {
+                DdsError::PreconditionNotMet(String::from(
+                    "WaitSet with only guard conditions attached can not wait for a timeout without a system timer",
+                ))
+            })?,
         };
-        let start = clock_handle.now();
-        while clock_handle.now() - start < timeout {
-            let mut finished = false;
+
+        let waiter_id = new_waiter_id();
+        let (waiter_sender, mut waiter_receiver) = R::channel();
+        let result = loop {
+            // The waiter is registered before evaluating the trigger values so that
+            // no change happening in between is missed
+            for status_condition in self.conditions.iter().filter_map(|c| c.status_condition()) {
+                status_condition
+                    .add_trigger_waiter(waiter_id, waiter_sender.clone())
+                    .await?;
+            }
+
             let mut trigger_conditions = Vec::new();
             for condition in &self.conditions {
                 if condition.get_trigger_value().await? {
                     trigger_conditions.push(condition.clone());
-                    finished = true;
                 }
             }
 
-            if finished {
-                return Ok(trigger_conditions);
+            if !trigger_conditions.is_empty() {
+                break Ok(trigger_conditions);
             }
-            // timer_handle
-            //     .sleep(std::time::Duration::from_millis(20))
-            //     .await;
+
+            let mut notification = pin!(waiter_receiver.receive());
+            let is_timeout = poll_fn(|cx| {
+                if notification.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(false);
+                }
+                for condition in &self.conditions {
+                    if let ConditionAsync::GuardCondition(c) = condition {
+                        c.register_waker(cx.waker());
+                        if c.load_trigger_value() {
+                            return Poll::Ready(false);
+                        }
+                    }
+                }
+                if timeout_delay.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(true);
+                }
+                Poll::Pending
+            })
+            .await;
+
+            if is_timeout {
+                break Err(DdsError::Timeout);
+            }
+        };
+
+        for status_condition in self.conditions.iter().filter_map(|c| c.status_condition()) {
+            status_condition.remove_trigger_waiter(waiter_id).await.ok();
         }
 
-        Err(DdsError::Timeout)
+        result
     }
 
     /// Async version of [`attach_condition`](crate::infrastructure::wait_set::WaitSet::attach_condition).
     #[tracing::instrument(skip(self, cond))]
     pub async fn attach_condition(&mut self, cond: ConditionAsync<R>) -> DdsResult<()> {
-        self.conditions.push(cond);
+        if !self.conditions.iter().any(|c| c.is_same(&cond)) {
+            self.conditions.push(cond);
+        }
         Ok(())
     }
 
     /// Async version of [`detach_condition`](crate::infrastructure::wait_set::WaitSet::detach_condition).
-    #[tracing::instrument(skip(self, _cond))]
-    pub async fn detach_condition(&self, _cond: ConditionAsync<R>) -> DdsResult<()> {
-        todo!()
+    #[tracing::instrument(skip(self, cond))]
+    pub async fn detach_condition(&mut self, cond: ConditionAsync<R>) -> DdsResult<()> {
+        let Some(index) = self.conditions.iter().position(|c| c.is_same(&cond)) else {
+            return Err(DdsError::PreconditionNotMet(String::from(
+                "Condition is not attached to the WaitSet",
+            )));
+        };
+        self.conditions.remove(index);
+        Ok(())
     }
 
     /// Async version of [`get_conditions`](crate::infrastructure::wait_set::WaitSet::get_conditions).
@@ -105,3 +212,18 @@ impl<R: DdsRuntime> WaitSetAsync<R> {
         Ok(self.conditions.clone())
     }
 }
+
+#[cfg(feature = "std")]
+fn system_delay(timeout: Duration) -> Option<Pin<Box<dyn Future<Output = ()> + Send>>> {
+    static SYSTEM_TIMER: std::sync::OnceLock<crate::std_runtime::timer::TimerDriver> =
+        std::sync::OnceLock::new();
+    let mut timer_handle = SYSTEM_TIMER.get_or_init(Default::default).handle();
+    Some(Box::pin(
+        async move { timer_handle.delay(timeout.into()).await },
+    ))
+}
+
+#[cfg(not(feature = "std"))]
+fn system_delay(_timeout: Duration) -> Option<Pin<Box<dyn Future<Output = ()> + Send>>> {
+    None
+}
@@ -34,6 +34,10 @@ pub mod rtps_messages;
 #[doc(hidden)]
 pub mod rtps_udp_transport;
 
+/// Contains test vectors of the reliable RTPS protocol to validate transport implementations and ports.
+#[cfg(any(test, feature = "rtps_conformance"))]
+pub mod rtps_conformance;
+
 #[cfg(feature = "transport")]
 #[doc(hidden)]
 /// Contains the Dust DDS transport interface definition.
@@ -1,13 +1,14 @@
 use crate::{
     rtps_messages::{
         self,
+        overall_structure::{Read, TryReadFromBytes, WriteIntoBytes},
         submessage_elements::{Parameter, ParameterList},
         submessages::data::DataSubmessage,
         types::ParameterId,
     },
     transport::{
-        history_cache::CacheChange,
-        types::{ChangeKind, EntityId, Guid, GuidPrefix},
+        history_cache::{CacheChange, OriginalWriterInfo},
+        types::{ChangeKind, EntityId, Guid, GuidPrefix, SequenceNumber},
     },
 };
 
@@ -19,6 +20,9 @@ use crate::xtypes::{deserialize::XTypesDeserialize, serialize::XTypesSerialize};
 
 pub const PID_KEY_HASH: ParameterId = 0x0070;
 pub const PID_STATUS_INFO: ParameterId = 0x0071;
+pub const PID_COHERENT_SET: ParameterId = 0x0056;
+pub const PID_DIRECTED_WRITE: ParameterId = 0x0057;
+pub const PID_ORIGINAL_WRITER_INFO: ParameterId = 0x0061;
 
 #[derive(Clone, Copy, PartialEq, Eq, XTypesSerialize, XTypesDeserialize, Debug)]
 struct StatusInfo(pub [u8; 4]);
@@ -36,7 +40,7 @@ impl CacheChange {
             | ChangeKind::NotAliveDisposedUnregistered => (false, true),
         };
 
-        let mut parameters = Vec::with_capacity(2);
+        let mut parameters = Vec::with_capacity(3);
         match self.kind {
             ChangeKind::Alive | ChangeKind::AliveFiltered => (),
             ChangeKind::NotAliveDisposed => parameters.push(Parameter::new(
@@ -56,6 +60,30 @@ impl CacheChange {
         if let Some(i) = self.instance_handle {
             parameters.push(Parameter::new(PID_KEY_HASH, Arc::from(i)));
         }
+        if let Some(coherent_set) = self.coherent_set {
+            let mut value = Vec::new();
+            coherent_set.write_into_bytes(&mut value);
+            parameters.push(Parameter::new(PID_COHERENT_SET, Arc::from(value)));
+        }
+        if !self.directed_write.is_empty() {
+            let mut value = Vec::new();
+            (self.directed_write.len() as u32).write_into_bytes(&mut value);
+            for reader_guid in &self.directed_write {
+                <[u8; 16]>::from(*reader_guid).write_into_bytes(&mut value);
+            }
+            parameters.push(Parameter::new(PID_DIRECTED_WRITE, Arc::from(value)));
+        }
+        if let Some(original_writer_info) = self.original_writer_info {
+            let mut value = Vec::new();
+            <[u8; 16]>::from(original_writer_info.original_writer_guid)
+                .write_into_bytes(&mut value);
+            original_writer_info
+                .original_writer_sn
+                .write_into_bytes(&mut value);
+            // The QoS of the original writer which differ from the ones of the forwarding writer
+            ParameterList::new(Vec::new()).write_into_bytes(&mut value);
+            parameters.push(Parameter::new(PID_ORIGINAL_WRITER_INFO, Arc::from(value)));
+        }
         let parameter_list = ParameterList::new(parameters);
 
         DataSubmessage::new(
@@ -113,13 +141,105 @@ impl CacheChange {
             None => None,
         };
 
+        let endianness = data_submessage.endianness();
+
+        // The coherent set is identified by the sequence number of its first change (RTPS 8.7.5)
+        let coherent_set = data_submessage
+            .inline_qos()
+            .parameter()
+            .iter()
+            .find(|&x| x.parameter_id() == PID_COHERENT_SET)
+            .and_then(|p| SequenceNumber::try_read_from_bytes(&mut p.value(), endianness).ok());
+
+        // The directed write is a sequence of the GUIDs of the readers to which the change is addressed
+        let directed_write = match data_submessage
+            .inline_qos()
+            .parameter()
+            .iter()
+            .find(|&x| x.parameter_id() == PID_DIRECTED_WRITE)
+        {
+            Some(p) => {
+                let mut value = p.value();
+                let length = u32::try_read_from_bytes(&mut value, endianness)
+                    .map_err(|_| RtpsError::InvalidData)?;
+                let mut directed_write = Vec::new();
+                for _ in 0..length {
+                    let mut reader_guid = [0; 16];
+                    value
+                        .read_exact(&mut reader_guid)
+                        .map_err(|_| RtpsError::InvalidData)?;
+                    directed_write.push(Guid::from(reader_guid));
+                }
+                directed_write
+            }
+            None => Vec::new(),
+        };
+
+        // The QoS of the original writer following its GUID and sequence number are not used
+        let original_writer_info = match data_submessage
+            .inline_qos()
+            .parameter()
+            .iter()
+            .find(|&x| x.parameter_id() == PID_ORIGINAL_WRITER_INFO)
+        {
+            Some(p) => {
+                let mut value = p.value();
+                let mut original_writer_guid = [0; 16];
+                value
+                    .read_exact(&mut original_writer_guid)
+                    .map_err(|_| RtpsError::InvalidData)?;
+                let original_writer_sn =
+                    SequenceNumber::try_read_from_bytes(&mut value, endianness)
+                        .map_err(|_| RtpsError::InvalidData)?;
+                Some(OriginalWriterInfo {
+                    original_writer_guid: Guid::from(original_writer_guid),
+                    original_writer_sn,
+                })
+            }
+            None => None,
+        };
+
         Ok(CacheChange {
             kind,
             writer_guid: Guid::new(source_guid_prefix, data_submessage.writer_id()),
             source_timestamp: source_timestamp.map(Into::into),
             instance_handle,
+            coherent_set,
+            directed_write,
+            original_writer_info,
             sequence_number: data_submessage.writer_sn(),
             data_value: data_submessage.serialized_payload().clone().into(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtps_messages::overall_structure::SubmessageHeaderRead;
+
+    #[test]
+    fn coherent_set_is_read_with_the_endianness_of_the_submessage() {
+        #[rustfmt::skip]
+        let mut data = &[
+            0x15, 0b_0000_0010, 0, 36, // Submessage header (big endian)
+            0, 0, 0, 16, // extraFlags, octetsToInlineQos
+            1, 2, 3, 4, // readerId: value[4]
+            6, 7, 8, 9, // writerId: value[4]
+            0, 0, 0, 0, // writerSN: high
+            0, 0, 0, 5, // writerSN: low
+            0x00, 0x56, 0, 8, // inlineQos: PID_COHERENT_SET, length
+            0, 0, 0, 0, // inlineQos: coherent set SN: high
+            0, 0, 0, 3, // inlineQos: coherent set SN: low
+            0, 1, 0, 0, // inlineQos: Sentinel
+        ][..];
+        let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
+        let data_submessage = DataSubmessage::try_from_bytes(&submessage_header, data).unwrap();
+
+        let change =
+            CacheChange::try_from_data_submessage(&data_submessage, [1; 12], None).unwrap();
+
+        assert_eq!(change.sequence_number, 5);
+        assert_eq!(change.coherent_set, Some(3));
+    }
+}
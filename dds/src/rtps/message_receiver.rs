@@ -38,11 +38,24 @@ impl<'a> Iterator for MessageReceiver<'a> {
                 RtpsSubmessageReadKind::InfoDestination(m) => {
                     self.dest_guid_prefix = m.guid_prefix();
                 }
-                RtpsSubmessageReadKind::InfoReply(_) => todo!(),
+                RtpsSubmessageReadKind::InfoReply(m) => {
+                    self._unicast_reply_locator_list = m.unicast_locator_list().value().to_vec();
+                    self._multicast_reply_locator_list = if m.multicast_flag() {
+                        m.multicast_locator_list().value().to_vec()
+                    } else {
+                        Vec::new()
+                    };
+                }
+                // The submessages which follow were sent by another participant, e.g. a message forwarded
+                // by a relay, so they are attributed to it and do not inherit the timestamp of the relay
                 RtpsSubmessageReadKind::InfoSource(m) => {
                     self.source_vendor_id = m.vendor_id();
                     self.source_version = m.protocol_version();
                     self.source_guid_prefix = m.guid_prefix();
+                    self._unicast_reply_locator_list = Vec::new();
+                    self._multicast_reply_locator_list = Vec::new();
+                    self.have_timestamp = false;
+                    self.timestamp = TIME_INVALID;
                 }
                 RtpsSubmessageReadKind::InfoTimestamp(m) => {
                     if !m.invalidate_flag() {
@@ -11,4 +11,5 @@ pub mod stateful_writer;
 pub mod stateless_reader;
 pub mod stateless_writer;
 pub mod types;
+pub mod writer_history_cache;
 pub mod writer_proxy;
@@ -1,12 +1,10 @@
+use super::writer_history_cache::WriterChanges;
 use crate::{
     rtps_messages::{
         submessages::{heartbeat::HeartbeatSubmessage, heartbeat_frag::HeartbeatFragSubmessage},
         types::{Count, FragmentNumber},
     },
-    transport::{
-        history_cache::CacheChange,
-        types::{DurabilityKind, EntityId, Guid, Locator, ReliabilityKind, SequenceNumber},
-    },
+    transport::types::{DurabilityKind, EntityId, Guid, Locator, ReliabilityKind, SequenceNumber},
 };
 use alloc::vec::Vec;
 
@@ -15,6 +13,9 @@ pub struct HeartbeatMachine {
     count: Count,
     reader_id: EntityId,
     last_heartbeat_time: core::time::Duration,
+    // Without positive acknowledgments all the heartbeats are final so that the reader only answers
+    // them when it is missing changes
+    positive_acks_disabled: bool,
 }
 impl HeartbeatMachine {
     fn new(reader_id: EntityId) -> Self {
@@ -22,8 +23,14 @@ impl HeartbeatMachine {
             count: 0,
             reader_id,
             last_heartbeat_time: core::time::Duration::ZERO,
+            positive_acks_disabled: false,
         }
     }
+
+    pub fn set_positive_acks_disabled(&mut self, positive_acks_disabled: bool) {
+        self.positive_acks_disabled = positive_acks_disabled;
+    }
+
     pub fn is_time_for_heartbeat(
         &self,
         now: core::time::Duration,
@@ -43,7 +50,7 @@ impl HeartbeatMachine {
         self.count = self.count.wrapping_add(1);
         self.last_heartbeat_time = heartbeat_time;
         HeartbeatSubmessage::new(
-            final_flag,
+            final_flag || self.positive_acks_disabled,
             false,
             self.reader_id,
             writer_id,
@@ -67,14 +74,14 @@ impl HeartbeatFragMachine {
             reader_id,
         }
     }
-    pub fn _submessage(
+    pub fn submessage(
         &mut self,
         writer_id: EntityId,
         writer_sn: SequenceNumber,
         last_fragment_num: FragmentNumber,
     ) -> HeartbeatFragSubmessage {
         self.count = self.count.wrapping_add(1);
-        HeartbeatFragSubmessage::_new(
+        HeartbeatFragSubmessage::new(
             self.reader_id,
             writer_id,
             writer_sn,
@@ -84,6 +91,15 @@ impl HeartbeatFragMachine {
     }
 }
 
+// Transmissions of an unacknowledged change to the reader
+#[derive(Debug, PartialEq, Eq)]
+struct SentChange {
+    seq_num: SequenceNumber,
+    // Time at which the change was last sent
+    sent_time: core::time::Duration,
+    retransmission_count: u32,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct RtpsReaderProxy {
     remote_reader_guid: Guid,
@@ -93,10 +109,19 @@ pub struct RtpsReaderProxy {
     highest_sent_seq_num: SequenceNumber,
     highest_acked_seq_num: SequenceNumber,
     requested_changes: Vec<SequenceNumber>,
+    // Fragments requested by the reader with NACK_FRAG for the changes it has partially received, ordered
+    // by sequence number
+    requested_fragments: Vec<(SequenceNumber, Vec<FragmentNumber>)>,
+    repair_time: Option<core::time::Duration>,
+    // Unacknowledged changes sent to the reader, ordered by sequence number
+    sent_changes: Vec<SentChange>,
+    samples_since_heartbeat: usize,
+    // Bytes of serialized data sent to the reader since they were last taken
+    data_bytes_sent: u64,
     expects_inline_qos: bool,
     is_active: bool,
-    last_received_acknack_count: Count,
-    last_received_nack_frag_count: Count,
+    last_received_acknack_count: Option<Count>,
+    last_received_nack_frag_count: Option<Count>,
     heartbeat_machine: HeartbeatMachine,
     heartbeat_frag_machine: HeartbeatFragMachine,
     reliability: ReliabilityKind,
@@ -127,10 +152,15 @@ impl RtpsReaderProxy {
             highest_sent_seq_num: 0,
             highest_acked_seq_num: 0,
             requested_changes: Vec::new(),
+            requested_fragments: Vec::new(),
+            repair_time: None,
+            sent_changes: Vec::new(),
+            samples_since_heartbeat: 0,
+            data_bytes_sent: 0,
             expects_inline_qos,
             is_active,
-            last_received_acknack_count: 0,
-            last_received_nack_frag_count: 0,
+            last_received_acknack_count: None,
+            last_received_nack_frag_count: None,
             heartbeat_machine,
             heartbeat_frag_machine,
             reliability,
@@ -143,6 +173,14 @@ impl RtpsReaderProxy {
         self.remote_reader_guid
     }
 
+    pub fn add_data_bytes_sent(&mut self, bytes: usize) {
+        self.data_bytes_sent += bytes as u64;
+    }
+
+    pub fn take_data_bytes_sent(&mut self) -> u64 {
+        core::mem::take(&mut self.data_bytes_sent)
+    }
+
     pub fn unicast_locator_list(&self) -> &[Locator] {
         self.unicast_locator_list.as_slice()
     }
@@ -151,11 +189,15 @@ impl RtpsReaderProxy {
         self.reliability
     }
 
+    pub fn highest_acked_seq_num(&self) -> SequenceNumber {
+        self.highest_acked_seq_num
+    }
+
     pub fn heartbeat_machine(&mut self) -> &mut HeartbeatMachine {
         &mut self.heartbeat_machine
     }
 
-    pub fn _heartbeat_frag_machine(&mut self) -> &mut HeartbeatFragMachine {
+    pub fn heartbeat_frag_machine(&mut self) -> &mut HeartbeatFragMachine {
         &mut self.heartbeat_frag_machine
     }
 
@@ -163,12 +205,45 @@ impl RtpsReaderProxy {
         self.durability
     }
 
+    pub fn expects_inline_qos(&self) -> bool {
+        self.expects_inline_qos
+    }
+
     // //////////////   ReaderProxy operations defined in the Rtps Standard
 
     pub fn acked_changes_set(&mut self, committed_seq_num: SequenceNumber) {
         if committed_seq_num > self.highest_acked_seq_num {
             self.highest_acked_seq_num = committed_seq_num
         }
+        let acked_count = self
+            .sent_changes
+            .partition_point(|sent_change| sent_change.seq_num <= committed_seq_num);
+        self.sent_changes.drain(..acked_count);
+        let acked_count = self
+            .requested_fragments
+            .partition_point(|(seq_num, _)| *seq_num <= committed_seq_num);
+        self.requested_fragments.drain(..acked_count);
+    }
+
+    // Acknowledges the changes sent to the reader before the given time, in order and up to the first
+    // one sent afterwards or still requested by the reader
+    pub fn acked_changes_sent_before(&mut self, time: core::time::Duration) {
+        let mut committed_seq_num = self
+            .sent_changes
+            .iter()
+            .find(|sent_change| sent_change.sent_time > time)
+            .map_or(self.highest_sent_seq_num, |sent_change| {
+                sent_change.seq_num - 1
+            });
+        if let Some(requested_seq_num) = self
+            .requested_changes
+            .iter()
+            .chain(self.requested_fragments.iter().map(|(seq_num, _)| seq_num))
+            .min()
+        {
+            committed_seq_num = committed_seq_num.min(requested_seq_num - 1);
+        }
+        self.acked_changes_set(committed_seq_num);
     }
 
     pub fn next_requested_change(&mut self) -> Option<SequenceNumber> {
@@ -181,27 +256,21 @@ impl RtpsReaderProxy {
         next_requested_change
     }
 
-    pub fn next_unsent_change<'a>(
-        &'a self,
-        writer_history_cache: impl Iterator<Item = &'a CacheChange>,
-    ) -> Option<SequenceNumber> {
+    pub fn next_unsent_change(&self, writer_changes: WriterChanges<'_>) -> Option<SequenceNumber> {
         //         unsent_changes :=
         // { changes SUCH_THAT change.sequenceNumber > this.highestSentChangeSN }
         //
         // IF unsent_changes == <empty> return SEQUENCE_NUMBER_INVALID
         // ELSE return MIN { unsent_changes.sequenceNumber }
-        writer_history_cache
+        writer_changes
+            .iter_after(self.highest_sent_seq_num)
+            .next()
             .map(|cc| cc.sequence_number())
-            .filter(|cc_sn| cc_sn > &self.highest_sent_seq_num)
-            .min()
     }
 
-    pub fn unsent_changes<'a>(
-        &'a self,
-        writer_history_cache: impl Iterator<Item = &'a CacheChange>,
-    ) -> bool {
+    pub fn unsent_changes(&self, writer_changes: WriterChanges<'_>) -> bool {
         // return this.next_unsent_change() != SEQUENCE_NUMBER_INVALID;
-        self.next_unsent_change(writer_history_cache).is_some()
+        self.next_unsent_change(writer_changes).is_some()
     }
 
     pub fn requested_changes(&self) -> Vec<SequenceNumber> {
@@ -218,7 +287,129 @@ impl RtpsReaderProxy {
             if !self.requested_changes.contains(&seq_num) {
                 self.requested_changes.push(seq_num);
             }
+            // The whole change is sent again so the fragments requested before are sent with it
+            self.requested_fragments.retain(|(sn, _)| *sn != seq_num);
+        }
+    }
+
+    pub fn requested_fragments_set(
+        &mut self,
+        seq_num: SequenceNumber,
+        fragment_numbers: impl Iterator<Item = FragmentNumber>,
+    ) {
+        if self.requested_changes.contains(&seq_num) {
+            return;
+        }
+        let index = match self
+            .requested_fragments
+            .binary_search_by_key(&seq_num, |(sn, _)| *sn)
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.requested_fragments
+                    .insert(index, (seq_num, Vec::new()));
+                index
+            }
+        };
+        let requested_fragment_numbers = &mut self.requested_fragments[index].1;
+        requested_fragment_numbers.extend(fragment_numbers);
+        requested_fragment_numbers.sort_unstable();
+        requested_fragment_numbers.dedup();
+    }
+
+    pub fn has_requested_fragments(&self) -> bool {
+        !self.requested_fragments.is_empty()
+    }
+
+    pub fn next_requested_fragments(&mut self) -> Option<(SequenceNumber, Vec<FragmentNumber>)> {
+        (!self.requested_fragments.is_empty()).then(|| self.requested_fragments.remove(0))
+    }
+
+    // The repair of the requested changes is scheduled when the first of the requests is received so that
+    // the requests arriving before the nack response delay has elapsed are repaired together
+    pub fn schedule_repair(&mut self, repair_time: core::time::Duration) {
+        if self.repair_time.is_none() {
+            self.repair_time = Some(repair_time);
+        }
+    }
+
+    pub fn is_time_for_repair(&self, now: core::time::Duration) -> bool {
+        self.repair_time
+            .is_none_or(|repair_time| now >= repair_time)
+    }
+
+    pub fn repair_done(&mut self) {
+        self.repair_time = None;
+    }
+
+    // Every send of the change after the first one is counted as a retransmission
+    pub fn set_change_sent_time(
+        &mut self,
+        seq_num: SequenceNumber,
+        sent_time: core::time::Duration,
+    ) {
+        match self
+            .sent_changes
+            .binary_search_by_key(&seq_num, |sent_change| sent_change.seq_num)
+        {
+            Ok(index) => {
+                let sent_change = &mut self.sent_changes[index];
+                sent_change.sent_time = sent_time;
+                sent_change.retransmission_count += 1;
+            }
+            Err(index) => self.sent_changes.insert(
+                index,
+                SentChange {
+                    seq_num,
+                    sent_time,
+                    retransmission_count: 0,
+                },
+            ),
+        }
+    }
+
+    // A request for a change is a duplicate when it arrives within the nack suppression duration of the
+    // last send of the change or, once the change has been retransmitted, within its resend interval. The
+    // resend interval starts at the minimum one and doubles with every retransmission up to the maximum.
+    pub fn is_change_request_suppressed(
+        &self,
+        seq_num: SequenceNumber,
+        now: core::time::Duration,
+        nack_suppression_duration: core::time::Duration,
+        min_resend_interval: core::time::Duration,
+        max_resend_interval: core::time::Duration,
+    ) -> bool {
+        let Ok(index) = self
+            .sent_changes
+            .binary_search_by_key(&seq_num, |sent_change| sent_change.seq_num)
+        else {
+            return false;
+        };
+        let sent_change = &self.sent_changes[index];
+        let suppression_duration = match sent_change.retransmission_count {
+            0 => nack_suppression_duration,
+            retransmission_count => min_resend_interval
+                .saturating_mul(1 << (retransmission_count - 1).min(31))
+                .min(max_resend_interval)
+                .max(nack_suppression_duration),
+        };
+        now.saturating_sub(sent_change.sent_time) < suppression_duration
+    }
+
+    // Counts the DATA sent to the reader and returns whether a HEARTBEAT is piggybacked on it. The last
+    // DATA sent at once always carries one so that the reader does not wait for the periodic heartbeat
+    pub fn piggyback_heartbeat(
+        &mut self,
+        samples_per_heartbeat: Option<usize>,
+        is_last_sample: bool,
+    ) -> bool {
+        self.samples_since_heartbeat += 1;
+        let piggyback_heartbeat = is_last_sample
+            || samples_per_heartbeat.is_some_and(|samples| self.samples_since_heartbeat >= samples);
+        if piggyback_heartbeat {
+            self.samples_since_heartbeat = 0;
         }
+        piggyback_heartbeat
     }
 
     pub fn unacked_changes(&self, highest_available_seq_num: Option<SequenceNumber>) -> bool {
@@ -252,19 +443,19 @@ impl RtpsReaderProxy {
         self.first_relevant_sample_seq_num = seq_num;
     }
 
-    pub fn last_received_acknack_count(&self) -> Count {
+    pub fn last_received_acknack_count(&self) -> Option<Count> {
         self.last_received_acknack_count
     }
 
     pub fn set_last_received_acknack_count(&mut self, count: Count) {
-        self.last_received_acknack_count = count;
+        self.last_received_acknack_count = Some(count);
     }
 
-    pub fn last_received_nack_frag_count(&self) -> Count {
+    pub fn last_received_nack_frag_count(&self) -> Option<Count> {
         self.last_received_nack_frag_count
     }
 
     pub fn set_last_received_nack_frag_count(&mut self, count: Count) {
-        self.last_received_nack_frag_count = count;
+        self.last_received_nack_frag_count = Some(count);
     }
 }
@@ -1,5 +1,7 @@
 use super::{
-    error::RtpsResult, message_receiver::MessageReceiver, message_sender::WriteMessage,
+    error::RtpsResult,
+    message_receiver::MessageReceiver,
+    message_sender::{Clock, WriteMessage},
     writer_proxy::RtpsWriterProxy,
 };
 use crate::{
@@ -10,20 +12,25 @@ use crate::{
             data::DataSubmessage, data_frag::DataFragSubmessage, gap::GapSubmessage,
             heartbeat::HeartbeatSubmessage, heartbeat_frag::HeartbeatFragSubmessage,
         },
+        types::is_count_newer,
     },
     transport::{
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
         history_cache::{CacheChange, HistoryCache},
         reader::WriterProxy,
         types::{Guid, GuidPrefix, ReliabilityKind},
     },
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, collections::TryReserveError, vec::Vec};
 
 pub struct RtpsStatefulReader {
     guid: Guid,
     matched_writers: Vec<RtpsWriterProxy>,
     reliability: ReliabilityKind,
     history_cache: Box<dyn HistoryCache>,
+    heartbeat_response_delay: core::time::Duration,
+    heartbeat_suppression_duration: core::time::Duration,
+    fragment_reassembly_timeout: core::time::Duration,
 }
 
 impl RtpsStatefulReader {
@@ -37,6 +44,9 @@ impl RtpsStatefulReader {
             matched_writers: Vec::new(),
             history_cache,
             reliability,
+            heartbeat_response_delay: core::time::Duration::ZERO,
+            heartbeat_suppression_duration: core::time::Duration::ZERO,
+            fragment_reassembly_timeout: core::time::Duration::MAX,
         }
     }
 
@@ -63,6 +73,17 @@ impl RtpsStatefulReader {
         }
     }
 
+    pub fn reserve_resources(&mut self, max_matched_writers: usize) -> Result<(), TryReserveError> {
+        self.matched_writers.try_reserve_exact(max_matched_writers)
+    }
+
+    pub fn fragment_buffer_memory_usage(&self) -> usize {
+        self.matched_writers
+            .iter()
+            .map(|wp| wp.frag_buffer_memory_usage())
+            .sum()
+    }
+
     pub fn delete_matched_writer(&mut self, writer_guid: Guid) {
         self.matched_writers
             .retain(|x| x.remote_writer_guid() != writer_guid)
@@ -88,10 +109,15 @@ impl RtpsStatefulReader {
                 ReliabilityKind::BestEffort => {
                     let expected_seq_num = writer_proxy.available_changes_max() + 1;
                     if sequence_number >= expected_seq_num {
+                        let lost_change_count = writer_proxy.lost_changes_count(sequence_number);
                         writer_proxy.received_change_set(sequence_number);
                         if sequence_number > expected_seq_num {
                             writer_proxy.lost_changes_update(sequence_number);
                         }
+                        if lost_change_count > 0 {
+                            self.report_lost_changes(writer_guid, lost_change_count)
+                                .await;
+                        }
 
                         if let Ok(change) = CacheChange::try_from_data_submessage(
                             data_submessage,
@@ -125,24 +151,54 @@ impl RtpsStatefulReader {
         data_frag_submessage: &DataFragSubmessage,
         source_guid_prefix: GuidPrefix,
         source_timestamp: Option<rtps_messages::types::Time>,
+        clock: &impl Clock,
     ) {
         let writer_guid = Guid::new(source_guid_prefix, data_frag_submessage.writer_id());
         let sequence_number = data_frag_submessage.writer_sn();
         if let Some(writer_proxy) = self.matched_writer_lookup(writer_guid) {
-            writer_proxy.push_data_frag(data_frag_submessage.clone());
-            if let Some(data_submessage) = writer_proxy.reconstruct_data_from_frag(sequence_number)
-            {
-                self.on_data_submessage_received(
-                    &data_submessage,
-                    source_guid_prefix,
-                    source_timestamp,
-                )
-                .await;
+            if DiagnosticSubsystem::Fragmentation.is_enabled(DiagnosticVerbosity::Trace) {
+                tracing::trace!(
+                    target: DiagnosticSubsystem::Fragmentation.target(),
+                    ?writer_guid,
+                    sequence_number,
+                    fragment_starting_num = data_frag_submessage.fragment_starting_num(),
+                    "Received data fragment"
+                );
             }
+            writer_proxy.push_data_frag(
+                data_frag_submessage.clone(),
+                source_timestamp,
+                clock.now(),
+            );
+            self.on_reassembled_changes(writer_guid).await;
         }
     }
 
-    pub fn on_gap_submessage_received(
+    // Makes available the changes of the writer whose fragments are all received. The changes kept by a reliable
+    // reader because they were completed before the preceding ones are made available once those are received.
+    async fn on_reassembled_changes(&mut self, writer_guid: Guid) {
+        while let Some((data_submessage, source_timestamp)) = self
+            .matched_writer_lookup(writer_guid)
+            .and_then(|wp| wp.take_reassembled_change())
+        {
+            if DiagnosticSubsystem::Fragmentation.is_enabled(DiagnosticVerbosity::Debug) {
+                tracing::debug!(
+                    target: DiagnosticSubsystem::Fragmentation.target(),
+                    ?writer_guid,
+                    sequence_number = data_submessage.writer_sn(),
+                    "Reassembled change from its fragments"
+                );
+            }
+            self.on_data_submessage_received(
+                &data_submessage,
+                writer_guid.prefix(),
+                source_timestamp,
+            )
+            .await;
+        }
+    }
+
+    pub async fn on_gap_submessage_received(
         &mut self,
         gap_submessage: &GapSubmessage,
         source_guid_prefix: GuidPrefix,
@@ -153,6 +209,17 @@ impl RtpsStatefulReader {
             .iter_mut()
             .find(|w| w.remote_writer_guid() == writer_guid)
         {
+            // The changes covered by the GAP which were still expected from the writer are lost
+            let available_changes_max = writer_proxy.available_changes_max();
+            let lost_change_count = if writer_proxy.is_receiving_changes() {
+                (gap_submessage.gap_start()..gap_submessage.gap_list().base())
+                    .chain(gap_submessage.gap_list().set())
+                    .filter(|&seq_num| seq_num > available_changes_max)
+                    .count() as i64
+            } else {
+                0
+            };
+
             for seq_num in gap_submessage.gap_start()..gap_submessage.gap_list().base() {
                 writer_proxy.irrelevant_change_set(seq_num)
             }
@@ -160,6 +227,11 @@ impl RtpsStatefulReader {
             for seq_num in gap_submessage.gap_list().set() {
                 writer_proxy.irrelevant_change_set(seq_num)
             }
+
+            if lost_change_count > 0 {
+                self.report_lost_changes(writer_guid, lost_change_count)
+                    .await;
+            }
         }
     }
 
@@ -168,6 +240,7 @@ impl RtpsStatefulReader {
         heartbeat_submessage: &HeartbeatSubmessage,
         source_guid_prefix: GuidPrefix,
         message_writer: &impl WriteMessage,
+        clock: &impl Clock,
     ) {
         let writer_guid = Guid::new(source_guid_prefix, heartbeat_submessage.writer_id());
         if let Some(writer_proxy) = self
@@ -175,25 +248,73 @@ impl RtpsStatefulReader {
             .iter_mut()
             .find(|w| w.remote_writer_guid() == writer_guid)
         {
-            if writer_proxy.last_received_heartbeat_count() < heartbeat_submessage.count() {
+            if is_count_newer(
+                heartbeat_submessage.count(),
+                writer_proxy.last_received_heartbeat_count(),
+            ) {
+                if DiagnosticSubsystem::Reliability.is_enabled(DiagnosticVerbosity::Trace) {
+                    tracing::trace!(
+                        target: DiagnosticSubsystem::Reliability.target(),
+                        reader_guid = ?self.guid,
+                        ?writer_guid,
+                        first_sn = heartbeat_submessage.first_sn(),
+                        last_sn = heartbeat_submessage.last_sn(),
+                        "Received heartbeat"
+                    );
+                }
                 writer_proxy.set_last_received_heartbeat_count(heartbeat_submessage.count());
                 writer_proxy.missing_changes_update(heartbeat_submessage.last_sn());
+                let lost_change_count =
+                    writer_proxy.lost_changes_count(heartbeat_submessage.first_sn());
                 writer_proxy.lost_changes_update(heartbeat_submessage.first_sn());
 
-                let must_send_acknacks = !heartbeat_submessage.final_flag()
-                    || (!heartbeat_submessage.liveliness_flag()
-                        && writer_proxy.missing_changes().count() > 0);
-                writer_proxy.set_must_send_acknacks(must_send_acknacks);
+                // The heartbeats received within the suppression duration of the last answered one
+                // leave the scheduled ACKNACK unchanged
+                let now = clock.now();
+                if !writer_proxy.is_heartbeat_suppressed(now, self.heartbeat_suppression_duration) {
+                    let must_send_acknacks = !heartbeat_submessage.final_flag()
+                        || (!heartbeat_submessage.liveliness_flag()
+                            && writer_proxy.missing_changes().count() > 0);
+                    if must_send_acknacks {
+                        writer_proxy.schedule_acknack(now, self.heartbeat_response_delay);
+                    } else {
+                        writer_proxy.set_must_send_acknacks(false);
+                    }
+                }
 
-                writer_proxy.write_message(&self.guid, message_writer).await;
+                writer_proxy
+                    .write_message(&self.guid, message_writer, now)
+                    .await;
+
+                if lost_change_count > 0 {
+                    self.report_lost_changes(writer_guid, lost_change_count)
+                        .await;
+                }
             }
         }
     }
 
-    pub fn on_heartbeat_frag_submessage_received(
+    async fn report_lost_changes(&mut self, writer_guid: Guid, lost_change_count: i64) {
+        if DiagnosticSubsystem::Reliability.is_enabled(DiagnosticVerbosity::Info) {
+            tracing::info!(
+                target: DiagnosticSubsystem::Reliability.target(),
+                reader_guid = ?self.guid,
+                ?writer_guid,
+                lost_change_count,
+                "Changes from the writer were lost"
+            );
+        }
+        self.history_cache
+            .changes_lost(writer_guid, lost_change_count)
+            .await;
+    }
+
+    pub async fn on_heartbeat_frag_submessage_received(
         &mut self,
         heartbeat_frag_submessage: &HeartbeatFragSubmessage,
         source_guid_prefix: GuidPrefix,
+        message_writer: &impl WriteMessage,
+        clock: &impl Clock,
     ) {
         let writer_guid = Guid::new(source_guid_prefix, heartbeat_frag_submessage.writer_id());
         if let Some(writer_proxy) = self
@@ -201,9 +322,25 @@ impl RtpsStatefulReader {
             .iter_mut()
             .find(|w| w.remote_writer_guid() == writer_guid)
         {
-            if writer_proxy.last_received_heartbeat_count() < heartbeat_frag_submessage.count() {
+            if is_count_newer(
+                heartbeat_frag_submessage.count(),
+                writer_proxy.last_received_heartbeat_frag_count(),
+            ) {
                 writer_proxy
                     .set_last_received_heartbeat_frag_count(heartbeat_frag_submessage.count());
+
+                // The fragments missing from a change which is partially received are requested the same
+                // way as the changes missing when a heartbeat is received
+                let now = clock.now();
+                if writer_proxy.has_received_fragments(heartbeat_frag_submessage.writer_sn())
+                    && !writer_proxy
+                        .is_heartbeat_suppressed(now, self.heartbeat_suppression_duration)
+                {
+                    writer_proxy.schedule_acknack(now, self.heartbeat_response_delay);
+                    writer_proxy
+                        .write_message(&self.guid, message_writer, now)
+                        .await;
+                }
             }
         }
     }
@@ -212,6 +349,7 @@ impl RtpsStatefulReader {
         &mut self,
         datagram: &[u8],
         message_writer: &impl WriteMessage,
+        clock: &impl Clock,
     ) -> RtpsResult<()> {
         let rtps_message = RtpsMessageRead::try_from(datagram)?;
         let mut message_receiver = MessageReceiver::new(&rtps_message);
@@ -225,12 +363,18 @@ impl RtpsStatefulReader {
                         message_receiver.source_timestamp(),
                     )
                     .await;
+                    self.on_reassembled_changes(Guid::new(
+                        message_receiver.source_guid_prefix(),
+                        data_submessage.writer_id(),
+                    ))
+                    .await;
                 }
                 RtpsSubmessageReadKind::DataFrag(data_frag_submessage) => {
                     self.on_data_frag_submessage_received(
                         data_frag_submessage,
                         message_receiver.source_guid_prefix(),
                         message_receiver.source_timestamp(),
+                        clock,
                     )
                     .await;
                 }
@@ -238,21 +382,36 @@ impl RtpsStatefulReader {
                     self.on_heartbeat_frag_submessage_received(
                         heartbeat_frag_submessage,
                         message_receiver.source_guid_prefix(),
-                    );
+                        message_writer,
+                        clock,
+                    )
+                    .await;
                 }
                 RtpsSubmessageReadKind::Gap(gap_submessage) => {
                     self.on_gap_submessage_received(
                         gap_submessage,
                         message_receiver.source_guid_prefix(),
-                    );
+                    )
+                    .await;
+                    self.on_reassembled_changes(Guid::new(
+                        message_receiver.source_guid_prefix(),
+                        gap_submessage.writer_id(),
+                    ))
+                    .await;
                 }
                 RtpsSubmessageReadKind::Heartbeat(heartbeat_submessage) => {
                     self.on_heartbeat_submessage_received(
                         heartbeat_submessage,
                         message_receiver.source_guid_prefix(),
                         message_writer,
+                        clock,
                     )
                     .await;
+                    self.on_reassembled_changes(Guid::new(
+                        message_receiver.source_guid_prefix(),
+                        heartbeat_submessage.writer_id(),
+                    ))
+                    .await;
                 }
                 _ => (),
             }
@@ -269,4 +428,38 @@ impl RtpsStatefulReader {
             .iter()
             .any(|p| !p.is_historical_data_received())
     }
+
+    pub fn set_heartbeat_response_timing(
+        &mut self,
+        heartbeat_response_delay: core::time::Duration,
+        heartbeat_suppression_duration: core::time::Duration,
+    ) {
+        self.heartbeat_response_delay = heartbeat_response_delay;
+        self.heartbeat_suppression_duration = heartbeat_suppression_duration;
+    }
+
+    pub fn set_fragment_reassembly_timeout(
+        &mut self,
+        fragment_reassembly_timeout: core::time::Duration,
+    ) {
+        self.fragment_reassembly_timeout = fragment_reassembly_timeout;
+    }
+
+    // Sends the ACKNACKs whose heartbeat response delay has elapsed and discards the fragments of the changes
+    // whose reassembly timed out
+    pub async fn write_message(&mut self, message_writer: &impl WriteMessage, clock: &impl Clock) {
+        let now = clock.now();
+        for writer_proxy in self.matched_writers.iter_mut() {
+            writer_proxy.remove_expired_fragments(now, self.fragment_reassembly_timeout);
+            writer_proxy
+                .write_message(&self.guid, message_writer, now)
+                .await;
+        }
+    }
+
+    pub fn request_historical_data(&mut self) {
+        for writer_proxy in self.matched_writers.iter_mut() {
+            writer_proxy.reset_changes_from_writer();
+        }
+    }
 }
@@ -1,48 +1,92 @@
 use super::{
-    behavior_types::Duration,
     error::RtpsResult,
     message_receiver::MessageReceiver,
     message_sender::{Clock, WriteMessage},
     reader_proxy::RtpsReaderProxy,
+    writer_history_cache::{WriterChanges, WriterHistoryCache},
 };
 use crate::{
     rtps_messages::{
-        overall_structure::{RtpsMessageRead, RtpsMessageWrite, RtpsSubmessageReadKind},
+        overall_structure::{
+            RtpsMessageRead, RtpsMessageWrite, RtpsSubmessageReadKind, Submessage,
+        },
         submessage_elements::{ParameterList, SequenceNumberSet, SerializedDataFragment},
         submessages::{
-            ack_nack::AckNackSubmessage, data_frag::DataFragSubmessage, gap::GapSubmessage,
-            info_destination::InfoDestinationSubmessage, info_timestamp::InfoTimestampSubmessage,
+            ack_nack::AckNackSubmessage,
+            data_frag::{DataFragSubmessage, PrebuiltDataFragSubmessage},
+            gap::GapSubmessage,
+            info_destination::InfoDestinationSubmessage,
+            info_timestamp::InfoTimestampSubmessage,
             nack_frag::NackFragSubmessage,
         },
-        types::TIME_INVALID,
+        types::{is_count_newer, FragmentNumber, TIME_INVALID},
     },
     transport::{
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
         history_cache::CacheChange,
         types::{
-            ChangeKind, DurabilityKind, EntityId, Guid, GuidPrefix, ReliabilityKind,
+            ChangeKind, DurabilityKind, EntityId, Guid, GuidPrefix, Locator, ReliabilityKind,
             SequenceNumber, ENTITYID_UNKNOWN,
         },
-        writer::ReaderProxy,
+        writer::{
+            ChangeLifecycleEvent, ChangeLifecycleEventKind, ChangeLifecycleObserver, ReaderProxy,
+        },
     },
 };
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, sync::Arc, vec::Vec};
+
+// Number of bytes of changes sent to a reader, or group of readers sharing a message stream, in each round
+// of the writer before moving on to the next one
+const SEND_QUANTUM: usize = 65536;
 
 pub struct RtpsStatefulWriter {
     guid: Guid,
-    changes: Vec<CacheChange>,
+    history_cache: WriterHistoryCache,
     matched_readers: Vec<RtpsReaderProxy>,
-    heartbeat_period: Duration,
+    heartbeat_period: core::time::Duration,
+    nack_response_delay: core::time::Duration,
+    nack_suppression_duration: core::time::Duration,
+    min_resend_interval: core::time::Duration,
+    samples_per_heartbeat: Option<usize>,
+    positive_acks_keep_duration: Option<core::time::Duration>,
     data_max_size_serialized: usize,
+    payload_prefragmentation: bool,
+    prebuilt_fragments: Vec<PrebuiltChangeFragments>,
+    change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    batching: bool,
+    flushed_seq_num: SequenceNumber,
+    unmatched_data_bytes_sent: Vec<(Guid, u64)>,
+}
+
+// DATA_FRAG submessages of a change built when the change is added to the writer
+struct PrebuiltChangeFragments {
+    sequence_number: SequenceNumber,
+    data_frags: Vec<PrebuiltDataFragSubmessage>,
 }
 
 impl RtpsStatefulWriter {
-    pub fn new(guid: Guid, data_max_size_serialized: usize) -> Self {
+    pub fn new(
+        guid: Guid,
+        data_max_size_serialized: usize,
+        payload_prefragmentation: bool,
+    ) -> Self {
         Self {
             guid,
-            changes: Vec::new(),
+            history_cache: WriterHistoryCache::new(),
             matched_readers: Vec::new(),
-            heartbeat_period: Duration::from_millis(200),
+            heartbeat_period: core::time::Duration::from_millis(200),
+            nack_response_delay: core::time::Duration::ZERO,
+            nack_suppression_duration: core::time::Duration::ZERO,
+            min_resend_interval: core::time::Duration::from_millis(10),
+            samples_per_heartbeat: Some(1),
+            positive_acks_keep_duration: None,
             data_max_size_serialized,
+            payload_prefragmentation,
+            prebuilt_fragments: Vec::new(),
+            change_lifecycle_observer: None,
+            batching: false,
+            flushed_seq_num: 0,
+            unmatched_data_bytes_sent: Vec::new(),
         }
     }
 
@@ -54,36 +98,189 @@ impl RtpsStatefulWriter {
         self.data_max_size_serialized
     }
 
+    pub fn reserve_resources(
+        &mut self,
+        max_changes: usize,
+        max_matched_readers: usize,
+    ) -> Result<(), TryReserveError> {
+        self.history_cache.try_reserve(max_changes)?;
+        self.prebuilt_fragments.try_reserve_exact(max_changes)?;
+        self.matched_readers.try_reserve_exact(max_matched_readers)
+    }
+
+    pub fn history_memory_usage(&self) -> usize {
+        self.history_cache
+            .changes()
+            .iter()
+            .map(|c| core::mem::size_of::<CacheChange>() + c.data_value.len())
+            .sum()
+    }
+
+    pub fn fragment_buffer_memory_usage(&self) -> usize {
+        self.prebuilt_fragments
+            .iter()
+            .flat_map(|f| f.data_frags.iter())
+            .map(|f| f.memory_usage())
+            .sum()
+    }
+
     pub fn add_change(&mut self, cache_change: CacheChange) {
-        self.changes.push(cache_change);
+        let number_of_fragments = cache_change
+            .data_value()
+            .len()
+            .div_ceil(self.data_max_size_serialized);
+        if self.payload_prefragmentation && number_of_fragments > 1 {
+            // The reader id is written when sending so any value can be used here
+            let data_frags = (0..number_of_fragments)
+                .map(|frag_index| {
+                    PrebuiltDataFragSubmessage::new(&data_frag_submessage(
+                        &cache_change,
+                        frag_index,
+                        self.data_max_size_serialized,
+                        ENTITYID_UNKNOWN,
+                        self.guid.entity_id(),
+                    ))
+                })
+                .collect();
+            if DiagnosticSubsystem::Fragmentation.is_enabled(DiagnosticVerbosity::Trace) {
+                tracing::trace!(
+                    target: DiagnosticSubsystem::Fragmentation.target(),
+                    writer_guid = ?self.guid,
+                    sequence_number = cache_change.sequence_number(),
+                    number_of_fragments,
+                    "Prefragmented change"
+                );
+            }
+            self.prebuilt_fragments.push(PrebuiltChangeFragments {
+                sequence_number: cache_change.sequence_number(),
+                data_frags,
+            });
+        }
+        if !self.batching {
+            self.flushed_seq_num = cache_change.sequence_number();
+        }
+        self.history_cache.add_change(cache_change);
+    }
+
+    /// Holds the changes added to the writer until [`Self::flush`] is called when enabled.
+    /// Disabling batching flushes the held changes.
+    pub fn set_batching(&mut self, batching: bool) {
+        self.batching = batching;
+        if !batching {
+            self.flush();
+        }
+    }
+
+    pub fn set_reliability_timing(
+        &mut self,
+        heartbeat_period: core::time::Duration,
+        nack_response_delay: core::time::Duration,
+        nack_suppression_duration: core::time::Duration,
+        min_resend_interval: core::time::Duration,
+    ) {
+        self.heartbeat_period = heartbeat_period;
+        self.nack_response_delay = nack_response_delay;
+        self.nack_suppression_duration = nack_suppression_duration;
+        self.min_resend_interval = min_resend_interval;
+    }
+
+    pub fn set_samples_per_heartbeat(&mut self, samples_per_heartbeat: Option<usize>) {
+        self.samples_per_heartbeat = samples_per_heartbeat;
+    }
+
+    /// Without positive acknowledgments a change is acknowledged by a reliable reader once it has been
+    /// sent to it for the keep duration.
+    pub fn set_disable_positive_acks(&mut self, keep_duration: Option<core::time::Duration>) {
+        self.positive_acks_keep_duration = keep_duration;
+        for reader_proxy in &mut self.matched_readers {
+            reader_proxy
+                .heartbeat_machine()
+                .set_positive_acks_disabled(keep_duration.is_some());
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if let Some(seq_num_max) = self.history_cache.changes().seq_num_max() {
+            self.flushed_seq_num = self.flushed_seq_num.max(seq_num_max);
+        }
     }
 
     pub fn remove_change(&mut self, sequence_number: SequenceNumber) {
-        self.changes
-            .retain(|cc| cc.sequence_number() != sequence_number);
+        if !self.is_change_acknowledged(sequence_number) {
+            notify_change_event(
+                self.change_lifecycle_observer.as_deref(),
+                ChangeLifecycleEventKind::Dropped,
+                sequence_number,
+                self.history_cache.changes(),
+                None,
+            );
+        }
+        self.history_cache.remove_change(sequence_number);
+        self.prebuilt_fragments
+            .retain(|p| p.sequence_number != sequence_number);
+    }
+
+    pub fn set_change_lifecycle_observer(
+        &mut self,
+        change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    ) {
+        self.change_lifecycle_observer = change_lifecycle_observer;
+    }
+
+    // Only tracked when an observer is registered since it has to check all the changes
+    fn unacknowledged_changes(&self) -> Vec<SequenceNumber> {
+        if self.change_lifecycle_observer.is_none() {
+            return Vec::new();
+        }
+        self.history_cache
+            .changes()
+            .iter()
+            .map(|cc| cc.sequence_number())
+            .filter(|&sequence_number| !self.is_change_acknowledged(sequence_number))
+            .collect()
+    }
+
+    fn notify_acknowledged_changes(&self, previously_unacknowledged_changes: &[SequenceNumber]) {
+        for &sequence_number in previously_unacknowledged_changes {
+            if self.is_change_acknowledged(sequence_number) {
+                notify_change_event(
+                    self.change_lifecycle_observer.as_deref(),
+                    ChangeLifecycleEventKind::AcknowledgedByAll,
+                    sequence_number,
+                    self.history_cache.changes(),
+                    None,
+                );
+            }
+        }
     }
 
     pub fn is_change_acknowledged(&self, sequence_number: SequenceNumber) -> bool {
-        !self
-            .matched_readers
+        sequence_number <= self.acknowledged_seq_num()
+    }
+
+    /// Sequence number up to which all the changes are acknowledged by all the matched reliable readers, which is
+    /// [`SequenceNumber::MAX`] if there are none.
+    pub fn acknowledged_seq_num(&self) -> SequenceNumber {
+        self.matched_readers
             .iter()
             .filter(|rp| rp.reliability() == ReliabilityKind::Reliable)
-            .any(|rp| rp.unacked_changes(Some(sequence_number)))
+            .map(|rp| rp.highest_acked_seq_num())
+            .min()
+            .unwrap_or(SequenceNumber::MAX)
     }
 
     pub fn add_matched_reader(&mut self, reader_proxy: &ReaderProxy) {
         let first_relevant_sample_seq_num = match reader_proxy.durability_kind {
             DurabilityKind::Volatile => self
-                .changes
-                .iter()
-                .map(|cc| cc.sequence_number)
-                .max()
+                .history_cache
+                .changes_up_to(self.flushed_seq_num)
+                .seq_num_max()
                 .unwrap_or(0),
             DurabilityKind::TransientLocal
             | DurabilityKind::Transient
             | DurabilityKind::Persistent => 0,
         };
-        let rtps_reader_proxy = RtpsReaderProxy::new(
+        let mut rtps_reader_proxy = RtpsReaderProxy::new(
             reader_proxy.remote_reader_guid,
             reader_proxy.remote_group_entity_id,
             &reader_proxy.unicast_locator_list,
@@ -94,11 +291,19 @@ impl RtpsStatefulWriter {
             first_relevant_sample_seq_num,
             reader_proxy.durability_kind,
         );
+        rtps_reader_proxy
+            .heartbeat_machine()
+            .set_positive_acks_disabled(self.positive_acks_keep_duration.is_some());
         if let Some(rp) = self
             .matched_readers
             .iter_mut()
             .find(|rp| rp.remote_reader_guid() == reader_proxy.remote_reader_guid)
         {
+            let data_bytes_sent = rp.take_data_bytes_sent();
+            if data_bytes_sent > 0 {
+                self.unmatched_data_bytes_sent
+                    .push((reader_proxy.remote_reader_guid, data_bytes_sent));
+            }
             *rp = rtps_reader_proxy;
         } else {
             self.matched_readers.push(rtps_reader_proxy);
@@ -106,38 +311,179 @@ impl RtpsStatefulWriter {
     }
 
     pub fn delete_matched_reader(&mut self, reader_guid: Guid) {
-        self.matched_readers
-            .retain(|rp| rp.remote_reader_guid() != reader_guid);
+        let unacknowledged_changes = self.unacknowledged_changes();
+        if let Some(i) = self
+            .matched_readers
+            .iter()
+            .position(|rp| rp.remote_reader_guid() == reader_guid)
+        {
+            // The data sent to the reader is kept until it is taken
+            let data_bytes_sent = self.matched_readers.remove(i).take_data_bytes_sent();
+            if data_bytes_sent > 0 {
+                self.unmatched_data_bytes_sent
+                    .push((reader_guid, data_bytes_sent));
+            }
+        }
+        self.notify_acknowledged_changes(&unacknowledged_changes);
     }
 
-    pub async fn write_message(&mut self, message_writer: &impl WriteMessage, clock: &impl Clock) {
+    /// Returns the number of bytes of serialized data sent to each of the readers since the last call, including
+    /// the readers deleted since then.
+    pub fn take_data_bytes_sent(&mut self) -> Vec<(Guid, u64)> {
+        let mut data_bytes_sent = core::mem::take(&mut self.unmatched_data_bytes_sent);
         for reader_proxy in &mut self.matched_readers {
-            match reader_proxy.reliability() {
-                ReliabilityKind::BestEffort => {
-                    write_message_to_reader_proxy_best_effort(
-                        reader_proxy,
-                        self.guid.entity_id(),
-                        &self.changes,
-                        self.data_max_size_serialized,
-                        message_writer,
-                    )
-                    .await
+            let bytes = reader_proxy.take_data_bytes_sent();
+            if bytes > 0 {
+                data_bytes_sent.push((reader_proxy.remote_reader_guid(), bytes));
+            }
+        }
+        data_bytes_sent
+    }
+
+    fn acknowledge_changes_sent_before(&mut self, time: core::time::Duration) {
+        let unacknowledged_changes = self.unacknowledged_changes();
+        for reader_proxy in self
+            .matched_readers
+            .iter_mut()
+            .filter(|rp| rp.reliability() == ReliabilityKind::Reliable)
+        {
+            reader_proxy.acked_changes_sent_before(time);
+        }
+        self.notify_acknowledged_changes(&unacknowledged_changes);
+    }
+
+    // Groups of matched readers which can receive the same message stream. These are readers
+    // of the same participant reachable on the same locators which have been sent the same changes.
+    // The stream is addressed to ENTITYID_UNKNOWN so it reaches all the readers of the participant
+    // matched with this writer. Hence a group is only formed when it includes all of them.
+    fn reader_proxy_groups(&self) -> Vec<Vec<Guid>> {
+        let mut reader_proxy_groups: Vec<Vec<Guid>> = Vec::new();
+        for (index, reader_proxy) in self.matched_readers.iter().enumerate() {
+            if reader_proxy_groups
+                .iter()
+                .flatten()
+                .any(|g| g == &reader_proxy.remote_reader_guid())
+            {
+                continue;
+            }
+            let reader_proxy_group: Vec<Guid> = self.matched_readers[index..]
+                .iter()
+                .filter(|rp| share_message_stream(reader_proxy, rp))
+                .map(|rp| rp.remote_reader_guid())
+                .collect();
+            let participant_reader_count = self
+                .matched_readers
+                .iter()
+                .filter(|rp| {
+                    rp.remote_reader_guid().prefix() == reader_proxy.remote_reader_guid().prefix()
+                })
+                .count();
+            if reader_proxy_group.len() > 1 && reader_proxy_group.len() == participant_reader_count
+            {
+                reader_proxy_groups.push(reader_proxy_group);
+            }
+        }
+        reader_proxy_groups
+    }
+
+    pub async fn write_message(&mut self, message_writer: &impl WriteMessage, clock: &impl Clock) {
+        if let Some(keep_start) = self
+            .positive_acks_keep_duration
+            .and_then(|keep_duration| clock.now().checked_sub(keep_duration))
+        {
+            self.acknowledge_changes_sent_before(keep_start);
+        }
+        let changes = self.history_cache.changes_up_to(self.flushed_seq_num);
+        // The readers are served in rounds of at most SEND_QUANTUM bytes each so that a reader with a large
+        // backlog does not delay the changes sent to the others. The rounds go on while any of them has used
+        // its whole quantum and may have more to send.
+        let mut pending_reader_proxy_groups = self.reader_proxy_groups();
+        let mut pending_readers: Vec<Guid> = self
+            .matched_readers
+            .iter()
+            .map(|rp| rp.remote_reader_guid())
+            .collect();
+        while !pending_reader_proxy_groups.is_empty() || !pending_readers.is_empty() {
+            let mut busy_reader_proxy_groups = Vec::new();
+            for reader_guid_list in pending_reader_proxy_groups {
+                let mut reader_proxy_group: Vec<&mut RtpsReaderProxy> = self
+                    .matched_readers
+                    .iter_mut()
+                    .filter(|rp| reader_guid_list.contains(&rp.remote_reader_guid()))
+                    .collect();
+                let mut send_budget = SEND_QUANTUM;
+                write_message_to_reader_proxy_group(
+                    &mut reader_proxy_group,
+                    self.guid.entity_id(),
+                    changes,
+                    self.data_max_size_serialized,
+                    &self.prebuilt_fragments,
+                    message_writer,
+                    clock,
+                    self.change_lifecycle_observer.as_deref(),
+                    &mut send_budget,
+                )
+                .await;
+                if send_budget == 0 {
+                    busy_reader_proxy_groups.push(reader_guid_list);
                 }
-                ReliabilityKind::Reliable => {
-                    write_message_to_reader_proxy_reliable(
-                        reader_proxy,
-                        self.guid.entity_id(),
-                        &self.changes,
-                        self.changes.iter().map(|cc| cc.sequence_number()).min(),
-                        self.changes.iter().map(|cc| cc.sequence_number()).max(),
-                        self.data_max_size_serialized,
-                        self.heartbeat_period,
-                        message_writer,
-                        clock,
-                    )
-                    .await
+            }
+
+            let mut busy_readers = Vec::new();
+            for reader_proxy in self
+                .matched_readers
+                .iter_mut()
+                .filter(|rp| pending_readers.contains(&rp.remote_reader_guid()))
+            {
+                // The readers of a group still sending its changes are served once the group is done so
+                // that they keep sharing its message stream
+                if busy_reader_proxy_groups
+                    .iter()
+                    .flatten()
+                    .any(|g| g == &reader_proxy.remote_reader_guid())
+                {
+                    busy_readers.push(reader_proxy.remote_reader_guid());
+                    continue;
+                }
+                let mut send_budget = SEND_QUANTUM;
+                match reader_proxy.reliability() {
+                    ReliabilityKind::BestEffort => {
+                        write_message_to_reader_proxy_best_effort(
+                            reader_proxy,
+                            self.guid.entity_id(),
+                            changes,
+                            self.data_max_size_serialized,
+                            &self.prebuilt_fragments,
+                            message_writer,
+                            self.change_lifecycle_observer.as_deref(),
+                            &mut send_budget,
+                        )
+                        .await
+                    }
+                    ReliabilityKind::Reliable => {
+                        write_message_to_reader_proxy_reliable(
+                            reader_proxy,
+                            self.guid.entity_id(),
+                            changes,
+                            self.data_max_size_serialized,
+                            &self.prebuilt_fragments,
+                            self.heartbeat_period,
+                            self.samples_per_heartbeat,
+                            message_writer,
+                            clock,
+                            self.change_lifecycle_observer.as_deref(),
+                            &mut send_budget,
+                        )
+                        .await
+                    }
+                }
+                if send_budget == 0 {
+                    busy_readers.push(reader_proxy.remote_reader_guid());
                 }
             }
+
+            pending_reader_proxy_groups = busy_reader_proxy_groups;
+            pending_readers = busy_readers;
         }
     }
 
@@ -150,6 +496,8 @@ impl RtpsStatefulWriter {
     ) {
         if &self.guid.entity_id() == acknack_submessage.writer_id() {
             let reader_guid = Guid::new(source_guid_prefix, *acknack_submessage.reader_id());
+            let changes = self.history_cache.changes_up_to(self.flushed_seq_num);
+            let unacknowledged_changes = self.unacknowledged_changes();
 
             if let Some(reader_proxy) = self
                 .matched_readers
@@ -157,27 +505,79 @@ impl RtpsStatefulWriter {
                 .find(|x| x.remote_reader_guid() == reader_guid)
             {
                 if reader_proxy.reliability() == ReliabilityKind::Reliable
-                    && acknack_submessage.count() > reader_proxy.last_received_acknack_count()
+                    && is_count_newer(
+                        acknack_submessage.count(),
+                        reader_proxy.last_received_acknack_count(),
+                    )
                 {
+                    if DiagnosticSubsystem::Reliability.is_enabled(DiagnosticVerbosity::Trace) {
+                        tracing::trace!(
+                            target: DiagnosticSubsystem::Reliability.target(),
+                            writer_guid = ?self.guid,
+                            ?reader_guid,
+                            acknowledged_sn = acknack_submessage.reader_sn_state().base() - 1,
+                            "Received acknack"
+                        );
+                    }
+                    if DiagnosticSubsystem::Reliability.is_enabled(DiagnosticVerbosity::Debug)
+                        && acknack_submessage.reader_sn_state().set().next().is_some()
+                    {
+                        let requested_changes: Vec<SequenceNumber> =
+                            acknack_submessage.reader_sn_state().set().collect();
+                        tracing::debug!(
+                            target: DiagnosticSubsystem::Reliability.target(),
+                            writer_guid = ?self.guid,
+                            ?reader_guid,
+                            ?requested_changes,
+                            "Repairing the changes requested by the reader"
+                        );
+                    }
                     reader_proxy.acked_changes_set(acknack_submessage.reader_sn_state().base() - 1);
-                    reader_proxy.requested_changes_set(acknack_submessage.reader_sn_state().set());
+                    // The requests for changes sent within the nack suppression duration are assumed
+                    // to have crossed the change on the wire and are ignored. The repeated requests for a
+                    // retransmitted change are ignored within its resend interval, which backs off up to
+                    // the heartbeat period, since the reader sends them faster than the repair can arrive.
+                    let now = clock.now();
+                    let max_resend_interval = self.heartbeat_period.max(self.min_resend_interval);
+                    let requested_changes: Vec<SequenceNumber> = acknack_submessage
+                        .reader_sn_state()
+                        .set()
+                        .filter(|sn| {
+                            !reader_proxy.is_change_request_suppressed(
+                                *sn,
+                                now,
+                                self.nack_suppression_duration,
+                                self.min_resend_interval,
+                                max_resend_interval,
+                            )
+                        })
+                        .collect();
+                    if !requested_changes.is_empty() {
+                        reader_proxy.requested_changes_set(requested_changes.into_iter());
+                        reader_proxy.schedule_repair(now + self.nack_response_delay);
+                    }
 
                     reader_proxy.set_last_received_acknack_count(acknack_submessage.count());
 
+                    // The changes beyond the quantum are sent in the next rounds of the writer
+                    let mut send_budget = SEND_QUANTUM;
                     write_message_to_reader_proxy_reliable(
                         reader_proxy,
                         self.guid.entity_id(),
-                        &self.changes,
-                        self.changes.iter().map(|cc| cc.sequence_number()).min(),
-                        self.changes.iter().map(|cc| cc.sequence_number()).max(),
+                        changes,
                         self.data_max_size_serialized,
+                        &self.prebuilt_fragments,
                         self.heartbeat_period,
+                        self.samples_per_heartbeat,
                         message_writer,
                         clock,
+                        self.change_lifecycle_observer.as_deref(),
+                        &mut send_budget,
                     )
                     .await;
                 }
             }
+            self.notify_acknowledged_changes(&unacknowledged_changes);
         }
     }
 
@@ -189,6 +589,7 @@ impl RtpsStatefulWriter {
         clock: &impl Clock,
     ) {
         let reader_guid = Guid::new(source_guid_prefix, nackfrag_submessage.reader_id());
+        let changes = self.history_cache.changes_up_to(self.flushed_seq_num);
 
         if let Some(reader_proxy) = self
             .matched_readers
@@ -196,22 +597,44 @@ impl RtpsStatefulWriter {
             .find(|x| x.remote_reader_guid() == reader_guid)
         {
             if reader_proxy.reliability() == ReliabilityKind::Reliable
-                && nackfrag_submessage.count() > reader_proxy.last_received_nack_frag_count()
+                && is_count_newer(
+                    nackfrag_submessage.count(),
+                    reader_proxy.last_received_nack_frag_count(),
+                )
             {
-                reader_proxy
-                    .requested_changes_set(core::iter::once(nackfrag_submessage.writer_sn()));
+                if DiagnosticSubsystem::Fragmentation.is_enabled(DiagnosticVerbosity::Debug) {
+                    tracing::debug!(
+                        target: DiagnosticSubsystem::Fragmentation.target(),
+                        writer_guid = ?self.guid,
+                        ?reader_guid,
+                        sequence_number = nackfrag_submessage.writer_sn(),
+                        "Repairing the fragments requested by the reader"
+                    );
+                }
+                reader_proxy.requested_fragments_set(
+                    nackfrag_submessage.writer_sn(),
+                    nackfrag_submessage
+                        .fragment_number_state()
+                        .set()
+                        .iter()
+                        .copied(),
+                );
+                reader_proxy.schedule_repair(clock.now() + self.nack_response_delay);
                 reader_proxy.set_last_received_nack_frag_count(nackfrag_submessage.count());
 
+                let mut send_budget = SEND_QUANTUM;
                 write_message_to_reader_proxy_reliable(
                     reader_proxy,
                     self.guid.entity_id(),
-                    &self.changes,
-                    self.changes.iter().map(|cc| cc.sequence_number()).min(),
-                    self.changes.iter().map(|cc| cc.sequence_number()).max(),
+                    changes,
                     self.data_max_size_serialized,
+                    &self.prebuilt_fragments,
                     self.heartbeat_period,
+                    self.samples_per_heartbeat,
                     message_writer,
                     clock,
+                    self.change_lifecycle_observer.as_deref(),
+                    &mut send_budget,
                 )
                 .await;
             }
@@ -254,12 +677,436 @@ impl RtpsStatefulWriter {
     }
 }
 
+// Charges the size of the change sent to a reader to its budget. Every change is charged at least one
+// byte so that a round always makes progress.
+fn spend_send_budget(send_budget: &mut usize, changes: WriterChanges<'_>, seq_num: SequenceNumber) {
+    let change_size = changes
+        .get_change(seq_num)
+        .map_or(0, |cc| cc.data_value().len());
+    *send_budget = send_budget.saturating_sub(change_size.max(1));
+}
+
+fn data_frag_submessage(
+    cache_change: &CacheChange,
+    frag_index: usize,
+    data_max_size_serialized: usize,
+    reader_id: EntityId,
+    writer_id: EntityId,
+) -> DataFragSubmessage {
+    let inline_qos_flag = true;
+    let key_flag = match cache_change.kind() {
+        ChangeKind::Alive => false,
+        ChangeKind::NotAliveDisposed
+        | ChangeKind::NotAliveUnregistered
+        | ChangeKind::NotAliveDisposedUnregistered => true,
+        _ => todo!(),
+    };
+    let non_standard_payload_flag = false;
+    let writer_sn = cache_change.sequence_number();
+    let fragment_starting_num = (frag_index + 1) as u32;
+    let fragments_in_submessage = 1;
+    let fragment_size = data_max_size_serialized as u16;
+    let data_size = cache_change.data_value().len() as u32;
+
+    let start = frag_index * data_max_size_serialized;
+    let end = core::cmp::min(
+        (frag_index + 1) * data_max_size_serialized,
+        cache_change.data_value().len(),
+    );
+
+    let serialized_payload =
+        SerializedDataFragment::new(cache_change.data_value().clone().into(), start..end);
+
+    DataFragSubmessage::new(
+        inline_qos_flag,
+        non_standard_payload_flag,
+        key_flag,
+        reader_id,
+        writer_id,
+        writer_sn,
+        fragment_starting_num,
+        fragments_in_submessage,
+        fragment_size,
+        data_size,
+        ParameterList::new(Vec::new()),
+        serialized_payload,
+    )
+}
+
+fn notify_change_event(
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    kind: ChangeLifecycleEventKind,
+    sequence_number: SequenceNumber,
+    changes: WriterChanges<'_>,
+    reader_guid: Option<Guid>,
+) {
+    if let Some(change_lifecycle_observer) = change_lifecycle_observer {
+        let source_timestamp = changes
+            .get_change(sequence_number)
+            .and_then(|cc| cc.source_timestamp());
+        change_lifecycle_observer.on_change_event(&ChangeLifecycleEvent {
+            kind,
+            sequence_number,
+            source_timestamp,
+            reader_guid,
+        });
+    }
+}
+
+// Sends the DATA_FRAG submessages of the given fragments of the change to the reader. A HEARTBEAT_FRAG is
+// piggybacked on the last one so that the reader requests the fragments it is still missing.
+#[allow(clippy::too_many_arguments)]
+async fn write_fragments_reader_proxy_reliable(
+    reader_proxy: &mut RtpsReaderProxy,
+    writer_id: EntityId,
+    cache_change: &CacheChange,
+    fragment_numbers: impl IntoIterator<Item = FragmentNumber>,
+    data_max_size_serialized: usize,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
+    message_writer: &impl WriteMessage,
+) {
+    let number_of_fragments = cache_change
+        .data_value()
+        .len()
+        .div_ceil(data_max_size_serialized);
+    let prebuilt_data_frags = prebuilt_fragments
+        .iter()
+        .find(|p| p.sequence_number == cache_change.sequence_number())
+        .map(|p| p.data_frags.as_slice());
+    let info_dst = InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
+    let info_timestamp = if let Some(timestamp) = cache_change.source_timestamp() {
+        InfoTimestampSubmessage::new(false, timestamp.into())
+    } else {
+        InfoTimestampSubmessage::new(true, TIME_INVALID)
+    };
+    let reader_id = reader_proxy.remote_reader_guid().entity_id();
+
+    let mut fragment_numbers = fragment_numbers
+        .into_iter()
+        .filter(|&fragment_number| {
+            (1..=number_of_fragments as FragmentNumber).contains(&fragment_number)
+        })
+        .peekable();
+    while let Some(fragment_number) = fragment_numbers.next() {
+        let frag_index = fragment_number as usize - 1;
+        let heartbeat_frag = fragment_numbers.peek().is_none().then(|| {
+            reader_proxy.heartbeat_frag_machine().submessage(
+                writer_id,
+                cache_change.sequence_number(),
+                number_of_fragments as FragmentNumber,
+            )
+        });
+        let rtps_message = {
+            let prebuilt_data_frag;
+            let data_frag;
+            let data_frag_for_reader: &(dyn Submessage + Send) = match prebuilt_data_frags {
+                Some(prebuilt_data_frags) => {
+                    prebuilt_data_frag = prebuilt_data_frags[frag_index].for_reader(reader_id);
+                    &prebuilt_data_frag
+                }
+                None => {
+                    data_frag = data_frag_submessage(
+                        cache_change,
+                        frag_index,
+                        data_max_size_serialized,
+                        reader_id,
+                        writer_id,
+                    );
+                    &data_frag
+                }
+            };
+            match &heartbeat_frag {
+                Some(heartbeat_frag) => RtpsMessageWrite::from_submessages(
+                    &[
+                        &info_dst,
+                        &info_timestamp,
+                        data_frag_for_reader,
+                        heartbeat_frag,
+                    ],
+                    message_writer.guid_prefix(),
+                ),
+                None => RtpsMessageWrite::from_submessages(
+                    &[&info_dst, &info_timestamp, data_frag_for_reader],
+                    message_writer.guid_prefix(),
+                ),
+            }
+        };
+        message_writer
+            .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+            .await;
+        reader_proxy.add_data_bytes_sent(
+            (cache_change.data_value().len() - frag_index * data_max_size_serialized)
+                .min(data_max_size_serialized),
+        );
+    }
+}
+
+// Builds the GAP submessages for the irrelevant sequence numbers, sorted in ascending order. Each GAP covers a
+// run of consecutive sequence numbers from its gap start up to the base of its gap list and the sequence
+// numbers following the run which fit in the bitmap of the gap list.
+fn gap_submessages(
+    reader_id: EntityId,
+    writer_id: EntityId,
+    irrelevant_seq_nums: &[SequenceNumber],
+) -> Vec<GapSubmessage> {
+    let mut gap_submessages = Vec::new();
+    let mut remaining_seq_nums = irrelevant_seq_nums;
+    while let Some(&gap_start) = remaining_seq_nums.first() {
+        let run_length = remaining_seq_nums
+            .iter()
+            .zip(gap_start..)
+            .take_while(|(seq_num, expected_seq_num)| **seq_num == *expected_seq_num)
+            .count();
+        let base = gap_start + run_length as SequenceNumber;
+        let set_length = remaining_seq_nums[run_length..]
+            .iter()
+            .take_while(|&&seq_num| seq_num < base + 256)
+            .count();
+        gap_submessages.push(GapSubmessage::new(
+            reader_id,
+            writer_id,
+            gap_start,
+            SequenceNumberSet::new(
+                base,
+                remaining_seq_nums[run_length..run_length + set_length]
+                    .iter()
+                    .copied(),
+            ),
+        ));
+        remaining_seq_nums = &remaining_seq_nums[run_length + set_length..];
+    }
+    gap_submessages
+}
+
+fn notify_gapped_changes(
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    gap_start_sequence_number: SequenceNumber,
+    gap_end_sequence_number: SequenceNumber,
+    changes: WriterChanges<'_>,
+    reader_guid: Guid,
+) {
+    if change_lifecycle_observer.is_some() {
+        for sequence_number in gap_start_sequence_number..=gap_end_sequence_number {
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Gapped,
+                sequence_number,
+                changes,
+                Some(reader_guid),
+            );
+        }
+    }
+}
+
+fn share_message_stream(reader_proxy: &RtpsReaderProxy, other: &RtpsReaderProxy) -> bool {
+    reader_proxy.remote_reader_guid().prefix() == other.remote_reader_guid().prefix()
+        && reader_proxy.unicast_locator_list() == other.unicast_locator_list()
+        && reader_proxy.reliability() == other.reliability()
+        && reader_proxy.durability() == other.durability()
+        && reader_proxy.expects_inline_qos() == other.expects_inline_qos()
+        && reader_proxy.highest_sent_seq_num() == other.highest_sent_seq_num()
+        && reader_proxy.first_relevant_sample_seq_num() == other.first_relevant_sample_seq_num()
+}
+
+// Sends the DATA submessage of the change or its DATA_FRAG submessages if it does not fit in one
+#[allow(clippy::too_many_arguments)]
+async fn write_change_data(
+    cache_change: &CacheChange,
+    reader_guid_prefix: GuidPrefix,
+    reader_id: EntityId,
+    writer_id: EntityId,
+    data_max_size_serialized: usize,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
+    locator_list: &[Locator],
+    message_writer: &impl WriteMessage,
+) {
+    let info_dst = InfoDestinationSubmessage::new(reader_guid_prefix);
+    let info_timestamp = if let Some(timestamp) = cache_change.source_timestamp() {
+        InfoTimestampSubmessage::new(false, timestamp.into())
+    } else {
+        InfoTimestampSubmessage::new(true, TIME_INVALID)
+    };
+
+    let number_of_fragments = cache_change
+        .data_value()
+        .len()
+        .div_ceil(data_max_size_serialized);
+
+    if number_of_fragments > 1 {
+        let prebuilt_data_frags = prebuilt_fragments
+            .iter()
+            .find(|p| p.sequence_number == cache_change.sequence_number())
+            .map(|p| p.data_frags.as_slice());
+        for frag_index in 0..number_of_fragments {
+            let rtps_message = match prebuilt_data_frags {
+                Some(prebuilt_data_frags) => RtpsMessageWrite::from_submessages(
+                    &[
+                        &info_dst,
+                        &info_timestamp,
+                        &prebuilt_data_frags[frag_index].for_reader(reader_id),
+                    ],
+                    message_writer.guid_prefix(),
+                ),
+                None => {
+                    let data_frag = data_frag_submessage(
+                        cache_change,
+                        frag_index,
+                        data_max_size_serialized,
+                        reader_id,
+                        writer_id,
+                    );
+                    RtpsMessageWrite::from_submessages(
+                        &[&info_dst, &info_timestamp, &data_frag],
+                        message_writer.guid_prefix(),
+                    )
+                }
+            };
+            message_writer
+                .write_message(rtps_message.buffer(), locator_list)
+                .await;
+        }
+    } else {
+        let data_submessage = cache_change.as_data_submessage(reader_id, writer_id);
+        let rtps_message = RtpsMessageWrite::from_submessages(
+            &[&info_dst, &info_timestamp, &data_submessage],
+            message_writer.guid_prefix(),
+        );
+        message_writer
+            .write_message(rtps_message.buffer(), locator_list)
+            .await;
+    }
+}
+
+// Sends the unsent changes once to a group of readers sharing the same message stream using
+// ENTITYID_UNKNOWN as reader id. The heartbeats of reliable readers are still sent to
+// each of the readers since they are counted per reader.
+#[allow(clippy::too_many_arguments)]
+async fn write_message_to_reader_proxy_group(
+    reader_proxy_group: &mut [&mut RtpsReaderProxy],
+    writer_id: EntityId,
+    changes: WriterChanges<'_>,
+    data_max_size_serialized: usize,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
+    message_writer: &impl WriteMessage,
+    clock: &impl Clock,
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    send_budget: &mut usize,
+) {
+    let Some(first_reader_proxy) = reader_proxy_group.first() else {
+        return;
+    };
+    if !first_reader_proxy.unsent_changes(changes) {
+        return;
+    }
+    let reader_guid_prefix = first_reader_proxy.remote_reader_guid().prefix();
+    let reliability = first_reader_proxy.reliability();
+    let first_relevant_sample_seq_num = first_reader_proxy.first_relevant_sample_seq_num();
+    let locator_list = first_reader_proxy.unicast_locator_list().to_vec();
+    let info_dst = InfoDestinationSubmessage::new(reader_guid_prefix);
+    let now = clock.now();
+
+    while *send_budget > 0 {
+        let Some(next_unsent_change_seq_num) = reader_proxy_group[0].next_unsent_change(changes)
+        else {
+            break;
+        };
+        let highest_sent_seq_num = reader_proxy_group[0].highest_sent_seq_num();
+        let cache_change = changes.get_change(next_unsent_change_seq_num).filter(|_| {
+            reliability == ReliabilityKind::BestEffort
+                || next_unsent_change_seq_num > first_relevant_sample_seq_num
+        });
+        // The changes written before the readers were matched are irrelevant to them so they are
+        // communicated in the same GAP as the removed changes preceding them
+        let gap_end_sequence_number = if cache_change.is_some() {
+            next_unsent_change_seq_num - 1
+        } else {
+            first_relevant_sample_seq_num
+        };
+        if gap_end_sequence_number > highest_sent_seq_num {
+            let gap_submessage = GapSubmessage::new(
+                ENTITYID_UNKNOWN,
+                writer_id,
+                highest_sent_seq_num + 1,
+                SequenceNumberSet::new(gap_end_sequence_number + 1, []),
+            );
+            let rtps_message = RtpsMessageWrite::from_submessages(
+                &[&info_dst, &gap_submessage],
+                message_writer.guid_prefix(),
+            );
+            message_writer
+                .write_message(rtps_message.buffer(), &locator_list)
+                .await;
+            for reader_proxy in reader_proxy_group.iter() {
+                notify_gapped_changes(
+                    change_lifecycle_observer,
+                    highest_sent_seq_num + 1,
+                    gap_end_sequence_number,
+                    changes,
+                    reader_proxy.remote_reader_guid(),
+                );
+            }
+        }
+
+        if let Some(cache_change) = cache_change {
+            write_change_data(
+                cache_change,
+                reader_guid_prefix,
+                ENTITYID_UNKNOWN,
+                writer_id,
+                data_max_size_serialized,
+                prebuilt_fragments,
+                &locator_list,
+                message_writer,
+            )
+            .await;
+            for reader_proxy in reader_proxy_group.iter_mut() {
+                reader_proxy.set_change_sent_time(next_unsent_change_seq_num, now);
+                reader_proxy.add_data_bytes_sent(cache_change.data_value().len());
+                notify_change_event(
+                    change_lifecycle_observer,
+                    ChangeLifecycleEventKind::Sent,
+                    next_unsent_change_seq_num,
+                    changes,
+                    Some(reader_proxy.remote_reader_guid()),
+                );
+            }
+        }
+
+        for reader_proxy in reader_proxy_group.iter_mut() {
+            reader_proxy
+                .set_highest_sent_seq_num(next_unsent_change_seq_num.max(gap_end_sequence_number));
+        }
+        spend_send_budget(send_budget, changes, next_unsent_change_seq_num);
+    }
+
+    if reliability == ReliabilityKind::Reliable {
+        let first_sn = changes.seq_num_min().unwrap_or(1);
+        let last_sn = changes.seq_num_max().unwrap_or(0);
+        for reader_proxy in reader_proxy_group.iter_mut() {
+            let heartbeat_submessage = reader_proxy
+                .heartbeat_machine()
+                .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
+            let rtps_message = RtpsMessageWrite::from_submessages(
+                &[&info_dst, &heartbeat_submessage],
+                message_writer.guid_prefix(),
+            );
+            message_writer
+                .write_message(rtps_message.buffer(), &locator_list)
+                .await;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn write_message_to_reader_proxy_best_effort(
     reader_proxy: &mut RtpsReaderProxy,
     writer_id: EntityId,
-    changes: &[CacheChange],
+    changes: WriterChanges<'_>,
     data_max_size_serialized: usize,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
     message_writer: &impl WriteMessage,
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    send_budget: &mut usize,
 ) {
     // a_change_seq_num := the_reader_proxy.next_unsent_change();
     // if ( a_change_seq_num > the_reader_proxy.higuest_sent_seq_num +1 ) {
@@ -285,7 +1132,10 @@ async fn write_message_to_reader_proxy_best_effort(
     //      send GAP;
     // }
     // the_reader_proxy.higuest_sent_seq_num := a_change_seq_num;
-    while let Some(next_unsent_change_seq_num) = reader_proxy.next_unsent_change(changes.iter()) {
+    while *send_budget > 0 {
+        let Some(next_unsent_change_seq_num) = reader_proxy.next_unsent_change(changes) else {
+            break;
+        };
         if next_unsent_change_seq_num > reader_proxy.highest_sent_seq_num() + 1 {
             let gap_start_sequence_number = reader_proxy.highest_sent_seq_num() + 1;
             let gap_end_sequence_number = next_unsent_change_seq_num - 1;
@@ -302,97 +1152,35 @@ async fn write_message_to_reader_proxy_best_effort(
             message_writer
                 .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
                 .await;
+            notify_gapped_changes(
+                change_lifecycle_observer,
+                gap_start_sequence_number,
+                gap_end_sequence_number,
+                changes,
+                reader_proxy.remote_reader_guid(),
+            );
+        }
 
-            reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
-        } else if let Some(cache_change) = changes
-            .iter()
-            .find(|cc| cc.sequence_number() == next_unsent_change_seq_num)
-        {
-            let number_of_fragments = cache_change
-                .data_value()
-                .len()
-                .div_ceil(data_max_size_serialized);
-
-            // Either send a DATAFRAG submessages or send a single DATA submessage
-            if number_of_fragments > 1 {
-                for frag_index in 0..number_of_fragments {
-                    let info_dst =
-                        InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
-
-                    let info_timestamp = if let Some(timestamp) = cache_change.source_timestamp() {
-                        InfoTimestampSubmessage::new(false, timestamp.into())
-                    } else {
-                        InfoTimestampSubmessage::new(true, TIME_INVALID)
-                    };
-
-                    let inline_qos_flag = true;
-                    let key_flag = match cache_change.kind() {
-                        ChangeKind::Alive => false,
-                        ChangeKind::NotAliveDisposed | ChangeKind::NotAliveUnregistered => true,
-                        _ => todo!(),
-                    };
-                    let non_standard_payload_flag = false;
-                    let reader_id = reader_proxy.remote_reader_guid().entity_id();
-                    let writer_sn = cache_change.sequence_number();
-                    let fragment_starting_num = (frag_index + 1) as u32;
-                    let fragments_in_submessage = 1;
-                    let fragment_size = data_max_size_serialized as u16;
-                    let data_size = cache_change.data_value().len() as u32;
-
-                    let start = frag_index * data_max_size_serialized;
-                    let end = core::cmp::min(
-                        (frag_index + 1) * data_max_size_serialized,
-                        cache_change.data_value().len(),
-                    );
-
-                    let serialized_payload = SerializedDataFragment::new(
-                        cache_change.data_value().clone().into(),
-                        start..end,
-                    );
-
-                    let data_frag = DataFragSubmessage::new(
-                        inline_qos_flag,
-                        non_standard_payload_flag,
-                        key_flag,
-                        reader_id,
-                        writer_id,
-                        writer_sn,
-                        fragment_starting_num,
-                        fragments_in_submessage,
-                        fragment_size,
-                        data_size,
-                        ParameterList::new(Vec::new()),
-                        serialized_payload,
-                    );
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&info_dst, &info_timestamp, &data_frag],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
-                        .await;
-                }
-            } else {
-                let info_dst =
-                    InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
-
-                let info_timestamp = if let Some(timestamp) = cache_change.source_timestamp() {
-                    InfoTimestampSubmessage::new(false, timestamp.into())
-                } else {
-                    InfoTimestampSubmessage::new(true, TIME_INVALID)
-                };
-
-                let data_submessage = cache_change
-                    .as_data_submessage(reader_proxy.remote_reader_guid().entity_id(), writer_id);
-
-                let rtps_message = RtpsMessageWrite::from_submessages(
-                    &[&info_dst, &info_timestamp, &data_submessage],
-                    message_writer.guid_prefix(),
-                );
-                message_writer
-                    .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
-                    .await;
-            }
+        if let Some(cache_change) = changes.get_change(next_unsent_change_seq_num) {
+            write_change_data(
+                cache_change,
+                reader_proxy.remote_reader_guid().prefix(),
+                reader_proxy.remote_reader_guid().entity_id(),
+                writer_id,
+                data_max_size_serialized,
+                prebuilt_fragments,
+                reader_proxy.unicast_locator_list(),
+                message_writer,
+            )
+            .await;
+            reader_proxy.add_data_bytes_sent(cache_change.data_value().len());
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Sent,
+                next_unsent_change_seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
         } else {
             let gap_submessage = GapSubmessage::new(
                 ENTITYID_UNKNOWN,
@@ -407,9 +1195,17 @@ async fn write_message_to_reader_proxy_best_effort(
             message_writer
                 .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
                 .await;
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Gapped,
+                next_unsent_change_seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
         }
 
         reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
+        spend_send_budget(send_budget, changes, next_unsent_change_seq_num);
     }
 }
 
@@ -417,30 +1213,41 @@ async fn write_message_to_reader_proxy_best_effort(
 async fn write_message_to_reader_proxy_reliable(
     reader_proxy: &mut RtpsReaderProxy,
     writer_id: EntityId,
-    changes: &[CacheChange],
-    seq_num_min: Option<SequenceNumber>,
-    seq_num_max: Option<SequenceNumber>,
+    changes: WriterChanges<'_>,
     data_max_size_serialized: usize,
-    heartbeat_period: Duration,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
+    heartbeat_period: core::time::Duration,
+    samples_per_heartbeat: Option<usize>,
     message_writer: &impl WriteMessage,
     clock: &impl Clock,
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    send_budget: &mut usize,
 ) {
     let now = clock.now();
     // Top part of the state machine - Figure 8.19 RTPS standard
-    if reader_proxy.unsent_changes(changes.iter()) {
-        while let Some(next_unsent_change_seq_num) = reader_proxy.next_unsent_change(changes.iter())
-        {
-            if next_unsent_change_seq_num > reader_proxy.highest_sent_seq_num() + 1 {
-                let gap_start_sequence_number = reader_proxy.highest_sent_seq_num() + 1;
-                let gap_end_sequence_number = next_unsent_change_seq_num - 1;
+    if reader_proxy.unsent_changes(changes) {
+        while *send_budget > 0 {
+            let Some(next_unsent_change_seq_num) = reader_proxy.next_unsent_change(changes) else {
+                break;
+            };
+            let gap_start_sequence_number = reader_proxy.highest_sent_seq_num() + 1;
+            // The changes written before the reader was matched are irrelevant to it so they are
+            // communicated in the same GAP as the removed changes preceding them
+            let gap_end_sequence_number =
+                if next_unsent_change_seq_num <= reader_proxy.first_relevant_sample_seq_num() {
+                    reader_proxy.first_relevant_sample_seq_num()
+                } else {
+                    next_unsent_change_seq_num - 1
+                };
+            if gap_end_sequence_number >= gap_start_sequence_number {
                 let gap_submessage = GapSubmessage::new(
                     reader_proxy.remote_reader_guid().entity_id(),
                     writer_id,
                     gap_start_sequence_number,
                     SequenceNumberSet::new(gap_end_sequence_number + 1, []),
                 );
-                let first_sn = seq_num_min.unwrap_or(1);
-                let last_sn = seq_num_max.unwrap_or(0);
+                let first_sn = changes.seq_num_min().unwrap_or(1);
+                let last_sn = changes.seq_num_max().unwrap_or(0);
                 let heartbeat_submessage = reader_proxy
                     .heartbeat_machine()
                     .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
@@ -453,31 +1260,48 @@ async fn write_message_to_reader_proxy_reliable(
                 message_writer
                     .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
                     .await;
+                notify_gapped_changes(
+                    change_lifecycle_observer,
+                    gap_start_sequence_number,
+                    gap_end_sequence_number,
+                    changes,
+                    reader_proxy.remote_reader_guid(),
+                );
             } else {
+                let is_last_sample = changes
+                    .iter_after(next_unsent_change_seq_num)
+                    .next()
+                    .is_none();
+                let piggyback_heartbeat =
+                    reader_proxy.piggyback_heartbeat(samples_per_heartbeat, is_last_sample);
                 write_change_message_reader_proxy_reliable(
                     reader_proxy,
                     writer_id,
                     changes,
-                    seq_num_min,
-                    seq_num_max,
                     data_max_size_serialized,
+                    prebuilt_fragments,
                     next_unsent_change_seq_num,
+                    piggyback_heartbeat,
                     message_writer,
                     clock,
+                    change_lifecycle_observer,
+                    ChangeLifecycleEventKind::Sent,
                 )
                 .await;
             }
-            reader_proxy.set_highest_sent_seq_num(next_unsent_change_seq_num);
+            reader_proxy
+                .set_highest_sent_seq_num(next_unsent_change_seq_num.max(gap_end_sequence_number));
+            spend_send_budget(send_budget, changes, next_unsent_change_seq_num);
         }
-    } else if !reader_proxy.unacked_changes(seq_num_max) {
+    } else if !reader_proxy.unacked_changes(changes.seq_num_max()) {
         // Idle
         if reader_proxy
             .heartbeat_machine()
-            .is_time_for_heartbeat(now, heartbeat_period.into())
+            .is_time_for_heartbeat(now, heartbeat_period)
             && reader_proxy.durability() != DurabilityKind::Volatile
         {
-            let first_sn = seq_num_min.unwrap_or(1);
-            let last_sn = seq_num_max.unwrap_or(0);
+            let first_sn = changes.seq_num_min().unwrap_or(1);
+            let last_sn = changes.seq_num_max().unwrap_or(0);
             let heartbeat_submessage = reader_proxy
                 .heartbeat_machine()
                 .generate_new_heartbeat(writer_id, first_sn, last_sn, now, true);
@@ -495,10 +1319,10 @@ async fn write_message_to_reader_proxy_reliable(
         }
     } else if reader_proxy
         .heartbeat_machine()
-        .is_time_for_heartbeat(now, heartbeat_period.into())
+        .is_time_for_heartbeat(now, heartbeat_period)
     {
-        let first_sn = seq_num_min.unwrap_or(1);
-        let last_sn = seq_num_max.unwrap_or(0);
+        let first_sn = changes.seq_num_min().unwrap_or(1);
+        let last_sn = changes.seq_num_max().unwrap_or(0);
         let heartbeat_submessage = reader_proxy
             .heartbeat_machine()
             .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
@@ -515,25 +1339,108 @@ async fn write_message_to_reader_proxy_reliable(
     }
 
     // Middle-part of the state-machine - Figure 8.19 RTPS standard
-    if !reader_proxy.requested_changes().is_empty() {
-        while let Some(next_requested_change_seq_num) = reader_proxy.next_requested_change() {
+    // The requested changes are repaired once the nack response delay has elapsed
+    if (!reader_proxy.requested_changes().is_empty() || reader_proxy.has_requested_fragments())
+        && reader_proxy.is_time_for_repair(now)
+    {
+        let is_relevant_change = |seq_num: SequenceNumber, first_relevant_sample_seq_num| {
+            seq_num > first_relevant_sample_seq_num && changes.get_change(seq_num).is_some()
+        };
+        // The requested changes which are irrelevant to the reader are communicated together once the
+        // relevant ones are repaired
+        let mut irrelevant_seq_nums = Vec::new();
+        while *send_budget > 0 {
+            let Some(next_requested_change_seq_num) = reader_proxy.next_requested_change() else {
+                break;
+            };
             // "a_change.status := UNDERWAY;" should be done by next_requested_change() as
             // it's not done here to avoid the change being a mutable reference
             // Also the post-condition:
             // a_change BELONGS-TO the_reader_proxy.requested_changes() ) == FALSE
             // should be full-filled by next_requested_change()
+            let first_relevant_sample_seq_num = reader_proxy.first_relevant_sample_seq_num();
+            if !is_relevant_change(next_requested_change_seq_num, first_relevant_sample_seq_num) {
+                irrelevant_seq_nums.push(next_requested_change_seq_num);
+                continue;
+            }
+            let is_last_sample = !reader_proxy
+                .requested_changes()
+                .into_iter()
+                .any(|seq_num| is_relevant_change(seq_num, first_relevant_sample_seq_num));
+            let piggyback_heartbeat =
+                reader_proxy.piggyback_heartbeat(samples_per_heartbeat, is_last_sample);
             write_change_message_reader_proxy_reliable(
                 reader_proxy,
                 writer_id,
                 changes,
-                seq_num_min,
-                seq_num_max,
                 data_max_size_serialized,
+                prebuilt_fragments,
                 next_requested_change_seq_num,
+                piggyback_heartbeat,
                 message_writer,
                 clock,
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Retransmitted,
+            )
+            .await;
+            spend_send_budget(send_budget, changes, next_requested_change_seq_num);
+        }
+        // Only the fragments missing to the reader are sent for the changes it has partially received
+        while *send_budget > 0 {
+            let Some((seq_num, fragment_numbers)) = reader_proxy.next_requested_fragments() else {
+                break;
+            };
+            let first_relevant_sample_seq_num = reader_proxy.first_relevant_sample_seq_num();
+            let Some(cache_change) = changes
+                .get_change(seq_num)
+                .filter(|_| seq_num > first_relevant_sample_seq_num)
+            else {
+                irrelevant_seq_nums.push(seq_num);
+                continue;
+            };
+            reader_proxy.set_change_sent_time(seq_num, now);
+            write_fragments_reader_proxy_reliable(
+                reader_proxy,
+                writer_id,
+                cache_change,
+                fragment_numbers,
+                data_max_size_serialized,
+                prebuilt_fragments,
+                message_writer,
             )
             .await;
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Retransmitted,
+                seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
+            spend_send_budget(send_budget, changes, seq_num);
+        }
+        irrelevant_seq_nums.sort_unstable();
+        let info_dst = InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
+        for gap_submessage in gap_submessages(ENTITYID_UNKNOWN, writer_id, &irrelevant_seq_nums) {
+            let rtps_message = RtpsMessageWrite::from_submessages(
+                &[&info_dst, &gap_submessage],
+                message_writer.guid_prefix(),
+            );
+            message_writer
+                .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
+                .await;
+        }
+        for &seq_num in &irrelevant_seq_nums {
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Gapped,
+                seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
+        }
+        // The repair goes on in the next round when the budget ran out before all the requests were answered
+        if reader_proxy.requested_changes().is_empty() && !reader_proxy.has_requested_fragments() {
+            reader_proxy.repair_done();
         }
     }
 }
@@ -542,20 +1449,20 @@ async fn write_message_to_reader_proxy_reliable(
 async fn write_change_message_reader_proxy_reliable(
     reader_proxy: &mut RtpsReaderProxy,
     writer_id: EntityId,
-    changes: &[CacheChange],
-    seq_num_min: Option<SequenceNumber>,
-    seq_num_max: Option<SequenceNumber>,
+    changes: WriterChanges<'_>,
     data_max_size_serialized: usize,
+    prebuilt_fragments: &[PrebuiltChangeFragments],
     change_seq_num: SequenceNumber,
+    piggyback_heartbeat: bool,
     message_writer: &impl WriteMessage,
     clock: &impl Clock,
+    change_lifecycle_observer: Option<&dyn ChangeLifecycleObserver>,
+    change_event_kind: ChangeLifecycleEventKind,
 ) {
     let now = clock.now();
-    match changes
-        .iter()
-        .find(|cc| cc.sequence_number() == change_seq_num)
-    {
+    match changes.get_change(change_seq_num) {
         Some(cache_change) if change_seq_num > reader_proxy.first_relevant_sample_seq_num() => {
+            reader_proxy.set_change_sent_time(change_seq_num, now);
             let number_of_fragments = cache_change
                 .data_value()
                 .len()
@@ -563,64 +1470,16 @@ async fn write_change_message_reader_proxy_reliable(
 
             // Either send a DATAFRAG submessages or send a single DATA submessage
             if number_of_fragments > 1 {
-                for frag_index in 0..number_of_fragments {
-                    let info_dst =
-                        InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
-
-                    let info_timestamp = if let Some(timestamp) = cache_change.source_timestamp() {
-                        InfoTimestampSubmessage::new(false, timestamp.into())
-                    } else {
-                        InfoTimestampSubmessage::new(true, TIME_INVALID)
-                    };
-
-                    let inline_qos_flag = true;
-                    let key_flag = match cache_change.kind() {
-                        ChangeKind::Alive => false,
-                        ChangeKind::NotAliveDisposed | ChangeKind::NotAliveUnregistered => true,
-                        _ => todo!(),
-                    };
-                    let non_standard_payload_flag = false;
-                    let reader_id = reader_proxy.remote_reader_guid().entity_id();
-                    let writer_sn = cache_change.sequence_number();
-                    let fragment_starting_num = (frag_index + 1) as u32;
-                    let fragments_in_submessage = 1;
-                    let fragment_size = data_max_size_serialized as u16;
-                    let data_size = cache_change.data_value().len() as u32;
-
-                    let start = frag_index * data_max_size_serialized;
-                    let end = core::cmp::min(
-                        (frag_index + 1) * data_max_size_serialized,
-                        cache_change.data_value().len(),
-                    );
-
-                    let serialized_payload = SerializedDataFragment::new(
-                        cache_change.data_value().clone().into(),
-                        start..end,
-                    );
-
-                    let data_frag = DataFragSubmessage::new(
-                        inline_qos_flag,
-                        non_standard_payload_flag,
-                        key_flag,
-                        reader_id,
-                        writer_id,
-                        writer_sn,
-                        fragment_starting_num,
-                        fragments_in_submessage,
-                        fragment_size,
-                        data_size,
-                        ParameterList::new(Vec::new()),
-                        serialized_payload,
-                    );
-
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&info_dst, &info_timestamp, &data_frag],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
-                        .await;
-                }
+                write_fragments_reader_proxy_reliable(
+                    reader_proxy,
+                    writer_id,
+                    cache_change,
+                    1..=number_of_fragments as FragmentNumber,
+                    data_max_size_serialized,
+                    prebuilt_fragments,
+                    message_writer,
+                )
+                .await;
             } else {
                 let info_dst =
                     InfoDestinationSubmessage::new(reader_proxy.remote_reader_guid().prefix());
@@ -634,20 +1493,34 @@ async fn write_change_message_reader_proxy_reliable(
                 let data_submessage = cache_change
                     .as_data_submessage(reader_proxy.remote_reader_guid().entity_id(), writer_id);
 
-                let first_sn = seq_num_min.unwrap_or(1);
-                let last_sn = seq_num_max.unwrap_or(0);
-                let heartbeat = reader_proxy
-                    .heartbeat_machine()
-                    .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
-
-                let rtps_message = RtpsMessageWrite::from_submessages(
-                    &[&info_dst, &info_timestamp, &data_submessage, &heartbeat],
-                    message_writer.guid_prefix(),
-                );
+                let rtps_message = if piggyback_heartbeat {
+                    let first_sn = changes.seq_num_min().unwrap_or(1);
+                    let last_sn = changes.seq_num_max().unwrap_or(0);
+                    let heartbeat = reader_proxy
+                        .heartbeat_machine()
+                        .generate_new_heartbeat(writer_id, first_sn, last_sn, now, false);
+                    RtpsMessageWrite::from_submessages(
+                        &[&info_dst, &info_timestamp, &data_submessage, &heartbeat],
+                        message_writer.guid_prefix(),
+                    )
+                } else {
+                    RtpsMessageWrite::from_submessages(
+                        &[&info_dst, &info_timestamp, &data_submessage],
+                        message_writer.guid_prefix(),
+                    )
+                };
                 message_writer
                     .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
                     .await;
+                reader_proxy.add_data_bytes_sent(cache_change.data_value().len());
             }
+            notify_change_event(
+                change_lifecycle_observer,
+                change_event_kind,
+                change_seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
         }
         _ => {
             let info_dst =
@@ -667,6 +1540,13 @@ async fn write_change_message_reader_proxy_reliable(
             message_writer
                 .write_message(rtps_message.buffer(), reader_proxy.unicast_locator_list())
                 .await;
+            notify_change_event(
+                change_lifecycle_observer,
+                ChangeLifecycleEventKind::Gapped,
+                change_seq_num,
+                changes,
+                Some(reader_proxy.remote_reader_guid()),
+            );
         }
     }
 }
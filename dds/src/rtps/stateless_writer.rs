@@ -56,51 +56,74 @@ impl RtpsStatelessWriter {
     }
 
     pub async fn write_message(&mut self, message_writer: &impl WriteMessage) {
-        for reader_locator in &mut self.reader_locators {
-            while let Some(unsent_change_seq_num) =
-                reader_locator.next_unsent_change(self.changes.iter())
+        // Each unsent change is serialized once and sent at once to all the reader locators which did not
+        // receive it yet, starting with the oldest one
+        loop {
+            let mut unsent_change_seq_num = None;
+            let mut locator_list = Vec::new();
+            for reader_locator in &mut self.reader_locators {
+                let Some(seq_num) = reader_locator.next_unsent_change(self.changes.iter()) else {
+                    continue;
+                };
+                match unsent_change_seq_num {
+                    Some(min_seq_num) if seq_num > min_seq_num => (),
+                    Some(min_seq_num) if seq_num == min_seq_num => {
+                        locator_list.push(reader_locator.locator())
+                    }
+                    _ => {
+                        unsent_change_seq_num = Some(seq_num);
+                        locator_list.clear();
+                        locator_list.push(reader_locator.locator());
+                    }
+                }
+            }
+            let Some(unsent_change_seq_num) = unsent_change_seq_num else {
+                break;
+            };
+
+            if let Some(cache_change) = self
+                .changes
+                .iter()
+                .find(|cc| cc.sequence_number() == unsent_change_seq_num)
             {
-                // The post-condition:
-                // "( a_change BELONGS-TO the_reader_locator.unsent_changes() ) == FALSE"
-                // should be full-filled by next_unsent_change()
+                let info_ts_submessage = cache_change
+                    .source_timestamp()
+                    .map_or(InfoTimestampSubmessage::new(true, TIME_INVALID), |t| {
+                        InfoTimestampSubmessage::new(false, t.into())
+                    });
 
-                if let Some(cache_change) = self
-                    .changes
-                    .iter()
-                    .find(|cc| cc.sequence_number() == unsent_change_seq_num)
-                {
-                    let info_ts_submessage = cache_change
-                        .source_timestamp()
-                        .map_or(InfoTimestampSubmessage::new(true, TIME_INVALID), |t| {
-                            InfoTimestampSubmessage::new(false, t.into())
-                        });
+                let data_submessage =
+                    cache_change.as_data_submessage(ENTITYID_UNKNOWN, self.guid.entity_id());
 
-                    let data_submessage =
-                        cache_change.as_data_submessage(ENTITYID_UNKNOWN, self.guid.entity_id());
+                let rtps_message = RtpsMessageWrite::from_submessages(
+                    &[&info_ts_submessage, &data_submessage],
+                    message_writer.guid_prefix(),
+                );
+                message_writer
+                    .write_message(rtps_message.buffer(), &locator_list)
+                    .await;
+            } else {
+                let gap_submessage = GapSubmessage::new(
+                    ENTITYID_UNKNOWN,
+                    self.guid.entity_id(),
+                    unsent_change_seq_num,
+                    SequenceNumberSet::new(unsent_change_seq_num + 1, []),
+                );
+                let rtps_message = RtpsMessageWrite::from_submessages(
+                    &[&gap_submessage],
+                    message_writer.guid_prefix(),
+                );
+                message_writer
+                    .write_message(rtps_message.buffer(), &locator_list)
+                    .await;
+            }
 
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&info_ts_submessage, &data_submessage],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), &[reader_locator.locator()])
-                        .await;
-                } else {
-                    let gap_submessage = GapSubmessage::new(
-                        ENTITYID_UNKNOWN,
-                        self.guid.entity_id(),
-                        unsent_change_seq_num,
-                        SequenceNumberSet::new(unsent_change_seq_num + 1, []),
-                    );
-                    let rtps_message = RtpsMessageWrite::from_submessages(
-                        &[&gap_submessage],
-                        message_writer.guid_prefix(),
-                    );
-                    message_writer
-                        .write_message(rtps_message.buffer(), &[reader_locator.locator()])
-                        .await;
+            // The post-condition:
+            // "( a_change BELONGS-TO the_reader_locator.unsent_changes() ) == FALSE"
+            for reader_locator in &mut self.reader_locators {
+                if locator_list.contains(&reader_locator.locator()) {
+                    reader_locator.set_highest_sent_change_sn(unsent_change_seq_num);
                 }
-                reader_locator.set_highest_sent_change_sn(unsent_change_seq_num);
             }
         }
     }
@@ -0,0 +1,91 @@
+use crate::transport::{history_cache::CacheChange, types::SequenceNumber};
+use alloc::collections::{vec_deque, TryReserveError, VecDeque};
+
+/// Changes of a writer kept in a ring buffer ordered by sequence number. The changes are found by
+/// binary search and the lowest and highest sequence numbers are read from the ends of the buffer.
+#[derive(Default)]
+pub struct WriterHistoryCache {
+    changes: VecDeque<CacheChange>,
+}
+
+impl WriterHistoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.changes.try_reserve_exact(additional)
+    }
+
+    pub fn add_change(&mut self, cache_change: CacheChange) {
+        // The changes are added in increasing order of sequence number so this is a push to the back
+        let index = self
+            .changes
+            .partition_point(|cc| cc.sequence_number() < cache_change.sequence_number());
+        self.changes.insert(index, cache_change);
+    }
+
+    pub fn remove_change(&mut self, sequence_number: SequenceNumber) {
+        if let Ok(index) = self
+            .changes
+            .binary_search_by_key(&sequence_number, |cc| cc.sequence_number())
+        {
+            self.changes.remove(index);
+        }
+    }
+
+    pub fn changes(&self) -> WriterChanges<'_> {
+        WriterChanges {
+            changes: &self.changes,
+            len: self.changes.len(),
+        }
+    }
+
+    /// Changes with a sequence number lower than or equal to the given one.
+    pub fn changes_up_to(&self, sequence_number: SequenceNumber) -> WriterChanges<'_> {
+        WriterChanges {
+            changes: &self.changes,
+            len: self
+                .changes
+                .partition_point(|cc| cc.sequence_number() <= sequence_number),
+        }
+    }
+}
+
+/// Changes at the start of a [`WriterHistoryCache`], ordered by sequence number.
+#[derive(Clone, Copy)]
+pub struct WriterChanges<'a> {
+    changes: &'a VecDeque<CacheChange>,
+    len: usize,
+}
+
+impl<'a> WriterChanges<'a> {
+    pub fn iter(self) -> vec_deque::Iter<'a, CacheChange> {
+        self.changes.range(..self.len)
+    }
+
+    /// Changes with a sequence number greater than the given one in increasing order.
+    pub fn iter_after(self, sequence_number: SequenceNumber) -> vec_deque::Iter<'a, CacheChange> {
+        let start = self
+            .changes
+            .partition_point(|cc| cc.sequence_number() <= sequence_number)
+            .min(self.len);
+        self.changes.range(start..self.len)
+    }
+
+    pub fn get_change(self, sequence_number: SequenceNumber) -> Option<&'a CacheChange> {
+        self.changes
+            .binary_search_by_key(&sequence_number, |cc| cc.sequence_number())
+            .ok()
+            .filter(|&index| index < self.len)
+            .map(|index| &self.changes[index])
+    }
+
+    pub fn seq_num_min(self) -> Option<SequenceNumber> {
+        self.iter().next().map(|cc| cc.sequence_number())
+    }
+
+    pub fn seq_num_max(self) -> Option<SequenceNumber> {
+        self.iter().next_back().map(|cc| cc.sequence_number())
+    }
+}
@@ -7,19 +7,107 @@ use crate::{
             ack_nack::AckNackSubmessage, data::DataSubmessage, data_frag::DataFragSubmessage,
             info_destination::InfoDestinationSubmessage, nack_frag::NackFragSubmessage,
         },
-        types::Count,
+        types::{Count, FragmentNumber, Time},
     },
     transport::types::{EntityId, Guid, Locator, ReliabilityKind, SequenceNumber},
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{sync::Arc, vec, vec::Vec};
 
 use core::cmp::max;
 
-fn total_fragments_expected(data_frag_submessage: &DataFragSubmessage) -> u32 {
-    let data_size = data_frag_submessage.data_size();
-    let fragment_size = data_frag_submessage.fragment_size() as u32;
-    let total_fragments_correction = if data_size % fragment_size == 0 { 0 } else { 1 };
-    data_size / fragment_size + total_fragments_correction
+// Fragments received of a change which is not complete yet. The DATA_FRAG submessages are kept sorted by
+// their starting fragment number and the ones only carrying fragments received before are discarded.
+#[derive(Debug, PartialEq, Eq)]
+struct FragmentedChange {
+    writer_sn: SequenceNumber,
+    source_timestamp: Option<Time>,
+    last_fragment_time: core::time::Duration,
+    fragments: Vec<DataFragSubmessage>,
+}
+
+impl FragmentedChange {
+    fn total_fragments(&self) -> u32 {
+        let first_fragment = &self.fragments[0];
+        let fragment_size = first_fragment.fragment_size() as u32;
+        first_fragment.data_size().div_ceil(fragment_size)
+    }
+
+    fn is_consistent(&self, data_frag_submessage: &DataFragSubmessage) -> bool {
+        let first_fragment = &self.fragments[0];
+        data_frag_submessage.data_size() == first_fragment.data_size()
+            && data_frag_submessage.fragment_size() == first_fragment.fragment_size()
+    }
+
+    fn is_fragment_received(&self, fragment_number: FragmentNumber) -> bool {
+        self.fragments.iter().any(|f| {
+            (f.fragment_starting_num()
+                ..f.fragment_starting_num() + f.fragments_in_submessage() as u32)
+                .contains(&fragment_number)
+        })
+    }
+
+    fn missing_fragments(&self) -> impl Iterator<Item = FragmentNumber> + '_ {
+        (1..=self.total_fragments()).filter(|&n| !self.is_fragment_received(n))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.missing_fragments().next().is_none()
+    }
+
+    fn push(&mut self, data_frag_submessage: DataFragSubmessage, now: core::time::Duration) {
+        let fragment_starting_num = data_frag_submessage.fragment_starting_num();
+        let fragment_range = fragment_starting_num
+            ..fragment_starting_num + data_frag_submessage.fragments_in_submessage() as u32;
+        if fragment_starting_num == 0
+            || fragment_range.is_empty()
+            || !self.is_consistent(&data_frag_submessage)
+            || fragment_range.clone().all(|n| self.is_fragment_received(n))
+        {
+            return;
+        }
+        let index = self
+            .fragments
+            .partition_point(|f| f.fragment_starting_num() <= fragment_starting_num);
+        self.fragments.insert(index, data_frag_submessage);
+        self.last_fragment_time = now;
+    }
+
+    // The fragments may overlap when they are retransmitted grouped differently so only the bytes following the
+    // ones already copied are taken from each of them
+    fn reassemble(&self, writer_id: EntityId) -> Option<DataSubmessage> {
+        let first_fragment = &self.fragments[0];
+        let fragment_size = first_fragment.fragment_size() as usize;
+        let data_size = first_fragment.data_size() as usize;
+        let mut data = Vec::with_capacity(data_size);
+        for fragment in &self.fragments {
+            let fragment_start = (fragment.fragment_starting_num() as usize - 1) * fragment_size;
+            let payload = fragment.serialized_payload().as_ref();
+            if fragment_start > data.len() {
+                return None;
+            }
+            if fragment_start + payload.len() > data.len() {
+                data.extend_from_slice(&payload[data.len() - fragment_start..]);
+            }
+        }
+        if data.len() != data_size {
+            return None;
+        }
+
+        Some(
+            DataSubmessage::new(
+                first_fragment.inline_qos_flag(),
+                !first_fragment.key_flag(),
+                first_fragment.key_flag(),
+                false,
+                first_fragment.reader_id(),
+                writer_id,
+                self.writer_sn,
+                first_fragment.inline_qos().clone(),
+                Data::new(Arc::from(data)),
+            )
+            .with_endianness(*first_fragment.endianness()),
+        )
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -32,11 +120,14 @@ pub struct RtpsWriterProxy {
     last_available_seq_num: SequenceNumber,
     highest_received_change_sn: SequenceNumber,
     must_send_acknacks: bool,
-    last_received_heartbeat_count: Count,
-    last_received_heartbeat_frag_count: Count,
+    acknack_time: core::time::Duration,
+    last_answered_heartbeat_time: Option<core::time::Duration>,
+    heartbeat_received: bool,
+    last_received_heartbeat_count: Option<Count>,
+    last_received_heartbeat_frag_count: Option<Count>,
     acknack_count: Count,
     nack_frag_count: Count,
-    frag_buffer: Vec<DataFragSubmessage>,
+    frag_buffer: Vec<FragmentedChange>,
     reliability: ReliabilityKind,
 }
 
@@ -57,8 +148,11 @@ impl RtpsWriterProxy {
             last_available_seq_num: 0,
             highest_received_change_sn: 0,
             must_send_acknacks: false,
-            last_received_heartbeat_count: 0,
-            last_received_heartbeat_frag_count: 0,
+            acknack_time: core::time::Duration::ZERO,
+            last_answered_heartbeat_time: None,
+            heartbeat_received: false,
+            last_received_heartbeat_count: None,
+            last_received_heartbeat_frag_count: None,
             acknack_count: 0,
             nack_frag_count: 0,
             frag_buffer: Vec::new(),
@@ -66,72 +160,83 @@ impl RtpsWriterProxy {
         }
     }
 
-    pub fn push_data_frag(&mut self, submessage: DataFragSubmessage) {
-        if !self.frag_buffer.contains(&submessage) {
-            self.frag_buffer.push(submessage);
-        }
+    pub fn frag_buffer_memory_usage(&self) -> usize {
+        self.frag_buffer
+            .iter()
+            .flat_map(|c| c.fragments.iter())
+            .map(|f| {
+                core::mem::size_of::<DataFragSubmessage>() + f.serialized_payload().as_ref().len()
+            })
+            .sum()
     }
 
-    pub fn reconstruct_data_from_frag(
-        &mut self,
-        seq_num: SequenceNumber,
-    ) -> Option<DataSubmessage> {
-        let frag_submessage = self.frag_buffer.iter().find(|f| f.writer_sn() == seq_num)?;
-        let total_fragments_expected = total_fragments_expected(frag_submessage);
+    pub fn has_received_fragments(&self, seq_num: SequenceNumber) -> bool {
+        self.frag_buffer.iter().any(|c| c.writer_sn == seq_num)
+    }
 
-        let total_fragments = self
+    // The fragments of the changes which are already received or lost are not kept. The fragments of the
+    // different changes of the writer may be received interleaved and in any order.
+    pub fn push_data_frag(
+        &mut self,
+        submessage: DataFragSubmessage,
+        source_timestamp: Option<Time>,
+        now: core::time::Duration,
+    ) {
+        let writer_sn = submessage.writer_sn();
+        if writer_sn <= self.available_changes_max() {
+            return;
+        }
+        if let Some(fragmented_change) = self
             .frag_buffer
-            .iter()
-            .filter(|f| f.writer_sn() == seq_num)
-            .fold(0, |mut acc, f| {
-                acc += f.fragments_in_submessage() as u32;
-                acc
+            .iter_mut()
+            .find(|c| c.writer_sn == writer_sn)
+        {
+            fragmented_change.push(submessage, now);
+        } else if submessage.fragment_starting_num() > 0
+            && submessage.fragment_size() > 0
+            && submessage.fragments_in_submessage() > 0
+        {
+            self.frag_buffer.push(FragmentedChange {
+                writer_sn,
+                source_timestamp,
+                last_fragment_time: now,
+                fragments: vec![submessage],
             });
+        }
+    }
 
-        if total_fragments == total_fragments_expected {
-            let mut data = Vec::new();
-            for frag_number in 0..=total_fragments {
-                let Some(frag) = self
-                    .frag_buffer
-                    .iter()
-                    .find(|f| f.writer_sn() == seq_num && f.fragment_starting_num() == frag_number)
-                else {
-                    continue;
-                };
-
-                data.extend_from_slice(frag.serialized_payload().as_ref());
-            }
+    // Takes a change whose fragments are all received and which can be made available to the reader. A reliable
+    // reader keeps the complete changes until the preceding ones are received since the changes must be made
+    // available in order.
+    pub fn take_reassembled_change(&mut self) -> Option<(DataSubmessage, Option<Time>)> {
+        let available_changes_max = self.available_changes_max();
+        self.frag_buffer
+            .retain(|c| c.writer_sn > available_changes_max);
+        let index = self
+            .frag_buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| match self.reliability {
+                ReliabilityKind::BestEffort => true,
+                ReliabilityKind::Reliable => c.writer_sn == available_changes_max + 1,
+            })
+            .filter(|(_, c)| c.is_complete())
+            .min_by_key(|(_, c)| c.writer_sn)
+            .map(|(i, _)| i)?;
+        let fragmented_change = self.frag_buffer.remove(index);
+        // A change whose fragments do not add up to its size can only be received again as a whole
+        let data_submessage = fragmented_change.reassemble(self.remote_writer_guid.entity_id())?;
+        Some((data_submessage, fragmented_change.source_timestamp))
+    }
 
-            let frag = self
-                .frag_buffer
-                .iter()
-                .find(|f| f.writer_sn() == seq_num && f.fragment_starting_num() == 1)?;
-
-            let inline_qos_flag = frag.inline_qos_flag();
-            let data_flag = !frag.key_flag();
-            let key_flag = frag.key_flag();
-            let non_standard_payload_flag = false;
-            let writer_id = self.remote_writer_guid.entity_id();
-            let reader_id = frag.reader_id();
-            let writer_sn = seq_num;
-            let inline_qos = frag.inline_qos().clone();
-
-            self.frag_buffer.retain(|f| f.writer_sn() != seq_num);
-
-            Some(DataSubmessage::new(
-                inline_qos_flag,
-                data_flag,
-                key_flag,
-                non_standard_payload_flag,
-                reader_id,
-                writer_id,
-                writer_sn,
-                inline_qos,
-                Data::new(Arc::from(data)),
-            ))
-        } else {
-            None
-        }
+    // Discards the fragments of the changes which did not receive any fragment during the timeout
+    pub fn remove_expired_fragments(
+        &mut self,
+        now: core::time::Duration,
+        fragment_reassembly_timeout: core::time::Duration,
+    ) {
+        self.frag_buffer
+            .retain(|c| now.saturating_sub(c.last_fragment_time) < fragment_reassembly_timeout);
     }
 
     pub fn remote_writer_guid(&self) -> Guid {
@@ -156,6 +261,22 @@ impl RtpsWriterProxy {
         )
     }
 
+    pub fn is_receiving_changes(&self) -> bool {
+        // The changes preceding the first one received from the writer may have been written before the
+        // match so they are only considered lost once the reader has started receiving changes.
+        self.highest_received_change_sn > 0
+    }
+
+    pub fn lost_changes_count(&self, first_available_seq_num: SequenceNumber) -> i64 {
+        if !self.is_receiving_changes() {
+            return 0;
+        }
+        max(
+            first_available_seq_num - 1 - self.available_changes_max(),
+            0,
+        )
+    }
+
     pub fn irrelevant_change_set(&mut self, a_seq_num: SequenceNumber) {
         // This operation modifies the status of a ChangeFromWriter to indicate that the CacheChange with the
         // SequenceNumber_t 'a_seq_num' is irrelevant to the RTPS Reader. Logical action in the virtual machine:
@@ -211,6 +332,15 @@ impl RtpsWriterProxy {
         }
     }
 
+    pub fn reset_changes_from_writer(&mut self) {
+        // Mark all the changes of the writer as UNKNOWN so that the changes still available in the
+        // HistoryCache of the RTPS Writer become MISSING again with the next received heartbeat.
+        // The counts are kept so that the writer keeps accepting the ACKNACK messages of this reader.
+        self.highest_received_change_sn = 0;
+        self.heartbeat_received = false;
+        self.frag_buffer.clear();
+    }
+
     pub fn set_must_send_acknacks(&mut self, must_send_acknacks: bool) {
         self.must_send_acknacks = must_send_acknacks;
     }
@@ -219,19 +349,47 @@ impl RtpsWriterProxy {
         self.must_send_acknacks
     }
 
-    pub fn last_received_heartbeat_count(&self) -> Count {
+    // The ACKNACK is scheduled with the first heartbeat to be answered so that the heartbeats received
+    // before the response delay has elapsed are answered together
+    pub fn schedule_acknack(
+        &mut self,
+        heartbeat_time: core::time::Duration,
+        heartbeat_response_delay: core::time::Duration,
+    ) {
+        if !self.must_send_acknacks {
+            self.acknack_time = heartbeat_time + heartbeat_response_delay;
+        }
+        self.must_send_acknacks = true;
+        self.last_answered_heartbeat_time = Some(heartbeat_time);
+    }
+
+    pub fn is_heartbeat_suppressed(
+        &self,
+        heartbeat_time: core::time::Duration,
+        heartbeat_suppression_duration: core::time::Duration,
+    ) -> bool {
+        self.last_answered_heartbeat_time
+            .is_some_and(|t| heartbeat_time < t + heartbeat_suppression_duration)
+    }
+
+    pub fn last_received_heartbeat_count(&self) -> Option<Count> {
         self.last_received_heartbeat_count
     }
 
     pub fn set_last_received_heartbeat_count(&mut self, last_received_heartbeat_count: Count) {
-        self.last_received_heartbeat_count = last_received_heartbeat_count;
+        self.heartbeat_received = true;
+        self.last_received_heartbeat_count = Some(last_received_heartbeat_count);
+    }
+
+    pub fn last_received_heartbeat_frag_count(&self) -> Option<Count> {
+        self.last_received_heartbeat_frag_count
     }
 
     pub fn set_last_received_heartbeat_frag_count(
         &mut self,
         last_received_heartbeat_frag_count: Count,
     ) {
-        self.last_received_heartbeat_frag_count = last_received_heartbeat_frag_count;
+        self.last_received_heartbeat_frag_count = Some(last_received_heartbeat_frag_count);
     }
 
     pub fn acknack_count(&self) -> Count {
@@ -242,21 +400,32 @@ impl RtpsWriterProxy {
         self.acknack_count = self.acknack_count.wrapping_add(1);
     }
 
-    pub async fn write_message(&mut self, reader_guid: &Guid, message_writer: &impl WriteMessage) {
-        if self.must_send_acknacks() || !self.missing_changes().count() == 0 {
+    pub async fn write_message(
+        &mut self,
+        reader_guid: &Guid,
+        message_writer: &impl WriteMessage,
+        now: core::time::Duration,
+    ) {
+        if (self.must_send_acknacks() || !self.missing_changes().count() == 0)
+            && now >= self.acknack_time
+        {
             self.set_must_send_acknacks(false);
             self.increment_acknack_count();
 
             let info_dst_submessage =
                 InfoDestinationSubmessage::new(self.remote_writer_guid().prefix());
 
+            // The changes which are partially received are not requested as a whole since only their
+            // missing fragments are requested with NACK_FRAG
             let acknack_submessage = AckNackSubmessage::new(
                 true,
                 reader_guid.entity_id(),
                 self.remote_writer_guid().entity_id(),
                 SequenceNumberSet::new(
                     self.available_changes_max() + 1,
-                    self.missing_changes().take(256),
+                    self.missing_changes()
+                        .filter(|&seq_num| !self.has_received_fragments(seq_num))
+                        .take(256),
                 ),
                 self.acknack_count(),
             );
@@ -269,40 +438,22 @@ impl RtpsWriterProxy {
                 .write_message(rtps_message.buffer(), self.unicast_locator_list())
                 .await;
 
-            let mut missing_fragment_seq_num_list: Vec<SequenceNumber> =
-                self.frag_buffer.iter().map(|f| f.writer_sn()).collect();
-            missing_fragment_seq_num_list.sort();
-            missing_fragment_seq_num_list.dedup();
-            for missing_seq_num in missing_fragment_seq_num_list {
-                let Some(missing_seq_num_frag) = self
-                    .frag_buffer
-                    .iter()
-                    .find(|f| f.writer_sn() == missing_seq_num)
-                else {
-                    continue;
-                };
-                let total_fragments_expected = total_fragments_expected(missing_seq_num_frag);
-                let mut missing_fragment_number = Vec::new();
-                for fragment_number in 1..=total_fragments_expected {
-                    if !self.frag_buffer.iter().any(|f| {
-                        f.writer_sn() == missing_seq_num
-                            && (f.fragment_starting_num()
-                                ..f.fragment_starting_num() + f.fragments_in_submessage() as u32)
-                                .contains(&fragment_number)
-                    }) {
-                        missing_fragment_number.push(fragment_number)
-                    }
-                }
+            for fragmented_change in &self.frag_buffer {
+                let missing_seq_num = fragmented_change.writer_sn;
+                let missing_fragment_number: Vec<FragmentNumber> =
+                    fragmented_change.missing_fragments().collect();
 
-                if !missing_fragment_number.is_empty() {
+                if let Some(&base) = missing_fragment_number.first() {
                     self.nack_frag_count = self.nack_frag_count.wrapping_add(1);
                     let nack_frag_submessage = NackFragSubmessage::new(
                         reader_guid.entity_id(),
                         self.remote_writer_guid().entity_id(),
                         missing_seq_num,
                         FragmentNumberSet::new(
-                            missing_fragment_number[0],
-                            missing_fragment_number.into_iter(),
+                            base,
+                            missing_fragment_number
+                                .into_iter()
+                                .take_while(|&fragment_number| fragment_number < base + 256),
                         ),
                         self.nack_frag_count,
                     );
@@ -320,7 +471,6 @@ impl RtpsWriterProxy {
     }
 
     pub fn is_historical_data_received(&self) -> bool {
-        let at_least_one_heartbeat_received = self.last_received_heartbeat_count > 0;
-        at_least_one_heartbeat_received && self.missing_changes().count() == 0
+        self.heartbeat_received && self.missing_changes().count() == 0
     }
 }
@@ -0,0 +1,521 @@
+//! Test vectors of the reliable RTPS protocol. Each vector contains the RTPS datagrams received by a stateful
+//! reader or writer and the state which the endpoint is expected to reach after processing them. The same vectors are
+//! checked against the Dust DDS stateful endpoints, so that transport implementations and embedded ports can validate
+//! their integration against a known good exchange.
+//!
+//! All the vectors use a writer with GUID prefix [`WRITER_GUID_PREFIX`] and entity id [`WRITER_ENTITY_ID`] matched
+//! with a reliable reader with GUID prefix [`READER_GUID_PREFIX`] and entity id [`READER_ENTITY_ID`].
+//! The datagrams are encoded in little endian.
+
+use crate::transport::types::{
+    EntityId, GuidPrefix, SequenceNumber, USER_DEFINED_READER_WITH_KEY,
+    USER_DEFINED_WRITER_WITH_KEY,
+};
+
+/// GUID prefix of the participant of the writer used in the vectors.
+pub const WRITER_GUID_PREFIX: GuidPrefix = [1; 12];
+
+/// Entity id of the writer used in the vectors.
+pub const WRITER_ENTITY_ID: EntityId = EntityId::new([0, 0, 1], USER_DEFINED_WRITER_WITH_KEY);
+
+/// GUID prefix of the participant of the reader used in the vectors.
+pub const READER_GUID_PREFIX: GuidPrefix = [2; 12];
+
+/// Entity id of the reader used in the vectors.
+pub const READER_ENTITY_ID: EntityId = EntityId::new([0, 0, 2], USER_DEFINED_READER_WITH_KEY);
+
+/// Content of an ACKNACK submessage expected to be sent by the reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectedAckNack {
+    /// Base of the `readerSNState`, that is the first sequence number not yet received.
+    pub base: SequenceNumber,
+    /// Sequence numbers requested in the `readerSNState`.
+    pub set: &'static [SequenceNumber],
+}
+
+/// Exchange checked on a reliable stateful reader matched with the writer.
+#[derive(Debug, Clone, Copy)]
+pub struct StatefulReaderVector {
+    /// Name describing the behavior checked by the vector.
+    pub name: &'static str,
+    /// Datagrams sent by the writer, in the order in which they are received.
+    pub datagrams: &'static [&'static [u8]],
+    /// Sequence numbers of the changes delivered to the history cache of the reader, in order.
+    /// The serialized payload of each change contains its sequence number in the first octet of data.
+    pub delivered_changes: &'static [SequenceNumber],
+    /// ACKNACK submessages sent by the reader to the writer, in order.
+    pub sent_acknacks: &'static [ExpectedAckNack],
+}
+
+/// Exchange checked on a reliable stateful writer matched with the reader.
+#[derive(Debug, Clone, Copy)]
+pub struct StatefulWriterVector {
+    /// Name describing the behavior checked by the vector.
+    pub name: &'static str,
+    /// Sequence numbers of the changes in the history of the writer. The changes are sent to the reader
+    /// before the datagrams are received.
+    pub history: &'static [SequenceNumber],
+    /// Datagrams sent by the reader, in the order in which they are received.
+    pub datagrams: &'static [&'static [u8]],
+    /// Sequence numbers of the changes sent again by the writer as a reply to the datagrams, in order.
+    pub repaired_changes: &'static [SequenceNumber],
+    /// Sequence numbers of the changes acknowledged by the reader after all the datagrams are processed.
+    pub acknowledged_changes: &'static [SequenceNumber],
+}
+
+/// Vectors of the reliable stateful reader.
+pub const STATEFUL_READER_VECTORS: &[StatefulReaderVector] = &[
+    StatefulReaderVector {
+        name: "reader_delivers_changes_in_order",
+        datagrams: &[READER_DATA_1, READER_DATA_2, READER_HEARTBEAT_1_2],
+        delivered_changes: &[1, 2],
+        sent_acknacks: &[ExpectedAckNack { base: 3, set: &[] }],
+    },
+    StatefulReaderVector {
+        name: "reader_requests_missing_changes",
+        datagrams: &[
+            READER_DATA_2,
+            READER_HEARTBEAT_1_2,
+            READER_DATA_1,
+            READER_DATA_2,
+            READER_HEARTBEAT_1_2_REPEATED,
+        ],
+        delivered_changes: &[1, 2],
+        sent_acknacks: &[
+            ExpectedAckNack {
+                base: 1,
+                set: &[1, 2],
+            },
+            ExpectedAckNack { base: 3, set: &[] },
+        ],
+    },
+    StatefulReaderVector {
+        name: "reader_skips_irrelevant_changes",
+        datagrams: &[
+            READER_DATA_1,
+            READER_GAP_2,
+            READER_DATA_3,
+            READER_HEARTBEAT_1_3,
+        ],
+        delivered_changes: &[1, 3],
+        sent_acknacks: &[ExpectedAckNack { base: 4, set: &[] }],
+    },
+    StatefulReaderVector {
+        name: "reader_ignores_repeated_heartbeat",
+        datagrams: &[
+            READER_DATA_1,
+            READER_DATA_2,
+            READER_HEARTBEAT_1_2,
+            READER_HEARTBEAT_1_2,
+        ],
+        delivered_changes: &[1, 2],
+        sent_acknacks: &[ExpectedAckNack { base: 3, set: &[] }],
+    },
+];
+
+/// Vectors of the reliable stateful writer.
+pub const STATEFUL_WRITER_VECTORS: &[StatefulWriterVector] = &[
+    StatefulWriterVector {
+        name: "writer_repairs_requested_changes",
+        history: &[1, 2, 3],
+        datagrams: &[WRITER_ACKNACK_REQUEST_2],
+        repaired_changes: &[2],
+        acknowledged_changes: &[1],
+    },
+    StatefulWriterVector {
+        name: "writer_acknowledges_received_changes",
+        history: &[1, 2, 3],
+        datagrams: &[WRITER_ACKNACK_ALL_FIRST],
+        repaired_changes: &[],
+        acknowledged_changes: &[1, 2, 3],
+    },
+    StatefulWriterVector {
+        name: "writer_ignores_stale_acknack",
+        history: &[1, 2, 3],
+        datagrams: &[WRITER_ACKNACK_ALL, WRITER_ACKNACK_REQUEST_2],
+        repaired_changes: &[],
+        acknowledged_changes: &[1, 2, 3],
+    },
+];
+
+#[rustfmt::skip]
+const READER_DATA_1: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x15, 0b_0000_0101, 28, 0, // DATA submessage header
+    0, 0, 16, 0, // extraFlags | octetsToInlineQos
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // writerSN: high
+    1, 0, 0, 0, // writerSN: low
+    0, 1, 0, 0, // SerializedPayload: CDR_LE
+    1, 0, 0, 0, // SerializedPayload: data
+];
+
+#[rustfmt::skip]
+const READER_DATA_2: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x15, 0b_0000_0101, 28, 0, // DATA submessage header
+    0, 0, 16, 0, // extraFlags | octetsToInlineQos
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // writerSN: high
+    2, 0, 0, 0, // writerSN: low
+    0, 1, 0, 0, // SerializedPayload: CDR_LE
+    2, 0, 0, 0, // SerializedPayload: data
+];
+
+#[rustfmt::skip]
+const READER_DATA_3: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x15, 0b_0000_0101, 28, 0, // DATA submessage header
+    0, 0, 16, 0, // extraFlags | octetsToInlineQos
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // writerSN: high
+    3, 0, 0, 0, // writerSN: low
+    0, 1, 0, 0, // SerializedPayload: CDR_LE
+    3, 0, 0, 0, // SerializedPayload: data
+];
+
+#[rustfmt::skip]
+const READER_HEARTBEAT_1_2: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x07, 0b_0000_0001, 28, 0, // HEARTBEAT submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // firstSN: high
+    1, 0, 0, 0, // firstSN: low
+    0, 0, 0, 0, // lastSN: high
+    2, 0, 0, 0, // lastSN: low
+    1, 0, 0, 0, // count
+];
+
+#[rustfmt::skip]
+const READER_HEARTBEAT_1_2_REPEATED: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x07, 0b_0000_0001, 28, 0, // HEARTBEAT submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // firstSN: high
+    1, 0, 0, 0, // firstSN: low
+    0, 0, 0, 0, // lastSN: high
+    2, 0, 0, 0, // lastSN: low
+    2, 0, 0, 0, // count
+];
+
+#[rustfmt::skip]
+const READER_HEARTBEAT_1_3: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x07, 0b_0000_0001, 28, 0, // HEARTBEAT submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // firstSN: high
+    1, 0, 0, 0, // firstSN: low
+    0, 0, 0, 0, // lastSN: high
+    3, 0, 0, 0, // lastSN: low
+    1, 0, 0, 0, // count
+];
+
+#[rustfmt::skip]
+const READER_GAP_2: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    1, 1, 1, 1, // GuidPrefix
+    0x08, 0b_0000_0001, 28, 0, // GAP submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // gapStart: high
+    2, 0, 0, 0, // gapStart: low
+    0, 0, 0, 0, // gapList: bitmapBase: high
+    3, 0, 0, 0, // gapList: bitmapBase: low
+    0, 0, 0, 0, // gapList: numBits
+];
+
+#[rustfmt::skip]
+const WRITER_ACKNACK_REQUEST_2: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    0x06, 0b_0000_0011, 28, 0, // ACKNACK submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // readerSNState: bitmapBase: high
+    2, 0, 0, 0, // readerSNState: bitmapBase: low
+    1, 0, 0, 0, // readerSNState: numBits
+    0, 0, 0, 128, // readerSNState: bitmap
+    1, 0, 0, 0, // count
+];
+
+#[rustfmt::skip]
+const WRITER_ACKNACK_ALL: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    0x06, 0b_0000_0011, 24, 0, // ACKNACK submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // readerSNState: bitmapBase: high
+    4, 0, 0, 0, // readerSNState: bitmapBase: low
+    0, 0, 0, 0, // readerSNState: numBits
+    2, 0, 0, 0, // count
+];
+
+#[rustfmt::skip]
+const WRITER_ACKNACK_ALL_FIRST: &[u8] = &[
+    0x52, 0x54, 0x50, 0x53, // Protocol: 'RTPS'
+    2, 4, 0x01, 0x14, // ProtocolVersion | VendorId
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    2, 2, 2, 2, // GuidPrefix
+    0x06, 0b_0000_0011, 24, 0, // ACKNACK submessage header
+    0, 0, 2, 7, // readerId
+    0, 0, 1, 2, // writerId
+    0, 0, 0, 0, // readerSNState: bitmapBase: high
+    4, 0, 0, 0, // readerSNState: bitmapBase: low
+    0, 0, 0, 0, // readerSNState: numBits
+    1, 0, 0, 0, // count
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rtps::{
+            message_sender::{Clock, WriteMessage},
+            stateful_reader::RtpsStatefulReader,
+            stateful_writer::RtpsStatefulWriter,
+        },
+        rtps_messages::overall_structure::{RtpsMessageRead, RtpsSubmessageReadKind},
+        transport::{
+            history_cache::{CacheChange, HistoryCache},
+            reader::WriterProxy,
+            types::{
+                ChangeKind, DurabilityKind, Guid, Locator, ReliabilityKind, ENTITYID_UNKNOWN,
+                LOCATOR_KIND_UDP_V4,
+            },
+            writer::ReaderProxy,
+        },
+    };
+    use core::{future::Future, pin::Pin};
+    use futures::executor::block_on;
+    use std::sync::{Arc, Mutex};
+
+    const LOCATOR: Locator = Locator::new(LOCATOR_KIND_UDP_V4, 7400, [0; 16]);
+
+    struct MockMessageWriter {
+        guid_prefix: GuidPrefix,
+        datagrams: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl MockMessageWriter {
+        fn new(guid_prefix: GuidPrefix) -> Self {
+            Self {
+                guid_prefix,
+                datagrams: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn take_messages(&self) -> Vec<RtpsMessageRead> {
+            self.datagrams
+                .lock()
+                .unwrap()
+                .drain(..)
+                .map(|d| RtpsMessageRead::try_from(d.as_slice()).unwrap())
+                .collect()
+        }
+    }
+
+    impl WriteMessage for MockMessageWriter {
+        fn write_message(
+            &self,
+            datagram: &[u8],
+            _locator_list: &[Locator],
+        ) -> impl Future<Output = ()> + Send {
+            self.datagrams.lock().unwrap().push(datagram.to_vec());
+            core::future::ready(())
+        }
+
+        fn guid_prefix(&self) -> GuidPrefix {
+            self.guid_prefix
+        }
+    }
+
+    struct MockClock;
+
+    impl Clock for MockClock {
+        fn now(&self) -> core::time::Duration {
+            core::time::Duration::ZERO
+        }
+    }
+
+    struct MockHistoryCache(Arc<Mutex<Vec<CacheChange>>>);
+
+    impl HistoryCache for MockHistoryCache {
+        fn add_change(
+            &mut self,
+            cache_change: CacheChange,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            self.0.lock().unwrap().push(cache_change);
+            Box::pin(async {})
+        }
+
+        fn remove_change(
+            &mut self,
+            _sequence_number: i64,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            Box::pin(async {})
+        }
+    }
+
+    #[test]
+    fn stateful_reader_vectors() {
+        for vector in STATEFUL_READER_VECTORS {
+            let changes = Arc::new(Mutex::new(Vec::new()));
+            let mut reader = RtpsStatefulReader::new(
+                Guid::new(READER_GUID_PREFIX, READER_ENTITY_ID),
+                Box::new(MockHistoryCache(changes.clone())),
+                ReliabilityKind::Reliable,
+            );
+            reader.add_matched_writer(&WriterProxy {
+                remote_writer_guid: Guid::new(WRITER_GUID_PREFIX, WRITER_ENTITY_ID),
+                remote_group_entity_id: ENTITYID_UNKNOWN,
+                reliability_kind: ReliabilityKind::Reliable,
+                durability_kind: DurabilityKind::Volatile,
+                unicast_locator_list: vec![LOCATOR],
+                multicast_locator_list: vec![],
+            });
+            let message_writer = MockMessageWriter::new(READER_GUID_PREFIX);
+
+            for datagram in vector.datagrams {
+                block_on(reader.process_message(datagram, &message_writer, &MockClock)).unwrap();
+            }
+
+            let delivered_changes: Vec<_> = changes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|c| {
+                    assert_eq!(
+                        c.data_value()[4] as i64,
+                        c.sequence_number(),
+                        "{}",
+                        vector.name
+                    );
+                    c.sequence_number()
+                })
+                .collect();
+            assert_eq!(
+                delivered_changes, vector.delivered_changes,
+                "{}",
+                vector.name
+            );
+            let sent_acknacks: Vec<_> = message_writer
+                .take_messages()
+                .iter()
+                .flat_map(|m| m.submessages())
+                .filter_map(|s| match s {
+                    RtpsSubmessageReadKind::AckNack(a) => Some((
+                        a.reader_sn_state().base(),
+                        a.reader_sn_state().set().collect::<Vec<_>>(),
+                    )),
+                    _ => None,
+                })
+                .collect();
+            let expected_acknacks: Vec<_> = vector
+                .sent_acknacks
+                .iter()
+                .map(|a| (a.base, a.set.to_vec()))
+                .collect();
+            assert_eq!(sent_acknacks, expected_acknacks, "{}", vector.name);
+        }
+    }
+
+    #[test]
+    fn stateful_writer_vectors() {
+        for vector in STATEFUL_WRITER_VECTORS {
+            let writer_guid = Guid::new(WRITER_GUID_PREFIX, WRITER_ENTITY_ID);
+            let mut writer = RtpsStatefulWriter::new(writer_guid, 1024, false);
+            writer.add_matched_reader(&ReaderProxy {
+                remote_reader_guid: Guid::new(READER_GUID_PREFIX, READER_ENTITY_ID),
+                remote_group_entity_id: ENTITYID_UNKNOWN,
+                reliability_kind: ReliabilityKind::Reliable,
+                durability_kind: DurabilityKind::Volatile,
+                unicast_locator_list: vec![LOCATOR],
+                multicast_locator_list: vec![],
+                expects_inline_qos: false,
+            });
+            for &sequence_number in vector.history {
+                writer.add_change(CacheChange {
+                    kind: ChangeKind::Alive,
+                    writer_guid,
+                    sequence_number,
+                    source_timestamp: None,
+                    instance_handle: None,
+                    coherent_set: None,
+                    directed_write: Vec::new(),
+                    original_writer_info: None,
+                    data_value: vec![0, 1, 0, 0, sequence_number as u8, 0, 0, 0].into(),
+                });
+            }
+            let message_writer = MockMessageWriter::new(WRITER_GUID_PREFIX);
+            block_on(writer.write_message(&message_writer, &MockClock));
+            message_writer.take_messages();
+
+            for datagram in vector.datagrams {
+                block_on(writer.process_message(datagram, &message_writer, &MockClock)).unwrap();
+            }
+
+            let repaired_changes: Vec<_> = message_writer
+                .take_messages()
+                .iter()
+                .flat_map(|m| m.submessages())
+                .filter_map(|s| match s {
+                    RtpsSubmessageReadKind::Data(d) => Some(d.writer_sn()),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(repaired_changes, vector.repaired_changes, "{}", vector.name);
+            let acknowledged_changes: Vec<_> = vector
+                .history
+                .iter()
+                .copied()
+                .filter(|&sn| writer.is_change_acknowledged(sn))
+                .collect();
+            assert_eq!(
+                acknowledged_changes, vector.acknowledged_changes,
+                "{}",
+                vector.name
+            );
+        }
+    }
+}
@@ -120,6 +120,10 @@ impl FragmentNumberSet {
         }
     }
 
+    pub fn set(&self) -> &[FragmentNumber] {
+        &self.set
+    }
+
     pub fn try_read_from_bytes(
         data: &mut &[u8],
         endianness: &Endianness,
@@ -362,9 +366,7 @@ impl From<&[u8]> for SerializedDataFragment {
 
 impl WriteIntoBytes for SerializedDataFragment {
     fn write_into_bytes(&self, buf: &mut dyn Write) {
-        self.data.as_ref()[self.range.start..self.range.end]
-            .as_ref()
-            .write_into_bytes(buf);
+        (&self.data.as_ref()[self.range.start..self.range.end]).write_into_bytes(buf);
     }
 }
 
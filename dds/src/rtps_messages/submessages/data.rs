@@ -1,8 +1,8 @@
 use super::super::{
     error::{RtpsMessageError, RtpsMessageResult},
     overall_structure::{
-        Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes, Write,
-        WriteIntoBytes,
+        Endianness, Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes,
+        Write, WriteIntoBytes,
     },
     submessage_elements::{Data, ParameterList},
     types::{SubmessageFlag, SubmessageKind},
@@ -20,6 +20,8 @@ pub struct DataSubmessage {
     writer_sn: SequenceNumber,
     inline_qos: ParameterList,
     serialized_payload: Data,
+    // The values of the inline QoS parameters are encoded with the endianness of the submessage
+    endianness: Endianness,
 }
 
 impl DataSubmessage {
@@ -73,6 +75,7 @@ impl DataSubmessage {
             writer_sn,
             inline_qos,
             serialized_payload,
+            endianness: *endianness,
         })
     }
 
@@ -98,9 +101,15 @@ impl DataSubmessage {
             writer_sn,
             inline_qos,
             serialized_payload,
+            endianness: Endianness::LittleEndian,
         }
     }
 
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
     pub fn _inline_qos_flag(&self) -> bool {
         self.inline_qos_flag
     }
@@ -136,6 +145,10 @@ impl DataSubmessage {
     pub fn serialized_payload(&self) -> &Data {
         &self.serialized_payload
     }
+
+    pub fn endianness(&self) -> &Endianness {
+        &self.endianness
+    }
 }
 
 impl Submessage for DataSubmessage {
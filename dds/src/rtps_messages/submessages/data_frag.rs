@@ -1,10 +1,11 @@
 use crate::transport::types::{EntityId, SequenceNumber};
+use alloc::{sync::Arc, vec::Vec};
 
 use super::super::{
     error::{RtpsMessageError, RtpsMessageResult},
     overall_structure::{
-        Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes, Write,
-        WriteIntoBytes,
+        Endianness, Submessage, SubmessageHeaderRead, SubmessageHeaderWrite, TryReadFromBytes,
+        Write, WriteIntoBytes,
     },
     submessage_elements::{ParameterList, SerializedDataFragment},
     types::{FragmentNumber, SubmessageFlag, SubmessageKind},
@@ -23,6 +24,8 @@ pub struct DataFragSubmessage {
     data_size: u32,
     inline_qos: ParameterList,
     serialized_payload: SerializedDataFragment,
+    // The values of the inline QoS parameters are encoded with the endianness of the submessage
+    endianness: Endianness,
 }
 
 impl DataFragSubmessage {
@@ -80,6 +83,7 @@ impl DataFragSubmessage {
                 data_size,
                 inline_qos,
                 serialized_payload,
+                endianness: *endianness,
             })
         } else {
             Err(RtpsMessageError::NotEnoughData)
@@ -133,6 +137,10 @@ impl DataFragSubmessage {
     pub fn serialized_payload(&self) -> &SerializedDataFragment {
         &self.serialized_payload
     }
+
+    pub fn endianness(&self) -> &Endianness {
+        &self.endianness
+    }
 }
 
 impl DataFragSubmessage {
@@ -164,10 +172,30 @@ impl DataFragSubmessage {
             data_size,
             inline_qos,
             serialized_payload,
+            endianness: Endianness::LittleEndian,
         }
     }
 }
 
+const EXTRA_FLAGS: u16 = 0;
+const OCTETS_TO_INLINE_QOS: u16 = 28;
+
+impl DataFragSubmessage {
+    // Elements following the reader id, which are the same for all the readers
+    fn write_reader_independent_elements_into_bytes(&self, buf: &mut dyn Write) {
+        self.writer_id.write_into_bytes(buf);
+        self.writer_sn.write_into_bytes(buf);
+        self.fragment_starting_num.write_into_bytes(buf);
+        self.fragments_in_submessage.write_into_bytes(buf);
+        self.fragment_size.write_into_bytes(buf);
+        self.data_size.write_into_bytes(buf);
+        if self.inline_qos_flag {
+            self.inline_qos.write_into_bytes(buf);
+        }
+        self.serialized_payload.write_into_bytes(buf);
+    }
+}
+
 impl Submessage for DataFragSubmessage {
     fn write_submessage_header_into_bytes(&self, octets_to_next_header: u16, buf: &mut dyn Write) {
         SubmessageHeaderWrite::new(
@@ -183,21 +211,74 @@ impl Submessage for DataFragSubmessage {
     }
 
     fn write_submessage_elements_into_bytes(&self, buf: &mut dyn Write) {
-        const EXTRA_FLAGS: u16 = 0;
-        const OCTETS_TO_INLINE_QOS: u16 = 28;
         EXTRA_FLAGS.write_into_bytes(buf);
         OCTETS_TO_INLINE_QOS.write_into_bytes(buf);
         self.reader_id.write_into_bytes(buf);
-        self.writer_id.write_into_bytes(buf);
-        self.writer_sn.write_into_bytes(buf);
-        self.fragment_starting_num.write_into_bytes(buf);
-        self.fragments_in_submessage.write_into_bytes(buf);
-        self.fragment_size.write_into_bytes(buf);
-        self.data_size.write_into_bytes(buf);
-        if self.inline_qos_flag {
-            self.inline_qos.write_into_bytes(buf);
+        self.write_reader_independent_elements_into_bytes(buf);
+    }
+}
+
+/// DATA_FRAG submessage serialized in advance so that it can be sent to several readers, and
+/// retransmitted, without being serialized again. Only the reader id is written at send time.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrebuiltDataFragSubmessage {
+    inline_qos_flag: bool,
+    non_standard_payload_flag: SubmessageFlag,
+    key_flag: bool,
+    reader_independent_elements: Arc<[u8]>,
+}
+
+impl PrebuiltDataFragSubmessage {
+    pub fn new(data_frag: &DataFragSubmessage) -> Self {
+        let mut reader_independent_elements = Vec::new();
+        data_frag.write_reader_independent_elements_into_bytes(&mut reader_independent_elements);
+        Self {
+            inline_qos_flag: data_frag.inline_qos_flag,
+            non_standard_payload_flag: data_frag.non_standard_payload_flag,
+            key_flag: data_frag.key_flag,
+            reader_independent_elements: Arc::from(reader_independent_elements),
+        }
+    }
+
+    /// Number of bytes held by the elements shared by all the readers.
+    pub fn memory_usage(&self) -> usize {
+        self.reader_independent_elements.len()
+    }
+
+    pub fn for_reader(&self, reader_id: EntityId) -> PrebuiltDataFragReaderSubmessage<'_> {
+        PrebuiltDataFragReaderSubmessage {
+            prebuilt: self,
+            reader_id,
         }
-        self.serialized_payload.write_into_bytes(buf);
+    }
+}
+
+/// [`PrebuiltDataFragSubmessage`] addressed to a specific reader.
+pub struct PrebuiltDataFragReaderSubmessage<'a> {
+    prebuilt: &'a PrebuiltDataFragSubmessage,
+    reader_id: EntityId,
+}
+
+impl Submessage for PrebuiltDataFragReaderSubmessage<'_> {
+    fn write_submessage_header_into_bytes(&self, octets_to_next_header: u16, buf: &mut dyn Write) {
+        SubmessageHeaderWrite::new(
+            SubmessageKind::DATA_FRAG,
+            &[
+                self.prebuilt.inline_qos_flag,
+                self.prebuilt.key_flag,
+                self.prebuilt.non_standard_payload_flag,
+            ],
+            octets_to_next_header,
+        )
+        .write_into_bytes(buf);
+    }
+
+    fn write_submessage_elements_into_bytes(&self, buf: &mut dyn Write) {
+        EXTRA_FLAGS.write_into_bytes(buf);
+        OCTETS_TO_INLINE_QOS.write_into_bytes(buf);
+        self.reader_id.write_into_bytes(buf);
+        buf.write_all(&self.prebuilt.reader_independent_elements)
+            .expect("buffer big enough");
     }
 }
 
@@ -281,6 +362,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prebuilt_submessage_serializes_as_submessage() {
+        let inline_qos = ParameterList::new(vec![Parameter::new(8, vec![71, 72, 73, 74].into())]);
+        let serialized_payload = SerializedDataFragment::from([1, 2, 3].as_slice());
+        let reader_id = EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY);
+        let submessage = DataFragSubmessage::new(
+            true,
+            false,
+            true,
+            reader_id,
+            EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP),
+            6,
+            2,
+            3,
+            5,
+            8,
+            inline_qos,
+            serialized_payload,
+        );
+        let prebuilt = PrebuiltDataFragSubmessage::new(&submessage);
+        assert_eq!(
+            write_submessage_into_bytes_vec(&prebuilt.for_reader(reader_id)),
+            write_submessage_into_bytes_vec(&submessage)
+        );
+    }
+
     #[test]
     fn deserialize_no_inline_qos_no_serialized_payload() {
         #[rustfmt::skip]
@@ -32,7 +32,7 @@ impl GapSubmessage {
         })
     }
 
-    pub fn _reader_id(&self) -> EntityId {
+    pub fn reader_id(&self) -> EntityId {
         self.reader_id
     }
 
@@ -127,7 +127,7 @@ mod tests {
         ][..];
         let submessage_header = SubmessageHeaderRead::try_read_from_bytes(&mut data).unwrap();
         let submessage = GapSubmessage::try_from_bytes(&submessage_header, data).unwrap();
-        assert_eq!(expected_reader_id, submessage._reader_id());
+        assert_eq!(expected_reader_id, submessage.reader_id());
         assert_eq!(expected_writer_id, submessage.writer_id());
         assert_eq!(expected_gap_start, submessage.gap_start());
         assert_eq!(&expected_gap_list, submessage.gap_list());
@@ -45,7 +45,7 @@ impl HeartbeatSubmessage {
         self.liveliness_flag
     }
 
-    pub fn _reader_id(&self) -> EntityId {
+    pub fn reader_id(&self) -> EntityId {
         self.reader_id
     }
 
@@ -171,7 +171,7 @@ mod tests {
         let submessage = HeartbeatSubmessage::try_from_bytes(&submessage_header, data).unwrap();
         assert_eq!(expected_final_flag, submessage.final_flag());
         assert_eq!(expected_liveliness_flag, submessage.liveliness_flag());
-        assert_eq!(expected_reader_id, submessage._reader_id());
+        assert_eq!(expected_reader_id, submessage.reader_id());
         assert_eq!(expected_writer_id, submessage.writer_id());
         assert_eq!(expected_first_sn, submessage.first_sn());
         assert_eq!(expected_last_sn, submessage.last_sn());
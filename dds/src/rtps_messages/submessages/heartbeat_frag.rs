@@ -33,7 +33,7 @@ impl HeartbeatFragSubmessage {
         })
     }
 
-    pub fn _reader_id(&self) -> EntityId {
+    pub fn reader_id(&self) -> EntityId {
         self.reader_id
     }
 
@@ -41,11 +41,11 @@ impl HeartbeatFragSubmessage {
         self.writer_id
     }
 
-    pub fn _writer_sn(&self) -> SequenceNumber {
+    pub fn writer_sn(&self) -> SequenceNumber {
         self.writer_sn
     }
 
-    pub fn _last_fragment_num(&self) -> FragmentNumber {
+    pub fn last_fragment_num(&self) -> FragmentNumber {
         self.last_fragment_num
     }
 
@@ -55,7 +55,7 @@ impl HeartbeatFragSubmessage {
 }
 
 impl HeartbeatFragSubmessage {
-    pub fn _new(
+    pub fn new(
         reader_id: EntityId,
         writer_id: EntityId,
         writer_sn: SequenceNumber,
@@ -97,7 +97,7 @@ mod tests {
 
     #[test]
     fn serialize_heart_beat() {
-        let submessage = HeartbeatFragSubmessage::_new(
+        let submessage = HeartbeatFragSubmessage::new(
             EntityId::new([1, 2, 3], USER_DEFINED_READER_NO_KEY),
             EntityId::new([6, 7, 8], USER_DEFINED_READER_GROUP),
             5,
@@ -138,10 +138,10 @@ mod tests {
         let expected_last_fragment_num = 7;
         let expected_count = 2;
 
-        assert_eq!(expected_reader_id, submessage._reader_id());
+        assert_eq!(expected_reader_id, submessage.reader_id());
         assert_eq!(expected_writer_id, submessage.writer_id());
-        assert_eq!(expected_writer_sn, submessage._writer_sn());
-        assert_eq!(expected_last_fragment_num, submessage._last_fragment_num());
+        assert_eq!(expected_writer_sn, submessage.writer_sn());
+        assert_eq!(expected_last_fragment_num, submessage.last_fragment_num());
         assert_eq!(expected_count, submessage.count());
     }
 }
@@ -44,7 +44,7 @@ impl InfoSourceSubmessage {
 }
 
 impl InfoSourceSubmessage {
-    pub fn _new(
+    pub fn new(
         protocol_version: ProtocolVersion,
         vendor_id: VendorId,
         guid_prefix: GuidPrefix,
@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn serialize_info_source() {
         let submessage =
-            InfoSourceSubmessage::_new(PROTOCOLVERSION_1_0, VENDOR_ID_UNKNOWN, GUIDPREFIX_UNKNOWN);
+            InfoSourceSubmessage::new(PROTOCOLVERSION_1_0, VENDOR_ID_UNKNOWN, GUIDPREFIX_UNKNOWN);
         #[rustfmt::skip]
         assert_eq!(write_submessage_into_bytes_vec(&submessage), vec![
                 0x0c, 0b_0000_0001, 20, 0, // Submessage header
@@ -206,6 +206,15 @@ impl WriteIntoBytes for Time {
 /// Type used to hold a count that is incremented monotonically, used to identify message duplicates.
 pub type Count = Long;
 
+/// Checks whether `count` is more recent than `last_count`. Counts are incremented monotonically
+/// but wrap around when the maximum value is reached so they are compared using serial number arithmetic:
+/// a count is more recent if it is at most 2^31 - 1 increments ahead of `last_count`. Any other count is
+/// either a duplicate or a stale value and must be ignored. A peer may start counting from any value so
+/// the first count received from it, when there is no `last_count`, is always accepted.
+pub fn is_count_newer(count: Count, last_count: Option<Count>) -> bool {
+    last_count.is_none_or(|last_count| count.wrapping_sub(last_count) > 0)
+}
+
 /// Checksum_t
 /// Type used to hold a checksum. Used to detect RTPS message corruption by the underlying transport.
 /// The following values are reserved by the protocol: CHECKSUM_INVALID.
@@ -242,3 +251,29 @@ pub type UExtension4 = [Octet; 4];
 /// Type used to hold an undefined 8-byte value. It is intended to be used in future revisions of the specification.
 #[allow(dead_code)]
 pub type WExtension8 = [Octet; 8];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_comparison() {
+        assert!(is_count_newer(2, Some(1)));
+        assert!(!is_count_newer(1, Some(1)));
+        assert!(!is_count_newer(1, Some(2)));
+    }
+
+    #[test]
+    fn count_comparison_with_wrap_around() {
+        assert!(is_count_newer(Count::MIN, Some(Count::MAX)));
+        assert!(is_count_newer(Count::MIN + 5, Some(Count::MAX - 5)));
+        assert!(!is_count_newer(Count::MAX, Some(Count::MIN)));
+    }
+
+    #[test]
+    fn first_count_is_always_newer() {
+        assert!(is_count_newer(0, None));
+        assert!(is_count_newer(-1, None));
+        assert!(is_count_newer(Count::MIN, None));
+    }
+}
@@ -2,7 +2,6 @@ use crate::{
     rtps::message_sender::Clock, std_runtime::executor::block_on,
     transport::types::LOCATOR_KIND_UDP_V6,
 };
-use async_lock::Mutex;
 use core::{
     future::Future,
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4},
@@ -17,29 +16,36 @@ use dust_dds::{
         stateless_writer::RtpsStatelessWriter,
         types::{PROTOCOLVERSION, VENDOR_ID_S2E},
     },
+    rtps_messages::overall_structure::{RtpsMessageRead, RtpsSubmessageReadKind},
     transport::{
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
         factory::TransportParticipantFactory,
         history_cache::{CacheChange, HistoryCache},
-        participant::TransportParticipant,
+        participant::{TransportParticipant, TransportTaskKind, TransportTaskObserver},
         reader::{TransportStatefulReader, TransportStatelessReader, WriterProxy},
         types::{
             EntityId, Guid, GuidPrefix, Locator, ProtocolVersion, ReliabilityKind, VendorId,
-            ENTITYID_PARTICIPANT, LOCATOR_KIND_UDP_V4,
+            ENTITYID_PARTICIPANT, ENTITYID_UNKNOWN, LOCATOR_KIND_UDP_V4,
+        },
+        writer::{
+            ChangeLifecycleObserver, ReaderProxy, TransportStatefulWriter, TransportStatelessWriter,
         },
-        writer::{ReaderProxy, TransportStatefulWriter, TransportStatelessWriter},
     },
 };
 use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
 use socket2::Socket;
 use std::{
+    collections::{TryReserveError, VecDeque},
     net::{ToSocketAddrs, UdpSocket},
     sync::{
+        atomic::{self, AtomicBool, AtomicI64, AtomicUsize},
         mpsc::{channel, Sender},
-        Arc,
+        Arc, RwLock,
     },
 };
 
 const MAX_DATAGRAM_SIZE: usize = 65507;
+const RTPS_MESSAGE_HEADER_SIZE: usize = 20;
 
 type LocatorAddress = [u8; 16];
 // As of 9.6.1.4.1  Default multicast address
@@ -103,6 +109,10 @@ pub struct RtpsUdpTransportParticipantFactoryBuilder {
     interface_name: Option<String>,
     fragment_size: usize,
     udp_receive_buffer_size: Option<usize>,
+    payload_prefragmentation: bool,
+    max_message_size: usize,
+    fragment_reassembly_timeout: core::time::Duration,
+    flow_controllers: Vec<FlowControllerSettings>,
 }
 
 impl Default for RtpsUdpTransportParticipantFactoryBuilder {
@@ -118,6 +128,10 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
             interface_name: None,
             fragment_size: 1344,
             udp_receive_buffer_size: None,
+            payload_prefragmentation: false,
+            max_message_size: 1472,
+            fragment_reassembly_timeout: core::time::Duration::from_secs(10),
+            flow_controllers: Vec::new(),
         }
     }
 
@@ -139,6 +153,51 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
         self
     }
 
+    /// Set whether the samples above the fragment size are split into fragments when they are written. The fragments are
+    /// then reused when sending the sample to each of the readers and when retransmitting it instead of being built on
+    /// every send, at the cost of keeping them in memory while the sample is in the history of the writer.
+    pub fn payload_prefragmentation(mut self, payload_prefragmentation: bool) -> Self {
+        self.payload_prefragmentation = payload_prefragmentation;
+        self
+    }
+
+    /// Set the maximum size of the datagrams sent by the writers. The messages sent at once to the same locators are
+    /// packed together in datagrams up to this size instead of being sent one by one. The default of 1472 bytes fits
+    /// in the MTU of an Ethernet network.
+    pub fn max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Set the time after which the fragments received of a sample are discarded if no other fragment of the sample
+    /// is received, releasing the memory held for its reassembly. The default is 10 seconds.
+    pub fn fragment_reassembly_timeout(
+        mut self,
+        fragment_reassembly_timeout: core::time::Duration,
+    ) -> Self {
+        self.fragment_reassembly_timeout = fragment_reassembly_timeout;
+        self
+    }
+
+    /// Add a flow controller to which the writers are assigned by name with their
+    /// [`FlowControllerQosPolicy`](crate::infrastructure::qos_policy::FlowControllerQosPolicy). The flow controller
+    /// sends at most `bytes_per_period` bytes of the messages of its writers in each `period`, holding the other
+    /// messages until the following periods. Adding a flow controller with the name of a previous one replaces it.
+    pub fn flow_controller(
+        mut self,
+        name: &str,
+        bytes_per_period: usize,
+        period: core::time::Duration,
+    ) -> Self {
+        self.flow_controllers.retain(|f| f.name != name);
+        self.flow_controllers.push(FlowControllerSettings {
+            name: name.to_string(),
+            bytes_per_period,
+            period,
+        });
+        self
+    }
+
     /// Build a new participant factory
     pub fn build(self) -> Result<RtpsUdpTransportParticipantFactory, String> {
         let fragment_size_range = 8..=65000;
@@ -147,11 +206,29 @@ impl RtpsUdpTransportParticipantFactoryBuilder {
                 "Interface size out of range. Value must be between in {:?}",
                 fragment_size_range
             ))
+        } else if self.max_message_size > MAX_DATAGRAM_SIZE {
+            Err(format!(
+                "Maximum message size out of range. Value must be at most {}",
+                MAX_DATAGRAM_SIZE
+            ))
+        } else if let Some(flow_controller) = self
+            .flow_controllers
+            .iter()
+            .find(|f| f.bytes_per_period == 0 || f.period.is_zero())
+        {
+            Err(format!(
+                "Flow controller {} must send a number of bytes in a period greater than zero",
+                flow_controller.name
+            ))
         } else {
             Ok(RtpsUdpTransportParticipantFactory {
                 interface_name: self.interface_name,
                 fragment_size: self.fragment_size,
                 udp_receive_buffer_size: self.udp_receive_buffer_size,
+                payload_prefragmentation: self.payload_prefragmentation,
+                max_message_size: self.max_message_size,
+                fragment_reassembly_timeout: self.fragment_reassembly_timeout,
+                flow_controllers: self.flow_controllers,
             })
         }
     }
@@ -161,6 +238,10 @@ pub struct RtpsUdpTransportParticipantFactory {
     interface_name: Option<String>,
     fragment_size: usize,
     udp_receive_buffer_size: Option<usize>,
+    payload_prefragmentation: bool,
+    max_message_size: usize,
+    fragment_reassembly_timeout: core::time::Duration,
+    flow_controllers: Vec<FlowControllerSettings>,
 }
 
 impl Default for RtpsUdpTransportParticipantFactory {
@@ -171,16 +252,162 @@ impl Default for RtpsUdpTransportParticipantFactory {
     }
 }
 
+// Messages processed by the endpoint task of the participant. The operations of the application on the stateful
+// endpoints are queued on the same channel as the received datagrams and the periodic pokes, so the state of the
+// endpoints is only ever used by the task and the application never waits for it to be released.
 enum ChannelMessageKind {
     AddStatelessReader(RtpsStatelessReader),
-    AddStatefulReader(Arc<Mutex<RtpsStatefulReader>>),
-    AddStatefulWriter(Arc<Mutex<RtpsStatefulWriter>>),
+    AddStatefulReader(EntityId, Box<StatefulReaderEndpoint>),
+    AddStatefulWriter(EntityId, Box<StatefulWriterEndpoint>),
+    StatefulReaderOperation(EntityId, StatefulReaderOperation),
+    StatefulWriterOperation(EntityId, StatefulWriterOperation),
+    SetFlowControllerLimit(FlowControllerSettings),
     MetatrafficMulticastSocket(Arc<[u8]>),
     MetatrafficUnicastSocket(Arc<[u8]>),
     DefaultUnicastSocket(Arc<[u8]>),
     Poke,
 }
 
+enum StatefulReaderOperation {
+    AddMatchedWriter(WriterProxy),
+    RemoveMatchedWriter(Guid),
+    RequestHistoricalData,
+    SetHeartbeatResponseTiming {
+        heartbeat_response_delay: core::time::Duration,
+        heartbeat_suppression_duration: core::time::Duration,
+    },
+    ReserveResources {
+        max_matched_writers: usize,
+        reply_sender: Sender<Result<(), TryReserveError>>,
+    },
+    FragmentBufferMemoryUsage(Sender<usize>),
+}
+
+enum StatefulWriterOperation {
+    AddChange(CacheChange),
+    RemoveChange(i64),
+    AddMatchedReader(ReaderProxy),
+    RemoveMatchedReader(Guid),
+    SetChangeLifecycleObserver(Option<Arc<dyn ChangeLifecycleObserver>>),
+    SetBatching(bool),
+    SetReliabilityTiming {
+        heartbeat_period: core::time::Duration,
+        nack_response_delay: core::time::Duration,
+        nack_suppression_duration: core::time::Duration,
+        min_resend_interval: core::time::Duration,
+    },
+    SetSamplesPerHeartbeat(Option<usize>),
+    SetDisablePositiveAcks(Option<core::time::Duration>),
+    SetFlowController(String),
+    SetAsynchronousPublishing(bool),
+    SetTransportPriority(i32),
+    Flush,
+    ReserveResources {
+        max_changes: usize,
+        max_matched_readers: usize,
+        reply_sender: Sender<Result<(), TryReserveError>>,
+    },
+    HistoryMemoryUsage(Sender<usize>),
+    FragmentBufferMemoryUsage(Sender<usize>),
+    TakeDataBytesSent(Sender<Vec<(Guid, u64)>>),
+}
+
+// State of a stateful reader published by the endpoint task for the queries of the application which are answered
+// without waiting for the task. While operations changing the answer are pending the historical data is reported as
+// not received.
+struct StatefulReaderState {
+    historical_data_received: AtomicBool,
+    pending_operations: AtomicUsize,
+}
+
+struct StatefulReaderEndpoint {
+    rtps_stateful_reader: RtpsStatefulReader,
+    state: Arc<StatefulReaderState>,
+}
+
+impl StatefulReaderEndpoint {
+    fn publish_state(&self) {
+        self.state.historical_data_received.store(
+            self.rtps_stateful_reader.is_historical_data_received(),
+            atomic::Ordering::Release,
+        );
+    }
+}
+
+// State of a stateful writer published by the endpoint task for the queries of the application which are answered
+// without waiting for the task. While operations changing the answer are pending no change is reported as
+// acknowledged.
+struct StatefulWriterState {
+    acknowledged_seq_num: AtomicI64,
+    pending_operations: AtomicUsize,
+}
+
+struct StatefulWriterEndpoint {
+    rtps_stateful_writer: RtpsStatefulWriter,
+    flow_controller: Option<usize>,
+    asynchronous_publishing: bool,
+    transport_priority: i32,
+    state: Arc<StatefulWriterState>,
+}
+
+impl StatefulWriterEndpoint {
+    fn publish_state(&self) {
+        self.state.acknowledged_seq_num.store(
+            self.rtps_stateful_writer.acknowledged_seq_num(),
+            atomic::Ordering::Release,
+        );
+    }
+
+    async fn write_message(
+        &mut self,
+        message_writer: &MessageWriter,
+        flow_controller_list: &[FlowController],
+    ) {
+        let message_packer = MessagePacker::new(
+            message_writer,
+            self.flow_controller
+                .and_then(|i| flow_controller_list.get(i)),
+        );
+        self.rtps_stateful_writer
+            .write_message(&message_packer, &RtpsUdpTransportClock)
+            .await;
+        message_packer.flush().await;
+    }
+}
+
+// Observer registered by the participant, shared with the threads whose progress it supervises
+type SharedTaskObserver = Arc<RwLock<Option<Arc<dyn TransportTaskObserver>>>>;
+
+// Reports the task as busy to the observer until it is dropped
+struct BusyTask {
+    task_observer: Option<Arc<dyn TransportTaskObserver>>,
+    task_kind: TransportTaskKind,
+}
+
+impl BusyTask {
+    fn new(task_observer: &SharedTaskObserver, task_kind: TransportTaskKind) -> Self {
+        let task_observer = task_observer
+            .read()
+            .expect("Task observer lock shouldn't be poisoned")
+            .clone();
+        if let Some(o) = &task_observer {
+            o.on_task_busy(task_kind);
+        }
+        Self {
+            task_observer,
+            task_kind,
+        }
+    }
+}
+
+impl Drop for BusyTask {
+    fn drop(&mut self) {
+        if let Some(o) = &self.task_observer {
+            o.on_task_idle(self.task_kind);
+        }
+    }
+}
+
 impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
     type TransportParticipant = Box<
         dyn TransportParticipant<
@@ -268,11 +495,13 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
         let message_writer = Arc::new(MessageWriter::new(
             guid_prefix,
             default_unicast_socket.try_clone().expect("Socket cloning"),
+            self.max_message_size,
         ));
 
         let guid = Guid::new(guid_prefix, ENTITYID_PARTICIPANT);
 
         let (chanel_message_sender, chanel_message_receiver) = channel();
+        let task_observer = SharedTaskObserver::default();
 
         let global_participant = RtpsUdpTransportParticipant {
             guid,
@@ -281,7 +510,10 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
             metatraffic_unicast_locator_list,
             metatraffic_multicast_locator_list,
             fragment_size: self.fragment_size,
+            payload_prefragmentation: self.payload_prefragmentation,
+            fragment_reassembly_timeout: self.fragment_reassembly_timeout,
             chanel_message_sender: chanel_message_sender.clone(),
+            task_observer: task_observer.clone(),
         };
 
         let chanel_message_sender_clone = chanel_message_sender.clone();
@@ -351,76 +583,35 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
             })
             .expect("failed to spawn thread");
 
+        let mut endpoint_task = EndpointTask {
+            message_writer,
+            stateless_reader_list: Vec::new(),
+            stateful_reader_list: Vec::new(),
+            stateful_writer_list: Vec::new(),
+            asynchronous_writer_list: Vec::new(),
+            flow_controller_list: self
+                .flow_controllers
+                .iter()
+                .map(FlowController::new)
+                .collect(),
+            publication_pending: false,
+        };
         std::thread::Builder::new()
             .name("Socket receiver".to_string())
-            .spawn(move || -> ! {
-                let mut stateless_reader_list = Vec::new();
-                let mut stateful_reader_list = Vec::new();
-                let mut stateful_writer_list = Vec::new();
-                loop {
-                    if let Ok(chanel_message) = chanel_message_receiver.recv() {
-                        match chanel_message {
-                            ChannelMessageKind::AddStatelessReader(stateless_reader) => {
-                                stateless_reader_list.push(stateless_reader)
-                            }
-                            ChannelMessageKind::AddStatefulReader(stateful_reader) => {
-                                stateful_reader_list.push(stateful_reader)
-                            }
-                            ChannelMessageKind::AddStatefulWriter(stateful_writer) => {
-                                stateful_writer_list.push(stateful_writer)
-                            }
-                            ChannelMessageKind::MetatrafficMulticastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
-                            }
-                            ChannelMessageKind::MetatrafficUnicastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
-                            }
-                            ChannelMessageKind::DefaultUnicastSocket(datagram) => {
-                                block_on(async {
-                                    process_message(
-                                        &datagram,
-                                        &message_writer,
-                                        &RtpsUdpTransportClock,
-                                        &mut stateless_reader_list,
-                                        &stateful_reader_list,
-                                        &stateful_writer_list,
-                                    )
-                                    .await
-                                });
-                            }
-                            ChannelMessageKind::Poke => block_on(async {
-                                for rtps_stateful_writer in &stateful_writer_list {
-                                    rtps_stateful_writer
-                                        .lock()
-                                        .await
-                                        .write_message(
-                                            message_writer.as_ref(),
-                                            &RtpsUdpTransportClock,
-                                        )
-                                        .await;
-                                }
-                            }),
-                        }
+            .spawn(move || {
+                while let Ok(chanel_message) = chanel_message_receiver.recv() {
+                    // The changes of the asynchronous writers added while the queued messages are processed are
+                    // all sent at once
+                    for chanel_message in
+                        core::iter::once(chanel_message).chain(chanel_message_receiver.try_iter())
+                    {
+                        let _busy_task = BusyTask::new(&task_observer, TransportTaskKind::Receiver);
+                        block_on(endpoint_task.process_chanel_message(chanel_message));
+                    }
+                    if endpoint_task.publication_pending {
+                        let _busy_task =
+                            BusyTask::new(&task_observer, TransportTaskKind::Publisher);
+                        block_on(endpoint_task.publish_asynchronous_writers());
                     }
                 }
             })
@@ -430,32 +621,390 @@ impl TransportParticipantFactory for RtpsUdpTransportParticipantFactory {
     }
 }
 
-async fn process_message(
-    datagram: &[u8],
-    message_writer: &MessageWriter,
-    clock: &impl Clock,
-    stateless_reader_list: &mut [RtpsStatelessReader],
-    stateful_reader_list: &[Arc<Mutex<RtpsStatefulReader>>],
-    stateful_writer_list: &[Arc<Mutex<RtpsStatefulWriter>>],
-) {
-    for stateless_reader in stateless_reader_list {
-        stateless_reader.process_message(datagram).await.ok();
+// Entity ids to which the submessages of a datagram are addressed
+#[derive(Default)]
+struct DatagramDestinations {
+    writer_id_list: Vec<EntityId>,
+    reader_id_list: Vec<EntityId>,
+}
+
+impl DatagramDestinations {
+    fn new(rtps_message: &RtpsMessageRead) -> Self {
+        let mut destinations = Self::default();
+        for submessage in rtps_message.submessages() {
+            match submessage {
+                RtpsSubmessageReadKind::AckNack(s) => {
+                    destinations.writer_id_list.push(*s.writer_id())
+                }
+                RtpsSubmessageReadKind::NackFrag(s) => {
+                    destinations.writer_id_list.push(s.writer_id())
+                }
+                RtpsSubmessageReadKind::Data(s) => destinations.reader_id_list.push(s.reader_id()),
+                RtpsSubmessageReadKind::DataFrag(s) => {
+                    destinations.reader_id_list.push(s.reader_id())
+                }
+                RtpsSubmessageReadKind::Gap(s) => destinations.reader_id_list.push(s.reader_id()),
+                RtpsSubmessageReadKind::Heartbeat(s) => {
+                    destinations.reader_id_list.push(s.reader_id())
+                }
+                RtpsSubmessageReadKind::HeartbeatFrag(s) => {
+                    destinations.reader_id_list.push(s.reader_id())
+                }
+                _ => (),
+            }
+        }
+        destinations
     }
-    for stateful_reader in stateful_reader_list {
-        stateful_reader
-            .lock()
-            .await
-            .process_message(datagram, message_writer)
-            .await
-            .ok();
+
+    fn contains_writer(&self, writer_id: &EntityId) -> bool {
+        self.writer_id_list.contains(writer_id)
     }
-    for stateful_writer in stateful_writer_list {
-        stateful_writer
-            .lock()
-            .await
-            .process_message(datagram, message_writer, clock)
-            .await
-            .ok();
+
+    fn contains_reader(&self, reader_id: &EntityId) -> bool {
+        self.reader_id_list
+            .iter()
+            .any(|id| id == reader_id || id == &ENTITYID_UNKNOWN)
+    }
+}
+
+// Task owning the endpoints of the participant. It processes the messages of its channel one at a time, so the
+// state of the endpoints is never shared with the application.
+struct EndpointTask {
+    message_writer: Arc<MessageWriter>,
+    stateless_reader_list: Vec<RtpsStatelessReader>,
+    stateful_reader_list: Vec<(EntityId, StatefulReaderEndpoint)>,
+    stateful_writer_list: Vec<(EntityId, StatefulWriterEndpoint)>,
+    asynchronous_writer_list: Vec<EntityId>,
+    flow_controller_list: Vec<FlowController>,
+    publication_pending: bool,
+}
+
+impl EndpointTask {
+    async fn process_chanel_message(&mut self, chanel_message: ChannelMessageKind) {
+        match chanel_message {
+            ChannelMessageKind::AddStatelessReader(stateless_reader) => {
+                self.stateless_reader_list.push(stateless_reader)
+            }
+            ChannelMessageKind::AddStatefulReader(reader_id, stateful_reader) => self
+                .stateful_reader_list
+                .push((reader_id, *stateful_reader)),
+            ChannelMessageKind::AddStatefulWriter(writer_id, stateful_writer) => self
+                .stateful_writer_list
+                .push((writer_id, *stateful_writer)),
+            ChannelMessageKind::StatefulReaderOperation(reader_id, operation) => {
+                self.process_stateful_reader_operation(reader_id, operation)
+            }
+            ChannelMessageKind::StatefulWriterOperation(writer_id, operation) => {
+                self.process_stateful_writer_operation(writer_id, operation)
+                    .await
+            }
+            ChannelMessageKind::SetFlowControllerLimit(settings) => {
+                match self
+                    .flow_controller_list
+                    .iter()
+                    .find(|f| f.name == settings.name)
+                {
+                    Some(flow_controller) => {
+                        flow_controller.set_limit(settings.bytes_per_period, settings.period)
+                    }
+                    None => self
+                        .flow_controller_list
+                        .push(FlowController::new(&settings)),
+                }
+            }
+            ChannelMessageKind::MetatrafficMulticastSocket(datagram)
+            | ChannelMessageKind::MetatrafficUnicastSocket(datagram)
+            | ChannelMessageKind::DefaultUnicastSocket(datagram) => {
+                self.process_message(&datagram, &RtpsUdpTransportClock)
+                    .await
+            }
+            ChannelMessageKind::Poke => {
+                // The messages held by the flow controllers are sent as their periods elapse, before the ones
+                // written now by their writers
+                for flow_controller in &self.flow_controller_list {
+                    flow_controller
+                        .send_queued_messages(&self.message_writer)
+                        .await;
+                }
+                for (_, stateful_writer) in &mut self.stateful_writer_list {
+                    stateful_writer
+                        .write_message(&self.message_writer, &self.flow_controller_list)
+                        .await;
+                    stateful_writer.publish_state();
+                }
+                // The delayed heartbeat responses of the readers are sent the same way
+                for (_, stateful_reader) in &mut self.stateful_reader_list {
+                    stateful_reader
+                        .rtps_stateful_reader
+                        .write_message(self.message_writer.as_ref(), &RtpsUdpTransportClock)
+                        .await;
+                    stateful_reader.publish_state();
+                }
+            }
+        }
+    }
+
+    fn process_stateful_reader_operation(
+        &mut self,
+        reader_id: EntityId,
+        operation: StatefulReaderOperation,
+    ) {
+        let Some((_, stateful_reader)) = self
+            .stateful_reader_list
+            .iter_mut()
+            .find(|(id, _)| id == &reader_id)
+        else {
+            return;
+        };
+        let pending_operation = matches!(
+            operation,
+            StatefulReaderOperation::AddMatchedWriter(_)
+                | StatefulReaderOperation::RequestHistoricalData
+        );
+        let rtps_stateful_reader = &mut stateful_reader.rtps_stateful_reader;
+        match operation {
+            StatefulReaderOperation::AddMatchedWriter(writer_proxy) => {
+                rtps_stateful_reader.add_matched_writer(&writer_proxy)
+            }
+            StatefulReaderOperation::RemoveMatchedWriter(remote_writer_guid) => {
+                rtps_stateful_reader.delete_matched_writer(remote_writer_guid)
+            }
+            StatefulReaderOperation::RequestHistoricalData => {
+                rtps_stateful_reader.request_historical_data()
+            }
+            StatefulReaderOperation::SetHeartbeatResponseTiming {
+                heartbeat_response_delay,
+                heartbeat_suppression_duration,
+            } => rtps_stateful_reader.set_heartbeat_response_timing(
+                heartbeat_response_delay,
+                heartbeat_suppression_duration,
+            ),
+            StatefulReaderOperation::ReserveResources {
+                max_matched_writers,
+                reply_sender,
+            } => {
+                reply_sender
+                    .send(rtps_stateful_reader.reserve_resources(max_matched_writers))
+                    .ok();
+            }
+            StatefulReaderOperation::FragmentBufferMemoryUsage(reply_sender) => {
+                reply_sender
+                    .send(rtps_stateful_reader.fragment_buffer_memory_usage())
+                    .ok();
+            }
+        }
+        stateful_reader.publish_state();
+        if pending_operation {
+            stateful_reader
+                .state
+                .pending_operations
+                .fetch_sub(1, atomic::Ordering::Release);
+        }
+    }
+
+    async fn process_stateful_writer_operation(
+        &mut self,
+        writer_id: EntityId,
+        operation: StatefulWriterOperation,
+    ) {
+        let Some((_, stateful_writer)) = self
+            .stateful_writer_list
+            .iter_mut()
+            .find(|(id, _)| id == &writer_id)
+        else {
+            return;
+        };
+        let pending_operation = matches!(operation, StatefulWriterOperation::AddMatchedReader(_));
+        let rtps_stateful_writer = &mut stateful_writer.rtps_stateful_writer;
+        match operation {
+            StatefulWriterOperation::AddChange(cache_change) => {
+                rtps_stateful_writer.add_change(cache_change);
+                if stateful_writer.asynchronous_publishing {
+                    self.publication_pending = true;
+                } else {
+                    stateful_writer
+                        .write_message(&self.message_writer, &self.flow_controller_list)
+                        .await;
+                }
+            }
+            StatefulWriterOperation::RemoveChange(sequence_number) => {
+                rtps_stateful_writer.remove_change(sequence_number)
+            }
+            StatefulWriterOperation::AddMatchedReader(reader_proxy) => {
+                rtps_stateful_writer.add_matched_reader(&reader_proxy)
+            }
+            StatefulWriterOperation::RemoveMatchedReader(remote_reader_guid) => {
+                rtps_stateful_writer.delete_matched_reader(remote_reader_guid)
+            }
+            StatefulWriterOperation::SetChangeLifecycleObserver(change_lifecycle_observer) => {
+                rtps_stateful_writer.set_change_lifecycle_observer(change_lifecycle_observer)
+            }
+            StatefulWriterOperation::SetBatching(batching) => {
+                rtps_stateful_writer.set_batching(batching)
+            }
+            StatefulWriterOperation::SetReliabilityTiming {
+                heartbeat_period,
+                nack_response_delay,
+                nack_suppression_duration,
+                min_resend_interval,
+            } => rtps_stateful_writer.set_reliability_timing(
+                heartbeat_period,
+                nack_response_delay,
+                nack_suppression_duration,
+                min_resend_interval,
+            ),
+            StatefulWriterOperation::SetSamplesPerHeartbeat(samples_per_heartbeat) => {
+                rtps_stateful_writer.set_samples_per_heartbeat(samples_per_heartbeat)
+            }
+            StatefulWriterOperation::SetDisablePositiveAcks(keep_duration) => {
+                rtps_stateful_writer.set_disable_positive_acks(keep_duration)
+            }
+            StatefulWriterOperation::SetFlowController(flow_controller_name) => {
+                stateful_writer.flow_controller = self
+                    .flow_controller_list
+                    .iter()
+                    .position(|f| f.name == flow_controller_name);
+                if stateful_writer.flow_controller.is_none()
+                    && !flow_controller_name.is_empty()
+                    && DiagnosticSubsystem::Transport.is_enabled(DiagnosticVerbosity::Warn)
+                {
+                    tracing::warn!(
+                        target: DiagnosticSubsystem::Transport.target(),
+                        flow_controller = flow_controller_name,
+                        "Unknown flow controller, the writer sends its messages unthrottled"
+                    );
+                }
+            }
+            StatefulWriterOperation::SetAsynchronousPublishing(asynchronous_publishing) => {
+                stateful_writer.asynchronous_publishing = asynchronous_publishing;
+                self.asynchronous_writer_list.retain(|id| id != &writer_id);
+                if asynchronous_publishing {
+                    self.asynchronous_writer_list.push(writer_id);
+                }
+            }
+            StatefulWriterOperation::SetTransportPriority(transport_priority) => {
+                stateful_writer.transport_priority = transport_priority
+            }
+            StatefulWriterOperation::Flush => {
+                rtps_stateful_writer.flush();
+                if stateful_writer.asynchronous_publishing {
+                    self.publication_pending = true;
+                } else {
+                    stateful_writer
+                        .write_message(&self.message_writer, &self.flow_controller_list)
+                        .await;
+                }
+            }
+            StatefulWriterOperation::ReserveResources {
+                max_changes,
+                max_matched_readers,
+                reply_sender,
+            } => {
+                reply_sender
+                    .send(rtps_stateful_writer.reserve_resources(max_changes, max_matched_readers))
+                    .ok();
+            }
+            StatefulWriterOperation::HistoryMemoryUsage(reply_sender) => {
+                reply_sender
+                    .send(rtps_stateful_writer.history_memory_usage())
+                    .ok();
+            }
+            StatefulWriterOperation::FragmentBufferMemoryUsage(reply_sender) => {
+                reply_sender
+                    .send(rtps_stateful_writer.fragment_buffer_memory_usage())
+                    .ok();
+            }
+            StatefulWriterOperation::TakeDataBytesSent(reply_sender) => {
+                reply_sender
+                    .send(rtps_stateful_writer.take_data_bytes_sent())
+                    .ok();
+            }
+        }
+        stateful_writer.publish_state();
+        if pending_operation {
+            stateful_writer
+                .state
+                .pending_operations
+                .fetch_sub(1, atomic::Ordering::Release);
+        }
+    }
+
+    // The writers with a higher transport priority are served first and those with the same priority in the order
+    // in which their asynchronous publishing was enabled
+    async fn publish_asynchronous_writers(&mut self) {
+        self.publication_pending = false;
+        let mut asynchronous_writer_list: Vec<_> = self
+            .asynchronous_writer_list
+            .iter()
+            .filter_map(|writer_id| {
+                self.stateful_writer_list
+                    .iter()
+                    .position(|(id, _)| id == writer_id)
+            })
+            .collect();
+        asynchronous_writer_list.sort_by_key(|&index| {
+            core::cmp::Reverse(self.stateful_writer_list[index].1.transport_priority)
+        });
+        for index in asynchronous_writer_list {
+            let stateful_writer = &mut self.stateful_writer_list[index].1;
+            stateful_writer
+                .write_message(&self.message_writer, &self.flow_controller_list)
+                .await;
+            stateful_writer.publish_state();
+        }
+    }
+
+    async fn process_message(&mut self, datagram: &[u8], clock: &impl Clock) {
+        if DiagnosticSubsystem::Transport.is_enabled(DiagnosticVerbosity::Trace) {
+            tracing::trace!(
+                target: DiagnosticSubsystem::Transport.target(),
+                length = datagram.len(),
+                "Received datagram"
+            );
+        }
+        for stateless_reader in &mut self.stateless_reader_list {
+            stateless_reader.process_message(datagram).await.ok();
+        }
+
+        // Only the stateful endpoints to which the datagram is addressed process it
+        let Ok(rtps_message) = RtpsMessageRead::try_from(datagram) else {
+            if DiagnosticSubsystem::Transport.is_enabled(DiagnosticVerbosity::Debug) {
+                tracing::debug!(
+                    target: DiagnosticSubsystem::Transport.target(),
+                    length = datagram.len(),
+                    "Discarding datagram which is not a valid RTPS message"
+                );
+            }
+            return;
+        };
+        let destinations = DatagramDestinations::new(&rtps_message);
+        for (reader_id, stateful_reader) in &mut self.stateful_reader_list {
+            if destinations.contains_reader(reader_id) {
+                stateful_reader
+                    .rtps_stateful_reader
+                    .process_message(datagram, self.message_writer.as_ref(), clock)
+                    .await
+                    .ok();
+                stateful_reader.publish_state();
+            }
+        }
+        for (writer_id, stateful_writer) in &mut self.stateful_writer_list {
+            if destinations.contains_writer(writer_id) {
+                let message_packer = MessagePacker::new(
+                    &self.message_writer,
+                    stateful_writer
+                        .flow_controller
+                        .and_then(|i| self.flow_controller_list.get(i)),
+                );
+                stateful_writer
+                    .rtps_stateful_writer
+                    .process_message(datagram, &message_packer, clock)
+                    .await
+                    .ok();
+                message_packer.flush().await;
+                stateful_writer.publish_state();
+            }
+        }
     }
 }
 
@@ -535,13 +1084,43 @@ impl UdpLocator {
 struct MessageWriter {
     guid_prefix: GuidPrefix,
     socket: UdpSocket,
+    max_message_size: usize,
 }
 
 impl MessageWriter {
-    fn new(guid_prefix: GuidPrefix, socket: UdpSocket) -> Self {
+    fn new(guid_prefix: GuidPrefix, socket: UdpSocket, max_message_size: usize) -> Self {
         Self {
             guid_prefix,
             socket,
+            max_message_size,
+        }
+    }
+
+    fn send_datagram(&self, datagram: &[u8], destination_locator: Locator) {
+        match self
+            .socket
+            .send_to(datagram, UdpLocator(destination_locator))
+        {
+            Ok(_) => {
+                if DiagnosticSubsystem::Transport.is_enabled(DiagnosticVerbosity::Trace) {
+                    tracing::trace!(
+                        target: DiagnosticSubsystem::Transport.target(),
+                        ?destination_locator,
+                        length = datagram.len(),
+                        "Sent datagram"
+                    );
+                }
+            }
+            Err(error) => {
+                if DiagnosticSubsystem::Transport.is_enabled(DiagnosticVerbosity::Debug) {
+                    tracing::debug!(
+                        target: DiagnosticSubsystem::Transport.target(),
+                        ?destination_locator,
+                        %error,
+                        "Failed to send datagram"
+                    );
+                }
+            }
         }
     }
 }
@@ -563,15 +1142,11 @@ impl WriteMessage for MessageWriter {
                     .collect();
                 for address in interface_addresses {
                     if socket2.set_multicast_if_v4(&address).is_ok() {
-                        self.socket
-                            .send_to(datagram, UdpLocator(destination_locator))
-                            .ok();
+                        self.send_datagram(datagram, destination_locator);
                     }
                 }
             } else {
-                self.socket
-                    .send_to(datagram, UdpLocator(destination_locator))
-                    .ok();
+                self.send_datagram(datagram, destination_locator);
             }
         }
     }
@@ -581,6 +1156,187 @@ impl WriteMessage for MessageWriter {
     }
 }
 
+// Packs the consecutive messages written to the same locators into a single datagram up to the maximum
+// message size of the message writer. All the messages have the same RTPS header so only the one of the
+// first message is kept. The last datagram is only sent when the packer is flushed.
+struct MessagePacker<'a> {
+    message_writer: &'a MessageWriter,
+    flow_controller: Option<&'a FlowController>,
+    packed_message: std::sync::Mutex<Option<(Vec<u8>, Vec<Locator>)>>,
+}
+
+impl<'a> MessagePacker<'a> {
+    fn new(message_writer: &'a MessageWriter, flow_controller: Option<&'a FlowController>) -> Self {
+        Self {
+            message_writer,
+            flow_controller,
+            packed_message: std::sync::Mutex::new(None),
+        }
+    }
+
+    async fn send(&self, message: Vec<u8>, locator_list: Vec<Locator>) {
+        match self.flow_controller {
+            Some(flow_controller) => {
+                flow_controller
+                    .write_message(self.message_writer, message, locator_list)
+                    .await
+            }
+            None => {
+                self.message_writer
+                    .write_message(&message, &locator_list)
+                    .await
+            }
+        }
+    }
+
+    // Appends the message to the packed one if possible and otherwise returns the packed message
+    // which has to be sent before starting a new one
+    fn pack(&self, datagram: &[u8], locator_list: &[Locator]) -> Option<(Vec<u8>, Vec<Locator>)> {
+        let mut packed_message = self
+            .packed_message
+            .lock()
+            .expect("Packed message lock not poisoned");
+        if let Some((message, message_locator_list)) = packed_message.as_mut() {
+            if message_locator_list == locator_list
+                && datagram.len() > RTPS_MESSAGE_HEADER_SIZE
+                && datagram[..RTPS_MESSAGE_HEADER_SIZE] == message[..RTPS_MESSAGE_HEADER_SIZE]
+                && message.len() + datagram.len() - RTPS_MESSAGE_HEADER_SIZE
+                    <= self.message_writer.max_message_size
+            {
+                message.extend_from_slice(&datagram[RTPS_MESSAGE_HEADER_SIZE..]);
+                return None;
+            }
+        }
+        packed_message.replace((datagram.to_vec(), locator_list.to_vec()))
+    }
+
+    async fn flush(&self) {
+        let packed_message = self
+            .packed_message
+            .lock()
+            .expect("Packed message lock not poisoned")
+            .take();
+        if let Some((message, locator_list)) = packed_message {
+            self.send(message, locator_list).await;
+        }
+    }
+}
+
+impl WriteMessage for MessagePacker<'_> {
+    async fn write_message(&self, datagram: &[u8], locator_list: &[Locator]) {
+        if let Some((message, message_locator_list)) = self.pack(datagram, locator_list) {
+            self.send(message, message_locator_list).await;
+        }
+    }
+
+    fn guid_prefix(&self) -> GuidPrefix {
+        self.message_writer.guid_prefix
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FlowControllerSettings {
+    name: String,
+    bytes_per_period: usize,
+    period: core::time::Duration,
+}
+
+// Token bucket shared by the writers assigned to the flow controller. The bucket is refilled with the bytes
+// of a period at the end of each period, up to the bytes of one period, and the messages are sent in the
+// order they were written while the bucket has enough bytes left for them. The flow controllers are owned by the
+// endpoint task and their state is only behind a mutex since the messages are written through shared references.
+struct FlowController {
+    name: String,
+    state: std::sync::Mutex<FlowControllerState>,
+}
+
+struct FlowControllerState {
+    bytes_per_period: usize,
+    period: core::time::Duration,
+    available_bytes: usize,
+    refill_time: std::time::Instant,
+    queued_messages: VecDeque<(Vec<u8>, Vec<Locator>)>,
+}
+
+impl FlowController {
+    fn new(settings: &FlowControllerSettings) -> Self {
+        Self {
+            name: settings.name.clone(),
+            state: std::sync::Mutex::new(FlowControllerState {
+                bytes_per_period: settings.bytes_per_period,
+                period: settings.period,
+                available_bytes: settings.bytes_per_period,
+                refill_time: std::time::Instant::now(),
+                queued_messages: VecDeque::new(),
+            }),
+        }
+    }
+
+    // The bytes already available are kept up to the bytes of a period of the new limit
+    fn set_limit(&self, bytes_per_period: usize, period: core::time::Duration) {
+        let mut state = self
+            .state
+            .lock()
+            .expect("Flow controller lock not poisoned");
+        state.bytes_per_period = bytes_per_period;
+        state.period = period;
+        state.available_bytes = state.available_bytes.min(bytes_per_period);
+    }
+
+    async fn write_message(
+        &self,
+        message_writer: &MessageWriter,
+        message: Vec<u8>,
+        locator_list: Vec<Locator>,
+    ) {
+        self.state
+            .lock()
+            .expect("Flow controller lock not poisoned")
+            .queued_messages
+            .push_back((message, locator_list));
+        self.send_queued_messages(message_writer).await;
+    }
+
+    async fn send_queued_messages(&self, message_writer: &MessageWriter) {
+        for (message, locator_list) in self.take_sendable_messages() {
+            message_writer.write_message(&message, &locator_list).await;
+        }
+    }
+
+    // A message bigger than the bytes of a period is sent once the bucket is full so that it is not held
+    // forever, emptying the bucket
+    fn take_sendable_messages(&self) -> Vec<(Vec<u8>, Vec<Locator>)> {
+        let mut state = self
+            .state
+            .lock()
+            .expect("Flow controller lock not poisoned");
+        let bytes_per_period = state.bytes_per_period;
+        let period = state.period;
+        let elapsed = state.refill_time.elapsed();
+        let elapsed_periods = elapsed.as_nanos() / period.as_nanos();
+        if elapsed_periods > 0 {
+            state.available_bytes = (state.available_bytes as u128
+                + elapsed_periods * bytes_per_period as u128)
+                .min(bytes_per_period as u128) as usize;
+            state.refill_time += elapsed
+                - core::time::Duration::from_nanos((elapsed.as_nanos() % period.as_nanos()) as u64);
+        }
+
+        let mut sendable_messages = Vec::new();
+        while let Some((message, locator_list)) = state.queued_messages.front() {
+            let message_bytes = message.len() * locator_list.len();
+            if message_bytes > state.available_bytes && state.available_bytes < bytes_per_period {
+                break;
+            }
+            state.available_bytes = state.available_bytes.saturating_sub(message_bytes);
+            if let Some(message) = state.queued_messages.pop_front() {
+                sendable_messages.push(message);
+            }
+        }
+        sendable_messages
+    }
+}
+
 pub struct RtpsUdpTransportClock;
 
 impl Clock for RtpsUdpTransportClock {
@@ -598,7 +1354,10 @@ pub struct RtpsUdpTransportParticipant {
     metatraffic_unicast_locator_list: Vec<Locator>,
     metatraffic_multicast_locator_list: Vec<Locator>,
     fragment_size: usize,
+    payload_prefragmentation: bool,
+    fragment_reassembly_timeout: core::time::Duration,
     chanel_message_sender: Sender<ChannelMessageKind>,
+    task_observer: SharedTaskObserver,
 }
 
 impl TransportParticipant for RtpsUdpTransportParticipant {
@@ -629,6 +1388,28 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     fn default_multicast_locator_list(&self) -> &[Locator] {
         &[]
     }
+    fn set_task_observer(&mut self, task_observer: Option<Arc<dyn TransportTaskObserver>>) {
+        *self
+            .task_observer
+            .write()
+            .expect("Task observer lock shouldn't be poisoned") = task_observer;
+    }
+    fn set_flow_controller_limit(
+        &mut self,
+        name: &str,
+        bytes_per_period: usize,
+        period: core::time::Duration,
+    ) {
+        self.chanel_message_sender
+            .send(ChannelMessageKind::SetFlowControllerLimit(
+                FlowControllerSettings {
+                    name: name.to_string(),
+                    bytes_per_period,
+                    period,
+                },
+            ))
+            .expect("chanel_message receiver alive");
+    }
     fn create_stateless_reader(
         &mut self,
         entity_id: EntityId,
@@ -711,52 +1492,111 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     ) -> Self::StatefulReader {
         struct StatefulReader {
             guid: Guid,
-            rtps_stateful_reader: Arc<Mutex<RtpsStatefulReader>>,
+            state: Arc<StatefulReaderState>,
+            chanel_message_sender: Sender<ChannelMessageKind>,
+        }
+        impl StatefulReader {
+            fn send_operation(&self, operation: StatefulReaderOperation) {
+                self.chanel_message_sender
+                    .send(ChannelMessageKind::StatefulReaderOperation(
+                        self.guid.entity_id(),
+                        operation,
+                    ))
+                    .expect("chanel_message receiver alive");
+            }
+
+            fn send_pending_operation(&self, operation: StatefulReaderOperation) {
+                self.state
+                    .pending_operations
+                    .fetch_add(1, atomic::Ordering::AcqRel);
+                self.send_operation(operation);
+            }
+
+            fn request<T>(
+                &self,
+                operation: impl FnOnce(Sender<T>) -> StatefulReaderOperation,
+            ) -> T {
+                let (reply_sender, reply_receiver) = channel();
+                self.send_operation(operation(reply_sender));
+                reply_receiver
+                    .recv()
+                    .expect("chanel_message receiver alive")
+            }
         }
         impl TransportStatefulReader for StatefulReader {
             fn guid(&self) -> Guid {
                 self.guid
             }
             fn is_historical_data_received(&self) -> bool {
-                block_on(async {
-                    self.rtps_stateful_reader
-                        .lock()
-                        .await
-                        .is_historical_data_received()
-                })
+                self.state
+                    .pending_operations
+                    .load(atomic::Ordering::Acquire)
+                    == 0
+                    && self
+                        .state
+                        .historical_data_received
+                        .load(atomic::Ordering::Acquire)
             }
             fn add_matched_writer(&mut self, writer_proxy: WriterProxy) {
-                block_on(async {
-                    self.rtps_stateful_reader
-                        .lock()
-                        .await
-                        .add_matched_writer(&writer_proxy)
-                })
+                self.send_pending_operation(StatefulReaderOperation::AddMatchedWriter(
+                    writer_proxy,
+                ));
             }
             fn remove_matched_writer(&mut self, remote_writer_guid: Guid) {
-                block_on(async {
-                    self.rtps_stateful_reader
-                        .lock()
-                        .await
-                        .delete_matched_writer(remote_writer_guid)
+                self.send_operation(StatefulReaderOperation::RemoveMatchedWriter(
+                    remote_writer_guid,
+                ));
+            }
+            fn request_historical_data(&mut self) {
+                self.send_pending_operation(StatefulReaderOperation::RequestHistoricalData);
+            }
+            fn fragment_buffer_memory_usage(&self) -> usize {
+                self.request(StatefulReaderOperation::FragmentBufferMemoryUsage)
+            }
+            fn reserve_resources(
+                &mut self,
+                max_matched_writers: usize,
+            ) -> Result<(), TryReserveError> {
+                self.request(|reply_sender| StatefulReaderOperation::ReserveResources {
+                    max_matched_writers,
+                    reply_sender,
                 })
             }
+            fn set_heartbeat_response_timing(
+                &mut self,
+                heartbeat_response_delay: core::time::Duration,
+                heartbeat_suppression_duration: core::time::Duration,
+            ) {
+                self.send_operation(StatefulReaderOperation::SetHeartbeatResponseTiming {
+                    heartbeat_response_delay,
+                    heartbeat_suppression_duration,
+                });
+            }
         }
 
         let guid = Guid::new(self.guid.prefix(), entity_id);
-        let rtps_stateful_reader = Arc::new(Mutex::new(RtpsStatefulReader::new(
-            guid,
-            reader_history_cache,
-            reliability_kind,
-        )));
+        let mut rtps_stateful_reader =
+            RtpsStatefulReader::new(guid, reader_history_cache, reliability_kind);
+        rtps_stateful_reader.set_fragment_reassembly_timeout(self.fragment_reassembly_timeout);
+        let state = Arc::new(StatefulReaderState {
+            historical_data_received: AtomicBool::new(
+                rtps_stateful_reader.is_historical_data_received(),
+            ),
+            pending_operations: AtomicUsize::new(0),
+        });
         self.chanel_message_sender
             .send(ChannelMessageKind::AddStatefulReader(
-                rtps_stateful_reader.clone(),
+                entity_id,
+                Box::new(StatefulReaderEndpoint {
+                    rtps_stateful_reader,
+                    state: state.clone(),
+                }),
             ))
             .expect("chanel_message receiver alive");
         Box::new(StatefulReader {
             guid,
-            rtps_stateful_reader,
+            state,
+            chanel_message_sender: self.chanel_message_sender.clone(),
         })
     }
 
@@ -767,9 +1607,44 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
     ) -> Self::StatefulWriter {
         struct StatefulWriter {
             guid: Guid,
-            rtps_stateful_writer: Arc<Mutex<RtpsStatefulWriter>>,
-            message_writer: Arc<MessageWriter>,
+            state: Arc<StatefulWriterState>,
             default_unicast_locator_list: Vec<Locator>,
+            asynchronous_publishing: bool,
+            chanel_message_sender: Sender<ChannelMessageKind>,
+        }
+        impl StatefulWriter {
+            fn send_operation(&self, operation: StatefulWriterOperation) {
+                self.chanel_message_sender
+                    .send(ChannelMessageKind::StatefulWriterOperation(
+                        self.guid.entity_id(),
+                        operation,
+                    ))
+                    .expect("chanel_message receiver alive");
+            }
+
+            fn request<T>(
+                &self,
+                operation: impl FnOnce(Sender<T>) -> StatefulWriterOperation,
+            ) -> T {
+                let (reply_sender, reply_receiver) = channel();
+                self.send_operation(operation(reply_sender));
+                reply_receiver
+                    .recv()
+                    .expect("chanel_message receiver alive")
+            }
+        }
+        // The asynchronous publisher must not keep serving a deleted writer
+        impl Drop for StatefulWriter {
+            fn drop(&mut self) {
+                if self.asynchronous_publishing {
+                    self.chanel_message_sender
+                        .send(ChannelMessageKind::StatefulWriterOperation(
+                            self.guid.entity_id(),
+                            StatefulWriterOperation::SetAsynchronousPublishing(false),
+                        ))
+                        .ok();
+                }
+            }
         }
         impl TransportStatefulWriter for StatefulWriter {
             fn guid(&self) -> Guid {
@@ -779,12 +1654,15 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
                 self
             }
             fn is_change_acknowledged(&self, sequence_number: i64) -> bool {
-                block_on(async {
-                    self.rtps_stateful_writer
-                        .lock()
-                        .await
-                        .is_change_acknowledged(sequence_number)
-                })
+                self.state
+                    .pending_operations
+                    .load(atomic::Ordering::Acquire)
+                    == 0
+                    && sequence_number
+                        <= self
+                            .state
+                            .acknowledged_seq_num
+                            .load(atomic::Ordering::Acquire)
             }
             fn add_matched_reader(&mut self, mut reader_proxy: ReaderProxy) {
                 if reader_proxy.unicast_locator_list.is_empty() {
@@ -792,68 +1670,134 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
                         .unicast_locator_list
                         .clone_from(&self.default_unicast_locator_list);
                 }
-                block_on(async {
-                    self.rtps_stateful_writer
-                        .lock()
-                        .await
-                        .add_matched_reader(&reader_proxy);
-                })
+                self.state
+                    .pending_operations
+                    .fetch_add(1, atomic::Ordering::AcqRel);
+                self.send_operation(StatefulWriterOperation::AddMatchedReader(reader_proxy));
             }
             fn remove_matched_reader(&mut self, remote_reader_guid: Guid) {
-                block_on(async {
-                    self.rtps_stateful_writer
-                        .lock()
-                        .await
-                        .delete_matched_reader(remote_reader_guid);
+                self.send_operation(StatefulWriterOperation::RemoveMatchedReader(
+                    remote_reader_guid,
+                ));
+            }
+            fn set_change_lifecycle_observer(
+                &mut self,
+                change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+            ) {
+                self.send_operation(StatefulWriterOperation::SetChangeLifecycleObserver(
+                    change_lifecycle_observer,
+                ));
+            }
+            fn set_batching(&mut self, batching: bool) {
+                self.send_operation(StatefulWriterOperation::SetBatching(batching));
+            }
+            fn set_reliability_timing(
+                &mut self,
+                heartbeat_period: core::time::Duration,
+                nack_response_delay: core::time::Duration,
+                nack_suppression_duration: core::time::Duration,
+                min_resend_interval: core::time::Duration,
+            ) {
+                self.send_operation(StatefulWriterOperation::SetReliabilityTiming {
+                    heartbeat_period,
+                    nack_response_delay,
+                    nack_suppression_duration,
+                    min_resend_interval,
+                });
+            }
+            fn set_samples_per_heartbeat(&mut self, samples_per_heartbeat: Option<usize>) {
+                self.send_operation(StatefulWriterOperation::SetSamplesPerHeartbeat(
+                    samples_per_heartbeat,
+                ));
+            }
+            fn set_disable_positive_acks(&mut self, keep_duration: Option<core::time::Duration>) {
+                self.send_operation(StatefulWriterOperation::SetDisablePositiveAcks(
+                    keep_duration,
+                ));
+            }
+            fn set_flow_controller(&mut self, flow_controller_name: &str) {
+                self.send_operation(StatefulWriterOperation::SetFlowController(
+                    flow_controller_name.to_string(),
+                ));
+            }
+            fn set_asynchronous_publishing(&mut self, asynchronous_publishing: bool) {
+                self.send_operation(StatefulWriterOperation::SetAsynchronousPublishing(
+                    asynchronous_publishing,
+                ));
+                self.asynchronous_publishing = asynchronous_publishing;
+            }
+            fn set_transport_priority(&mut self, transport_priority: i32) {
+                self.send_operation(StatefulWriterOperation::SetTransportPriority(
+                    transport_priority,
+                ));
+            }
+            fn flush(&mut self) {
+                self.send_operation(StatefulWriterOperation::Flush);
+            }
+            fn history_memory_usage(&self) -> usize {
+                self.request(StatefulWriterOperation::HistoryMemoryUsage)
+            }
+            fn fragment_buffer_memory_usage(&self) -> usize {
+                self.request(StatefulWriterOperation::FragmentBufferMemoryUsage)
+            }
+            fn reserve_resources(
+                &mut self,
+                max_changes: usize,
+                max_matched_readers: usize,
+            ) -> Result<(), TryReserveError> {
+                self.request(|reply_sender| StatefulWriterOperation::ReserveResources {
+                    max_changes,
+                    max_matched_readers,
+                    reply_sender,
                 })
             }
+            fn take_data_bytes_sent(&mut self) -> Vec<(Guid, u64)> {
+                self.request(StatefulWriterOperation::TakeDataBytesSent)
+            }
         }
         impl HistoryCache for StatefulWriter {
             fn add_change(
                 &mut self,
                 cache_change: CacheChange,
             ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
-                let rtps_stateful_writer = self.rtps_stateful_writer.clone();
-                let message_writer = self.message_writer.clone();
-                Box::pin(async move {
-                    rtps_stateful_writer.lock().await.add_change(cache_change);
-                    rtps_stateful_writer
-                        .lock()
-                        .await
-                        .write_message(message_writer.as_ref(), &RtpsUdpTransportClock)
-                        .await;
-                })
+                self.send_operation(StatefulWriterOperation::AddChange(cache_change));
+                Box::pin(async {})
             }
 
             fn remove_change(
                 &mut self,
                 sequence_number: i64,
             ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
-                let rtps_stateful_writer = self.rtps_stateful_writer.clone();
-                Box::pin(async move {
-                    rtps_stateful_writer
-                        .lock()
-                        .await
-                        .remove_change(sequence_number);
-                })
+                self.send_operation(StatefulWriterOperation::RemoveChange(sequence_number));
+                Box::pin(async {})
             }
         }
 
         let guid = Guid::new(self.guid.prefix(), entity_id);
-        let rtps_stateful_writer = Arc::new(Mutex::new(RtpsStatefulWriter::new(
-            guid,
-            self.fragment_size,
-        )));
+        let rtps_stateful_writer =
+            RtpsStatefulWriter::new(guid, self.fragment_size, self.payload_prefragmentation);
+        let state = Arc::new(StatefulWriterState {
+            acknowledged_seq_num: AtomicI64::new(rtps_stateful_writer.acknowledged_seq_num()),
+            pending_operations: AtomicUsize::new(0),
+        });
         self.chanel_message_sender
             .send(ChannelMessageKind::AddStatefulWriter(
-                rtps_stateful_writer.clone(),
+                entity_id,
+                Box::new(StatefulWriterEndpoint {
+                    rtps_stateful_writer,
+                    flow_controller: None,
+                    asynchronous_publishing: false,
+                    transport_priority: 0,
+                    state: state.clone(),
+                }),
             ))
             .expect("chanel_message receiver alive");
         Box::new(StatefulWriter {
             guid,
-            rtps_stateful_writer,
-            message_writer: self.message_writer.clone(),
+            state,
             default_unicast_locator_list: self.default_unicast_locator_list.clone(),
+            asynchronous_publishing: false,
+            chanel_message_sender: self.chanel_message_sender.clone(),
         })
     }
 }
@@ -997,3 +1941,41 @@ impl TransportParticipant for RtpsUdpTransportParticipant {
 //         assert_eq!(cache_change, received_cache_change);
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dust_dds::rtps_conformance::{
+        READER_ENTITY_ID, STATEFUL_READER_VECTORS, STATEFUL_WRITER_VECTORS, WRITER_ENTITY_ID,
+    };
+
+    const OTHER_ENTITY_ID: EntityId = EntityId::new([0, 0, 3], 0);
+
+    #[test]
+    fn datagram_destinations_contain_the_addressed_endpoints() {
+        let data_datagram = STATEFUL_READER_VECTORS[0].datagrams[0];
+        let destinations =
+            DatagramDestinations::new(&RtpsMessageRead::try_from(data_datagram).unwrap());
+        assert!(destinations.contains_reader(&READER_ENTITY_ID));
+        assert!(!destinations.contains_reader(&OTHER_ENTITY_ID));
+        assert!(!destinations.contains_writer(&WRITER_ENTITY_ID));
+
+        let acknack_datagram = STATEFUL_WRITER_VECTORS[0].datagrams[0];
+        let destinations =
+            DatagramDestinations::new(&RtpsMessageRead::try_from(acknack_datagram).unwrap());
+        assert!(destinations.contains_writer(&WRITER_ENTITY_ID));
+        assert!(!destinations.contains_writer(&OTHER_ENTITY_ID));
+        assert!(!destinations.contains_reader(&READER_ENTITY_ID));
+    }
+
+    #[test]
+    fn datagram_destinations_contain_all_readers_for_unknown_reader_id() {
+        let mut data_datagram = STATEFUL_READER_VECTORS[0].datagrams[0].to_vec();
+        // Replace the readerId following the header, the submessage header and the extraFlags
+        data_datagram[28..32].copy_from_slice(&[0, 0, 0, 0]);
+        let destinations =
+            DatagramDestinations::new(&RtpsMessageRead::try_from(&data_datagram[..]).unwrap());
+        assert!(destinations.contains_reader(&READER_ENTITY_ID));
+        assert!(destinations.contains_reader(&OTHER_ENTITY_ID));
+    }
+}
@@ -0,0 +1,92 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Subsystems of Dust DDS whose diagnostics can be enabled independently. The diagnostics of each subsystem are
+/// emitted as [`tracing`] events with the target given by [`DiagnosticSubsystem::target`], so that they can also be
+/// routed and filtered by the tracing subscriber of the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSubsystem {
+    /// Discovery of the remote participants and endpoints and matching with the local endpoints.
+    Discovery,
+    /// Acknowledgment, repair and loss of the samples exchanged by the reliable endpoints.
+    Reliability,
+    /// Fragmentation of the large samples and reassembly of the received fragments.
+    Fragmentation,
+    /// Sending and reception of the RTPS messages over the network.
+    Transport,
+}
+
+impl DiagnosticSubsystem {
+    /// Target of the [`tracing`] events emitted by the subsystem.
+    pub const fn target(&self) -> &'static str {
+        match self {
+            DiagnosticSubsystem::Discovery => "dust_dds::discovery",
+            DiagnosticSubsystem::Reliability => "dust_dds::reliability",
+            DiagnosticSubsystem::Fragmentation => "dust_dds::fragmentation",
+            DiagnosticSubsystem::Transport => "dust_dds::transport",
+        }
+    }
+
+    /// Returns whether the diagnostics of the subsystem with the given verbosity are currently emitted.
+    pub fn is_enabled(&self, verbosity: DiagnosticVerbosity) -> bool {
+        verbosity != DiagnosticVerbosity::Off && verbosity <= get_verbosity(*self)
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            DiagnosticSubsystem::Discovery => 0,
+            DiagnosticSubsystem::Reliability => 1,
+            DiagnosticSubsystem::Fragmentation => 2,
+            DiagnosticSubsystem::Transport => 3,
+        }
+    }
+}
+
+/// Verbosity of the diagnostics of a [`DiagnosticSubsystem`], in increasing order of detail. Each verbosity also
+/// enables the diagnostics of the less detailed verbosities and corresponds to the [`tracing`] level of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticVerbosity {
+    /// No diagnostics are emitted.
+    Off,
+    /// Only the failures of the subsystem are reported.
+    Error,
+    /// Failures and abnormal situations, such as endpoints which can not be matched, are reported.
+    Warn,
+    /// Significant events, such as the discovery of a participant or the loss of samples, are also reported.
+    Info,
+    /// Details of the protocol, such as the matched endpoints, the repaired samples and the discarded messages,
+    /// are also reported.
+    Debug,
+    /// Every message and fragment processed by the subsystem is also reported.
+    Trace,
+}
+
+impl DiagnosticVerbosity {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => DiagnosticVerbosity::Off,
+            1 => DiagnosticVerbosity::Error,
+            2 => DiagnosticVerbosity::Warn,
+            3 => DiagnosticVerbosity::Info,
+            4 => DiagnosticVerbosity::Debug,
+            _ => DiagnosticVerbosity::Trace,
+        }
+    }
+}
+
+// The tracing subscriber is global to the application so the verbosity is shared by all the participants
+static VERBOSITY: [AtomicU8; 4] = [
+    AtomicU8::new(DiagnosticVerbosity::Warn as u8),
+    AtomicU8::new(DiagnosticVerbosity::Warn as u8),
+    AtomicU8::new(DiagnosticVerbosity::Warn as u8),
+    AtomicU8::new(DiagnosticVerbosity::Warn as u8),
+];
+
+/// Set the verbosity of the diagnostics of the subsystem.
+pub fn set_verbosity(subsystem: DiagnosticSubsystem, verbosity: DiagnosticVerbosity) {
+    VERBOSITY[subsystem.index()].store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Get the verbosity of the diagnostics of the subsystem.
+pub fn get_verbosity(subsystem: DiagnosticSubsystem) -> DiagnosticVerbosity {
+    DiagnosticVerbosity::from_u8(VERBOSITY[subsystem.index()].load(Ordering::Relaxed))
+}
@@ -1,8 +1,16 @@
 use core::{future::Future, pin::Pin};
 
-use alloc::{boxed::Box, sync::Arc};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
-use super::types::{ChangeKind, Guid, Time};
+use super::types::{ChangeKind, Guid, SequenceNumber, Time, SEQUENCENUMBER_UNKNOWN};
+
+/// Writer which originally wrote a change forwarded by another writer, like the one of a persistence service
+/// or a relay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalWriterInfo {
+    pub original_writer_guid: Guid,
+    pub original_writer_sn: SequenceNumber,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CacheChange {
@@ -11,6 +19,9 @@ pub struct CacheChange {
     pub sequence_number: i64,
     pub source_timestamp: Option<Time>,
     pub instance_handle: Option<[u8; 16]>,
+    pub coherent_set: Option<i64>,
+    pub directed_write: Vec<Guid>,
+    pub original_writer_info: Option<OriginalWriterInfo>,
     pub data_value: Arc<[u8]>,
 }
 
@@ -30,6 +41,25 @@ impl CacheChange {
     pub fn data_value(&self) -> &Arc<[u8]> {
         &self.data_value
     }
+
+    /// GUID of the writer which originally wrote the change, which is the writer of the change unless it was
+    /// forwarded.
+    pub fn original_writer_guid(&self) -> Guid {
+        self.original_writer_info
+            .map_or(self.writer_guid, |x| x.original_writer_guid)
+    }
+
+    /// Whether the change is addressed to the reader with the given GUID. A change without a list of readers
+    /// in its directed write is addressed to all of them.
+    pub fn is_addressed_to(&self, reader_guid: Guid) -> bool {
+        self.directed_write.is_empty() || self.directed_write.contains(&reader_guid)
+    }
+
+    /// Whether the change only marks the end of the coherent set of its writer. Such a change carries no data
+    /// and its coherent set is [`SEQUENCENUMBER_UNKNOWN`].
+    pub fn is_coherent_set_end(&self) -> bool {
+        self.coherent_set == Some(SEQUENCENUMBER_UNKNOWN) && self.data_value.is_empty()
+    }
 }
 
 pub trait HistoryCache: Send {
@@ -37,4 +67,14 @@ pub trait HistoryCache: Send {
         -> Pin<Box<dyn Future<Output = ()> + Send>>;
 
     fn remove_change(&mut self, sequence_number: i64) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    /// Notifies that changes of the writer identified by `writer_guid` are no longer available and will
+    /// never be received.
+    fn changes_lost(
+        &mut self,
+        _writer_guid: Guid,
+        _lost_change_count: i64,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(async {})
+    }
 }
@@ -1,3 +1,4 @@
+pub mod diagnostics;
 pub mod factory;
 pub mod history_cache;
 pub mod participant;
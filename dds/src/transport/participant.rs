@@ -1,4 +1,23 @@
 use super::types::{EntityId, Guid, Locator, ProtocolVersion, ReliabilityKind, VendorId};
+use alloc::sync::Arc;
+
+/// Task of a transport participant whose progress is reported to its [`TransportTaskObserver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportTaskKind {
+    /// Task processing the received messages and sending the periodic messages of the endpoints.
+    Receiver,
+    /// Task sending the changes of the writers which publish asynchronously.
+    Publisher,
+}
+
+/// Observer of the progress of the tasks of a transport participant, which allows supervising them. The observer
+/// is called from the tasks of the transport so it should return quickly.
+pub trait TransportTaskObserver: Send + Sync {
+    /// Called when the task starts processing an event.
+    fn on_task_busy(&self, task_kind: TransportTaskKind);
+    /// Called when the task is done processing an event and waits for the next one.
+    fn on_task_idle(&self, task_kind: TransportTaskKind);
+}
 
 pub trait TransportParticipant: Send {
     type HistoryCache;
@@ -15,6 +34,22 @@ pub trait TransportParticipant: Send {
     fn default_unicast_locator_list(&self) -> &[Locator];
     fn default_multicast_locator_list(&self) -> &[Locator];
 
+    /// Registers the observer of the progress of the tasks of the participant replacing any previous one.
+    /// Transports without tasks of their own ignore the observer.
+    fn set_task_observer(&mut self, _task_observer: Option<Arc<dyn TransportTaskObserver>>) {}
+
+    /// Adds a flow controller with the given name which sends at most `bytes_per_period` bytes of the messages of its
+    /// writers in each `period`, or changes the limit of the flow controller if it exists already. The writers are
+    /// assigned to it with [`TransportStatefulWriter::set_flow_controller`](super::writer::TransportStatefulWriter::set_flow_controller).
+    /// Transports without flow controllers ignore it.
+    fn set_flow_controller_limit(
+        &mut self,
+        _name: &str,
+        _bytes_per_period: usize,
+        _period: core::time::Duration,
+    ) {
+    }
+
     fn create_stateless_reader(
         &mut self,
         entity_id: EntityId,
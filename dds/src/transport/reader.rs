@@ -1,5 +1,5 @@
 use super::types::{DurabilityKind, EntityId, Guid, Locator, ReliabilityKind};
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, vec::Vec};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct WriterProxy {
@@ -20,4 +20,31 @@ pub trait TransportStatefulReader: Send {
     fn is_historical_data_received(&self) -> bool;
     fn add_matched_writer(&mut self, writer_proxy: WriterProxy);
     fn remove_matched_writer(&mut self, remote_writer_guid: Guid);
+
+    /// Requests the matched writers to send again all the changes in their history.
+    /// Transports which can not request the changes again ignore the request.
+    fn request_historical_data(&mut self) {}
+
+    /// Approximate number of bytes held by the received fragments of the changes which are not yet complete.
+    /// Transports which do not keep track of their memory report zero.
+    fn fragment_buffer_memory_usage(&self) -> usize {
+        0
+    }
+
+    /// Reserves the storage of the given number of matched writers, so that it is not allocated while the reader is
+    /// running. An error is returned if the storage can not be allocated. Transports which allocate nothing in advance
+    /// ignore the reservation.
+    fn reserve_resources(&mut self, _max_matched_writers: usize) -> Result<(), TryReserveError> {
+        Ok(())
+    }
+
+    /// Configures the delay before answering the heartbeats of the matched writers and the time after answering a
+    /// heartbeat of a writer during which its following heartbeats are not answered.
+    /// Transports without a reliable protocol ignore the configuration.
+    fn set_heartbeat_response_timing(
+        &mut self,
+        _heartbeat_response_delay: core::time::Duration,
+        _heartbeat_suppression_duration: core::time::Duration,
+    ) {
+    }
 }
@@ -137,6 +137,10 @@ pub const ENTITYID_PARTICIPANT: EntityId = EntityId::new([0, 0, 0x01], BUILT_IN_
 /// Must be possible to represent using 64 bits.
 pub type SequenceNumber = i64;
 
+/// SEQUENCENUMBER_UNKNOWN
+/// Reserved sequence number made of a high part of -1 and a low part of 0.
+pub const SEQUENCENUMBER_UNKNOWN: SequenceNumber = -1 << 32;
+
 /// TopicKind_t
 /// Enumeration used to distinguish whether a Topic has defined some fields within to be used as the 'key' that identifies data-instances within the Topic. See the DDS specification for more details on keys.
 /// The following values are reserved by the protocol: NO_KEY, WITH_KEY
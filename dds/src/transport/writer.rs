@@ -1,8 +1,8 @@
 use super::{
     history_cache::HistoryCache,
-    types::{DurabilityKind, EntityId, Guid, Locator, ReliabilityKind},
+    types::{DurabilityKind, EntityId, Guid, Locator, ReliabilityKind, Time},
 };
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, sync::Arc, vec::Vec};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ReaderProxy {
@@ -15,6 +15,38 @@ pub struct ReaderProxy {
     pub expects_inline_qos: bool,
 }
 
+/// Stage reached by a change in the history of a stateful writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeLifecycleEventKind {
+    /// The change was sent for the first time to a matched reader.
+    Sent,
+    /// The change was sent again to a matched reader which requested it.
+    Retransmitted,
+    /// The change was acknowledged by all the matched reliable readers.
+    AcknowledgedByAll,
+    /// A GAP was sent to a matched reader in place of the change.
+    Gapped,
+    /// The change was removed from the history before being acknowledged by all the matched reliable readers.
+    Dropped,
+}
+
+/// Event emitted when a change of a stateful writer reaches a stage of its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeLifecycleEvent {
+    pub kind: ChangeLifecycleEventKind,
+    pub sequence_number: i64,
+    /// Source timestamp of the change. Not available for changes no longer in the history of the writer.
+    pub source_timestamp: Option<Time>,
+    /// Reader to which the change or the GAP was sent. Not set for events concerning all the matched readers.
+    pub reader_guid: Option<Guid>,
+}
+
+/// Observer of the lifecycle of the changes of a stateful writer. The observer is called from the
+/// transport while processing the messages of the writer so it should return quickly.
+pub trait ChangeLifecycleObserver: Send + Sync {
+    fn on_change_event(&self, event: &ChangeLifecycleEvent);
+}
+
 pub trait TransportStatelessWriter: Send {
     fn guid(&self) -> Guid;
 
@@ -34,4 +66,85 @@ pub trait TransportStatefulWriter: Send {
     fn add_matched_reader(&mut self, reader_proxy: ReaderProxy);
 
     fn remove_matched_reader(&mut self, remote_reader_guid: Guid);
+
+    /// Registers the observer of the lifecycle of the changes of the writer replacing any previous one.
+    /// Transports which do not track the lifecycle of the changes ignore the observer.
+    fn set_change_lifecycle_observer(
+        &mut self,
+        _change_lifecycle_observer: Option<Arc<dyn ChangeLifecycleObserver>>,
+    ) {
+    }
+
+    /// Enables holding the changes added to the history cache of the writer until [`Self::flush`] is called.
+    /// Transports which do not support batching send the changes as soon as they are added.
+    fn set_batching(&mut self, _batching: bool) {}
+
+    /// Sends the changes held by the writer since the last flush.
+    fn flush(&mut self) {}
+
+    /// Configures the period of the heartbeats sent to the reliable readers, the delay before repairing the changes
+    /// they request, the time after sending a change during which their requests for it are ignored and the minimum
+    /// interval between two repairs of a change for a reader, which doubles with each repair up to the heartbeat period.
+    /// Transports without a reliable protocol ignore the configuration.
+    fn set_reliability_timing(
+        &mut self,
+        _heartbeat_period: core::time::Duration,
+        _nack_response_delay: core::time::Duration,
+        _nack_suppression_duration: core::time::Duration,
+        _min_resend_interval: core::time::Duration,
+    ) {
+    }
+
+    /// Configures the number of changes sent to a reliable reader for each heartbeat piggybacked on them. The last
+    /// of the changes sent to a reader at once always carries a heartbeat, so with `None` that is the only one.
+    /// Transports without a reliable protocol ignore the configuration.
+    fn set_samples_per_heartbeat(&mut self, _samples_per_heartbeat: Option<usize>) {}
+
+    /// Disables the positive acknowledgments of the reliable readers when given the time during which a change is kept
+    /// for repair after being sent to them, after which it is considered acknowledged. With `None` the changes are only
+    /// acknowledged by the ACKNACK messages of the readers. Transports without a reliable protocol ignore the configuration.
+    fn set_disable_positive_acks(&mut self, _keep_duration: Option<core::time::Duration>) {}
+
+    /// Assigns the writer to the flow controller of the transport with the given name, which limits the rate at which
+    /// the messages of the writer are sent. An empty name leaves the writer without flow controller.
+    /// Transports without flow controllers send the messages of the writer without limit.
+    fn set_flow_controller(&mut self, _flow_controller_name: &str) {}
+
+    /// Configures whether the changes added to the history of the writer are sent by a task of the transport instead
+    /// of by the call adding them. Transports without such a task send the changes when they are added.
+    fn set_asynchronous_publishing(&mut self, _asynchronous_publishing: bool) {}
+
+    /// Sets the priority of the writer, with higher values being more important. Transports sending the changes of
+    /// several writers asynchronously serve the ones with higher priority first, the others ignore it.
+    fn set_transport_priority(&mut self, _transport_priority: i32) {}
+
+    /// Approximate number of bytes held by the changes in the history of the writer. Transports which do not keep
+    /// track of their memory report zero.
+    fn history_memory_usage(&self) -> usize {
+        0
+    }
+
+    /// Approximate number of bytes held by the fragments built in advance for the changes of the writer.
+    fn fragment_buffer_memory_usage(&self) -> usize {
+        0
+    }
+
+    /// Reserves the storage of the given number of changes in the history of the writer, of the fragments built for
+    /// them and of the given number of matched readers, so that it is not allocated while the writer is running.
+    /// An error is returned if the storage can not be allocated. Transports which allocate nothing in advance ignore
+    /// the reservation.
+    fn reserve_resources(
+        &mut self,
+        _max_changes: usize,
+        _max_matched_readers: usize,
+    ) -> Result<(), TryReserveError> {
+        Ok(())
+    }
+
+    /// Returns the number of bytes of serialized data sent to each of the readers since the last call, including the
+    /// retransmissions and the readers which are no longer matched. Transports which do not keep track of the data
+    /// they send report nothing.
+    fn take_data_bytes_sent(&mut self) -> Vec<(Guid, u64)> {
+        Vec::new()
+    }
 }
@@ -4,4 +4,12 @@ pub enum XTypesError {
     InvalidData,
     PidNotFound(u16),
     InvalidIndex,
+    /// Data violating the alignment, padding or length rules of the CDR representation, detected by the
+    /// deserializers created in the strict conformance mode. These reject padding which is not made of zero
+    /// bytes, sequence and string lengths exceeding the remaining data, strings which are not terminated by a
+    /// single NUL character and booleans other than 0 or 1. The offset is the position of the offending byte
+    /// from the start of the serialized data.
+    NonConformant {
+        offset: usize,
+    },
 }
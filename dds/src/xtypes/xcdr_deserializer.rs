@@ -20,6 +20,14 @@ impl<'a> Xcdr1BeDeserializer<'a> {
             reader: Reader::new(buffer),
         }
     }
+
+    /// Create a deserializer in the strict conformance mode. See [`XTypesError::NonConformant`] for the
+    /// violations which are rejected in this mode.
+    pub fn new_strict(buffer: &'a [u8]) -> Self {
+        Self {
+            reader: Reader::new_strict(buffer),
+        }
+    }
 }
 
 pub struct Xcdr1LeDeserializer<'a> {
@@ -32,6 +40,14 @@ impl<'a> Xcdr1LeDeserializer<'a> {
             reader: Reader::new(buffer),
         }
     }
+
+    /// Create a deserializer in the strict conformance mode. See [`XTypesError::NonConformant`] for the
+    /// violations which are rejected in this mode.
+    pub fn new_strict(buffer: &'a [u8]) -> Self {
+        Self {
+            reader: Reader::new_strict(buffer),
+        }
+    }
 }
 
 pub struct Xcdr2BeDeserializer<'a> {
@@ -44,6 +60,14 @@ impl<'a> Xcdr2BeDeserializer<'a> {
             reader: Reader::new(buffer),
         }
     }
+
+    /// Create a deserializer in the strict conformance mode. See [`XTypesError::NonConformant`] for the
+    /// violations which are rejected in this mode.
+    pub fn new_strict(buffer: &'a [u8]) -> Self {
+        Self {
+            reader: Reader::new_strict(buffer),
+        }
+    }
 }
 
 pub struct Xcdr2LeDeserializer<'a> {
@@ -56,23 +80,61 @@ impl<'a> Xcdr2LeDeserializer<'a> {
             reader: Reader::new(buffer),
         }
     }
+
+    /// Create a deserializer in the strict conformance mode. See [`XTypesError::NonConformant`] for the
+    /// violations which are rejected in this mode.
+    pub fn new_strict(buffer: &'a [u8]) -> Self {
+        Self {
+            reader: Reader::new_strict(buffer),
+        }
+    }
 }
 
+#[derive(Clone, Copy)]
 struct Reader<'a> {
     buffer: &'a [u8],
     pos: usize,
+    // Offset of the start of the buffer within the complete payload, used to report non-conformances
+    base: usize,
+    strict: bool,
 }
 
 impl<'a> Reader<'a> {
     fn new(buffer: &'a [u8]) -> Self {
-        Self { buffer, pos: 0 }
+        Self {
+            buffer,
+            pos: 0,
+            base: 0,
+            strict: false,
+        }
     }
-    fn buffer(&self) -> &'a [u8] {
-        &self.buffer[self.pos..]
+    fn new_strict(buffer: &'a [u8]) -> Self {
+        Self {
+            strict: true,
+            ..Self::new(buffer)
+        }
+    }
+    // Reader starting at the current position, as used to decode the parameter list of mutable structs
+    fn remaining(&self) -> Self {
+        Self {
+            buffer: &self.buffer[self.pos..],
+            pos: 0,
+            base: self.base + self.pos,
+            strict: self.strict,
+        }
+    }
+    fn invalid_data_at(&self, pos: usize) -> XTypesError {
+        if self.strict {
+            XTypesError::NonConformant {
+                offset: self.base + pos,
+            }
+        } else {
+            XTypesError::InvalidData
+        }
     }
     fn read<const N: usize>(&mut self) -> Result<&'a [u8; N], XTypesError> {
         if self.pos + N > self.buffer.len() {
-            return Err(XTypesError::InvalidData);
+            return Err(self.invalid_data_at(self.pos));
         }
         let ret = core::convert::TryFrom::try_from(&self.buffer[self.pos..self.pos + N])
             .expect("length guaranteed");
@@ -81,7 +143,7 @@ impl<'a> Reader<'a> {
     }
     fn read_all(&mut self, length: usize) -> Result<&'a [u8], XTypesError> {
         if self.pos + length > self.buffer.len() {
-            return Err(XTypesError::InvalidData);
+            return Err(self.invalid_data_at(self.pos));
         }
         let ret = &self.buffer[self.pos..self.pos + length];
         self.pos += length;
@@ -96,15 +158,55 @@ impl<'a> Reader<'a> {
         let mask = alignment - 1;
         self.seek(((self.pos + mask) & !mask) - self.pos)
     }
+
+    // In the strict mode the padding must be fully contained in the buffer and made only of zero bytes
+    fn align(&mut self, alignment: usize) -> Result<(), XTypesError> {
+        let start = self.pos;
+        self.seek_padding(alignment);
+        if self.strict {
+            let padding = self
+                .buffer
+                .get(start..self.pos)
+                .ok_or(self.invalid_data_at(start))?;
+            if let Some(i) = padding.iter().position(|&b| b != 0) {
+                return Err(self.invalid_data_at(start + i));
+            }
+        }
+        Ok(())
+    }
+
+    // Called right after reading the length of a sequence. In the strict mode the length is checked against
+    // the remaining bytes since every element occupies at least one byte, and a violation is reported at the
+    // position of the length itself.
+    fn check_sequence_length(&self, length: usize) -> Result<(), XTypesError> {
+        if self.strict && length > self.buffer.len() - self.pos {
+            return Err(self.invalid_data_at(self.pos - 4));
+        }
+        Ok(())
+    }
+
+    // Called right after reading the bytes of a string, including its terminating NUL character
+    fn check_string(&self, bytes: &[u8]) -> Result<(), XTypesError> {
+        if self.strict {
+            let start = self.pos - bytes.len();
+            match bytes.iter().position(|&b| b == 0) {
+                Some(i) if i + 1 == bytes.len() => (),
+                Some(i) => return Err(self.invalid_data_at(start + i)),
+                None if bytes.is_empty() => return Err(self.invalid_data_at(start - 4)),
+                None => return Err(self.invalid_data_at(self.pos - 1)),
+            }
+        }
+        Ok(())
+    }
 }
 
 fn read_with_padding_v1<const N: usize>(reader: &mut Reader) -> Result<[u8; N], XTypesError> {
-    reader.seek_padding(N);
+    reader.align(N)?;
     reader.read().cloned()
 }
 
 fn read_with_padding_v2<const N: usize>(reader: &mut Reader) -> Result<[u8; N], XTypesError> {
-    reader.seek_padding(core::cmp::min(N, 4));
+    reader.align(core::cmp::min(N, 4))?;
     reader.read().cloned()
 }
 
@@ -117,8 +219,8 @@ fn seek_to_pid_be(reader: &mut Reader, pid: u16) -> Result<(), XTypesError> {
         } else if current_pid == PID_SENTINEL {
             return Err(XTypesError::PidNotFound(pid));
         } else {
-            reader.seek(length);
-            reader.seek_padding(4);
+            reader.read_all(length)?;
+            reader.align(4)?;
         }
     }
 }
@@ -132,8 +234,8 @@ fn seek_to_pid_le(reader: &mut Reader, pid: u16) -> Result<(), XTypesError> {
         } else if current_pid == PID_SENTINEL {
             return Err(XTypesError::PidNotFound(pid));
         } else {
-            reader.seek(length);
-            reader.seek_padding(4);
+            reader.read_all(length)?;
+            reader.align(4)?;
         }
     }
 }
@@ -147,8 +249,8 @@ fn seek_to_optional_pid_be(reader: &mut Reader, pid: u16) -> Result<bool, XTypes
         } else if current_pid == PID_SENTINEL {
             return Ok(false);
         } else {
-            reader.seek(length);
-            reader.seek_padding(4);
+            reader.read_all(length)?;
+            reader.align(4)?;
         }
     }
 }
@@ -162,8 +264,8 @@ fn seek_to_optional_pid_le(reader: &mut Reader, pid: u16) -> Result<bool, XTypes
         } else if current_pid == PID_SENTINEL {
             return Ok(false);
         } else {
-            reader.seek(length);
-            reader.seek_padding(4);
+            reader.read_all(length)?;
+            reader.align(4)?;
         }
     }
 }
@@ -193,7 +295,7 @@ where
 }
 
 struct PlCdrBeDecoder<'a> {
-    buffer: &'a [u8],
+    reader: Reader<'a>,
 }
 
 impl<'de> DeserializeMutableStruct<'de> for PlCdrBeDecoder<'de> {
@@ -202,7 +304,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrBeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<T, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         seek_to_pid_be(&mut reader, pid as u16)?;
         T::deserialize(&mut Xcdr1BeDeserializer { reader })
     }
@@ -212,7 +314,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrBeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         Ok(if seek_to_optional_pid_be(&mut reader, pid as u16)? {
             Some(T::deserialize(&mut Xcdr1BeDeserializer { reader })?)
         } else {
@@ -222,7 +324,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrBeDecoder<'de> {
 }
 
 struct PlCdrLeDecoder<'a> {
-    buffer: &'a [u8],
+    reader: Reader<'a>,
 }
 
 impl<'de> DeserializeMutableStruct<'de> for PlCdrLeDecoder<'de> {
@@ -231,7 +333,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrLeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<T, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         seek_to_pid_le(&mut reader, pid as u16)?;
         T::deserialize(&mut Xcdr1LeDeserializer { reader })
     }
@@ -241,7 +343,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrLeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         Ok(if seek_to_optional_pid_le(&mut reader, pid as u16)? {
             Some(T::deserialize(&mut Xcdr1LeDeserializer { reader })?)
         } else {
@@ -251,7 +353,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdrLeDecoder<'de> {
 }
 
 struct PlCdr2BeDecoder<'a> {
-    buffer: &'a [u8],
+    reader: Reader<'a>,
 }
 
 impl<'de> DeserializeMutableStruct<'de> for PlCdr2BeDecoder<'de> {
@@ -260,7 +362,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdr2BeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<T, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         seek_to_pid_be(&mut reader, pid as u16)?;
         T::deserialize(&mut Xcdr2BeDeserializer { reader })
     }
@@ -270,7 +372,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdr2BeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         Ok(if seek_to_optional_pid_be(&mut reader, pid as u16)? {
             Some(T::deserialize(&mut Xcdr2BeDeserializer { reader })?)
         } else {
@@ -280,7 +382,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdr2BeDecoder<'de> {
 }
 
 struct PlCdr2LeDecoder<'a> {
-    buffer: &'a [u8],
+    reader: Reader<'a>,
 }
 
 impl<'de> DeserializeMutableStruct<'de> for PlCdr2LeDecoder<'de> {
@@ -289,7 +391,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdr2LeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<T, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         seek_to_pid_le(&mut reader, pid as u16)?;
         T::deserialize(&mut Xcdr2LeDeserializer { reader })
     }
@@ -299,7 +401,7 @@ impl<'de> DeserializeMutableStruct<'de> for PlCdr2LeDecoder<'de> {
         pid: u32,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        let mut reader = Reader::new(self.buffer);
+        let mut reader = self.reader;
         Ok(if seek_to_optional_pid_le(&mut reader, pid as u16)? {
             Some(T::deserialize(&mut Xcdr2LeDeserializer { reader })?)
         } else {
@@ -365,7 +467,7 @@ impl<'de> DeserializeFinalStruct<'de> for PlainCdrBeDecoder<'_, 'de> {
         &mut self,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        self.deserializer.reader.seek_padding(4);
+        self.deserializer.reader.align(4)?;
         let _pid = u16::deserialize(&mut *self.deserializer)?;
         let length = u16::deserialize(&mut *self.deserializer)?;
         if length == 0 {
@@ -400,7 +502,7 @@ impl<'de> DeserializeFinalStruct<'de> for PlainCdrLeDecoder<'_, 'de> {
         &mut self,
         _name: &str,
     ) -> Result<Option<T>, XTypesError> {
-        self.deserializer.reader.seek_padding(4);
+        self.deserializer.reader.align(4)?;
         let _pid = u16::deserialize(&mut *self.deserializer)?;
         let length = u16::deserialize(&mut *self.deserializer)?;
         if length == 0 {
@@ -448,7 +550,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1BeDeserializer<'de> {
     }
     fn deserialize_mutable_struct(self) -> Result<impl DeserializeMutableStruct<'de>, XTypesError> {
         Ok(PlCdrBeDecoder {
-            buffer: self.reader.buffer(),
+            reader: self.reader.remaining(),
         })
     }
     fn deserialize_array(self) -> Result<impl DeserializeArray<'de>, XTypesError> {
@@ -456,6 +558,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1BeDeserializer<'de> {
     }
     fn deserialize_sequence(self) -> Result<impl DeserializeSequence<'de>, XTypesError> {
         let len = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(len)?;
         Ok(SequenceDecoder {
             deserializer: self,
             len,
@@ -463,7 +566,8 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1BeDeserializer<'de> {
     }
 
     fn deserialize_boolean(self) -> Result<bool, XTypesError> {
-        into_bool(self.deserialize_uint8()?)
+        let v = self.deserialize_uint8()?;
+        into_bool(v).map_err(|_| self.reader.invalid_data_at(self.reader.pos - 1))
     }
     fn deserialize_int8(self) -> Result<i8, XTypesError> {
         Ok(i8::from_be_bytes(read_with_padding_v1(&mut self.reader)?))
@@ -499,16 +603,14 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1BeDeserializer<'de> {
         Ok(self.deserialize_uint8()? as char)
     }
     fn deserialize_string(self) -> Result<&'de str, XTypesError> {
-        str::from_utf8(
-            self.deserialize_byte_sequence()?
-                .split_last()
-                .ok_or(XTypesError::InvalidData)?
-                .1,
-        )
-        .map_err(|_| XTypesError::InvalidData)
+        let bytes = self.deserialize_byte_sequence()?;
+        self.reader.check_string(bytes)?;
+        str::from_utf8(bytes.split_last().ok_or(XTypesError::InvalidData)?.1)
+            .map_err(|_| XTypesError::InvalidData)
     }
     fn deserialize_byte_sequence(self) -> Result<&'de [u8], XTypesError> {
         let length = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(length)?;
         self.reader.read_all(length)
     }
 
@@ -528,7 +630,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1LeDeserializer<'de> {
     }
     fn deserialize_mutable_struct(self) -> Result<impl DeserializeMutableStruct<'de>, XTypesError> {
         Ok(PlCdrLeDecoder {
-            buffer: self.reader.buffer(),
+            reader: self.reader.remaining(),
         })
     }
     fn deserialize_array(self) -> Result<impl DeserializeArray<'de>, XTypesError> {
@@ -536,6 +638,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1LeDeserializer<'de> {
     }
     fn deserialize_sequence(self) -> Result<impl DeserializeSequence<'de>, XTypesError> {
         let len = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(len)?;
         Ok(SequenceDecoder {
             deserializer: self,
             len,
@@ -543,7 +646,8 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1LeDeserializer<'de> {
     }
 
     fn deserialize_boolean(self) -> Result<bool, XTypesError> {
-        into_bool(self.deserialize_uint8()?)
+        let v = self.deserialize_uint8()?;
+        into_bool(v).map_err(|_| self.reader.invalid_data_at(self.reader.pos - 1))
     }
     fn deserialize_uint8(self) -> Result<u8, XTypesError> {
         Ok(u8::from_le_bytes(read_with_padding_v1(&mut self.reader)?))
@@ -579,16 +683,14 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr1LeDeserializer<'de> {
         Ok(self.deserialize_uint8()? as char)
     }
     fn deserialize_string(self) -> Result<&'de str, XTypesError> {
-        str::from_utf8(
-            self.deserialize_byte_sequence()?
-                .split_last()
-                .ok_or(XTypesError::InvalidData)?
-                .1,
-        )
-        .map_err(|_| XTypesError::InvalidData)
+        let bytes = self.deserialize_byte_sequence()?;
+        self.reader.check_string(bytes)?;
+        str::from_utf8(bytes.split_last().ok_or(XTypesError::InvalidData)?.1)
+            .map_err(|_| XTypesError::InvalidData)
     }
     fn deserialize_byte_sequence(self) -> Result<&'de [u8], XTypesError> {
         let length = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(length)?;
         self.reader.read_all(length)
     }
     fn deserialize_byte_array<const N: usize>(self) -> Result<&'de [u8; N], XTypesError> {
@@ -607,7 +709,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2BeDeserializer<'de> {
     }
     fn deserialize_mutable_struct(self) -> Result<impl DeserializeMutableStruct<'de>, XTypesError> {
         Ok(PlCdr2BeDecoder {
-            buffer: self.reader.buffer(),
+            reader: self.reader.remaining(),
         })
     }
     fn deserialize_array(self) -> Result<impl DeserializeArray<'de>, XTypesError> {
@@ -615,6 +717,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2BeDeserializer<'de> {
     }
     fn deserialize_sequence(self) -> Result<impl DeserializeSequence<'de>, XTypesError> {
         let len = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(len)?;
         Ok(SequenceDecoder {
             deserializer: self,
             len,
@@ -622,7 +725,8 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2BeDeserializer<'de> {
     }
 
     fn deserialize_boolean(self) -> Result<bool, XTypesError> {
-        into_bool(self.deserialize_uint8()?)
+        let v = self.deserialize_uint8()?;
+        into_bool(v).map_err(|_| self.reader.invalid_data_at(self.reader.pos - 1))
     }
     fn deserialize_int8(self) -> Result<i8, XTypesError> {
         Ok(i8::from_be_bytes(read_with_padding_v2(&mut self.reader)?))
@@ -658,16 +762,14 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2BeDeserializer<'de> {
         Ok(self.deserialize_uint8()? as char)
     }
     fn deserialize_string(self) -> Result<&'de str, XTypesError> {
-        str::from_utf8(
-            self.deserialize_byte_sequence()?
-                .split_last()
-                .ok_or(XTypesError::InvalidData)?
-                .1,
-        )
-        .map_err(|_| XTypesError::InvalidData)
+        let bytes = self.deserialize_byte_sequence()?;
+        self.reader.check_string(bytes)?;
+        str::from_utf8(bytes.split_last().ok_or(XTypesError::InvalidData)?.1)
+            .map_err(|_| XTypesError::InvalidData)
     }
     fn deserialize_byte_sequence(self) -> Result<&'de [u8], XTypesError> {
         let length = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(length)?;
         self.reader.read_all(length)
     }
     fn deserialize_byte_array<const N: usize>(self) -> Result<&'de [u8; N], XTypesError> {
@@ -688,7 +790,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2LeDeserializer<'de> {
     }
     fn deserialize_mutable_struct(self) -> Result<impl DeserializeMutableStruct<'de>, XTypesError> {
         Ok(PlCdr2LeDecoder {
-            buffer: self.reader.buffer(),
+            reader: self.reader.remaining(),
         })
     }
     fn deserialize_array(self) -> Result<impl DeserializeArray<'de>, XTypesError> {
@@ -696,6 +798,7 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2LeDeserializer<'de> {
     }
     fn deserialize_sequence(self) -> Result<impl DeserializeSequence<'de>, XTypesError> {
         let len = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(len)?;
         Ok(SequenceDecoder {
             deserializer: self,
             len,
@@ -703,7 +806,8 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2LeDeserializer<'de> {
     }
 
     fn deserialize_boolean(self) -> Result<bool, XTypesError> {
-        into_bool(self.deserialize_uint8()?)
+        let v = self.deserialize_uint8()?;
+        into_bool(v).map_err(|_| self.reader.invalid_data_at(self.reader.pos - 1))
     }
     fn deserialize_uint8(self) -> Result<u8, XTypesError> {
         Ok(u8::from_le_bytes(read_with_padding_v2(&mut self.reader)?))
@@ -739,16 +843,14 @@ impl<'de> XTypesDeserializer<'de> for &mut Xcdr2LeDeserializer<'de> {
         Ok(self.deserialize_uint8()? as char)
     }
     fn deserialize_string(self) -> Result<&'de str, XTypesError> {
-        str::from_utf8(
-            self.deserialize_byte_sequence()?
-                .split_last()
-                .ok_or(XTypesError::InvalidData)?
-                .1,
-        )
-        .map_err(|_| XTypesError::InvalidData)
+        let bytes = self.deserialize_byte_sequence()?;
+        self.reader.check_string(bytes)?;
+        str::from_utf8(bytes.split_last().ok_or(XTypesError::InvalidData)?.1)
+            .map_err(|_| XTypesError::InvalidData)
     }
     fn deserialize_byte_sequence(self) -> Result<&'de [u8], XTypesError> {
         let length = self.deserialize_uint32()? as usize;
+        self.reader.check_sequence_length(length)?;
         self.reader.read_all(length)
     }
     fn deserialize_byte_array<const N: usize>(self) -> Result<&'de [u8; N], XTypesError> {
@@ -1498,13 +1600,13 @@ mod tests {
             deserialize_v2_be(&[
                 1, 2, 0, 3, // f1: bool | f2: i8 | f3: i16
                 0, 0, 0, 4, // f4: i32
-                0, 0, 0, 0, // f5-1: i64 
+                0, 0, 0, 0, // f5-1: i64
                 0, 0, 0, 5, // f5-2: i64
-                6, 0, 0, 7, // f6: u8 | padding (1 byte) | f7: u16 
+                6, 0, 0, 7, // f6: u8 | padding (1 byte) | f7: u16
                 0, 0, 0, 8, // f8: u32
                 0, 0, 0, 0, // f9-1: u64
                 0, 0, 0, 9, // f9-2: u64
-                0x3F, 0x80, 0x00, 0x00, // f10: f32 
+                0x3F, 0x80, 0x00, 0x00, // f10: f32
                 0x3F, 0xF0, 0x00, 0x00, // f11-1: f64
                 0x00, 0x00, 0x00, 0x00, // f11-2: f64
                 b'a', // f12: char
@@ -1515,13 +1617,13 @@ mod tests {
             deserialize_v2_le(&[
                 1, 2, 3, 0, // f1: bool | f2: i8 | f3: i16
                 4, 0, 0, 0, // f4: i32
-                5, 0, 0, 0, // f5-1: i64 
+                5, 0, 0, 0, // f5-1: i64
                 0, 0, 0, 0, // f5-2: i64
-                6, 0, 7, 0, // f6: u8 | padding (1 byte) | f7: u16 
+                6, 0, 7, 0, // f6: u8 | padding (1 byte) | f7: u16
                 8, 0, 0, 0, // f8: u32
                 9, 0, 0, 0, // f9-1: u64
                 0, 0, 0, 0, // f9-2: u64
-                0x00, 0x00, 0x80, 0x3F, // f10: f32 
+                0x00, 0x00, 0x80, 0x3F, // f10: f32
                 0x00, 0x00, 0x00, 0x00, // f11-1: f64
                 0x00, 0x00, 0xF0, 0x3F, // f11-2: f64
                 b'a', // f12: char
@@ -1529,4 +1631,77 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn strict_deserialization_rejects_non_conformant_data() {
+        let final_type = [
+            0, 7, 0, 0, 0, 0, 0, 0, // field_u16 | padding (6 bytes)
+            0, 0, 0, 0, 0, 0, 0, 9, // field_u64
+        ];
+        assert_eq!(
+            FinalType::deserialize(&mut Xcdr1BeDeserializer::new_strict(&final_type)),
+            Ok(FinalType {
+                field_u16: 7,
+                field_u64: 9,
+            })
+        );
+        let final_type_with_padding_garbage = [
+            0, 7, 0, 0xFF, 0, 0, 0, 0, // field_u16 | padding (6 bytes)
+            0, 0, 0, 0, 0, 0, 0, 9, // field_u64
+        ];
+        assert!(deserialize_v1_be::<FinalType>(&final_type_with_padding_garbage).is_ok());
+        assert_eq!(
+            FinalType::deserialize(&mut Xcdr1BeDeserializer::new_strict(
+                &final_type_with_padding_garbage
+            )),
+            Err(XTypesError::NonConformant { offset: 3 })
+        );
+
+        let unterminated_string = [
+            3, 0, 0, 0, // field_str: length
+            b'x', b't', b'!', 0, //field_str: data | padding (1 bytes)
+            9, 0, 0, 0, // field_u16 | padding (2 bytes)
+            2, 0, 0, 0, // field_slice: length
+            10, 11, //field_slice: data
+        ];
+        assert!(deserialize_v2_le::<TypeWithStr>(&unterminated_string).is_ok());
+        assert_eq!(
+            TypeWithStr::deserialize(&mut Xcdr2LeDeserializer::new_strict(&unterminated_string)),
+            Err(XTypesError::NonConformant { offset: 6 })
+        );
+
+        let sequence_length_out_of_bounds = [
+            2, 0, 0, 0, // field_str: length
+            b'x', 0, // field_str: data
+            9, 0, // field_u16
+            200, 0, 0, 0, // field_slice: length
+            10, 11, //field_slice: data
+        ];
+        assert_eq!(
+            TypeWithStr::deserialize(&mut Xcdr2LeDeserializer::new_strict(
+                &sequence_length_out_of_bounds
+            )),
+            Err(XTypesError::NonConformant { offset: 8 })
+        );
+
+        assert_eq!(
+            bool::deserialize(&mut Xcdr2BeDeserializer::new_strict(&[2])),
+            Err(XTypesError::NonConformant { offset: 0 })
+        );
+
+        let mutable_type_with_padding_garbage = [
+            0x05A, 0x00, 1, 0, // PID | length
+            7, 0xAA, 0, 0, // key | padding
+            0x050, 0x00, 4, 0, // PID | length
+            8, 0, 0, 0, // participant_key
+            0, 0, 0, 0, // Sentinel
+        ];
+        assert!(deserialize_v1_le::<MutableType>(&mutable_type_with_padding_garbage).is_ok());
+        assert_eq!(
+            MutableType::deserialize(&mut Xcdr1LeDeserializer::new_strict(
+                &mutable_type_with_padding_garbage
+            )),
+            Err(XTypesError::NonConformant { offset: 5 })
+        );
+    }
 }
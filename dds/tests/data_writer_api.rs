@@ -2,13 +2,29 @@ use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         error::DdsError,
-        qos::{DataWriterQos, QosKind},
-        qos_policy::{HistoryQosPolicy, HistoryQosPolicyKind, Length, ResourceLimitsQosPolicy},
-        status::NO_STATUS,
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{
+            BatchQosPolicy, DeadlineQosPolicy, DurabilityQosPolicy, DurabilityQosPolicyKind,
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, LivelinessQosPolicy,
+            LivelinessQosPolicyKind, ReaderProtocolQosPolicy, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind, ResourceLimitsQosPolicy, UserDataQosPolicy,
+            WriterDataLifecycleQosPolicy,
+        },
+        sample_info::{InstanceStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
         type_support::DdsType,
     },
     listener::NO_LISTENER,
+    publication::{
+        change_lifecycle::{
+            ChangeLifecycleEvent, ChangeLifecycleEventKind, ChangeLifecycleObserver,
+        },
+        data_writer::{DuplicateSuppressionMode, KeepLastReplacementPolicy, PublicationThrottling},
+    },
+    wait_set::{Condition, WaitSet},
 };
+use std::sync::{Arc, Mutex};
 
 mod utils;
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
@@ -65,6 +81,118 @@ fn data_writer_write_more_than_max_instances_should_fail() {
     assert_eq!(result, Err(DdsError::OutOfResources));
 }
 
+#[test]
+fn data_writer_should_reclaim_unregistered_instance_once_acknowledged() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Unlimited,
+            max_instances: Length::Limited(1),
+            max_samples_per_instance: Length::Unlimited,
+        },
+        writer_data_lifecycle: WriterDataLifecycleQosPolicy {
+            autodispose_unregistered_instances: false,
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data_instance1 = KeyedData { id: 1, value: 0 };
+    let data_instance2 = KeyedData { id: 2, value: 0 };
+    data_writer.write(&data_instance1, None).unwrap();
+    data_writer
+        .unregister_instance(&data_instance1, None)
+        .unwrap();
+    assert_eq!(data_writer.lookup_instance(&data_instance1), Ok(None));
+    assert_eq!(
+        data_writer.unregister_instance(&data_instance1, None),
+        Err(DdsError::BadParameter)
+    );
+
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    data_writer.write(&data_instance2, None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let instance_states: Vec<_> = samples
+        .iter()
+        .map(|s| (s.data().ok(), s.sample_info().instance_state))
+        .collect();
+    assert_eq!(
+        instance_states,
+        vec![
+            (Some(data_instance1), InstanceStateKind::NotAliveNoWriters),
+            (None, InstanceStateKind::NotAliveNoWriters),
+            (Some(data_instance2), InstanceStateKind::Alive),
+        ]
+    );
+}
+
 #[test]
 fn data_writer_write_more_than_max_samples_per_instances_should_fail() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -95,6 +223,10 @@ fn data_writer_write_more_than_max_samples_per_instances_should_fail() {
         history: HistoryQosPolicy {
             kind: HistoryQosPolicyKind::KeepAll,
         },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100_000_000)),
+        },
         ..Default::default()
     };
     let data_writer = publisher
@@ -159,3 +291,1078 @@ fn data_writer_write_more_than_max_samples_should_fail() {
     let result = data_writer.write(&data_instance3, None);
     assert_eq!(result, Err(DdsError::OutOfResources));
 }
+
+#[test]
+fn data_writer_asserting_liveliness_should_not_lose_liveliness() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByParticipant,
+            lease_duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    for i in 0..10 {
+        if i % 2 == 0 {
+            data_writer.assert_liveliness().unwrap();
+        } else {
+            participant.assert_liveliness().unwrap();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let status = data_writer.get_liveliness_lost_status().unwrap();
+    assert_eq!(status.total_count, 0);
+}
+
+#[test]
+fn automatic_liveliness_should_be_asserted_within_the_shortest_lease_of_the_participant_writers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let publisher_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let publisher = publisher_participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = subscriber_participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // The writer with the longer lease is created first so that the assertion period
+    // has to be shortened for the second one
+    let mut data_reader_list = Vec::new();
+    for (topic_name, lease_duration) in [
+        ("LongLeaseTopic", Duration::new(10, 0)),
+        ("ShortLeaseTopic", Duration::new(0, 400_000_000)),
+    ] {
+        let liveliness = LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::Automatic,
+            lease_duration: DurationKind::Finite(lease_duration),
+        };
+        let publisher_topic = publisher_participant
+            .create_topic::<KeyedData>(
+                topic_name,
+                "KeyedData",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let data_writer_qos = DataWriterQos {
+            liveliness: liveliness.clone(),
+            ..Default::default()
+        };
+        publisher
+            .create_datawriter::<KeyedData>(
+                &publisher_topic,
+                QosKind::Specific(data_writer_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+
+        let subscriber_topic = subscriber_participant
+            .create_topic::<KeyedData>(
+                topic_name,
+                "KeyedData",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let data_reader_qos = DataReaderQos {
+            liveliness,
+            ..Default::default()
+        };
+        let data_reader = subscriber
+            .create_datareader::<KeyedData>(
+                &subscriber_topic,
+                QosKind::Specific(data_reader_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+
+        let cond = data_reader.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+        assert_eq!(
+            data_reader
+                .get_subscription_matched_status()
+                .unwrap()
+                .current_count,
+            1
+        );
+
+        data_reader_list.push(data_reader);
+    }
+
+    std::thread::sleep(std::time::Duration::from_millis(1500));
+
+    for data_reader in &data_reader_list {
+        let status = data_reader.get_liveliness_changed_status().unwrap();
+        assert_eq!(status.alive_count, 1);
+        assert_eq!(status.not_alive_count, 0);
+    }
+}
+
+#[test]
+fn data_writer_overwriting_unacknowledged_samples_should_count_them() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    // The reader is slower to acknowledge the samples than the writer is to replace them
+    let data_reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        reader_protocol: ReaderProtocolQosPolicy {
+            heartbeat_response_delay: Duration::new(10, 0),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let _data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer
+        .set_keep_last_replacement_policy(KeepLastReplacementPolicy::OverwriteOldest)
+        .unwrap();
+    for value in 0..10 {
+        data_writer
+            .write(&KeyedData { id: 1, value }, None)
+            .unwrap();
+    }
+
+    let replaced_count = data_writer
+        .get_replaced_unacknowledged_sample_count()
+        .unwrap();
+    assert!(replaced_count > 0);
+}
+
+#[test]
+fn reliable_keep_all_writer_should_wait_for_acknowledgments_when_reaching_resource_limits() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(10, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    for value in 0..5 {
+        data_writer
+            .write(&KeyedData { id: 1, value }, None)
+            .unwrap();
+    }
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 5);
+}
+
+#[test]
+fn data_writer_with_duplicate_suppression_should_not_publish_identical_samples() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer
+        .set_duplicate_suppression_mode(DuplicateSuppressionMode::SerializedData)
+        .unwrap();
+    assert!(data_writer
+        .write(&KeyedData { id: 1, value: 1 }, None)
+        .unwrap()
+        .is_some());
+    assert!(data_writer
+        .write(&KeyedData { id: 1, value: 1 }, None)
+        .unwrap()
+        .is_none());
+    assert!(data_writer
+        .write(&KeyedData { id: 2, value: 1 }, None)
+        .unwrap()
+        .is_some());
+    assert!(data_writer
+        .write(&KeyedData { id: 1, value: 2 }, None)
+        .unwrap()
+        .is_some());
+
+    data_writer
+        .set_duplicate_suppression_mode(DuplicateSuppressionMode::UserProvidedHash)
+        .unwrap();
+    assert!(data_writer
+        .write_w_hash(&KeyedData { id: 1, value: 3 }, None, 7)
+        .unwrap()
+        .is_some());
+    assert!(data_writer
+        .write_w_hash(&KeyedData { id: 1, value: 4 }, None, 7)
+        .unwrap()
+        .is_none());
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let received_data: Vec<_> = samples.iter().map(|s| s.data().unwrap()).collect();
+    assert_eq!(
+        received_data,
+        vec![
+            KeyedData { id: 1, value: 1 },
+            KeyedData { id: 2, value: 1 },
+            KeyedData { id: 1, value: 2 },
+            KeyedData { id: 1, value: 3 },
+        ]
+    );
+}
+
+#[test]
+fn data_writer_with_write_coalescing_window_should_publish_latest_value_per_instance() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let keep_all_data_writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let keep_all_data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(keep_all_data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    assert!(matches!(
+        keep_all_data_writer.set_write_coalescing_window(Some(Duration::new(0, 100_000_000))),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    publisher.delete_datawriter(&keep_all_data_writer).unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer
+        .set_write_coalescing_window(Some(Duration::new(0, 100_000_000)))
+        .unwrap();
+    for value in 1..=5 {
+        assert!(data_writer
+            .write(&KeyedData { id: 1, value }, None)
+            .unwrap()
+            .is_none());
+    }
+    assert!(data_writer
+        .write(&KeyedData { id: 2, value: 1 }, None)
+        .unwrap()
+        .is_none());
+
+    // The windows expire without flushing the writer
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let received_data: Vec<_> = samples.iter().map(|s| s.data().unwrap()).collect();
+    assert_eq!(
+        received_data,
+        vec![KeyedData { id: 1, value: 5 }, KeyedData { id: 2, value: 1 }]
+    );
+
+    data_writer
+        .write(&KeyedData { id: 1, value: 6 }, None)
+        .unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 6 });
+}
+
+#[test]
+fn data_writer_with_publication_throttling_should_throttle_while_readers_lag() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        // The batched samples are held by the writer so the reader cannot acknowledge them before they are flushed
+        batch: BatchQosPolicy {
+            enable: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    assert_eq!(
+        data_writer.set_publication_throttling(Some(PublicationThrottling {
+            throttle_threshold: 0,
+            release_threshold: 1,
+            throttled_publication_period: Duration::new(0, 200_000_000),
+        })),
+        Err(DdsError::BadParameter)
+    );
+    assert_eq!(
+        data_writer.set_publication_throttling(Some(PublicationThrottling {
+            throttle_threshold: 1,
+            release_threshold: 1,
+            throttled_publication_period: Duration::new(0, 200_000_000),
+        })),
+        Err(DdsError::BadParameter)
+    );
+    // More than one change not yet acknowledged by the reader throttles the publication
+    data_writer
+        .set_publication_throttling(Some(PublicationThrottling {
+            throttle_threshold: 1,
+            release_threshold: 0,
+            throttled_publication_period: Duration::new(0, 200_000_000),
+        }))
+        .unwrap();
+    cond.set_enabled_statuses(&[StatusKind::PublicationThrottled])
+        .unwrap();
+    // The writes alternate between two instances so that each change is kept in the history
+    for value in 1..=20 {
+        data_writer
+            .write(
+                &KeyedData {
+                    id: (value % 2) as u8,
+                    value,
+                },
+                None,
+            )
+            .unwrap();
+    }
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let status = data_writer.get_publication_throttled_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert!(status.throttled);
+    assert_eq!(status.unacknowledged_change_count, 2);
+
+    // The value held while throttled is published at the end of the throttled publication period
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    data_writer.flush().unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let samples = data_reader
+        .take(100, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert!(samples.len() < 20);
+    let values: Vec<_> = samples.iter().map(|s| s.data().unwrap()).collect();
+    assert!(values.contains(&KeyedData { id: 0, value: 20 }));
+    assert!(values.contains(&KeyedData { id: 1, value: 19 }));
+
+    // Once the reader caught up the throttling stops without any further write
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let status = data_writer.get_publication_throttled_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert!(!status.throttled);
+    assert_eq!(status.unacknowledged_change_count, 0);
+
+    // The next write is published immediately
+    data_writer
+        .write(&KeyedData { id: 1, value: 21 }, None)
+        .unwrap();
+    data_writer.flush().unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let samples = data_reader
+        .take(100, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 21 });
+}
+
+#[test]
+fn reannounced_reader_should_stay_matched_while_compatible() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(10, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(5, 0)).unwrap();
+    let subscription_handle = writer.get_matched_subscriptions().unwrap()[0];
+
+    writer.write(&KeyedData { id: 1, value: 1 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let user_data = UserDataQosPolicy {
+        value: vec![1, 2, 3],
+    };
+    reader
+        .set_qos(QosKind::Specific(DataReaderQos {
+            user_data: user_data.clone(),
+            ..reader_qos.clone()
+        }))
+        .unwrap();
+    let start_time = std::time::Instant::now();
+    while writer
+        .get_matched_subscription_data(subscription_handle)
+        .unwrap()
+        .user_data()
+        != &user_data
+    {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(5));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(
+        writer.get_publication_matched_status().unwrap().total_count,
+        1
+    );
+
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(
+        samples
+            .iter()
+            .map(|s| s.data().unwrap().value)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // A requested deadline shorter than the offered one makes the reader incompatible
+    reader
+        .set_qos(QosKind::Specific(DataReaderQos {
+            deadline: DeadlineQosPolicy {
+                period: DurationKind::Finite(Duration::new(1, 0)),
+            },
+            ..reader_qos
+        }))
+        .unwrap();
+    let start_time = std::time::Instant::now();
+    while !writer.get_matched_subscriptions().unwrap().is_empty() {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(5));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(
+        writer
+            .get_publication_matched_status()
+            .unwrap()
+            .current_count,
+        0
+    );
+}
+
+#[test]
+fn change_lifecycle_observer_should_be_notified_of_sent_and_acknowledged_changes() {
+    struct EventRecorder(Mutex<Vec<ChangeLifecycleEvent>>);
+    impl ChangeLifecycleObserver for EventRecorder {
+        fn on_change_event(&self, event: &ChangeLifecycleEvent) {
+            self.0.lock().unwrap().push(event.clone());
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reliable_qos = ReliabilityQosPolicy {
+        kind: ReliabilityQosPolicyKind::Reliable,
+        max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+    };
+    let data_writer_qos = DataWriterQos {
+        reliability: reliable_qos.clone(),
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        reliability: reliable_qos,
+        ..Default::default()
+    };
+    let _data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let recorder = Arc::new(EventRecorder(Mutex::new(Vec::new())));
+    data_writer
+        .set_change_lifecycle_observer(Some(recorder.clone()))
+        .unwrap();
+    data_writer
+        .write(&KeyedData { id: 1, value: 1 }, None)
+        .unwrap();
+    data_writer
+        .write(&KeyedData { id: 2, value: 2 }, None)
+        .unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let events = recorder.0.lock().unwrap();
+    for sequence_number in [1, 2] {
+        let sent = events
+            .iter()
+            .find(|e| {
+                e.kind == ChangeLifecycleEventKind::Sent && e.sequence_number == sequence_number
+            })
+            .unwrap();
+        assert!(sent.source_timestamp.is_some());
+        assert!(sent.reader_guid.is_some());
+        assert!(events
+            .iter()
+            .any(|e| e.kind == ChangeLifecycleEventKind::AcknowledgedByAll
+                && e.sequence_number == sequence_number));
+    }
+    assert!(!events
+        .iter()
+        .any(|e| e.kind == ChangeLifecycleEventKind::Dropped));
+}
+
+#[test]
+fn reliable_keep_all_durable_writer_should_keep_the_acknowledged_samples_for_late_joiners() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data_writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 200_000_000)),
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let _data_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer
+        .write(&KeyedData { id: 1, value: 1 }, None)
+        .unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    // The acknowledged sample can not be removed since it is still served to the late-joining readers
+    assert_eq!(
+        data_writer.write(&KeyedData { id: 1, value: 2 }, None),
+        Err(DdsError::Timeout)
+    );
+
+    let late_joining_reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let samples = late_joining_reader
+        .take_timeout(10, Duration::new(10, 0))
+        .unwrap();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 1 });
+}
@@ -0,0 +1,99 @@
+use dust_dds::{
+    domain::{
+        diagnostics::{DiagnosticSubsystem, DiagnosticVerbosity},
+        domain_participant_factory::DomainParticipantFactory,
+    },
+    infrastructure::{qos::QosKind, status::NO_STATUS},
+    listener::NO_LISTENER,
+};
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Default)]
+struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedOutput {
+    fn contains(&self, text: &str) -> bool {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).contains(text)
+    }
+
+    fn wait_for(&self, text: &str) -> bool {
+        let start = std::time::Instant::now();
+        while start.elapsed() < std::time::Duration::from_secs(10) {
+            if self.contains(text) {
+                return true;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        false
+    }
+}
+
+impl Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// The tracing subscriber and the diagnostic verbosity are global so this is the only test in this file
+#[test]
+fn diagnostic_verbosity_should_be_adjustable_per_subsystem_at_runtime() {
+    let output = CapturedOutput::default();
+    let writer_output = output.clone();
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .with_writer(move || writer_output.clone())
+        .init();
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert_eq!(
+        participant1.get_diagnostic_verbosity(DiagnosticSubsystem::Discovery),
+        Ok(DiagnosticVerbosity::Warn)
+    );
+
+    participant1
+        .set_diagnostic_verbosity(DiagnosticSubsystem::Discovery, DiagnosticVerbosity::Info)
+        .unwrap();
+    assert_eq!(
+        participant1.get_diagnostic_verbosity(DiagnosticSubsystem::Discovery),
+        Ok(DiagnosticVerbosity::Info)
+    );
+    let _participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert!(output.wait_for("Discovered remote participant"));
+    assert!(output.contains("dust_dds::discovery"));
+    // The other subsystems keep their default verbosity
+    assert!(!output.contains("Received datagram"));
+
+    participant1
+        .set_diagnostic_verbosity(DiagnosticSubsystem::Transport, DiagnosticVerbosity::Trace)
+        .unwrap();
+    assert!(output.wait_for("Received datagram"));
+
+    participant1
+        .set_diagnostic_verbosity(DiagnosticSubsystem::Transport, DiagnosticVerbosity::Off)
+        .unwrap();
+    participant1
+        .set_diagnostic_verbosity(DiagnosticSubsystem::Discovery, DiagnosticVerbosity::Warn)
+        .unwrap();
+    assert_eq!(
+        participant1.get_diagnostic_verbosity(DiagnosticSubsystem::Transport),
+        Ok(DiagnosticVerbosity::Off)
+    );
+}
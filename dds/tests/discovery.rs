@@ -1,16 +1,38 @@
-use std::time::Instant;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 use dust_dds::{
-    domain::domain_participant_factory::DomainParticipantFactory,
+    builtin_topics::{
+        ParticipantBuiltinTopicData, PublicationBuiltinTopicData, SubscriptionBuiltinTopicData,
+        LOCATOR_KIND_UDP_V4, LOCATOR_KIND_UDP_V6,
+    },
+    domain::{
+        discovery_conformance::{DiscoveredEntityKind, MissingParameter},
+        domain_participant::EndpointAuthorizationHook,
+        domain_participant_factory::DomainParticipantFactory,
+    },
     infrastructure::{
-        qos::{DataReaderQos, DataWriterQos, PublisherQos, QosKind, SubscriberQos},
+        error::DdsError,
+        instance::InstanceHandle,
+        qos::{
+            DataReaderQos, DataWriterQos, DomainParticipantQos, PublisherQos, QosKind,
+            SubscriberQos, TopicQos,
+        },
         qos_policy::{
-            DataRepresentationQosPolicy, OwnershipQosPolicy, OwnershipQosPolicyKind,
-            PartitionQosPolicy, UserDataQosPolicy, XCDR2_DATA_REPRESENTATION,
-            XCDR_DATA_REPRESENTATION,
+            DataRepresentationQosPolicy, GroupDataQosPolicy, OwnershipQosPolicy,
+            OwnershipQosPolicyKind, PartitionQosPolicy, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind, TopicDataQosPolicy, UserDataQosPolicy,
+            OWNERSHIP_QOS_POLICY_ID, RELIABILITY_QOS_POLICY_ID, USERDATA_QOS_POLICY_ID,
+            XCDR2_DATA_REPRESENTATION, XCDR_DATA_REPRESENTATION,
         },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
         status::{StatusKind, NO_STATUS},
-        time::Duration,
+        time::{Duration, DurationKind},
         type_support::DdsType,
     },
     listener::NO_LISTENER,
@@ -23,6 +45,9 @@ use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
 #[derive(DdsType)]
 struct UserType(#[dust_dds(key)] i32);
 
+#[derive(DdsType)]
+struct KeylessUserType(i32);
+
 #[test]
 fn writer_discovers_reader_in_same_participant() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -1118,3 +1143,704 @@ fn participant_removed_after_lease_duration() {
 
     assert_eq!(discovered_participant.len(), 1);
 }
+
+#[test]
+fn updated_group_data_and_topic_data_are_announced_to_matched_endpoints() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let publisher_group_data = GroupDataQosPolicy {
+        value: vec![1, 2, 3],
+    };
+    let subscriber_group_data = GroupDataQosPolicy {
+        value: vec![4, 5, 6],
+    };
+    publisher
+        .set_qos(QosKind::Specific(PublisherQos {
+            group_data: publisher_group_data.clone(),
+            ..Default::default()
+        }))
+        .unwrap();
+    subscriber
+        .set_qos(QosKind::Specific(SubscriberQos {
+            group_data: subscriber_group_data.clone(),
+            ..Default::default()
+        }))
+        .unwrap();
+
+    let start_time = Instant::now();
+    loop {
+        let publication_handle = data_reader.get_matched_publications().unwrap()[0];
+        let publication_data = data_reader
+            .get_matched_publication_data(publication_handle)
+            .unwrap();
+        let subscription_handle = data_writer.get_matched_subscriptions().unwrap()[0];
+        let subscription_data = data_writer
+            .get_matched_subscription_data(subscription_handle)
+            .unwrap();
+        if publication_data.group_data() == &publisher_group_data
+            && subscription_data.group_data() == &subscriber_group_data
+        {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Updated group data not received before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let topic_data = TopicDataQosPolicy {
+        value: vec![7, 8, 9],
+    };
+    topic
+        .set_qos(QosKind::Specific(TopicQos {
+            topic_data: topic_data.clone(),
+            ..Default::default()
+        }))
+        .unwrap();
+
+    let start_time = Instant::now();
+    loop {
+        let publication_handle = data_reader.get_matched_publications().unwrap()[0];
+        let publication_data = data_reader
+            .get_matched_publication_data(publication_handle)
+            .unwrap();
+        let subscription_handle = data_writer.get_matched_subscriptions().unwrap()[0];
+        let subscription_data = data_writer
+            .get_matched_subscription_data(subscription_handle)
+            .unwrap();
+        if publication_data.topic_data() == &topic_data
+            && subscription_data.topic_data() == &topic_data
+        {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Updated topic data not received before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn discovery_conformance_report_of_discovered_participant() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2_handle = participant2.get_instance_handle();
+
+    let start_time = Instant::now();
+    while !participant1
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant2_handle)
+    {
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant not discovered before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let report = participant1
+        .get_discovery_conformance_report(participant2_handle)
+        .unwrap();
+
+    assert_eq!(report.vendor_id, [0x01, 0x14]);
+    assert_eq!(report.builtin_endpoints.len(), 10);
+    assert!(report
+        .builtin_endpoints
+        .iter()
+        .all(|e| e.announced && e.matched));
+    // Parameters with the default value are not transmitted
+    assert!(report
+        .defaulted_qos_policies
+        .iter()
+        .any(|x| x.entity_kind == DiscoveredEntityKind::Participant
+            && x.policy_id == USERDATA_QOS_POLICY_ID));
+    assert!(report.missing_parameters.contains(&MissingParameter {
+        entity_kind: DiscoveredEntityKind::Participant,
+        entity_handle: participant2_handle,
+        parameter_id: 0x4014, // PID_DOMAIN_TAG
+    }));
+}
+
+#[test]
+fn discovered_participant_data_should_be_available() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2_qos = DomainParticipantQos {
+        user_data: UserDataQosPolicy {
+            value: vec![1, 2, 3],
+        },
+        ..Default::default()
+    };
+    let participant2 = domain_participant_factory
+        .create_participant(
+            domain_id,
+            QosKind::Specific(participant2_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let participant2_handle = participant2.get_instance_handle();
+
+    let start_time = Instant::now();
+    while !participant1
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant2_handle)
+    {
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant not discovered before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let participant2_data = participant1
+        .get_discovered_participant_data(participant2_handle)
+        .unwrap();
+    assert_eq!(
+        participant2_data.key().value,
+        <[u8; 16]>::from(participant2_handle)
+    );
+    assert_eq!(participant2_data.user_data().value, vec![1, 2, 3]);
+    assert!(matches!(
+        participant1.get_discovered_participant_data(InstanceHandle::new([0xff; 16])),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn check_compatibility_should_report_incompatible_qos_policies() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100)),
+        },
+        ownership: OwnershipQosPolicy {
+            kind: OwnershipQosPolicyKind::Exclusive,
+        },
+        ..Default::default()
+    };
+    let data_writer = publisher
+        .create_datawriter::<UserType>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100)),
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<UserType>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::RequestedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::OfferedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let builtin_subscriber = dp.get_builtin_subscriber();
+    let publication_data = builtin_subscriber
+        .lookup_datareader::<PublicationBuiltinTopicData>("DCPSPublication")
+        .unwrap()
+        .unwrap()
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.data().unwrap())
+        .find(|d| d.topic_name() == "topic_name")
+        .unwrap();
+    let subscription_data = builtin_subscriber
+        .lookup_datareader::<SubscriptionBuiltinTopicData>("DCPSSubscription")
+        .unwrap()
+        .unwrap()
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.data().unwrap())
+        .find(|d| d.topic_name() == "topic_name")
+        .unwrap();
+
+    assert_eq!(
+        data_reader.check_compatibility(&publication_data).unwrap(),
+        vec![RELIABILITY_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID]
+    );
+    assert_eq!(
+        data_writer.check_compatibility(&subscription_data).unwrap(),
+        vec![RELIABILITY_QOS_POLICY_ID, OWNERSHIP_QOS_POLICY_ID]
+    );
+}
+
+#[test]
+fn endpoints_disagreeing_on_topic_keys_should_not_match() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let dp1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic1 = dp1
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let dp2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic2 = dp2
+        .create_topic::<KeylessUserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = dp2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<KeylessUserType>(&topic2, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    for topic_cond in [topic1.get_statuscondition(), topic2.get_statuscondition()] {
+        topic_cond
+            .set_enabled_statuses(&[StatusKind::InconsistentTopic])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(topic_cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    assert!(topic1.get_inconsistent_topic_status().unwrap().total_count > 0);
+    assert!(topic2.get_inconsistent_topic_status().unwrap().total_count > 0);
+    assert!(data_writer.get_matched_subscriptions().unwrap().is_empty());
+    assert!(data_reader.get_matched_publications().unwrap().is_empty());
+}
+
+#[test]
+fn matched_endpoints_should_report_their_locators() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = dp
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let writer_cond = data_writer.get_statuscondition();
+    writer_cond
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut writer_wait_set = WaitSet::new();
+    writer_wait_set
+        .attach_condition(Condition::StatusCondition(writer_cond))
+        .unwrap();
+    writer_wait_set.wait(Duration::new(10, 0)).unwrap();
+    let reader_cond = data_reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut reader_wait_set = WaitSet::new();
+    reader_wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+    reader_wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let subscription_handle = data_writer.get_matched_subscriptions().unwrap()[0];
+    let publication_handle = data_reader.get_matched_publications().unwrap()[0];
+    let subscription_locators = data_writer
+        .get_matched_subscription_locators(subscription_handle)
+        .unwrap();
+    assert!(!subscription_locators.is_empty());
+    assert!(subscription_locators
+        .iter()
+        .all(|l| l.kind() == LOCATOR_KIND_UDP_V4 || l.kind() == LOCATOR_KIND_UDP_V6));
+    assert!(!data_reader
+        .get_matched_publication_locators(publication_handle)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        data_writer.get_matched_subscription_locators(publication_handle),
+        Err(DdsError::BadParameter)
+    );
+}
+
+#[test]
+fn partition_session_should_announce_additional_partitions() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let dp = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = dp
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher_qos = PublisherQos {
+        partition: PartitionQosPolicy {
+            name: vec!["A".to_string()],
+        },
+        ..Default::default()
+    };
+    let publisher = dp
+        .create_publisher(
+            QosKind::Specific(publisher_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let _data_writer = publisher
+        .create_datawriter::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = dp
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<UserType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    let publication_handle = data_reader.get_matched_publications().unwrap()[0];
+
+    let wait_for_announced_partition = |expected_partition: &[&str]| {
+        let start_time = Instant::now();
+        while data_reader
+            .get_matched_publication_data(publication_handle)
+            .unwrap()
+            .partition()
+            .name
+            != expected_partition
+        {
+            if start_time.elapsed() > std::time::Duration::from_secs(10) {
+                panic!("Partition not announced before timeout")
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    };
+
+    publisher
+        .begin_partition_session(vec!["A".to_string(), "B".to_string()])
+        .unwrap();
+    assert!(matches!(
+        publisher.begin_partition_session(vec!["C".to_string()]),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    wait_for_announced_partition(&["A", "B"]);
+    assert_eq!(
+        publisher.get_qos().unwrap().partition,
+        publisher_qos.partition
+    );
+
+    publisher.end_partition_session().unwrap();
+    wait_for_announced_partition(&["A"]);
+    assert!(matches!(
+        publisher.end_partition_session(),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
+#[test]
+fn endpoint_authorization_hook_should_prevent_matching_rejected_endpoints() {
+    struct UserDataAuthorization {
+        rejected_publications: AtomicUsize,
+        rejected_subscriptions: AtomicUsize,
+    }
+    impl EndpointAuthorizationHook for UserDataAuthorization {
+        fn authorize_publication(
+            &self,
+            _participant_data: Option<&ParticipantBuiltinTopicData>,
+            publication_data: &PublicationBuiltinTopicData,
+        ) -> bool {
+            let is_authorized = publication_data.user_data().value == b"trusted";
+            if !is_authorized {
+                self.rejected_publications.fetch_add(1, Ordering::SeqCst);
+            }
+            is_authorized
+        }
+
+        fn authorize_subscription(
+            &self,
+            participant_data: Option<&ParticipantBuiltinTopicData>,
+            _subscription_data: &SubscriptionBuiltinTopicData,
+        ) -> bool {
+            let is_authorized = participant_data.is_some_and(|p| p.user_data().value == b"trusted");
+            if !is_authorized {
+                self.rejected_subscriptions.fetch_add(1, Ordering::SeqCst);
+            }
+            is_authorized
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let dp1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let authorization = Arc::new(UserDataAuthorization {
+        rejected_publications: AtomicUsize::new(0),
+        rejected_subscriptions: AtomicUsize::new(0),
+    });
+    dp1.set_endpoint_authorization_hook(Some(authorization.clone()))
+        .unwrap();
+    let topic1 = dp1
+        .create_topic::<UserType>(
+            "topic_name",
+            "UserType",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_writer = dp1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datawriter::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = dp1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datareader::<UserType>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // The remote readers are authorized by the user data of their participant and the remote
+    // writers by their own user data. The readers carry the user data of their participant so that
+    // they can be told apart
+    let mut remote_endpoints = Vec::new();
+    for (participant_user_data, writer_user_data) in
+        [("untrusted", "trusted"), ("trusted", "untrusted")]
+    {
+        let participant_qos = DomainParticipantQos {
+            user_data: UserDataQosPolicy {
+                value: participant_user_data.as_bytes().to_vec(),
+            },
+            ..Default::default()
+        };
+        let dp = domain_participant_factory
+            .create_participant(
+                domain_id,
+                QosKind::Specific(participant_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let topic = dp
+            .create_topic::<UserType>(
+                "topic_name",
+                "UserType",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let writer_qos = DataWriterQos {
+            user_data: UserDataQosPolicy {
+                value: writer_user_data.as_bytes().to_vec(),
+            },
+            ..Default::default()
+        };
+        let remote_writer = dp
+            .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap()
+            .create_datawriter::<UserType>(
+                &topic,
+                QosKind::Specific(writer_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let reader_qos = DataReaderQos {
+            user_data: UserDataQosPolicy {
+                value: participant_user_data.as_bytes().to_vec(),
+            },
+            ..Default::default()
+        };
+        let remote_reader = dp
+            .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap()
+            .create_datareader::<UserType>(
+                &topic,
+                QosKind::Specific(reader_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        remote_endpoints.push((dp, remote_writer, remote_reader));
+    }
+    // The endpoints of the participant itself are not subject to the authorization
+    let expected_user_data = vec![Vec::new(), b"trusted".to_vec()];
+    let matched_subscriptions_user_data = || {
+        let mut user_data: Vec<_> = data_writer
+            .get_matched_subscriptions()
+            .unwrap()
+            .into_iter()
+            .map(|h| {
+                let subscription_data = data_writer.get_matched_subscription_data(h).unwrap();
+                subscription_data.user_data().value.clone()
+            })
+            .collect();
+        user_data.sort();
+        user_data
+    };
+    let matched_publications_user_data = || {
+        let mut user_data: Vec<_> = data_reader
+            .get_matched_publications()
+            .unwrap()
+            .into_iter()
+            .map(|h| {
+                let publication_data = data_reader.get_matched_publication_data(h).unwrap();
+                publication_data.user_data().value.clone()
+            })
+            .collect();
+        user_data.sort();
+        user_data
+    };
+
+    let start_time = Instant::now();
+    loop {
+        if matched_subscriptions_user_data() == expected_user_data
+            && matched_publications_user_data() == expected_user_data
+            && authorization.rejected_publications.load(Ordering::SeqCst) > 0
+            && authorization.rejected_subscriptions.load(Ordering::SeqCst) > 0
+        {
+            break;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Authorized endpoints not matched before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    // The rejected endpoints stay unmatched
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    assert_eq!(matched_subscriptions_user_data(), expected_user_data);
+    assert_eq!(matched_publications_user_data(), expected_user_data);
+}
@@ -0,0 +1,134 @@
+use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    domain::{
+        discovery_conformance::DiscoveredEntityKind, discovery_history::RemovedDiscoveredEntity,
+        domain_participant::DomainParticipant,
+        domain_participant_factory::DomainParticipantFactory,
+    },
+    infrastructure::{
+        qos::QosKind,
+        status::{StatusKind, NO_STATUS},
+        time::Duration,
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    std_runtime::StdRuntime,
+    wait_set::{Condition, WaitSet},
+};
+use std::time::Instant;
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct KeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u8,
+}
+
+fn wait_for_removed_entity(
+    participant: &DomainParticipant<StdRuntime>,
+    entity_kind: DiscoveredEntityKind,
+) -> RemovedDiscoveredEntity {
+    let start_time = Instant::now();
+    loop {
+        if let Some(removed_entity) = participant
+            .get_removed_discovered_entities()
+            .unwrap()
+            .into_iter()
+            .find(|x| x.entity_kind == entity_kind)
+        {
+            return removed_entity;
+        }
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Entity removal not received before timeout")
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+// The configuration is global to the factory so this is the only test in this file
+#[test]
+fn removed_entities_should_be_kept_in_discovery_history() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .discovery_history_retention(std::time::Duration::from_secs(60))
+        .build()
+        .unwrap();
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic1 = participant1
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = participant1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(&topic1, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic2 = participant2
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant2
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(&topic2, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    assert!(participant1
+        .get_removed_discovered_entities()
+        .unwrap()
+        .is_empty());
+
+    let publication_handle = data_reader.get_matched_publications().unwrap()[0];
+    publisher.delete_datawriter(&data_writer).unwrap();
+    let removed_publication =
+        wait_for_removed_entity(&participant1, DiscoveredEntityKind::Publication);
+    assert_eq!(removed_publication.entity_handle, publication_handle);
+
+    let participant2_handle = participant2.get_instance_handle();
+    participant2.delete_contained_entities().unwrap();
+    domain_participant_factory
+        .delete_participant(&participant2)
+        .unwrap();
+    let removed_participant =
+        wait_for_removed_entity(&participant1, DiscoveredEntityKind::Participant);
+    assert_eq!(removed_participant.entity_handle, participant2_handle);
+    assert!(!participant1
+        .get_discovered_participants()
+        .unwrap()
+        .contains(&participant2_handle));
+}
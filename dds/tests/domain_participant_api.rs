@@ -3,7 +3,9 @@ use dust_dds::{
         ParticipantBuiltinTopicData, PublicationBuiltinTopicData, SubscriptionBuiltinTopicData,
         TopicBuiltinTopicData,
     },
-    domain::domain_participant_factory::DomainParticipantFactory,
+    domain::{
+        bandwidth_statistics::BandwidthQuota, domain_participant_factory::DomainParticipantFactory,
+    },
     infrastructure::{
         error::DdsError,
         qos::{
@@ -11,8 +13,8 @@ use dust_dds::{
             SubscriberQos, TopicQos,
         },
         qos_policy::{
-            GroupDataQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind, TopicDataQosPolicy,
-            UserDataQosPolicy,
+            EntityFactoryQosPolicy, GroupDataQosPolicy, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind, TopicDataQosPolicy, UserDataQosPolicy,
         },
         sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
         status::{StatusKind, NO_STATUS},
@@ -140,6 +142,41 @@ fn not_allowed_to_delete_subscriber_from_different_participant() {
     );
 }
 
+#[test]
+fn found_topic_should_be_deleted_as_many_times_as_obtained() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<TestType>("abc", "TestType", QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let found_topic = participant
+        .find_topic::<TestType>("abc", Duration::new(0, 0))
+        .unwrap();
+    assert_eq!(
+        found_topic.get_instance_handle(),
+        topic.get_instance_handle()
+    );
+
+    assert_eq!(participant.delete_topic(&found_topic), Ok(()));
+    assert!(participant
+        .lookup_topicdescription("abc")
+        .unwrap()
+        .is_some());
+    assert_eq!(participant.delete_topic(&topic), Ok(()));
+    assert!(participant
+        .lookup_topicdescription("abc")
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        participant.delete_topic(&topic),
+        Err(DdsError::AlreadyDeleted)
+    );
+}
+
 #[test]
 fn not_allowed_to_delete_topic_from_different_participant() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -357,6 +394,51 @@ fn allowed_to_delete_subscriber_with_created_and_deleted_reader() {
     assert_eq!(participant.delete_subscriber(&subscriber), Ok(()));
 }
 
+#[test]
+fn publisher_and_subscriber_delete_contained_entities() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<TestType>("Test", "TestType", QosKind::Default, NO_LISTENER, NO_STATUS)
+        .expect("Error creating topic");
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_list: Vec<_> = (0..2)
+        .map(|_| {
+            publisher
+                .create_datawriter::<TestType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+                .unwrap()
+        })
+        .collect();
+    let reader_list: Vec<_> = (0..2)
+        .map(|_| {
+            subscriber
+                .create_datareader::<TestType>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+                .unwrap()
+        })
+        .collect();
+
+    assert_eq!(publisher.delete_contained_entities(), Ok(()));
+    assert_eq!(subscriber.delete_contained_entities(), Ok(()));
+
+    for writer in &writer_list {
+        assert_eq!(writer.get_qos().err(), Some(DdsError::AlreadyDeleted));
+    }
+    for reader in &reader_list {
+        assert_eq!(reader.get_qos().err(), Some(DdsError::AlreadyDeleted));
+    }
+    assert_eq!(participant.delete_publisher(&publisher), Ok(()));
+    assert_eq!(participant.delete_subscriber(&subscriber), Ok(()));
+    assert_eq!(participant.delete_topic(&topic), Ok(()));
+}
+
 #[test]
 fn allowed_to_delete_topic_with_created_and_deleted_writer() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -586,6 +668,69 @@ fn builtin_reader_access() {
         .is_ok());
 }
 
+#[test]
+fn builtin_readers_should_behave_as_ordinary_readers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "topic_name",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+    let participants_reader = builtin_subscriber
+        .lookup_datareader::<ParticipantBuiltinTopicData>("DCPSParticipant")
+        .unwrap()
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    let participant_samples = loop {
+        if let Ok(samples) =
+            participants_reader.take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        {
+            break samples;
+        }
+
+        if start_time.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Participant discovery data not received")
+        }
+    };
+    assert_eq!(participant_samples.len(), 1);
+    assert_eq!(
+        participants_reader.read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE),
+        Err(DdsError::NoData)
+    );
+    assert!(participants_reader.get_qos().is_ok());
+    assert!(participants_reader
+        .get_subscription_matched_status()
+        .is_ok());
+    assert!(participants_reader
+        .set_listener(NO_LISTENER, NO_STATUS)
+        .is_ok());
+
+    assert!(matches!(
+        builtin_subscriber.delete_datareader(&participants_reader),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    assert!(matches!(
+        builtin_subscriber.create_datareader::<MyData>(
+            &topic,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS
+        ),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+}
+
 #[test]
 fn get_discovery_data_from_builtin_reader() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -916,3 +1061,356 @@ fn ignore_participant() {
     // Participant should only discover itself
     assert_eq!(participant1.get_discovered_participants().unwrap().len(), 1);
 }
+
+#[test]
+fn entities_created_disabled_do_not_communicate_until_enabled() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant_qos = DomainParticipantQos {
+        entity_factory: EntityFactoryQosPolicy {
+            autoenable_created_entities: false,
+        },
+        ..Default::default()
+    };
+    let participant = domain_participant_factory
+        .create_participant(
+            domain_id,
+            QosKind::Specific(participant_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let data = MyData { id: 1, value: 2 };
+    assert_eq!(data_writer.write(&data, None), Err(DdsError::NotEnabled));
+    assert_eq!(
+        data_reader
+            .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NotEnabled)
+    );
+    assert_eq!(
+        data_reader.get_matched_publications(),
+        Err(DdsError::NotEnabled)
+    );
+    assert!(matches!(
+        data_writer.enable(),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+
+    topic.enable().unwrap();
+    publisher.enable().unwrap();
+    subscriber.enable().unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer.write(&data, None).unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let samples = data_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap(), data);
+}
+
+#[test]
+fn bandwidth_statistics_and_topic_quota() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reader_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_topic = reader_participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Infinite,
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<MyData>(
+            &reader_topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    // A sample arriving before the reader is matched is sent again and counted twice
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data = MyData { id: 1, value: 2 };
+    data_writer.write(&data, None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let statistics = participant.get_bandwidth_statistics().unwrap();
+    let topic_statistics = statistics
+        .topics
+        .iter()
+        .find(|x| x.topic_name == "MyTopic")
+        .unwrap();
+    let sample_bytes = topic_statistics.bytes_sent;
+    assert!(sample_bytes > 0);
+    let participant_statistics = statistics
+        .participants
+        .iter()
+        .find(|x| x.participant_handle == reader_participant.get_instance_handle())
+        .unwrap();
+    assert_eq!(participant_statistics.bytes_sent, sample_bytes);
+    let reader_topic_statistics = reader_participant
+        .get_bandwidth_statistics()
+        .unwrap()
+        .topics
+        .into_iter()
+        .find(|x| x.topic_name == "MyTopic")
+        .unwrap();
+    assert_eq!(reader_topic_statistics.bytes_received, sample_bytes);
+
+    // The writes exceeding the quota are not rejected but sent in the following periods
+    participant
+        .set_topic_bandwidth_quota(
+            "MyTopic",
+            Some(BandwidthQuota {
+                max_bytes: 1,
+                period: Duration::new(0, 200_000_000),
+            }),
+        )
+        .unwrap();
+    let start_time = std::time::Instant::now();
+    for id in 10..13 {
+        data_writer.write(&MyData { id, value: 2 }, None).unwrap();
+    }
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    assert!(start_time.elapsed() >= std::time::Duration::from_millis(400));
+    let samples = data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 4);
+    let topic_statistics = participant
+        .get_bandwidth_statistics()
+        .unwrap()
+        .topics
+        .into_iter()
+        .find(|x| x.topic_name == "MyTopic")
+        .unwrap();
+    assert_eq!(topic_statistics.bytes_sent, 4 * sample_bytes);
+
+    participant
+        .set_topic_bandwidth_quota("MyTopic", None)
+        .unwrap();
+    data_writer.write(&data, None).unwrap();
+
+    assert_eq!(
+        participant.set_topic_bandwidth_quota(
+            "MyTopic",
+            Some(BandwidthQuota {
+                max_bytes: 0,
+                period: Duration::new(1, 0),
+            }),
+        ),
+        Err(DdsError::BadParameter)
+    );
+    assert_eq!(
+        participant.set_topic_bandwidth_quota("OtherTopic", None),
+        Err(DdsError::BadParameter)
+    );
+
+    // The statistics of a participant are removed with it
+    let reader_participant_handle = reader_participant.get_instance_handle();
+    reader_participant.delete_contained_entities().unwrap();
+    domain_participant_factory
+        .delete_participant(&reader_participant)
+        .unwrap();
+    let start_time = std::time::Instant::now();
+    while participant
+        .get_bandwidth_statistics()
+        .unwrap()
+        .participants
+        .iter()
+        .any(|x| x.participant_handle == reader_participant_handle)
+    {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn memory_usage_statistics() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Infinite,
+        },
+        ..Default::default()
+    };
+    let data_reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    data_writer
+        .write(&MyData { id: 1, value: 1 }, None)
+        .unwrap();
+    data_writer
+        .write(&MyData { id: 2, value: 2 }, None)
+        .unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let statistics = participant.get_memory_usage_statistics().unwrap();
+    assert_eq!(statistics.data_writers.len(), 1);
+    let writer_usage = &statistics.data_writers[0];
+    assert_eq!(
+        writer_usage.writer_handle,
+        data_writer.get_instance_handle()
+    );
+    assert_eq!(writer_usage.topic_name, "MyTopic");
+    assert_eq!(writer_usage.sample_count, 2);
+    assert!(writer_usage.history_bytes > 0);
+    assert_eq!(writer_usage.fragment_buffer_bytes, 0);
+    assert_eq!(statistics.data_readers.len(), 1);
+    let reader_usage = &statistics.data_readers[0];
+    assert_eq!(
+        reader_usage.reader_handle,
+        data_reader.get_instance_handle()
+    );
+    assert_eq!(reader_usage.sample_count, 2);
+    assert_eq!(reader_usage.instance_count, 2);
+    assert!(reader_usage.cache_bytes > 0);
+    assert!(statistics.discovery_bytes > 0);
+    assert_eq!(
+        statistics.total_bytes(),
+        writer_usage.history_bytes + reader_usage.cache_bytes + statistics.discovery_bytes
+    );
+
+    data_reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let statistics = participant.get_memory_usage_statistics().unwrap();
+    assert_eq!(statistics.data_readers[0].sample_count, 0);
+    assert_eq!(statistics.data_readers[0].cache_bytes, 0);
+}
@@ -1,8 +1,9 @@
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DomainParticipantQos, QosKind},
-        qos_policy::UserDataQosPolicy,
+        qos_policy::{GuidPrefixQosPolicy, GuidPrefixQosPolicyKind, UserDataQosPolicy},
         status::NO_STATUS,
         type_support::DdsType,
     },
@@ -130,3 +131,77 @@ fn allowed_to_delete_participant_after_delete_contained_entities() {
         .delete_participant(&participant)
         .is_ok());
 }
+
+#[test]
+fn participants_with_automatic_guid_prefix_have_different_prefixes() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant_list: Vec<_> = (0..3)
+        .map(|_| {
+            domain_participant_factory
+                .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+                .unwrap()
+        })
+        .collect();
+
+    let mut guid_prefix_list: Vec<_> = participant_list
+        .iter()
+        .map(|p| p.get_instance_handle().as_ref()[..12].to_vec())
+        .collect();
+    guid_prefix_list.sort();
+    guid_prefix_list.dedup();
+    assert_eq!(guid_prefix_list.len(), participant_list.len());
+}
+
+#[test]
+fn participant_with_manual_guid_prefix() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let guid_prefix = [0xd0, 0x57, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let qos = DomainParticipantQos {
+        guid_prefix: GuidPrefixQosPolicy {
+            kind: GuidPrefixQosPolicyKind::Manual(guid_prefix),
+        },
+        ..Default::default()
+    };
+
+    let participant = domain_participant_factory
+        .create_participant(
+            domain_id,
+            QosKind::Specific(qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    assert_eq!(
+        participant.get_instance_handle().as_ref()[..12],
+        guid_prefix
+    );
+
+    // The prefix can not be given to another participant while it is in use
+    assert!(matches!(
+        domain_participant_factory.create_participant(
+            domain_id,
+            QosKind::Specific(qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        ),
+        Err(DdsError::PreconditionNotMet(_))
+    ));
+    assert_eq!(
+        participant.set_qos(QosKind::Default),
+        Err(DdsError::ImmutablePolicy)
+    );
+
+    domain_participant_factory
+        .delete_participant(&participant)
+        .unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Specific(qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert_eq!(
+        participant.get_instance_handle().as_ref()[..12],
+        guid_prefix
+    );
+}
@@ -0,0 +1,299 @@
+use dust_dds::{
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        error::DdsError,
+        qos::{DataReaderQos, QosKind},
+        qos_policy::{ReliabilityQosPolicy, ReliabilityQosPolicyKind},
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::{DdsSerialize, DdsType},
+    },
+    listener::NO_LISTENER,
+    topic_definition::dynamic_data::{
+        DynamicData, DynamicMemberType, DynamicStructType, DynamicStructTypeBuilder, MemberValue,
+    },
+    wait_set::{Condition, WaitSet},
+};
+use std::sync::Arc;
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct Vehicle {
+    #[dust_dds(key)]
+    id: u32,
+    name: String,
+    position: Position,
+    readings: Vec<f64>,
+}
+
+fn vehicle_type() -> DynamicStructType {
+    let position_type = DynamicStructTypeBuilder::new("Position")
+        .member("x", DynamicMemberType::Int32)
+        .member("y", DynamicMemberType::Int32)
+        .build()
+        .unwrap();
+    DynamicStructTypeBuilder::new("Vehicle")
+        .key_member("id", DynamicMemberType::Uint32)
+        .member("name", DynamicMemberType::String)
+        .member("position", DynamicMemberType::Struct(position_type))
+        .member(
+            "readings",
+            DynamicMemberType::Sequence(Box::new(DynamicMemberType::Float64)),
+        )
+        .build()
+        .unwrap()
+}
+
+fn reliable_reader_qos() -> DataReaderQos {
+    DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn dynamic_data_members_should_be_accessed_by_name_and_id() {
+    let vehicle_type = vehicle_type();
+    assert_eq!(vehicle_type.type_name(), "Vehicle");
+    assert_eq!(vehicle_type.member_count(), 4);
+    assert_eq!(vehicle_type.get_member_id_by_name("position"), Some(2));
+    assert_eq!(vehicle_type.get_member_name(1), Some("name"));
+    assert!(vehicle_type.is_key_member(0));
+    assert!(!vehicle_type.is_key_member(1));
+
+    let mut data = DynamicData::new(vehicle_type.clone());
+    assert_eq!(data.get_value("id"), Ok(&MemberValue::Uint32(0)));
+    assert_eq!(
+        data.get_value("position"),
+        Ok(&MemberValue::Struct(vec![
+            MemberValue::Int32(0),
+            MemberValue::Int32(0)
+        ]))
+    );
+
+    data.set_value("id", MemberValue::Uint32(3)).unwrap();
+    data.set_value_by_id(1, MemberValue::String(String::from("truck")))
+        .unwrap();
+    let mut position = data.get_complex_value("position").unwrap();
+    position.set_value("x", MemberValue::Int32(-5)).unwrap();
+    data.set_complex_value("position", position).unwrap();
+    assert_eq!(data.get_value_by_id(0), Ok(&MemberValue::Uint32(3)));
+    assert_eq!(
+        data.get_value("name"),
+        Ok(&MemberValue::String(String::from("truck")))
+    );
+    assert_eq!(
+        data.get_complex_value("position").unwrap().get_value("x"),
+        Ok(&MemberValue::Int32(-5))
+    );
+
+    assert_eq!(
+        data.set_value("id", MemberValue::Int32(3)),
+        Err(DdsError::BadParameter)
+    );
+    assert_eq!(
+        data.set_value("speed", MemberValue::Uint32(3)),
+        Err(DdsError::BadParameter)
+    );
+    assert_eq!(data.get_value_by_id(4), Err(DdsError::BadParameter));
+    assert_eq!(data.get_complex_value("name"), Err(DdsError::BadParameter));
+
+    // The samples are serialized as those of the equivalent generated struct
+    let vehicle = Vehicle {
+        id: 3,
+        name: String::from("truck"),
+        position: Position { x: -5, y: 0 },
+        readings: vec![],
+    };
+    assert_eq!(data.serialize_data(), vehicle.serialize_data());
+    assert_eq!(
+        DynamicData::from_serialized_data(vehicle_type, &vehicle.serialize_data().unwrap()),
+        Ok(data)
+    );
+
+    assert_eq!(
+        DynamicStructTypeBuilder::new("Duplicated")
+            .member("a", DynamicMemberType::Int8)
+            .member("a", DynamicMemberType::Int16)
+            .build(),
+        Err(DdsError::BadParameter)
+    );
+}
+
+#[test]
+fn dynamic_data_should_be_exchanged_with_generated_types() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let vehicle_type = vehicle_type();
+    let dynamic_topic = participant1
+        .create_dynamic_topic(
+            "VehicleTopic",
+            vehicle_type.type_name(),
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+            Arc::new(vehicle_type.clone()),
+        )
+        .unwrap();
+    let topic = participant2
+        .create_topic::<Vehicle>(
+            "VehicleTopic",
+            "Vehicle",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let dynamic_writer = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datawriter::<DynamicData>(&dynamic_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let dynamic_reader = participant1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datareader::<DynamicData>(
+            &dynamic_topic,
+            QosKind::Specific(reliable_reader_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let writer = participant2
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datawriter::<Vehicle>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap()
+        .create_datareader::<Vehicle>(
+            &topic,
+            QosKind::Specific(reliable_reader_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    for condition in [
+        dynamic_writer.get_statuscondition(),
+        writer.get_statuscondition(),
+    ] {
+        condition
+            .set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        wait_set
+            .attach_condition(Condition::StatusCondition(condition))
+            .unwrap();
+    }
+    // Each writer matches the reader of its own participant and the one of the other participant
+    while dynamic_writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 2
+        || writer
+            .get_publication_matched_status()
+            .unwrap()
+            .current_count
+            < 2
+    {
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    let mut data = DynamicData::new(vehicle_type.clone());
+    data.set_value("id", MemberValue::Uint32(1)).unwrap();
+    data.set_value("name", MemberValue::String(String::from("car")))
+        .unwrap();
+    data.set_value(
+        "position",
+        MemberValue::Struct(vec![MemberValue::Int32(10), MemberValue::Int32(20)]),
+    )
+    .unwrap();
+    data.set_value(
+        "readings",
+        MemberValue::Sequence(vec![MemberValue::Float64(0.5)]),
+    )
+    .unwrap();
+    dynamic_writer.write(&data, None).unwrap();
+    let vehicle = Vehicle {
+        id: 2,
+        name: String::from("bus"),
+        position: Position { x: 1, y: 2 },
+        readings: vec![1.5, 2.5],
+    };
+    writer.write(&vehicle, None).unwrap();
+    dynamic_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let mut received: Vec<Vehicle> = samples.iter().map(|s| s.data().unwrap()).collect();
+    received.sort_by_key(|v| v.id);
+    assert_eq!(
+        received,
+        vec![
+            Vehicle {
+                id: 1,
+                name: String::from("car"),
+                position: Position { x: 10, y: 20 },
+                readings: vec![0.5],
+            },
+            vehicle.clone()
+        ]
+    );
+
+    let samples = dynamic_reader
+        .take_serialized(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let mut received: Vec<DynamicData> = samples
+        .iter()
+        .map(|s| {
+            DynamicData::from_serialized_data(vehicle_type.clone(), s.serialized_data().unwrap())
+                .unwrap()
+        })
+        .collect();
+    received.sort_by_key(|d| match d.get_value("id") {
+        Ok(MemberValue::Uint32(id)) => *id,
+        _ => u32::MAX,
+    });
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0], data);
+    assert_eq!(
+        received[1].get_value("name"),
+        Ok(&MemberValue::String(String::from("bus")))
+    );
+    assert_eq!(
+        received[1].get_value("readings"),
+        Ok(&MemberValue::Sequence(vec![
+            MemberValue::Float64(1.5),
+            MemberValue::Float64(2.5)
+        ]))
+    );
+}
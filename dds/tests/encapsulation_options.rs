@@ -0,0 +1,106 @@
+use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{ReliabilityQosPolicy, ReliabilityQosPolicyKind},
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    wait_set::{Condition, WaitSet},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct PaddedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u8,
+}
+
+// The configuration is global to the factory so this is the only test in this file
+#[test]
+fn samples_without_encoded_padding_should_pass_strict_validation() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .encapsulation_padding_encoding(false)
+        .strict_encapsulation_options(true)
+        .build()
+        .unwrap();
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<PaddedData>(
+            "MyTopic",
+            "PaddedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let reliability = ReliabilityQosPolicy {
+        kind: ReliabilityQosPolicyKind::Reliable,
+        max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+    };
+    let data_writer = publisher
+        .create_datawriter::<PaddedData>(
+            &topic,
+            QosKind::Specific(DataWriterQos {
+                reliability: reliability.clone(),
+                ..Default::default()
+            }),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let data_reader = subscriber
+        .create_datareader::<PaddedData>(
+            &topic,
+            QosKind::Specific(DataReaderQos {
+                reliability,
+                ..Default::default()
+            }),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = data_writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data = PaddedData { id: 1, value: 2 };
+    data_writer.write(&data, None).unwrap();
+    data_writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = data_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap(), data);
+    assert_eq!(data_reader.get_sample_lost_status().unwrap().total_count, 0);
+}
@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use dust_dds::{
+    condition::{GuardCondition, QueryCondition, ReadCondition, StatusCondition},
+    dds_async::{
+        data_reader::DataReaderAsync, data_writer::DataWriterAsync,
+        domain_participant::DomainParticipantAsync, publisher::PublisherAsync,
+        subscriber::SubscriberAsync, topic::TopicAsync,
+    },
+    domain::{
+        domain_participant::DomainParticipant, domain_participant_factory::DomainParticipantFactory,
+    },
+    infrastructure::{
+        error::DdsError,
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+        },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::NO_STATUS,
+        time::{Duration, DurationKind},
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    publication::{data_writer::DataWriter, publisher::Publisher},
+    std_runtime::StdRuntime,
+    subscription::{data_reader::DataReader, subscriber::Subscriber},
+    topic_definition::topic::Topic,
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct KeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u32,
+}
+
+// Type which can neither be sent nor shared between threads
+struct LocalData(#[allow(dead_code)] Rc<u8>);
+
+fn assert_clone_send_sync<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn entity_handles_should_be_clone_send_and_sync() {
+    assert_clone_send_sync::<DomainParticipant<StdRuntime>>();
+    assert_clone_send_sync::<Publisher<StdRuntime>>();
+    assert_clone_send_sync::<Subscriber<StdRuntime>>();
+    assert_clone_send_sync::<Topic<StdRuntime>>();
+    assert_clone_send_sync::<DataWriter<StdRuntime, LocalData>>();
+    assert_clone_send_sync::<DataReader<StdRuntime, LocalData>>();
+    assert_clone_send_sync::<StatusCondition<StdRuntime>>();
+    assert_clone_send_sync::<GuardCondition>();
+    assert_clone_send_sync::<ReadCondition<StdRuntime>>();
+    assert_clone_send_sync::<QueryCondition<StdRuntime>>();
+
+    assert_clone_send_sync::<DomainParticipantAsync<StdRuntime>>();
+    assert_clone_send_sync::<PublisherAsync<StdRuntime>>();
+    assert_clone_send_sync::<SubscriberAsync<StdRuntime>>();
+    assert_clone_send_sync::<TopicAsync<StdRuntime>>();
+    assert_clone_send_sync::<DataWriterAsync<StdRuntime, LocalData>>();
+    assert_clone_send_sync::<DataReaderAsync<StdRuntime, LocalData>>();
+}
+
+#[test]
+fn cloned_entities_should_be_usable_from_multiple_threads() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while writer.get_matched_subscriptions().unwrap().is_empty() {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(5));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    std::thread::scope(|s| {
+        for id in 0..4 {
+            let writer = writer.clone();
+            let participant = participant.clone();
+            s.spawn(move || {
+                for value in 0..10 {
+                    writer.write(&KeyedData { id, value }, None).unwrap();
+                }
+                participant
+                    .create_topic::<KeyedData>(
+                        &format!("OtherTopic{id}"),
+                        "KeyedData",
+                        QosKind::Default,
+                        NO_LISTENER,
+                        NO_STATUS,
+                    )
+                    .unwrap();
+            });
+        }
+    });
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let samples = reader
+        .take(100, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 40);
+    for id in 0..4 {
+        assert!(participant
+            .lookup_topicdescription(&format!("OtherTopic{id}"))
+            .unwrap()
+            .is_some());
+    }
+
+    let writer_clone = writer.clone();
+    publisher.delete_datawriter(&writer).unwrap();
+    assert_eq!(
+        writer_clone.write(&KeyedData { id: 0, value: 0 }, None),
+        Err(DdsError::AlreadyDeleted)
+    );
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use dust_dds::{
+    configuration::{DustDdsConfigurationBuilder, EntityKeyAllocation},
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        error::DdsError,
+        qos::QosKind,
+        status::{StatusKind, NO_STATUS},
+        time::Duration,
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    wait_set::{Condition, WaitSet},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct KeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u32,
+}
+
+// The configuration is global to the factory so this is the only test in this file
+#[test]
+fn application_provided_entity_key_should_be_used_in_writer_guid() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let entity_keys = BTreeMap::from([(String::from("MyTopic"), [0x12, 0x34, 0x56])]);
+    let configuration = DustDdsConfigurationBuilder::new()
+        .entity_key_allocation(EntityKeyAllocation::ApplicationProvided(entity_keys))
+        .build()
+        .unwrap();
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let _data_writer = publisher
+        .create_datawriter::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert!(matches!(
+        publisher
+            .create_datawriter::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .err(),
+        Some(DdsError::PreconditionNotMet(_))
+    ));
+    let data_reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = data_reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let matched_publications = data_reader.get_matched_publications().unwrap();
+    let matched_publication_data = data_reader
+        .get_matched_publication_data(matched_publications[0])
+        .unwrap();
+    assert_eq!(
+        matched_publication_data.key().value[12..15],
+        [0x12, 0x34, 0x56]
+    );
+}
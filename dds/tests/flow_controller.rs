@@ -0,0 +1,139 @@
+use dust_dds::{
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{
+            FlowControllerQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind,
+        },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder,
+    wait_set::{Condition, WaitSet},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct LargeData {
+    #[dust_dds(key)]
+    id: u8,
+    value: Vec<u8>,
+}
+
+// The transport is global to the factory so this is the only test in this file
+#[test]
+fn writer_assigned_to_flow_controller_should_send_at_the_flow_controller_rate() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let transport = Box::new(
+        RtpsUdpTransportParticipantFactoryBuilder::new()
+            .flow_controller("slow", 4000, std::time::Duration::from_millis(100))
+            .build()
+            .unwrap(),
+    );
+    domain_participant_factory.set_transport(transport).unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<LargeData>(
+            "LargeDataTopic",
+            "LargeData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        flow_controller: FlowControllerQosPolicy {
+            name: String::from("slow"),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<LargeData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 1
+    {
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    let data: Vec<LargeData> = (1..=4)
+        .map(|id| LargeData {
+            id,
+            value: vec![id; 10000],
+        })
+        .collect();
+    let start_time = std::time::Instant::now();
+    for sample in &data {
+        writer.write(sample, None).unwrap();
+    }
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    // The 40000 bytes of the samples take at least 9 periods of 4000 bytes after the first one
+    assert!(start_time.elapsed() >= std::time::Duration::from_millis(900));
+    let samples = reader
+        .take(5, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let received: Vec<LargeData> = samples.iter().map(|s| s.data().unwrap()).collect();
+    assert_eq!(received, data);
+}
@@ -0,0 +1,341 @@
+use dust_dds::{
+    builtin_topics::DCPS_PARTICIPANT,
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        error::DdsError,
+        qos::{DataReaderQos, QosKind},
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+        },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsSerialize, DdsType},
+    },
+    listener::NO_LISTENER,
+    rtps::types::{PROTOCOLVERSION, VENDOR_ID_S2E},
+    rtps_messages::{
+        overall_structure::{BufRead, RtpsMessageHeader, RtpsMessageWrite},
+        submessage_elements::{Data, ParameterList, SerializedDataFragment},
+        submessages::{data::DataSubmessage, data_frag::DataFragSubmessage},
+    },
+    rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder,
+    transport::types::{
+        EntityId, BUILT_IN_READER_WITH_KEY, BUILT_IN_WRITER_WITH_KEY, ENTITYID_UNKNOWN,
+        USER_DEFINED_WRITER_WITH_KEY,
+    },
+    wait_set::{Condition, WaitSet},
+};
+use std::io::Read;
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+const ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER: EntityId =
+    EntityId::new([0, 0, 0x03], BUILT_IN_WRITER_WITH_KEY);
+
+const ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR: EntityId =
+    EntityId::new([0, 0, 0x03], BUILT_IN_READER_WITH_KEY);
+
+const FRAGMENT_SIZE: u16 = 100;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct LargeKeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: Vec<u8>,
+}
+
+struct DynamicType<'a>(&'a [u8]);
+impl<'de> DdsDeserialize<'de> for DynamicType<'de> {
+    fn deserialize_data(
+        serialized_data: &'de [u8],
+    ) -> dust_dds::infrastructure::error::DdsResult<Self> {
+        Ok(Self(serialized_data))
+    }
+}
+
+impl DynamicType<'_> {
+    fn locator_port(&self, locator_pid: i16) -> u32 {
+        let reader = &mut &self.0[4..];
+        let mut pid = [0, 0];
+        let mut length = [0, 0];
+        loop {
+            reader.read_exact(&mut pid).unwrap();
+            reader.read_exact(&mut length).unwrap();
+            if i16::from_le_bytes(pid) == locator_pid {
+                return u32::from_le_bytes([reader[4], reader[5], reader[6], reader[7]]);
+            } else {
+                reader.consume(u16::from_le_bytes(length) as usize);
+            }
+        }
+    }
+}
+
+fn data_frag_submessage(
+    writer_id: EntityId,
+    writer_sn: i64,
+    serialized_data: &Data,
+    fragment_starting_num: u32,
+) -> DataFragSubmessage {
+    let data_size = serialized_data.len();
+    let start = (fragment_starting_num as usize - 1) * FRAGMENT_SIZE as usize;
+    let end = data_size.min(start + FRAGMENT_SIZE as usize);
+    DataFragSubmessage::new(
+        false,
+        false,
+        false,
+        ENTITYID_UNKNOWN,
+        writer_id,
+        writer_sn,
+        fragment_starting_num,
+        1,
+        FRAGMENT_SIZE,
+        data_size as u32,
+        ParameterList::empty(),
+        SerializedDataFragment::new(serialized_data.clone(), start..end),
+    )
+}
+
+// The transport is global to the factory so this is the only test in this file
+#[test]
+fn reader_should_reassemble_interleaved_fragments_and_discard_the_incomplete_ones_after_timeout() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let transport = Box::new(
+        RtpsUdpTransportParticipantFactoryBuilder::new()
+            .fragment_reassembly_timeout(std::time::Duration::from_millis(500))
+            .build()
+            .unwrap(),
+    );
+    domain_participant_factory.set_transport(transport).unwrap();
+
+    let mock_writer_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+    let writer_socket_port = mock_writer_socket.local_addr().unwrap().port();
+
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic = participant
+        .create_topic::<LargeKeyedData>(
+            "MyTopic",
+            "LargeKeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<LargeKeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy writer
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (writer_socket_port as u32).to_le_bytes();
+    let writer_id = EntityId::new([0, 0, 0x10], USER_DEFINED_WRITER_WITH_KEY);
+
+    let serialized_dummy_writer_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // PublicationBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0x10, 0x02, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 20, 0x00, // PID_TYPE_NAME, Length
+            15, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'L', b'a', b'r', b'g', //
+            b'e', b'K', b'e', b'y', //
+            b'e', b'd', b'D', b'a', //
+            b't', b'a', 0, 0, //
+            // WriterProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_writer_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_writer_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_writer_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_writer_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let participant_data = dcps_sample_list[0].data().unwrap();
+    const PID_METATRAFFIC_UNICAST_LOCATOR: i16 = 0x0032;
+    const PID_DEFAULT_UNICAST_LOCATOR: i16 = 0x0031;
+    let metatraffic_port = participant_data.locator_port(PID_METATRAFFIC_UNICAST_LOCATOR);
+    let default_port = participant_data.locator_port(PID_DEFAULT_UNICAST_LOCATOR);
+    mock_writer_socket
+        .send_to(
+            discovered_writer_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_reader = WaitSet::new();
+    let reader_status_condition = reader.get_statuscondition();
+    reader_status_condition
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    waitset_reader
+        .attach_condition(Condition::StatusCondition(reader_status_condition))
+        .unwrap();
+    waitset_reader.wait(Duration::new(10, 0)).unwrap();
+
+    let samples: Vec<_> = (1..=3)
+        .map(|id| LargeKeyedData {
+            id,
+            value: vec![id; 250],
+        })
+        .collect();
+    let serialized_samples: Vec<Data> = samples
+        .iter()
+        .map(|s| Data::new(s.serialize_data().unwrap().into()))
+        .collect();
+    let send_fragment = |writer_sn: i64, fragment_starting_num| {
+        let data_frag_submessage = data_frag_submessage(
+            writer_id,
+            writer_sn,
+            &serialized_samples[writer_sn as usize - 1],
+            fragment_starting_num,
+        );
+        let data_frag_message =
+            RtpsMessageWrite::new(&rtps_message_header, &[&data_frag_submessage]);
+        mock_writer_socket
+            .send_to(
+                data_frag_message.buffer(),
+                ("127.0.0.1", default_port as u16),
+            )
+            .unwrap();
+    };
+
+    // The fragments of the first two samples are received interleaved, out of order and some of them twice
+    send_fragment(2, 3);
+    send_fragment(1, 2);
+    send_fragment(2, 1);
+    send_fragment(2, 1);
+    send_fragment(1, 2);
+    send_fragment(2, 2);
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    // The second sample is complete but it is only made available after the first one
+    assert_eq!(
+        reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
+    send_fragment(1, 3);
+    send_fragment(1, 1);
+
+    let start_time = std::time::Instant::now();
+    let mut received_samples = Vec::new();
+    while received_samples.len() < 2 {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        if let Ok(sample_list) =
+            reader.take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        {
+            received_samples.extend(sample_list.iter().map(|s| s.data().unwrap()));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(received_samples, samples[..2]);
+
+    // The fragments of a sample which is never completed are discarded after the reassembly timeout
+    let fragment_buffer_bytes = || {
+        participant
+            .get_memory_usage_statistics()
+            .unwrap()
+            .data_readers[0]
+            .fragment_buffer_bytes
+    };
+    assert_eq!(fragment_buffer_bytes(), 0);
+    send_fragment(3, 1);
+    let start_time = std::time::Instant::now();
+    while fragment_buffer_bytes() == 0 {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let first_fragment_time = std::time::Instant::now();
+    while fragment_buffer_bytes() > 0 {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(first_fragment_time.elapsed() >= std::time::Duration::from_millis(400));
+}
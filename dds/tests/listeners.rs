@@ -10,9 +10,11 @@ use dust_dds::{
     infrastructure::{
         qos::{DataReaderQos, DataWriterQos, QosKind},
         qos_policy::{
-            DeadlineQosPolicy, HistoryQosPolicy, HistoryQosPolicyKind, Length,
-            ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+            DeadlineQosPolicy, DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy,
+            HistoryQosPolicyKind, Length, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            ResourceLimitsQosPolicy,
         },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
         status::{
             OfferedDeadlineMissedStatus, OfferedIncompatibleQosStatus, PublicationMatchedStatus,
             RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus, SampleRejectedStatus,
@@ -25,6 +27,7 @@ use dust_dds::{
     publication::{
         data_writer_listener::DataWriterListener, publisher_listener::PublisherListener,
     },
+    std_runtime::executor::Executor,
     subscription::{
         data_reader_listener::DataReaderListener, subscriber_listener::SubscriberListener,
     },
@@ -739,6 +742,116 @@ fn on_data_available_listener() {
         .is_ok());
 }
 
+#[test]
+fn blocked_listener_on_own_executor_does_not_stall_reception() {
+    struct BlockingListener {
+        called_sender: std::sync::mpsc::SyncSender<()>,
+        release_receiver: std::sync::mpsc::Receiver<()>,
+    }
+
+    impl<R: DdsRuntime> DataReaderListener<R, MyData> for BlockingListener {
+        async fn on_data_available(&mut self, _the_reader: DataReaderAsync<R, MyData>) {
+            self.called_sender.send(()).ok();
+            self.release_receiver.recv().ok();
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let (called_sender, called_receiver) = std::sync::mpsc::sync_channel(5);
+    let (release_sender, release_receiver) = std::sync::mpsc::channel();
+    let reader_listener = BlockingListener {
+        called_sender,
+        release_receiver,
+    };
+
+    let listener_executor = Executor::new();
+    let reader = subscriber
+        .create_datareader_with_executor(
+            &topic,
+            QosKind::Specific(reader_qos),
+            Some(reader_listener),
+            &[StatusKind::DataAvailable],
+            &listener_executor.handle(),
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(&MyData { id: 1, value: 1 }, None).unwrap();
+    assert!(called_receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .is_ok());
+
+    // The listener is now blocked but the participant keeps receiving and acknowledging data
+    writer.write(&MyData { id: 2, value: 2 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    let samples = reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples.len(), 2);
+
+    release_sender.send(()).unwrap();
+    release_sender.send(()).unwrap();
+}
+
 #[test]
 fn data_on_readers_listener() {
     struct DataOnReadersListener {
@@ -1246,6 +1359,123 @@ fn data_reader_subscription_matched_listener() {
     assert_eq!(status.total_count_change, 1);
 }
 
+#[test]
+fn data_reader_subscription_matched_listener_should_be_notified_when_writer_becomes_incompatible() {
+    struct SubscriptionMatchedListener {
+        sender: std::sync::mpsc::SyncSender<SubscriptionMatchedStatus>,
+    }
+
+    impl<R: DdsRuntime> DataReaderListener<R, MyData> for SubscriptionMatchedListener {
+        async fn on_subscription_matched(
+            &mut self,
+            _the_reader: DataReaderAsync<R, MyData>,
+            status: SubscriptionMatchedStatus,
+        ) {
+            self.sender.send(status).ok();
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "SubscriptionMatchedListenerTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let data_writer_qos = DataWriterQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<MyData>(
+            &topic,
+            QosKind::Specific(data_writer_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+
+    let (sender, receiver) = std::sync::mpsc::sync_channel(5);
+    let reader_listener = SubscriptionMatchedListener { sender };
+
+    let reader = subscriber
+        .create_datareader(
+            &topic,
+            QosKind::Specific(reader_qos),
+            Some(reader_listener),
+            &[StatusKind::SubscriptionMatched],
+        )
+        .unwrap();
+
+    let status = receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .unwrap();
+    assert_eq!(status.current_count, 1);
+
+    // An offered deadline longer than the requested one makes the writer incompatible
+    writer
+        .set_qos(QosKind::Specific(DataWriterQos {
+            deadline: DeadlineQosPolicy {
+                period: DurationKind::Finite(Duration::new(10, 0)),
+            },
+            ..data_writer_qos.clone()
+        }))
+        .unwrap();
+    let status = receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .unwrap();
+    assert_eq!(status.current_count, 0);
+    assert_eq!(status.current_count_change, -1);
+
+    writer.set_qos(QosKind::Specific(data_writer_qos)).unwrap();
+    let status = receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .unwrap();
+    assert_eq!(status.current_count, 1);
+    assert_eq!(status.total_count, 2);
+
+    // The writer was never lost so it did not restart
+    assert_eq!(reader.get_writer_restarted_status().unwrap().total_count, 0);
+}
+
 #[test]
 fn data_reader_requested_incompatible_qos_listener() {
     struct RequestedIncompatibleQosListener {
@@ -1840,6 +2070,98 @@ fn subscriber_subscription_matched_listener() {
     assert_eq!(status.total_count_change, 1);
 }
 
+#[test]
+fn subscriber_data_available_listener_called_for_reader_without_listener() {
+    struct DataAvailableListener {
+        sender: std::sync::mpsc::SyncSender<()>,
+    }
+
+    impl<R: DdsRuntime> SubscriberListener<R> for DataAvailableListener {
+        async fn on_data_available(&mut self, _the_reader: DataReaderAsync<R, ()>) {
+            self.sender.send(()).ok();
+        }
+    }
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let participant_factory = DomainParticipantFactory::get_instance();
+
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let (sender, receiver) = std::sync::mpsc::sync_channel(5);
+    let subscriber_listener = DataAvailableListener { sender };
+
+    let subscriber = participant
+        .create_subscriber(
+            QosKind::Default,
+            Some(subscriber_listener),
+            &[StatusKind::DataAvailable],
+        )
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    // The reader has the status in its mask but no listener so it must be
+    // handled by the subscriber listener
+    let _reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            &[StatusKind::DataAvailable],
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    writer.write(&MyData { id: 1, value: 1 }, None).unwrap();
+
+    assert!(receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .is_ok());
+}
+
 #[test]
 fn subscriber_requested_incompatible_qos_listener() {
     struct RequestedIncompatibleQosListener {
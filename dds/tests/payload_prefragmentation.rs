@@ -0,0 +1,146 @@
+use dust_dds::{
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+        },
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    rtps_udp_transport::udp_transport::RtpsUdpTransportParticipantFactoryBuilder,
+    wait_set::{Condition, WaitSet},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct LargeData {
+    #[dust_dds(key)]
+    id: u8,
+    value: Vec<u8>,
+}
+
+// The transport is global to the factory so this is the only test in this file
+#[test]
+fn prefragmented_large_data_should_be_received_by_all_readers() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let transport = Box::new(
+        RtpsUdpTransportParticipantFactoryBuilder::new()
+            .fragment_size(500)
+            .payload_prefragmentation(true)
+            .build()
+            .unwrap(),
+    );
+    domain_participant_factory.set_transport(transport).unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<LargeData>(
+            "LargeDataTopic",
+            "LargeData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader1 = subscriber
+        .create_datareader::<LargeData>(
+            &topic,
+            QosKind::Specific(reader_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let reader2 = subscriber
+        .create_datareader::<LargeData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 2
+    {
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    let data1 = LargeData {
+        id: 1,
+        value: vec![1; 5000],
+    };
+    let data2 = LargeData {
+        id: 2,
+        value: (0..=255).cycle().take(7001).collect(),
+    };
+    writer.write(&data1, None).unwrap();
+    writer.write(&data2, None).unwrap();
+
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    for reader in [reader1, reader2] {
+        let samples = reader
+            .take(3, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].data().unwrap(), data1);
+        assert_eq!(samples[1].data().unwrap(), data2);
+    }
+}
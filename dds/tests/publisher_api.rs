@@ -1,8 +1,12 @@
 use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataWriterQos, QosKind},
-        qos_policy::UserDataQosPolicy,
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, ResourceLimitsQosPolicy,
+            UserDataQosPolicy,
+        },
         status::NO_STATUS,
         type_support::DdsType,
     },
@@ -83,6 +87,51 @@ fn default_data_writer_qos() {
     assert_eq!(&writer.get_qos().unwrap().user_data.value, &user_data);
 }
 
+#[test]
+fn inconsistent_default_data_writer_qos_is_rejected_and_qos_default_restores_default() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let inconsistent_qos = DataWriterQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(10),
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples_per_instance: Length::Limited(2),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        publisher.set_default_datawriter_qos(QosKind::Specific(inconsistent_qos)),
+        Err(DdsError::InconsistentPolicy)
+    );
+
+    let qos = DataWriterQos {
+        user_data: UserDataQosPolicy {
+            value: vec![1, 2, 3],
+        },
+        ..Default::default()
+    };
+    publisher
+        .set_default_datawriter_qos(QosKind::Specific(qos))
+        .unwrap();
+    publisher
+        .set_default_datawriter_qos(QosKind::Default)
+        .unwrap();
+
+    assert_eq!(
+        publisher.get_default_datawriter_qos().unwrap(),
+        DataWriterQos::default()
+    );
+}
+
 #[test]
 fn different_writers_have_different_instance_handles() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -3,14 +3,17 @@ use dust_dds::{
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
         error::DdsError,
-        qos::{DataWriterQos, QosKind},
+        qos::{DataReaderQos, DataWriterQos, QosKind, SubscriberQos},
         qos_policy::{
-            DurabilityQosPolicy, DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind,
-            ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+            BatchQosPolicy, DisablePositiveAcksQosPolicy, DurabilityQosPolicy,
+            DurabilityQosPolicyKind, HistoryQosPolicy, HistoryQosPolicyKind, Length,
+            PresentationQosPolicy, PresentationQosPolicyAccessScopeKind, ReaderProtocolQosPolicy,
+            ReliabilityQosPolicy, ReliabilityQosPolicyKind, ResourceLimitsQosPolicy,
+            WriterProtocolQosPolicy,
         },
-        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        sample_info::{InstanceStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
         status::{StatusKind, NO_STATUS},
-        time::{Duration, DurationKind},
+        time::{Duration, DurationKind, Time},
         type_support::{DdsDeserialize, DdsType},
     },
     listener::NO_LISTENER,
@@ -19,11 +22,19 @@ use dust_dds::{
         overall_structure::{
             BufRead, RtpsMessageHeader, RtpsMessageRead, RtpsMessageWrite, RtpsSubmessageReadKind,
         },
-        submessage_elements::{Data, ParameterList, SequenceNumberSet},
-        submessages::{ack_nack::AckNackSubmessage, data::DataSubmessage},
+        submessage_elements::{
+            Data, FragmentNumberSet, LocatorList, Parameter, ParameterList, SequenceNumberSet,
+        },
+        submessages::{
+            ack_nack::AckNackSubmessage, data::DataSubmessage, heartbeat::HeartbeatSubmessage,
+            info_reply::InfoReplySubmessage, info_source::InfoSourceSubmessage,
+            info_timestamp::InfoTimestampSubmessage, nack_frag::NackFragSubmessage,
+        },
     },
+    subscription::data_reader::IncompleteCoherentSetPolicy,
     transport::types::{
-        EntityId, BUILT_IN_READER_WITH_KEY, BUILT_IN_WRITER_WITH_KEY, USER_DEFINED_READER_WITH_KEY,
+        EntityId, Locator, BUILT_IN_READER_WITH_KEY, BUILT_IN_WRITER_WITH_KEY, ENTITYID_UNKNOWN,
+        LOCATOR_KIND_UDP_V4, USER_DEFINED_READER_WITH_KEY, USER_DEFINED_WRITER_WITH_KEY,
     },
     wait_set::{Condition, WaitSet},
 };
@@ -56,6 +67,8 @@ pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER: EntityId =
 pub const ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR: EntityId =
     EntityId::new([0, 0, 0x04], BUILT_IN_READER_WITH_KEY);
 
+pub const PID_KEY_HASH: i16 = 0x0070;
+
 #[derive(Clone, Debug, PartialEq, DdsType)]
 struct KeyedData {
     #[dust_dds(key)]
@@ -63,6 +76,18 @@ struct KeyedData {
     value: u32,
 }
 
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct NoKeyData {
+    value: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct LargeKeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: Vec<u8>,
+}
+
 struct DynamicType<'a>(&'a [u8]);
 impl<'de> DdsDeserialize<'de> for DynamicType<'de> {
     fn deserialize_data(
@@ -497,7 +522,7 @@ fn writer_should_not_send_heartbeat_after_acknack() {
 }
 
 #[test]
-fn writer_should_resend_data_after_acknack_request() {
+fn writer_should_piggyback_heartbeats_according_to_samples_per_heartbeat() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
@@ -531,6 +556,19 @@ fn writer_should_resend_data_after_acknack_request() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        // The samples are held in a batch so that they are all sent to the reader at once
+        batch: BatchQosPolicy {
+            enable: true,
+            ..Default::default()
+        },
+        writer_protocol: WriterProtocolQosPolicy {
+            heartbeat_period: Duration::new(10, 0),
+            samples_per_heartbeat: Length::Limited(2),
+            ..Default::default()
+        },
         ..Default::default()
     };
     let writer = publisher
@@ -613,7 +651,11 @@ fn writer_should_resend_data_after_acknack_request() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -630,10 +672,6 @@ fn writer_should_resend_data_after_acknack_request() {
         .data()
         .unwrap()
         .metatraffic_unicast_locator_port();
-    let user_defined_traffic_port = dcps_sample_list[0]
-        .data()
-        .unwrap()
-        .default_unicast_locator_port();
     mock_reader_socket
         .send_to(
             discovered_reader_rtps_message.buffer(),
@@ -651,64 +689,36 @@ fn writer_should_resend_data_after_acknack_request() {
         .unwrap();
     waitset_writer.wait(Duration::new(10, 0)).unwrap();
 
-    // Send data with the writer
-    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    for value in 1..=5 {
+        writer.write(&KeyedData { id: 1, value }, None).unwrap();
+    }
+    writer.flush().unwrap();
 
+    // A heartbeat is sent with every second sample and with the last one
     let mut buffer = [0; 65535];
     mock_reader_socket.set_nonblocking(false).unwrap();
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
-    mock_reader_socket.recv(&mut buffer).unwrap();
-
-    let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    let received_heartbeat = rtps_message
-        .submessages()
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
-        .unwrap();
-
-    let writer_id = match received_heartbeat {
-        RtpsSubmessageReadKind::Heartbeat(h) => h.writer_id(),
-        _ => panic!("Wrong message type"),
-    };
-
-    // Everything is acknowledge up to the number prior to the base. By sending 1 this should
-    // result in the Data submessage with sample 1 being resent
-    let reader_sn_state = SequenceNumberSet::new(1, [1]);
-    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
-    let reader_acknack_submessage =
-        AckNackSubmessage::new(true, reader_id, writer_id, reader_sn_state, 1);
-
-    let acknack_message =
-        RtpsMessageWrite::new(&rtps_message_header, &[&reader_acknack_submessage]);
-    mock_reader_socket
-        .send_to(
-            acknack_message.buffer(),
-            ("127.0.0.1", user_defined_traffic_port as u16),
-        )
-        .unwrap();
-
-    // Default heartbeat period is 200ms
-    let mut buffer = [0; 65535];
-    mock_reader_socket
-        .set_read_timeout(Some(std::time::Duration::from_millis(300)))
-        .unwrap();
-    mock_reader_socket.recv(&mut buffer).unwrap();
-    let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    let submessages = received_data_heartbeat.submessages();
-    assert!(submessages
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
-        .is_some());
-    assert!(submessages
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
-        .is_some());
+    let mut piggybacked_heartbeats = Vec::new();
+    while piggybacked_heartbeats.len() < 5 {
+        let received_length = mock_reader_socket.recv(&mut buffer).unwrap();
+        let rtps_message = RtpsMessageRead::try_from(&buffer[..received_length]).unwrap();
+        for submessage in rtps_message.submessages() {
+            match submessage {
+                RtpsSubmessageReadKind::Data(_) => piggybacked_heartbeats.push(false),
+                RtpsSubmessageReadKind::Heartbeat(_) => {
+                    *piggybacked_heartbeats.last_mut().unwrap() = true
+                }
+                _ => (),
+            }
+        }
+    }
+    assert_eq!(piggybacked_heartbeats, vec![false, true, false, true, true]);
 }
 
 #[test]
-fn volatile_writer_should_send_gap_submessage_after_discovery() {
+fn writer_should_pack_the_data_sent_at_once_to_a_reader_in_a_single_datagram() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
@@ -742,6 +752,14 @@ fn volatile_writer_should_send_gap_submessage_after_discovery() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        // The samples are held in a batch so that they are all sent to the reader at once
+        batch: BatchQosPolicy {
+            enable: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
     let writer = publisher
@@ -814,26 +832,26 @@ fn volatile_writer_should_send_gap_submessage_after_discovery() {
         ParameterList::empty(),
         Data::new(serialized_dummy_reader_discovery_bytes.into()),
     );
-    let discovered_reader_rtps_message = RtpsMessageWrite::new(
-        &RtpsMessageHeader::new(
-            PROTOCOLVERSION,
-            VENDOR_ID_S2E,
-            guid_prefix.try_into().unwrap(),
-        ),
-        &[&discovered_reader_data_submessage],
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
     );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
     assert!(participant.get_discovered_participants().unwrap().len() == 1);
 
-    // Send data with the writer before discovery
-    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
-
     let dcps_participant_reader = builtin_subscriber
         .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
         .unwrap()
@@ -862,36 +880,36 @@ fn volatile_writer_should_send_gap_submessage_after_discovery() {
         .unwrap();
     waitset_writer.wait(Duration::new(10, 0)).unwrap();
 
+    for value in 1..=5 {
+        writer.write(&KeyedData { id: 1, value }, None).unwrap();
+    }
+    writer.flush().unwrap();
+
+    // The messages of the samples and the heartbeat piggybacked on the last one fit in one datagram
     let mut buffer = [0; 65535];
     mock_reader_socket.set_nonblocking(false).unwrap();
     mock_reader_socket
         .set_read_timeout(Some(std::time::Duration::from_secs(10)))
         .unwrap();
-    mock_reader_socket.recv(&mut buffer).unwrap();
-
-    let received_gap = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    assert!(received_gap
-        .submessages()
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Gap(_)))
-        .is_some());
-
-    // Default heartbeat period is 200ms
-    let mut buffer = [0; 65535];
-    mock_reader_socket
-        .set_read_timeout(Some(std::time::Duration::from_millis(300)))
-        .unwrap();
-    mock_reader_socket.recv(&mut buffer).unwrap();
-    let received_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    assert!(received_heartbeat
-        .submessages()
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
-        .is_some());
+    let received_length = mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(received_length <= 1472);
+    let rtps_message = RtpsMessageRead::try_from(&buffer[..received_length]).unwrap();
+    let submessages = rtps_message.submessages();
+    assert_eq!(
+        submessages
+            .iter()
+            .filter(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
+            .count(),
+        5
+    );
+    assert!(matches!(
+        submessages.last(),
+        Some(RtpsSubmessageReadKind::Heartbeat(_))
+    ));
 }
 
 #[test]
-fn transient_local_writer_should_send_data_submessage_after_discovery() {
+fn writer_should_resend_data_after_acknack_request() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
@@ -925,9 +943,6 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
             kind: ReliabilityQosPolicyKind::Reliable,
             max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
-        durability: DurabilityQosPolicy {
-            kind: DurabilityQosPolicyKind::TransientLocal,
-        },
         ..Default::default()
     };
     let writer = publisher
@@ -971,8 +986,6 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
             2, 0, 0, 0, // kind
             0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
             0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
-            0x1D, 0x00, 4, 0x00, // PID_DURABILITY, Length
-            1, 0, 0, 0, // kind (transient local)
             // ReaderProxy:
             0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
             0, 0, 0, 0, //
@@ -1002,14 +1015,13 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
         ParameterList::empty(),
         Data::new(serialized_dummy_reader_discovery_bytes.into()),
     );
-    let discovered_reader_rtps_message = RtpsMessageWrite::new(
-        &RtpsMessageHeader::new(
-            PROTOCOLVERSION,
-            VENDOR_ID_S2E,
-            guid_prefix.try_into().unwrap(),
-        ),
-        &[&discovered_reader_data_submessage],
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
     );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
@@ -1019,9 +1031,6 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
     }
     assert!(participant.get_discovered_participants().unwrap().len() == 1);
 
-    // Send data with the writer before discovery
-    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
-
     let dcps_participant_reader = builtin_subscriber
         .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
         .unwrap()
@@ -1033,6 +1042,10 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
         .data()
         .unwrap()
         .metatraffic_unicast_locator_port();
+    let user_defined_traffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .default_unicast_locator_port();
     mock_reader_socket
         .send_to(
             discovered_reader_rtps_message.buffer(),
@@ -1050,6 +1063,9 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
         .unwrap();
     waitset_writer.wait(Duration::new(10, 0)).unwrap();
 
+    // Send data with the writer
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
     let mut buffer = [0; 65535];
     mock_reader_socket.set_nonblocking(false).unwrap();
     mock_reader_socket
@@ -1057,16 +1073,33 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
         .unwrap();
     mock_reader_socket.recv(&mut buffer).unwrap();
 
-    let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    let submessages = received_data_heartbeat.submessages();
-    assert!(submessages
-        .iter()
-        .find(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
-        .is_some());
-    assert!(submessages
+    let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let received_heartbeat = rtps_message
+        .submessages()
         .iter()
         .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
-        .is_some());
+        .unwrap();
+
+    let writer_id = match received_heartbeat {
+        RtpsSubmessageReadKind::Heartbeat(h) => h.writer_id(),
+        _ => panic!("Wrong message type"),
+    };
+
+    // Everything is acknowledge up to the number prior to the base. By sending 1 this should
+    // result in the Data submessage with sample 1 being resent
+    let reader_sn_state = SequenceNumberSet::new(1, [1]);
+    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let reader_acknack_submessage =
+        AckNackSubmessage::new(true, reader_id, writer_id, reader_sn_state, 1);
+
+    let acknack_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&reader_acknack_submessage]);
+    mock_reader_socket
+        .send_to(
+            acknack_message.buffer(),
+            ("127.0.0.1", user_defined_traffic_port as u16),
+        )
+        .unwrap();
 
     // Default heartbeat period is 200ms
     let mut buffer = [0; 65535];
@@ -1074,8 +1107,12 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
         .set_read_timeout(Some(std::time::Duration::from_millis(300)))
         .unwrap();
     mock_reader_socket.recv(&mut buffer).unwrap();
-    let received_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
-    let submessages = received_heartbeat.submessages();
+    let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let submessages = received_data_heartbeat.submessages();
+    assert!(submessages
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
+        .is_some());
     assert!(submessages
         .iter()
         .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
@@ -1083,7 +1120,7 @@ fn transient_local_writer_should_send_data_submessage_after_discovery() {
 }
 
 #[test]
-fn reliable_writer_should_not_remove_unacked_sample_from_history() {
+fn writer_should_resend_only_the_fragments_requested_with_nack_frag() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
 
     let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
@@ -1100,7 +1137,7 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
     let topic_name = "MyTopic";
     let type_name = "KeyedData";
     let topic = participant
-        .create_topic::<KeyedData>(
+        .create_topic::<LargeKeyedData>(
             topic_name,
             type_name,
             QosKind::Default,
@@ -1115,10 +1152,7 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
     let writer_qos = DataWriterQos {
         reliability: ReliabilityQosPolicy {
             kind: ReliabilityQosPolicyKind::Reliable,
-            max_blocking_time: DurationKind::Finite(Duration::new(0, 100_000_000)),
-        },
-        history: HistoryQosPolicy {
-            kind: HistoryQosPolicyKind::KeepLast(1),
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
         },
         ..Default::default()
     };
@@ -1202,7 +1236,11 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
 
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < std::time::Duration::from_secs(10) {
-        if participant.get_discovered_participants().unwrap().len() >= 1 {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
             break;
         }
     }
@@ -1219,6 +1257,10 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
         .data()
         .unwrap()
         .metatraffic_unicast_locator_port();
+    let user_defined_traffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .default_unicast_locator_port();
     mock_reader_socket
         .send_to(
             discovered_reader_rtps_message.buffer(),
@@ -1236,11 +1278,3214 @@ fn reliable_writer_should_not_remove_unacked_sample_from_history() {
         .unwrap();
     waitset_writer.wait(Duration::new(10, 0)).unwrap();
 
-    // Send data with the writer
-    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
-    // Second send fails because sample is not acknowledged
-    assert_eq!(
-        writer.write(&KeyedData { id: 1, value: 2 }, None),
-        Err(DdsError::Timeout)
-    );
+    // Send data with the writer which is split in 3 fragments of the default fragment size
+    writer
+        .write(
+            &LargeKeyedData {
+                id: 1,
+                value: vec![7; 3000],
+            },
+            None,
+        )
+        .unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    let mut sent_fragments = Vec::new();
+    let mut heartbeat_frag = None;
+    while heartbeat_frag.is_none() {
+        let received_length = mock_reader_socket.recv(&mut buffer).unwrap();
+        let rtps_message = RtpsMessageRead::try_from(&buffer[..received_length]).unwrap();
+        for submessage in rtps_message.submessages() {
+            match submessage {
+                RtpsSubmessageReadKind::DataFrag(d) => {
+                    sent_fragments.push(d.fragment_starting_num())
+                }
+                RtpsSubmessageReadKind::HeartbeatFrag(h) => {
+                    heartbeat_frag = Some((h.writer_id(), h.writer_sn(), h.last_fragment_num()))
+                }
+                _ => (),
+            }
+        }
+    }
+    let (writer_id, writer_sn, last_fragment_num) = heartbeat_frag.unwrap();
+    assert_eq!(sent_fragments, vec![1, 2, 3]);
+    assert_eq!(writer_sn, 1);
+    assert_eq!(last_fragment_num, 3);
+
+    // Only the second fragment is requested by the reader so the other ones are not sent again
+    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let nack_frag_submessage =
+        NackFragSubmessage::new(reader_id, writer_id, 1, FragmentNumberSet::new(2, [2]), 1);
+    let nack_frag_message = RtpsMessageWrite::new(&rtps_message_header, &[&nack_frag_submessage]);
+    mock_reader_socket
+        .send_to(
+            nack_frag_message.buffer(),
+            ("127.0.0.1", user_defined_traffic_port as u16),
+        )
+        .unwrap();
+
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(100)))
+        .unwrap();
+    let mut resent_fragments = Vec::new();
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_millis(500) {
+        let Ok(received_length) = mock_reader_socket.recv(&mut buffer) else {
+            continue;
+        };
+        let rtps_message = RtpsMessageRead::try_from(&buffer[..received_length]).unwrap();
+        for submessage in rtps_message.submessages() {
+            if let RtpsSubmessageReadKind::DataFrag(d) = submessage {
+                resent_fragments.push(d.fragment_starting_num())
+            }
+        }
+    }
+    assert_eq!(resent_fragments, vec![2]);
+}
+
+#[test]
+fn writer_should_delay_and_suppress_repairs_according_to_writer_protocol_qos() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        writer_protocol: WriterProtocolQosPolicy {
+            heartbeat_period: Duration::new(10, 0),
+            nack_response_delay: Duration::new(0, 300_000_000),
+            nack_suppression_duration: Duration::new(0, 500_000_000),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    let user_defined_traffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .default_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    // Send data with the writer
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let received_heartbeat = rtps_message
+        .submessages()
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .unwrap();
+
+    let writer_id = match received_heartbeat {
+        RtpsSubmessageReadKind::Heartbeat(h) => h.writer_id(),
+        _ => panic!("Wrong message type"),
+    };
+
+    let data_sent_time = std::time::Instant::now();
+    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let send_acknack = |count| {
+        let reader_acknack_submessage = AckNackSubmessage::new(
+            true,
+            reader_id,
+            writer_id,
+            SequenceNumberSet::new(1, [1]),
+            count,
+        );
+        let acknack_message =
+            RtpsMessageWrite::new(&rtps_message_header, &[&reader_acknack_submessage]);
+        mock_reader_socket
+            .send_to(
+                acknack_message.buffer(),
+                ("127.0.0.1", user_defined_traffic_port as u16),
+            )
+            .unwrap();
+    };
+
+    // The request arrives within the nack suppression duration of the data so it is ignored
+    send_acknack(1);
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(400)))
+        .unwrap();
+    assert!(mock_reader_socket.recv(&mut buffer).is_err());
+
+    // Once the suppression duration is over the requested data is repaired after the nack response delay
+    while data_sent_time.elapsed() < std::time::Duration::from_millis(600) {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let acknack_time = std::time::Instant::now();
+    send_acknack(2);
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(2)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    assert!(acknack_time.elapsed() >= std::time::Duration::from_millis(300));
+    let repair_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    assert!(repair_message
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Data(_))));
+}
+
+#[test]
+fn volatile_writer_should_send_gap_submessage_after_discovery() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&discovered_reader_data_submessage],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if participant.get_discovered_participants().unwrap().len() >= 1 {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    // Send data with the writer before discovery
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let received_gap = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    assert!(received_gap
+        .submessages()
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Gap(_)))
+        .is_some());
+
+    // Default heartbeat period is 200ms
+    let mut buffer = [0; 65535];
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(300)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    let received_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    assert!(received_heartbeat
+        .submessages()
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .is_some());
+}
+
+#[test]
+fn volatile_writer_should_send_a_single_gap_submessage_for_the_changes_written_before_discovery() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&discovered_reader_data_submessage],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    // Send data of different instances with the writer before discovery
+    for id in 1..=5 {
+        writer.write(&KeyedData { id, value: 2 }, None).unwrap();
+    }
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let received_gap = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let gap_submessages: Vec<_> = received_gap
+        .submessages()
+        .iter()
+        .filter_map(|s| match s {
+            RtpsSubmessageReadKind::Gap(gap) => Some(gap),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(gap_submessages.len(), 1);
+    assert_eq!(gap_submessages[0].gap_start(), 1);
+    assert_eq!(gap_submessages[0].gap_list().base(), 6);
+    assert_eq!(gap_submessages[0].gap_list().set().count(), 0);
+}
+
+#[test]
+fn transient_local_writer_should_send_data_submessage_after_discovery() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        durability: DurabilityQosPolicy {
+            kind: DurabilityQosPolicyKind::TransientLocal,
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            0x1D, 0x00, 4, 0x00, // PID_DURABILITY, Length
+            1, 0, 0, 0, // kind (transient local)
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&discovered_reader_data_submessage],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if participant.get_discovered_participants().unwrap().len() >= 1 {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    // Send data with the writer before discovery
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let received_data_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let submessages = received_data_heartbeat.submessages();
+    assert!(submessages
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
+        .is_some());
+    assert!(submessages
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .is_some());
+
+    // Default heartbeat period is 200ms
+    let mut buffer = [0; 65535];
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(300)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    let received_heartbeat = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let submessages = received_heartbeat.submessages();
+    assert!(submessages
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .is_some());
+}
+
+#[test]
+fn reliable_writer_should_not_remove_unacked_sample_from_history() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100_000_000)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(1),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if participant.get_discovered_participants().unwrap().len() >= 1 {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    // Send data with the writer
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    // Second send fails because sample is not acknowledged
+    assert_eq!(
+        writer.write(&KeyedData { id: 1, value: 2 }, None),
+        Err(DdsError::Timeout)
+    );
+}
+
+#[test]
+fn incomplete_coherent_set_should_be_released_or_purged_after_timeout() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_writer_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let writer_socket_port = mock_writer_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber_qos = SubscriberQos {
+        presentation: PresentationQosPolicy {
+            access_scope: PresentationQosPolicyAccessScopeKind::Instance,
+            coherent_access: true,
+            ordered_access: false,
+        },
+        ..Default::default()
+    };
+    let subscriber = participant
+        .create_subscriber(QosKind::Specific(subscriber_qos), NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let release_reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    release_reader
+        .set_incomplete_coherent_set_timeout(
+            DurationKind::Finite(Duration::new(0, 500_000_000)),
+            IncompleteCoherentSetPolicy::Release,
+        )
+        .unwrap();
+    let purge_reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    purge_reader
+        .set_incomplete_coherent_set_timeout(
+            DurationKind::Finite(Duration::new(0, 500_000_000)),
+            IncompleteCoherentSetPolicy::Purge,
+        )
+        .unwrap();
+
+    // Add discovered dummy writer
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (writer_socket_port as u32).to_le_bytes();
+    let writer_id = EntityId::new([0, 0, 0x10], USER_DEFINED_WRITER_WITH_KEY);
+
+    let serialized_dummy_writer_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // PublicationBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0x10, 0x02, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x21, 0x00, 4, 0x00, // PID_PRESENTATION, Length
+            0, 1, 0, 0, // access_scope (instance), coherent_access, ordered_access
+            // WriterProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_writer_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_writer_discovery_bytes.into()),
+    );
+    let discovered_writer_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&discovered_writer_data_submessage],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let participant_data = dcps_sample_list[0].data().unwrap();
+    let metatraffic_port = participant_data.metatraffic_unicast_locator_port();
+    let default_port = participant_data.default_unicast_locator_port();
+    mock_writer_socket
+        .send_to(
+            discovered_writer_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    for reader in [&release_reader, &purge_reader] {
+        let mut waitset_reader = WaitSet::new();
+        let reader_status_condition = reader.get_statuscondition();
+        reader_status_condition
+            .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+            .unwrap();
+        waitset_reader
+            .attach_condition(Condition::StatusCondition(reader_status_condition))
+            .unwrap();
+        waitset_reader.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    // Send two samples of a coherent set starting at sequence number 1 which is never completed
+    let coherent_set_inline_qos = ParameterList::new(vec![Parameter::new(
+        0x0056, // PID_COHERENT_SET
+        vec![0, 0, 0, 0, 1, 0, 0, 0].into(),
+    )]);
+    let data_submessage_list: Vec<DataSubmessage> = (1..=2)
+        .map(|sequence_number| {
+            DataSubmessage::new(
+                true,
+                true,
+                false,
+                false,
+                ENTITYID_UNKNOWN,
+                writer_id,
+                sequence_number,
+                coherent_set_inline_qos.clone(),
+                Data::new(vec![0, 1, 0, 0, sequence_number as u8, 0, 0, 0, 1, 0, 0, 0].into()),
+            )
+        })
+        .collect();
+    let data_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&data_submessage_list[0], &data_submessage_list[1]],
+    );
+    mock_writer_socket
+        .send_to(
+            data_rtps_message.buffer(),
+            ("127.0.0.1", default_port as u16),
+        )
+        .unwrap();
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    assert_eq!(
+        release_reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
+
+    let mut waitset_release_reader = WaitSet::new();
+    let release_reader_status_condition = release_reader.get_statuscondition();
+    release_reader_status_condition
+        .set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+    waitset_release_reader
+        .attach_condition(Condition::StatusCondition(release_reader_status_condition))
+        .unwrap();
+    waitset_release_reader.wait(Duration::new(10, 0)).unwrap();
+    let released_samples = release_reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(released_samples.len(), 2);
+
+    let mut waitset_purge_reader = WaitSet::new();
+    let purge_reader_status_condition = purge_reader.get_statuscondition();
+    purge_reader_status_condition
+        .set_enabled_statuses(&[StatusKind::SampleLost])
+        .unwrap();
+    waitset_purge_reader
+        .attach_condition(Condition::StatusCondition(purge_reader_status_condition))
+        .unwrap();
+    waitset_purge_reader.wait(Duration::new(10, 0)).unwrap();
+    let sample_lost_status = purge_reader.get_sample_lost_status().unwrap();
+    assert_eq!(sample_lost_status.total_count, 2);
+    assert_eq!(
+        purge_reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .err(),
+        Some(DdsError::NoData)
+    );
+}
+
+#[test]
+fn reader_should_delay_and_suppress_heartbeat_responses_according_to_reader_protocol_qos() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_writer_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let writer_socket_port = mock_writer_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        reader_protocol: ReaderProtocolQosPolicy {
+            heartbeat_response_delay: Duration::new(0, 300_000_000),
+            heartbeat_suppression_duration: Duration::new(1, 0),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy writer
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (writer_socket_port as u32).to_le_bytes();
+    let writer_id = EntityId::new([0, 0, 0x10], USER_DEFINED_WRITER_WITH_KEY);
+
+    let serialized_dummy_writer_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // PublicationBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0x10, 0x02, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            // WriterProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_writer_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_writer_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_writer_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_writer_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let participant_data = dcps_sample_list[0].data().unwrap();
+    let metatraffic_port = participant_data.metatraffic_unicast_locator_port();
+    let default_port = participant_data.default_unicast_locator_port();
+    mock_writer_socket
+        .send_to(
+            discovered_writer_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_reader = WaitSet::new();
+    let reader_status_condition = reader.get_statuscondition();
+    reader_status_condition
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    waitset_reader
+        .attach_condition(Condition::StatusCondition(reader_status_condition))
+        .unwrap();
+    waitset_reader.wait(Duration::new(10, 0)).unwrap();
+
+    // The heartbeats announce a change which the reader has not received so they must be answered
+    let send_heartbeat = |count| {
+        let heartbeat_submessage =
+            HeartbeatSubmessage::new(false, false, ENTITYID_UNKNOWN, writer_id, 1, 1, count);
+        let heartbeat_message =
+            RtpsMessageWrite::new(&rtps_message_header, &[&heartbeat_submessage]);
+        mock_writer_socket
+            .send_to(
+                heartbeat_message.buffer(),
+                ("127.0.0.1", default_port as u16),
+            )
+            .unwrap();
+        std::time::Instant::now()
+    };
+    let mut buffer = [0; 65535];
+    let mut receive_acknack = |timeout| {
+        mock_writer_socket.set_read_timeout(Some(timeout)).unwrap();
+        mock_writer_socket.recv(&mut buffer).ok()?;
+        let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+        let is_acknack = rtps_message
+            .submessages()
+            .iter()
+            .any(|s| matches!(s, RtpsSubmessageReadKind::AckNack(_)));
+        Some(is_acknack)
+    };
+
+    // The first heartbeat is answered once the response delay has elapsed
+    let first_heartbeat_time = send_heartbeat(1);
+    assert_eq!(
+        receive_acknack(std::time::Duration::from_secs(2)),
+        Some(true)
+    );
+    assert!(first_heartbeat_time.elapsed() >= std::time::Duration::from_millis(300));
+
+    // A heartbeat arriving within the suppression duration of the answered one is not answered
+    send_heartbeat(2);
+    assert_eq!(receive_acknack(std::time::Duration::from_millis(500)), None);
+
+    // Once the suppression duration is over the heartbeats are answered again
+    while first_heartbeat_time.elapsed() < std::time::Duration::from_millis(1100) {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    send_heartbeat(3);
+    assert_eq!(
+        receive_acknack(std::time::Duration::from_secs(2)),
+        Some(true)
+    );
+}
+
+#[test]
+fn reliable_keep_all_writer_should_time_out_when_resource_limits_are_reached_with_unacked_samples()
+{
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(0, 100_000_000)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples: Length::Unlimited,
+            max_instances: Length::Unlimited,
+            max_samples_per_instance: Length::Limited(1),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    // Send data with the writer
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+    // Second send fails because the resource limits are reached and the sample is not acknowledged
+    assert_eq!(
+        writer.write(&KeyedData { id: 1, value: 2 }, None),
+        Err(DdsError::Timeout)
+    );
+}
+
+#[test]
+fn reader_should_attribute_relayed_data_to_the_writer_of_info_source() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_relay_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let relay_socket_port = mock_relay_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Add discovered dummy writer which is only reachable through the relay
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let relay_guid_prefix = [0xAA; 12];
+    let port = (relay_socket_port as u32).to_le_bytes();
+    let writer_id = EntityId::new([0, 0, 0x10], USER_DEFINED_WRITER_WITH_KEY);
+
+    let serialized_dummy_writer_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // PublicationBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0x10, 0x02, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            // WriterProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_writer_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_writer_discovery_bytes.into()),
+    );
+    let discovered_writer_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[&discovered_writer_data_submessage],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let participant_data = dcps_sample_list[0].data().unwrap();
+    let metatraffic_port = participant_data.metatraffic_unicast_locator_port();
+    let default_port = participant_data.default_unicast_locator_port();
+    mock_relay_socket
+        .send_to(
+            discovered_writer_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_reader = WaitSet::new();
+    let reader_status_condition = reader.get_statuscondition();
+    reader_status_condition
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    waitset_reader
+        .attach_condition(Condition::StatusCondition(reader_status_condition))
+        .unwrap();
+    waitset_reader.wait(Duration::new(10, 0)).unwrap();
+
+    let data_submessage_list: Vec<DataSubmessage> = (1..=3)
+        .map(|sequence_number| {
+            DataSubmessage::new(
+                false,
+                true,
+                false,
+                false,
+                ENTITYID_UNKNOWN,
+                writer_id,
+                sequence_number,
+                ParameterList::empty(),
+                Data::new(
+                    vec![
+                        0,
+                        1,
+                        0,
+                        0,
+                        sequence_number as u8,
+                        0,
+                        0,
+                        0,
+                        sequence_number as u8,
+                        0,
+                        0,
+                        0,
+                    ]
+                    .into(),
+                ),
+            )
+        })
+        .collect();
+    let relay_message_header =
+        RtpsMessageHeader::new(PROTOCOLVERSION, VENDOR_ID_S2E, relay_guid_prefix);
+    let info_source_submessage = InfoSourceSubmessage::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    // The timestamp set by the relay does not apply to the submessages of the original writer
+    let relay_info_timestamp_submessage =
+        InfoTimestampSubmessage::new(false, dust_dds::rtps_messages::types::Time::new(100, 0));
+    let writer_info_timestamp_submessage =
+        InfoTimestampSubmessage::new(false, dust_dds::rtps_messages::types::Time::new(200, 0));
+    let info_reply_submessage = InfoReplySubmessage::new(
+        false,
+        LocatorList::new(vec![Locator::new(
+            LOCATOR_KIND_UDP_V4,
+            relay_socket_port as u32,
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1],
+        )]),
+        LocatorList::new(vec![]),
+    );
+    let relayed_rtps_message_list = [
+        RtpsMessageWrite::new(
+            &relay_message_header,
+            &[
+                &relay_info_timestamp_submessage,
+                &info_source_submessage,
+                &data_submessage_list[0],
+            ],
+        ),
+        // Without INFO_SRC the data is attributed to the relay which is not a matched writer
+        RtpsMessageWrite::new(&relay_message_header, &[&data_submessage_list[1]]),
+        RtpsMessageWrite::new(
+            &relay_message_header,
+            &[
+                &info_reply_submessage,
+                &info_source_submessage,
+                &writer_info_timestamp_submessage,
+                &data_submessage_list[2],
+            ],
+        ),
+    ];
+    for relayed_rtps_message in &relayed_rtps_message_list {
+        mock_relay_socket
+            .send_to(
+                relayed_rtps_message.buffer(),
+                ("127.0.0.1", default_port as u16),
+            )
+            .unwrap();
+    }
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .is_ok_and(|samples| samples.len() >= 2)
+        {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let samples = reader
+        .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(
+        samples
+            .iter()
+            .map(|s| s.data().unwrap().value)
+            .collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+    assert_ne!(
+        samples[0].sample_info().source_timestamp,
+        Some(Time::new(100, 0))
+    );
+    assert_eq!(
+        samples[1].sample_info().source_timestamp,
+        Some(Time::new(200, 0))
+    );
+}
+
+#[test]
+fn writer_without_positive_acks_should_consider_data_acknowledged_after_keep_duration() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        disable_positive_acks: DisablePositiveAcksQosPolicy {
+            enable: true,
+            duration: Duration::new(0, 500_000_000),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    let user_defined_traffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .default_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    // Send data with the writer
+    let write_time = std::time::Instant::now();
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let received_heartbeat = rtps_message
+        .submessages()
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .unwrap();
+
+    // The reader is not asked to acknowledge the data it received
+    let writer_id = match received_heartbeat {
+        RtpsSubmessageReadKind::Heartbeat(h) => {
+            assert!(h.final_flag());
+            h.writer_id()
+        }
+        _ => panic!("Wrong message type"),
+    };
+
+    // The data can still be requested by the reader within the keep duration
+    let reader_sn_state = SequenceNumberSet::new(1, [1]);
+    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let reader_acknack_submessage =
+        AckNackSubmessage::new(true, reader_id, writer_id, reader_sn_state, 1);
+
+    let acknack_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&reader_acknack_submessage]);
+    mock_reader_socket
+        .send_to(
+            acknack_message.buffer(),
+            ("127.0.0.1", user_defined_traffic_port as u16),
+        )
+        .unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(300)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+    let received_data = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    assert!(received_data
+        .submessages()
+        .iter()
+        .any(|s| matches!(s, RtpsSubmessageReadKind::Data(_))));
+
+    // Without any positive acknowledgment of the reader the data is acknowledged once the keep duration elapses
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    assert!(write_time.elapsed() >= std::time::Duration::from_millis(500));
+}
+
+#[test]
+fn writer_should_send_the_backlog_of_its_readers_in_turns() {
+    use dust_dds::publication::change_lifecycle::{
+        ChangeLifecycleEvent, ChangeLifecycleEventKind, ChangeLifecycleObserver,
+    };
+
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<LargeKeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    // The samples are held in a batch so that the backlog of both readers is sent at once
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        batch: BatchQosPolicy {
+            enable: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add a reliable and a best-effort dummy reader on the same socket
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+    let reliable_reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let best_effort_reader_id = EntityId::new([0, 0, 1], USER_DEFINED_READER_WITH_KEY);
+
+    let serialized_dummy_reader_discovery_bytes = |entity_key: u8, reliability_kind: u8| {
+        [
+            &[
+                0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+                // SubscriptionBuiltinTopicData:
+                0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+            ],
+            guid_prefix,
+            &[0, 0, entity_key, 7], // Entity ID
+            &[
+                0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+            ],
+            participant_key,
+            &[
+                0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+                8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                b'M', b'y', b'T', b'o', //
+                b'p', b'i', b'c', 0, //
+                0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+                10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                b'K', b'e', b'y', b'e', //
+                b'd', b'D', b'a', b't', //
+                b'a', 0, 0, 0, //
+                0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            ],
+            &[reliability_kind, 0, 0, 0], // kind
+            &[
+                0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+                0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+                // ReaderProxy:
+                0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+                0, 0, 0, 0, //
+                0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+                1, 0, 0, 0, // locator kind
+            ],
+            &port, //locator port
+            &[
+                0, 0, 0, 0, // locator address
+                0, 0, 0, 0, // locator address
+                0, 0, 0, 0, // locator address
+                127, 0, 0, 1, // locator address
+                0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+            ],
+        ]
+        .concat()
+        .to_vec()
+    };
+
+    let discovered_reliable_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes(0, 2).into()),
+    );
+    let discovered_best_effort_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        2,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes(1, 1).into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &rtps_message_header,
+        &[
+            &discovered_reliable_reader_data_submessage,
+            &discovered_best_effort_reader_data_submessage,
+        ],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 2
+    {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    // The order in which the changes are sent is recorded by the writer itself since the datagrams
+    // sent at once may not all fit in the buffer of the mock reader socket
+    struct SentChangeRecorder(std::sync::Mutex<Vec<EntityId>>);
+    impl ChangeLifecycleObserver for SentChangeRecorder {
+        fn on_change_event(&self, event: &ChangeLifecycleEvent) {
+            if let (ChangeLifecycleEventKind::Sent, Some(reader_guid)) =
+                (&event.kind, event.reader_guid)
+            {
+                self.0.lock().unwrap().push(reader_guid.entity_id());
+            }
+        }
+    }
+    let recorder = std::sync::Arc::new(SentChangeRecorder(std::sync::Mutex::new(Vec::new())));
+    writer
+        .set_change_lifecycle_observer(Some(recorder.clone()))
+        .unwrap();
+
+    // Each reader has a backlog of about 100 kB
+    for id in 0..100 {
+        writer
+            .write(
+                &LargeKeyedData {
+                    id,
+                    value: vec![id; 1000],
+                },
+                None,
+            )
+            .unwrap();
+    }
+    writer.flush().unwrap();
+
+    let start_time = std::time::Instant::now();
+    while recorder.0.lock().unwrap().len() < 200 {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let data_reader_ids = recorder.0.lock().unwrap().clone();
+    let last_reliable_reader_data = data_reader_ids
+        .iter()
+        .rposition(|&id| id == reliable_reader_id)
+        .unwrap();
+    let first_best_effort_reader_data = data_reader_ids
+        .iter()
+        .position(|&id| id == best_effort_reader_id)
+        .unwrap();
+    assert!(first_best_effort_reader_data < last_reliable_reader_data);
+}
+
+#[test]
+fn writer_should_back_off_the_repairs_of_a_change_requested_repeatedly() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+    println!("Socket open on port {}", reader_socket_port);
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        writer_protocol: WriterProtocolQosPolicy {
+            heartbeat_period: Duration::new(10, 0),
+            min_resend_interval: Duration::new(0, 300_000_000),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy reader
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // SubscriptionBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0, 7, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            2, 0, 0, 0, // kind
+            0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+            0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+            // ReaderProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_reader_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_reader_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_reader_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    let user_defined_traffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .default_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_writer = WaitSet::new();
+    let writer_status_condition = writer.get_statuscondition();
+    writer_status_condition
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    waitset_writer
+        .attach_condition(Condition::StatusCondition(writer_status_condition))
+        .unwrap();
+    waitset_writer.wait(Duration::new(10, 0)).unwrap();
+
+    // Send data with the writer
+    writer.write(&KeyedData { id: 1, value: 2 }, None).unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    mock_reader_socket.recv(&mut buffer).unwrap();
+
+    let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+    let received_heartbeat = rtps_message
+        .submessages()
+        .iter()
+        .find(|s| matches!(s, RtpsSubmessageReadKind::Heartbeat(_)))
+        .unwrap();
+
+    let writer_id = match received_heartbeat {
+        RtpsSubmessageReadKind::Heartbeat(h) => h.writer_id(),
+        _ => panic!("Wrong message type"),
+    };
+
+    let reader_id = EntityId::new([0, 0, 0], USER_DEFINED_READER_WITH_KEY);
+    let send_acknack = |count| {
+        let reader_acknack_submessage = AckNackSubmessage::new(
+            true,
+            reader_id,
+            writer_id,
+            SequenceNumberSet::new(1, [1]),
+            count,
+        );
+        let acknack_message =
+            RtpsMessageWrite::new(&rtps_message_header, &[&reader_acknack_submessage]);
+        mock_reader_socket
+            .send_to(
+                acknack_message.buffer(),
+                ("127.0.0.1", user_defined_traffic_port as u16),
+            )
+            .unwrap();
+    };
+    let mut receive_repair = |timeout| {
+        mock_reader_socket.set_read_timeout(Some(timeout)).unwrap();
+        mock_reader_socket.recv(&mut buffer).is_ok_and(|_| {
+            RtpsMessageRead::try_from(buffer.as_slice())
+                .unwrap()
+                .submessages()
+                .iter()
+                .any(|s| matches!(s, RtpsSubmessageReadKind::Data(_)))
+        })
+    };
+
+    // The first request is repaired right away but its duplicate is ignored within the min resend interval
+    send_acknack(1);
+    assert!(receive_repair(std::time::Duration::from_secs(2)));
+    let first_repair_time = std::time::Instant::now();
+    send_acknack(2);
+    assert!(!receive_repair(std::time::Duration::from_millis(200)));
+
+    // Once the interval is over the change is repaired again and the interval is doubled
+    while first_repair_time.elapsed() < std::time::Duration::from_millis(400) {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    send_acknack(3);
+    assert!(receive_repair(std::time::Duration::from_secs(2)));
+    let second_repair_time = std::time::Instant::now();
+    while second_repair_time.elapsed() < std::time::Duration::from_millis(400) {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    send_acknack(4);
+    assert!(!receive_repair(std::time::Duration::from_millis(100)));
+    while second_repair_time.elapsed() < std::time::Duration::from_millis(700) {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    send_acknack(5);
+    assert!(receive_repair(std::time::Duration::from_secs(2)));
+}
+
+#[test]
+fn writer_should_send_the_key_hash_only_for_keyed_topics() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let keyed_topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let no_key_topic = participant
+        .create_topic::<NoKeyData>(
+            "MyOther",
+            "NoKeyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let keyed_writer = publisher
+        .create_datawriter(&keyed_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let no_key_writer = publisher
+        .create_datawriter(&no_key_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Add a discovered dummy reader for each of the types
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+
+    let serialized_dummy_reader_discovery_bytes =
+        |entity_kind: u8, topic_name: &[u8; 7], type_name: &[u8; 9]| {
+            [
+                &[
+                    0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+                    // SubscriptionBuiltinTopicData:
+                    0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+                ],
+                guid_prefix,
+                &[
+                    0,
+                    0,
+                    0,
+                    entity_kind, // Entity ID
+                    0x50,
+                    0x00,
+                    16,
+                    0, // PID_PARTICIPANT_GUID, length
+                ],
+                participant_key,
+                &[
+                    0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+                    8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                ],
+                topic_name,
+                &[
+                    0, // terminator
+                    0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+                    10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                ],
+                type_name,
+                &[
+                    0, 0, 0, // terminator and padding
+                    // ReaderProxy:
+                    0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+                    0, 0, 0, 0, //
+                    0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+                    1, 0, 0, 0, // locator kind
+                ],
+                &port, //locator port
+                &[
+                    0, 0, 0, 0, // locator address
+                    0, 0, 0, 0, // locator address
+                    0, 0, 0, 0, // locator address
+                    127, 0, 0, 1, // locator address
+                    0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+                ],
+            ]
+            .concat()
+        };
+
+    let discovered_reader_data_submessage_list = [
+        DataSubmessage::new(
+            false,
+            true,
+            false,
+            false,
+            ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+            ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+            1,
+            ParameterList::empty(),
+            Data::new(serialized_dummy_reader_discovery_bytes(7, b"MyTopic", b"KeyedData").into()),
+        ),
+        DataSubmessage::new(
+            false,
+            true,
+            false,
+            false,
+            ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+            ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+            2,
+            ParameterList::empty(),
+            Data::new(serialized_dummy_reader_discovery_bytes(4, b"MyOther", b"NoKeyData").into()),
+        ),
+    ];
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &RtpsMessageHeader::new(
+            PROTOCOLVERSION,
+            VENDOR_ID_S2E,
+            guid_prefix.try_into().unwrap(),
+        ),
+        &[
+            &discovered_reader_data_submessage_list[0],
+            &discovered_reader_data_submessage_list[1],
+        ],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut buffer = [0; 65535];
+    mock_reader_socket.set_nonblocking(false).unwrap();
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    // Returns the key hash sent in the inline QoS of the next data submessage received
+    let mut receive_data_key_hash = || loop {
+        mock_reader_socket.recv(&mut buffer).unwrap();
+        let rtps_message = RtpsMessageRead::try_from(buffer.as_slice()).unwrap();
+        if let Some(key_hash) = rtps_message.submessages().iter().find_map(|s| match s {
+            RtpsSubmessageReadKind::Data(d) => Some(
+                d.inline_qos()
+                    .parameter()
+                    .iter()
+                    .find(|p| p.parameter_id() == PID_KEY_HASH)
+                    .map(|p| p.value().to_vec()),
+            ),
+            _ => None,
+        }) {
+            break key_hash;
+        }
+    };
+
+    for writer_status_condition in [
+        keyed_writer.get_statuscondition(),
+        no_key_writer.get_statuscondition(),
+    ] {
+        let mut waitset_writer = WaitSet::new();
+        writer_status_condition
+            .set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        waitset_writer
+            .attach_condition(Condition::StatusCondition(writer_status_condition))
+            .unwrap();
+        waitset_writer.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    // The key of KeyedData fits in the 16 bytes of the key hash so it is sent without MD5
+    keyed_writer
+        .write(&KeyedData { id: 1, value: 2 }, None)
+        .unwrap();
+    assert_eq!(
+        receive_data_key_hash(),
+        Some(vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+    );
+
+    no_key_writer.write(&NoKeyData { value: 2 }, None).unwrap();
+    assert_eq!(receive_data_key_hash(), None);
+}
+
+#[test]
+fn reader_should_dispose_the_instance_of_the_key_hash_of_a_data_without_payload() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_writer_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let writer_socket_port = mock_writer_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic_name = "MyTopic";
+    let type_name = "KeyedData";
+    let topic = participant
+        .create_topic::<KeyedData>(
+            topic_name,
+            type_name,
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // Add discovered dummy writer
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (writer_socket_port as u32).to_le_bytes();
+    let writer_id = EntityId::new([0, 0, 0x10], USER_DEFINED_WRITER_WITH_KEY);
+
+    let serialized_dummy_writer_discovery_bytes = [
+        &[
+            0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+            // PublicationBuiltinTopicData:
+            0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+        ],
+        guid_prefix,
+        &[
+            0, 0, 0x10, 0x02, // Entity ID
+            0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+        ],
+        participant_key,
+        &[
+            0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+            8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'M', b'y', b'T', b'o', //
+            b'p', b'i', b'c', 0, //
+            0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+            10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+            b'K', b'e', b'y', b'e', //
+            b'd', b'D', b'a', b't', //
+            b'a', 0, 0, 0, //
+            // WriterProxy:
+            0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+            0, 0, 0, 0, //
+            0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+            1, 0, 0, 0, // locator kind
+        ],
+        &port, //locator port
+        &[
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            0, 0, 0, 0, // locator address
+            127, 0, 0, 1, // locator address
+            0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+        ],
+    ]
+    .concat()
+    .to_vec();
+
+    let discovered_writer_data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_DETECTOR,
+        ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER,
+        1,
+        ParameterList::empty(),
+        Data::new(serialized_dummy_writer_discovery_bytes.into()),
+    );
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_writer_rtps_message =
+        RtpsMessageWrite::new(&rtps_message_header, &[&discovered_writer_data_submessage]);
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let participant_data = dcps_sample_list[0].data().unwrap();
+    let metatraffic_port = participant_data.metatraffic_unicast_locator_port();
+    let default_port = participant_data.default_unicast_locator_port();
+    mock_writer_socket
+        .send_to(
+            discovered_writer_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let mut waitset_reader = WaitSet::new();
+    let reader_status_condition = reader.get_statuscondition();
+    reader_status_condition
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    waitset_reader
+        .attach_condition(Condition::StatusCondition(reader_status_condition))
+        .unwrap();
+    waitset_reader.wait(Duration::new(10, 0)).unwrap();
+
+    // The dispose only carries the key hash of the instance and no serialized key
+    let data_submessage = DataSubmessage::new(
+        false,
+        true,
+        false,
+        false,
+        ENTITYID_UNKNOWN,
+        writer_id,
+        1,
+        ParameterList::empty(),
+        Data::new(vec![0, 1, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0].into()),
+    );
+    let dispose_submessage = DataSubmessage::new(
+        true,
+        false,
+        false,
+        false,
+        ENTITYID_UNKNOWN,
+        writer_id,
+        2,
+        ParameterList::new(vec![
+            Parameter::new(
+                PID_KEY_HASH,
+                vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0].into(),
+            ),
+            Parameter::new(
+                0x0071, // PID_STATUS_INFO
+                vec![0, 0, 0, 1].into(),
+            ),
+        ]),
+        Data::new(vec![].into()),
+    );
+    let data_rtps_message = RtpsMessageWrite::new(
+        &rtps_message_header,
+        &[&data_submessage, &dispose_submessage],
+    );
+    mock_writer_socket
+        .send_to(
+            data_rtps_message.buffer(),
+            ("127.0.0.1", default_port as u16),
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    let mut samples = Vec::new();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        samples = reader
+            .read(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+            .unwrap_or_default();
+        if samples.len() == 2 {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 2 });
+    assert_eq!(
+        samples[1].sample_info().instance_state,
+        InstanceStateKind::NotAliveDisposed
+    );
+    assert!(!samples[1].sample_info().valid_data);
+}
+
+#[test]
+fn writer_should_not_send_duplicates_to_a_reader_outside_the_group_of_its_participant() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let mock_reader_socket = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+
+    let reader_socket_port = mock_reader_socket.local_addr().unwrap().port();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let builtin_subscriber = participant.get_builtin_subscriber();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Add two best-effort dummy readers which share their message stream and a reliable one
+    // which doesn't, all on the same socket
+    let instance_handle = participant.get_instance_handle();
+    let participant_key = instance_handle.as_ref().as_slice();
+    let guid_prefix = &participant_key[..12];
+    let port = (reader_socket_port as u32).to_le_bytes();
+    let reliable_reader_id = EntityId::new([0, 0, 2], USER_DEFINED_READER_WITH_KEY);
+
+    let serialized_dummy_reader_discovery_bytes = |entity_key: u8, reliability_kind: u8| {
+        [
+            &[
+                0x00, 0x03, 0x00, 0x00, // PL_CDR_LE
+                // SubscriptionBuiltinTopicData:
+                0x5a, 0x00, 16, 0, //PID_ENDPOINT_GUID, length
+            ],
+            guid_prefix,
+            &[0, 0, entity_key, 7], // Entity ID
+            &[
+                0x50, 0x00, 16, 0, // PID_PARTICIPANT_GUID, length
+            ],
+            participant_key,
+            &[
+                0x05, 0x00, 12, 0x00, // PID_TOPIC_NAME, Length
+                8, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                b'M', b'y', b'T', b'o', //
+                b'p', b'i', b'c', 0, //
+                0x07, 0x00, 16, 0x00, // PID_TYPE_NAME, Length
+                10, 0x00, 0x00, 0x00, // string length (incl. terminator)
+                b'K', b'e', b'y', b'e', //
+                b'd', b'D', b'a', b't', //
+                b'a', 0, 0, 0, //
+                0x1A, 0x00, 12, 0x00, // PID_RELIABILITY, Length
+            ],
+            &[reliability_kind, 0, 0, 0], // kind
+            &[
+                0xff, 0xff, 0xff, 0x7f, // max_blocking_time: sec
+                0xff, 0xff, 0xff, 0xff, // max_blocking_time: nanosec
+                // ReaderProxy:
+                0x53, 0x00, 4, 0, //PID_GROUP_ENTITYID
+                0, 0, 0, 0, //
+                0x2F, 0x00, 24, 0, // PID_UNICAST_LOCATOR, Length
+                1, 0, 0, 0, // locator kind
+            ],
+            &port, //locator port
+            &[
+                0, 0, 0, 0, // locator address
+                0, 0, 0, 0, // locator address
+                0, 0, 0, 0, // locator address
+                127, 0, 0, 1, // locator address
+                0x01, 0x00, 0x00, 0x00, // PID_SENTINEL, length
+            ],
+        ]
+        .concat()
+        .to_vec()
+    };
+
+    let discovered_reader_data_submessage_list: Vec<_> = [(0, 1), (1, 1), (2, 2)]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (entity_key, reliability_kind))| {
+            DataSubmessage::new(
+                false,
+                true,
+                false,
+                false,
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_DETECTOR,
+                ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER,
+                index as i64 + 1,
+                ParameterList::empty(),
+                Data::new(
+                    serialized_dummy_reader_discovery_bytes(entity_key, reliability_kind).into(),
+                ),
+            )
+        })
+        .collect();
+    let rtps_message_header = RtpsMessageHeader::new(
+        PROTOCOLVERSION,
+        VENDOR_ID_S2E,
+        guid_prefix.try_into().unwrap(),
+    );
+    let discovered_reader_rtps_message = RtpsMessageWrite::new(
+        &rtps_message_header,
+        &[
+            &discovered_reader_data_submessage_list[0],
+            &discovered_reader_data_submessage_list[1],
+            &discovered_reader_data_submessage_list[2],
+        ],
+    );
+
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(10) {
+        if !participant
+            .get_discovered_participants()
+            .unwrap()
+            .is_empty()
+        {
+            break;
+        }
+    }
+    assert!(participant.get_discovered_participants().unwrap().len() == 1);
+
+    let dcps_participant_reader = builtin_subscriber
+        .lookup_datareader::<DynamicType>(DCPS_PARTICIPANT)
+        .unwrap()
+        .unwrap();
+    let dcps_sample_list = dcps_participant_reader
+        .read(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    let metatraffic_port = dcps_sample_list[0]
+        .data()
+        .unwrap()
+        .metatraffic_unicast_locator_port();
+    mock_reader_socket
+        .send_to(
+            discovered_reader_rtps_message.buffer(),
+            ("127.0.0.1", metatraffic_port as u16),
+        )
+        .unwrap();
+
+    let start_time = std::time::Instant::now();
+    while writer
+        .get_publication_matched_status()
+        .unwrap()
+        .current_count
+        < 3
+    {
+        assert!(start_time.elapsed() < std::time::Duration::from_secs(10));
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    for id in 0..3 {
+        writer.write(&KeyedData { id, value: 0 }, None).unwrap();
+    }
+
+    // The reliable reader accepts the data addressed to it and to ENTITYID_UNKNOWN
+    mock_reader_socket
+        .set_read_timeout(Some(std::time::Duration::from_millis(100)))
+        .unwrap();
+    let mut reliable_reader_data_sequence_numbers = Vec::new();
+    let mut buffer = [0; 65535];
+    let start_time = std::time::Instant::now();
+    while start_time.elapsed() < std::time::Duration::from_secs(1) {
+        let Ok(length) = mock_reader_socket.recv(&mut buffer) else {
+            continue;
+        };
+        let rtps_message = RtpsMessageRead::try_from(&buffer[..length]).unwrap();
+        for submessage in rtps_message.submessages() {
+            if let RtpsSubmessageReadKind::Data(d) = submessage {
+                if d.writer_id() != ENTITYID_SEDP_BUILTIN_SUBSCRIPTIONS_ANNOUNCER
+                    && d.writer_id() != ENTITYID_SEDP_BUILTIN_PUBLICATIONS_ANNOUNCER
+                    && (d.reader_id() == reliable_reader_id || d.reader_id() == ENTITYID_UNKNOWN)
+                {
+                    reliable_reader_data_sequence_numbers.push(d.writer_sn());
+                }
+            }
+        }
+    }
+    assert_eq!(reliable_reader_data_sequence_numbers, vec![1, 2, 3]);
 }
@@ -0,0 +1,105 @@
+use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        error::DdsError,
+        qos::{DataReaderQos, DataWriterQos, QosKind},
+        qos_policy::{Length, ResourceLimitsQosPolicy},
+        status::NO_STATUS,
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct KeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u32,
+}
+
+// The configuration is global to the factory so this is the only test in this file
+#[test]
+fn endpoint_creation_exceeding_participant_budget_should_fail() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .participant_max_samples(Length::Limited(10))
+        .build()
+        .unwrap();
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic = participant
+        .create_topic::<KeyedData>(
+            "MyTopic",
+            "KeyedData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let resource_limits = ResourceLimitsQosPolicy {
+        max_samples: Length::Limited(6),
+        max_instances: Length::Limited(2),
+        max_samples_per_instance: Length::Limited(3),
+    };
+    let data_writer_qos = DataWriterQos {
+        resource_limits: resource_limits.clone(),
+        ..Default::default()
+    };
+    let data_reader_qos = DataReaderQos {
+        resource_limits,
+        ..Default::default()
+    };
+
+    let data_writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos.clone()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    assert_eq!(
+        subscriber
+            .create_datareader::<KeyedData>(
+                &topic,
+                QosKind::Specific(data_reader_qos),
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .err(),
+        Some(DdsError::OutOfResources)
+    );
+    assert_eq!(
+        subscriber
+            .create_datareader::<KeyedData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .err(),
+        Some(DdsError::OutOfResources)
+    );
+
+    publisher.delete_datawriter(&data_writer).unwrap();
+    assert!(publisher
+        .create_datawriter::<KeyedData>(
+            &topic,
+            QosKind::Specific(data_writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .is_ok());
+}
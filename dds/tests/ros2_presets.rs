@@ -0,0 +1,171 @@
+use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    domain::domain_participant_factory::DomainParticipantFactory,
+    infrastructure::{
+        qos::QosKind,
+        qos_policy::{DurabilityQosPolicyKind, HistoryQosPolicyKind, ReliabilityQosPolicyKind},
+        sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::DdsType,
+    },
+    listener::NO_LISTENER,
+    ros2::{Ros2QosProfile, Ros2Rmw},
+    wait_set::{Condition, WaitSet},
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct KeyedData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u8,
+}
+
+#[test]
+fn ros2_qos_profiles_should_follow_the_rmw_definitions() {
+    let default_writer_qos = Ros2QosProfile::Default.data_writer_qos();
+    assert_eq!(
+        default_writer_qos.reliability.kind,
+        ReliabilityQosPolicyKind::Reliable
+    );
+    assert_eq!(
+        default_writer_qos.history.kind,
+        HistoryQosPolicyKind::KeepLast(10)
+    );
+    assert_eq!(
+        default_writer_qos.durability.kind,
+        DurabilityQosPolicyKind::Volatile
+    );
+    assert_eq!(
+        default_writer_qos.liveliness.lease_duration,
+        DurationKind::Infinite
+    );
+
+    let sensor_data_reader_qos = Ros2QosProfile::SensorData.data_reader_qos();
+    assert_eq!(
+        sensor_data_reader_qos.reliability.kind,
+        ReliabilityQosPolicyKind::BestEffort
+    );
+    assert_eq!(
+        sensor_data_reader_qos.history.kind,
+        HistoryQosPolicyKind::KeepLast(5)
+    );
+
+    let rosout_topic_qos = Ros2QosProfile::Rosout.topic_qos();
+    assert_eq!(
+        rosout_topic_qos.durability.kind,
+        DurabilityQosPolicyKind::TransientLocal
+    );
+    assert_eq!(
+        rosout_topic_qos.history.kind,
+        HistoryQosPolicyKind::KeepLast(1000)
+    );
+    assert_eq!(
+        rosout_topic_qos.lifespan.duration,
+        DurationKind::Finite(Duration::new(10, 0))
+    );
+}
+
+// The configuration is global to the factory so this is the only test in this file using participants
+#[test]
+fn participants_with_ros2_presets_should_communicate() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .ros2_discovery_timing(Ros2Rmw::FastDds)
+        .build()
+        .unwrap();
+    assert_eq!(
+        configuration.participant_lease_duration(),
+        std::time::Duration::from_secs(20)
+    );
+    assert_eq!(
+        configuration.participant_announcement_interval(),
+        std::time::Duration::from_secs(3)
+    );
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant1 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let topic1 = participant1
+        .create_topic::<KeyedData>(
+            "rt/chatter",
+            "KeyedData",
+            QosKind::Specific(Ros2QosProfile::Default.topic_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let topic2 = participant2
+        .create_topic::<KeyedData>(
+            "rt/chatter",
+            "KeyedData",
+            QosKind::Specific(Ros2QosProfile::Default.topic_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant1
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<KeyedData>(
+            &topic1,
+            QosKind::Specific(Ros2QosProfile::Default.data_writer_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    // A sensor data subscription is compatible with a publication using the default profile
+    let subscriber = participant2
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<KeyedData>(
+            &topic2,
+            QosKind::Specific(Ros2QosProfile::SensorData.data_reader_qos()),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let reader_cond = reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut reader_wait_set = WaitSet::new();
+    reader_wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond.clone()))
+        .unwrap();
+    reader_wait_set.wait(Duration::new(10, 0)).unwrap();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+
+    writer.write(&KeyedData { id: 1, value: 7 }, None).unwrap();
+    reader_wait_set.wait(Duration::new(10, 0)).unwrap();
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap(), KeyedData { id: 1, value: 7 });
+}
@@ -1,13 +1,25 @@
 use dust_dds::{
+    builtin_topics::PublicationBuiltinTopicData,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataReaderQos, QosKind},
-        qos_policy::UserDataQosPolicy,
-        status::NO_STATUS,
-        type_support::DdsType,
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, Length, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind, ResourceLimitsQosPolicy, UserDataQosPolicy,
+        },
+        sample_info::SampleInfo,
+        status::{StatusKind, NO_STATUS},
+        time::{Duration, DurationKind},
+        type_support::{DdsDeserialize, DdsType, TypeSupport},
     },
     listener::NO_LISTENER,
+    runtime::DdsRuntime,
+    subscription::wildcard_subscription_listener::WildcardSubscriptionListener,
+    wait_set::{Condition, WaitSet},
+    xtypes::dynamic_type::DynamicType,
 };
+use std::sync::{mpsc::Sender, Arc};
 
 mod utils;
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
@@ -83,6 +95,51 @@ fn default_data_reader_qos() {
     assert_eq!(&reader.get_qos().unwrap().user_data.value, &user_data);
 }
 
+#[test]
+fn inconsistent_default_data_reader_qos_is_rejected_and_qos_default_restores_default() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let inconsistent_qos = DataReaderQos {
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepLast(10),
+        },
+        resource_limits: ResourceLimitsQosPolicy {
+            max_samples_per_instance: Length::Limited(2),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        subscriber.set_default_datareader_qos(QosKind::Specific(inconsistent_qos)),
+        Err(DdsError::InconsistentPolicy)
+    );
+
+    let qos = DataReaderQos {
+        user_data: UserDataQosPolicy {
+            value: vec![1, 2, 3],
+        },
+        ..Default::default()
+    };
+    subscriber
+        .set_default_datareader_qos(QosKind::Specific(qos))
+        .unwrap();
+    subscriber
+        .set_default_datareader_qos(QosKind::Default)
+        .unwrap();
+
+    assert_eq!(
+        subscriber.get_default_datareader_qos().unwrap(),
+        DataReaderQos::default()
+    );
+}
+
 #[test]
 fn different_readers_have_different_instance_handles() {
     let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
@@ -162,3 +219,134 @@ fn data_reader_get_topicdescription_should_return_same_topic_as_used_for_creatio
 
     assert!(reader.get_topicdescription().get_instance_handle() == topic.get_instance_handle());
 }
+
+#[derive(Clone, Debug, PartialEq, DdsType)]
+struct MyData {
+    #[dust_dds(key)]
+    id: u8,
+    value: u32,
+}
+
+struct WildcardListener {
+    sender: Sender<(String, MyData)>,
+}
+
+impl<R: DdsRuntime> WildcardSubscriptionListener<R> for WildcardListener {
+    fn get_topic_type(
+        &mut self,
+        _publication_data: &PublicationBuiltinTopicData,
+    ) -> Option<Arc<dyn DynamicType + Send + Sync>> {
+        Some(Arc::new(MyData::get_type()))
+    }
+
+    async fn on_sample(
+        &mut self,
+        topic_name: &str,
+        serialized_data: &[u8],
+        _sample_info: &SampleInfo,
+    ) {
+        self.sender
+            .send((
+                topic_name.to_string(),
+                MyData::deserialize_data(serialized_data).unwrap(),
+            ))
+            .unwrap();
+    }
+}
+
+#[test]
+fn wildcard_subscription_receives_samples_of_matching_topics() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let publisher_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber_participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let subscriber = subscriber_participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let (sender, receiver) = std::sync::mpsc::channel();
+    subscriber
+        .create_wildcard_subscription(
+            "rt/*",
+            QosKind::Specific(reader_qos),
+            WildcardListener { sender },
+        )
+        .unwrap();
+
+    let publisher = publisher_participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let mut writer_list = Vec::new();
+    for topic_name in ["rt/chatter", "rt/status", "other"] {
+        let topic = publisher_participant
+            .create_topic::<MyData>(
+                topic_name,
+                "MyData",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let writer = publisher
+            .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+        writer_list.push(writer);
+    }
+
+    for writer in &writer_list[0..2] {
+        let cond = writer.get_statuscondition();
+        cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+            .unwrap();
+        let mut wait_set = WaitSet::new();
+        wait_set
+            .attach_condition(Condition::StatusCondition(cond))
+            .unwrap();
+        wait_set.wait(Duration::new(10, 0)).unwrap();
+    }
+
+    for (i, writer) in writer_list.iter().enumerate() {
+        writer
+            .write(
+                &MyData {
+                    id: i as u8,
+                    value: 10,
+                },
+                None,
+            )
+            .unwrap();
+    }
+
+    let mut received_list = vec![
+        receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap(),
+        receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap(),
+    ];
+    received_list.sort_by_key(|(_, data)| data.id);
+    assert_eq!(
+        received_list,
+        vec![
+            (String::from("rt/chatter"), MyData { id: 0, value: 10 }),
+            (String::from("rt/status"), MyData { id: 1, value: 10 }),
+        ]
+    );
+    assert!(receiver
+        .recv_timeout(std::time::Duration::from_millis(500))
+        .is_err());
+    assert!(subscriber_participant
+        .lookup_topicdescription("other")
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,43 @@
+use dust_dds::{
+    configuration::DustDdsConfigurationBuilder,
+    domain::{domain_participant_factory::DomainParticipantFactory, task_health::TaskHealthStatus},
+    infrastructure::{qos::QosKind, status::NO_STATUS},
+    listener::NO_LISTENER,
+};
+
+mod utils;
+use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
+
+// The configuration is global to the factory so this is the only test in this file
+#[test]
+fn watchdog_reports_healthy_participant_tasks() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+    let domain_participant_factory = DomainParticipantFactory::get_instance();
+    let configuration = DustDdsConfigurationBuilder::new()
+        .participant_announcement_interval(std::time::Duration::from_millis(100))
+        .task_stall_threshold(std::time::Duration::from_millis(500))
+        .restart_stalled_tasks(true)
+        .build()
+        .unwrap();
+    domain_participant_factory
+        .set_configuration(configuration)
+        .unwrap();
+    let participant = domain_participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Run for several watchdog periods while the participant is used
+    for _ in 0..10 {
+        participant.get_qos().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    assert_eq!(
+        participant.get_task_health_status().unwrap(),
+        TaskHealthStatus::default()
+    );
+
+    domain_participant_factory
+        .delete_participant(&participant)
+        .unwrap();
+}
@@ -6,7 +6,9 @@ use dust_dds::{
     },
     infrastructure::{
         qos::{DataReaderQos, DataWriterQos, QosKind},
-        qos_policy::{ReliabilityQosPolicy, ReliabilityQosPolicyKind},
+        qos_policy::{
+            HistoryQosPolicy, HistoryQosPolicyKind, ReliabilityQosPolicy, ReliabilityQosPolicyKind,
+        },
         sample_info::{ANY_INSTANCE_STATE, ANY_SAMPLE_STATE, ANY_VIEW_STATE},
         status::{StatusKind, NO_STATUS},
         time::{Duration, DurationKind},
@@ -14,6 +16,7 @@ use dust_dds::{
     },
     listener::NO_LISTENER,
 };
+use futures::StreamExt;
 
 use crate::utils::domain_id_generator::TEST_DOMAIN_ID_GENERATOR;
 
@@ -124,3 +127,109 @@ async fn dust_dds_should_run_inside_tokio_runtime() {
     assert_eq!(samples.len(), 1);
     assert_eq!(samples[0].data().unwrap(), data);
 }
+
+#[tokio::test]
+async fn sample_stream_should_yield_samples_as_they_are_received() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant_factory = DomainParticipantFactoryAsync::get_instance();
+    let participant = participant_factory
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .await
+        .unwrap();
+    let topic = participant
+        .create_topic::<UserData>(
+            "SampleStreamTopic",
+            "UserData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .await
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .await
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        history: HistoryQosPolicy {
+            kind: HistoryQosPolicyKind::KeepAll,
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<UserData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .await
+        .unwrap();
+    let mut sample_stream = reader.sample_stream();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .await
+        .unwrap();
+    let mut wait_set = WaitSetAsync::new();
+    wait_set
+        .attach_condition(ConditionAsync::StatusCondition(cond))
+        .await
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).await.unwrap();
+
+    let data = [
+        UserData {
+            id: 1,
+            value: vec![1],
+        },
+        UserData {
+            id: 2,
+            value: vec![2],
+        },
+        UserData {
+            id: 1,
+            value: vec![3],
+        },
+    ];
+    for d in &data {
+        writer.write(d, None).await.unwrap();
+    }
+
+    let mut received_data = Vec::new();
+    while received_data.len() < data.len() {
+        let sample = sample_stream.next().await.unwrap();
+        received_data.push(sample.data().unwrap());
+    }
+    assert_eq!(received_data, data);
+
+    subscriber.delete_datareader(&reader).await.unwrap();
+    assert!(sample_stream.next().await.is_none());
+}
@@ -1 +1 @@
-pub mod domain_id_generator;
\ No newline at end of file
+pub mod domain_id_generator;
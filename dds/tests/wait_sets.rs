@@ -1,14 +1,25 @@
 mod utils;
 use dust_dds::{
+    builtin_topics::PublicationBuiltinTopicData,
+    condition::GuardCondition,
     domain::domain_participant_factory::DomainParticipantFactory,
     infrastructure::{
+        error::DdsError,
         qos::{DataReaderQos, DataWriterQos, QosKind},
-        qos_policy::{DeadlineQosPolicy, ReliabilityQosPolicy, ReliabilityQosPolicyKind},
-        status::{StatusKind, NO_STATUS},
+        qos_policy::{
+            DeadlineQosPolicy, LivelinessQosPolicy, LivelinessQosPolicyKind, ReliabilityQosPolicy,
+            ReliabilityQosPolicyKind, RELIABILITY_QOS_POLICY_ID,
+        },
+        sample_info::{
+            InstanceStateKind, SampleStateKind, ANY_INSTANCE_STATE, ANY_SAMPLE_STATE,
+            ANY_VIEW_STATE,
+        },
+        status::{QosPolicyCount, StatusKind, NO_STATUS},
         time::{Duration, DurationKind},
         type_support::DdsType,
     },
     listener::NO_LISTENER,
+    std_runtime::StdRuntime,
     wait_set::{Condition, WaitSet},
 };
 
@@ -113,3 +124,834 @@ fn writer_offered_deadline_missed_waitset() {
     assert_eq!(status.total_count, 1);
     assert_eq!(status.total_count_change, 1);
 }
+
+#[test]
+fn incompatible_qos_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::BestEffort,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<MyData>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer_cond = writer.get_statuscondition();
+    writer_cond
+        .set_enabled_statuses(&[StatusKind::OfferedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(writer_cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let reader_cond = reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::RequestedIncompatibleQos])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let offered_status = writer.get_offered_incompatible_qos_status().unwrap();
+    assert_eq!(offered_status.total_count, 1);
+    assert_eq!(offered_status.total_count_change, 1);
+    assert_eq!(offered_status.last_policy_id, RELIABILITY_QOS_POLICY_ID);
+    assert_eq!(
+        offered_status.policies,
+        vec![QosPolicyCount {
+            policy_id: RELIABILITY_QOS_POLICY_ID,
+            count: 1
+        }]
+    );
+    assert!(!writer_cond.get_trigger_value().unwrap());
+
+    let requested_status = reader.get_requested_incompatible_qos_status().unwrap();
+    assert_eq!(requested_status.total_count, 1);
+    assert_eq!(requested_status.total_count_change, 1);
+    assert_eq!(requested_status.last_policy_id, RELIABILITY_QOS_POLICY_ID);
+    assert_eq!(
+        requested_status.policies,
+        vec![QosPolicyCount {
+            policy_id: RELIABILITY_QOS_POLICY_ID,
+            count: 1
+        }]
+    );
+    assert!(!reader_cond.get_trigger_value().unwrap());
+}
+
+#[test]
+fn status_changes_should_list_the_triggered_statuses() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    assert_eq!(writer.get_status_changes().unwrap(), vec![]);
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let writer_cond = writer.get_statuscondition();
+    writer_cond
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(writer_cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let reader_cond = reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::SubscriptionMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    assert!(writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::PublicationMatched));
+    assert!(reader
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::SubscriptionMatched));
+
+    writer.get_publication_matched_status().unwrap();
+    reader.get_subscription_matched_status().unwrap();
+    assert!(!writer
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::PublicationMatched));
+    assert!(!reader
+        .get_status_changes()
+        .unwrap()
+        .contains(&StatusKind::SubscriptionMatched));
+}
+
+#[test]
+fn writer_liveliness_lost_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByTopic,
+            lease_duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    writer.write(&MyData { id: 1, value: 1 }, None).unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessLost])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let status = writer.get_liveliness_lost_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.total_count_change, 1);
+}
+
+#[test]
+fn read_and_query_conditions_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter::<MyData>(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let writer_cond = writer.get_statuscondition();
+    writer_cond
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(writer_cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let read_condition = reader
+        .create_readcondition(
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            &[InstanceStateKind::Alive],
+        )
+        .unwrap();
+    let query_condition = reader
+        .create_querycondition(
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+            "value > %0",
+            &[String::from("5")],
+        )
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::ReadCondition(read_condition.clone()))
+        .unwrap();
+    wait_set
+        .attach_condition(Condition::QueryCondition(query_condition.clone()))
+        .unwrap();
+    assert!(matches!(
+        wait_set.wait(Duration::new(0, 100_000_000)),
+        Err(DdsError::Timeout)
+    ));
+
+    writer.write(&MyData { id: 1, value: 1 }, None).unwrap();
+    let triggered_conditions = wait_set.wait(Duration::new(10, 0)).unwrap();
+    assert_eq!(triggered_conditions.len(), 1);
+    assert!(matches!(
+        triggered_conditions[0],
+        Condition::ReadCondition(_)
+    ));
+
+    writer.write(&MyData { id: 2, value: 10 }, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+    assert_eq!(wait_set.wait(Duration::new(10, 0)).unwrap().len(), 2);
+
+    query_condition
+        .set_query_parameters(&[String::from("20")])
+        .unwrap();
+    assert_eq!(
+        query_condition.get_query_parameters().unwrap(),
+        vec![String::from("20")]
+    );
+    assert!(!query_condition.get_trigger_value().unwrap());
+
+    reader
+        .take(10, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert!(!read_condition.get_trigger_value().unwrap());
+
+    wait_set
+        .detach_condition(Condition::ReadCondition(read_condition.clone()))
+        .unwrap();
+    assert_eq!(wait_set.get_conditions().unwrap().len(), 1);
+    reader.delete_readcondition(&read_condition).unwrap();
+    reader.delete_querycondition(&query_condition).unwrap();
+}
+
+#[test]
+fn guard_condition_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_cond = reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+
+    let guard_condition = GuardCondition::new();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+    wait_set
+        .attach_condition(Condition::GuardCondition(guard_condition.clone()))
+        .unwrap();
+
+    let guard_condition_setter = guard_condition.clone();
+    let setter_thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        guard_condition_setter.set_trigger_value(true).unwrap();
+    });
+    let triggered_conditions = wait_set.wait(Duration::new(10, 0)).unwrap();
+    setter_thread.join().unwrap();
+
+    assert_eq!(triggered_conditions.len(), 1);
+    assert!(matches!(
+        triggered_conditions[0],
+        Condition::GuardCondition(_)
+    ));
+    assert!(guard_condition.get_trigger_value().unwrap());
+
+    guard_condition.set_trigger_value(false).unwrap();
+    assert!(matches!(
+        wait_set.wait(Duration::new(0, 100_000_000)),
+        Err(DdsError::Timeout)
+    ));
+}
+
+#[test]
+fn guard_condition_only_waitset() {
+    let guard_condition = GuardCondition::new();
+    let mut wait_set = WaitSet::<StdRuntime>::new();
+    wait_set
+        .attach_condition(Condition::GuardCondition(guard_condition.clone()))
+        .unwrap();
+
+    assert!(matches!(
+        wait_set.wait(Duration::new(0, 100_000_000)),
+        Err(DdsError::Timeout)
+    ));
+
+    let guard_condition_setter = guard_condition.clone();
+    let setter_thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        guard_condition_setter.set_trigger_value(true).unwrap();
+    });
+    let triggered_conditions = wait_set.wait(Duration::new(10, 0)).unwrap();
+    setter_thread.join().unwrap();
+
+    assert_eq!(triggered_conditions.len(), 1);
+    assert!(matches!(
+        triggered_conditions[0],
+        Condition::GuardCondition(_)
+    ));
+}
+
+#[test]
+fn waitset_on_builtin_reader_conditions() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let publications_reader = participant
+        .get_builtin_subscriber()
+        .lookup_datareader::<PublicationBuiltinTopicData>("DCPSPublication")
+        .unwrap()
+        .unwrap();
+    let reader_cond = publications_reader.get_statuscondition();
+    reader_cond
+        .set_enabled_statuses(&[StatusKind::DataAvailable])
+        .unwrap();
+    let read_condition = publications_reader
+        .create_readcondition(
+            &[SampleStateKind::NotRead],
+            ANY_VIEW_STATE,
+            ANY_INSTANCE_STATE,
+        )
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(reader_cond))
+        .unwrap();
+    assert!(matches!(
+        wait_set.wait(Duration::new(0, 100_000_000)),
+        Err(DdsError::Timeout)
+    ));
+
+    let _writer = publisher
+        .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+    assert!(read_condition.get_trigger_value().unwrap());
+
+    let samples = publications_reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap().topic_name(), "MyTopic");
+    assert!(!read_condition.get_trigger_value().unwrap());
+}
+
+#[test]
+fn reader_requested_deadline_missed_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        deadline: DeadlineQosPolicy {
+            period: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let data1 = MyData { id: 1, value: 1 };
+    writer.write(&data1, None).unwrap();
+    writer
+        .wait_for_acknowledgments(Duration::new(10, 0))
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::RequestedDeadlineMissed])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let status = reader.get_requested_deadline_missed_status().unwrap();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.total_count_change, 1);
+    assert!(!cond.get_trigger_value().unwrap());
+}
+
+#[test]
+fn reader_liveliness_changed_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let writer_participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_topic = writer_participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let publisher = writer_participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        liveliness: LivelinessQosPolicy {
+            kind: LivelinessQosPolicyKind::ManualByParticipant,
+            lease_duration: DurationKind::Finite(Duration::new(0, 500_000_000)),
+        },
+        ..Default::default()
+    };
+    let _writer = publisher
+        .create_datawriter::<MyData>(
+            &writer_topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let reader_participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_topic = reader_participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+    let subscriber = reader_participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader = subscriber
+        .create_datareader::<MyData>(&reader_topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let cond = reader.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::LivelinessChanged])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond.clone()))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.alive_count_change, 1);
+    assert_eq!(status.not_alive_count, 0);
+    assert_eq!(
+        vec![status.last_publication_handle],
+        reader.get_matched_publications().unwrap()
+    );
+
+    // The participant assertions are received by the reader as participant messages which keep
+    // the writer alive beyond its lease
+    for _ in 0..10 {
+        writer_participant.assert_liveliness().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let status = reader.get_liveliness_changed_status().unwrap();
+    assert_eq!(status.alive_count, 0);
+    assert_eq!(status.alive_count_change, -1);
+    assert_eq!(status.not_alive_count, 1);
+    assert_eq!(status.not_alive_count_change, 1);
+    assert!(!cond.get_trigger_value().unwrap());
+}
+
+#[test]
+fn subscriber_data_on_readers_waitset() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let topic = participant
+        .create_topic::<MyData>(
+            "MyTopic",
+            "MyData",
+            QosKind::Default,
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let publisher = participant
+        .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let writer_qos = DataWriterQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let writer = publisher
+        .create_datawriter(
+            &topic,
+            QosKind::Specific(writer_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let subscriber = participant
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let reader_qos = DataReaderQos {
+        reliability: ReliabilityQosPolicy {
+            kind: ReliabilityQosPolicyKind::Reliable,
+            max_blocking_time: DurationKind::Finite(Duration::new(1, 0)),
+        },
+        ..Default::default()
+    };
+    let reader = subscriber
+        .create_datareader::<MyData>(
+            &topic,
+            QosKind::Specific(reader_qos),
+            NO_LISTENER,
+            NO_STATUS,
+        )
+        .unwrap();
+
+    let cond = writer.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond))
+        .unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let cond = subscriber.get_statuscondition();
+    cond.set_enabled_statuses(&[StatusKind::DataOnReaders])
+        .unwrap();
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond.clone()))
+        .unwrap();
+
+    let data1 = MyData { id: 1, value: 1 };
+    writer.write(&data1, None).unwrap();
+    wait_set.wait(Duration::new(10, 0)).unwrap();
+
+    let samples = reader
+        .take(1, ANY_SAMPLE_STATE, ANY_VIEW_STATE, ANY_INSTANCE_STATE)
+        .unwrap();
+    assert_eq!(samples[0].data().unwrap(), data1);
+    assert!(!cond.get_trigger_value().unwrap());
+}
+
+#[test]
+fn waitset_with_status_conditions_of_different_participants() {
+    let domain_id = TEST_DOMAIN_ID_GENERATOR.generate_unique_domain_id();
+
+    let participant1 = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let participant2 = DomainParticipantFactory::get_instance()
+        .create_participant(domain_id, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    // Both writers are the first entities created in their participant
+    let mut writers = Vec::new();
+    for participant in [&participant1, &participant2] {
+        let topic = participant
+            .create_topic::<MyData>(
+                "MyTopic",
+                "MyData",
+                QosKind::Default,
+                NO_LISTENER,
+                NO_STATUS,
+            )
+            .unwrap();
+        let publisher = participant
+            .create_publisher(QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+        let writer = publisher
+            .create_datawriter::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+            .unwrap();
+        writers.push(writer);
+    }
+    let cond1 = writers[0].get_statuscondition();
+    cond1
+        .set_enabled_statuses(&[StatusKind::LivelinessLost])
+        .unwrap();
+    let cond2 = writers[1].get_statuscondition();
+    cond2
+        .set_enabled_statuses(&[StatusKind::PublicationMatched])
+        .unwrap();
+
+    let mut wait_set = WaitSet::new();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond1))
+        .unwrap();
+    wait_set
+        .attach_condition(Condition::StatusCondition(cond2))
+        .unwrap();
+    assert_eq!(wait_set.get_conditions().unwrap().len(), 2);
+
+    let topic = participant1
+        .lookup_topicdescription("MyTopic")
+        .unwrap()
+        .unwrap();
+    let subscriber = participant1
+        .create_subscriber(QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+    let _reader = subscriber
+        .create_datareader::<MyData>(&topic, QosKind::Default, NO_LISTENER, NO_STATUS)
+        .unwrap();
+
+    let triggered_conditions = wait_set.wait(Duration::new(10, 0)).unwrap();
+    assert_eq!(triggered_conditions.len(), 1);
+}
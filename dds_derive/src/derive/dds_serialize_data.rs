@@ -18,6 +18,13 @@ pub fn expand_dds_serialize_data(input: &DeriveInput) -> Result<TokenStream> {
                     fn serialize_data(&self) -> dust_dds::infrastructure::error::DdsResult<Vec<u8>> {
                         #serialize_function
                     }
+
+                    fn serialize_data_with_representation(
+                        &self,
+                        representation: dust_dds::infrastructure::qos_policy::DataRepresentationId,
+                    ) -> dust_dds::infrastructure::error::DdsResult<Vec<u8>> {
+                        dust_dds::infrastructure::type_support::serialize_rtps_xtypes_le(self, representation)
+                    }
                 }
             })
         }